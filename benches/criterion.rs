@@ -22,6 +22,31 @@ fn rasn(c: &mut Criterion) {
     bench_encoding_rules!(ber, der, cer, uper, oer);
 }
 
+fn der_two_pass(c: &mut Criterion) {
+    let decoded = black_box(bench_default());
+    // A large nested value, similar in shape to a CRL with many entries.
+    let nested: Vec<Vec<Bench>> = black_box(
+        (0..64)
+            .map(|_| (0..64).map(|_| bench_default()).collect())
+            .collect(),
+    );
+
+    let mut group = c.benchmark_group("DER - Two Pass");
+    group.bench_function("buffered", |b| {
+        b.iter_with_large_drop(|| black_box(rasn::der::encode(&decoded).unwrap()))
+    });
+    group.bench_function("two pass", |b| {
+        b.iter_with_large_drop(|| black_box(rasn::der::encode_two_pass(&decoded).unwrap()))
+    });
+    group.bench_function("nested buffered", |b| {
+        b.iter_with_large_drop(|| black_box(rasn::der::encode(&nested).unwrap()))
+    });
+    group.bench_function("nested two pass", |b| {
+        b.iter_with_large_drop(|| black_box(rasn::der::encode_two_pass(&nested).unwrap()))
+    });
+    group.finish();
+}
+
 #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
 fn x509_decode(c: &mut Criterion) {
     let data: &[u8] = include_bytes!("../standards/pkix/tests/data/letsencrypt-x3.crt");
@@ -65,6 +90,10 @@ fn x509_encode(c: &mut Criterion) {
         let cert = rasn::der::decode::<rasn_pkix::Certificate>(data).unwrap();
         b.iter(|| black_box(rasn::der::encode(&cert)))
     });
+    group.bench_function("rasn (two pass)", |b| {
+        let cert = rasn::der::decode::<rasn_pkix::Certificate>(data).unwrap();
+        b.iter(|| black_box(rasn::der::encode_two_pass(&cert)))
+    });
     group.bench_function("x509-cert", |b| {
         use x509_cert::der::{Decode, Encode};
         let cert = x509_cert::Certificate::from_der(data).unwrap();
@@ -131,8 +160,15 @@ fn x509_rtt(c: &mut Criterion) {
 }
 
 #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
-criterion_group!(codec, x509_decode, x509_encode, x509_rtt, rasn);
+criterion_group!(
+    codec,
+    x509_decode,
+    x509_encode,
+    x509_rtt,
+    rasn,
+    der_two_pass
+);
 
 #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
-criterion_group!(codec, rasn);
+criterion_group!(codec, rasn, der_two_pass);
 criterion_main!(codec);
@@ -7,6 +7,11 @@ fn main() {
     let cer = rasn::cer::encode(&original).unwrap();
     let uper = rasn::uper::encode(&original).unwrap();
     let aper = rasn::aper::encode(&original).unwrap();
+    let pem = rasn::der::encode_pem(&original).unwrap();
+    assert_eq!(
+        rasn::der::decode_pem::<Certificate>(pem.as_bytes()).unwrap(),
+        original
+    );
 
     let der_len = DER_BYTES.len();
     println!("Original DER size: {der_len:.2}");
@@ -30,6 +35,11 @@ fn main() {
         aper.len(),
         percentage_difference(der_len, aper.len())
     );
+    println!(
+        "PEM size: {} ({:.2}%)",
+        pem.len(),
+        percentage_difference(der_len, pem.len())
+    );
 }
 
 pub fn percentage_difference(num1: usize, num2: usize) -> f64 {
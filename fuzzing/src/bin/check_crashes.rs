@@ -1,11 +1,17 @@
 fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Crashes of the `differential` target are replayed with its harness.
+    let fuzz: fn(&[u8]) = match std::env::args().nth(1).as_deref() {
+        Some("differential") => fuzz::differential::fuzz,
+        _ => fuzz::fuzz,
+    };
+
     for file in std::fs::read_dir("out/default/crashes/")?
         .filter_map(Result::ok)
         .map(|entry| entry.path())
     {
         let case = file.file_stem().unwrap().to_str().unwrap().to_owned();
         println!("Testing Crash case: `{}`", case);
-        fuzz::fuzz(&std::fs::read(file)?);
+        fuzz(&std::fs::read(file)?);
         println!("Testing Crash case: `{}` successful", case);
     }
 
@@ -0,0 +1,7 @@
+extern crate afl;
+
+fn main() {
+    afl::fuzz!(|data: &[u8]| {
+        fuzz::differential::fuzz(data);
+    });
+}
@@ -0,0 +1,632 @@
+//! Differential fuzzing across every codec.
+//!
+//! Fuzz input is decoded as one of [`TARGETS`] with one of [`CODECS`], both
+//! selected by its first bytes. A decoded value is then re-encoded with every
+//! codec, and must decode back to the same value, with the canonical codecs
+//! also producing the same bytes when re-encoding it. Input decoded by a
+//! canonical codec must be that codec's encoding of the value.
+//!
+//! [`Any`](rasn::types::Any) holds an encoded BER element, which JER and
+//! XER can't represent, so targets containing it are only decoded with the
+//! BER codecs, and aren't re-encoded with the text codecs.
+
+use core::fmt;
+
+use rasn::{
+    error::{DecodeError, EncodeError, EncodeErrorKind},
+    Codec, Decode, Encode,
+};
+use rasn_its as its;
+use rasn_pkix as pkix;
+use rasn_snmp as snmp;
+
+use crate::personnel;
+
+/// The codecs values are decoded from and re-encoded with.
+pub const CODECS: [Codec; 9] = [
+    Codec::Ber,
+    Codec::Cer,
+    Codec::Der,
+    Codec::Uper,
+    Codec::Aper,
+    Codec::Oer,
+    Codec::Coer,
+    Codec::Jer,
+    Codec::Xer,
+];
+
+/// The codecs which can represent `Any`.
+const ANY_CODECS: [Codec; 7] = [
+    Codec::Ber,
+    Codec::Cer,
+    Codec::Der,
+    Codec::Uper,
+    Codec::Aper,
+    Codec::Oer,
+    Codec::Coer,
+];
+
+/// The codecs whose encoding of a value must be byte for byte stable.
+const CANONICAL_CODECS: [Codec; 2] = [Codec::Der, Codec::Coer];
+
+/// The first codec which didn't round trip a decoded value.
+#[derive(Debug)]
+pub enum Divergence {
+    /// The codec couldn't encode a value within the constraints of its type.
+    Encode { codec: Codec, error: EncodeError },
+    /// The codec couldn't decode its own encoding of the value.
+    Decode { codec: Codec, error: DecodeError },
+    /// The codec decoded its own encoding of the value as a different value.
+    Value {
+        codec: Codec,
+        expected: String,
+        found: String,
+    },
+    /// The canonical codec encoded the same value as different bytes.
+    Unstable {
+        codec: Codec,
+        first: Vec<u8>,
+        second: Vec<u8>,
+    },
+    /// The canonical codec decoded bytes other than its encoding of the value.
+    NonCanonical {
+        codec: Codec,
+        data: Vec<u8>,
+        encoded: Vec<u8>,
+    },
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Encode { codec, error } => write!(f, "{codec} failed to encode: {error}"),
+            Self::Decode { codec, error } => {
+                write!(f, "{codec} failed to decode its own encoding: {error}")
+            }
+            Self::Value {
+                codec,
+                expected,
+                found,
+            } => write!(
+                f,
+                "{codec} decoded its own encoding of {expected} as {found}"
+            ),
+            Self::Unstable {
+                codec,
+                first,
+                second,
+            } => write!(f, "{codec} re-encoded {first:02x?} as {second:02x?}"),
+            Self::NonCanonical {
+                codec,
+                data,
+                encoded,
+            } => write!(
+                f,
+                "{codec} decoded {data:02x?}, which it encodes as {encoded:02x?}"
+            ),
+        }
+    }
+}
+
+/// Decodes `data` as a `T` with `codec`, and checks that every codec round
+/// trips the value.
+///
+/// Codecs which can't encode the value because it is outside of the
+/// constraints of its type, such as a PER encoder given a value a BER
+/// decoder doesn't check, are skipped.
+pub fn check<T>(codec: Codec, data: &[u8]) -> Result<(), Divergence>
+where
+    T: Decode + Encode + PartialEq + fmt::Debug,
+{
+    round_trip::<T>(codec, data, &CODECS)
+}
+
+/// Like [`check`], for types containing `Any`, which only decodes `data`
+/// with the BER codecs, and only re-encodes the value with [`ANY_CODECS`].
+pub fn check_any<T>(codec: Codec, data: &[u8]) -> Result<(), Divergence>
+where
+    T: Decode + Encode + PartialEq + fmt::Debug,
+{
+    if !matches!(codec, Codec::Ber | Codec::Cer | Codec::Der) {
+        return Ok(());
+    }
+    round_trip::<T>(codec, data, &ANY_CODECS)
+}
+
+/// Decodes `data` as a `T` with `codec`, and checks that each of `codecs`
+/// round trips the value.
+fn round_trip<T>(codec: Codec, data: &[u8], codecs: &[Codec]) -> Result<(), Divergence>
+where
+    T: Decode + Encode + PartialEq + fmt::Debug,
+{
+    let value = if CANONICAL_CODECS.contains(&codec) {
+        let Ok((value, remainder)) = codec.decode_from_binary_with_remainder::<T>(data) else {
+            return Ok(());
+        };
+        let data = &data[..data.len() - remainder.len()];
+        if let Some(encoded) = encode(codec, &value)? {
+            if encoded != data {
+                return Err(Divergence::NonCanonical {
+                    codec,
+                    data: data.to_vec(),
+                    encoded,
+                });
+            }
+        }
+        value
+    } else {
+        let Ok(value) = codec.decode_from_binary::<T>(data) else {
+            return Ok(());
+        };
+        value
+    };
+
+    for &codec in codecs {
+        let Some(encoded) = encode(codec, &value)? else {
+            continue;
+        };
+        let decoded = codec
+            .decode_from_binary::<T>(&encoded)
+            .map_err(|error| Divergence::Decode { codec, error })?;
+
+        if decoded != value {
+            return Err(Divergence::Value {
+                codec,
+                expected: format!("{value:?}"),
+                found: format!("{decoded:?}"),
+            });
+        }
+
+        if CANONICAL_CODECS.contains(&codec) {
+            let reencoded = codec
+                .encode_to_binary(&decoded)
+                .map_err(|error| Divergence::Encode { codec, error })?;
+            if reencoded != encoded {
+                return Err(Divergence::Unstable {
+                    codec,
+                    first: encoded,
+                    second: reencoded,
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Encodes `value` with `codec`, or returns `None` if the value is outside of
+/// the constraints of its type.
+fn encode<T: Encode>(codec: Codec, value: &T) -> Result<Option<Vec<u8>>, Divergence> {
+    match codec.encode_to_binary(value) {
+        Ok(encoded) => Ok(Some(encoded)),
+        Err(error) => match *error.kind {
+            EncodeErrorKind::AlphabetConstraintNotSatisfied { .. }
+            | EncodeErrorKind::SizeConstraintNotSatisfied { .. }
+            | EncodeErrorKind::ValueConstraintNotSatisfied { .. } => Ok(None),
+            _ => Err(Divergence::Encode { codec, error }),
+        },
+    }
+}
+
+/// Runs [`check`] for the codec selected by the first byte of `data` and the
+/// target selected by the next two, panicking at the first divergence.
+pub fn fuzz(data: &[u8]) {
+    let [codec, high, low, data @ ..] = data else {
+        return;
+    };
+    let codec = CODECS[usize::from(*codec) % CODECS.len()];
+    let (name, check) = TARGETS[usize::from(u16::from_be_bytes([*high, *low])) % TARGETS.len()];
+
+    if let Err(divergence) = check(codec, data) {
+        panic!("`{}` decoded with {}: {}", name, codec, divergence);
+    }
+}
+
+macro_rules! target {
+    (#[any] $ty:ty) => {
+        check_any::<$ty>
+    };
+    ($ty:ty) => {
+        check::<$ty>
+    };
+}
+
+/// Types marked `#[any]` contain `Any`, and are checked with [`check_any`].
+macro_rules! targets {
+    ($($(#[$marker:ident])? $ty:ty),+ $(,)?) => {
+        /// The types which are fuzzed, with their names.
+        pub const TARGETS: &[(&str, fn(Codec, &[u8]) -> Result<(), Divergence>)] = &[
+            $((stringify!($ty), target!($(#[$marker])? $ty))),+
+        ];
+    }
+}
+
+targets! {
+    pkix::algorithms::DssParams,
+    pkix::algorithms::DssSigValue,
+    pkix::algorithms::RsaPublicKey,
+    pkix::algorithms::DomainParameters,
+    pkix::algorithms::ValidationParams,
+    #[any] pkix::algorithms::FieldId,
+    pkix::algorithms::EcdsaSigValue,
+    #[any] pkix::algorithms::CharacteristicTwo,
+    pkix::algorithms::Pentanomial,
+    #[any] pkix::algorithms::EcpkParameters,
+    #[any] pkix::algorithms::EcParameters,
+    pkix::algorithms::Curve,
+    #[any] pkix::attribute_certificate::AttributeCertificate,
+    #[any] pkix::attribute_certificate::AttributeCertificateInfo,
+    pkix::attribute_certificate::AttributeCertificateVersion,
+    #[any] pkix::attribute_certificate::Holder,
+    #[any] pkix::attribute_certificate::ObjectDigestInfo,
+    pkix::attribute_certificate::DisgestedObjectType,
+    #[any] pkix::attribute_certificate::Issuer,
+    #[any] pkix::attribute_certificate::V2Form,
+    #[any] pkix::attribute_certificate::IssuerSerial,
+    pkix::attribute_certificate::AttributeCertificateValidityPeriod,
+    #[any] pkix::attribute_certificate::Target,
+    #[any] pkix::attribute_certificate::TargetCert,
+    #[any] pkix::attribute_certificate::IetfAttrSyntax,
+    pkix::attribute_certificate::IetfAttrSyntaxValue,
+    #[any] pkix::attribute_certificate::SvceAuthInfo,
+    #[any] pkix::attribute_certificate::RoleSyntax,
+    #[any] pkix::attribute_certificate::Clearance,
+    pkix::attribute_certificate::ClassList,
+    #[any] pkix::attribute_certificate::SecurityCategory,
+    pkix::attribute_certificate::AaControls,
+    #[any] pkix::attribute_certificate::AttributeCertificateClearAttributes,
+    #[any] pkix::est::AttrOrOid,
+    #[any] pkix::est::Attribute,
+    #[any] pkix::Certificate,
+    #[any] pkix::TbsCertificate,
+    pkix::Version,
+    #[any] pkix::TrustAnchorInfo,
+    #[any] pkix::CertPathControls,
+    #[any] pkix::TrustAnchorChoice,
+    pkix::TrustAnchorInfoVersion,
+    pkix::Validity,
+    pkix::Time,
+    #[any] pkix::SubjectPublicKeyInfo,
+    #[any] pkix::AuthorityKeyIdentifier,
+    pkix::Extension,
+    #[any] pkix::CertificateList,
+    #[any] pkix::TbsCertList,
+    pkix::RevokedCertificate,
+    #[any] pkix::AlgorithmIdentifier,
+    #[any] pkix::OrAddress,
+    pkix::BuiltInStandardAttributes,
+    pkix::BuiltInDomainDefinedAttributes,
+    pkix::BuiltInDomainDefinedAttribute,
+    pkix::CountryName,
+    pkix::PrivateDomainName,
+    pkix::AdministrationDomainName,
+    pkix::PersonalName,
+    pkix::OrganisationalUnitNames,
+    pkix::OrganisationalUnitName,
+    pkix::Extensions,
+    #[any] pkix::RelativeDistinguishedName,
+    #[any] pkix::ExtensionAttributes,
+    #[any] pkix::ExtensionAttribute,
+    pkix::TeletexPersonalName,
+    pkix::PhysicalDeliveryCountryName,
+    pkix::PostalCode,
+    pkix::CommonName,
+    pkix::TeletexCommonName,
+    pkix::TeletexOrganizationName,
+    pkix::TeletexOrganisationalUnitNames,
+    pkix::TeletexOrganisationalUnitName,
+    pkix::PdsName,
+    pkix::PrintableAddress,
+    pkix::TeletexAddress,
+    pkix::UnformattedPostalAddress,
+    pkix::PdsParameter,
+    pkix::ExtendedNetworkAddress,
+    pkix::E1634Address,
+    pkix::PresentationAddress,
+    pkix::TeletexDomainDefinedAttributes,
+    pkix::TeletexDomainDefinedAttribute,
+    #[any] pkix::Name,
+    #[any] pkix::Attribute,
+    #[any] pkix::AttributeTypeAndValue,
+    #[any] pkix::PolicyInformation,
+    #[any] pkix::PolicyQualifierInfo,
+    pkix::UserNotice,
+    pkix::NoticeReference,
+    pkix::DisplayText,
+    pkix::PolicyMapping,
+    #[any] pkix::GeneralName,
+    pkix::EdiPartyName,
+    pkix::X520CountryName,
+    pkix::X520SerialNumber,
+    pkix::X520StateOrProvinceName,
+    pkix::X520OrganisationName,
+    pkix::X520OrganisationalUnitName,
+    pkix::X520Title,
+    pkix::X520Pseudonym,
+    pkix::X520LocalityName,
+    pkix::X520Name,
+    pkix::X520CommonName,
+    pkix::DirectoryString,
+    pkix::BasicConstraints,
+    #[any] pkix::NameConstraints,
+    #[any] pkix::GeneralSubtree,
+    pkix::PolicyConstraints,
+    #[any] pkix::DistributionPoint,
+    #[any] pkix::DistributionPointName,
+    #[any] pkix::AccessDescription,
+    pkix::CrlReason,
+    #[any] pkix::IssuingDistributionPoint,
+    pkix::PrivateKeyUsagePeriod,
+    snmp::v1::Pdus,
+    snmp::v1::GetRequest,
+    snmp::v1::GetNextRequest,
+    snmp::v1::GetResponse,
+    snmp::v1::SetRequest,
+    snmp::v1::Pdu,
+    snmp::v1::Trap,
+    snmp::v1::VarBind,
+    snmp::v2::Pdus,
+    snmp::v2::GetRequest,
+    snmp::v2::GetNextRequest,
+    snmp::v2::Response,
+    snmp::v2::SetRequest,
+    snmp::v2::GetBulkRequest,
+    snmp::v2::InformRequest,
+    snmp::v2::Trap,
+    snmp::v2::Report,
+    snmp::v2::Pdu,
+    snmp::v2::BulkPdu,
+    snmp::v2::VarBind,
+    snmp::v2::VarBindValue,
+    snmp::v3::Message,
+    snmp::v3::HeaderData,
+    snmp::v3::ScopedPduData,
+    snmp::v3::ScopedPdu,
+    snmp::v3::USMSecurityParameters,
+    its::ieee1609dot2::base_types::Uint3,
+    its::ieee1609dot2::base_types::SequenceOfUint16,
+    its::ieee1609dot2::base_types::SequenceOfUint8,
+    its::ieee1609dot2::base_types::Opaque,
+    its::ieee1609dot2::base_types::HashedId3,
+    its::ieee1609dot2::base_types::SequenceOfHashedId3,
+    its::ieee1609dot2::base_types::HashedId8,
+    its::ieee1609dot2::base_types::HashedId10,
+    its::ieee1609dot2::base_types::HashedId32,
+    its::ieee1609dot2::base_types::HashedId48,
+    its::ieee1609dot2::base_types::Time32,
+    its::ieee1609dot2::base_types::Time64,
+    its::ieee1609dot2::base_types::ValidityPeriod,
+    its::ieee1609dot2::base_types::Duration,
+    its::ieee1609dot2::base_types::GeographicRegion,
+    its::ieee1609dot2::base_types::CircularRegion,
+    its::ieee1609dot2::base_types::RectangularRegion,
+    its::ieee1609dot2::base_types::SequenceOfRectangularRegion,
+    its::ieee1609dot2::base_types::PolygonalRegion,
+    its::ieee1609dot2::base_types::TwoDLocation,
+    its::ieee1609dot2::base_types::IdentifiedRegion,
+    its::ieee1609dot2::base_types::SequenceOfIdentifiedRegion,
+    its::ieee1609dot2::base_types::UnCountryId,
+    its::ieee1609dot2::base_types::CountryOnly,
+    its::ieee1609dot2::base_types::CountryAndRegions,
+    its::ieee1609dot2::base_types::CountryAndSubregions,
+    its::ieee1609dot2::base_types::RegionAndSubregions,
+    its::ieee1609dot2::base_types::SequenceOfRegionAndSubregions,
+    its::ieee1609dot2::base_types::ThreeDLocation,
+    its::ieee1609dot2::base_types::Latitude,
+    its::ieee1609dot2::base_types::Longitude,
+    its::ieee1609dot2::base_types::Elevation,
+    its::ieee1609dot2::base_types::NinetyDegreeInt,
+    its::ieee1609dot2::base_types::KnownLatitude,
+    its::ieee1609dot2::base_types::UnknownLatitude,
+    its::ieee1609dot2::base_types::OneEightyDegreeInt,
+    its::ieee1609dot2::base_types::KnownLongitude,
+    its::ieee1609dot2::base_types::UnknownLongitude,
+    its::ieee1609dot2::base_types::Signature,
+    its::ieee1609dot2::base_types::EcdsaP256Signature,
+    its::ieee1609dot2::base_types::EcdsaP384Signature,
+    its::ieee1609dot2::base_types::EcsigP256Signature,
+    its::ieee1609dot2::base_types::EccP256CurvePoint,
+    its::ieee1609dot2::base_types::EccP256CurvePointUncompressedP256,
+    its::ieee1609dot2::base_types::EccP384CurvePoint,
+    its::ieee1609dot2::base_types::EccP384CurvePointUncompressedP384,
+    its::ieee1609dot2::base_types::SymmAlgorithm,
+    its::ieee1609dot2::base_types::HashAlgorithm,
+    its::ieee1609dot2::base_types::EciesP256EncryptedKey,
+    its::ieee1609dot2::base_types::EcencP256EncryptedKey,
+    its::ieee1609dot2::base_types::EncryptionKey,
+    its::ieee1609dot2::base_types::PublicEncryptionKey,
+    its::ieee1609dot2::base_types::BasePublicEncryptionKey,
+    its::ieee1609dot2::base_types::PublicVerificationKey,
+    its::ieee1609dot2::base_types::SymmetricEncryptionKey,
+    its::ieee1609dot2::base_types::PsidSsp,
+    its::ieee1609dot2::base_types::SequenceOfPsidSsp,
+    its::ieee1609dot2::base_types::Psid,
+    its::ieee1609dot2::base_types::SequenceOfPsid,
+    its::ieee1609dot2::base_types::ServiceSpecificPermissions,
+    its::ieee1609dot2::base_types::BitmapSsp,
+    its::ieee1609dot2::base_types::PsidSspRange,
+    its::ieee1609dot2::base_types::SequenceOfPsidSspRange,
+    its::ieee1609dot2::base_types::SspRange,
+    its::ieee1609dot2::base_types::BitmapSspRange,
+    its::ieee1609dot2::base_types::SubjectAssurance,
+    its::ieee1609dot2::base_types::CrlSeries,
+    its::ieee1609dot2::base_types::IValue,
+    its::ieee1609dot2::base_types::Hostname,
+    its::ieee1609dot2::base_types::LinkageValue,
+    its::ieee1609dot2::base_types::GroupLinkageValue,
+    its::ieee1609dot2::base_types::LaId,
+    its::ieee1609dot2::base_types::SequenceOfLinkageSeed,
+    its::ieee1609dot2::base_types::LinkageSeed,
+    its::ieee1609dot2::crl_base_types::CrlContents,
+    its::ieee1609dot2::crl_base_types::CrlPriorityInfo,
+    its::ieee1609dot2::crl_base_types::TypeSpecificCrlContents,
+    its::ieee1609dot2::crl_base_types::ToBeSignedHashIdCrl,
+    its::ieee1609dot2::crl_base_types::SequenceOfHashBasedRevocationInfo,
+    its::ieee1609dot2::crl_base_types::HashBasedRevocationInfo,
+    its::ieee1609dot2::crl_base_types::ToBeSignedLinkageValueCrl,
+    its::ieee1609dot2::crl_base_types::SequenceOfJMaxGroup,
+    its::ieee1609dot2::crl_base_types::JMaxGroup,
+    its::ieee1609dot2::crl_base_types::SequenceOfLAGroup,
+    its::ieee1609dot2::crl_base_types::LAGroup,
+    its::ieee1609dot2::crl_base_types::SequenceOfIMaxGroup,
+    its::ieee1609dot2::crl_base_types::IMaxGroup,
+    its::ieee1609dot2::crl_base_types::SequenceOfIndividualRevocation,
+    its::ieee1609dot2::crl_base_types::IndividualRevocation,
+    its::ieee1609dot2::crl_base_types::SequenceOfGroupCrlEntry,
+    its::ieee1609dot2::crl_base_types::GroupCrlEntry,
+    its::ieee1609dot2::crl_base_types::ToBeSignedLinkageValueCrlWithAlgIdentifier,
+    its::ieee1609dot2::crl_base_types::SequenceOfGroupSingleSeedCrlEntry,
+    its::ieee1609dot2::crl_base_types::GroupSingleSeedCrlEntry,
+    its::ieee1609dot2::crl_base_types::ExpansionAlgorithmIdentifier,
+    its::ieee1609dot2::Ieee1609Dot2Data,
+    its::ieee1609dot2::Ieee1609Dot2Content,
+    its::ieee1609dot2::SignedData,
+    its::ieee1609dot2::ToBeSignedData,
+    its::ieee1609dot2::SignedDataPayload,
+    its::ieee1609dot2::HashedData,
+    its::ieee1609dot2::HeaderInfo,
+    its::ieee1609dot2::MissingCrlIdentifier,
+    its::ieee1609dot2::PduFunctionalType,
+    its::ieee1609dot2::ContributedExtensionBlocks,
+    its::ieee1609dot2::Ieee1609HeaderInfoExtensions,
+    its::ieee1609dot2::Ieee1609ContributedHeaderInfoExtension,
+    its::ieee1609dot2::ContributedExtensionBlockType,
+    its::ieee1609dot2::HeaderInfoContributorId,
+    its::ieee1609dot2::SignerIdentifier,
+    its::ieee1609dot2::Countersignature,
+    its::ieee1609dot2::EncryptedData,
+    its::ieee1609dot2::RecipientInfo,
+    its::ieee1609dot2::SequenceOfRecipientInfo,
+    its::ieee1609dot2::PreSharedKeyRecipientInfo,
+    its::ieee1609dot2::SymmRecipientInfo,
+    its::ieee1609dot2::PKRecipientInfo,
+    its::ieee1609dot2::EncryptedDataEncryptionKey,
+    its::ieee1609dot2::SymmetricCiphertext,
+    its::ieee1609dot2::One28BitCcmCiphertext,
+    its::ieee1609dot2::Aes128CcmCiphertext,
+    its::ieee1609dot2::Certificate,
+    its::ieee1609dot2::TestCertificate,
+    its::ieee1609dot2::SequenceOfCertificate,
+    its::ieee1609dot2::CertificateBase,
+    its::ieee1609dot2::CertificateType,
+    its::ieee1609dot2::ImplicitCertificate,
+    its::ieee1609dot2::ExplicitCertificate,
+    its::ieee1609dot2::IssuerIdentifier,
+    its::ieee1609dot2::ToBeSignedCertificate,
+    its::ieee1609dot2::CertificateId,
+    its::ieee1609dot2::LinkageData,
+    its::ieee1609dot2::EndEntityType,
+    its::ieee1609dot2::PsidGroupPermissions,
+    its::ieee1609dot2::SequenceOfPsidGroupPermissions,
+    its::ieee1609dot2::SubjectPermissions,
+    its::ieee1609dot2::VerificationKeyIndicator,
+    its::ieee1609dot2::SequenceOfCertIssueExtensions,
+    its::ieee1609dot2::SequenceOfCertRequestExtensions,
+    its::ieee1609dot2::OperatingOrganizationId,
+    its::ieee1609dot2::OperatingOrganizationExtension,
+    its::ieee1609dot2::SequenceOfAppExtensions,
+    its::ieee1609dot2::crl::CrlPsid,
+    its::ieee1609dot2::crl::SecuredCrl,
+    its::ieee1609dot2::crl::CrlSsp,
+    its::ieee1609dot2::crl::CracaType,
+    its::ieee1609dot2::crl::PermissibleCrls,
+    its::ieee1609dot2::peer2peer::Ieee1609Dot2Peer2PeerPDU,
+    its::ieee1609dot2::peer2peer::Ieee1609Dot2Peer2PeerPduContent,
+    its::ieee1609dot2::peer2peer::CaCertP2pPDU,
+    its::ts103097::extension_module::ExtId,
+    its::ts103097::extension_module::EtsiTs103097HeaderInfoExtensions,
+    its::ts103097::extension_module::EtsiTs102941CrlRequest,
+    its::ts103097::extension_module::EtsiTs102941CtlRequest,
+    its::ts103097::EtsiTs103097Certificate,
+    its::ts103097::EtsiTs103097Data,
+    personnel::PersonnelRecord,
+    personnel::ChildInformation,
+    personnel::Name,
+    personnel::EmployeeNumber,
+    personnel::ExtensibleEmployeeNumber,
+    personnel::Date,
+    personnel::PersonnelRecordWithConstraints,
+    personnel::ExtensiblePersonnelRecord,
+    personnel::ExtensibleChildInformation,
+    personnel::Sex,
+    personnel::ChildInformationWithConstraints,
+    personnel::ExtensibleName,
+    personnel::NameWithConstraints,
+    personnel::ExtensibleDate,
+    personnel::DateWithConstraints,
+    personnel::ExtensibleNameString,
+    personnel::NameString,
+    personnel::InitialString,
+}
+
+#[cfg(test)]
+mod tests {
+    use rasn::prelude::*;
+
+    use super::*;
+
+    const CERTIFICATE: &[u8] = include_bytes!("../../standards/pkix/tests/data/letsencrypt-x3.crt");
+
+    #[test]
+    fn certificate() {
+        assert!(matches!(
+            check::<pkix::Certificate>(Codec::Der, CERTIFICATE),
+            Err(Divergence::Encode {
+                codec: Codec::Jer,
+                ..
+            })
+        ));
+        check_any::<pkix::Certificate>(Codec::Der, CERTIFICATE).unwrap();
+    }
+
+    #[test]
+    fn personnel() {
+        for codec in CODECS {
+            let data = codec
+                .encode_to_binary(&personnel::PersonnelRecordWithConstraints::default())
+                .unwrap();
+            check::<personnel::PersonnelRecordWithConstraints>(codec, &data).unwrap();
+        }
+    }
+
+    /// An integer which is encoded as the next integer.
+    #[derive(AsnType, Debug, Decode, PartialEq)]
+    #[rasn(delegate)]
+    struct Drifting(u8);
+
+    impl Encode for Drifting {
+        fn encode_with_tag_and_constraints<'b, E: Encoder<'b>>(
+            &self,
+            encoder: &mut E,
+            tag: Tag,
+            constraints: Constraints,
+            identifier: Identifier,
+        ) -> Result<(), E::Error> {
+            self.0.wrapping_add(1).encode_with_tag_and_constraints(
+                encoder,
+                tag,
+                constraints,
+                identifier,
+            )
+        }
+    }
+
+    #[test]
+    fn divergence() {
+        let data = [0x02, 0x01, 0x01];
+        assert!(matches!(
+            check::<Drifting>(Codec::Ber, &data),
+            Err(Divergence::Value {
+                codec: Codec::Ber,
+                ..
+            })
+        ));
+        assert!(matches!(
+            check::<Drifting>(Codec::Der, &data),
+            Err(Divergence::NonCanonical {
+                codec: Codec::Der,
+                ..
+            })
+        ));
+    }
+}
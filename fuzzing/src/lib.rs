@@ -1,3 +1,8 @@
+pub mod differential;
+#[path = "../../tests/personnel.rs"]
+#[allow(dead_code, unused_imports)]
+pub mod personnel;
+
 // Attempts to decode random fuzz data and if we're successful, we check
 // that the encoder can produce encoding that the is *semantically*
 // equal to the original decoded value. So we decode that value back
@@ -0,0 +1,131 @@
+use crate::config::{Config, FieldConfig, VariantConfig};
+
+/// Implements `ConstrainedArbitrary` and `Arbitrary` for a type deriving
+/// `Decode` with `#[rasn(arbitrary)]`.
+pub fn derive_impl(
+    name: &syn::Ident,
+    generics: &syn::Generics,
+    data: &syn::Data,
+    config: &Config,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let crate_root = &config.crate_root;
+
+    let body = match data {
+        syn::Data::Struct(container) => {
+            let fields = arbitrary_fields(&container.fields, config, config.delegate)?;
+            quote!(Ok(Self #fields))
+        }
+        syn::Data::Enum(syn::DataEnum { variants, .. }) if config.enumerated => {
+            let variants = variants.iter().map(|variant| &variant.ident);
+            quote!(u.choose(&[#(Self::#variants),*]).copied())
+        }
+        syn::Data::Enum(syn::DataEnum { variants, .. }) => {
+            let arms = variants
+                .iter()
+                .enumerate()
+                .map(|(i, variant)| {
+                    let ident = &variant.ident;
+                    let variant_config = VariantConfig::new(variant, generics, config, i)?;
+                    let fields = match &variant.fields {
+                        syn::Fields::Unnamed(unnamed) => {
+                            let ty = &unnamed.unnamed[0].ty;
+                            let constraints = variant_config.constraints.field_expr(ty, crate_root);
+                            quote!((<#ty as #crate_root::arbitrary::ConstrainedArbitrary>::arbitrary_with_constraints(u, #constraints)?))
+                        }
+                        fields => arbitrary_fields(fields, config, false)?,
+                    };
+
+                    Ok(quote!(#i => Self::#ident #fields,))
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+            let count = arms.len();
+
+            quote! {
+                Ok(match u.choose_index(#count)? {
+                    #(#arms)*
+                    _ => unreachable!(),
+                })
+            }
+        }
+        syn::Data::Union(_) => {
+            return Err(syn::Error::new(
+                name.span(),
+                "Union types are not supported.",
+            ))
+        }
+    };
+
+    // Only delegates are generated with the constraints they're given.
+    let constraints = if config.delegate {
+        quote!(constraints)
+    } else {
+        quote!(_)
+    };
+    let mut generics = generics.clone();
+    for param in generics.type_params_mut() {
+        param
+            .bounds
+            .push(syn::parse_quote!(#crate_root::arbitrary::ConstrainedArbitrary<'arbitrary>));
+    }
+    let (_, ty_generics, where_clause) = generics.split_for_impl();
+    let mut arbitrary_generics = generics.clone();
+    arbitrary_generics
+        .params
+        .insert(0, syn::parse_quote!('arbitrary));
+    let (impl_generics, _, _) = arbitrary_generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #crate_root::arbitrary::ConstrainedArbitrary<'arbitrary> for #name #ty_generics #where_clause {
+            fn arbitrary_with_constraints(
+                u: &mut #crate_root::arbitrary::Unstructured<'arbitrary>,
+                #constraints: #crate_root::types::Constraints,
+            ) -> #crate_root::arbitrary::Result<Self> {
+                #body
+            }
+        }
+
+        impl #impl_generics #crate_root::arbitrary::Arbitrary<'arbitrary> for #name #ty_generics #where_clause {
+            fn arbitrary(
+                u: &mut #crate_root::arbitrary::Unstructured<'arbitrary>,
+            ) -> #crate_root::arbitrary::Result<Self> {
+                <Self as #crate_root::arbitrary::ConstrainedArbitrary>::arbitrary_constrained(u)
+            }
+        }
+    })
+}
+
+/// Generates the fields of a struct or variant, with the first field of a
+/// delegate using the constraints of the container.
+fn arbitrary_fields(
+    fields: &syn::Fields,
+    config: &Config,
+    delegate: bool,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let crate_root = &config.crate_root;
+    let values = fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            let ty = &field.ty;
+            let constraints = if delegate && i == 0 {
+                quote!(constraints)
+            } else {
+                FieldConfig::new(field, config, i)?
+                    .constraints
+                    .field_expr(ty, crate_root)
+            };
+            let value = quote!(<#ty as #crate_root::arbitrary::ConstrainedArbitrary>::arbitrary_with_constraints(u, #constraints)?);
+
+            Ok(match &field.ident {
+                Some(ident) => quote!(#ident: #value),
+                None => value,
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    Ok(match fields {
+        syn::Fields::Named(_) => quote!({ #(#values),* }),
+        syn::Fields::Unnamed(_) => quote!(( #(#values),* )),
+        syn::Fields::Unit => quote!(),
+    })
+}
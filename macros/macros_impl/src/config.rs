@@ -1,8 +1,12 @@
-use crate::{ext::TypeExt, tag::Tag};
+use crate::{
+    element_set::{ElementSet, Ranges},
+    ext::TypeExt,
+    tag::Tag,
+};
 use proc_macro2::Span;
 use quote::ToTokens;
-use std::ops::Deref;
-use syn::spanned::Spanned;
+use std::{collections::BTreeSet, ops::Deref};
+use syn::{ext::IdentExt, spanned::Spanned};
 use syn::{parenthesized, Ident, LitStr, Path, Token, Type, UnOp};
 
 #[derive(Clone, Debug, Default)]
@@ -53,40 +57,45 @@ impl Constraints {
         })
     }
 
+    /// The constraints of a field or variant of type `ty`, as used when
+    /// encoding it.
+    pub fn field_expr(&self, ty: &syn::Type, crate_root: &syn::Path) -> proc_macro2::TokenStream {
+        let constraints = self
+            .const_expr(crate_root)
+            .unwrap_or_else(|| quote!(#crate_root::types::Constraints::default()));
+
+        // A `const` item, unlike an inline `const` block, lets the constraints
+        // borrow the sets of their elements for `'static`.
+        quote!(<#ty as #crate_root::AsnType>::CONSTRAINTS.intersect({
+            const CONSTRAINTS: #crate_root::types::Constraints = #constraints;
+            CONSTRAINTS
+        }))
+    }
+
     fn size_def(&self, crate_root: &syn::Path) -> Option<proc_macro2::TokenStream> {
         self.size.as_ref().map(|value| {
             let extensible = value.extensible.is_some();
-            let constraint = match value.constraint {
-                Value::Range(Some(min), Some(max)) => {
-                    if min > max {
-                        return syn::Error::new(
-                            Span::call_site(),
-                            "Minimum size constraint must be less than or equal to maximum size constraint.",
-                        )
-                        .to_compile_error();
-                    }
-                    quote!(#crate_root::types::constraints::Bounded::const_new(#min as usize, #max as usize))
-                }
-                Value::Range(Some(min), None) => {
-                    quote!(#crate_root::types::constraints::Bounded::start_from(#min as usize))
-                }
-                Value::Range(None, Some(max)) => {
-                    quote!(#crate_root::types::constraints::Bounded::up_to(#max as usize))
-                }
-                Value::Range(None, None) => {
-                    quote!(#crate_root::types::constraints::Bounded::const_new(usize::MIN, usize::MAX))
-                }
-                Value::Single(length) => {
-                    quote!(#crate_root::types::constraints::Bounded::single_value(#length as usize))
+            if let Value::Range(Some(min), Some(max)) = value.constraint {
+                if min > max {
+                    return syn::Error::new(
+                        Span::call_site(),
+                        "Minimum size constraint must be less than or equal to maximum size constraint.",
+                    )
+                    .to_compile_error();
                 }
-            };
+            }
+            let constraint = value.constraint.bounded(crate_root, &quote!(usize));
+            let set = value.set.as_ref().map(|set| {
+                let set = set.iter().map(|value| value.bounded(crate_root, &quote!(usize)));
+                quote!(.with_set(&[#(#set),*]))
+            });
 
             quote!(
                 #crate_root::types::Constraint::Size(
                     #crate_root::types::constraints::Extensible::new(
                         #crate_root::types::constraints::Size::new(
                             #constraint
-                        )
+                        )#set
                     ).set_extensible(#extensible)
                 )
             )
@@ -94,93 +103,32 @@ impl Constraints {
     }
 
     fn size_attr(&self) -> Option<proc_macro2::TokenStream> {
-        self.size.as_ref().map(|value| {
-
-            let extensible = value.extensible.is_some().then_some(quote!(extensible));
-            let constraint = match value.constraint {
-                Value::Range(Some(min), Some(max)) => {
-                    if min > max {
-                        return syn::Error::new(
-                            Span::call_site(),
-                            "Minimum size constraint must be less than or equal to maximum size constraint.",
-                        )
-                        .to_compile_error();
-                    }
-                    let string = quote!(#min..=#max).to_string();
-                    quote!(#string)
-                }
-                Value::Range(Some(min), None) => {
-                    let string = quote!(#min..).to_string();
-                    quote!(#string)
-                }
-                Value::Range(None, Some(max)) => {
-                    let string = quote!(..=#max).to_string();
-                    quote!(#string)
-                }
-                Value::Range(None, None) => {
-                    quote!("..")
-                }
-                Value::Single(length) => {
-                    quote!(#length)
-                }
-            };
-
-            quote!(size(#constraint, #extensible))
-        })
+        let meta = self.size.as_ref()?.meta.as_ref()?;
+        Some(quote!(size(#meta)))
     }
 
     fn value_attr(&self) -> Option<proc_macro2::TokenStream> {
-        self.value.as_ref().map(|value| {
-            let extensible = value.extensible.is_some().then_some(quote!(extensible));
-            let constraint = match value.constraint {
-                Value::Range(Some(min), Some(max)) => {
-                    quote!(#min..#max)
-                }
-                Value::Range(Some(min), None) => {
-                    quote!(#min..)
-                }
-                Value::Range(None, Some(max)) => {
-                    quote!(..#max)
-                }
-                Value::Range(None, None) => {
-                    quote!(..)
-                }
-                Value::Single(value) => {
-                    quote!(#value)
-                }
-            };
-
-            quote!(value(#constraint, #extensible))
-        })
+        let meta = self.value.as_ref()?.meta.as_ref()?;
+        Some(quote!(value(#meta)))
     }
 
     fn value_def(&self, crate_root: &syn::Path) -> Option<proc_macro2::TokenStream> {
         self.value.as_ref().map(|value| {
             let extensible = value.extensible.is_some();
-            let constraint = match value.constraint {
-                Value::Range(Some(min), Some(max)) => {
-                    quote!(#crate_root::types::constraints::Bounded::const_new(#min as i128, #max as i128))
-                }
-                Value::Range(Some(min), None) => {
-                    quote!(#crate_root::types::constraints::Bounded::start_from(#min as i128))
-                }
-                Value::Range(None, Some(max)) => {
-                    quote!(#crate_root::types::constraints::Bounded::up_to(#max as i128))
-                }
-                Value::Range(None, None) => {
-                    quote!(#crate_root::types::constraints::Bounded::const_new(i128::MIN, i128::MAX))
-                }
-                Value::Single(length) => {
-                    quote!(#crate_root::types::constraints::Bounded::single_value(#length as i128))
-                }
-            };
+            let constraint = value.constraint.bounded(crate_root, &quote!(i128));
+            let set = value.set.as_ref().map(|set| {
+                let set = set
+                    .iter()
+                    .map(|value| value.bounded(crate_root, &quote!(i128)));
+                quote!(.with_set(&[#(#set),*]))
+            });
 
             quote!(
                 #crate_root::types::Constraint::Value(
                     #crate_root::types::constraints::Extensible::new(
                         #crate_root::types::constraints::Value::new(
                             #constraint
-                        )
+                        )#set
                     ).set_extensible(#extensible)
                 )
             )
@@ -191,14 +139,18 @@ impl Constraints {
     fn from_def(&self, crate_root: &syn::Path) -> Option<proc_macro2::TokenStream> {
         self.from.as_ref().map(|value| {
             let extensible = value.extensible.is_some();
-            let StringValue(set) = &value.constraint;
+            let StringValue(alphabet) = &value.constraint;
+            let set = value.set.as_ref().map(|set| {
+                let set = set.iter().flat_map(|StringValue(set)| set);
+                quote!(.with_set(&[#(#set,)*]))
+            });
 
             quote!(
                 #crate_root::types::Constraint::PermittedAlphabet(
                     #crate_root::types::constraints::Extensible::new(
                         #crate_root::types::constraints::PermittedAlphabet::new(
-                            &[#(#set,)*]
-                        )
+                            &[#(#alphabet,)*]
+                        )#set
                     ).set_extensible(#extensible)
                 )
             )
@@ -207,12 +159,8 @@ impl Constraints {
 
     #[allow(clippy::wrong_self_convention)]
     fn from_attr(&self) -> Option<proc_macro2::TokenStream> {
-        self.from.as_ref().map(|value| {
-            let extensible = value.extensible.is_some().then_some(quote!(extensible));
-            let StringValue(set) = &value.constraint;
-
-            quote!(from(#(#set,)*, #extensible))
-        })
+        let meta = self.from.as_ref()?.meta.as_ref()?;
+        Some(quote!(from(#meta)))
     }
 
     fn extensible_def(&self, crate_root: &syn::Path) -> Option<proc_macro2::TokenStream> {
@@ -220,7 +168,7 @@ impl Constraints {
             .then(|| quote!(#crate_root::types::Constraint::Extensible))
     }
 
-    fn has_constraints(&self) -> bool {
+    pub fn has_constraints(&self) -> bool {
         self.extensible || self.from.is_some() || self.size.is_some() || self.value.is_some()
     }
 }
@@ -234,6 +182,9 @@ pub struct Config {
     pub set: bool,
     pub automatic_tags: bool,
     pub delegate: bool,
+    pub serde: bool,
+    pub arbitrary: bool,
+    pub inner_subtype: bool,
     pub tag: Option<Tag>,
     pub constraints: Constraints,
 }
@@ -251,6 +202,9 @@ impl Config {
         let mut size = None;
         let mut value = None;
         let mut delegate = false;
+        let mut serde = false;
+        let mut arbitrary = false;
+        let mut inner_subtype = false;
         let mut extensible = false;
 
         for attr in &input.attrs {
@@ -282,6 +236,12 @@ impl Config {
                         tag = Some(Tag::from_meta(&meta)?);
                     } else if path.is_ident("delegate") {
                         delegate = true;
+                    } else if path.is_ident("serde") {
+                        serde = true;
+                    } else if path.is_ident("arbitrary") {
+                        arbitrary = true;
+                    } else if path.is_ident("inner_subtype") {
+                        inner_subtype = true;
                     } else if path.is_ident("from") {
                         from = Some(StringValue::from_meta(&meta)?);
                     } else if path.is_ident("size") {
@@ -363,6 +323,9 @@ impl Config {
             choice,
             delegate,
             enumerated,
+            serde,
+            arbitrary,
+            inner_subtype,
             set,
             tag,
             identifier,
@@ -413,6 +376,14 @@ impl Config {
     }
 }
 
+/// The variable holding the decoded value of `member` in a struct.
+pub(crate) fn binding(member: &syn::Member) -> Ident {
+    match member {
+        syn::Member::Named(ident) => format_ident!("__rasn_field_{}", ident.unraw()),
+        syn::Member::Unnamed(index) => format_ident!("__rasn_field_{}", index.index),
+    }
+}
+
 pub(crate) fn is_option_type(ty: &syn::Type) -> bool {
     match ty {
         syn::Type::Path(path) => path
@@ -556,6 +527,33 @@ impl<'config> VariantConfig<'config> {
             })
     }
 
+    /// Returns the discriminants of the root and extension variants of an
+    /// `ENUMERATED` type. Root variants without an explicit discriminant are
+    /// numbered by their position, while extension additions without one
+    /// follow the largest discriminant before them, so that they never share
+    /// a value with another variant.
+    pub fn enumerated_discriminants(
+        root: &[&Self],
+        extended: &[&Self],
+    ) -> (Vec<isize>, Vec<isize>) {
+        let root: Vec<_> = root
+            .iter()
+            .enumerate()
+            .map(|(i, variant)| variant.discriminant().unwrap_or(i as isize))
+            .collect();
+        let mut next = root.iter().max().map_or(0, |max| max + 1);
+        let extended = extended
+            .iter()
+            .map(|variant| {
+                let discriminant = variant.discriminant().unwrap_or(next);
+                next = next.max(discriminant + 1);
+                discriminant
+            })
+            .collect();
+
+        (root, extended)
+    }
+
     pub fn has_explicit_tag(&self) -> bool {
         self.tag.as_ref().is_some_and(|tag| tag.is_explicit())
     }
@@ -728,7 +726,7 @@ impl<'config> VariantConfig<'config> {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct FieldConfig<'a> {
     pub field: &'a syn::Field,
     pub container_config: &'a Config,
@@ -739,6 +737,9 @@ pub struct FieldConfig<'a> {
     pub extension_addition_group: bool,
     pub constraints: Constraints,
     pub context: usize,
+    /// The sibling field identifying the information object of this open
+    /// type field.
+    pub table: Option<syn::Member>,
 }
 
 pub enum FieldType {
@@ -762,6 +763,7 @@ impl<'a> FieldConfig<'a> {
         let mut extensible = false;
         let mut extension_addition = false;
         let mut extension_addition_group = false;
+        let mut table = None;
         /*if !field.attrs.is_empty() {
             panic!("{:?}", field)
         }*/
@@ -802,6 +804,10 @@ impl<'a> FieldConfig<'a> {
                     extension_addition = true;
                 } else if path.is_ident("extension_addition_group") {
                     extension_addition_group = true;
+                } else if path.is_ident("table") {
+                    let content;
+                    parenthesized!(content in meta.input);
+                    table = Some(content.parse()?);
                 } else {
                     return Err(meta.error(format!(
                         "unknown field tag {:?}",
@@ -831,6 +837,7 @@ impl<'a> FieldConfig<'a> {
                 value,
             },
             context,
+            table,
         })
     }
 
@@ -881,13 +888,23 @@ impl<'a> FieldConfig<'a> {
             .constraints
             .const_expr(&self.container_config.crate_root)
             .unwrap_or_else(|| quote!(#crate_root::types::Constraints::default()));
+        // An extension addition is encoded with the given constraints as is,
+        // so they start from the constraints of the type within the `Option`.
+        let constraint_ty = if self.extension_addition {
+            map_to_inner_type(&ty).unwrap_or(&ty)
+        } else {
+            &ty
+        };
         let constraint_def = if has_generics {
             quote! {
-                let #constraint_name: #crate_root::types::Constraints  = <#ty as #crate_root::AsnType>::CONSTRAINTS.intersect(const {#constraints});
+                let #constraint_name: #crate_root::types::Constraints  = <#constraint_ty as #crate_root::AsnType>::CONSTRAINTS.intersect({
+                    const CONSTRAINTS: #crate_root::types::Constraints = #constraints;
+                    CONSTRAINTS
+                });
             }
         } else {
             quote! {
-                const #constraint_name : #crate_root::types::Constraints = <#ty as #crate_root::AsnType>::CONSTRAINTS.intersect(
+                const #constraint_name : #crate_root::types::Constraints = <#constraint_ty as #crate_root::AsnType>::CONSTRAINTS.intersect(
                     #constraints
                 );
             }
@@ -992,19 +1009,84 @@ impl<'a> FieldConfig<'a> {
             }
         };
 
+        let check_object = self.table.as_ref().map(|id| {
+            let object_id = if self.is_option_type() {
+                quote!(#this #field.as_ref().and_then(#crate_root::types::InformationObjectSet::identifier))
+            } else {
+                quote!(#crate_root::types::InformationObjectSet::identifier(&#this #field))
+            };
+            quote! {
+                if #object_id.is_some_and(|object_id| object_id != #this #id) {
+                    return Err(#crate_root::error::EncodeError::information_object_mismatch(&#this #id, encoder.codec()).into());
+                }
+            }
+        });
+
         Ok(quote! {
+            #check_object
             #encode
         })
     }
 
-    pub fn decode_field_def(
+    /// The variable holding the decoded value of the field, for fields to
+    /// refer to earlier fields.
+    pub fn binding(&self) -> Ident {
+        binding(&self.member())
+    }
+
+    pub fn member(&self) -> syn::Member {
+        self.field.ident.clone().map_or_else(
+            || syn::Member::Unnamed(syn::Index::from(self.context)),
+            syn::Member::Named,
+        )
+    }
+
+    /// Decodes an open type field as `Any`, and then as the value of the
+    /// information object identified by `id`.
+    fn decode_open_type(
         &self,
         name: &syn::Ident,
         type_params: &[Ident],
+        id: &syn::Member,
     ) -> syn::Result<proc_macro2::TokenStream> {
-        let lhs = self.field.ident.as_ref().map(|i| quote!(#i :));
-        let decode_op = self.decode(name, type_params)?;
-        Ok(quote!(#lhs #decode_op))
+        let crate_root = &self.container_config.crate_root;
+        if self.default.is_some() || self.extension_addition_group {
+            return Err(syn::Error::new(
+                self.field.span(),
+                "`table` fields can't be `default` or `extension_addition_group`",
+            ));
+        }
+
+        let mut field = self.field.clone();
+        field.ty = if self.is_option_type() {
+            syn::parse_quote!(Option<#crate_root::types::Any>)
+        } else {
+            syn::parse_quote!(#crate_root::types::Any)
+        };
+        let decode_any = FieldConfig {
+            field: &field,
+            table: None,
+            ..self.clone()
+        }
+        .decode(name, type_params)?;
+
+        let ty = map_to_inner_type(&self.field.ty).unwrap_or(&self.field.ty);
+        let ident = format!("{}.{}", name, self.member().to_token_stream());
+        let id = binding(id);
+        let decode_value = quote!(<#ty as #crate_root::types::InformationObjectSet>::decode_value(codec, &#id, &value));
+        let decode_value = if self.is_option_type() {
+            quote!(value.map(|value| #decode_value).transpose())
+        } else {
+            decode_value
+        };
+
+        let any_ty = &field.ty;
+
+        Ok(quote!({
+            let value: #any_ty = #decode_any;
+            let codec = decoder.codec();
+            #decode_value.map_err(|error| #crate_root::de::Error::field_error(#ident, error, codec))?
+        }))
     }
 
     pub fn decode(
@@ -1013,6 +1095,9 @@ impl<'a> FieldConfig<'a> {
         type_params: &[Ident],
     ) -> syn::Result<proc_macro2::TokenStream> {
         let crate_root = &self.container_config.crate_root;
+        if let Some(id) = &self.table {
+            return self.decode_open_type(name, type_params, id);
+        }
         let ty = &self.field.ty;
         let ident = format!(
             "{}.{}",
@@ -1044,7 +1129,10 @@ impl<'a> FieldConfig<'a> {
         let constraints = self.constraints.const_expr(crate_root);
         let constraint_def = if has_generics {
             quote! {
-                let #constraint_name: #crate_root::types::Constraints  = <#ty as #crate_root::AsnType>::CONSTRAINTS.intersect(const {#constraints});
+                let #constraint_name: #crate_root::types::Constraints  = <#ty as #crate_root::AsnType>::CONSTRAINTS.intersect({
+                    const CONSTRAINTS: #crate_root::types::Constraints = #constraints;
+                    CONSTRAINTS
+                });
             }
         } else {
             quote! {
@@ -1352,15 +1440,24 @@ impl<'a> FieldConfig<'a> {
 
 #[derive(Clone, Debug)]
 pub struct Constraint<T> {
+    /// The effective constraint, as visible to PER.
     pub constraint: T,
+    /// The exact permitted elements, as the union of these, if they differ
+    /// from the effective constraint.
+    pub set: Option<Vec<T>>,
     pub extensible: Option<Vec<T>>,
+    /// The arguments of the attribute, to apply the constraint to generated
+    /// types.
+    pub meta: Option<proc_macro2::TokenStream>,
 }
 
 impl<T> From<T> for Constraint<T> {
     fn from(constraint: T) -> Self {
         Self {
             constraint,
+            set: None,
             extensible: None,
+            meta: None,
         }
     }
 }
@@ -1370,20 +1467,13 @@ pub struct StringValue(pub Vec<u32>);
 
 impl StringValue {
     fn from_meta(item: &syn::meta::ParseNestedMeta) -> syn::Result<Constraint<StringValue>> {
-        let mut values = Vec::new();
-        let mut extensible: Option<_> = None;
-
-        enum StringRange {
-            Single(u32),
-            Range(u32, u32),
-        }
-
-        fn parse_character(string: &str) -> Option<u32> {
-            string.chars().map(u32::from).next()
-        }
+        let mut root: Option<ElementSet<BTreeSet<u32>>> = None;
+        let mut extensible: Option<ElementSet<BTreeSet<u32>>> = None;
+        let mut is_extensible = false;
 
         let content;
         parenthesized!(content in item.input);
+        let meta = content.fork().parse()?;
         while !content.is_empty() {
             let (span, string) = if content.peek(syn::LitStr) {
                 let str: syn::LitStr = content.parse()?;
@@ -1396,64 +1486,79 @@ impl StringValue {
                 return Err(content.error(format!("Unsupported meta item: {content:?}")));
             };
             if string == "extensible" {
-                extensible = Some(Vec::new());
-                skip_comma(&content);
-                continue;
-            }
-
-            if string.len() == 1 {
-                values.push(parse_character(&string).map(StringRange::Single).unwrap());
+                is_extensible = true;
                 skip_comma(&content);
                 continue;
             }
 
-            let Some((start, mut end)) = string.split_once("..") else {
-                return Err(syn::Error::new(span, format!("unknown format: {string}, must be a single character or range of characters (`..`, `..=`)")));
+            let set = match ElementSet::parse(span, &string, false, |element| {
+                Self::characters(span, element)
+            })? {
+                Some(set) => set,
+                None => ElementSet::new(Self::characters(span, &string)?),
             };
-
-            let Some(start) = parse_character(start) else {
-                return Err(syn::Error::new(
-                    span,
-                    format!("start of range was an invalid character: {start}"),
-                ));
+            let values = if is_extensible {
+                &mut extensible
+            } else {
+                &mut root
             };
+            *values = Some(match values.take() {
+                Some(values) => values.union(&set),
+                None => set,
+            });
+            skip_comma(&content);
+        }
+        let root = root.unwrap_or_else(|| ElementSet::new(BTreeSet::new()));
 
-            let is_inclusive = end.starts_with('=');
-            if is_inclusive {
-                end = &end[1..];
-            }
+        Ok(Constraint {
+            set: root
+                .exact_set()
+                .map(|set| vec![Self(set.iter().copied().collect())]),
+            constraint: Self(root.effective.into_iter().collect()),
+            extensible: is_extensible.then(|| {
+                extensible
+                    .map(|set| Self(set.effective.into_iter().collect()))
+                    .into_iter()
+                    .collect()
+            }),
+            meta: Some(meta),
+        })
+    }
 
-            let Some(end) = parse_character(end) else {
-                return Err(syn::Error::new(
-                    span,
-                    format!("end of range was an invalid character: {end}"),
-                ));
-            };
+    /// Parses a single character, or a range of characters (`..`, `..=`).
+    fn characters(span: Span, string: &str) -> syn::Result<BTreeSet<u32>> {
+        fn parse_character(string: &str) -> Option<u32> {
+            string.chars().map(u32::from).next()
+        }
 
-            if let Some(extensible_values) = extensible.as_mut() {
-                extensible_values.push(StringRange::Range(start, end + is_inclusive as u32));
-            } else {
-                values.push(StringRange::Range(start, end + is_inclusive as u32));
-            }
-            skip_comma(&content);
+        if string.chars().count() == 1 {
+            return Ok(parse_character(string).into_iter().collect());
         }
-        let into_flat_set = |constraints: Vec<_>| {
-            let mut set = constraints
-                .iter()
-                .flat_map(|from| match from {
-                    StringRange::Single(value) => vec![*value],
-                    StringRange::Range(start, end) => (*start..*end).collect::<Vec<u32>>(),
-                })
-                .collect::<Vec<u32>>();
-            set.sort();
-            set.dedup();
-            set
+
+        let Some((start, mut end)) = string.split_once("..") else {
+            return Err(syn::Error::new(span, format!("unknown format: {string}, must be a single character or range of characters (`..`, `..=`)")));
         };
 
-        Ok(Constraint {
-            constraint: Self((into_flat_set)(values)),
-            extensible: extensible.map(|values| vec![Self((into_flat_set)(values))]),
-        })
+        let Some(start) = parse_character(start) else {
+            return Err(syn::Error::new(
+                span,
+                format!("start of range was an invalid character: {start}"),
+            ));
+        };
+
+        let is_inclusive = end.starts_with('=');
+        if is_inclusive {
+            end = &end[1..];
+        }
+
+        let Some(end) = parse_character(end) else {
+            return Err(syn::Error::new(
+                span,
+                format!("end of range was an invalid character: {end}"),
+            ));
+        };
+
+        Ok((start..end + is_inclusive as u32).collect())
     }
 }
 
@@ -1466,29 +1571,16 @@ pub enum Value {
 impl Value {
     fn from_meta(item: &syn::meta::ParseNestedMeta) -> syn::Result<Constraint<Value>> {
         let mut extensible = None;
-        let mut constraint = None;
-
-        // Attempts to parse either size or value constraint value.
-        // These constraints are i128 types - proc macros might add i128 suffix so we need to remove that.
-        // Also check if the value is a valid number in general, noting the underscore separator as well.
-        fn parse_character(string: &str) -> Option<i128> {
-            let filtered: String = string
-                .chars()
-                .filter(|&c| !c.is_whitespace() && c != '_')
-                .collect();
-            // Remove the "i128" suffix if it exists.
-            if filtered.ends_with("i128") {
-                filtered[..filtered.len() - "i128".len()].parse().ok()
-            } else {
-                filtered.parse().ok()
-            }
-        }
+        // The constraint, and the set of its elements when there's more than
+        // one element.
+        let mut constraint: Option<(Value, ElementSet<Ranges>)> = None;
 
         let content;
         parenthesized!(content in item.input);
         if content.is_empty() {
             return Err(content.error("Missing content inside `value()`"));
         }
+        let meta = content.fork().parse()?;
         while !content.is_empty() {
             let (span, string) = if content.peek(syn::LitStr) {
                 let str: syn::LitStr = content.parse()?;
@@ -1498,9 +1590,7 @@ impl Value {
                 (ident.span(), ident.to_string())
             } else if content.peek(syn::LitInt) {
                 let int: syn::LitInt = content.parse()?;
-                constraint = Some(int.base10_parse().map(Value::Single)?);
-                skip_comma(&content);
-                continue;
+                (int.span(), int.base10_digits().to_owned())
             } else {
                 return Err(content.error(format!("Value Unsupported meta item: {content:?}")));
             };
@@ -1511,53 +1601,141 @@ impl Value {
                 continue;
             }
 
-            let value = if let Some(number) = parse_character(&string) {
-                Value::Single(number)
-            } else {
-                let Some((start, mut end)) = string.split_once("..") else {
-                    return Err(syn::Error::new(span, format!("unknown format: {string}, must be a single value or range of values (`..`, `..=`)")));
-                };
-
-                let start_parsed = parse_character(start);
-                if start_parsed.is_none() && !start.is_empty() {
-                    return Err(syn::Error::new(
-                        span,
-                        format!("start of the range constraint was an invalid value: {start:?}"),
-                    ));
-                }
-                let is_inclusive = end.starts_with('=');
-                if is_inclusive {
-                    end = &end[1..];
-                }
-
-                let end_parsed = parse_character(end).map(|end| end - (!is_inclusive) as i128);
-                if end_parsed.is_none() && !end.is_empty() {
-                    return Err(syn::Error::new(
-                        span,
-                        format!("end of the range constraint was an invalid value: {end:?}"),
-                    ));
+            let (value, set) = match ElementSet::parse(span, &string, true, |element| {
+                Self::parse(span, element).map(|value| value.ranges())
+            })? {
+                Some(set) => (Self::from_ranges(&set.effective), set),
+                None => {
+                    let value = Self::parse(span, &string)?;
+                    let set = ElementSet::new(value.ranges());
+                    (value, set)
                 }
-                Value::Range(start_parsed, end_parsed)
             };
 
             if let Some(extensible_values) = extensible.as_mut() {
                 extensible_values.push(value)
-            } else if constraint.is_none() {
-                constraint = Some(value);
+            } else if let Some((_, previous)) = constraint.take() {
+                let set = previous.union(&set);
+                constraint = Some((Self::from_ranges(&set.effective), set));
             } else {
-                return Err(syn::Error::new(
-                    span,
-                    "Multiple non-extensible value constraints are not permitted.",
-                ));
+                constraint = Some((value, set));
             }
             skip_comma(&content);
         }
+        let Some((constraint, set)) = constraint else {
+            return Err(content.error("Missing value or range inside `value()`"));
+        };
 
         Ok(Constraint {
-            constraint: constraint.unwrap(),
+            constraint,
+            set: set
+                .exact_set()
+                .map(|Ranges(ranges)| ranges.iter().map(Self::from_range).collect()),
             extensible,
+            meta: Some(meta),
         })
     }
+
+    /// Parses either a single value or a range of values (`..`, `..=`).
+    fn parse(span: Span, string: &str) -> syn::Result<Self> {
+        // Attempts to parse either size or value constraint value.
+        // These constraints are i128 types - proc macros might add i128 suffix so we need to remove that.
+        // Also check if the value is a valid number in general, noting the underscore separator as well.
+        fn parse_character(string: &str) -> Option<i128> {
+            let filtered: String = string
+                .chars()
+                .filter(|&c| !c.is_whitespace() && c != '_')
+                .collect();
+            // Remove the "i128" suffix if it exists.
+            if filtered.ends_with("i128") {
+                filtered[..filtered.len() - "i128".len()].parse().ok()
+            } else {
+                filtered.parse().ok()
+            }
+        }
+
+        if let Some(number) = parse_character(string) {
+            return Ok(Value::Single(number));
+        }
+
+        let Some((start, mut end)) = string.split_once("..") else {
+            return Err(syn::Error::new(span, format!("unknown format: {string}, must be a single value or range of values (`..`, `..=`)")));
+        };
+
+        let start_parsed = parse_character(start);
+        if start_parsed.is_none() && !start.trim().is_empty() {
+            return Err(syn::Error::new(
+                span,
+                format!("start of the range constraint was an invalid value: {start:?}"),
+            ));
+        }
+        let is_inclusive = end.starts_with('=');
+        if is_inclusive {
+            end = &end[1..];
+        }
+
+        let end_parsed = parse_character(end).map(|end| end - (!is_inclusive) as i128);
+        if end_parsed.is_none() && !end.trim().is_empty() {
+            return Err(syn::Error::new(
+                span,
+                format!("end of the range constraint was an invalid value: {end:?}"),
+            ));
+        }
+        Ok(Value::Range(start_parsed, end_parsed))
+    }
+
+    fn ranges(&self) -> Ranges {
+        match *self {
+            Self::Single(value) => Ranges::new(value, value),
+            Self::Range(start, end) => {
+                Ranges::new(start.unwrap_or(i128::MIN), end.unwrap_or(i128::MAX))
+            }
+        }
+    }
+
+    fn from_range(&(start, end): &(i128, i128)) -> Self {
+        if start == end {
+            Self::Single(start)
+        } else {
+            Self::Range(
+                (start != i128::MIN).then_some(start),
+                (end != i128::MAX).then_some(end),
+            )
+        }
+    }
+
+    /// The smallest range containing `ranges`.
+    fn from_ranges(Ranges(ranges): &Ranges) -> Self {
+        match (ranges.first(), ranges.last()) {
+            (Some(first), Some(last)) => Self::from_range(&(first.0, last.1)),
+            _ => Self::Range(None, None),
+        }
+    }
+
+    /// The bounds of the constraint on a `ty` integer.
+    fn bounded(
+        &self,
+        crate_root: &syn::Path,
+        ty: &proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        match *self {
+            Value::Range(Some(min), Some(max)) => {
+                quote!(#crate_root::types::constraints::Bounded::const_new(#min as #ty, #max as #ty))
+            }
+            Value::Range(Some(min), None) => {
+                quote!(#crate_root::types::constraints::Bounded::start_from(#min as #ty))
+            }
+            Value::Range(None, Some(max)) => {
+                quote!(#crate_root::types::constraints::Bounded::up_to(#max as #ty))
+            }
+            Value::Range(None, None) => {
+                quote!(#crate_root::types::constraints::Bounded::const_new(#ty::MIN, #ty::MAX))
+            }
+            Value::Single(value) => {
+                quote!(#crate_root::types::constraints::Bounded::single_value(#value as #ty))
+            }
+        }
+    }
 }
 
 fn skip_comma(content: &syn::parse::ParseBuffer) {
@@ -1,5 +1,5 @@
 use quote::ToTokens;
-use syn::Fields;
+use syn::{spanned::Spanned, Fields};
 
 use crate::config::{map_to_inner_type, Config, FieldConfig};
 
@@ -63,6 +63,12 @@ pub fn derive_struct_impl(
             }
         }
     } else if config.set {
+        if let Some(config) = field_configs.iter().find(|config| config.table.is_some()) {
+            return Err(syn::Error::new(
+                config.field.span(),
+                "`table` fields aren't supported in a `set`",
+            ));
+        }
         if field_configs.is_empty() {
             return Err(syn::Error::new(
                 name.span(),
@@ -195,6 +201,7 @@ pub fn derive_struct_impl(
         let mut all_fields_optional_or_default = true;
         let mut count_root_fields: usize = 0;
         let mut count_extended_fields: usize = 0;
+        let mut decode_fields = vec![];
 
         for field_config in &field_configs {
             if !field_config.is_option_or_default_type() {
@@ -217,11 +224,28 @@ pub fn derive_struct_impl(
                     }
                 })
                 .collect();
-            list.push(field_config.decode_field_def(name, &type_params)?);
+            if let Some(id) = &field_config.table {
+                if !field_configs[..field_config.context]
+                    .iter()
+                    .any(|config| config.member() == *id)
+                {
+                    return Err(syn::Error::new(
+                        id.span(),
+                        "`table` must refer to an earlier field",
+                    ));
+                }
+            }
+            let binding = field_config.binding();
+            let decode = field_config.decode(name, &type_params)?;
+            decode_fields.push(quote!(let #binding = #decode;));
+            list.push(binding);
         }
 
         let fields = match container.fields {
-            Fields::Named(_) => quote!({ #(#list),* }),
+            Fields::Named(_) => {
+                let names = field_configs.iter().map(|config| &config.field.ident);
+                quote!({ #(#names: #list),* })
+            }
             Fields::Unnamed(_) => quote!(( #(#list),* )),
             Fields::Unit => quote!(),
         };
@@ -257,6 +281,7 @@ pub fn derive_struct_impl(
 
         quote! {
             decoder.decode_sequence::<#count_root_fields, #count_extended_fields, _, _, _>(tag, #initializer_fn, |decoder| {
+                #(#decode_fields)*
                 Ok(Self #fields)
             })
         }
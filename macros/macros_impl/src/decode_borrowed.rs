@@ -0,0 +1,189 @@
+use syn::Fields;
+
+use crate::config::{map_to_inner_type, Config, FieldConfig};
+
+pub fn derive_struct_impl(
+    name: &syn::Ident,
+    mut generics: syn::Generics,
+    container: syn::DataStruct,
+    config: &Config,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let crate_root = &config.crate_root;
+
+    let lifetime = {
+        let mut lifetimes = generics.lifetimes();
+        match (lifetimes.next(), lifetimes.next()) {
+            (Some(param), None) => param.lifetime.clone(),
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &generics,
+                    "`DecodeBorrowed` requires exactly one lifetime parameter, which is used for the borrowed input",
+                ))
+            }
+        }
+    };
+
+    if config.set || config.choice || config.enumerated {
+        return Err(syn::Error::new(
+            name.span(),
+            "`DecodeBorrowed` can only be derived for `SEQUENCE` and delegate types",
+        ));
+    }
+
+    for param in generics.type_params_mut() {
+        param
+            .bounds
+            .push(syn::parse_quote!(#crate_root::de::DecodeBorrowed<#lifetime>));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let field_configs = container
+        .fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| FieldConfig::new(field, config, i))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let decode_impl = if config.delegate {
+        let ty = &container.fields.iter().next().unwrap().ty;
+        let phantom_data = (1..field_configs.len())
+            .map(|_| quote!(core::marker::PhantomData))
+            .collect::<Vec<_>>();
+        let map_quote = quote!(|data| Self(data, #(#phantom_data),*));
+
+        if config.tag.as_ref().is_some_and(|tag| tag.is_explicit()) {
+            quote! {
+                decoder.decode_explicit_prefix_borrowed::<#ty>(tag).map(#map_quote)
+            }
+        } else {
+            quote! {
+                match tag {
+                    #crate_root::types::Tag::EOC => {
+                        <#ty as #crate_root::de::DecodeBorrowed<#lifetime>>::decode_borrowed(decoder).map(#map_quote)
+                    }
+                    _ => {
+                        <#ty as #crate_root::de::DecodeBorrowed<#lifetime>>::decode_borrowed_with_tag_and_constraints(
+                            decoder,
+                            tag,
+                            constraints
+                        ).map(#map_quote)
+                    }
+                }
+            }
+        }
+    } else {
+        if config.tag.as_ref().is_some_and(|tag| tag.is_explicit()) {
+            return Err(syn::Error::new(
+                name.span(),
+                "`DecodeBorrowed` does not support explicitly tagged `SEQUENCE` types, tag the field containing it instead",
+            ));
+        }
+
+        let mut all_fields_optional_or_default = true;
+        let mut fields = Vec::new();
+
+        for field_config in &field_configs {
+            if field_config.extension_addition || field_config.extension_addition_group {
+                return Err(syn::Error::new_spanned(
+                    field_config.field,
+                    "`DecodeBorrowed` does not support extension additions",
+                ));
+            }
+            if !field_config.is_option_or_default_type() {
+                all_fields_optional_or_default = false;
+            }
+            let lhs = field_config.field.ident.as_ref().map(|i| quote!(#i :));
+            let decode_op = decode_field(name, field_config, &lifetime);
+            fields.push(quote!(#lhs #decode_op));
+        }
+
+        let count_root_fields = field_configs.len();
+        let fields = match container.fields {
+            Fields::Named(_) => quote!({ #(#fields),* }),
+            Fields::Unnamed(_) => quote!(( #(#fields),* )),
+            Fields::Unit => quote!(),
+        };
+
+        let initializer_fn = if all_fields_optional_or_default {
+            let init_fields = field_configs.iter().map(|config| {
+                let default_fn = config.default_fn().unwrap_or(quote!(<_>::default));
+                let name = config.field.ident.as_ref().map(|name| quote!(#name :));
+                quote!(#name #default_fn ())
+            });
+            let init = match container.fields {
+                Fields::Named(_) => quote!({ #(#init_fields),* }),
+                Fields::Unnamed(_) => quote!(( #(#init_fields),* )),
+                Fields::Unit => quote!(),
+            };
+            quote!(Some(|| Self #init))
+        } else {
+            quote!(None::<fn() -> Self>)
+        };
+
+        quote! {
+            decoder.decode_sequence_borrowed::<#count_root_fields, 0, _, _, _>(tag, #initializer_fn, |decoder| {
+                Ok(Self #fields)
+            })
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics #crate_root::de::DecodeBorrowed<#lifetime> for #name #ty_generics #where_clause {
+            fn decode_borrowed_with_tag_and_constraints<_DECODER: #crate_root::de::BorrowDecoder<#lifetime>>(
+                decoder: &mut _DECODER,
+                tag: #crate_root::types::Tag,
+                constraints: #crate_root::types::Constraints,
+            ) -> core::result::Result<Self, _DECODER::Error> {
+                #decode_impl
+            }
+        }
+    })
+}
+
+fn decode_field(
+    name: &syn::Ident,
+    config: &FieldConfig,
+    lifetime: &syn::Lifetime,
+) -> proc_macro2::TokenStream {
+    let crate_root = &config.container_config.crate_root;
+    let ty = &config.field.ty;
+    let inner_ty = map_to_inner_type(ty).unwrap_or(ty);
+    let ident = format!(
+        "{}.{}",
+        name,
+        config
+            .field
+            .ident
+            .as_ref()
+            .map(|ident| ident.to_string())
+            .unwrap_or_else(|| config.context.to_string())
+    );
+    let or_else = quote!(.map_err(|error| #crate_root::de::Error::field_error(#ident, error.into(), decoder.codec()))?);
+    let tag = config.tag();
+    let is_tagged = config.tag.is_some() || config.container_config.automatic_tags;
+    let constraints = match config.constraints.const_expr(crate_root) {
+        Some(constraints) => {
+            quote!(<#inner_ty as #crate_root::AsnType>::CONSTRAINTS.intersect(#constraints))
+        }
+        None => quote!(<#inner_ty as #crate_root::AsnType>::CONSTRAINTS),
+    };
+    let unwrap_default = config
+        .default_fn()
+        .map(|default_fn| quote!(.unwrap_or_else(#default_fn)));
+
+    if config.tag.as_ref().is_some_and(|tag| tag.is_explicit()) {
+        if config.is_option_type() {
+            quote!(decoder.decode_optional_with_explicit_prefix_borrowed::<#inner_ty>(#tag) #or_else)
+        } else if config.is_default_type() {
+            quote!(decoder.decode_optional_with_explicit_prefix_borrowed::<#ty>(#tag) #or_else #unwrap_default)
+        } else {
+            quote!(decoder.decode_explicit_prefix_borrowed::<#ty>(#tag) #or_else)
+        }
+    } else if config.is_option_or_default_type() {
+        quote!(decoder.decode_optional_with_tag_and_constraints_borrowed::<#inner_ty>(#tag, #constraints) #or_else #unwrap_default)
+    } else if is_tagged || config.constraints.has_constraints() {
+        quote!(<#ty as #crate_root::de::DecodeBorrowed<#lifetime>>::decode_borrowed_with_tag_and_constraints(decoder, #tag, #constraints) #or_else)
+    } else {
+        quote!(<#ty as #crate_root::de::DecodeBorrowed<#lifetime>>::decode_borrowed(decoder) #or_else)
+    }
+}
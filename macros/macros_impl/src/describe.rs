@@ -0,0 +1,367 @@
+use crate::{
+    config::{Config, FieldConfig, FieldType, VariantConfig},
+    ext::TypeExt,
+};
+
+pub fn derive_struct_impl(
+    name: &syn::Ident,
+    generics: syn::Generics,
+    container: syn::DataStruct,
+    config: &Config,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let crate_root = &config.crate_root;
+
+    let descriptor = if config.delegate {
+        let mut ty = container
+            .fields
+            .iter()
+            .next()
+            .ok_or_else(|| {
+                syn::Error::new_spanned(
+                    &container.fields,
+                    "Delegate is only supported for newtype pattern",
+                )
+            })?
+            .ty
+            .clone();
+        ty.strip_lifetimes();
+
+        describe_self(
+            config,
+            quote!(<#ty as #crate_root::types::Describe>::DESCRIPTOR.kind),
+            quote!(<#ty as #crate_root::AsnType>::TAG),
+        )
+    } else if container.fields == syn::Fields::Unit {
+        describe_self(
+            config,
+            quote!(#crate_root::types::descriptor::TypeKind::Null),
+            quote!(#crate_root::types::Tag::NULL),
+        )
+    } else {
+        let field_configs = container
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(i, f)| FieldConfig::new(f, config, i))
+            .collect::<Result<Vec<_>, _>>()?;
+        let (fields, extended_fields) = describe_fields(&field_configs, config);
+
+        let (kind, natural_tag) = if config.set {
+            (quote!(Set), quote!(#crate_root::types::Tag::SET))
+        } else {
+            (quote!(Sequence), quote!(#crate_root::types::Tag::SEQUENCE))
+        };
+
+        describe_self(
+            config,
+            quote! {
+                #crate_root::types::descriptor::TypeKind::#kind {
+                    fields: #fields,
+                    extended_fields: #extended_fields,
+                }
+            },
+            natural_tag,
+        )
+    };
+
+    Ok(impl_describe(name, generics, config, descriptor))
+}
+
+pub fn derive_enum_impl(
+    name: &syn::Ident,
+    generics: syn::Generics,
+    variants: &syn::punctuated::Punctuated<syn::Variant, syn::Token![,]>,
+    config: &Config,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let crate_root = &config.crate_root;
+    let variant_configs = variants
+        .iter()
+        .enumerate()
+        .map(|(i, v)| VariantConfig::new(v, &generics, config, i))
+        .collect::<Result<Vec<_>, _>>()?;
+    let (root_variants, extended_variants): (Vec<_>, Vec<_>) = variant_configs
+        .iter()
+        .partition(|config| !config.extension_addition);
+
+    let descriptor = if config.enumerated {
+        let (root_discriminants, extended_discriminants) =
+            VariantConfig::enumerated_discriminants(&root_variants, &extended_variants);
+        let describe_variants = |variants: &[&VariantConfig], discriminants: Vec<isize>| {
+            let variants = variants
+                .iter()
+                .zip(discriminants)
+                .map(|(variant, discriminant)| {
+                    let name = variant_name(variant);
+                    quote! {
+                        #crate_root::types::descriptor::EnumeratedVariant {
+                            name: #name,
+                            discriminant: #discriminant,
+                        }
+                    }
+                });
+            quote!(const { &[#(#variants),*] })
+        };
+        let variants = describe_variants(&root_variants, root_discriminants);
+        let extended_variants = extensions(
+            config,
+            describe_variants(&extended_variants, extended_discriminants),
+        );
+
+        describe_self(
+            config,
+            quote! {
+                #crate_root::types::descriptor::TypeKind::Enumerated {
+                    variants: #variants,
+                    extended_variants: #extended_variants,
+                }
+            },
+            quote!(#crate_root::types::Tag::ENUMERATED),
+        )
+    } else if config.choice {
+        let describe_variants = |variants: &[&VariantConfig]| {
+            let variants = variants
+                .iter()
+                .map(|variant| describe_variant(variant, config))
+                .collect::<syn::Result<Vec<_>>>()?;
+            Ok::<_, syn::Error>(quote!(const { &[#(#variants),*] }))
+        };
+        let variants = describe_variants(&root_variants)?;
+        let extended_variants = extensions(config, describe_variants(&extended_variants)?);
+
+        describe_self(
+            config,
+            quote! {
+                #crate_root::types::descriptor::TypeKind::Choice {
+                    variants: #variants,
+                    extended_variants: #extended_variants,
+                }
+            },
+            quote!(#crate_root::types::Tag::EOC),
+        )
+    } else {
+        return Err(syn::Error::new(
+            name.span(),
+            "`Describe` can only be derived for `CHOICE` and `ENUMERATED` enums.",
+        ));
+    };
+
+    Ok(impl_describe(name, generics, config, descriptor))
+}
+
+fn impl_describe(
+    name: &syn::Ident,
+    mut generics: syn::Generics,
+    config: &Config,
+    descriptor: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let crate_root = &config.crate_root;
+    for param in generics.type_params_mut() {
+        param
+            .bounds
+            .push(syn::parse_quote!(#crate_root::types::Describe));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics #crate_root::types::Describe for #name #ty_generics #where_clause {
+            const DESCRIPTOR: #crate_root::types::TypeDescriptor<'static> = #descriptor;
+        }
+    }
+}
+
+/// The descriptor of the deriving type itself, with `natural_tag` being the
+/// tag of `kind` inside of an explicit prefix.
+fn describe_self(
+    config: &Config,
+    kind: proc_macro2::TokenStream,
+    natural_tag: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let crate_root = &config.crate_root;
+    let descriptor = |tag: proc_macro2::TokenStream| {
+        quote! {
+            #crate_root::types::TypeDescriptor {
+                identifier: <Self as #crate_root::AsnType>::IDENTIFIER.0,
+                tag: #tag,
+                constraints: #crate_root::types::descriptor::DescribedConstraints::new(
+                    <Self as #crate_root::AsnType>::CONSTRAINTS,
+                ),
+                kind: #kind,
+            }
+        }
+    };
+
+    if config.has_explicit_tag() {
+        let inner = descriptor(natural_tag);
+        quote! {
+            #crate_root::types::TypeDescriptor::new(
+                <Self as #crate_root::AsnType>::IDENTIFIER.0,
+                <Self as #crate_root::AsnType>::TAG,
+                #crate_root::types::descriptor::TypeKind::Explicit {
+                    inner: const { &#inner },
+                },
+            )
+        }
+    } else {
+        descriptor(quote!(<Self as #crate_root::AsnType>::TAG))
+    }
+}
+
+/// Returns the root and extension field lists of a `SEQUENCE` or `SET`.
+fn describe_fields(
+    field_configs: &[FieldConfig],
+    config: &Config,
+) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    let (root_fields, extended_fields): (Vec<_>, Vec<_>) = field_configs
+        .iter()
+        .partition(|field| field.is_not_extension());
+    let describe = |fields: Vec<&FieldConfig>| {
+        let fields = fields.into_iter().map(describe_field);
+        quote!(const { &[#(#fields),*] })
+    };
+
+    (
+        describe(root_fields),
+        extensions(config, describe(extended_fields)),
+    )
+}
+
+fn describe_field(field: &FieldConfig) -> proc_macro2::TokenStream {
+    let crate_root = &field.container_config.crate_root;
+    let name = field
+        .identifier
+        .clone()
+        .or(field
+            .field
+            .ident
+            .as_ref()
+            .map(|id| syn::LitStr::new(&id.to_string(), proc_macro2::Span::call_site())))
+        .unwrap_or(syn::LitStr::new("", proc_macro2::Span::call_site()));
+    let presence = quote::format_ident!(
+        "{}",
+        match field.field_type() {
+            FieldType::Required => "Required",
+            FieldType::Optional => "Optional",
+            FieldType::Default => "Default",
+        }
+    );
+
+    let mut ty = field.field.ty.clone();
+    ty.strip_lifetimes();
+    let mut descriptor = quote!(<#ty as #crate_root::types::Describe>::DESCRIPTOR);
+    if let Some(constraints) = field.constraints.const_expr(crate_root) {
+        descriptor = quote!(#descriptor.with_constraints(#constraints));
+    }
+    if field.tag.is_some() || field.container_config.automatic_tags {
+        let is_explicit = field.tag.as_ref().is_some_and(|tag| tag.is_explicit());
+        descriptor = prefix(crate_root, &ty, descriptor, field.tag(), is_explicit);
+    }
+
+    quote! {
+        #crate_root::types::descriptor::FieldDescriptor {
+            name: #name,
+            presence: #crate_root::types::fields::FieldPresence::#presence,
+            ty: const { &#descriptor },
+        }
+    }
+}
+
+fn describe_variant(
+    variant: &VariantConfig,
+    config: &Config,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let crate_root = &config.crate_root;
+    let name = variant_name(variant);
+
+    let (ty, mut descriptor): (syn::Type, _) = match &variant.variant.fields {
+        syn::Fields::Unit => (
+            syn::parse_quote!(()),
+            quote!(<() as #crate_root::types::Describe>::DESCRIPTOR),
+        ),
+        syn::Fields::Unnamed(_) => {
+            let mut ty = variant.variant.fields.iter().next().unwrap().ty.clone();
+            ty.strip_lifetimes();
+            let mut descriptor = quote!(<#ty as #crate_root::types::Describe>::DESCRIPTOR);
+            if let Some(constraints) = variant.constraints.const_expr(crate_root) {
+                descriptor = quote!(#descriptor.with_constraints(#constraints));
+            }
+            (ty, descriptor)
+        }
+        syn::Fields::Named(_) => {
+            let field_configs = variant
+                .variant
+                .fields
+                .iter()
+                .enumerate()
+                .map(|(i, f)| FieldConfig::new(f, config, i))
+                .collect::<Result<Vec<_>, _>>()?;
+            let fields = field_configs.iter().map(describe_field);
+
+            (
+                syn::parse_quote!(()),
+                quote! {
+                    #crate_root::types::TypeDescriptor::new(
+                        None,
+                        #crate_root::types::Tag::SEQUENCE,
+                        #crate_root::types::descriptor::TypeKind::Sequence {
+                            fields: const { &[#(#fields),*] },
+                            extended_fields: None,
+                        },
+                    )
+                },
+            )
+        }
+    };
+
+    if variant.tag.is_some() || config.automatic_tags {
+        let tag = variant.tag()?.to_tokens(crate_root);
+        descriptor = prefix(crate_root, &ty, descriptor, tag, variant.has_explicit_tag());
+    }
+
+    Ok(quote! {
+        #crate_root::types::descriptor::VariantDescriptor {
+            name: #name,
+            ty: const { &#descriptor },
+        }
+    })
+}
+
+/// Applies a field or variant tag to `descriptor`.
+fn prefix(
+    crate_root: &syn::Path,
+    ty: &syn::Type,
+    descriptor: proc_macro2::TokenStream,
+    tag: proc_macro2::TokenStream,
+    is_explicit: bool,
+) -> proc_macro2::TokenStream {
+    if is_explicit {
+        quote! {
+            #crate_root::types::TypeDescriptor::new(
+                <#ty as #crate_root::AsnType>::IDENTIFIER.0,
+                #tag,
+                #crate_root::types::descriptor::TypeKind::Explicit {
+                    inner: const { &#descriptor },
+                },
+            )
+        }
+    } else {
+        quote!(#descriptor.with_tag(#tag))
+    }
+}
+
+fn extensions(config: &Config, list: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    if config.constraints.extensible {
+        quote!(Some(#list))
+    } else {
+        quote!(None)
+    }
+}
+
+fn variant_name(variant: &VariantConfig) -> syn::LitStr {
+    variant.identifier.clone().unwrap_or_else(|| {
+        syn::LitStr::new(
+            &variant.variant.ident.to_string(),
+            proc_macro2::Span::call_site(),
+        )
+    })
+}
@@ -0,0 +1,301 @@
+//! Constraint expressions combining elements with unions, intersections and
+//! `EXCEPT`, e.g. `1..=5 | 10..=20` or `a..=z EXCEPT q`.
+
+use std::collections::BTreeSet;
+
+/// A set operator, in order of increasing precedence.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Operator {
+    Union,
+    Intersection,
+    Except,
+}
+
+#[derive(Debug)]
+enum Token {
+    Operator(Operator),
+    Open,
+    Close,
+    Element(String),
+}
+
+/// The elements of a set, which can be combined as ASN.1 element sets.
+pub trait Elements: Clone + PartialEq + Sized {
+    fn union(&self, other: &Self) -> Self;
+    fn intersection(&self, other: &Self) -> Self;
+    fn difference(&self, other: &Self) -> Self;
+    fn is_empty(&self) -> bool;
+
+    /// The union of two effective constraints, which for integers is the
+    /// smallest range containing both.
+    fn effective_union(&self, other: &Self) -> Self {
+        self.union(other)
+    }
+}
+
+/// An element set, as both its effective constraint and its exact elements.
+///
+/// The effective constraint is the one visible to PER (X.691 §8.3), which
+/// ignores the `EXCEPT` clause of constraints.
+#[derive(Clone, Debug)]
+pub struct ElementSet<E> {
+    pub effective: E,
+    pub exact: E,
+}
+
+impl<E: Elements> ElementSet<E> {
+    pub fn new(elements: E) -> Self {
+        Self {
+            effective: elements.clone(),
+            exact: elements,
+        }
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self {
+            effective: self.effective.effective_union(&other.effective),
+            exact: self.exact.union(&other.exact),
+        }
+    }
+
+    fn apply(self, operator: Operator, other: Self) -> Self {
+        match operator {
+            Operator::Union => self.union(&other),
+            Operator::Intersection => Self {
+                effective: self.effective.intersection(&other.effective),
+                exact: self.exact.intersection(&other.exact),
+            },
+            Operator::Except => Self {
+                exact: self.exact.difference(&other.exact),
+                effective: self.effective,
+            },
+        }
+    }
+
+    /// Returns the exact elements, if they differ from the effective
+    /// constraint.
+    pub fn exact_set(&self) -> Option<&E> {
+        (self.exact != self.effective).then_some(&self.exact)
+    }
+
+    /// Parses an expression of `string`, whose elements are parsed with
+    /// `element`. Operators are `|` (or `UNION`), `^` (or `INTERSECTION`) and
+    /// `EXCEPT`, which can be grouped with parentheses. Returns `None` if
+    /// `string` only has a single element.
+    ///
+    /// With `split_symbols`, the symbol operators and parentheses don't need
+    /// to be separated from the elements with whitespace.
+    pub fn parse(
+        span: proc_macro2::Span,
+        string: &str,
+        split_symbols: bool,
+        element: impl Fn(&str) -> syn::Result<E>,
+    ) -> syn::Result<Option<Self>> {
+        let tokens = tokenize(string, split_symbols);
+        if tokens.len() <= 1 {
+            return Ok(None);
+        }
+
+        let mut parser = Parser {
+            tokens: tokens.into_iter().peekable(),
+            span,
+            element,
+        };
+        let set = parser.set(Operator::Union)?;
+        if parser.tokens.next().is_some() {
+            return Err(syn::Error::new(span, "expected an operator in constraint"));
+        }
+        if set.effective.is_empty() || set.exact.is_empty() {
+            return Err(syn::Error::new(
+                span,
+                "constraint doesn't permit any values",
+            ));
+        }
+
+        Ok(Some(set))
+    }
+}
+
+fn tokenize(string: &str, split_symbols: bool) -> Vec<Token> {
+    let symbol = |word: &str| match word {
+        "|" => Some(Token::Operator(Operator::Union)),
+        "^" => Some(Token::Operator(Operator::Intersection)),
+        "(" => Some(Token::Open),
+        ")" => Some(Token::Close),
+        _ => None,
+    };
+    let mut tokens = Vec::new();
+    for word in string.split_whitespace() {
+        match word {
+            "UNION" => tokens.push(Token::Operator(Operator::Union)),
+            "INTERSECTION" => tokens.push(Token::Operator(Operator::Intersection)),
+            "EXCEPT" => tokens.push(Token::Operator(Operator::Except)),
+            _ if split_symbols => {
+                let mut start = 0;
+                for (i, _) in word.match_indices(['|', '^', '(', ')']) {
+                    if start < i {
+                        tokens.push(Token::Element(word[start..i].to_owned()));
+                    }
+                    tokens.extend(symbol(&word[i..=i]));
+                    start = i + 1;
+                }
+                if start < word.len() {
+                    tokens.push(Token::Element(word[start..].to_owned()));
+                }
+            }
+            _ => tokens.push(symbol(word).unwrap_or_else(|| Token::Element(word.to_owned()))),
+        }
+    }
+
+    // Elements can contain whitespace, e.g. `1 ..= 5`.
+    let mut merged = Vec::<Token>::new();
+    for token in tokens {
+        match (merged.last_mut(), token) {
+            (Some(Token::Element(last)), Token::Element(element)) if split_symbols => {
+                last.push_str(&element);
+            }
+            (_, token) => merged.push(token),
+        }
+    }
+    merged
+}
+
+struct Parser<I: Iterator<Item = Token>, F> {
+    tokens: std::iter::Peekable<I>,
+    span: proc_macro2::Span,
+    element: F,
+}
+
+impl<E: Elements, I: Iterator<Item = Token>, F: Fn(&str) -> syn::Result<E>> Parser<I, F> {
+    /// Parses operands joined with operators of at least `precedence`.
+    fn set(&mut self, precedence: Operator) -> syn::Result<ElementSet<E>> {
+        let mut set = self.operand()?;
+        while let Some(&Token::Operator(operator)) = self.tokens.peek() {
+            if operator < precedence {
+                break;
+            }
+            self.tokens.next();
+            let rhs = match operator {
+                // `EXCEPT` applies to a single operand on either side.
+                Operator::Except => self.operand()?,
+                operator => self.set(next(operator))?,
+            };
+            set = set.apply(operator, rhs);
+        }
+
+        Ok(set)
+    }
+
+    fn operand(&mut self) -> syn::Result<ElementSet<E>> {
+        match self.tokens.next() {
+            Some(Token::Open) => {
+                let set = self.set(Operator::Union)?;
+                match self.tokens.next() {
+                    Some(Token::Close) => Ok(set),
+                    _ => Err(syn::Error::new(self.span, "missing `)` in constraint")),
+                }
+            }
+            Some(Token::Element(element)) => (self.element)(&element).map(ElementSet::new),
+            _ => Err(syn::Error::new(
+                self.span,
+                "expected a value, range or `(` in constraint",
+            )),
+        }
+    }
+}
+
+/// The operator binding more tightly than `operator`.
+fn next(operator: Operator) -> Operator {
+    match operator {
+        Operator::Union => Operator::Intersection,
+        Operator::Intersection | Operator::Except => Operator::Except,
+    }
+}
+
+/// Disjoint and sorted inclusive ranges of integers, where unbounded ends
+/// are `i128::MIN` and `i128::MAX`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ranges(pub Vec<(i128, i128)>);
+
+impl Ranges {
+    pub fn new(start: i128, end: i128) -> Self {
+        Self(if start <= end {
+            vec![(start, end)]
+        } else {
+            Vec::new()
+        })
+    }
+
+    fn normalized(mut ranges: Vec<(i128, i128)>) -> Self {
+        ranges.sort_unstable();
+        let mut merged: Vec<(i128, i128)> = Vec::with_capacity(ranges.len());
+        for (start, end) in ranges {
+            match merged.last_mut() {
+                Some(last) if start <= last.1.saturating_add(1) => last.1 = last.1.max(end),
+                _ => merged.push((start, end)),
+            }
+        }
+        Self(merged)
+    }
+}
+
+impl Elements for Ranges {
+    fn union(&self, other: &Self) -> Self {
+        Self::normalized(self.0.iter().chain(&other.0).copied().collect())
+    }
+
+    fn intersection(&self, other: &Self) -> Self {
+        Self::normalized(
+            self.0
+                .iter()
+                .flat_map(|a| other.0.iter().map(move |b| (a.0.max(b.0), a.1.min(b.1))))
+                .filter(|(start, end)| start <= end)
+                .collect(),
+        )
+    }
+
+    fn difference(&self, other: &Self) -> Self {
+        let mut ranges = self.0.clone();
+        for &(start, end) in &other.0 {
+            ranges = ranges
+                .into_iter()
+                .flat_map(|(a, b)| {
+                    let below = (a < start).then(|| (a, b.min(start - 1)));
+                    let above = (b > end).then(|| (a.max(end + 1), b));
+                    below.into_iter().chain(above)
+                })
+                .collect();
+        }
+        Self(ranges)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn effective_union(&self, other: &Self) -> Self {
+        let ranges = self.union(other).0;
+        match (ranges.first(), ranges.last()) {
+            (Some(first), Some(last)) => Self(vec![(first.0, last.1)]),
+            _ => Self(ranges),
+        }
+    }
+}
+
+impl Elements for BTreeSet<u32> {
+    fn union(&self, other: &Self) -> Self {
+        self | other
+    }
+
+    fn intersection(&self, other: &Self) -> Self {
+        self & other
+    }
+
+    fn difference(&self, other: &Self) -> Self {
+        self - other
+    }
+
+    fn is_empty(&self) -> bool {
+        BTreeSet::is_empty(self)
+    }
+}
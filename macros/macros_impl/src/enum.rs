@@ -136,13 +136,13 @@ impl Enum<'_> {
             let (variants, extended_variants): (Vec<_>, Vec<_>) = variant_configs.iter()
                 .partition(|config| !config.extension_addition);
 
-            let discriminants = variants.iter().enumerate().map(|(i, config)| {
-                let discriminant = config.discriminant().unwrap_or(i as isize);
+            let (discriminants, extended_discriminants) =
+                VariantConfig::enumerated_discriminants(&variants, &extended_variants);
+            let discriminants = variants.iter().zip(discriminants).map(|(config, discriminant)| {
                 let variant = &config.variant.ident;
                 quote!((Self::#variant, #discriminant))
             });
-            let extended_discriminants = extended_variants.iter().enumerate().map(|(i, config)| {
-                let discriminant = config.discriminant().unwrap_or(i as isize);
+            let extended_discriminants = extended_variants.iter().zip(extended_discriminants).map(|(config, discriminant)| {
                 let variant = &config.variant.ident;
                 quote!((Self::#variant, #discriminant))
             });
@@ -1,13 +1,19 @@
 #[macro_use]
 extern crate quote;
 
+mod arbitrary;
 mod asn_type;
 mod config;
 mod decode;
+mod decode_borrowed;
+mod describe;
+mod element_set;
 mod encode;
 mod r#enum;
 mod ext;
+mod object_set;
 mod tag;
+mod validate;
 
 use crate::ext::GenericsExt;
 use config::Config;
@@ -18,16 +24,23 @@ const CRATE_NAME: &str = "rasn";
 pub fn decode_derive_inner(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
     let config = Config::from_attributes(&input)?;
     let name = &input.ident;
+    let arbitrary = config
+        .arbitrary
+        .then(|| arbitrary::derive_impl(name, &input.generics, &input.data, &config))
+        .transpose()?;
     let mut generics = input.generics;
     let crate_root = &config.crate_root;
     generics.add_trait_bounds(crate_root, quote::format_ident!("Decode"));
+    let deserialize = config
+        .serde
+        .then(|| deserialize_impl(name, &generics, crate_root));
 
-    match input.data {
+    let decode = match input.data {
         // Unit structs are treated as ASN.1 NULL values.
         syn::Data::Struct(DataStruct {
             fields: syn::Fields::Unit,
             ..
-        }) => Ok(quote! {
+        }) => quote! {
             impl #crate_root::Decode for #name {
                 fn decode_with_tag_and_constraints<D: #crate_root::Decoder>(
                     decoder: &mut D,
@@ -37,18 +50,37 @@ pub fn decode_derive_inner(input: DeriveInput) -> syn::Result<proc_macro2::Token
                     decoder.decode_null(tag).map(|_| #name)
                 }
             }
-        }),
-        syn::Data::Struct(v) => decode::derive_struct_impl(name, generics, v, &config),
+        },
+        syn::Data::Struct(v) => decode::derive_struct_impl(name, generics, v, &config)?,
         syn::Data::Enum(syn::DataEnum { variants, .. }) => r#enum::Enum {
             name,
             generics: &generics,
             variants: &variants,
             config: &config,
         }
-        .impl_decode(),
+        .impl_decode()?,
+        _ => {
+            return Err(syn::Error::new(
+                name.span(),
+                "Union types are not supported.",
+            ))
+        }
+    };
+
+    Ok(quote!(#decode #deserialize #arbitrary))
+}
+
+pub fn decode_borrowed_derive_inner(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let config = Config::from_attributes(&input)?;
+    let name = &input.ident;
+
+    match input.data {
+        syn::Data::Struct(v) => {
+            decode_borrowed::derive_struct_impl(name, input.generics, v, &config)
+        }
         _ => Err(syn::Error::new(
             name.span(),
-            "Union types are not supported.",
+            "`DecodeBorrowed` can only be derived for structs.",
         )),
     }
 }
@@ -59,8 +91,11 @@ pub fn encode_derive_inner(input: DeriveInput) -> syn::Result<proc_macro2::Token
     let mut generics = input.generics;
     let crate_root = &config.crate_root;
     generics.add_trait_bounds(crate_root, quote::format_ident!("Encode"));
+    let serialize = config
+        .serde
+        .then(|| serialize_impl(name, &generics, crate_root));
 
-    Ok(match input.data {
+    let encode = match input.data {
         // Unit structs are treated as ASN.1 NULL values.
         syn::Data::Struct(DataStruct {
             fields: syn::Fields::Unit,
@@ -92,7 +127,9 @@ pub fn encode_derive_inner(input: DeriveInput) -> syn::Result<proc_macro2::Token
                 "Union types are not supported.",
             ))
         }
-    })
+    };
+
+    Ok(quote!(#encode #serialize))
 }
 
 pub fn asn_type_derive_inner(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
@@ -125,3 +162,75 @@ pub fn asn_type_derive_inner(input: DeriveInput) -> syn::Result<proc_macro2::Tok
         }
     })
 }
+
+pub fn describe_derive_inner(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let config = Config::from_attributes(&input)?;
+    let name = &input.ident;
+
+    match input.data {
+        syn::Data::Struct(v) => describe::derive_struct_impl(name, input.generics, v, &config),
+        syn::Data::Enum(syn::DataEnum { variants, .. }) => {
+            describe::derive_enum_impl(name, input.generics, &variants, &config)
+        }
+        _ => Err(syn::Error::new(
+            name.span(),
+            "Union types are not supported.",
+        )),
+    }
+}
+
+pub fn information_object_set_derive_inner(
+    input: DeriveInput,
+) -> syn::Result<proc_macro2::TokenStream> {
+    object_set::derive_impl(&input)
+}
+
+pub fn validate_derive_inner(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let config = Config::from_attributes(&input)?;
+
+    validate::derive_impl(&input.ident, input.generics, &input.data, &config)
+}
+
+/// Implements `Serialize` for a type deriving `Encode` with `#[rasn(serde)]`,
+/// using its JER representation.
+fn serialize_impl(
+    name: &syn::Ident,
+    generics: &syn::Generics,
+    crate_root: &syn::Path,
+) -> proc_macro2::TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics #crate_root::serde::Serialize for #name #ty_generics #where_clause {
+            fn serialize<S: #crate_root::serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> core::result::Result<S::Ok, S::Error> {
+                #crate_root::serde::serialize(self, serializer)
+            }
+        }
+    }
+}
+
+/// Implements `Deserialize` for a type deriving `Decode` with
+/// `#[rasn(serde)]`, using its JER representation.
+fn deserialize_impl(
+    name: &syn::Ident,
+    generics: &syn::Generics,
+    crate_root: &syn::Path,
+) -> proc_macro2::TokenStream {
+    let mut de_generics = generics.clone();
+    de_generics.params.insert(0, syn::parse_quote!('de));
+    let (impl_generics, _, _) = de_generics.split_for_impl();
+    let (_, ty_generics, where_clause) = generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics #crate_root::serde::Deserialize<'de> for #name #ty_generics #where_clause {
+            fn deserialize<D: #crate_root::serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> core::result::Result<Self, D::Error> {
+                #crate_root::serde::deserialize(deserializer)
+            }
+        }
+    }
+}
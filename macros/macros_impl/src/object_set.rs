@@ -0,0 +1,181 @@
+use quote::ToTokens;
+use syn::{spanned::Spanned, LitStr, Path};
+
+use crate::ext::GenericsExt;
+
+/// An information object, as a variant of the object set.
+struct Object<'a> {
+    variant: &'a syn::Variant,
+    /// The identifier of the object, or `None` for the variant holding
+    /// unknown objects.
+    id: Option<syn::Expr>,
+}
+
+pub fn derive_impl(input: &syn::DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let name = &input.ident;
+    let mut crate_root = None;
+    let mut id_type = None;
+
+    for attr in &input.attrs {
+        if !attr.path().is_ident(crate::CRATE_NAME) {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            let path = &meta.path;
+            if path.is_ident("crate_root") {
+                let s: LitStr = meta.value()?.parse()?;
+                crate_root = Some(s.parse::<Path>()?);
+            } else if path.is_ident("id_type") {
+                id_type = Some(meta.value()?.parse::<syn::Type>()?);
+            } else {
+                return Err(meta.error(format!(
+                    "unknown input provided: {}",
+                    path.to_token_stream()
+                )));
+            }
+            Ok(())
+        })?;
+    }
+
+    let crate_root = crate_root.unwrap_or_else(|| {
+        LitStr::new(crate::CRATE_NAME, proc_macro2::Span::call_site())
+            .parse()
+            .unwrap()
+    });
+    let id_type = id_type.ok_or_else(|| {
+        syn::Error::new(
+            name.span(),
+            "information object sets require the type of their identifiers with `#[rasn(id_type = T)]`",
+        )
+    })?;
+    let syn::Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new(
+            name.span(),
+            "`InformationObjectSet` can only be derived for enums.",
+        ));
+    };
+
+    let objects = data
+        .variants
+        .iter()
+        .map(|variant| {
+            if !matches!(&variant.fields, syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1)
+            {
+                return Err(syn::Error::new(
+                    variant.span(),
+                    "information objects must be tuple variants with a single field",
+                ));
+            }
+            let mut id = None;
+            for attr in &variant.attrs {
+                if !attr.path().is_ident(crate::CRATE_NAME) {
+                    continue;
+                }
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("id") {
+                        id = Some(meta.value()?.parse::<syn::Expr>()?);
+                        Ok(())
+                    } else {
+                        Err(meta.error(format!(
+                            "unknown input provided: {}",
+                            meta.path.to_token_stream()
+                        )))
+                    }
+                })?;
+            }
+            Ok(Object { variant, id })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let mut unknown = objects.iter().filter(|object| object.id.is_none());
+    let unknown_variant = unknown.next().map(|object| &object.variant.ident);
+    if let Some(object) = unknown.next() {
+        return Err(syn::Error::new(
+            object.variant.span(),
+            "only one variant can hold unknown objects, add `#[rasn(id = ...)]` to the others",
+        ));
+    }
+
+    let (identifier_arms, decode_ifs, encode_arms): (Vec<_>, Vec<_>, Vec<_>) =
+        itertools::multiunzip(objects.iter().map(|object| {
+            let variant = &object.variant.ident;
+            match &object.id {
+                Some(id) => (
+                    quote!(Self::#variant(_) => Some(<#id_type>::from(#id)),),
+                    quote! {
+                        if *identifier == <#id_type>::from(#id) {
+                            return codec.decode_from_binary(value.as_bytes()).map(Self::#variant);
+                        }
+                    },
+                    quote!(Self::#variant(value) => codec.encode_to_binary(value).map(|contents| #crate_root::types::Any::with_codec(codec, contents)),),
+                ),
+                None => (
+                    quote!(Self::#variant(_) => None,),
+                    quote!(),
+                    quote!(Self::#variant(value) => Ok(value.clone()),),
+                ),
+            }
+        }));
+    let decode_unknown = match unknown_variant {
+        Some(variant) => quote!(Ok(Self::#variant(value.clone()))),
+        None => quote! {
+            Err(#crate_root::error::DecodeError::unknown_information_object(identifier, codec))
+        },
+    };
+
+    let mut generics = input.generics.clone();
+    generics.add_trait_bounds(&crate_root, quote::format_ident!("Decode"));
+    generics.add_trait_bounds(&crate_root, quote::format_ident!("Encode"));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #crate_root::AsnType for #name #ty_generics #where_clause {
+            const TAG: #crate_root::types::Tag = #crate_root::types::Tag::EOC;
+            const TAG_TREE: #crate_root::types::TagTree = #crate_root::types::TagTree::Choice(&[]);
+        }
+
+        impl #impl_generics #crate_root::types::InformationObjectSet for #name #ty_generics #where_clause {
+            type Identifier = #id_type;
+
+            fn identifier(&self) -> Option<Self::Identifier> {
+                match self {
+                    #(#identifier_arms)*
+                }
+            }
+
+            // The identifiers are converted with `From`, as not every
+            // identifier type can be compared with its objects' ids.
+            #[allow(clippy::cmp_owned)]
+            fn decode_value(
+                codec: #crate_root::Codec,
+                identifier: &Self::Identifier,
+                value: &#crate_root::types::Any,
+            ) -> Result<Self, #crate_root::error::DecodeError> {
+                #(#decode_ifs)*
+                #decode_unknown
+            }
+
+            fn encode_value(
+                &self,
+                codec: #crate_root::Codec,
+            ) -> Result<#crate_root::types::Any, #crate_root::error::EncodeError> {
+                match self {
+                    #(#encode_arms)*
+                }
+            }
+        }
+
+        impl #impl_generics #crate_root::Encode for #name #ty_generics #where_clause {
+            fn encode_with_tag_and_constraints<'encoder, E: #crate_root::Encoder<'encoder>>(
+                &self,
+                encoder: &mut E,
+                tag: #crate_root::types::Tag,
+                _: #crate_root::types::Constraints,
+                identifier: #crate_root::types::Identifier,
+            ) -> Result<(), E::Error> {
+                let value = #crate_root::types::InformationObjectSet::encode_value(self, encoder.codec())?;
+                encoder.encode_any(tag, &value, identifier).map(drop)
+            }
+        }
+    })
+}
@@ -0,0 +1,165 @@
+use crate::config::{Config, FieldConfig, VariantConfig};
+
+pub fn derive_impl(
+    name: &syn::Ident,
+    mut generics: syn::Generics,
+    data: &syn::Data,
+    config: &Config,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let crate_root = &config.crate_root;
+
+    let mut body = match data {
+        syn::Data::Struct(container) if config.delegate => {
+            let field = container.fields.iter().next().ok_or_else(|| {
+                syn::Error::new_spanned(
+                    &container.fields,
+                    "Delegate is only supported for newtype pattern",
+                )
+            })?;
+            let member = field
+                .ident
+                .as_ref()
+                .map_or_else(|| quote!(0), |ident| quote!(#ident));
+            let ty = &field.ty;
+
+            quote!(<#ty as #crate_root::types::Validate>::validate_with_constraints(&self.#member, constraints, validator);)
+        }
+        syn::Data::Struct(container) => {
+            let fields = container
+                .fields
+                .iter()
+                .enumerate()
+                .map(|(i, field)| {
+                    let member = field.ident.as_ref().map_or_else(
+                        || {
+                            let index = syn::Index::from(i);
+                            quote!(#index)
+                        },
+                        |ident| quote!(#ident),
+                    );
+                    let field_config = FieldConfig::new(field, config, i)?;
+                    // Open types have no constraints of their own.
+                    Ok(field_config
+                        .table
+                        .is_none()
+                        .then(|| validate_field(&field_config, quote!(&self.#member), "")))
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+
+            quote!(#(#fields)*)
+        }
+        syn::Data::Enum(_) if config.enumerated => quote!(),
+        syn::Data::Enum(syn::DataEnum { variants, .. }) => {
+            let arms = variants
+                .iter()
+                .enumerate()
+                .map(|(i, variant)| {
+                    let variant_config = VariantConfig::new(variant, &generics, config, i)?;
+                    let variant_name = variant_config.identifier.as_ref().map_or_else(
+                        || variant.ident.to_string(),
+                        syn::LitStr::value,
+                    );
+                    let ident = &variant.ident;
+
+                    Ok(match &variant.fields {
+                        syn::Fields::Unit => quote!(Self::#ident => {}),
+                        syn::Fields::Unnamed(unnamed) => {
+                            let ty = &unnamed.unnamed[0].ty;
+                            let constraints = variant_config.constraints.field_expr(ty, crate_root);
+                            quote!(Self::#ident(value, ..) => validator.field(#variant_name, value, #constraints),)
+                        }
+                        syn::Fields::Named(named) => {
+                            let idents = named.named.iter().map(|field| &field.ident);
+                            let fields = named
+                                .named
+                                .iter()
+                                .enumerate()
+                                .map(|(i, field)| {
+                                    let ident = &field.ident;
+                                    Ok(validate_field(
+                                        &FieldConfig::new(field, config, i)?,
+                                        quote!(#ident),
+                                        &variant_name,
+                                    ))
+                                })
+                                .collect::<syn::Result<Vec<_>>>()?;
+
+                            quote!(Self::#ident { #(#idents),* } => { #(#fields)* })
+                        }
+                    })
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+
+            quote! {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+        syn::Data::Union(_) => {
+            return Err(syn::Error::new(
+                name.span(),
+                "Union types are not supported.",
+            ))
+        }
+    };
+
+    if config.inner_subtype {
+        body.extend(quote!(validator.inner_subtype(self);));
+    }
+
+    // Only delegates are validated with the constraints they're given.
+    let constraints = if config.delegate {
+        quote!(constraints)
+    } else {
+        quote!(_)
+    };
+    for param in generics.type_params_mut() {
+        param
+            .bounds
+            .push(syn::parse_quote!(#crate_root::types::Validate));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #crate_root::types::Validate for #name #ty_generics #where_clause {
+            fn validate_with_constraints(
+                &self,
+                #constraints: #crate_root::types::Constraints,
+                validator: &mut #crate_root::types::Validator,
+            ) {
+                #body
+            }
+        }
+    })
+}
+
+/// Validates the field `value`, whose name is prefixed with the name of its
+/// variant, if any.
+fn validate_field(
+    field: &FieldConfig,
+    value: proc_macro2::TokenStream,
+    variant_name: &str,
+) -> proc_macro2::TokenStream {
+    let crate_root = &field.container_config.crate_root;
+    let name = field.identifier.as_ref().map_or_else(
+        || {
+            field
+                .field
+                .ident
+                .as_ref()
+                .map_or_else(|| field.context.to_string(), ToString::to_string)
+        },
+        syn::LitStr::value,
+    );
+    let name = if variant_name.is_empty() {
+        name
+    } else {
+        format!("{variant_name}.{name}")
+    };
+    let ty = &field.field.ty;
+    let constraints = field.constraints.field_expr(ty, crate_root);
+
+    quote!(validator.field(#name, #value, #constraints);)
+}
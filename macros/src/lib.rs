@@ -20,6 +20,23 @@ pub fn decode_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream
         .into()
 }
 
+/// An automatic derive of the `DecodeBorrowed` trait.
+///
+/// Generates an implementation decoding a `SEQUENCE` (or delegate newtype)
+/// whose fields may borrow from the input, such as `BorrowedOctetString`.
+/// The container must have exactly one lifetime parameter, which is used as
+/// the lifetime of the input. `SET`s, `CHOICE`s and extension additions are
+/// not supported. See [`AsnType`](`asn_type_derive`) for information on
+/// available attributes.
+#[proc_macro_derive(DecodeBorrowed, attributes(rasn))]
+pub fn decode_borrowed_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let derive_input = parse_macro_input!(input as DeriveInput);
+
+    rasn_derive_impl::decode_borrowed_derive_inner(derive_input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
 /// An automatic derive of the `Encode` trait.
 ///
 /// Will automatically generate a encode implementation using the your
@@ -34,6 +51,64 @@ pub fn encode_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream
         .into()
 }
 
+/// An automatic derive of the `Describe` trait.
+///
+/// Generates a runtime `TypeDescriptor` mirroring the container's
+/// definition, which can be used to decode values without the Rust type, e.g.
+/// with `rasn::uper::decode_value`. Field and variant types must implement
+/// `Describe` as well, so recursive types are not supported. See
+/// [`AsnType`](`asn_type_derive`) for information on available attributes.
+#[proc_macro_derive(Describe, attributes(rasn))]
+pub fn describe_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let derive_input = parse_macro_input!(input as DeriveInput);
+
+    rasn_derive_impl::describe_derive_inner(derive_input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// An automatic derive of the `Validate` trait.
+///
+/// Generates an implementation checking the value, size and permitted
+/// alphabet constraints of every field (if struct) or of the active variant
+/// (if a choice style enum), reporting violations with the path to the
+/// field. Field and variant types must implement `Validate` as well. See
+/// [`AsnType`](`asn_type_derive`) for information on available attributes.
+#[proc_macro_derive(Validate, attributes(rasn))]
+pub fn validate_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let derive_input = parse_macro_input!(input as DeriveInput);
+
+    rasn_derive_impl::validate_derive_inner(derive_input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// An automatic derive of the `InformationObjectSet` trait.
+///
+/// Generates an implementation for an enum with a single-field tuple variant
+/// per information object, along with `AsnType` and `Encode`
+/// implementations encoding the value as an open type. Fields of the enum's
+/// type are decoded by annotating them with `#[rasn(table(id))]`, where `id`
+/// is an earlier field holding the object's identifier.
+///
+/// ##### Container Attributes
+/// - `id_type = T` The type of the objects' identifiers, e.g.
+///   `ObjectIdentifier` or `Integer`.
+/// - `crate_root` The path to the `rasn` library to use in the macro.
+///
+/// ##### Variant Attributes
+/// - `id = expr` The identifier of the object, which is converted to the
+///   identifier type with `From`. The variant without an `id`, if any, holds
+///   the `Any` value of objects with unknown identifiers.
+#[proc_macro_derive(InformationObjectSet, attributes(rasn))]
+pub fn information_object_set_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let derive_input = parse_macro_input!(input as DeriveInput);
+
+    rasn_derive_impl::information_object_set_derive_inner(derive_input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
 /// An automatic derive of the `AsnType` trait.
 ///
 /// This macro will automatically generate an implementation of `AsnType`,
@@ -52,6 +127,15 @@ pub fn encode_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream
 /// - `enumerated/choice` Use either `#[rasn(choice)]` or `#[rasn(enumerated)]`
 /// - `delegate` Only available for newtype wrappers (e.g. `struct Delegate(T)`);
 ///   uses the inner `T` type for implementing the trait. Tuple-struct can have more than one field if other fields are `PhantomData` types.
+/// - `serde` Also implement `serde::Serialize` for `Encode` and
+///   `serde::Deserialize` for `Decode` using the JER representation, which
+///   requires the `serde` feature of `rasn`.
+/// - `arbitrary` Also implement `rasn::arbitrary::ConstrainedArbitrary` and
+///   `arbitrary::Arbitrary` for `Decode`, generating values which satisfy
+///   their constraints, which requires the `arbitrary` feature of `rasn`.
+/// - `inner_subtype` Also check the type's inner subtype constraint with its
+///   `InnerSubtypeConstraint` implementation in `Validate`, which requires the
+///   type to implement `Clone`.
 #[proc_macro_derive(AsnType, attributes(rasn))]
 pub fn asn_type_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let derive_input = parse_macro_input!(input as DeriveInput);
@@ -21,6 +21,15 @@ pub fn decode_with_remainder<T: crate::Decode>(
     crate::per::decode_with_remainder(de::DecoderOptions::aligned(), input)
 }
 
+/// Attempts to decode a value of the type described by `descriptor` from
+/// `input` using APER-BASIC, without needing the Rust type of the value.
+pub fn decode_value<'d>(
+    descriptor: &crate::types::TypeDescriptor<'d>,
+    input: &[u8],
+) -> Result<crate::types::value::Value<'d>, crate::error::DecodeError> {
+    crate::per::decode_value(de::DecoderOptions::aligned(), descriptor, input)
+}
+
 /// Attempts to encode `value` to APER-CANONICAL.
 pub fn encode<T: crate::Encode>(
     value: &T,
@@ -28,6 +37,54 @@ pub fn encode<T: crate::Encode>(
     crate::per::encode(enc::EncoderOptions::aligned(), value)
 }
 
+/// Attempts to encode `value` as the type described by `descriptor` to
+/// APER-CANONICAL, without needing the Rust type of the value.
+pub fn encode_value(
+    descriptor: &crate::types::TypeDescriptor,
+    value: &crate::types::value::Value,
+) -> Result<alloc::vec::Vec<u8>, crate::error::EncodeError> {
+    crate::per::encode_value(enc::EncoderOptions::aligned(), descriptor, value)
+}
+
+/// Attempts to encode `value` to APER-CANONICAL into the start of `buffer`, returning
+/// the number of bytes written.
+///
+/// # Errors
+/// Returns `EncodeError` if `value` cannot be encoded, or with
+/// [`EncodeErrorKind::BufferTooSmall`](crate::error::EncodeErrorKind::BufferTooSmall)
+/// as soon as the encoding doesn't fit into `buffer`. The contents of
+/// `buffer` are unspecified on error.
+pub fn encode_into<T: crate::Encode>(
+    value: &T,
+    buffer: &mut [u8],
+) -> Result<usize, crate::error::EncodeError> {
+    enc::Encoder::encode_to_sink(
+        enc::EncoderOptions::aligned(),
+        value,
+        crate::enc::SliceSink::new(buffer),
+    )
+}
+
+/// Attempts to encode `value` to APER-CANONICAL, writing it to `writer` as it is
+/// encoded and returning the number of bytes written.
+///
+/// # Errors
+/// Returns `EncodeError` if `value` cannot be encoded, or with
+/// [`EncodeErrorKind::Io`](crate::error::EncodeErrorKind::Io) if writing
+/// fails, in which case part of the encoding may have been written.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn encode_to_writer<T: crate::Encode, W: std::io::Write>(
+    value: &T,
+    writer: W,
+) -> Result<usize, crate::error::EncodeError> {
+    enc::Encoder::encode_to_sink(
+        enc::EncoderOptions::aligned(),
+        value,
+        crate::enc::IoSink::new(writer),
+    )
+}
+
 /// Attempts to decode `T` from `input` using APER-BASIC.
 pub fn decode_with_constraints<T: crate::Decode>(
     constraints: Constraints,
@@ -530,4 +587,109 @@ mod tests {
         );
         let _: ConnectData = rasn::aper::decode(&encoded).expect("failed to decode");
     }
+
+    #[test]
+    fn sequence_of_alignment() {
+        use crate as rasn;
+        use crate::prelude::*;
+
+        #[derive(Debug, AsnType, Encode, Decode, PartialEq)]
+        #[rasn(automatic_tags)]
+        struct Item {
+            #[rasn(size("2..=4"))]
+            key: PrintableString,
+            flag: bool,
+        }
+
+        #[derive(Debug, AsnType, Encode, Decode, PartialEq)]
+        #[rasn(automatic_tags)]
+        struct Items {
+            flag: bool,
+            #[rasn(size("0..=3"))]
+            items: SequenceOf<Item>,
+        }
+
+        // The elements follow the constrained length without padding, and
+        // the second one starts after the first one's trailing BOOLEAN.
+        round_trip!(
+            aper,
+            Items,
+            Items {
+                flag: true,
+                items: vec![
+                    Item {
+                        key: PrintableString::try_from("ab").unwrap(),
+                        flag: true,
+                    },
+                    Item {
+                        key: PrintableString::try_from("cde").unwrap(),
+                        flag: false,
+                    },
+                ],
+            },
+            &[0xC0, 0x61, 0x62, 0xA0, 0x63, 0x64, 0x65, 0x00]
+        );
+    }
+
+    #[test]
+    fn time_types() {
+        let date = chrono::NaiveDate::from_ymd_opt(2012, 12, 21).unwrap();
+        let time = TimeOfDay::from_hms_opt(12, 30, 45).unwrap();
+        round_trip!(
+            aper,
+            IsoTime,
+            "2024-05-01T12:00Z".parse().unwrap(),
+            b"\x112024-05-01T12:00Z"
+        );
+        round_trip!(aper, TimeOfDay, time, &[0x63, 0xd6, 0x80]);
+        round_trip!(
+            aper,
+            DateTime,
+            date.and_time(time),
+            &[0x1e, 0xe8, 0xc7, 0xad]
+        );
+        // Years outside of 1749..=2276 are octet aligned unconstrained
+        // integers.
+        round_trip!(
+            aper,
+            DateTime,
+            chrono::NaiveDate::from_ymd_opt(1500, 1, 1)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap(),
+            &[0xc0, 0x02, 0x05, 0xdc, 0, 0, 0, 0]
+        );
+        round_trip!(
+            aper,
+            IsoDuration,
+            "P1Y2M10DT2H30M".parse().unwrap(),
+            &[0xdc, 1, 1, 1, 2, 1, 10, 1, 2, 1, 30]
+        );
+
+        let fraction = TimeOfDay::from_hms_milli_opt(12, 30, 45, 500).unwrap();
+        encode_error!(aper, TimeOfDay, fraction);
+        encode_error!(aper, DateTime, date.and_time(fraction));
+    }
+
+    #[test]
+    #[cfg(feature = "f64")]
+    fn real() {
+        #[derive(Debug, AsnType, Decode, Encode, PartialEq)]
+        #[rasn(crate_root = "crate")]
+        struct Measurement {
+            valid: bool,
+            value: f64,
+        }
+
+        round_trip!(aper, f64, -2.5, &[0x03, 0xC0, 0xFF, 0x05]);
+        round_trip!(
+            aper,
+            Measurement,
+            Measurement {
+                valid: true,
+                value: 1.0
+            },
+            &[0x80, 0x03, 0x80, 0x00, 0x01]
+        );
+    }
 }
@@ -0,0 +1,637 @@
+//! Generation of arbitrary values which satisfy their ASN.1 constraints, for
+//! fuzzing and property testing codecs.
+//!
+//! [`ConstrainedArbitrary`] generates values from [`Unstructured`] data which
+//! satisfy the value range, size, and permitted alphabet constraints they're
+//! encoded with, occasionally going outside the root of extensible
+//! constraints, so that every generated value can be encoded by every codec.
+//! Integers without an upper or lower bound are generated within the range
+//! of an `i64`, as the JSON Encoding Rules don't support larger values.
+//! It's implemented for the types in [`types`](crate::types), and can be
+//! derived for your own types with `#[rasn(arbitrary)]` alongside `Decode`,
+//! which also implements [`Arbitrary`].
+//!
+//! ```
+//! use rasn::{arbitrary::{Arbitrary, Unstructured}, prelude::*};
+//!
+//! #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+//! #[rasn(automatic_tags, arbitrary)]
+//! struct Reading {
+//!     #[rasn(value("0..=100"))]
+//!     level: u8,
+//!     #[rasn(size("1..=4"))]
+//!     samples: SequenceOf<Integer>,
+//! }
+//!
+//! let reading = Reading::arbitrary(&mut Unstructured::new(&[0xff; 64])).unwrap();
+//! assert!(reading.level <= 100 && (1..=4).contains(&reading.samples.len()));
+//! assert_eq!(reading, rasn::uper::decode(&rasn::uper::encode(&reading).unwrap()).unwrap());
+//! ```
+//!
+//! With the `proptest` feature, [`strategy`] provides a proptest strategy for
+//! generating values of any of these types.
+//!
+//! [`Any`](crate::types::Any) and [`Open`](crate::types::Open) values
+//! contain encodings of a specific codec, and aren't generated.
+
+use alloc::{boxed::Box, vec::Vec};
+
+use chrono::{Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime};
+
+use crate::types::{
+    constraints::{Bounded, Extensible},
+    strings::StaticPermittedAlphabet,
+    AsnType, BitString, BmpString, ConstrainedInteger, Constraints, Date, DateTime, Explicit,
+    FixedBitString, FixedOctetString, GeneralString, GeneralizedTime, GraphicString, Ia5String,
+    Implicit, Integer, NumericString, ObjectIdentifier, OctetString, PrintableString,
+    RelativeObjectIdentifier, SetOf, TeletexString, TimeOfDay, UtcTime, Utf8String, VisibleString,
+};
+
+#[doc(no_inline)]
+pub use ::arbitrary::{Arbitrary, Error, Result, Unstructured};
+
+/// The most elements generated beyond the lower bound of a size constraint.
+const MAX_EXTRA_ELEMENTS: usize = 64;
+
+/// The least integer generated, which every codec supports.
+const MIN_INTEGER: i128 = i64::MIN as i128;
+
+/// The greatest integer generated, which every codec supports.
+const MAX_INTEGER: i128 = i64::MAX as i128;
+
+/// The most bytes of [`Unstructured`] data used by [`strategy`].
+#[cfg(feature = "proptest")]
+const MAX_STRATEGY_BYTES: usize = 4096;
+
+/// A type which can generate arbitrary values satisfying a set of
+/// [`Constraints`].
+pub trait ConstrainedArbitrary<'a>: AsnType + Sized {
+    /// Generates a value which satisfies `constraints`.
+    /// # Errors
+    /// Returns an error if no value satisfies `constraints`, or if `u`
+    /// doesn't contain enough data.
+    fn arbitrary_with_constraints(
+        u: &mut Unstructured<'a>,
+        constraints: Constraints,
+    ) -> Result<Self>;
+
+    /// Generates a value which satisfies the constraints of its type.
+    /// # Errors
+    /// Returns an error if no value satisfies the constraints of its type, or
+    /// if `u` doesn't contain enough data.
+    fn arbitrary_constrained(u: &mut Unstructured<'a>) -> Result<Self> {
+        Self::arbitrary_with_constraints(u, Self::CONSTRAINTS)
+    }
+}
+
+/// A proptest strategy generating values of `T` which satisfy the
+/// constraints of its type.
+#[cfg(feature = "proptest")]
+#[cfg_attr(docsrs, doc(cfg(feature = "proptest")))]
+pub fn strategy<T>() -> impl proptest::strategy::Strategy<Value = T>
+where
+    T: for<'a> ConstrainedArbitrary<'a> + core::fmt::Debug,
+{
+    use proptest::strategy::Strategy;
+
+    proptest::collection::vec(proptest::num::u8::ANY, 0..=MAX_STRATEGY_BYTES)
+        .prop_filter_map("constraints can't be satisfied", |bytes| {
+            T::arbitrary_constrained(&mut Unstructured::new(&bytes)).ok()
+        })
+}
+
+/// Returns `constraint`, or `None` to go outside the root of an extensible
+/// constraint.
+fn root<T: Copy>(
+    u: &mut Unstructured<'_>,
+    constraint: Option<&Extensible<T>>,
+) -> Result<Option<T>> {
+    match constraint {
+        Some(constraint) if constraint.extensible.is_none() || u.ratio(3, 4)? => {
+            Ok(Some(constraint.constraint))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// The inclusive range of `bounded` within `min` and `max`.
+fn range<T: Copy + Ord>(bounded: Bounded<T>, min: T, max: T) -> Result<(T, T)> {
+    let (start, end) = match bounded {
+        Bounded::None => (min, max),
+        Bounded::Single(value) => (value.max(min), value.min(max)),
+        Bounded::Range { start, end } => (
+            start.map_or(min, |start| start.max(min)),
+            end.map_or(max, |end| end.min(max)),
+        ),
+    };
+
+    if start > end {
+        Err(Error::IncorrectFormat)
+    } else {
+        Ok((start, end))
+    }
+}
+
+/// The inclusive range of `bounded` within `min` and `max`, narrowed to one of
+/// the ranges of `set` for constraints whose values aren't contiguous.
+fn set_range<T: Copy + Ord>(
+    u: &mut Unstructured<'_>,
+    bounded: Bounded<T>,
+    set: Option<&[Bounded<T>]>,
+    min: T,
+    max: T,
+) -> Result<(T, T)> {
+    let (start, end) = range(bounded, min, max)?;
+    let Some(set) = set else {
+        return Ok((start, end));
+    };
+    let ranges = set
+        .iter()
+        .filter_map(|bounded| range(*bounded, start, end).ok())
+        .collect::<Vec<_>>();
+
+    u.choose(&ranges).copied()
+}
+
+/// Generates an integer satisfying the value constraint of `constraints`
+/// within `min` and `max`.
+fn integer(
+    u: &mut Unstructured<'_>,
+    constraints: &Constraints,
+    min: i128,
+    max: i128,
+) -> Result<i128> {
+    let value = root(u, constraints.value())?;
+    let set = value.and_then(|value| value.set());
+    let (start, end) = set_range(
+        u,
+        value.map_or(Bounded::None, |value| *value),
+        set.as_deref(),
+        min,
+        max,
+    )?;
+    u.int_in_range(start..=end)
+}
+
+/// Generates a length satisfying the size constraint of `constraints`.
+fn size(u: &mut Unstructured<'_>, constraints: &Constraints) -> Result<usize> {
+    let size = root(u, constraints.size())?;
+    let set = size.and_then(|size| size.set());
+    let (start, end) = set_range(
+        u,
+        size.map_or(Bounded::None, |size| *size),
+        set.as_deref(),
+        0,
+        usize::MAX,
+    )?;
+    u.int_in_range(start..=end.min(start.saturating_add(MAX_EXTRA_ELEMENTS)))
+}
+
+/// Generates elements with `element`, with a length satisfying the size
+/// constraint of `constraints`.
+fn elements<'a, T, C: FromIterator<T>>(
+    u: &mut Unstructured<'a>,
+    constraints: &Constraints,
+    mut element: impl FnMut(&mut Unstructured<'a>) -> Result<T>,
+) -> Result<C> {
+    let len = size(u, constraints)?;
+    (0..len).map(|_| element(u)).collect()
+}
+
+/// Generates characters which satisfy the size and permitted alphabet
+/// constraints of `constraints`, from `character_set`, or from any character
+/// if it's `None`.
+fn characters(
+    u: &mut Unstructured<'_>,
+    constraints: &Constraints,
+    character_set: Option<&[u32]>,
+    is_character: impl Fn(u32) -> bool,
+) -> Result<Vec<u32>> {
+    let alphabet = match (root(u, constraints.permitted_alphabet())?, character_set) {
+        (Some(alphabet), _) => alphabet
+            .iter()
+            .copied()
+            .filter(|ch| {
+                alphabet.permits(*ch)
+                    && character_set.is_none_or(|set| set.contains(ch))
+                    && is_character(*ch)
+            })
+            .collect::<Vec<_>>(),
+        (None, Some(set)) => set.iter().copied().filter(|ch| is_character(*ch)).collect(),
+        (None, None) => return elements(u, constraints, |u| char::arbitrary(u).map(u32::from)),
+    };
+
+    elements(u, constraints, |u| u.choose(&alphabet).copied())
+}
+
+/// Generates a date between the start of `first` and the end of `last`.
+fn date(u: &mut Unstructured<'_>, first: i32, last: i32) -> Result<Date> {
+    let day = |year, month, day| {
+        NaiveDate::from_ymd_opt(year, month, day)
+            .map(|date| date.num_days_from_ce())
+            .ok_or(Error::IncorrectFormat)
+    };
+    let days = u.int_in_range(day(first, 1, 1)?..=day(last, 12, 31)?)?;
+
+    NaiveDate::from_num_days_from_ce_opt(days).ok_or(Error::IncorrectFormat)
+}
+
+/// Generates a time of day with a precision of seconds.
+fn time_of_day(u: &mut Unstructured<'_>) -> Result<TimeOfDay> {
+    NaiveTime::from_num_seconds_from_midnight_opt(u.int_in_range(0..=86_399)?, 0)
+        .ok_or(Error::IncorrectFormat)
+}
+
+macro_rules! impl_primitive_integers {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl<'a> ConstrainedArbitrary<'a> for $ty {
+                #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+                fn arbitrary_with_constraints(
+                    u: &mut Unstructured<'a>,
+                    constraints: Constraints,
+                ) -> Result<Self> {
+                    integer(u, &constraints, <$ty>::MIN as i128, <$ty>::MAX as i128).map(|value| value as $ty)
+                }
+            }
+        )+
+    }
+}
+
+impl_primitive_integers!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl<'a> ConstrainedArbitrary<'a> for i128 {
+    fn arbitrary_with_constraints(
+        u: &mut Unstructured<'a>,
+        constraints: Constraints,
+    ) -> Result<Self> {
+        integer(u, &constraints, MIN_INTEGER, MAX_INTEGER)
+    }
+}
+
+impl<'a> ConstrainedArbitrary<'a> for Integer {
+    fn arbitrary_with_constraints(
+        u: &mut Unstructured<'a>,
+        constraints: Constraints,
+    ) -> Result<Self> {
+        integer(u, &constraints, MIN_INTEGER, MAX_INTEGER).map(Self::from)
+    }
+}
+
+impl<'a, const START: i128, const END: i128> ConstrainedArbitrary<'a>
+    for ConstrainedInteger<START, END>
+{
+    fn arbitrary_with_constraints(
+        u: &mut Unstructured<'a>,
+        constraints: Constraints,
+    ) -> Result<Self> {
+        integer(u, &constraints, START, END).map(|value| Self(value.into()))
+    }
+}
+
+macro_rules! impl_reals {
+    ($($feature:literal: $ty:ty),+ $(,)?) => {
+        $(
+            #[cfg(feature = $feature)]
+            impl<'a> ConstrainedArbitrary<'a> for $ty {
+                fn arbitrary_with_constraints(u: &mut Unstructured<'a>, _: Constraints) -> Result<Self> {
+                    // Non-finite values don't equal themselves after a round trip.
+                    <$ty>::arbitrary(u).map(|value| if value.is_finite() { value } else { 0.0 })
+                }
+            }
+        )+
+    }
+}
+
+impl_reals!("f32": f32, "f64": f64);
+
+impl<'a> ConstrainedArbitrary<'a> for bool {
+    fn arbitrary_with_constraints(u: &mut Unstructured<'a>, _: Constraints) -> Result<Self> {
+        Self::arbitrary(u)
+    }
+}
+
+impl<'a> ConstrainedArbitrary<'a> for () {
+    fn arbitrary_with_constraints(_: &mut Unstructured<'a>, _: Constraints) -> Result<Self> {
+        Ok(())
+    }
+}
+
+impl<'a, T> ConstrainedArbitrary<'a> for core::marker::PhantomData<T> {
+    fn arbitrary_with_constraints(_: &mut Unstructured<'a>, _: Constraints) -> Result<Self> {
+        Ok(Self)
+    }
+}
+
+impl<'a> ConstrainedArbitrary<'a> for OctetString {
+    fn arbitrary_with_constraints(
+        u: &mut Unstructured<'a>,
+        constraints: Constraints,
+    ) -> Result<Self> {
+        elements::<_, Vec<u8>>(u, &constraints, u8::arbitrary).map(Self::from)
+    }
+}
+
+impl<'a, const N: usize> ConstrainedArbitrary<'a> for FixedOctetString<N> {
+    fn arbitrary_with_constraints(u: &mut Unstructured<'a>, _: Constraints) -> Result<Self> {
+        <[u8; N]>::arbitrary(u).map(Self::new)
+    }
+}
+
+impl<'a> ConstrainedArbitrary<'a> for BitString {
+    fn arbitrary_with_constraints(
+        u: &mut Unstructured<'a>,
+        constraints: Constraints,
+    ) -> Result<Self> {
+        elements(u, &constraints, bool::arbitrary)
+    }
+}
+
+impl<'a, const N: usize> ConstrainedArbitrary<'a> for FixedBitString<N> {
+    fn arbitrary_with_constraints(u: &mut Unstructured<'a>, _: Constraints) -> Result<Self> {
+        // Only the first `N` bits are part of the value.
+        let mut bits = Self::ZERO;
+        for i in 0..N {
+            bits.set(i, bool::arbitrary(u)?);
+        }
+
+        Ok(bits)
+    }
+}
+
+impl<'a> ConstrainedArbitrary<'a> for Utf8String {
+    fn arbitrary_with_constraints(
+        u: &mut Unstructured<'a>,
+        constraints: Constraints,
+    ) -> Result<Self> {
+        // Sizes of UTF8String values are checked in octets by some codecs, so
+        // sized strings are kept to the characters which are one octet long.
+        let sized = constraints.size().is_some();
+        let ascii = sized.then(|| (0..0x80).collect::<Vec<_>>());
+        let characters = characters(u, &constraints, ascii.as_deref(), |ch| {
+            char::from_u32(ch).is_some() && (!sized || ch < 0x80)
+        })?;
+
+        Ok(characters.into_iter().filter_map(char::from_u32).collect())
+    }
+}
+
+macro_rules! impl_restricted_strings {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl<'a> ConstrainedArbitrary<'a> for $ty {
+                fn arbitrary_with_constraints(
+                    u: &mut Unstructured<'a>,
+                    constraints: Constraints,
+                ) -> Result<Self> {
+                    let mut string = Self::default();
+                    // Surrogates aren't characters, even in sets of code units.
+                    let characters = characters(u, &constraints, Some(Self::CHARACTER_SET), |ch| {
+                        char::from_u32(ch).is_some()
+                    })?;
+                    for ch in characters {
+                        string.push_char(ch);
+                    }
+
+                    Ok(string)
+                }
+            }
+        )+
+    }
+}
+
+impl_restricted_strings!(
+    BmpString,
+    GeneralString,
+    GraphicString,
+    Ia5String,
+    NumericString,
+    PrintableString,
+    TeletexString,
+    VisibleString,
+);
+
+impl<'a> ConstrainedArbitrary<'a> for ObjectIdentifier {
+    fn arbitrary_with_constraints(u: &mut Unstructured<'a>, _: Constraints) -> Result<Self> {
+        // The first two arcs are encoded together, as `40 * first + second`.
+        let first = u.int_in_range(0..=2)?;
+        let second = if first < 2 {
+            u.int_in_range(0..=39)?
+        } else {
+            u.int_in_range(0..=u32::MAX - 80)?
+        };
+        let mut arcs = alloc::vec![first, second];
+        for _ in 0..u.arbitrary_len::<u32>()? {
+            arcs.push(u32::arbitrary(u)?);
+        }
+
+        Self::new(arcs).ok_or(Error::IncorrectFormat)
+    }
+}
+
+impl<'a> ConstrainedArbitrary<'a> for RelativeObjectIdentifier {
+    fn arbitrary_with_constraints(u: &mut Unstructured<'a>, _: Constraints) -> Result<Self> {
+        let mut arcs = alloc::vec![u32::arbitrary(u)?];
+        for _ in 0..u.arbitrary_len::<u32>()? {
+            arcs.push(u32::arbitrary(u)?);
+        }
+
+        Self::new(arcs).ok_or(Error::IncorrectFormat)
+    }
+}
+
+impl<'a> ConstrainedArbitrary<'a> for UtcTime {
+    fn arbitrary_with_constraints(u: &mut Unstructured<'a>, _: Constraints) -> Result<Self> {
+        // UTCTime has two digit years, which are decoded as 1970 to 2069.
+        Ok(NaiveDateTime::new(date(u, 1970, 2069)?, time_of_day(u)?).and_utc())
+    }
+}
+
+impl<'a> ConstrainedArbitrary<'a> for GeneralizedTime {
+    fn arbitrary_with_constraints(u: &mut Unstructured<'a>, _: Constraints) -> Result<Self> {
+        let time = NaiveDateTime::new(date(u, 1, 9999)?, time_of_day(u)?);
+        let offset = FixedOffset::east_opt(u.int_in_range(-14..=14)? * 3600)
+            .ok_or(Error::IncorrectFormat)?;
+
+        time.and_local_timezone(offset)
+            .single()
+            .ok_or(Error::IncorrectFormat)
+    }
+}
+
+impl<'a> ConstrainedArbitrary<'a> for Date {
+    fn arbitrary_with_constraints(u: &mut Unstructured<'a>, _: Constraints) -> Result<Self> {
+        date(u, 1, 9999)
+    }
+}
+
+impl<'a> ConstrainedArbitrary<'a> for TimeOfDay {
+    fn arbitrary_with_constraints(u: &mut Unstructured<'a>, _: Constraints) -> Result<Self> {
+        time_of_day(u)
+    }
+}
+
+impl<'a> ConstrainedArbitrary<'a> for DateTime {
+    fn arbitrary_with_constraints(u: &mut Unstructured<'a>, _: Constraints) -> Result<Self> {
+        Ok(Self::new(date(u, 1, 9999)?, time_of_day(u)?))
+    }
+}
+
+impl<'a, T: ConstrainedArbitrary<'a>> ConstrainedArbitrary<'a> for Option<T> {
+    fn arbitrary_with_constraints(
+        u: &mut Unstructured<'a>,
+        constraints: Constraints,
+    ) -> Result<Self> {
+        if bool::arbitrary(u)? {
+            T::arbitrary_with_constraints(u, T::CONSTRAINTS.intersect(constraints)).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+impl<'a, T: ConstrainedArbitrary<'a>> ConstrainedArbitrary<'a> for Box<T> {
+    fn arbitrary_with_constraints(
+        u: &mut Unstructured<'a>,
+        constraints: Constraints,
+    ) -> Result<Self> {
+        T::arbitrary_with_constraints(u, T::CONSTRAINTS.intersect(constraints)).map(Box::new)
+    }
+}
+
+impl<'a, T: ConstrainedArbitrary<'a>> ConstrainedArbitrary<'a> for Vec<T> {
+    fn arbitrary_with_constraints(
+        u: &mut Unstructured<'a>,
+        constraints: Constraints,
+    ) -> Result<Self> {
+        elements(u, &constraints, T::arbitrary_constrained)
+    }
+}
+
+impl<'a, T: ConstrainedArbitrary<'a> + Eq> ConstrainedArbitrary<'a> for SetOf<T> {
+    fn arbitrary_with_constraints(
+        u: &mut Unstructured<'a>,
+        constraints: Constraints,
+    ) -> Result<Self> {
+        elements(u, &constraints, T::arbitrary_constrained).map(Self::from_vec)
+    }
+}
+
+impl<'a, T: ConstrainedArbitrary<'a>, const N: usize> ConstrainedArbitrary<'a> for [T; N] {
+    fn arbitrary_with_constraints(u: &mut Unstructured<'a>, _: Constraints) -> Result<Self> {
+        (0..N)
+            .map(|_| T::arbitrary_constrained(u))
+            .collect::<Result<Vec<_>>>()?
+            .try_into()
+            .map_err(|_| Error::IncorrectFormat)
+    }
+}
+
+impl<'a, T: AsnType, V: ConstrainedArbitrary<'a>> ConstrainedArbitrary<'a> for Implicit<T, V> {
+    fn arbitrary_with_constraints(
+        u: &mut Unstructured<'a>,
+        constraints: Constraints,
+    ) -> Result<Self> {
+        V::arbitrary_with_constraints(u, constraints).map(Self::new)
+    }
+}
+
+impl<'a, T: AsnType, V: ConstrainedArbitrary<'a>> ConstrainedArbitrary<'a> for Explicit<T, V> {
+    fn arbitrary_with_constraints(
+        u: &mut Unstructured<'a>,
+        constraints: Constraints,
+    ) -> Result<Self> {
+        V::arbitrary_with_constraints(u, constraints).map(Self::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+    #[rasn(crate_root = "crate", automatic_tags, arbitrary)]
+    #[non_exhaustive]
+    struct Record {
+        #[rasn(value("-5..=5"))]
+        offset: i32,
+        #[rasn(size("2..=3"), from("a..=c"))]
+        label: Ia5String,
+        flags: Option<FixedBitString<1>>,
+        choice: Selection,
+        #[rasn(extension_addition)]
+        kind: Option<Kind>,
+    }
+
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+    #[rasn(crate_root = "crate", choice, automatic_tags, arbitrary)]
+    enum Selection {
+        Number(ConstrainedInteger<0, 10>),
+        #[rasn(size(1))]
+        Text(PrintableString),
+        Empty,
+    }
+
+    #[derive(AsnType, Clone, Copy, Debug, Decode, Encode, PartialEq)]
+    #[rasn(crate_root = "crate", enumerated, arbitrary)]
+    enum Kind {
+        First,
+        Second,
+    }
+
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+    #[rasn(crate_root = "crate", delegate, size("4"), arbitrary)]
+    struct Key(OctetString);
+
+    /// Pseudo-random data, so that values aren't generated in a short cycle.
+    fn data() -> Vec<u8> {
+        (0..4096u32)
+            .map(|i| (i.wrapping_mul(2_654_435_761) >> 24) as u8)
+            .collect()
+    }
+
+    #[test]
+    fn constraints() {
+        let data = data();
+        let mut u = Unstructured::new(&data);
+
+        for _ in 0..64 {
+            let record = Record::arbitrary(&mut u).unwrap();
+            assert!((-5..=5).contains(&record.offset));
+            let label = record.label.as_iso646_bytes();
+            assert!((2..=3).contains(&label.len()));
+            assert!(label.iter().all(|ch| b"abc".contains(ch)));
+            match record.choice {
+                Selection::Number(number) => {
+                    assert!(*number >= Integer::ZERO && *number <= 10.into());
+                }
+                Selection::Text(text) => assert_eq!(text.as_bytes().len(), 1),
+                Selection::Empty => {}
+            }
+            assert_eq!(Key::arbitrary(&mut u).unwrap().0.len(), 4);
+        }
+    }
+
+    #[test]
+    fn extensible() {
+        const CONSTRAINTS: Constraints = constraints!(value_constraint!(0, 3, extensible));
+        let data = data();
+        let mut u = Unstructured::new(&data);
+
+        let values = (0..64)
+            .map(|_| Integer::arbitrary_with_constraints(&mut u, CONSTRAINTS).unwrap())
+            .collect::<Vec<_>>();
+        let root = |value: &Integer| *value >= Integer::ZERO && *value <= 3.into();
+        assert!(values.iter().any(root));
+        assert!(!values.iter().all(root));
+    }
+
+    #[test]
+    fn unsatisfiable() {
+        const CONSTRAINTS: Constraints = constraints!(value_constraint!(300, 400));
+        let mut u = Unstructured::new(&[0; 16]);
+
+        assert!(u8::arbitrary_with_constraints(&mut u, CONSTRAINTS).is_err());
+    }
+}
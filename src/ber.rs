@@ -4,9 +4,17 @@ pub mod de;
 pub mod enc;
 mod identifier;
 mod rules;
+mod tlv;
 
 pub use identifier::Identifier;
 pub(crate) use rules::EncodingRules;
+pub use tlv::{Length, Tlv};
+
+// X.690 8.5.9 contents octets of the special real values.
+const REAL_PLUS_INFINITY: u8 = 0x40;
+const REAL_MINUS_INFINITY: u8 = 0x41;
+const REAL_NOT_A_NUMBER: u8 = 0x42;
+const REAL_MINUS_ZERO: u8 = 0x43;
 
 /// Attempts to decode `T` from `input` using BER.
 /// # Errors
@@ -27,6 +35,32 @@ pub fn decode_with_remainder<T: crate::Decode>(
     Ok((decoded_instance, decoder.remaining()))
 }
 
+/// Attempts to decode `T` from `input` using BER, borrowing from `input`
+/// where possible.
+///
+/// # Errors
+/// Returns `DecodeError` if `input` is not valid BER encoding specific to the expected type.
+pub fn decode_borrowed<'de, T: crate::de::DecodeBorrowed<'de>>(
+    input: &'de [u8],
+) -> Result<T, crate::error::DecodeError> {
+    T::decode_borrowed(&mut de::Decoder::new(input, de::DecoderOptions::ber()))
+}
+
+/// Attempts to decode a value of the type described by `descriptor` from
+/// `input` using BER, without needing the Rust type of the value.
+///
+/// # Errors
+/// Returns `DecodeError` if `input` is not valid BER encoding of the described type.
+pub fn decode_value<'d>(
+    descriptor: &crate::types::TypeDescriptor<'d>,
+    input: &[u8],
+) -> Result<crate::types::value::Value<'d>, crate::error::DecodeError> {
+    crate::Decoder::decode_value(
+        &mut de::Decoder::new(input, de::DecoderOptions::ber()),
+        descriptor,
+    )
+}
+
 /// Attempts to encode `value` to BER.
 /// # Errors
 /// Returns error specific to BER encoder if encoding is not possible.
@@ -40,6 +74,61 @@ pub fn encode<T: crate::Encode>(
     Ok(enc.output())
 }
 
+/// Attempts to encode `value` as the type described by `descriptor` to
+/// BER, without needing the Rust type of the value.
+///
+/// # Errors
+/// Returns `EncodeError` if `value` is not a valid value of the described type.
+pub fn encode_value(
+    descriptor: &crate::types::TypeDescriptor,
+    value: &crate::types::value::Value,
+) -> Result<alloc::vec::Vec<u8>, crate::error::EncodeError> {
+    let mut enc = enc::Encoder::new(enc::EncoderOptions::ber());
+
+    crate::Encoder::encode_value(&mut enc, descriptor, value)?;
+
+    Ok(enc.output())
+}
+
+/// Attempts to encode `value` to BER into the start of `buffer`, returning
+/// the number of bytes written.
+///
+/// # Errors
+/// Returns `EncodeError` if `value` cannot be encoded, or with
+/// [`EncodeErrorKind::BufferTooSmall`](crate::error::EncodeErrorKind::BufferTooSmall)
+/// as soon as the encoding doesn't fit into `buffer`. The contents of
+/// `buffer` are unspecified on error.
+pub fn encode_into<T: crate::Encode>(
+    value: &T,
+    buffer: &mut [u8],
+) -> Result<usize, crate::error::EncodeError> {
+    crate::ber::enc::Encoder::encode_to_sink(
+        crate::ber::enc::EncoderOptions::ber(),
+        value,
+        crate::enc::SliceSink::new(buffer),
+    )
+}
+
+/// Attempts to encode `value` to BER, writing it to `writer` as it is
+/// encoded and returning the number of bytes written.
+///
+/// # Errors
+/// Returns `EncodeError` if `value` cannot be encoded, or with
+/// [`EncodeErrorKind::Io`](crate::error::EncodeErrorKind::Io) if writing
+/// fails, in which case part of the encoding may have been written.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn encode_to_writer<T: crate::Encode, W: std::io::Write>(
+    value: &T,
+    writer: W,
+) -> Result<usize, crate::error::EncodeError> {
+    crate::ber::enc::Encoder::encode_to_sink(
+        crate::ber::enc::EncoderOptions::ber(),
+        value,
+        crate::enc::IoSink::new(writer),
+    )
+}
+
 /// Creates a new BER encoder that can be used to encode any value.
 /// # Errors
 /// Returns error specific to BER encoder if encoding is not possible.
@@ -426,6 +515,134 @@ mod tests {
             &[0x1f, 0x1f, 0x08, 0x32, 0x30, 0x31, 0x32, 0x31, 0x32, 0x32, 0x31]
         );
     }
+    #[test]
+    fn test_encoded_oid_types() {
+        round_trip!(
+            ber,
+            EncodedObjectIdentifier,
+            crate::encoded_oid!("2.999.3").to_owned(),
+            b"\x06\x03\x88\x37\x03"
+        );
+        // 2.25.4294967296, whose last arc doesn't fit in a `u32`.
+        let encoded = b"\x06\x06\x69\x90\x80\x80\x80\x00";
+        assert_eq!(
+            decode::<EncodedObjectIdentifier>(encoded)
+                .unwrap()
+                .to_string(),
+            "2.25.4294967296"
+        );
+        // `ObjectIdentifier` arcs are limited to `u32`.
+        assert!(decode::<ObjectIdentifier>(encoded).is_err());
+        assert!(decode::<EncodedObjectIdentifier>(b"\x06\x02\x2b\x80").is_err());
+        assert!(decode::<EncodedObjectIdentifier>(b"\x06\x02\x80\x01").is_err());
+    }
+
+    #[test]
+    fn test_relative_oid_types() {
+        round_trip!(
+            ber,
+            RelativeObjectIdentifier,
+            RelativeObjectIdentifier::new(vec![8571, 3, 2]).unwrap(),
+            b"\x0d\x04\xc2\x7b\x03\x02"
+        );
+        round_trip!(ber, OidIri, "/ISO".parse().unwrap(), b"\x1f\x23\x04/ISO");
+        round_trip!(
+            ber,
+            RelativeOidIri,
+            "ISO/0".parse().unwrap(),
+            b"\x1f\x24\x05ISO/0"
+        );
+        assert!(decode::<RelativeObjectIdentifier>(b"\x0d\x00").is_err());
+        assert!(decode::<OidIri>(b"\x1f\x23\x03ISO").is_err());
+    }
+
+    #[test]
+    fn test_external_types() {
+        let syntax = ObjectIdentifier::new(vec![1, 2]).unwrap();
+        round_trip!(
+            ber,
+            External,
+            External {
+                identification: Identification::Syntax(syntax.clone()),
+                data_value_descriptor: None,
+                data_value: OctetString::from_static(&[0xab]),
+            },
+            b"\x28\x06\x06\x01\x2a\x81\x01\xab"
+        );
+        assert_eq!(
+            decode::<External>(b"\x28\x07\x02\x01\x05\x82\x02\x00\xcd").unwrap(),
+            External {
+                identification: Identification::PresentationContextId(5.into()),
+                data_value_descriptor: None,
+                data_value: OctetString::from_static(&[0xcd]),
+            }
+        );
+        assert_eq!(
+            decode::<External>(b"\x28\x08\x06\x01\x2a\xa0\x03\x02\x01\x07")
+                .unwrap()
+                .data_value,
+            OctetString::from_static(b"\x02\x01\x07")
+        );
+        assert!(decode::<External>(b"\x28\x03\x81\x01\xab").is_err());
+        assert!(decode::<External>(b"\x28\x07\x02\x01\x05\x82\x02\x04\xc0").is_err());
+        assert!(encode(&External {
+            identification: Identification::Fixed,
+            data_value_descriptor: None,
+            data_value: OctetString::from_static(&[0xab]),
+        })
+        .is_err());
+
+        round_trip!(
+            ber,
+            EmbeddedPdv,
+            EmbeddedPdv {
+                identification: Identification::Syntax(syntax.clone()),
+                data_value: OctetString::from_static(&[0xab]),
+            },
+            b"\x2b\x08\xa0\x03\x81\x01\x2a\x82\x01\xab"
+        );
+        round_trip!(
+            ber,
+            CharacterString,
+            CharacterString {
+                identification: Identification::Syntax(syntax),
+                string_value: OctetString::from_static(&[0xab]),
+            },
+            b"\x3d\x08\xa0\x03\x81\x01\x2a\x82\x01\xab"
+        );
+    }
+
+    #[test]
+    fn test_time_types() {
+        round_trip!(
+            ber,
+            crate::types::IsoTime,
+            "2012-12-21T12:00Z".parse().unwrap(),
+            b"\x0e\x112012-12-21T12:00Z"
+        );
+        round_trip!(
+            ber,
+            crate::types::TimeOfDay,
+            chrono::NaiveTime::from_hms_opt(12, 30, 45).unwrap(),
+            b"\x1f\x20\x06123045"
+        );
+        round_trip!(
+            ber,
+            crate::types::DateTime,
+            NaiveDate::from_ymd_opt(2012, 12, 21)
+                .unwrap()
+                .and_hms_opt(12, 30, 45)
+                .unwrap(),
+            b"\x1f\x21\x0f20121221T123045"
+        );
+        round_trip!(
+            ber,
+            crate::types::IsoDuration,
+            "P1Y2M10DT2H30M".parse().unwrap(),
+            b"\x1f\x22\x0eP1Y2M10DT2H30M"
+        );
+    }
+
     #[test]
     fn test_extended_sequence() {
         use crate as rasn;
@@ -460,4 +677,92 @@ mod tests {
             &[0x30, 0x05, 0xA5, 0x03, 0x02, 0x01, 0x2A]
         );
     }
+
+    #[test]
+    #[cfg(feature = "f64")]
+    fn real() {
+        round_trip!(ber, f64, 0.0, &[0x09, 0x00]);
+        round_trip!(ber, f64, -0.0, &[0x09, 0x01, 0x43]);
+        round_trip!(ber, f64, f64::INFINITY, &[0x09, 0x01, 0x40]);
+        round_trip!(ber, f64, f64::NEG_INFINITY, &[0x09, 0x01, 0x41]);
+        round_trip!(ber, f64, 1.0, &[0x09, 0x03, 0x80, 0x00, 0x01]);
+        round_trip!(ber, f64, 0.5, &[0x09, 0x03, 0x80, 0xFF, 0x01]);
+        round_trip!(ber, f64, -2.5, &[0x09, 0x03, 0xC0, 0xFF, 0x05]);
+        round_trip!(
+            ber,
+            f64,
+            f64::MAX,
+            &[0x09, 0x0A, 0x81, 0x03, 0xCB, 0x1F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]
+        );
+        round_trip!(
+            ber,
+            f64,
+            f64::MIN_POSITIVE / 2.0,
+            &[0x09, 0x04, 0x81, 0xFC, 0x01, 0x01]
+        );
+        assert_eq!(encode(&f64::NAN).unwrap(), [0x09, 0x01, 0x42]);
+        assert!(decode::<f64>(&[0x09, 0x01, 0x42]).unwrap().is_nan());
+        assert!(decode::<f64>(&[0x09, 0x01, 0x43])
+            .unwrap()
+            .is_sign_negative());
+
+        // Bases 8 and 16, scaling factor and long form exponent.
+        decode_ok!(ber, f64, &[0x09, 0x03, 0x90, 0x01, 0x01], 8.0);
+        decode_ok!(ber, f64, &[0x09, 0x03, 0xA4, 0x01, 0x01], 32.0);
+        decode_ok!(ber, f64, &[0x09, 0x04, 0x83, 0x01, 0x02, 0x03], 12.0);
+        decode_ok!(ber, f64, &[0x09, 0x04, 0x80, 0x00, 0x00, 0x03], 3.0);
+        // ISO 6093 NR1, NR2 and NR3 decimal forms.
+        decode_ok!(ber, f64, b"\x09\x06\x01  123", 123.0);
+        decode_ok!(ber, f64, b"\x09\x05\x02-1,5", -1.5);
+        decode_ok!(ber, f64, b"\x09\x06\x0315E-1", 1.5);
+        decode_error!(ber, f64, b"\x09\x04\x011.5");
+        decode_error!(ber, f64, b"\x09\x04\x03inf");
+        // Out of range exponents
+        decode_ok!(
+            ber,
+            f64,
+            &[0x09, 0x04, 0x81, 0x7F, 0xFF, 0x01],
+            f64::INFINITY
+        );
+        decode_ok!(ber, f64, &[0x09, 0x04, 0x81, 0x80, 0x00, 0x01], 0.0);
+        // Missing mantissa and undefined special values
+        decode_error!(ber, f64, &[0x09, 0x02, 0x80, 0x01]);
+        decode_error!(ber, f64, &[0x09, 0x01, 0x44]);
+        decode_error!(ber, f64, &[0x09, 0x03, 0xB0, 0x01, 0x01]);
+    }
+
+    #[test]
+    #[cfg(feature = "f32")]
+    fn real_f32() {
+        round_trip!(ber, f32, 1.0, &[0x09, 0x03, 0x80, 0x00, 0x01]);
+        round_trip!(ber, f32, -0.75, &[0x09, 0x03, 0xC0, 0xFE, 0x03]);
+        round_trip!(
+            ber,
+            f32,
+            f32::MAX,
+            &[0x09, 0x05, 0x80, 0x68, 0xFF, 0xFF, 0xFF]
+        );
+        decode_ok!(
+            ber,
+            f32,
+            &[0x09, 0x04, 0x81, 0x01, 0x00, 0x01],
+            f32::INFINITY
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "f64")]
+    fn der_real() {
+        round_trip!(der, f64, 1.0, &[0x09, 0x03, 0x80, 0x00, 0x01]);
+        round_trip!(der, f64, -0.0, &[0x09, 0x01, 0x43]);
+        decode_ok!(der, f64, b"\x09\x06\x0315E-1", 1.5);
+        // Non-canonical base, scaling factor, even mantissa and padded
+        // exponent or mantissa are rejected.
+        decode_error!(der, f64, &[0x09, 0x03, 0x90, 0x01, 0x01]);
+        decode_error!(der, f64, &[0x09, 0x03, 0x84, 0x00, 0x01]);
+        decode_error!(der, f64, &[0x09, 0x03, 0x80, 0xFF, 0x02]);
+        decode_error!(der, f64, &[0x09, 0x04, 0x81, 0x00, 0x01, 0x01]);
+        decode_error!(der, f64, &[0x09, 0x04, 0x80, 0x00, 0x00, 0x01]);
+        decode_error!(der, f64, b"\x09\x05\x02-1,5");
+    }
 }
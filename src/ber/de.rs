@@ -2,21 +2,30 @@
 
 mod config;
 pub(super) mod parser;
+mod stream;
 
 use super::identifier::Identifier;
 use crate::{
+    de::{Decoder as _, Error as _},
     types::{
         self,
+        descriptor::{FieldDescriptor, TypeDescriptor, TypeKind, VariantDescriptor},
         oid::{MAX_OID_FIRST_OCTET, MAX_OID_SECOND_OCTET},
+        strings::StaticPermittedAlphabet,
+        validate,
+        value::{NamedValue, Value},
         Constraints, Enumerated, Tag,
     },
     Decode,
 };
 use alloc::{borrow::Cow, borrow::ToOwned, string::ToString, vec::Vec};
-use chrono::{DateTime, NaiveDate, NaiveDateTime};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime};
 use parser::ParseNumberError;
 
 pub use self::config::DecoderOptions;
+#[cfg(feature = "std")]
+pub use self::stream::ReadDecoder;
+pub use self::stream::StreamDecoder;
 
 pub use crate::error::DecodeError;
 pub use crate::error::{BerDecodeErrorKind, CodecDecodeError, DecodeErrorKind, DerDecodeErrorKind};
@@ -37,6 +46,45 @@ impl<'input> Decoder<'input> {
     pub fn codec(&self) -> crate::Codec {
         self.config.current_codec()
     }
+
+    /// Checks a constraint of the decoded value, if constraints are enforced.
+    fn enforce(
+        &self,
+        check: impl FnOnce() -> Result<(), crate::error::ConstraintViolationKind>,
+    ) -> Result<()> {
+        if self.config.enforce_constraints {
+            check().map_err(|kind| DecodeError::constraint_violation(kind, self.codec()))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn decode_integer_value<I: types::IntegerType>(&mut self, tag: Tag) -> Result<I> {
+        let primitive_bytes = self.parse_primitive_value(tag)?.1;
+        let integer_width = I::WIDTH as usize / 8;
+        if primitive_bytes.len() > integer_width {
+            // in the case of superfluous leading bytes (especially zeroes),
+            // we may still want to try to decode the integer even though
+            // the length is > integer width ...
+            let leading_byte = if primitive_bytes[0] & 0x80 == 0x80 {
+                0xFF
+            } else {
+                0x00
+            };
+            let input_iter = primitive_bytes
+                .iter()
+                .copied()
+                .skip_while(|n| *n == leading_byte);
+            let data_length = input_iter.clone().count();
+            I::try_from_bytes(
+                &primitive_bytes[primitive_bytes.len() - data_length..primitive_bytes.len()],
+                self.codec(),
+            )
+        } else {
+            I::try_from_bytes(primitive_bytes, self.codec())
+        }
+    }
+
     /// Returns reference to the remaining input data that has not been parsed.
     #[must_use]
     pub fn remaining(&self) -> &'input [u8] {
@@ -82,6 +130,52 @@ impl<'input> Decoder<'input> {
         }
     }
 
+    /// Parses the contents of an `OCTET STRING`, only copying them when the
+    /// value uses the constructed encoding.
+    fn parse_octet_string(&mut self, tag: Tag) -> Result<Cow<'input, [u8]>> {
+        let (identifier, contents) = self.parse_value(tag)?;
+
+        if identifier.is_primitive() {
+            match contents {
+                Some(c) => Ok(Cow::Borrowed(c)),
+                None => Err(BerDecodeErrorKind::IndefiniteLengthNotAllowed.into()),
+            }
+        } else if identifier.is_constructed() && self.config.encoding_rules.is_der() {
+            Err(DerDecodeErrorKind::ConstructedEncodingNotAllowed.into())
+        } else {
+            let mut buffer = Vec::new();
+
+            if let Some(mut contents) = contents {
+                while !contents.is_empty() {
+                    let (c, mut vec) = self::parser::parse_encoded_value(
+                        self.config,
+                        contents,
+                        Tag::OCTET_STRING,
+                        |input, _| Ok(alloc::vec::Vec::from(input)),
+                    )?;
+                    contents = c;
+
+                    buffer.append(&mut vec);
+                }
+            } else {
+                while !self.input.starts_with(EOC) {
+                    let (c, mut vec) = self::parser::parse_encoded_value(
+                        self.config,
+                        self.input,
+                        Tag::OCTET_STRING,
+                        |input, _| Ok(alloc::vec::Vec::from(input)),
+                    )?;
+                    self.input = c;
+
+                    buffer.append(&mut vec);
+                }
+
+                self.parse_eoc()?;
+            }
+            Ok(Cow::Owned(buffer))
+        }
+    }
+
     /// Parses a constructed ASN.1 value, checking the `tag`, and optionally
     /// checking if the identifier is marked as encoded. This should be true
     /// in all cases except explicit prefixes.
@@ -123,17 +217,184 @@ impl<'input> Decoder<'input> {
 
         Ok(result)
     }
+
+    /// Returns the tag of the next value without consuming it.
+    fn peek_tag(&self) -> Result<Tag> {
+        let (_, identifier) = parser::parse_identifier_octet(self.input).map_err(|e| match e {
+            ParseNumberError::Nom(e) => DecodeError::map_nom_err(e, self.codec()),
+            ParseNumberError::Overflow => DecodeError::integer_overflow(32u32, self.codec()),
+        })?;
+        Ok(identifier.tag)
+    }
+
+    /// Returns whether there are more values in the contents of a
+    /// constructed value, which end either with the input or with an
+    /// end-of-contents marker when using the indefinite length form.
+    fn has_more_values(&self) -> bool {
+        !self.input.is_empty() && !self.input.starts_with(EOC)
+    }
+
+    /// Returns whether a value of `descriptor` can start with `tag`, open
+    /// types can start with any tag.
+    fn matches_tag(descriptor: &TypeDescriptor, tag: Tag) -> bool {
+        matches!(descriptor.kind, TypeKind::Any) || descriptor.contains_tag(tag)
+    }
+
+    /// Decodes the components of a `SEQUENCE` value in order, skipping absent
+    /// `OPTIONAL` and `DEFAULT` components as well as unknown extension
+    /// additions.
+    fn decode_sequence_value<'d>(
+        &mut self,
+        fields: &[FieldDescriptor<'d>],
+        extended_fields: Option<&[FieldDescriptor<'d>]>,
+    ) -> Result<Vec<NamedValue<'d>>> {
+        let mut components = Vec::new();
+
+        for (index, field) in fields
+            .iter()
+            .chain(extended_fields.into_iter().flatten())
+            .enumerate()
+        {
+            let tag = if self.has_more_values() {
+                Some(self.peek_tag()?)
+            } else {
+                None
+            };
+
+            match tag {
+                Some(tag) if Self::matches_tag(field.ty, tag) => {
+                    let value = self.decode_value(field.ty).map_err(|error| {
+                        DecodeError::described_field_error(field.name, error, self.codec())
+                    })?;
+                    components.push(NamedValue::new(field.name, value));
+                }
+                _ if index >= fields.len() || field.presence.is_optional_or_default() => {}
+                Some(tag) => {
+                    return Err(DecodeError::missing_tag_class_or_value_in_sequence_or_set(
+                        tag.class,
+                        tag.value,
+                        self.codec(),
+                    ))
+                }
+                None => {
+                    return Err(DecodeError::described_missing_field(
+                        field.name,
+                        self.codec(),
+                    ))
+                }
+            }
+        }
+
+        if extended_fields.is_some() {
+            while self.has_more_values() {
+                self.decode_any()?;
+            }
+        }
+
+        Ok(components)
+    }
+
+    /// Decodes the components of a `SET` value in any order, returning them
+    /// in order of definition.
+    fn decode_set_value<'d>(
+        &mut self,
+        fields: &[FieldDescriptor<'d>],
+        extended_fields: Option<&[FieldDescriptor<'d>]>,
+    ) -> Result<Vec<NamedValue<'d>>> {
+        let all_fields = fields
+            .iter()
+            .chain(extended_fields.into_iter().flatten())
+            .collect::<Vec<_>>();
+        let mut values = alloc::vec![None; all_fields.len()];
+
+        while self.has_more_values() {
+            let tag = self.peek_tag()?;
+            match all_fields
+                .iter()
+                .position(|field| Self::matches_tag(field.ty, tag))
+            {
+                Some(index) if values[index].is_some() => {
+                    return Err(DecodeError::described_duplicate_field(
+                        all_fields[index].name,
+                        self.codec(),
+                    ))
+                }
+                Some(index) => {
+                    let field = all_fields[index];
+                    values[index] = Some(self.decode_value(field.ty).map_err(|error| {
+                        DecodeError::described_field_error(field.name, error, self.codec())
+                    })?);
+                }
+                None if extended_fields.is_some() => {
+                    self.decode_any()?;
+                }
+                None => return Err(DecodeError::unknown_field(values.len(), tag, self.codec())),
+            }
+        }
+
+        all_fields
+            .into_iter()
+            .zip(values)
+            .enumerate()
+            .filter_map(|(index, (field, value))| match value {
+                Some(value) => Some(Ok(NamedValue::new(field.name, value))),
+                None if index >= fields.len() || field.presence.is_optional_or_default() => None,
+                None => Some(Err(DecodeError::described_missing_field(
+                    field.name,
+                    self.codec(),
+                ))),
+            })
+            .collect()
+    }
+
+    /// Decodes an untagged `CHOICE` value, identifying the alternative by the
+    /// tag of the next value.
+    fn decode_choice_value<'d>(
+        &mut self,
+        descriptor: &TypeDescriptor<'d>,
+        variants: &[VariantDescriptor<'d>],
+        extended_variants: Option<&[VariantDescriptor<'d>]>,
+    ) -> Result<Value<'d>> {
+        let tag = self.peek_tag()?;
+        let variant = variants
+            .iter()
+            .chain(extended_variants.into_iter().flatten())
+            .find(|variant| Self::matches_tag(variant.ty, tag))
+            .ok_or_else(|| {
+                DecodeError::described_no_valid_choice(
+                    descriptor.identifier.unwrap_or("CHOICE"),
+                    self.codec(),
+                )
+            })?;
+
+        Ok(Value::Choice {
+            variant: variant.name,
+            value: alloc::boxed::Box::new(self.decode_value(variant.ty)?),
+        })
+    }
+
+    /// Decodes the elements of a `SEQUENCE OF` or `SET OF` value.
+    fn decode_sequence_of_value<'d>(
+        &mut self,
+        element: &TypeDescriptor<'d>,
+    ) -> Result<Vec<Value<'d>>> {
+        let mut elements = Vec::new();
+        while self.has_more_values() {
+            elements.push(self.decode_value(element)?);
+        }
+        Ok(elements)
+    }
+
     /// Decode an object identifier from a byte slice in BER format.
     /// Function is public to be used by other codecs.
     pub fn decode_object_identifier_from_bytes(
         &self,
         data: &[u8],
     ) -> Result<crate::types::ObjectIdentifier, DecodeError> {
-        let (mut contents, root_octets) =
-            parser::parse_base128_number(data).map_err(|e| match e {
-                ParseNumberError::Nom(e) => DecodeError::map_nom_err(e, self.codec()),
-                ParseNumberError::Overflow => DecodeError::integer_overflow(32u32, self.codec()),
-            })?;
+        let (contents, root_octets) = parser::parse_base128_number(data).map_err(|e| match e {
+            ParseNumberError::Nom(e) => DecodeError::map_nom_err(e, self.codec()),
+            ParseNumberError::Overflow => DecodeError::integer_overflow(32u32, self.codec()),
+        })?;
         let first: u32;
         let second: u32;
         const MAX_OID_THRESHOLD: u32 = MAX_OID_SECOND_OCTET + 1;
@@ -151,7 +412,37 @@ impl<'input> Decoder<'input> {
         let mut buffer = alloc::vec::Vec::with_capacity(core::cmp::min(contents.len() + 2, 16));
         buffer.push(first);
         buffer.push(second);
+        self.decode_arcs_from_bytes(contents, &mut buffer)?;
+
+        crate::types::ObjectIdentifier::new(buffer)
+            .ok_or_else(|| BerDecodeErrorKind::InvalidObjectIdentifier.into())
+    }
+
+    /// Decode an object identifier from a byte slice in BER format, keeping
+    /// the contents so arcs of any size are supported.
+    /// Function is public to be used by other codecs.
+    pub fn decode_encoded_oid_from_bytes(
+        &self,
+        data: &[u8],
+    ) -> Result<types::EncodedObjectIdentifier, DecodeError> {
+        types::EncodedObjectIdentifier::new(data.to_vec())
+            .ok_or_else(|| BerDecodeErrorKind::InvalidObjectIdentifier.into())
+    }
+
+    /// Decode a relative object identifier from a byte slice in BER format.
+    /// Function is public to be used by other codecs.
+    pub fn decode_relative_oid_from_bytes(
+        &self,
+        data: &[u8],
+    ) -> Result<crate::types::RelativeObjectIdentifier, DecodeError> {
+        let mut buffer = alloc::vec::Vec::with_capacity(core::cmp::min(data.len(), 16));
+        self.decode_arcs_from_bytes(data, &mut buffer)?;
+
+        crate::types::RelativeObjectIdentifier::new(buffer)
+            .ok_or_else(|| BerDecodeErrorKind::InvalidObjectIdentifier.into())
+    }
 
+    fn decode_arcs_from_bytes(&self, mut contents: &[u8], buffer: &mut Vec<u32>) -> Result<()> {
         while !contents.is_empty() {
             let (c, number) = parser::parse_base128_number(contents).map_err(|e| match e {
                 ParseNumberError::Nom(e) => DecodeError::map_nom_err(e, self.codec()),
@@ -160,8 +451,32 @@ impl<'input> Decoder<'input> {
             contents = c;
             buffer.push(number);
         }
-        crate::types::ObjectIdentifier::new(buffer)
-            .ok_or_else(|| BerDecodeErrorKind::InvalidObjectIdentifier.into())
+        Ok(())
+    }
+    /// Decode a real value from the contents octets of a REAL in BER format,
+    /// as defined in X.690 section 8.5. CER and DER only accept the canonical
+    /// forms of section 11.3.
+    /// Function is public to be used by other codecs.
+    pub fn decode_real_from_bytes<R: types::RealType>(&self, data: &[u8]) -> Result<R> {
+        let canonical = !self.config.encoding_rules.is_ber();
+        let value = match data {
+            [] => Some(0.0),
+            [super::REAL_PLUS_INFINITY] => return Ok(R::INFINITY),
+            [super::REAL_MINUS_INFINITY] => return Ok(R::NEG_INFINITY),
+            [super::REAL_NOT_A_NUMBER] => return Ok(R::NAN),
+            [super::REAL_MINUS_ZERO] => Some(-0.0),
+            [first, contents @ ..] if first & 0x80 != 0 => {
+                decode_binary_real(*first, contents, canonical)
+            }
+            [first, contents @ ..] if first & 0xC0 == 0 => {
+                decode_decimal_real(*first, contents, canonical)
+            }
+            _ => None,
+        };
+
+        value.and_then(R::try_from_float).ok_or_else(|| {
+            DecodeError::from_kind(DecodeErrorKind::InvalidRealEncoding, self.codec())
+        })
     }
     /// Parse any GeneralizedTime string, allowing for any from ASN.1 definition
     /// TODO, move to type itself?
@@ -310,6 +625,154 @@ impl<'input> Decoder<'input> {
 
         Ok(date)
     }
+
+    /// Parse a TIME string, which can be any ISO 8601 time value.
+    pub fn parse_time_string(string: &str) -> Result<types::IsoTime, DecodeError> {
+        let time = string
+            .parse()
+            .map_err(|_| BerDecodeErrorKind::invalid_date(string.to_string()))?;
+
+        Ok(time)
+    }
+
+    /// Parse a TIME-OF-DAY string in the basic format `HHMMSS`.
+    pub fn parse_time_of_day_string(string: &str) -> Result<types::TimeOfDay, DecodeError> {
+        let time = NaiveTime::parse_from_str(string, "%H%M%S")
+            .map_err(|_| BerDecodeErrorKind::invalid_date(string.to_string()))?;
+
+        Ok(time)
+    }
+
+    /// Parse a DATE-TIME string in the basic format `YYYYMMDDTHHMMSS`.
+    pub fn parse_date_time_string(string: &str) -> Result<types::DateTime, DecodeError> {
+        let date_time = NaiveDateTime::parse_from_str(string, "%Y%m%dT%H%M%S")
+            .map_err(|_| BerDecodeErrorKind::invalid_date(string.to_string()))?;
+
+        Ok(date_time)
+    }
+
+    /// Parse a DURATION string, such as `P1Y2M10DT2H30M`.
+    pub fn parse_duration_string(string: &str) -> Result<types::IsoDuration, DecodeError> {
+        let duration = string
+            .parse()
+            .map_err(|_| BerDecodeErrorKind::invalid_date(string.to_string()))?;
+
+        Ok(duration)
+    }
+}
+
+/// Decodes the X.690 8.5.7 binary encoding of a real value, `first` being the
+/// first contents octet.
+fn decode_binary_real(first: u8, contents: &[u8], canonical: bool) -> Option<f64> {
+    let base_bits: i64 = match (first >> 4) & 0b11 {
+        0b00 => 1,
+        0b01 => 3,
+        0b10 => 4,
+        _ => return None,
+    };
+    let scaling_factor = i64::from((first >> 2) & 0b11);
+    let (exponent_len, contents) = match first & 0b11 {
+        0b11 => {
+            let (&len, contents) = contents.split_first()?;
+            (usize::from(len), contents)
+        }
+        len => (usize::from(len) + 1, contents),
+    };
+    if exponent_len == 0 || contents.len() <= exponent_len {
+        return None;
+    }
+    let (exponent_bytes, mantissa_bytes) = contents.split_at(exponent_len);
+
+    if canonical {
+        // X.690 11.3.1, base 2 with an odd mantissa, and the exponent encoded
+        // in the fewest octets (8.5.7.4).
+        let redundant_exponent = exponent_len > 1
+            && matches!(
+                (exponent_bytes[0], exponent_bytes[1] & 0x80),
+                (0x00, 0x00) | (0xFF, 0x80)
+            );
+        if base_bits != 1
+            || scaling_factor != 0
+            || redundant_exponent
+            || mantissa_bytes[0] == 0
+            || mantissa_bytes[mantissa_bytes.len() - 1] & 1 == 0
+        {
+            return None;
+        }
+    }
+
+    // Exponents that don't fit are saturated, as they overflow or underflow
+    // any floating point type anyway.
+    let mut exponent: i64 = if exponent_bytes[0] & 0x80 == 0 { 0 } else { -1 };
+    for &byte in exponent_bytes {
+        exponent = exponent.saturating_mul(256).saturating_add(i64::from(byte));
+    }
+
+    let leading_zeros = mantissa_bytes.iter().take_while(|&&byte| byte == 0).count();
+    let mantissa_bytes = &mantissa_bytes[leading_zeros..];
+    // Only the 64 most significant bits of the mantissa are kept, which is
+    // more than any supported floating point type can represent.
+    let (head, tail) = mantissa_bytes.split_at(mantissa_bytes.len().min(8));
+    let mantissa = head
+        .iter()
+        .fold(0u64, |acc, &byte| (acc << 8) | u64::from(byte));
+    let exponent = exponent
+        .saturating_mul(base_bits)
+        .saturating_add(scaling_factor)
+        .saturating_add(tail.len() as i64 * 8);
+
+    let value = ldexp(mantissa as f64, exponent);
+    Some(if first & 0x40 == 0 { value } else { -value })
+}
+
+/// Decodes the X.690 8.5.8 decimal encoding of a real value, represented in
+/// one of the ISO 6093 numerical representations NR1, NR2 or NR3.
+fn decode_decimal_real(first: u8, contents: &[u8], canonical: bool) -> Option<f64> {
+    let form = first & 0x3F;
+    // X.690 11.3.2, CER and DER use the NR3 form.
+    if canonical && form != 3 {
+        return None;
+    }
+
+    let string = core::str::from_utf8(contents).ok()?.trim_start_matches(' ');
+    let has_mark = string.contains(['.', ',']);
+    let has_exponent = string.contains(['E', 'e']);
+    let valid_form = match form {
+        1 => !has_mark && !has_exponent,
+        2 => has_mark && !has_exponent,
+        3 => has_exponent,
+        _ => false,
+    };
+    if !valid_form
+        || !string
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, '+' | '-' | '.' | ',' | 'E' | 'e'))
+    {
+        return None;
+    }
+
+    string.replace(',', ".").parse().ok()
+}
+
+/// Computes `value * 2^exponent` without overflowing intermediate powers of two.
+fn ldexp(mut value: f64, mut exponent: i64) -> f64 {
+    // 2^e as a float, for exponents within the normal range.
+    fn pow2(exponent: i64) -> f64 {
+        f64::from_bits(((exponent + 1023) as u64) << 52)
+    }
+
+    while exponent > 1000 && value.is_finite() && value != 0.0 {
+        value *= pow2(1000);
+        exponent -= 1000;
+    }
+    while exponent < -1000 && value != 0.0 {
+        value *= pow2(-1000);
+        exponent += 1000;
+    }
+    if exponent.unsigned_abs() > 1000 {
+        return value;
+    }
+    value * pow2(exponent)
 }
 
 impl<'input> crate::Decoder for Decoder<'input> {
@@ -337,9 +800,7 @@ impl<'input> crate::Decoder for Decoder<'input> {
         let contents = &self.input[..diff];
         self.input = input;
 
-        Ok(types::Any {
-            contents: contents.to_vec(),
-        })
+        Ok(types::Any::new(contents.to_vec()))
     }
 
     fn decode_bool(&mut self, tag: Tag) -> Result<bool> {
@@ -365,86 +826,38 @@ impl<'input> crate::Decoder for Decoder<'input> {
             .ok_or_else(|| DecodeError::discriminant_value_not_found(discriminant, self.codec()))
     }
 
-    fn decode_integer<I: types::IntegerType>(&mut self, tag: Tag, _: Constraints) -> Result<I> {
-        let primitive_bytes = self.parse_primitive_value(tag)?.1;
-        let integer_width = I::WIDTH as usize / 8;
-        if primitive_bytes.len() > integer_width {
-            // in the case of superfluous leading bytes (especially zeroes),
-            // we may still want to try to decode the integer even though
-            // the length is > integer width ...
-            let leading_byte = if primitive_bytes[0] & 0x80 == 0x80 {
-                0xFF
-            } else {
-                0x00
-            };
-            let input_iter = primitive_bytes
-                .iter()
-                .copied()
-                .skip_while(|n| *n == leading_byte);
-            let data_length = input_iter.clone().count();
-            I::try_from_bytes(
-                &primitive_bytes[primitive_bytes.len() - data_length..primitive_bytes.len()],
-                self.codec(),
-            )
-        } else {
-            I::try_from_bytes(primitive_bytes, self.codec())
-        }
+    fn decode_integer<I: types::IntegerType>(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<I> {
+        let value = self.decode_integer_value(tag)?;
+        self.enforce(|| validate::check_value(&value, &constraints))?;
+
+        Ok(value)
     }
 
     fn decode_real<R: types::RealType>(
         &mut self,
-        _: Tag,
+        tag: Tag,
         _: Constraints,
     ) -> Result<R, Self::Error> {
-        Err(DecodeError::real_not_supported(self.codec()))
+        let contents = self.parse_primitive_value(tag)?.1;
+        self.decode_real_from_bytes(contents)
     }
 
     fn decode_octet_string<'b, T: From<&'b [u8]> + From<Vec<u8>>>(
         &'b mut self,
         tag: Tag,
-        _: Constraints,
+        constraints: Constraints,
     ) -> Result<T> {
-        let (identifier, contents) = self.parse_value(tag)?;
-
-        if identifier.is_primitive() {
-            match contents {
-                Some(c) => Ok(T::from(c)),
-                None => Err(BerDecodeErrorKind::IndefiniteLengthNotAllowed.into()),
-            }
-        } else if identifier.is_constructed() && self.config.encoding_rules.is_der() {
-            Err(DerDecodeErrorKind::ConstructedEncodingNotAllowed.into())
-        } else {
-            let mut buffer = Vec::new();
+        let contents = self.parse_octet_string(tag)?;
+        self.enforce(|| validate::check_size(contents.len(), &constraints))?;
 
-            if let Some(mut contents) = contents {
-                while !contents.is_empty() {
-                    let (c, mut vec) = self::parser::parse_encoded_value(
-                        self.config,
-                        contents,
-                        Tag::OCTET_STRING,
-                        |input, _| Ok(alloc::vec::Vec::from(input)),
-                    )?;
-                    contents = c;
-
-                    buffer.append(&mut vec);
-                }
-            } else {
-                while !self.input.starts_with(EOC) {
-                    let (c, mut vec) = self::parser::parse_encoded_value(
-                        self.config,
-                        self.input,
-                        Tag::OCTET_STRING,
-                        |input, _| Ok(alloc::vec::Vec::from(input)),
-                    )?;
-                    self.input = c;
-
-                    buffer.append(&mut vec);
-                }
-
-                self.parse_eoc()?;
-            }
-            Ok(T::from(buffer))
-        }
+        Ok(match contents {
+            Cow::Borrowed(contents) => T::from(contents),
+            Cow::Owned(contents) => T::from(contents),
+        })
     }
 
     fn decode_null(&mut self, tag: Tag) -> Result<()> {
@@ -458,7 +871,43 @@ impl<'input> crate::Decoder for Decoder<'input> {
         self.decode_object_identifier_from_bytes(contents)
     }
 
-    fn decode_bit_string(&mut self, tag: Tag, _: Constraints) -> Result<types::BitString> {
+    fn decode_encoded_oid(&mut self, tag: Tag) -> Result<types::EncodedObjectIdentifier> {
+        let contents = self.parse_primitive_value(tag)?.1;
+        self.decode_encoded_oid_from_bytes(contents)
+    }
+
+    fn decode_relative_oid(&mut self, tag: Tag) -> Result<types::RelativeObjectIdentifier> {
+        let contents = self.parse_primitive_value(tag)?.1;
+        self.decode_relative_oid_from_bytes(contents)
+    }
+
+    fn decode_oid_iri(&mut self, tag: Tag) -> Result<types::OidIri> {
+        let string = self.decode_utf8_string(tag, Constraints::default())?;
+        string.parse::<types::OidIri>().map_err(|error| {
+            DecodeError::string_conversion_failed(Tag::OID_IRI, error.to_string(), self.codec())
+        })
+    }
+
+    fn decode_relative_oid_iri(&mut self, tag: Tag) -> Result<types::RelativeOidIri> {
+        let string = self.decode_utf8_string(tag, Constraints::default())?;
+        string.parse::<types::RelativeOidIri>().map_err(|error| {
+            DecodeError::string_conversion_failed(
+                Tag::RELATIVE_OID_IRI,
+                error.to_string(),
+                self.codec(),
+            )
+        })
+    }
+
+    fn decode_external(&mut self, tag: Tag) -> Result<types::External> {
+        types::External::decode_legacy(self, tag)
+    }
+
+    fn decode_bit_string(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<types::BitString> {
         let (input, bs) =
             self::parser::parse_encoded_value(self.config, self.input, tag, |input, codec| {
                 let unused_bits = input
@@ -488,6 +937,8 @@ impl<'input> crate::Decoder for Decoder<'input> {
             })?;
 
         self.input = input;
+        self.enforce(|| validate::check_size(bs.len(), &constraints))?;
+
         Ok(bs)
     }
 
@@ -496,11 +947,14 @@ impl<'input> crate::Decoder for Decoder<'input> {
         tag: Tag,
         constraints: Constraints,
     ) -> Result<types::VisibleString, Self::Error> {
-        types::VisibleString::try_from(
-            self.decode_octet_string::<Cow<[u8]>>(tag, constraints)?
+        let string = types::VisibleString::try_from(
+            self.decode_octet_string::<Cow<[u8]>>(tag, Constraints::default())?
                 .as_ref(),
         )
-        .map_err(|e| DecodeError::permitted_alphabet_error(e, self.codec()))
+        .map_err(|e| DecodeError::permitted_alphabet_error(e, self.codec()))?;
+        self.enforce(|| validate::check_characters(|| string.chars(), &constraints))?;
+
+        Ok(string)
     }
 
     fn decode_ia5_string(
@@ -508,11 +962,14 @@ impl<'input> crate::Decoder for Decoder<'input> {
         tag: Tag,
         constraints: Constraints,
     ) -> Result<types::Ia5String> {
-        types::Ia5String::try_from(
-            self.decode_octet_string::<Cow<[u8]>>(tag, constraints)?
+        let string = types::Ia5String::try_from(
+            self.decode_octet_string::<Cow<[u8]>>(tag, Constraints::default())?
                 .as_ref(),
         )
-        .map_err(|e| DecodeError::permitted_alphabet_error(e, self.codec()))
+        .map_err(|e| DecodeError::permitted_alphabet_error(e, self.codec()))?;
+        self.enforce(|| validate::check_characters(|| string.chars(), &constraints))?;
+
+        Ok(string)
     }
 
     fn decode_printable_string(
@@ -520,11 +977,14 @@ impl<'input> crate::Decoder for Decoder<'input> {
         tag: Tag,
         constraints: Constraints,
     ) -> Result<types::PrintableString> {
-        types::PrintableString::try_from(
-            self.decode_octet_string::<Cow<[u8]>>(tag, constraints)?
+        let string = types::PrintableString::try_from(
+            self.decode_octet_string::<Cow<[u8]>>(tag, Constraints::default())?
                 .as_ref(),
         )
-        .map_err(|e| DecodeError::permitted_alphabet_error(e, self.codec()))
+        .map_err(|e| DecodeError::permitted_alphabet_error(e, self.codec()))?;
+        self.enforce(|| validate::check_characters(|| string.chars(), &constraints))?;
+
+        Ok(string)
     }
 
     fn decode_numeric_string(
@@ -532,11 +992,14 @@ impl<'input> crate::Decoder for Decoder<'input> {
         tag: Tag,
         constraints: Constraints,
     ) -> Result<types::NumericString> {
-        types::NumericString::try_from(
-            self.decode_octet_string::<Cow<[u8]>>(tag, constraints)?
+        let string = types::NumericString::try_from(
+            self.decode_octet_string::<Cow<[u8]>>(tag, Constraints::default())?
                 .as_ref(),
         )
-        .map_err(|e| DecodeError::permitted_alphabet_error(e, self.codec()))
+        .map_err(|e| DecodeError::permitted_alphabet_error(e, self.codec()))?;
+        self.enforce(|| validate::check_characters(|| string.chars(), &constraints))?;
+
+        Ok(string)
     }
 
     fn decode_teletex_string(
@@ -544,11 +1007,14 @@ impl<'input> crate::Decoder for Decoder<'input> {
         tag: Tag,
         constraints: Constraints,
     ) -> Result<types::TeletexString> {
-        types::TeletexString::try_from(
-            self.decode_octet_string::<Cow<[u8]>>(tag, constraints)?
+        let string = types::TeletexString::try_from(
+            self.decode_octet_string::<Cow<[u8]>>(tag, Constraints::default())?
                 .as_ref(),
         )
-        .map_err(|e| DecodeError::permitted_alphabet_error(e, self.codec()))
+        .map_err(|e| DecodeError::permitted_alphabet_error(e, self.codec()))?;
+        self.enforce(|| validate::check_characters(|| string.chars(), &constraints))?;
+
+        Ok(string)
     }
 
     fn decode_bmp_string(
@@ -556,11 +1022,14 @@ impl<'input> crate::Decoder for Decoder<'input> {
         tag: Tag,
         constraints: Constraints,
     ) -> Result<types::BmpString> {
-        types::BmpString::try_from(
-            self.decode_octet_string::<Cow<[u8]>>(tag, constraints)?
+        let string = types::BmpString::try_from(
+            self.decode_octet_string::<Cow<[u8]>>(tag, Constraints::default())?
                 .as_ref(),
         )
-        .map_err(|e| DecodeError::permitted_alphabet_error(e, self.codec()))
+        .map_err(|e| DecodeError::permitted_alphabet_error(e, self.codec()))?;
+        self.enforce(|| validate::check_characters(|| string.chars(), &constraints))?;
+
+        Ok(string)
     }
 
     fn decode_utf8_string(
@@ -568,14 +1037,19 @@ impl<'input> crate::Decoder for Decoder<'input> {
         tag: Tag,
         constraints: Constraints,
     ) -> Result<types::Utf8String> {
-        let vec = self.decode_octet_string(tag, constraints)?;
-        types::Utf8String::from_utf8(vec).map_err(|e| {
+        let vec = self.decode_octet_string(tag, Constraints::default())?;
+        let string = types::Utf8String::from_utf8(vec).map_err(|e| {
             DecodeError::string_conversion_failed(
                 types::Tag::UTF8_STRING,
                 e.to_string(),
                 self.codec(),
             )
-        })
+        })?;
+        self.enforce(|| {
+            validate::check_characters(|| string.chars().map(u32::from), &constraints)
+        })?;
+
+        Ok(string)
     }
 
     fn decode_general_string(
@@ -583,11 +1057,14 @@ impl<'input> crate::Decoder for Decoder<'input> {
         tag: Tag,
         constraints: Constraints,
     ) -> Result<types::GeneralString> {
-        <types::GeneralString>::try_from(
-            self.decode_octet_string::<Cow<[u8]>>(tag, constraints)?
+        let string = <types::GeneralString>::try_from(
+            self.decode_octet_string::<Cow<[u8]>>(tag, Constraints::default())?
                 .as_ref(),
         )
-        .map_err(|e| DecodeError::permitted_alphabet_error(e, self.codec()))
+        .map_err(|e| DecodeError::permitted_alphabet_error(e, self.codec()))?;
+        self.enforce(|| validate::check_characters(|| string.chars(), &constraints))?;
+
+        Ok(string)
     }
 
     fn decode_graphic_string(
@@ -595,11 +1072,14 @@ impl<'input> crate::Decoder for Decoder<'input> {
         tag: Tag,
         constraints: Constraints,
     ) -> Result<types::GraphicString> {
-        <types::GraphicString>::try_from(
-            self.decode_octet_string::<Cow<[u8]>>(tag, constraints)?
+        let string = <types::GraphicString>::try_from(
+            self.decode_octet_string::<Cow<[u8]>>(tag, Constraints::default())?
                 .as_ref(),
         )
-        .map_err(|e| DecodeError::permitted_alphabet_error(e, self.codec()))
+        .map_err(|e| DecodeError::permitted_alphabet_error(e, self.codec()))?;
+        self.enforce(|| validate::check_characters(|| string.chars(), &constraints))?;
+
+        Ok(string)
     }
 
     fn decode_generalized_time(&mut self, tag: Tag) -> Result<types::GeneralizedTime> {
@@ -626,12 +1106,32 @@ impl<'input> crate::Decoder for Decoder<'input> {
         Self::parse_date_string(&string)
     }
 
+    fn decode_time(&mut self, tag: Tag) -> Result<types::IsoTime> {
+        let string = self.decode_utf8_string(tag, Constraints::default())?;
+        Self::parse_time_string(&string)
+    }
+
+    fn decode_time_of_day(&mut self, tag: Tag) -> Result<types::TimeOfDay> {
+        let string = self.decode_utf8_string(tag, Constraints::default())?;
+        Self::parse_time_of_day_string(&string)
+    }
+
+    fn decode_date_time(&mut self, tag: Tag) -> Result<types::DateTime> {
+        let string = self.decode_utf8_string(tag, Constraints::default())?;
+        Self::parse_date_time_string(&string)
+    }
+
+    fn decode_duration(&mut self, tag: Tag) -> Result<types::IsoDuration> {
+        let string = self.decode_utf8_string(tag, Constraints::default())?;
+        Self::parse_duration_string(&string)
+    }
+
     fn decode_sequence_of<D: Decode>(
         &mut self,
         tag: Tag,
-        _: Constraints,
+        constraints: Constraints,
     ) -> Result<Vec<D>, Self::Error> {
-        self.parse_constructed_contents(tag, true, |decoder| {
+        let items = self.parse_constructed_contents(tag, true, |decoder| {
             let mut items = Vec::new();
 
             if decoder.input.is_empty() {
@@ -647,15 +1147,18 @@ impl<'input> crate::Decoder for Decoder<'input> {
             }
 
             Ok(items)
-        })
+        })?;
+        self.enforce(|| validate::check_size(items.len(), &constraints))?;
+
+        Ok(items)
     }
 
     fn decode_set_of<D: Decode + Eq + core::hash::Hash>(
         &mut self,
         tag: Tag,
-        _: Constraints,
+        constraints: Constraints,
     ) -> Result<types::SetOf<D>, Self::Error> {
-        self.parse_constructed_contents(tag, true, |decoder| {
+        let items = self.parse_constructed_contents(tag, true, |decoder| {
             let mut items = types::SetOf::new();
 
             while let Ok(item) = D::decode(decoder) {
@@ -663,7 +1166,10 @@ impl<'input> crate::Decoder for Decoder<'input> {
             }
 
             Ok(items)
-        })
+        })?;
+        self.enforce(|| validate::check_size(items.len(), &constraints))?;
+
+        Ok(items)
     }
 
     fn decode_sequence<
@@ -767,11 +1273,8 @@ impl<'input> crate::Decoder for Decoder<'input> {
     where
         D: crate::types::DecodeChoice,
     {
-        let (_, identifier) = parser::parse_identifier_octet(self.input).map_err(|e| match e {
-            ParseNumberError::Nom(e) => DecodeError::map_nom_err(e, self.codec()),
-            ParseNumberError::Overflow => DecodeError::integer_overflow(32u32, self.codec()),
-        })?;
-        D::from_tag(self, identifier.tag)
+        let tag = self.peek_tag()?;
+        D::from_tag(self, tag)
     }
 
     fn decode_extension_addition_with_explicit_tag_and_constraints<D>(
@@ -806,6 +1309,167 @@ impl<'input> crate::Decoder for Decoder<'input> {
     ) -> Result<Option<D>, Self::Error> {
         <Option<D>>::decode(self)
     }
+
+    fn decode_value<'d>(&mut self, descriptor: &TypeDescriptor<'d>) -> Result<Value<'d>> {
+        let descriptor = &descriptor.resolve();
+        match descriptor.kind {
+            TypeKind::Enumerated {
+                variants,
+                extended_variants,
+            } => {
+                let discriminant =
+                    self.decode_integer::<isize>(descriptor.tag, Constraints::default())?;
+                variants
+                    .iter()
+                    .chain(extended_variants.into_iter().flatten())
+                    .find(|variant| variant.discriminant == discriminant)
+                    .map(|variant| Value::Enumerated(variant.name))
+                    .ok_or_else(|| {
+                        DecodeError::discriminant_value_not_found(discriminant, self.codec())
+                    })
+            }
+            TypeKind::Sequence {
+                fields,
+                extended_fields,
+            } => self
+                .parse_constructed_contents(descriptor.tag, true, |decoder| {
+                    decoder.decode_sequence_value(fields, extended_fields)
+                })
+                .map(Value::Sequence),
+            TypeKind::Set {
+                fields,
+                extended_fields,
+            } => self
+                .parse_constructed_contents(descriptor.tag, true, |decoder| {
+                    decoder.decode_set_value(fields, extended_fields)
+                })
+                .map(Value::Set),
+            // A tagged `CHOICE` is always explicitly tagged.
+            TypeKind::Choice { .. } if descriptor.tag != Tag::EOC => self
+                .parse_constructed_contents(descriptor.tag, false, |decoder| {
+                    decoder.decode_value(&descriptor.with_tag(Tag::EOC))
+                }),
+            TypeKind::Choice {
+                variants,
+                extended_variants,
+            } => self.decode_choice_value(descriptor, variants, extended_variants),
+            TypeKind::SequenceOf { element } => self
+                .parse_constructed_contents(descriptor.tag, true, |decoder| {
+                    decoder.decode_sequence_of_value(element)
+                })
+                .map(Value::SequenceOf),
+            TypeKind::SetOf { element } => self
+                .parse_constructed_contents(descriptor.tag, true, |decoder| {
+                    decoder.decode_sequence_of_value(element)
+                })
+                .map(Value::SetOf),
+            TypeKind::Explicit { inner } => {
+                self.parse_constructed_contents(descriptor.tag, false, |decoder| {
+                    decoder.decode_value(inner)
+                })
+            }
+            _ => Value::decode_primitive(self, descriptor),
+        }
+    }
+}
+
+impl<'input> crate::de::BorrowDecoder<'input> for Decoder<'input> {
+    type AnyBorrowDecoder<const R: usize, const E: usize> = Decoder<'input>;
+
+    fn decode_octet_string_borrowed(
+        &mut self,
+        tag: Tag,
+        _: Constraints,
+    ) -> Result<Cow<'input, [u8]>> {
+        self.parse_octet_string(tag)
+    }
+
+    fn decode_utf8_string_borrowed(
+        &mut self,
+        tag: Tag,
+        _: Constraints,
+    ) -> Result<Cow<'input, str>> {
+        let bytes = self.parse_octet_string(tag)?;
+        types::strings::utf8_str_from_bytes(bytes).map_err(|e| {
+            DecodeError::string_conversion_failed(Tag::UTF8_STRING, e.to_string(), self.codec())
+        })
+    }
+
+    fn decode_ia5_string_borrowed(&mut self, tag: Tag, _: Constraints) -> Result<Cow<'input, str>> {
+        let bytes = self.parse_octet_string(tag)?;
+        types::strings::restricted_str_from_bytes::<types::Ia5String>(bytes)
+            .map_err(|e| DecodeError::permitted_alphabet_error(e, self.codec()))
+    }
+
+    fn decode_printable_string_borrowed(
+        &mut self,
+        tag: Tag,
+        _: Constraints,
+    ) -> Result<Cow<'input, str>> {
+        let bytes = self.parse_octet_string(tag)?;
+        types::strings::restricted_str_from_bytes::<types::PrintableString>(bytes)
+            .map_err(|e| DecodeError::permitted_alphabet_error(e, self.codec()))
+    }
+
+    fn decode_visible_string_borrowed(
+        &mut self,
+        tag: Tag,
+        _: Constraints,
+    ) -> Result<Cow<'input, str>> {
+        let bytes = self.parse_octet_string(tag)?;
+        types::strings::restricted_str_from_bytes::<types::VisibleString>(bytes)
+            .map_err(|e| DecodeError::permitted_alphabet_error(e, self.codec()))
+    }
+
+    fn decode_numeric_string_borrowed(
+        &mut self,
+        tag: Tag,
+        _: Constraints,
+    ) -> Result<Cow<'input, str>> {
+        let bytes = self.parse_octet_string(tag)?;
+        types::strings::restricted_str_from_bytes::<types::NumericString>(bytes)
+            .map_err(|e| DecodeError::permitted_alphabet_error(e, self.codec()))
+    }
+
+    fn decode_sequence_borrowed<const RC: usize, const EC: usize, D, DF, F>(
+        &mut self,
+        tag: Tag,
+        default_initializer_fn: Option<DF>,
+        decode_fn: F,
+    ) -> Result<D>
+    where
+        D: crate::types::Constructed<RC, EC>,
+        DF: FnOnce() -> D,
+        F: FnOnce(&mut Self) -> Result<D>,
+    {
+        crate::Decoder::decode_sequence(self, tag, default_initializer_fn, decode_fn)
+    }
+
+    fn decode_explicit_prefix_borrowed<D: crate::de::DecodeBorrowed<'input>>(
+        &mut self,
+        tag: Tag,
+    ) -> Result<D> {
+        self.parse_constructed_contents(tag, false, D::decode_borrowed)
+    }
+
+    fn decode_optional_with_explicit_prefix_borrowed<D: crate::de::DecodeBorrowed<'input>>(
+        &mut self,
+        tag: Tag,
+    ) -> Result<Option<D>> {
+        Ok(self.decode_explicit_prefix_borrowed(tag).ok())
+    }
+
+    fn decode_optional_with_tag_and_constraints_borrowed<D: crate::de::DecodeBorrowed<'input>>(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<Option<D>> {
+        if tag == Tag::EOC {
+            Ok(D::decode_borrowed(self).ok())
+        } else {
+            Ok(D::decode_borrowed_with_tag_and_constraints(self, tag, constraints).ok())
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1120,12 +1784,7 @@ mod tests {
     #[test]
     fn any() {
         let expected = &[0x1A, 0x05, 0x4A, 0x6F, 0x6E, 0x65, 0x73];
-        assert_eq!(
-            Any {
-                contents: expected.to_vec()
-            },
-            decode(expected).unwrap()
-        );
+        assert_eq!(Any::new(expected.to_vec()), decode(expected).unwrap());
     }
 
     #[test]
@@ -1134,12 +1793,7 @@ mod tests {
             0x30, 0x80, 0x2C, 0x80, 0x04, 0x03, 0x4A, 0x6F, 0x6E, 0x04, 0x02, 0x65, 0x73, 0x00,
             0x00, 0x00, 0x00,
         ];
-        assert_eq!(
-            Any {
-                contents: any.to_vec()
-            },
-            decode(any).unwrap(),
-        );
+        assert_eq!(Any::new(any.to_vec()), decode(any).unwrap(),);
     }
 
     #[test]
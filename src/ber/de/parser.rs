@@ -132,18 +132,13 @@ pub fn parse_base128_number(input: &[u8]) -> Result<(&[u8], u32), ParseNumberErr
     let (input, end) = nom::bytes::streaming::take(1usize)(input)?;
 
     let mut number = 0u32;
-    for byte in body {
-        number = match number.checked_shl(7) {
-            Some(n) => n,
-            None => return Err(ParseNumberError::Overflow),
-        };
-        number |= u32::from(*byte & 0x7F);
+    for byte in body.iter().chain(end) {
+        // Shifting would discard the highest bits of the number.
+        if number.leading_zeros() < 7 {
+            return Err(ParseNumberError::Overflow);
+        }
+        number = (number << 7) | u32::from(*byte & 0x7F);
     }
-    number = match number.checked_shl(7) {
-        Some(n) => n,
-        None => return Err(ParseNumberError::Overflow),
-    };
-    number |= u32::from(end[0]);
     Ok((input, number))
 }
 
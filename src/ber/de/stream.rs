@@ -0,0 +1,418 @@
+//! Incremental decoding of BER values from partially received input.
+
+use alloc::vec::Vec;
+
+#[cfg(any(test, feature = "std"))]
+use super::DecodeErrorKind;
+use super::{
+    parser::{self, ParseNumberError},
+    DecodeError, Decoder, DecoderOptions, Result,
+};
+use crate::{
+    de::{Error as _, Needed},
+    Decode,
+};
+
+/// A decoder for BER, CER, and DER values that arrive in pieces, such as
+/// LDAP or SNMP messages read off of a socket.
+///
+/// Bytes are handed to the decoder with [`StreamDecoder::feed`], and values
+/// are taken out with [`StreamDecoder::decode`]. When the buffered input
+/// does not yet hold a complete value, decoding fails with
+/// [`DecodeErrorKind::Incomplete`](crate::error::DecodeErrorKind::Incomplete), reporting how many more bytes are needed
+/// to make progress. For definite length values this is the exact number of
+/// bytes missing from the value, for indefinite length values it is the
+/// minimum number of bytes needed to read the next nested value.
+///
+/// The decoder remembers how far it has framed the current value, so
+/// feeding more input only parses the TLVs that were not yet complete,
+/// rather than re-trying the whole buffer.
+///
+/// ```
+/// use rasn::{ber::de::{DecoderOptions, StreamDecoder}, de::Needed, error::DecodeErrorKind};
+///
+/// let mut stream = StreamDecoder::new(DecoderOptions::ber());
+/// stream.feed(&[0x02, 0x02]);
+///
+/// let error = stream.decode::<u16>().unwrap_err();
+/// assert!(matches!(
+///     *error.kind,
+///     DecodeErrorKind::Incomplete { needed: Needed::Size(n) } if n.get() == 2
+/// ));
+///
+/// stream.feed(&[0x01, 0x00]);
+/// assert_eq!(256, stream.decode::<u16>().unwrap());
+/// ```
+#[derive(Debug, Clone)]
+pub struct StreamDecoder {
+    buffer: Vec<u8>,
+    /// Start of the current value in `buffer`.
+    start: usize,
+    /// Offset in `buffer` up to which the current value has been framed.
+    scanned: usize,
+    /// Number of indefinite length values still waiting for their
+    /// end-of-contents octets.
+    depth: usize,
+    config: DecoderOptions,
+}
+
+impl StreamDecoder {
+    /// Creates a new [`StreamDecoder`] with an empty buffer and the given
+    /// `config`.
+    #[must_use]
+    pub fn new(config: DecoderOptions) -> Self {
+        Self {
+            buffer: Vec::new(),
+            start: 0,
+            scanned: 0,
+            depth: 0,
+            config,
+        }
+    }
+
+    /// Return the current codec `Codec` variant
+    #[must_use]
+    pub fn codec(&self) -> crate::Codec {
+        self.config.current_codec()
+    }
+
+    /// Appends `bytes` to the end of the buffered input.
+    pub fn feed(&mut self, bytes: &[u8]) {
+        if self.start > 0 {
+            self.buffer.drain(..self.start);
+            self.scanned -= self.start;
+            self.start = 0;
+        }
+        self.buffer.extend_from_slice(bytes);
+    }
+
+    /// Returns the buffered input that has not been decoded yet.
+    #[must_use]
+    pub fn buffered(&self) -> &[u8] {
+        &self.buffer[self.start..]
+    }
+
+    /// Returns whether there is no buffered input left to decode.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.start == self.buffer.len()
+    }
+
+    /// Attempts to decode the next `T` from the buffered input.
+    ///
+    /// Returns [`DecodeErrorKind::Incomplete`](crate::error::DecodeErrorKind::Incomplete) if the buffer does not yet
+    /// contain the complete value, in which case nothing is consumed and the
+    /// call can be retried after feeding more input. If the value is complete
+    /// but can't be decoded as `T`, it is still consumed so that the values
+    /// following it can be decoded. Errors in the tag or length octets leave
+    /// the stream in a state it can't recover from, as the end of the value
+    /// is unknown.
+    pub fn decode<T: Decode>(&mut self) -> Result<T> {
+        let end = self.frame()?;
+        let input = &self.buffer[self.start..end];
+        self.start = end;
+        self.scanned = end;
+
+        let mut decoder = Decoder::new(input, self.config);
+        let value = T::decode(&mut decoder)?;
+
+        if decoder.remaining().is_empty() {
+            Ok(value)
+        } else {
+            Err(DecodeError::unexpected_extra_data(
+                decoder.remaining().len(),
+                self.codec(),
+            ))
+        }
+    }
+
+    /// Walks the TLVs of the current value starting from where the last call
+    /// stopped, returning the end offset of the value once it is complete.
+    fn frame(&mut self) -> Result<usize> {
+        loop {
+            let input = &self.buffer[self.scanned..];
+
+            if self.depth > 0 && input.first() == Some(&0) {
+                match input.get(1) {
+                    Some(0) => {
+                        self.scanned += 2;
+                        self.depth -= 1;
+                    }
+                    Some(_) => {
+                        return Err(DecodeError::parser_fail(
+                            "Invalid end-of-contents octets".into(),
+                            self.codec(),
+                        ))
+                    }
+                    None => {
+                        return Err(DecodeError::incomplete(Needed::new(1), self.codec()));
+                    }
+                }
+            } else {
+                let (rest, identifier) =
+                    parser::parse_identifier_octet(input).map_err(|e| match e {
+                        ParseNumberError::Nom(e) => DecodeError::map_nom_err(e, self.codec()),
+                        ParseNumberError::Overflow => {
+                            DecodeError::integer_overflow(32u32, self.codec())
+                        }
+                    })?;
+                let (rest, contents) = parser::parse_contents(self.config, identifier, rest)
+                    .map_err(|e| DecodeError::map_nom_err(e, self.codec()))?;
+
+                // Definite length values are skipped as a whole, only
+                // indefinite length values need their contents walked to
+                // find where they end.
+                if contents.is_none() {
+                    self.depth += 1;
+                }
+                self.scanned = self.buffer.len() - rest.len();
+            }
+
+            if self.depth == 0 {
+                return Ok(self.scanned);
+            }
+        }
+    }
+}
+
+/// A decoder for BER, CER, and DER values that pulls its input from a
+/// [`std::io::Read`] source as it is needed.
+///
+/// Reads are sized from the [`Needed`] reported by the underlying
+/// [`StreamDecoder`], and any bytes read past the end of a value are kept
+/// for decoding the next one.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Debug)]
+pub struct ReadDecoder<R> {
+    reader: R,
+    stream: StreamDecoder,
+}
+
+#[cfg(feature = "std")]
+impl<R: std::io::Read> ReadDecoder<R> {
+    /// The minimum number of bytes requested from the reader at once.
+    const READ_SIZE: usize = 4096;
+
+    /// Creates a new [`ReadDecoder`] reading from `reader` with the given
+    /// `config`.
+    pub fn new(reader: R, config: DecoderOptions) -> Self {
+        Self {
+            reader,
+            stream: StreamDecoder::new(config),
+        }
+    }
+
+    /// Returns a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Returns a mutable reference to the underlying reader.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    /// Returns the input that has been read but not decoded yet.
+    #[must_use]
+    pub fn buffered(&self) -> &[u8] {
+        self.stream.buffered()
+    }
+
+    /// Consumes the decoder, returning the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Decodes the next `T`, reading from the underlying reader until a
+    /// complete value is available.
+    ///
+    /// Returns `Ok(None)` if the reader is exhausted before any byte of a
+    /// new value has been read. If the reader is exhausted partway through
+    /// a value, the [`DecodeErrorKind::Incomplete`](crate::error::DecodeErrorKind::Incomplete) error describing the
+    /// missing input is returned.
+    pub fn decode<T: Decode>(&mut self) -> Result<Option<T>> {
+        let mut chunk = Vec::new();
+
+        loop {
+            let error = match self.stream.decode() {
+                Ok(value) => return Ok(Some(value)),
+                Err(error) => error,
+            };
+            let size = match *error.kind {
+                DecodeErrorKind::Incomplete {
+                    needed: Needed::Size(size),
+                } => size.get().max(Self::READ_SIZE),
+                DecodeErrorKind::Incomplete {
+                    needed: Needed::Unknown,
+                } => Self::READ_SIZE,
+                _ => return Err(error),
+            };
+            chunk.resize(size, 0);
+
+            let read = loop {
+                match self.reader.read(&mut chunk) {
+                    Ok(read) => break read,
+                    Err(error) if error.kind() == std::io::ErrorKind::Interrupted => {}
+                    Err(error) => return Err(DecodeError::io(error, self.stream.codec())),
+                }
+            };
+
+            if read == 0 {
+                return if self.stream.is_empty() {
+                    Ok(None)
+                } else {
+                    Err(error)
+                };
+            }
+
+            self.stream.feed(&chunk[..read]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::OctetString;
+
+    fn assert_needed(result: Result<impl core::fmt::Debug>, expected: usize) {
+        match *result.unwrap_err().kind {
+            DecodeErrorKind::Incomplete {
+                needed: Needed::Size(n),
+            } => assert_eq!(expected, n.get()),
+            kind => panic!("expected incomplete input, got: {kind}"),
+        }
+    }
+
+    #[test]
+    fn definite_length() {
+        let value = alloc::vec![
+            OctetString::from_static(b"hello"),
+            OctetString::from_static(b"world"),
+        ];
+        let encoded = crate::ber::encode(&value).unwrap();
+        let mut stream = StreamDecoder::new(DecoderOptions::ber());
+
+        assert_needed(stream.decode::<Vec<OctetString>>(), 1);
+        stream.feed(&encoded[..1]);
+        assert_needed(stream.decode::<Vec<OctetString>>(), 1);
+        stream.feed(&encoded[1..2]);
+        assert_needed(stream.decode::<Vec<OctetString>>(), 14);
+        stream.feed(&encoded[2..5]);
+        assert_needed(stream.decode::<Vec<OctetString>>(), 11);
+        stream.feed(&encoded[5..]);
+
+        assert_eq!(value, stream.decode::<Vec<OctetString>>().unwrap());
+        assert!(stream.is_empty());
+    }
+
+    #[test]
+    fn long_form_length() {
+        let value = OctetString::from(alloc::vec![0xAB; 300]);
+        let encoded = crate::ber::encode(&value).unwrap();
+        let mut stream = StreamDecoder::new(DecoderOptions::ber());
+
+        stream.feed(&encoded[..2]);
+        assert_needed(stream.decode::<OctetString>(), 2);
+        stream.feed(&encoded[2..4]);
+        assert_needed(stream.decode::<OctetString>(), 300);
+        stream.feed(&encoded[4..]);
+        assert_eq!(value, stream.decode::<OctetString>().unwrap());
+    }
+
+    #[test]
+    fn indefinite_length() {
+        let encoded = [
+            0x30, 0x80, 0x30, 0x80, 0x02, 0x01, 0x01, 0x00, 0x00, 0x30, 0x03, 0x02, 0x01, 0x02,
+            0x00, 0x00,
+        ];
+        let mut stream = StreamDecoder::new(DecoderOptions::ber());
+
+        for (i, byte) in encoded[..encoded.len() - 1].iter().enumerate() {
+            stream.feed(core::slice::from_ref(byte));
+            // Only the definite length inner sequence knows its full size
+            // once its header has been read.
+            let needed = match i {
+                10 => 3,
+                11 => 2,
+                _ => 1,
+            };
+            assert_needed(stream.decode::<Vec<Vec<u8>>>(), needed);
+        }
+        stream.feed(&encoded[encoded.len() - 1..]);
+
+        assert_eq!(
+            alloc::vec![alloc::vec![1], alloc::vec![2]],
+            stream.decode::<Vec<Vec<u8>>>().unwrap()
+        );
+        assert!(stream.is_empty());
+    }
+
+    #[test]
+    fn multiple_values() {
+        let mut stream = StreamDecoder::new(DecoderOptions::ber());
+        stream.feed(&[0x02, 0x01, 0x01, 0x01, 0x01, 0xFF, 0x02]);
+
+        assert_eq!(1, stream.decode::<u8>().unwrap());
+        assert!(stream.decode::<bool>().unwrap());
+        assert_needed(stream.decode::<u8>(), 1);
+        assert_eq!(&[0x02], stream.buffered());
+
+        stream.feed(&[0x01, 0x03]);
+        assert_eq!(3, stream.decode::<u8>().unwrap());
+        assert!(stream.is_empty());
+    }
+
+    #[test]
+    fn invalid_value_is_consumed() {
+        let mut stream = StreamDecoder::new(DecoderOptions::ber());
+        stream.feed(&[0x01, 0x01, 0xFF, 0x02, 0x01, 0x07]);
+
+        assert!(stream.decode::<u8>().is_err());
+        assert_eq!(7, stream.decode::<u8>().unwrap());
+    }
+
+    #[test]
+    fn der_rejects_indefinite_length() {
+        let mut stream = StreamDecoder::new(DecoderOptions::der());
+        stream.feed(&[0x30, 0x80]);
+
+        assert!(!matches!(
+            *stream.decode::<Vec<u8>>().unwrap_err().kind,
+            DecodeErrorKind::Incomplete { .. }
+        ));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn reader() {
+        /// Yields its input one byte per read.
+        struct Trickle<'a>(&'a [u8]);
+
+        impl std::io::Read for Trickle<'_> {
+            fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+                match self.0.split_first() {
+                    Some((byte, rest)) if !buf.is_empty() => {
+                        buf[0] = *byte;
+                        self.0 = rest;
+                        Ok(1)
+                    }
+                    _ => Ok(0),
+                }
+            }
+        }
+
+        let input = [
+            0x30, 0x80, 0x02, 0x01, 0x01, 0x00, 0x00, 0x30, 0x03, 0x02, 0x01, 0x02,
+        ];
+
+        let mut decoder = ReadDecoder::new(Trickle(&input), DecoderOptions::ber());
+        assert_eq!(Some(alloc::vec![1]), decoder.decode::<Vec<u8>>().unwrap());
+        assert_eq!(Some(alloc::vec![2]), decoder.decode::<Vec<u8>>().unwrap());
+        assert_eq!(None, decoder.decode::<Vec<u8>>().unwrap());
+
+        let mut decoder = ReadDecoder::new(&input[..4], DecoderOptions::ber());
+        assert_needed(decoder.decode::<Vec<u8>>(), 1);
+    }
+}
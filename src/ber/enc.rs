@@ -4,13 +4,19 @@ mod config;
 
 use alloc::{borrow::ToOwned, collections::VecDeque, string::ToString, vec::Vec};
 use chrono::Timelike;
+use num_traits::{float::FloatCore, Zero};
 
 use super::Identifier;
 use crate::{
     bits::octet_string_ascending,
+    enc::Sink,
     types::{
         self,
+        descriptor::{TypeDescriptor, TypeKind},
         oid::{MAX_OID_FIRST_OCTET, MAX_OID_SECOND_OCTET},
+        strings::StaticPermittedAlphabet,
+        validate,
+        value::Value,
         Constraints, Enumerated, IntegerType, Tag,
     },
     Codec, Encode,
@@ -23,11 +29,34 @@ const START_OF_CONTENTS: u8 = 0x80;
 const END_OF_CONTENTS: &[u8] = &[0, 0];
 
 /// Encodes Rust structures into Basic Encoding Rules data.
-pub struct Encoder {
+///
+/// `S` is the [`Sink`] the encoding is written into by
+/// [`Encoder::encode_to_sink`], other encoders write into their own buffer.
+pub struct Encoder<S = Vec<u8>> {
     output: Vec<u8>,
     config: EncoderOptions,
     is_set_encoding: bool,
     set_buffer: alloc::collections::BTreeMap<Tag, Vec<u8>>,
+    pass: Pass<S>,
+}
+
+/// How the encoder produces the contents of constructed values.
+enum Pass<S> {
+    /// Each constructed value is encoded into a temporary buffer, which is
+    /// then copied into its parent after the length.
+    Buffered,
+    /// Nothing is written, only the number of bytes is counted, and the
+    /// contents length of each constructed value is recorded in the order
+    /// they start.
+    Measure { written: usize, lengths: Vec<usize> },
+    /// Everything is written directly into the `sink`, each constructed
+    /// value after the length recorded by a previous [`Pass::Measure`].
+    Write {
+        sink: S,
+        written: usize,
+        lengths: Vec<usize>,
+        next: usize,
+    },
 }
 
 /// A convenience type around results needing to return one or many bytes.
@@ -40,30 +69,14 @@ impl Encoder {
     /// Creates a new instance from the given `config`.
     #[must_use]
     pub fn new(config: EncoderOptions) -> Self {
-        Self {
-            config,
-            is_set_encoding: false,
-            output: <_>::default(),
-            set_buffer: <_>::default(),
-        }
-    }
-
-    /// Returns the currently selected codec.
-    #[must_use]
-    pub fn codec(&self) -> crate::Codec {
-        self.config.current_codec()
+        Self::buffered(config, false)
     }
 
     /// Creates a new instance from the given `config`, and uses SET encoding
     /// logic, ensuring that all messages are encoded in order by tag.
     #[must_use]
     pub fn new_set(config: EncoderOptions) -> Self {
-        Self {
-            config,
-            is_set_encoding: true,
-            output: <_>::default(),
-            set_buffer: <_>::default(),
-        }
+        Self::buffered(config, true)
     }
 
     /// Creates a new instance from the given `config` and a user-supplied
@@ -78,12 +91,138 @@ impl Encoder {
             config,
             is_set_encoding: false,
             set_buffer: <_>::default(),
+            pass: Pass::Buffered,
+        }
+    }
+
+    /// Runs the first pass of [`Encoder::encode_two_pass`], returning the
+    /// number of bytes `value` encodes to and the contents length of each of
+    /// its constructed values.
+    fn measure<E: Encode>(
+        config: EncoderOptions,
+        value: &E,
+    ) -> Result<(usize, Vec<usize>), EncodeError> {
+        let mut encoder = Self {
+            pass: Pass::Measure {
+                written: 0,
+                lengths: Vec::new(),
+            },
+            ..Self::new(config)
+        };
+        value.encode(&mut encoder)?;
+        match encoder.pass {
+            Pass::Measure { written, lengths } => Ok((written, lengths)),
+            _ => unreachable!(),
         }
     }
 
+    /// Returns the number of bytes `value` encodes to with the given
+    /// `config`, without producing the encoding.
+    ///
+    /// # Errors
+    /// Returns `EncodeError` if `value` cannot be encoded.
+    pub fn encoded_len<E: Encode>(config: EncoderOptions, value: &E) -> Result<usize, EncodeError> {
+        Self::measure(config, value).map(|(written, _)| written)
+    }
+
+    /// Encodes `value` with the given `config` in two passes. The first pass
+    /// only measures the length of every constructed value, which lets the
+    /// second pass write each value directly after its length into a buffer
+    /// of exactly the right size, instead of encoding the contents into a
+    /// temporary buffer and copying it into the parent, as [`Encoder::new`]
+    /// does. This is faster for large and deeply nested values.
+    ///
+    /// `SET` and `SET OF` values are still encoded into temporary buffers,
+    /// as their components have to be sorted.
+    ///
+    /// # Errors
+    /// Returns `EncodeError` if `value` cannot be encoded, or if it encodes
+    /// differently in the second pass.
+    pub fn encode_two_pass<E: Encode>(
+        config: EncoderOptions,
+        value: &E,
+    ) -> Result<Vec<u8>, EncodeError> {
+        let (written, _) = Self::measure(config, value)?;
+        let mut output = Vec::with_capacity(written);
+        Self::encode_to_sink(config, value, &mut output)?;
+        Ok(output)
+    }
+
+    /// Encodes `value` with the given `config` directly into `sink` in the
+    /// same two passes as [`Encoder::encode_two_pass`], returning the number
+    /// of bytes written. CER encodes constructed values with indefinite
+    /// lengths, and is written in a single pass.
+    ///
+    /// If encoding fails, the bytes written into `sink` up to that point are
+    /// left there.
+    ///
+    /// # Errors
+    /// Returns `EncodeError` if `value` cannot be encoded, or if writing into
+    /// `sink` fails.
+    pub fn encode_to_sink<E: Encode, S: Sink>(
+        config: EncoderOptions,
+        value: &E,
+        sink: S,
+    ) -> Result<usize, EncodeError> {
+        let lengths = if config.encoding_rules.is_cer() {
+            Vec::new()
+        } else {
+            Self::measure(config, value)?.1
+        };
+
+        let mut encoder = Encoder {
+            pass: Pass::Write {
+                sink,
+                written: 0,
+                lengths,
+                next: 0,
+            },
+            ..Encoder::buffered(config, false)
+        };
+        value.encode(&mut encoder)?;
+        Ok(encoder.position())
+    }
+
     /// Consumes the encoder and returns the output of the encoding.
     #[must_use]
     pub fn output(self) -> Vec<u8> {
+        self.take_output()
+    }
+}
+
+impl<S: Sink> Encoder<S> {
+    /// Creates an instance encoding into its own buffer.
+    fn buffered(config: EncoderOptions, is_set_encoding: bool) -> Self {
+        Self {
+            config,
+            is_set_encoding,
+            output: <_>::default(),
+            set_buffer: <_>::default(),
+            pass: Pass::Buffered,
+        }
+    }
+
+    /// Returns the currently selected codec.
+    #[must_use]
+    pub fn codec(&self) -> crate::Codec {
+        self.config.current_codec()
+    }
+
+    /// Checks a constraint of the value being encoded, if constraints are
+    /// enforced.
+    fn enforce(
+        &self,
+        check: impl FnOnce() -> Result<(), crate::error::ConstraintViolationKind>,
+    ) -> Result<(), EncodeError> {
+        if self.config.enforce_constraints {
+            check().map_err(|kind| EncodeError::constraint_violation(kind, self.codec()))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Returns the output of a buffered encoder.
+    fn take_output(self) -> Vec<u8> {
         if self.is_set_encoding {
             self.set_buffer
                 .into_values()
@@ -96,10 +235,35 @@ impl Encoder {
         }
     }
 
-    fn append_byte_or_bytes(&mut self, bytes: ByteOrBytes) {
+    fn append_byte_or_bytes(&mut self, bytes: ByteOrBytes) -> Result<(), EncodeError> {
         match bytes {
-            ByteOrBytes::Single(b) => self.output.push(b),
-            ByteOrBytes::Many(mut bs) => self.output.append(&mut bs),
+            ByteOrBytes::Single(b) => self.write_byte(b),
+            ByteOrBytes::Many(bs) => self.write_bytes(&bs),
+        }
+    }
+
+    fn write_byte(&mut self, byte: u8) -> Result<(), EncodeError> {
+        self.write_bytes(&[byte])
+    }
+
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), EncodeError> {
+        let codec = self.codec();
+        match &mut self.pass {
+            Pass::Buffered => self.output.extend_from_slice(bytes),
+            Pass::Measure { written, .. } => *written += bytes.len(),
+            Pass::Write { sink, written, .. } => {
+                sink.write(bytes, codec)?;
+                *written += bytes.len();
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the number of bytes written so far.
+    fn position(&self) -> usize {
+        match &self.pass {
+            Pass::Buffered => self.output.len(),
+            Pass::Measure { written, .. } | Pass::Write { written, .. } => *written,
         }
     }
 
@@ -168,16 +332,17 @@ impl Encoder {
         }
     }
 
-    fn encode_length(&mut self, identifier: Identifier, value: &[u8]) {
+    fn encode_length(&mut self, identifier: Identifier, value: &[u8]) -> Result<(), EncodeError> {
         if identifier.is_primitive() || !self.config.encoding_rules.is_cer() {
             let len_bytes = self.encode_definite_length(value.len());
-            self.append_byte_or_bytes(len_bytes);
-            self.output.extend_from_slice(value);
+            self.append_byte_or_bytes(len_bytes)?;
+            self.write_bytes(value)?;
         } else {
-            self.output.push(START_OF_CONTENTS);
-            self.output.extend_from_slice(value);
-            self.output.extend_from_slice(END_OF_CONTENTS);
+            self.write_byte(START_OF_CONTENTS)?;
+            self.write_bytes(value)?;
+            self.write_bytes(END_OF_CONTENTS)?;
         }
+        Ok(())
     }
 
     fn encode_definite_length(&mut self, len: usize) -> ByteOrBytes {
@@ -216,37 +381,97 @@ impl Encoder {
 
         if value.len() > max_string_length {
             let ident_bytes = self.encode_identifier(Identifier::from_tag(tag, true));
-            self.append_byte_or_bytes(ident_bytes);
+            self.append_byte_or_bytes(ident_bytes)?;
 
-            self.output.push(START_OF_CONTENTS);
+            self.write_byte(START_OF_CONTENTS)?;
 
             for chunk in value.chunks(max_string_length) {
-                self.encode_primitive(nested_tag, chunk);
+                self.encode_primitive(nested_tag, chunk)?;
             }
 
-            self.output.extend_from_slice(END_OF_CONTENTS);
+            self.write_bytes(END_OF_CONTENTS)?;
             self.encode_to_set(tag);
         } else {
-            self.encode_primitive(tag, value);
+            self.encode_primitive(tag, value)?;
         }
 
         Ok(())
     }
 
-    fn encode_primitive(&mut self, tag: Tag, value: &[u8]) {
-        self.encode_value(Identifier::from_tag(tag, false), value);
+    fn encode_primitive(&mut self, tag: Tag, value: &[u8]) -> Result<(), EncodeError> {
+        self.encode_tlv(Identifier::from_tag(tag, false), value)
     }
 
-    fn encode_constructed(&mut self, tag: Tag, value: &[u8]) {
-        self.encode_value(Identifier::from_tag(tag, true), value);
+    fn encode_constructed(&mut self, tag: Tag, value: &[u8]) -> Result<(), EncodeError> {
+        self.encode_tlv(Identifier::from_tag(tag, true), value)
+    }
+
+    /// Encodes a constructed value with the `tag`, whose contents are
+    /// encoded by `encode_fn`.
+    fn encode_constructed_with(
+        &mut self,
+        tag: Tag,
+        encode_fn: impl FnOnce(&mut Self) -> Result<(), EncodeError>,
+    ) -> Result<(), EncodeError> {
+        if let Pass::Buffered = self.pass {
+            let mut encoder = Self::buffered(self.config, false);
+            (encode_fn)(&mut encoder)?;
+            self.encode_constructed(tag, &encoder.output)?;
+            return Ok(());
+        }
+
+        let ident_bytes = self.encode_identifier(Identifier::from_tag(tag, true));
+        self.append_byte_or_bytes(ident_bytes)?;
+
+        if self.config.encoding_rules.is_cer() {
+            self.write_byte(START_OF_CONTENTS)?;
+            (encode_fn)(self)?;
+            self.write_bytes(END_OF_CONTENTS)?;
+            return Ok(());
+        }
+
+        match &mut self.pass {
+            Pass::Measure { lengths, .. } => {
+                let index = lengths.len();
+                lengths.push(0);
+                let start = self.position();
+                (encode_fn)(self)?;
+                let length = self.position() - start;
+                if let Pass::Measure { lengths, .. } = &mut self.pass {
+                    lengths[index] = length;
+                }
+                // The length octets follow the identifier rather than the
+                // contents, but only their count matters when measuring.
+                let len_bytes = self.encode_definite_length(length);
+                self.append_byte_or_bytes(len_bytes)?;
+            }
+            Pass::Write { lengths, next, .. } => {
+                let measured = *lengths
+                    .get(*next)
+                    .ok_or(BerEncodeErrorKind::UnmeasuredValue)?;
+                *next += 1;
+                let len_bytes = self.encode_definite_length(measured);
+                self.append_byte_or_bytes(len_bytes)?;
+                let start = self.position();
+                (encode_fn)(self)?;
+                let written = self.position() - start;
+                if written != measured {
+                    return Err(BerEncodeErrorKind::InconsistentLength { measured, written }.into());
+                }
+            }
+            Pass::Buffered => unreachable!(),
+        }
+
+        Ok(())
     }
 
     /// Encodes a given ASN.1 BER value with the `identifier`.
-    fn encode_value(&mut self, identifier: Identifier, value: &[u8]) {
+    fn encode_tlv(&mut self, identifier: Identifier, value: &[u8]) -> Result<(), EncodeError> {
         let ident_bytes = self.encode_identifier(identifier);
-        self.append_byte_or_bytes(ident_bytes);
-        self.encode_length(identifier, value);
+        self.append_byte_or_bytes(ident_bytes)?;
+        self.encode_length(identifier, value)?;
         self.encode_to_set(identifier.tag);
+        Ok(())
     }
 
     /// Runs at the end of a complete value encoding to decide whether to sort
@@ -272,11 +497,29 @@ impl Encoder {
             return Err(BerEncodeErrorKind::invalid_object_identifier(oid.to_owned()).into());
         }
         self.encode_as_base128((first * (MAX_OID_SECOND_OCTET + 1)) + second, &mut bytes);
-        for component in oid.iter().skip(2) {
-            self.encode_as_base128(*component, &mut bytes);
+        self.encode_arcs_as_base128(&oid[2..], &mut bytes);
+        Ok(bytes)
+    }
+
+    /// Converts a relative object identifier into a byte vector in BER format.
+    /// Reusable function by other codecs.
+    pub fn relative_oid_as_bytes(&mut self, arcs: &[u32]) -> Result<Vec<u8>, EncodeError> {
+        if arcs.is_empty() {
+            return Err(BerEncodeErrorKind::invalid_object_identifier(arcs.to_owned()).into());
         }
+        let mut bytes = Vec::new();
+        self.encode_arcs_as_base128(arcs, &mut bytes);
         Ok(bytes)
     }
+
+    fn encode_arcs_as_base128(&self, arcs: &[u32], buffer: &mut Vec<u8>) {
+        for arc in arcs {
+            self.encode_as_base128(*arc, buffer);
+        }
+    }
+}
+
+impl Encoder {
     #[must_use]
     /// Canonical byte presentation for CER/DER as defined in X.690 section 11.7.
     /// Also used for BER on this crate.
@@ -316,12 +559,89 @@ impl Encoder {
     pub fn naivedate_to_date_bytes(value: &chrono::NaiveDate) -> Vec<u8> {
         value.format("%Y%m%d").to_string().into_bytes()
     }
+
+    /// Byte presentation for TIME-OF-DAY in the basic format `HHMMSS`.
+    ///
+    /// # Errors
+    /// Returns an error for `codec` if the time has a fraction of a second.
+    pub fn naivetime_to_time_of_day_bytes(
+        value: &chrono::NaiveTime,
+        codec: Codec,
+    ) -> Result<Vec<u8>, EncodeError> {
+        types::time::whole_seconds(value, codec)?;
+        Ok(value.format("%H%M%S").to_string().into_bytes())
+    }
+
+    /// Byte presentation for DATE-TIME in the basic format `YYYYMMDDTHHMMSS`.
+    ///
+    /// # Errors
+    /// Returns an error for `codec` if the time has a fraction of a second.
+    pub fn naivedatetime_to_date_time_bytes(
+        value: &chrono::NaiveDateTime,
+        codec: Codec,
+    ) -> Result<Vec<u8>, EncodeError> {
+        types::time::whole_seconds(&value.time(), codec)?;
+        Ok(value.format("%Y%m%dT%H%M%S").to_string().into_bytes())
+    }
+
+    #[must_use]
+    /// Canonical byte presentation for CER/DER REAL as defined in X.690 section 11.3.1,
+    /// using the binary encoding with base 2 and a mantissa that is either zero or odd.
+    /// Also used for BER and PER on this crate.
+    ///
+    /// Returns `None` if the value can't be represented as a floating point number.
+    pub fn real_to_canonical_bytes<R: types::RealType>(value: &R) -> Option<Vec<u8>> {
+        let value = value.try_to_float()?;
+
+        if value.is_nan() {
+            return Some(alloc::vec![super::REAL_NOT_A_NUMBER]);
+        } else if value.is_infinite() {
+            return Some(alloc::vec![if value.is_sign_negative() {
+                super::REAL_MINUS_INFINITY
+            } else {
+                super::REAL_PLUS_INFINITY
+            }]);
+        } else if value.is_zero() {
+            // Positive zero has no contents octets, X.690 8.5.2
+            return Some(if value.is_sign_negative() {
+                alloc::vec![super::REAL_MINUS_ZERO]
+            } else {
+                Vec::new()
+            });
+        }
+
+        let (mantissa, exponent, sign) = value.integer_decode();
+        let shift = mantissa.trailing_zeros();
+        let mantissa = mantissa >> shift;
+        let exponent = i32::from(exponent) + shift as i32;
+
+        let (exponent_bytes, exponent_len) = exponent.to_signed_bytes_be();
+        let (mantissa_bytes, mantissa_len) = mantissa.to_unsigned_bytes_be();
+
+        // X.690 8.5.7, binary encoding with base 2 and scaling factor of zero.
+        let mut first_octet = 0x80;
+        if sign < 0 {
+            first_octet |= 0x40;
+        }
+        let mut bytes = Vec::with_capacity(3 + exponent_len + mantissa_len);
+        match exponent_len {
+            1..=3 => bytes.push(first_octet | (exponent_len as u8 - 1)),
+            _ => {
+                bytes.push(first_octet | 0b11);
+                bytes.push(exponent_len as u8);
+            }
+        }
+        bytes.extend_from_slice(&exponent_bytes.as_ref()[..exponent_len]);
+        bytes.extend_from_slice(&mantissa_bytes.as_ref()[..mantissa_len]);
+
+        Some(bytes)
+    }
 }
 
-impl crate::Encoder<'_> for Encoder {
+impl<S: Sink> crate::Encoder<'_> for Encoder<S> {
     type Ok = ();
     type Error = EncodeError;
-    type AnyEncoder<'this, const R: usize, const E: usize> = Encoder;
+    type AnyEncoder<'this, const R: usize, const E: usize> = Encoder<S>;
 
     fn codec(&self) -> Codec {
         Self::codec(self)
@@ -337,7 +657,7 @@ impl crate::Encoder<'_> for Encoder {
             return Err(BerEncodeErrorKind::AnyInSet.into());
         }
 
-        self.output.extend_from_slice(&value.contents);
+        self.write_bytes(&value.contents)?;
 
         Ok(())
     }
@@ -345,10 +665,11 @@ impl crate::Encoder<'_> for Encoder {
     fn encode_bit_string(
         &mut self,
         tag: Tag,
-        _constraints: Constraints,
+        constraints: Constraints,
         value: &types::BitStr,
         _: crate::types::Identifier,
     ) -> Result<Self::Ok, Self::Error> {
+        self.enforce(|| validate::check_size(value.len(), &constraints))?;
         let bit_length = value.len();
         let vec = value.to_bitvec();
         let bytes = vec.as_raw_slice();
@@ -371,7 +692,7 @@ impl crate::Encoder<'_> for Encoder {
         value: bool,
         _: crate::types::Identifier,
     ) -> Result<Self::Ok, Self::Error> {
-        self.encode_primitive(tag, &[if value { 0xff } else { 0x00 }]);
+        self.encode_primitive(tag, &[if value { 0xff } else { 0x00 }])?;
         Ok(())
     }
 
@@ -403,23 +724,27 @@ impl crate::Encoder<'_> for Encoder {
     fn encode_integer<I: IntegerType>(
         &mut self,
         tag: Tag,
-        _constraints: Constraints,
+        constraints: Constraints,
         value: &I,
         _: crate::types::Identifier,
     ) -> Result<Self::Ok, Self::Error> {
+        self.enforce(|| validate::check_value(value, &constraints))?;
         let (bytes, needed) = value.to_signed_bytes_be();
-        self.encode_primitive(tag, &bytes.as_ref()[..needed]);
+        self.encode_primitive(tag, &bytes.as_ref()[..needed])?;
         Ok(())
     }
 
     fn encode_real<R: types::RealType>(
         &mut self,
-        _: Tag,
+        tag: Tag,
         _: Constraints,
-        _: &R,
+        value: &R,
         _: crate::types::Identifier,
     ) -> Result<Self::Ok, Self::Error> {
-        Err(EncodeError::real_not_supported(self.codec()))
+        let bytes = Encoder::real_to_canonical_bytes(value)
+            .ok_or_else(|| EncodeError::real_not_supported(self.codec()))?;
+        self.encode_primitive(tag, &bytes)?;
+        Ok(())
     }
 
     fn encode_null(
@@ -427,7 +752,7 @@ impl crate::Encoder<'_> for Encoder {
         tag: Tag,
         _: crate::types::Identifier,
     ) -> Result<Self::Ok, Self::Error> {
-        self.encode_primitive(tag, &[]);
+        self.encode_primitive(tag, &[])?;
         Ok(())
     }
 
@@ -438,107 +763,167 @@ impl crate::Encoder<'_> for Encoder {
         _: crate::types::Identifier,
     ) -> Result<Self::Ok, Self::Error> {
         let bytes = self.object_identifier_as_bytes(oid)?;
-        self.encode_primitive(tag, &bytes);
+        self.encode_primitive(tag, &bytes)?;
         Ok(())
     }
 
+    fn encode_encoded_oid(
+        &mut self,
+        tag: Tag,
+        value: &types::EncodedOid,
+        _: crate::types::Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_primitive(tag, value.as_bytes())?;
+        Ok(())
+    }
+
+    fn encode_relative_oid(
+        &mut self,
+        tag: Tag,
+        value: &[u32],
+        _: crate::types::Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        let bytes = self.relative_oid_as_bytes(value)?;
+        self.encode_primitive(tag, &bytes)?;
+        Ok(())
+    }
+
+    fn encode_oid_iri(
+        &mut self,
+        tag: Tag,
+        value: &types::OidIri,
+        _: crate::types::Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_primitive(tag, value.as_str().as_bytes())?;
+        Ok(())
+    }
+
+    fn encode_relative_oid_iri(
+        &mut self,
+        tag: Tag,
+        value: &types::RelativeOidIri,
+        _: crate::types::Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_primitive(tag, value.as_str().as_bytes())?;
+        Ok(())
+    }
+
+    fn encode_external(
+        &mut self,
+        tag: Tag,
+        value: &types::External,
+        _: crate::types::Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        value.encode_legacy(self, tag)
+    }
+
     fn encode_octet_string(
         &mut self,
         tag: Tag,
-        _constraints: Constraints,
+        constraints: Constraints,
         value: &[u8],
         _: crate::types::Identifier,
     ) -> Result<Self::Ok, Self::Error> {
+        self.enforce(|| validate::check_size(value.len(), &constraints))?;
         self.encode_octet_string_(tag, value)
     }
 
     fn encode_visible_string(
         &mut self,
         tag: Tag,
-        _constraints: Constraints,
+        constraints: Constraints,
         value: &types::VisibleString,
         _: crate::types::Identifier,
     ) -> Result<Self::Ok, Self::Error> {
+        self.enforce(|| validate::check_characters(|| value.chars(), &constraints))?;
         self.encode_octet_string_(tag, value.as_iso646_bytes())
     }
 
     fn encode_ia5_string(
         &mut self,
         tag: Tag,
-        _constraints: Constraints,
+        constraints: Constraints,
         value: &types::Ia5String,
         _: crate::types::Identifier,
     ) -> Result<Self::Ok, Self::Error> {
+        self.enforce(|| validate::check_characters(|| value.chars(), &constraints))?;
         self.encode_octet_string_(tag, value.as_iso646_bytes())
     }
 
     fn encode_general_string(
         &mut self,
         tag: Tag,
-        _constraints: Constraints,
+        constraints: Constraints,
         value: &types::GeneralString,
         _: crate::types::Identifier,
     ) -> Result<Self::Ok, Self::Error> {
+        self.enforce(|| validate::check_characters(|| value.chars(), &constraints))?;
         self.encode_octet_string_(tag, value)
     }
 
     fn encode_graphic_string(
         &mut self,
         tag: Tag,
-        _constraints: Constraints,
+        constraints: Constraints,
         value: &types::GraphicString,
         _: crate::types::Identifier,
     ) -> Result<Self::Ok, Self::Error> {
+        self.enforce(|| validate::check_characters(|| value.chars(), &constraints))?;
         self.encode_octet_string_(tag, value)
     }
 
     fn encode_printable_string(
         &mut self,
         tag: Tag,
-        _constraints: Constraints,
+        constraints: Constraints,
         value: &types::PrintableString,
         _: crate::types::Identifier,
     ) -> Result<Self::Ok, Self::Error> {
+        self.enforce(|| validate::check_characters(|| value.chars(), &constraints))?;
         self.encode_octet_string_(tag, value.as_bytes())
     }
 
     fn encode_numeric_string(
         &mut self,
         tag: Tag,
-        _constraints: Constraints,
+        constraints: Constraints,
         value: &types::NumericString,
         _: crate::types::Identifier,
     ) -> Result<Self::Ok, Self::Error> {
+        self.enforce(|| validate::check_characters(|| value.chars(), &constraints))?;
         self.encode_octet_string_(tag, value.as_bytes())
     }
 
     fn encode_teletex_string(
         &mut self,
         tag: Tag,
-        _: Constraints,
+        constraints: Constraints,
         value: &types::TeletexString,
         _: crate::types::Identifier,
     ) -> Result<Self::Ok, Self::Error> {
+        self.enforce(|| validate::check_characters(|| value.chars(), &constraints))?;
         self.encode_octet_string_(tag, &value.to_bytes())
     }
 
     fn encode_bmp_string(
         &mut self,
         tag: Tag,
-        _constraints: Constraints,
+        constraints: Constraints,
         value: &types::BmpString,
         _: crate::types::Identifier,
     ) -> Result<Self::Ok, Self::Error> {
+        self.enforce(|| validate::check_characters(|| value.chars(), &constraints))?;
         self.encode_octet_string_(tag, &value.to_bytes())
     }
 
     fn encode_utf8_string(
         &mut self,
         tag: Tag,
-        _: Constraints,
+        constraints: Constraints,
         value: &str,
         _: crate::types::Identifier,
     ) -> Result<Self::Ok, Self::Error> {
+        self.enforce(|| validate::check_characters(|| value.chars().map(u32::from), &constraints))?;
         self.encode_octet_string_(tag, value.as_bytes())
     }
 
@@ -550,8 +935,8 @@ impl crate::Encoder<'_> for Encoder {
     ) -> Result<Self::Ok, Self::Error> {
         self.encode_primitive(
             tag,
-            Self::datetime_to_canonical_utc_time_bytes(value).as_slice(),
-        );
+            Encoder::datetime_to_canonical_utc_time_bytes(value).as_slice(),
+        )?;
 
         Ok(())
     }
@@ -564,8 +949,8 @@ impl crate::Encoder<'_> for Encoder {
     ) -> Result<Self::Ok, Self::Error> {
         self.encode_primitive(
             tag,
-            Self::datetime_to_canonical_generalized_time_bytes(value).as_slice(),
-        );
+            Encoder::datetime_to_canonical_generalized_time_bytes(value).as_slice(),
+        )?;
 
         Ok(())
     }
@@ -576,7 +961,57 @@ impl crate::Encoder<'_> for Encoder {
         value: &types::Date,
         _: crate::types::Identifier,
     ) -> Result<Self::Ok, Self::Error> {
-        self.encode_primitive(tag, Self::naivedate_to_date_bytes(value).as_slice());
+        self.encode_primitive(tag, Encoder::naivedate_to_date_bytes(value).as_slice())?;
+
+        Ok(())
+    }
+
+    fn encode_time(
+        &mut self,
+        tag: Tag,
+        value: &types::IsoTime,
+        _: crate::types::Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_primitive(tag, value.as_str().as_bytes())?;
+
+        Ok(())
+    }
+
+    fn encode_time_of_day(
+        &mut self,
+        tag: Tag,
+        value: &types::TimeOfDay,
+        _: crate::types::Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_primitive(
+            tag,
+            Encoder::naivetime_to_time_of_day_bytes(value, self.codec())?.as_slice(),
+        )?;
+
+        Ok(())
+    }
+
+    fn encode_date_time(
+        &mut self,
+        tag: Tag,
+        value: &types::DateTime,
+        _: crate::types::Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_primitive(
+            tag,
+            Encoder::naivedatetime_to_date_time_bytes(value, self.codec())?.as_slice(),
+        )?;
+
+        Ok(())
+    }
+
+    fn encode_duration(
+        &mut self,
+        tag: Tag,
+        value: &types::IsoDuration,
+        _: crate::types::Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_primitive(tag, value.to_string().as_bytes())?;
 
         Ok(())
     }
@@ -632,32 +1067,32 @@ impl crate::Encoder<'_> for Encoder {
         &mut self,
         tag: Tag,
         values: &[E],
-        _constraints: Constraints,
+        constraints: Constraints,
         _: crate::types::Identifier,
     ) -> Result<Self::Ok, Self::Error> {
-        let mut sequence_encoder = Self::new(self.config);
-
-        for value in values {
-            value.encode(&mut sequence_encoder)?;
-        }
-
-        self.encode_constructed(tag, &sequence_encoder.output);
+        self.enforce(|| validate::check_size(values.len(), &constraints))?;
+        self.encode_constructed_with(tag, |encoder| {
+            for value in values {
+                value.encode(encoder)?;
+            }
 
-        Ok(())
+            Ok(())
+        })
     }
 
     fn encode_set_of<E: Encode + Eq + core::hash::Hash>(
         &mut self,
         tag: Tag,
         values: &types::SetOf<E>,
-        _constraints: Constraints,
+        constraints: Constraints,
         _: crate::types::Identifier,
     ) -> Result<Self::Ok, Self::Error> {
+        self.enforce(|| validate::check_size(values.len(), &constraints))?;
         let mut encoded_values = values
             .to_vec()
             .iter()
             .map(|val| {
-                let mut sequence_encoder = Self::new(self.config);
+                let mut sequence_encoder = Self::buffered(self.config, false);
                 val.encode(&mut sequence_encoder)
                     .map(|()| sequence_encoder.output)
             })
@@ -668,7 +1103,7 @@ impl crate::Encoder<'_> for Encoder {
         encoded_values.sort_by(octet_string_ascending);
         let sorted_elements: Vec<u8> = encoded_values.into_iter().flatten().collect();
 
-        self.encode_constructed(tag, &sorted_elements);
+        self.encode_constructed(tag, &sorted_elements)?;
 
         Ok(())
     }
@@ -680,9 +1115,7 @@ impl crate::Encoder<'_> for Encoder {
         _: crate::types::Identifier,
     ) -> Result<Self::Ok, Self::Error> {
         if value.is_present() {
-            let mut encoder = Self::new(self.config);
-            value.encode(&mut encoder)?;
-            self.encode_constructed(tag, &encoder.output);
+            self.encode_constructed_with(tag, |encoder| value.encode(encoder))?;
         }
         Ok(())
     }
@@ -697,13 +1130,7 @@ impl crate::Encoder<'_> for Encoder {
         C: crate::types::Constructed<RC, EC>,
         F: FnOnce(&mut Self::AnyEncoder<'b, 0, 0>) -> Result<(), Self::Error>,
     {
-        let mut encoder = Self::new(self.config);
-
-        (encoder_scope)(&mut encoder)?;
-
-        self.encode_constructed(tag, &encoder.output);
-
-        Ok(())
+        self.encode_constructed_with(tag, encoder_scope)
     }
 
     fn encode_set<'b, const RC: usize, const EC: usize, C, F>(
@@ -716,11 +1143,11 @@ impl crate::Encoder<'_> for Encoder {
         C: crate::types::Constructed<RC, EC>,
         F: FnOnce(&mut Self::AnyEncoder<'b, 0, 0>) -> Result<(), Self::Error>,
     {
-        let mut encoder = Self::new_set(self.config);
+        let mut encoder = Self::buffered(self.config, true);
 
         (encoder_scope)(&mut encoder)?;
 
-        self.encode_constructed(tag, &encoder.output());
+        self.encode_constructed(tag, &encoder.take_output())?;
 
         Ok(())
     }
@@ -751,6 +1178,92 @@ impl crate::Encoder<'_> for Encoder {
     {
         value.encode(self)
     }
+
+    fn encode_value(
+        &mut self,
+        descriptor: &TypeDescriptor,
+        value: &Value,
+    ) -> Result<Self::Ok, Self::Error> {
+        let descriptor = &descriptor.resolve();
+        match descriptor.kind {
+            TypeKind::Enumerated { .. } => {
+                let (_, _, variant) = value.enumerated(descriptor, self.codec())?;
+                self.encode_integer(
+                    descriptor.tag,
+                    Constraints::default(),
+                    &variant.discriminant,
+                    crate::types::Identifier::EMPTY,
+                )
+            }
+            TypeKind::Sequence { .. } => {
+                let components = value.components(descriptor, self.codec())?;
+                self.encode_constructed_with(descriptor.tag, |encoder| {
+                    for (field, value) in
+                        components.present().chain(components.present_extensions())
+                    {
+                        encoder.encode_value(field.ty, value)?;
+                    }
+                    Ok(())
+                })
+            }
+            TypeKind::Set { .. } => {
+                let components = value.components(descriptor, self.codec())?;
+                let mut encoder = Self::buffered(self.config, true);
+                for (field, value) in components.present().chain(components.present_extensions()) {
+                    encoder.encode_value(field.ty, value)?;
+                }
+
+                self.encode_constructed(descriptor.tag, &encoder.take_output())?;
+
+                Ok(())
+            }
+            // A tagged `CHOICE` is always explicitly tagged.
+            TypeKind::Choice { .. } if descriptor.tag != Tag::EOC => self
+                .encode_constructed_with(descriptor.tag, |encoder| {
+                    encoder.encode_value(&descriptor.with_tag(Tag::EOC), value)
+                }),
+            TypeKind::Choice { .. } => {
+                let (_, _, variant, value) = value.chosen(descriptor, self.codec())?;
+                self.encode_value(variant.ty, value)
+            }
+            TypeKind::SequenceOf { element } => {
+                let values = value.elements(descriptor, self.codec())?;
+                self.encode_constructed_with(descriptor.tag, |encoder| {
+                    for value in values {
+                        encoder.encode_value(element, value)?;
+                    }
+
+                    Ok(())
+                })
+            }
+            TypeKind::SetOf { element } => {
+                let mut encoded_values = value
+                    .elements(descriptor, self.codec())?
+                    .iter()
+                    .map(|value| {
+                        let mut set_of_encoder = Self::buffered(self.config, false);
+                        set_of_encoder
+                            .encode_value(element, value)
+                            .map(|()| set_of_encoder.output)
+                    })
+                    .collect::<Result<Vec<Vec<u8>>, _>>()?;
+
+                // The encodings of the component values of a set-of value shall appear in ascending order,
+                // the encodings being compared as octet strings [...]
+                encoded_values.sort_by(octet_string_ascending);
+                let sorted_elements: Vec<u8> = encoded_values.into_iter().flatten().collect();
+
+                self.encode_constructed(descriptor.tag, &sorted_elements)?;
+
+                Ok(())
+            }
+            TypeKind::Explicit { inner } => self
+                .encode_constructed_with(descriptor.tag, |encoder| {
+                    encoder.encode_value(inner, value)
+                }),
+            _ => value.encode_primitive(self, descriptor),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -861,9 +1374,7 @@ mod tests {
         let bitstring = BitString::from_vec([0x0A, 0x3B, 0x5F, 0x29, 0x1C, 0xD0][..].to_owned());
 
         let primitive_encoded = &[0x03, 0x07, 0x00, 0x0A, 0x3B, 0x5F, 0x29, 0x1C, 0xD0][..];
-        let any = Any {
-            contents: primitive_encoded.into(),
-        };
+        let any = Any::new(primitive_encoded.into());
 
         assert_eq!(primitive_encoded, super::super::encode(&bitstring).unwrap());
         assert_eq!(
@@ -939,4 +1450,103 @@ mod tests {
             output,
         );
     }
+
+    #[test]
+    fn two_pass() {
+        use crate as rasn;
+        use rasn::prelude::*;
+
+        #[derive(AsnType, Clone, Debug, Encode, PartialEq, Eq, Hash)]
+        struct Inner {
+            id: Integer,
+            #[rasn(tag(explicit(0)))]
+            name: Option<Utf8String>,
+            data: OctetString,
+        }
+
+        #[derive(AsnType, Clone, Debug, Encode, PartialEq)]
+        #[rasn(set)]
+        struct Attributes {
+            #[rasn(tag(1))]
+            b: bool,
+            #[rasn(tag(0))]
+            a: Vec<Inner>,
+        }
+
+        #[derive(AsnType, Clone, Debug, Encode, PartialEq)]
+        #[rasn(choice)]
+        enum Choice {
+            #[rasn(tag(explicit(2)))]
+            Nested(Vec<Vec<Inner>>),
+            Flag(bool),
+        }
+
+        #[derive(AsnType, Clone, Debug, Encode, PartialEq)]
+        struct Outer {
+            inners: Vec<Inner>,
+            attributes: Attributes,
+            set: SetOf<Inner>,
+            choices: Vec<Choice>,
+        }
+
+        let inner = |id: i32, len: usize| Inner {
+            id: id.into(),
+            name: (id % 2 == 0).then(|| "inner".into()),
+            data: alloc::vec![id as u8; len].into(),
+        };
+        let value = Outer {
+            inners: (0..20).map(|i| inner(i, i as usize * 20)).collect(),
+            attributes: Attributes {
+                b: true,
+                a: alloc::vec![inner(1, 300)],
+            },
+            set: SetOf::from_vec(alloc::vec![inner(3, 1), inner(2, 2)]),
+            choices: alloc::vec![
+                Choice::Nested(alloc::vec![alloc::vec![inner(4, 70_000)], alloc::vec![]]),
+                Choice::Flag(true),
+            ],
+        };
+
+        for config in [
+            EncoderOptions::ber(),
+            EncoderOptions::cer(),
+            EncoderOptions::der(),
+        ] {
+            let mut encoder = super::Encoder::new(config);
+            value.encode(&mut encoder).unwrap();
+            let expected = encoder.output();
+
+            assert_eq!(
+                expected.len(),
+                super::Encoder::encoded_len(config, &value).unwrap()
+            );
+            assert_eq!(
+                expected,
+                super::Encoder::encode_two_pass(config, &value).unwrap()
+            );
+
+            let mut buffer = alloc::vec![0; expected.len()];
+            let sink = crate::enc::SliceSink::new(&mut buffer);
+            assert_eq!(
+                expected.len(),
+                super::Encoder::encode_to_sink(config, &value, sink).unwrap()
+            );
+            assert_eq!(expected, buffer);
+
+            // Writing stops at the first write which doesn't fit.
+            let mut buffer = alloc::vec![0; expected.len() - 1];
+            let sink = crate::enc::SliceSink::new(&mut buffer);
+            let error = super::Encoder::encode_to_sink(config, &value, sink).unwrap_err();
+            assert!(matches!(
+                *error.kind,
+                super::EncodeErrorKind::BufferTooSmall { needed, available }
+                    if needed == expected.len() && available == expected.len() - 1
+            ));
+        }
+
+        assert_eq!(
+            crate::der::encode(&value).unwrap().len(),
+            value.encoded_len().unwrap()
+        );
+    }
 }
@@ -4,6 +4,7 @@ use crate::ber::EncodingRules;
 #[derive(Clone, Copy, Debug)]
 pub struct EncoderOptions {
     pub(crate) encoding_rules: EncodingRules,
+    pub(crate) enforce_constraints: bool,
 }
 
 impl EncoderOptions {
@@ -12,6 +13,7 @@ impl EncoderOptions {
     pub const fn ber() -> Self {
         Self {
             encoding_rules: EncodingRules::Ber,
+            enforce_constraints: false,
         }
     }
 
@@ -20,6 +22,7 @@ impl EncoderOptions {
     pub const fn cer() -> Self {
         Self {
             encoding_rules: EncodingRules::Cer,
+            enforce_constraints: false,
         }
     }
 
@@ -28,9 +31,19 @@ impl EncoderOptions {
     pub const fn der() -> Self {
         Self {
             encoding_rules: EncodingRules::Der,
+            enforce_constraints: false,
         }
     }
 
+    /// Sets whether the value, size and permitted alphabet constraints of
+    /// values are checked when encoding, failing on the first violation.
+    /// Constraints aren't enforced by default, as BER doesn't depend on them.
+    #[must_use]
+    pub const fn enforce_constraints(mut self, enforce: bool) -> Self {
+        self.enforce_constraints = enforce;
+        self
+    }
+
     /// Returns the currently selected codec.
     #[must_use]
     pub fn current_codec(&self) -> crate::Codec {
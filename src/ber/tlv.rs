@@ -0,0 +1,659 @@
+//! Schema-less parsing of BER, CER, and DER input into a tree of TLVs, for
+//! inspecting and debugging encodings without knowing their ASN.1 type.
+
+use alloc::vec::Vec;
+use core::fmt::{self, Write as _};
+
+use super::{
+    de::{
+        parser::{self, ParseNumberError},
+        Decoder, DecoderOptions,
+    },
+    Identifier,
+};
+use crate::{
+    error::{BerDecodeErrorKind, DecodeError},
+    types::{Class, Tag},
+};
+
+/// The end-of-contents octets terminating an indefinite length value.
+const EOC: &[u8] = &[0, 0];
+/// The number of contents octets shown on a single line when printing.
+const BYTES_PER_LINE: usize = 16;
+/// The number of lines of contents octets shown before the rest is skipped
+/// when printing.
+const MAX_LINES: usize = 4;
+/// The number of characters of a string shown when printing.
+const MAX_STRING_PREVIEW: usize = 64;
+/// The number of columns used for the offset and length before the tree.
+const HEADER_WIDTH: usize = 10;
+/// The maximum number of values nested in each other, including values
+/// encapsulated in `OCTET STRING` and `BIT STRING` values, which bounds the
+/// recursion of the parser.
+const MAX_DEPTH: usize = 128;
+
+/// The form of the length octets of a [`Tlv`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Length {
+    /// The length octets contain the number of contents octets.
+    Definite(usize),
+    /// The contents are terminated by end-of-contents octets.
+    Indefinite,
+}
+
+/// A single BER value, parsed without a schema.
+///
+/// Constructed values contain the values they're made of in
+/// [`Tlv::children`]. Primitive `OCTET STRING` and `BIT STRING` values whose
+/// contents are themselves a complete BER encoding, such as X.509
+/// extensions and public keys, also have the encapsulated values as their
+/// children.
+///
+/// The [`Display`](core::fmt::Display) implementation prints the tree in the
+/// style of `dumpasn1`, with the offset and contents length of each value,
+/// the names of well-known object identifiers, and previews of strings.
+///
+/// ```
+/// use rasn::ber::{de::DecoderOptions, Tlv};
+///
+/// let encoded = [0x30, 0x06, 0x02, 0x01, 0x01, 0x01, 0x01, 0xFF];
+/// let (tlv, rest) = Tlv::parse(&encoded, DecoderOptions::der()).unwrap();
+/// assert!(rest.is_empty());
+/// assert_eq!(2, tlv.children.len());
+/// println!("{tlv}");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Tlv<'input> {
+    /// The identifier octets of the value.
+    pub identifier: Identifier,
+    /// The form of the length octets of the value.
+    pub length: Length,
+    /// The offset of the identifier octets in the parsed input.
+    pub offset: usize,
+    /// The number of identifier and length octets.
+    pub header_len: usize,
+    /// The contents octets, excluding any end-of-contents octets.
+    pub contents: &'input [u8],
+    /// The nested values of a constructed or encapsulating value.
+    pub children: Vec<Tlv<'input>>,
+}
+
+impl<'input> Tlv<'input> {
+    /// Parses a single value from the start of `input`, returning it and
+    /// the remaining input.
+    ///
+    /// # Errors
+    /// Returns `DecodeError` if the identifier or length octets of any value
+    /// are invalid, if `input` ends before the value does, or if more than
+    /// 128 values are nested in each other.
+    pub fn parse(
+        input: &'input [u8],
+        config: DecoderOptions,
+    ) -> Result<(Self, &'input [u8]), DecodeError> {
+        let tlv = Self::parse_at(input, 0, 0, config)?;
+        let end = tlv.encoded_len();
+        Ok((tlv, &input[end..]))
+    }
+
+    /// Parses all of `input` as a series of consecutive values.
+    ///
+    /// # Errors
+    /// Returns `DecodeError` if any of the values are invalid, if `input`
+    /// ends in the middle of a value, or if more than 128 values are nested
+    /// in each other.
+    pub fn parse_all(
+        input: &'input [u8],
+        config: DecoderOptions,
+    ) -> Result<Vec<Self>, DecodeError> {
+        Self::parse_children(input, 0, 0, config)
+    }
+
+    /// Returns the tag of the value.
+    #[must_use]
+    pub fn tag(&self) -> Tag {
+        self.identifier.tag
+    }
+
+    /// Returns the total number of octets in the encoding of the value.
+    #[must_use]
+    pub fn encoded_len(&self) -> usize {
+        let eoc_len = match self.length {
+            Length::Definite(_) => 0,
+            Length::Indefinite => EOC.len(),
+        };
+        self.header_len + self.contents.len() + eoc_len
+    }
+
+    /// Returns whether the children of the value were parsed from the
+    /// contents of a primitive value.
+    #[must_use]
+    pub fn is_encapsulating(&self) -> bool {
+        self.identifier.is_primitive() && !self.children.is_empty()
+    }
+
+    /// Parses the value starting at `offset` in `source`, which must end
+    /// where the enclosing value does, and is nested in `depth` values.
+    fn parse_at(
+        source: &'input [u8],
+        offset: usize,
+        depth: usize,
+        config: DecoderOptions,
+    ) -> Result<Self, DecodeError> {
+        if depth >= MAX_DEPTH {
+            return Err(BerDecodeErrorKind::ExceedsMaxDepth { max: MAX_DEPTH }.into());
+        }
+        let codec = config.current_codec();
+        let input = &source[offset..];
+
+        let (rest, identifier) = parser::parse_identifier_octet(input).map_err(|e| match e {
+            ParseNumberError::Nom(e) => DecodeError::map_nom_err(e, codec),
+            ParseNumberError::Overflow => DecodeError::integer_overflow(32u32, codec),
+        })?;
+        let (rest, contents) = parser::parse_contents(config, identifier, rest)
+            .map_err(|e| DecodeError::map_nom_err(e, codec))?;
+
+        let Some(contents) = contents else {
+            return Self::parse_indefinite(
+                source,
+                offset,
+                input.len() - rest.len(),
+                identifier,
+                depth,
+                config,
+            );
+        };
+
+        let header_len = input.len() - rest.len() - contents.len();
+        let start = offset + header_len;
+        let end = start + contents.len();
+
+        let children = if identifier.is_constructed() {
+            Self::parse_children(&source[..end], start, depth + 1, config)?
+        } else {
+            Self::parse_encapsulated(&source[..end], identifier.tag, start, depth + 1, config)
+        };
+
+        Ok(Self {
+            identifier,
+            length: Length::Definite(contents.len()),
+            offset,
+            header_len,
+            contents,
+            children,
+        })
+    }
+
+    /// Parses the children of an indefinite length value up to and including
+    /// its end-of-contents octets.
+    fn parse_indefinite(
+        source: &'input [u8],
+        offset: usize,
+        header_len: usize,
+        identifier: Identifier,
+        depth: usize,
+        config: DecoderOptions,
+    ) -> Result<Self, DecodeError> {
+        let start = offset + header_len;
+        let mut position = start;
+        let mut children = Vec::new();
+
+        while !source[position..].starts_with(EOC) {
+            let child = Self::parse_at(source, position, depth + 1, config)?;
+            position += child.encoded_len();
+            children.push(child);
+        }
+
+        Ok(Self {
+            identifier,
+            length: Length::Indefinite,
+            offset,
+            header_len,
+            contents: &source[start..position],
+            children,
+        })
+    }
+
+    /// Parses the values from `start` up to the end of `source`, each nested
+    /// in `depth` values.
+    fn parse_children(
+        source: &'input [u8],
+        start: usize,
+        depth: usize,
+        config: DecoderOptions,
+    ) -> Result<Vec<Self>, DecodeError> {
+        let mut position = start;
+        let mut children = Vec::new();
+
+        while position < source.len() {
+            let child = Self::parse_at(source, position, depth, config)?;
+            position += child.encoded_len();
+            children.push(child);
+        }
+
+        Ok(children)
+    }
+
+    /// Attempts to parse the contents of a primitive `OCTET STRING` or
+    /// `BIT STRING` as an encapsulated encoding. The contents are only
+    /// considered to be encapsulating if they parse completely and start
+    /// with a constructed value, as other contents are likely to parse by
+    /// coincidence.
+    fn parse_encapsulated(
+        source: &'input [u8],
+        tag: Tag,
+        start: usize,
+        depth: usize,
+        config: DecoderOptions,
+    ) -> Vec<Self> {
+        let start = match tag {
+            Tag::OCTET_STRING => start,
+            // The first contents octet holds the number of unused bits.
+            Tag::BIT_STRING if source.get(start) == Some(&0) => start + 1,
+            _ => return Vec::new(),
+        };
+
+        match Self::parse_children(source, start, depth, config) {
+            Ok(children)
+                if children
+                    .first()
+                    .is_some_and(|child| child.identifier.is_constructed()) =>
+            {
+                children
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    fn fmt_at(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        write!(f, "{:>5} ", self.offset)?;
+        match self.length {
+            Length::Definite(length) => write!(f, "{length:>4}: ")?,
+            Length::Indefinite => f.write_str("NDEF: ")?,
+        }
+        indent(f, depth)?;
+        write_tag_name(f, self.identifier.tag)?;
+
+        if self.identifier.is_constructed() {
+            f.write_str(" {")?;
+        } else if self.is_encapsulating() {
+            if self.identifier.tag == Tag::BIT_STRING {
+                f.write_str(" 0 unused bits")?;
+            }
+            f.write_str(", encapsulates {")?;
+        } else {
+            return self.fmt_primitive(f, depth);
+        }
+
+        for child in &self.children {
+            f.write_char('\n')?;
+            child.fmt_at(f, depth + 1)?;
+        }
+        f.write_char('\n')?;
+        continuation(f, depth + 1)?;
+        f.write_char('}')
+    }
+
+    fn fmt_primitive(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        let contents = self.contents;
+        if self.identifier.tag.class != Class::Universal {
+            return write_hex(f, contents, depth);
+        }
+
+        match self.identifier.tag {
+            Tag::NULL if contents.is_empty() => Ok(()),
+            Tag::BOOL if contents.len() == 1 => {
+                f.write_str(if contents[0] == 0 { " FALSE" } else { " TRUE" })
+            }
+            Tag::INTEGER | Tag::ENUMERATED if !contents.is_empty() && contents.len() <= 8 => {
+                let sign = if contents[0] & 0x80 == 0 { 0 } else { -1 };
+                let value = contents
+                    .iter()
+                    .fold(sign, |value: i64, &byte| (value << 8) | i64::from(byte));
+                write!(f, " {value}")
+            }
+            Tag::OBJECT_IDENTIFIER => {
+                let oid = Decoder::new(&[], DecoderOptions::ber())
+                    .decode_object_identifier_from_bytes(contents);
+                match oid {
+                    Ok(oid) => {
+                        f.write_char(' ')?;
+                        #[cfg(feature = "oid-registry")]
+                        if let Some(name) = oid.name() {
+                            write!(f, "{name} (")?;
+                            write_arcs(f, &oid)?;
+                            return f.write_char(')');
+                        }
+                        write_arcs(f, &oid)
+                    }
+                    Err(_) => write_hex(f, contents, depth),
+                }
+            }
+            Tag::RELATIVE_OID => match relative_oid_arcs(contents) {
+                Some(arcs) => {
+                    f.write_char(' ')?;
+                    write_arcs(f, &arcs)
+                }
+                None => write_hex(f, contents, depth),
+            },
+            Tag::BIT_STRING if !contents.is_empty() => {
+                write!(f, " {} unused bits", contents[0])?;
+                write_hex(f, &contents[1..], depth)
+            }
+            Tag::UTF8_STRING
+            | Tag::NUMERIC_STRING
+            | Tag::PRINTABLE_STRING
+            | Tag::TELETEX_STRING
+            | Tag::VIDEOTEX_STRING
+            | Tag::IA5_STRING
+            | Tag::UTC_TIME
+            | Tag::GENERALIZED_TIME
+            | Tag::GRAPHIC_STRING
+            | Tag::VISIBLE_STRING
+            | Tag::GENERAL_STRING
+            | Tag::OBJECT_DESCRIPTOR
+            | Tag::TIME
+            | Tag::DATE
+            | Tag::TIME_OF_DAY
+            | Tag::DATE_TIME
+            | Tag::DURATION
+            | Tag::OID_IRI
+            | Tag::RELATIVE_OID_IRI => match core::str::from_utf8(contents) {
+                Ok(string) => write_string(f, string.chars()),
+                Err(_) => write_hex(f, contents, depth),
+            },
+            Tag::BMP_STRING if contents.len().is_multiple_of(2) => {
+                let chars = char::decode_utf16(
+                    contents
+                        .chunks_exact(2)
+                        .map(|pair| u16::from_be_bytes([pair[0], pair[1]])),
+                )
+                .collect::<Result<Vec<char>, _>>();
+                match chars {
+                    Ok(chars) => write_string(f, chars.into_iter()),
+                    Err(_) => write_hex(f, contents, depth),
+                }
+            }
+            _ => write_hex(f, contents, depth),
+        }
+    }
+}
+
+impl fmt::Display for Tlv<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_at(f, 0)
+    }
+}
+
+fn indent(f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+    for _ in 0..depth {
+        f.write_str("  ")?;
+    }
+    Ok(())
+}
+
+/// Starts a line that continues the value printed on the previous one.
+fn continuation(f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+    write!(f, "{:HEADER_WIDTH$}: ", "")?;
+    indent(f, depth)
+}
+
+fn write_tag_name(f: &mut fmt::Formatter<'_>, tag: Tag) -> fmt::Result {
+    let name = match tag {
+        Tag::EOC => "EOC",
+        Tag::BOOL => "BOOLEAN",
+        Tag::INTEGER => "INTEGER",
+        Tag::BIT_STRING => "BIT STRING",
+        Tag::OCTET_STRING => "OCTET STRING",
+        Tag::NULL => "NULL",
+        Tag::OBJECT_IDENTIFIER => "OBJECT IDENTIFIER",
+        Tag::OBJECT_DESCRIPTOR => "ObjectDescriptor",
+        Tag::EXTERNAL => "EXTERNAL",
+        Tag::REAL => "REAL",
+        Tag::ENUMERATED => "ENUMERATED",
+        Tag::EMBEDDED_PDV => "EMBEDDED PDV",
+        Tag::UTF8_STRING => "UTF8String",
+        Tag::RELATIVE_OID => "RELATIVE-OID",
+        Tag::TIME => "TIME",
+        Tag::SEQUENCE => "SEQUENCE",
+        Tag::SET => "SET",
+        Tag::NUMERIC_STRING => "NumericString",
+        Tag::PRINTABLE_STRING => "PrintableString",
+        Tag::TELETEX_STRING => "TeletexString",
+        Tag::VIDEOTEX_STRING => "VideotexString",
+        Tag::IA5_STRING => "IA5String",
+        Tag::UTC_TIME => "UTCTime",
+        Tag::GENERALIZED_TIME => "GeneralizedTime",
+        Tag::GRAPHIC_STRING => "GraphicString",
+        Tag::VISIBLE_STRING => "VisibleString",
+        Tag::GENERAL_STRING => "GeneralString",
+        Tag::UNIVERSAL_STRING => "UniversalString",
+        Tag::CHARACTER_STRING => "CHARACTER STRING",
+        Tag::BMP_STRING => "BMPString",
+        Tag::DATE => "DATE",
+        Tag::TIME_OF_DAY => "TIME-OF-DAY",
+        Tag::DATE_TIME => "DATE-TIME",
+        Tag::DURATION => "DURATION",
+        Tag::OID_IRI => "OID-IRI",
+        Tag::RELATIVE_OID_IRI => "RELATIVE-OID-IRI",
+        Tag { class, value } => {
+            return match class {
+                Class::Universal => write!(f, "[UNIVERSAL {value}]"),
+                Class::Application => write!(f, "[APPLICATION {value}]"),
+                Class::Context => write!(f, "[{value}]"),
+                Class::Private => write!(f, "[PRIVATE {value}]"),
+            };
+        }
+    };
+    f.write_str(name)
+}
+
+fn write_arcs(f: &mut fmt::Formatter<'_>, arcs: &[u32]) -> fmt::Result {
+    for (i, arc) in arcs.iter().enumerate() {
+        if i > 0 {
+            f.write_char('.')?;
+        }
+        write!(f, "{arc}")?;
+    }
+    Ok(())
+}
+
+fn write_string(f: &mut fmt::Formatter<'_>, chars: impl Iterator<Item = char>) -> fmt::Result {
+    f.write_str(" '")?;
+    for (i, ch) in chars.enumerate() {
+        if i == MAX_STRING_PREVIEW {
+            return f.write_str("'...");
+        }
+        if ch.is_control() {
+            write!(f, "{}", ch.escape_default())?;
+        } else {
+            f.write_char(ch)?;
+        }
+    }
+    f.write_char('\'')
+}
+
+/// Writes `bytes` as hexadecimal, on the same line if they fit and on
+/// continuation lines below the value otherwise.
+fn write_hex(f: &mut fmt::Formatter<'_>, bytes: &[u8], depth: usize) -> fmt::Result {
+    if bytes.len() <= BYTES_PER_LINE {
+        for byte in bytes {
+            write!(f, " {byte:02X}")?;
+        }
+        return Ok(());
+    }
+
+    for line in bytes.chunks(BYTES_PER_LINE).take(MAX_LINES) {
+        f.write_char('\n')?;
+        continuation(f, depth + 1)?;
+        for (i, byte) in line.iter().enumerate() {
+            if i > 0 {
+                f.write_char(' ')?;
+            }
+            write!(f, "{byte:02X}")?;
+        }
+    }
+
+    let skipped = bytes.len().saturating_sub(BYTES_PER_LINE * MAX_LINES);
+    if skipped > 0 {
+        f.write_char('\n')?;
+        continuation(f, depth + 1)?;
+        write!(f, "[ Another {skipped} bytes skipped ]")?;
+    }
+    Ok(())
+}
+
+fn relative_oid_arcs(mut contents: &[u8]) -> Option<Vec<u32>> {
+    let mut arcs = Vec::new();
+    while !contents.is_empty() {
+        let (rest, arc) = parser::parse_base128_number(contents).ok()?;
+        contents = rest;
+        arcs.push(arc);
+    }
+    Some(arcs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn definite_length() {
+        let encoded = [
+            0x30, 0x0C, 0x02, 0x01, 0x05, 0x06, 0x03, 0x55, 0x04, 0x03, 0x0C, 0x02, b'h', b'i',
+            0xFF,
+        ];
+        let (tlv, rest) = Tlv::parse(&encoded, DecoderOptions::der()).unwrap();
+
+        assert_eq!(&[0xFF], rest);
+        assert_eq!(Tag::SEQUENCE, tlv.tag());
+        assert_eq!(Length::Definite(12), tlv.length);
+        assert_eq!(2, tlv.header_len);
+        assert_eq!(14, tlv.encoded_len());
+        assert_eq!(
+            [2, 5, 10],
+            [
+                tlv.children[0].offset,
+                tlv.children[1].offset,
+                tlv.children[2].offset
+            ]
+        );
+        assert_eq!(&[b'h', b'i'], tlv.children[2].contents);
+
+        let oid = if cfg!(feature = "oid-registry") {
+            "commonName (2.5.4.3)"
+        } else {
+            "2.5.4.3"
+        };
+        assert_eq!(
+            alloc::format!(
+                "    0   12: SEQUENCE {{\n    \
+                     2    1:   INTEGER 5\n    \
+                     5    3:   OBJECT IDENTIFIER {oid}\n   \
+                    10    2:   UTF8String 'hi'\n          \
+                           :   }}"
+            ),
+            tlv.to_string()
+        );
+    }
+
+    #[test]
+    fn indefinite_length() {
+        let encoded = [
+            0x30, 0x80, 0xA0, 0x80, 0x01, 0x01, 0xFF, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00,
+        ];
+        let (tlv, rest) = Tlv::parse(&encoded, DecoderOptions::ber()).unwrap();
+
+        assert!(rest.is_empty());
+        assert_eq!(Length::Indefinite, tlv.length);
+        assert_eq!(13, tlv.encoded_len());
+        assert_eq!(&encoded[2..11], tlv.contents);
+        assert_eq!(Length::Indefinite, tlv.children[0].length);
+        assert_eq!(Tag::new(Class::Context, 0), tlv.children[0].tag());
+        assert_eq!(9, tlv.children[1].offset);
+
+        assert!(Tlv::parse(&encoded, DecoderOptions::der()).is_err());
+        assert!(Tlv::parse(&encoded[..11], DecoderOptions::ber()).is_err());
+    }
+
+    #[test]
+    fn encapsulated() {
+        // An OCTET STRING containing a SEQUENCE, and one containing bytes
+        // that only happen to start like a primitive value.
+        let encoded = [
+            0x30, 0x0B, 0x04, 0x05, 0x30, 0x03, 0x02, 0x01, 0x01, 0x04, 0x02, 0x02, 0x00,
+        ];
+        let (tlv, _) = Tlv::parse(&encoded, DecoderOptions::der()).unwrap();
+
+        assert!(tlv.children[0].is_encapsulating());
+        assert_eq!(4, tlv.children[0].children[0].offset);
+        assert_eq!(6, tlv.children[0].children[0].children[0].offset);
+        assert!(!tlv.children[1].is_encapsulating());
+        assert!(tlv.to_string().contains("OCTET STRING, encapsulates {"));
+    }
+
+    #[test]
+    fn parse_all() {
+        let encoded = [0x05, 0x00, 0x01, 0x01, 0x00];
+        let values = Tlv::parse_all(&encoded, DecoderOptions::ber()).unwrap();
+        assert_eq!(2, values.len());
+        assert_eq!("    2    1: BOOLEAN FALSE", values[1].to_string());
+
+        assert!(Tlv::parse_all(&encoded[..4], DecoderOptions::ber()).is_err());
+    }
+
+    #[test]
+    fn max_depth() {
+        // `depth` values nested in each other, the innermost being empty.
+        let nested = |depth: usize, tag: fn(usize) -> u8| {
+            let mut encoded = Vec::new();
+            for level in (1..depth).rev() {
+                let len = 2 + 6 * (level - 1);
+                encoded.extend([tag(level), 0x84]);
+                encoded.extend(u32::try_from(len).unwrap().to_be_bytes());
+            }
+            encoded.extend([0x30, 0x00]);
+            encoded
+        };
+        let sequence = |_| 0x30;
+
+        assert!(Tlv::parse(&nested(MAX_DEPTH, sequence), DecoderOptions::ber()).is_ok());
+        let error =
+            Tlv::parse(&nested(MAX_DEPTH + 1, sequence), DecoderOptions::ber()).unwrap_err();
+        assert!(matches!(
+            *error.kind,
+            crate::error::DecodeErrorKind::CodecSpecific {
+                inner: crate::error::CodecDecodeError::Ber(BerDecodeErrorKind::ExceedsMaxDepth {
+                    max: MAX_DEPTH
+                })
+            }
+        ));
+        assert!(Tlv::parse(&nested(100_000, sequence), DecoderOptions::ber()).is_err());
+
+        let mut encoded = alloc::vec![0x30, 0x80].repeat(MAX_DEPTH + 1);
+        encoded.extend([0x00, 0x00].repeat(MAX_DEPTH + 1));
+        assert!(Tlv::parse(&encoded, DecoderOptions::ber()).is_err());
+
+        // Encapsulated values count towards the depth, and are no longer
+        // considered encapsulated once it's exceeded.
+        let octet_strings = |level: usize| if level % 2 == 0 { 0x30 } else { 0x04 };
+        let encoded = nested(MAX_DEPTH + 1, octet_strings);
+        let (tlv, _) = Tlv::parse(&encoded, DecoderOptions::ber()).unwrap();
+        let mut tlv = &tlv;
+        for _ in 1..MAX_DEPTH {
+            tlv = &tlv.children[0];
+        }
+        assert!(tlv.children.is_empty());
+    }
+
+    #[test]
+    fn long_contents() {
+        let mut encoded = alloc::vec![0x04, 0x81, 0x50];
+        encoded.extend(0..0x50);
+        let (tlv, _) = Tlv::parse(&encoded, DecoderOptions::ber()).unwrap();
+
+        let printed = tlv.to_string();
+        assert_eq!(6, printed.lines().count());
+        assert!(printed.ends_with("[ Another 16 bytes skipped ]"));
+    }
+}
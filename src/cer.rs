@@ -19,6 +19,35 @@ pub fn decode_with_remainder<T: crate::Decode>(
     Ok((decoded_instance, decoder.remaining()))
 }
 
+/// Attempts to decode `T` from `input` using CER, borrowing from `input`
+/// where possible.
+///
+/// # Errors
+/// Returns `DecodeError` if `input` is not valid CER encoding specific to the expected type.
+pub fn decode_borrowed<'de, T: crate::de::DecodeBorrowed<'de>>(
+    input: &'de [u8],
+) -> Result<T, crate::error::DecodeError> {
+    T::decode_borrowed(&mut crate::ber::de::Decoder::new(
+        input,
+        crate::ber::de::DecoderOptions::cer(),
+    ))
+}
+
+/// Attempts to decode a value of the type described by `descriptor` from
+/// `input` using CER, without needing the Rust type of the value.
+///
+/// # Errors
+/// Returns `DecodeError` if `input` is not valid CER encoding of the described type.
+pub fn decode_value<'d>(
+    descriptor: &crate::types::TypeDescriptor<'d>,
+    input: &[u8],
+) -> Result<crate::types::value::Value<'d>, crate::error::DecodeError> {
+    crate::Decoder::decode_value(
+        &mut crate::ber::de::Decoder::new(input, crate::ber::de::DecoderOptions::cer()),
+        descriptor,
+    )
+}
+
 /// Attempts to encode `value` to CER.
 pub fn encode<T: crate::Encode>(
     value: &T,
@@ -29,3 +58,58 @@ pub fn encode<T: crate::Encode>(
 
     Ok(enc.output())
 }
+
+/// Attempts to encode `value` as the type described by `descriptor` to
+/// CER, without needing the Rust type of the value.
+///
+/// # Errors
+/// Returns `EncodeError` if `value` is not a valid value of the described type.
+pub fn encode_value(
+    descriptor: &crate::types::TypeDescriptor,
+    value: &crate::types::value::Value,
+) -> Result<alloc::vec::Vec<u8>, crate::error::EncodeError> {
+    let mut enc = crate::ber::enc::Encoder::new(crate::ber::enc::EncoderOptions::cer());
+
+    crate::Encoder::encode_value(&mut enc, descriptor, value)?;
+
+    Ok(enc.output())
+}
+
+/// Attempts to encode `value` to CER into the start of `buffer`, returning
+/// the number of bytes written.
+///
+/// # Errors
+/// Returns `EncodeError` if `value` cannot be encoded, or with
+/// [`EncodeErrorKind::BufferTooSmall`](crate::error::EncodeErrorKind::BufferTooSmall)
+/// as soon as the encoding doesn't fit into `buffer`. The contents of
+/// `buffer` are unspecified on error.
+pub fn encode_into<T: crate::Encode>(
+    value: &T,
+    buffer: &mut [u8],
+) -> Result<usize, crate::error::EncodeError> {
+    crate::ber::enc::Encoder::encode_to_sink(
+        crate::ber::enc::EncoderOptions::cer(),
+        value,
+        crate::enc::SliceSink::new(buffer),
+    )
+}
+
+/// Attempts to encode `value` to CER, writing it to `writer` as it is
+/// encoded and returning the number of bytes written.
+///
+/// # Errors
+/// Returns `EncodeError` if `value` cannot be encoded, or with
+/// [`EncodeErrorKind::Io`](crate::error::EncodeErrorKind::Io) if writing
+/// fails, in which case part of the encoding may have been written.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn encode_to_writer<T: crate::Encode, W: std::io::Write>(
+    value: &T,
+    writer: W,
+) -> Result<usize, crate::error::EncodeError> {
+    crate::ber::enc::Encoder::encode_to_sink(
+        crate::ber::enc::EncoderOptions::cer(),
+        value,
+        crate::enc::IoSink::new(writer),
+    )
+}
@@ -64,6 +64,74 @@ impl Codec {
         }
     }
 
+    /// Encodes a given value based on the value of `Codec` into the start of
+    /// `buffer`, returning the number of bytes written.
+    ///
+    /// The binary codecs write directly into `buffer` as they encode, JER and
+    /// XER encode into a string first.
+    ///
+    /// # Errors
+    /// - If the value fails to be encoded returns `EncodeError` struct.
+    /// - If the encoded value doesn't fit into `buffer`, returns `EncodeError`
+    ///   with [`EncodeErrorKind::BufferTooSmall`](crate::error::EncodeErrorKind::BufferTooSmall)
+    ///   at the first write that doesn't fit. The contents of `buffer` are
+    ///   unspecified on error.
+    pub fn encode_into<T: Encode>(
+        self,
+        value: &T,
+        buffer: &mut [u8],
+    ) -> Result<usize, crate::error::EncodeError> {
+        match self {
+            Self::Aper => crate::aper::encode_into(value, buffer),
+            Self::Ber => crate::ber::encode_into(value, buffer),
+            Self::Cer => crate::cer::encode_into(value, buffer),
+            Self::Der => crate::der::encode_into(value, buffer),
+            Self::Uper => crate::uper::encode_into(value, buffer),
+            Self::Oer => crate::oer::encode_into(value, buffer),
+            Self::Coer => crate::coer::encode_into(value, buffer),
+            Self::Jer | Self::Xer => {
+                let encoded = self.encode_to_binary(value)?;
+                let mut sink = crate::enc::SliceSink::new(buffer);
+                crate::enc::Sink::write(&mut sink, &encoded, self)?;
+                Ok(sink.position())
+            }
+        }
+    }
+
+    /// Encodes a given value based on the value of `Codec` and writes it to
+    /// `writer`, returning the number of bytes written.
+    ///
+    /// The binary codecs write to `writer` as they encode, JER and XER encode
+    /// into a string first.
+    ///
+    /// # Errors
+    /// - If the value fails to be encoded returns `EncodeError` struct.
+    /// - If writing fails, returns `EncodeError` with
+    ///   [`EncodeErrorKind::Io`](crate::error::EncodeErrorKind::Io).
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn encode_to_writer<T: Encode, W: std::io::Write>(
+        self,
+        value: &T,
+        writer: W,
+    ) -> Result<usize, crate::error::EncodeError> {
+        match self {
+            Self::Aper => crate::aper::encode_to_writer(value, writer),
+            Self::Ber => crate::ber::encode_to_writer(value, writer),
+            Self::Cer => crate::cer::encode_to_writer(value, writer),
+            Self::Der => crate::der::encode_to_writer(value, writer),
+            Self::Uper => crate::uper::encode_to_writer(value, writer),
+            Self::Oer => crate::oer::encode_to_writer(value, writer),
+            Self::Coer => crate::coer::encode_to_writer(value, writer),
+            Self::Jer | Self::Xer => {
+                let encoded = self.encode_to_binary(value)?;
+                let mut sink = crate::enc::IoSink::new(writer);
+                crate::enc::Sink::write(&mut sink, &encoded, self)?;
+                Ok(sink.written())
+            }
+        }
+    }
+
     /// Decodes `input` to `D` based on the value of `Codec`.
     /// This method shall be used when using binary-based encoding rules.
     ///
@@ -162,3 +230,65 @@ impl Codec {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::EncodeErrorKind;
+
+    const BINARY_CODECS: [Codec; 7] = [
+        Codec::Aper,
+        Codec::Ber,
+        Codec::Cer,
+        Codec::Der,
+        Codec::Uper,
+        Codec::Oer,
+        Codec::Coer,
+    ];
+
+    #[test]
+    fn encode_into() {
+        let value = alloc::vec![Integer::from(0x1234), Integer::from(-5)];
+
+        for codec in BINARY_CODECS {
+            let expected = codec.encode_to_binary(&value).unwrap();
+            let mut buffer = [0xFF; 32];
+
+            let written = codec.encode_into(&value, &mut buffer).unwrap();
+            assert_eq!(expected, &buffer[..written], "{codec}");
+            assert!(buffer[written..].iter().all(|&byte| byte == 0xFF));
+
+            // The error is returned by the first write which doesn't fit.
+            let mut buffer = [0xFF; 4];
+            let error = codec.encode_into(&value, &mut buffer).unwrap_err();
+            assert!(
+                matches!(
+                    *error.kind,
+                    EncodeErrorKind::BufferTooSmall { needed, available: 4 }
+                        if needed > 4 && needed <= expected.len()
+                ),
+                "{codec}: {error}"
+            );
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn encode_to_writer() {
+        let value = Utf8String::from("rasn");
+
+        for codec in BINARY_CODECS {
+            let mut output = alloc::vec::Vec::new();
+            let written = codec.encode_to_writer(&value, &mut output).unwrap();
+            assert_eq!(written, output.len());
+            assert_eq!(codec.encode_to_binary(&value).unwrap(), output);
+
+            let mut buffer = [0; 2];
+            let error = codec.encode_to_writer(&value, &mut buffer[..]).unwrap_err();
+            assert!(
+                matches!(*error.kind, EncodeErrorKind::Io { .. }),
+                "{codec}: {error}"
+            );
+        }
+    }
+}
@@ -20,6 +20,32 @@ pub fn decode_with_remainder<T: crate::Decode>(input: &[u8]) -> Result<(T, &[u8]
     let decoded_instance = T::decode(decoder)?;
     Ok((decoded_instance, decoder.remaining()))
 }
+/// Attempts to decode `T` from `input` using COER, borrowing from `input`
+/// where possible.
+///
+/// # Errors
+/// Returns `DecodeError` if `input` is not valid COER encoding specific to the expected type.
+pub fn decode_borrowed<'de, T: crate::de::DecodeBorrowed<'de>>(
+    input: &'de [u8],
+) -> Result<T, DecodeError> {
+    T::decode_borrowed(&mut Decoder::<0, 0>::new(input, de::DecoderOptions::coer()))
+}
+
+/// Attempts to decode a value of the type described by `descriptor` from
+/// `input` using COER, without needing the Rust type of the value.
+///
+/// # Errors
+/// Returns `DecodeError` if `input` is not valid COER encoding of the described type.
+pub fn decode_value<'d>(
+    descriptor: &crate::types::TypeDescriptor<'d>,
+    input: &[u8],
+) -> Result<crate::types::value::Value<'d>, DecodeError> {
+    crate::Decoder::decode_value(
+        &mut Decoder::<0, 0>::new(input, de::DecoderOptions::coer()),
+        descriptor,
+    )
+}
+
 /// Attempts to encode `value` of type `T` to COER.
 ///
 /// # Errors
@@ -32,6 +58,60 @@ pub fn encode<T: crate::Encode>(value: &T) -> Result<alloc::vec::Vec<u8>, Encode
     value.encode(&mut enc)?;
     Ok(enc.output())
 }
+
+/// Attempts to encode `value` as the type described by `descriptor` to
+/// COER, without needing the Rust type of the value.
+///
+/// # Errors
+/// Returns `EncodeError` if `value` is not a valid value of the described
+/// type, or cannot be encoded as COER.
+pub fn encode_value(
+    descriptor: &crate::types::TypeDescriptor,
+    value: &crate::types::value::Value,
+) -> Result<alloc::vec::Vec<u8>, EncodeError> {
+    let mut buffer = alloc::vec::Vec::new();
+    let mut worker = alloc::vec::Vec::new();
+    let mut enc = Encoder::<0>::from_buffer(enc::EncoderOptions::coer(), &mut buffer, &mut worker);
+    crate::Encoder::encode_value(&mut enc, descriptor, value)?;
+    Ok(enc.output())
+}
+
+/// Attempts to encode `value` to COER into the start of `buffer`, returning
+/// the number of bytes written.
+///
+/// # Errors
+/// Returns `EncodeError` if `value` cannot be encoded, or with
+/// [`EncodeErrorKind::BufferTooSmall`](crate::error::EncodeErrorKind::BufferTooSmall)
+/// as soon as the encoding doesn't fit into `buffer`. The contents of
+/// `buffer` are unspecified on error.
+pub fn encode_into<T: crate::Encode>(value: &T, buffer: &mut [u8]) -> Result<usize, EncodeError> {
+    Encoder::encode_to_sink(
+        enc::EncoderOptions::coer(),
+        value,
+        crate::enc::SliceSink::new(buffer),
+    )
+}
+
+/// Attempts to encode `value` to COER, writing it to `writer` as it is
+/// encoded and returning the number of bytes written.
+///
+/// # Errors
+/// Returns `EncodeError` if `value` cannot be encoded, or with
+/// [`EncodeErrorKind::Io`](crate::error::EncodeErrorKind::Io) if writing
+/// fails, in which case part of the encoding may have been written.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn encode_to_writer<T: crate::Encode, W: std::io::Write>(
+    value: &T,
+    writer: W,
+) -> Result<usize, EncodeError> {
+    Encoder::encode_to_sink(
+        enc::EncoderOptions::coer(),
+        value,
+        crate::enc::IoSink::new(writer),
+    )
+}
+
 /// Attempts to encode `value` of type `T` to COER.
 /// Variant of `encode` that writes to a provided existing `buffer`.
 ///
@@ -755,7 +835,6 @@ mod tests {
     }
     #[test]
     fn test_teletext_string() {
-        // For now, Teletex string needs to be aligned for 4 bytes
         round_trip!(
             coer,
             TeletexString,
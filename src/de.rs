@@ -1,13 +1,13 @@
 //! Generic ASN.1 decoding framework.
 
-use alloc::{boxed::Box, vec::Vec};
+use alloc::{borrow::Cow, boxed::Box, vec::Vec};
 use num_bigint::BigInt;
 
 use crate::error::DecodeError;
 use crate::types::{self, AsnType, Constraints, Enumerated, SetOf, Tag};
 
 pub use nom::Needed;
-pub use rasn_derive::Decode;
+pub use rasn_derive::{Decode, DecodeBorrowed};
 
 /// A generic ASN.1 decoding iterator. JER and XER are not supported.
 #[must_use]
@@ -208,6 +208,24 @@ pub trait Decoder<const RCL: usize = 0, const ECL: usize = 0>: Sized {
         &mut self,
         tag: Tag,
     ) -> Result<types::ObjectIdentifier, Self::Error>;
+    /// Decode a `OBJECT IDENTIFIER` identified by `tag` from the available
+    /// input, keeping the contents of its encoding so arcs of any size are
+    /// supported.
+    fn decode_encoded_oid(
+        &mut self,
+        tag: Tag,
+    ) -> Result<types::EncodedObjectIdentifier, Self::Error>;
+    /// Decode a `EXTERNAL` identified by `tag` from the available input.
+    fn decode_external(&mut self, tag: Tag) -> Result<types::External, Self::Error>;
+    /// Decode a `RELATIVE-OID` identified by `tag` from the available input.
+    fn decode_relative_oid(
+        &mut self,
+        tag: Tag,
+    ) -> Result<types::RelativeObjectIdentifier, Self::Error>;
+    /// Decode a `OID-IRI` identified by `tag` from the available input.
+    fn decode_oid_iri(&mut self, tag: Tag) -> Result<types::OidIri, Self::Error>;
+    /// Decode a `RELATIVE-OID-IRI` identified by `tag` from the available input.
+    fn decode_relative_oid_iri(&mut self, tag: Tag) -> Result<types::RelativeOidIri, Self::Error>;
     /// Decode a `SEQUENCE` identified by `tag` from the available input. Returning
     /// a new `Decoder` containing the sequence's contents to be decoded.
     ///
@@ -324,6 +342,14 @@ pub trait Decoder<const RCL: usize = 0, const ECL: usize = 0>: Sized {
     fn decode_generalized_time(&mut self, tag: Tag) -> Result<types::GeneralizedTime, Self::Error>;
     /// Decode a 'DATE' identified by 'tag' from the available input
     fn decode_date(&mut self, tag: Tag) -> Result<types::Date, Self::Error>;
+    /// Decode a `TIME` identified by `tag` from the available input.
+    fn decode_time(&mut self, tag: Tag) -> Result<types::IsoTime, Self::Error>;
+    /// Decode a `TIME-OF-DAY` identified by `tag` from the available input.
+    fn decode_time_of_day(&mut self, tag: Tag) -> Result<types::TimeOfDay, Self::Error>;
+    /// Decode a `DATE-TIME` identified by `tag` from the available input.
+    fn decode_date_time(&mut self, tag: Tag) -> Result<types::DateTime, Self::Error>;
+    /// Decode a `DURATION` identified by `tag` from the available input.
+    fn decode_duration(&mut self, tag: Tag) -> Result<types::IsoDuration, Self::Error>;
 
     /// Decode a `SET` identified by `tag` from the available input. Decoding
     /// `SET`s works a little different than other methods, as you need to
@@ -424,7 +450,7 @@ pub trait Decoder<const RCL: usize = 0, const ECL: usize = 0>: Sized {
     where
         D: Decode,
     {
-        self.decode_extension_addition_with_constraints(Constraints::default())
+        self.decode_extension_addition_with_constraints(D::CONSTRAINTS)
     }
     /// Decode an extension addition with explicit tag in a `SEQUENCE` or `SET`.
     fn decode_extension_addition_with_explicit_tag_and_constraints<D>(
@@ -440,7 +466,7 @@ pub trait Decoder<const RCL: usize = 0, const ECL: usize = 0>: Sized {
     where
         D: Decode,
     {
-        self.decode_extension_addition_with_tag_and_constraints(tag, Constraints::default())
+        self.decode_extension_addition_with_tag_and_constraints(tag, D::CONSTRAINTS)
     }
     /// Decode an extension addition with constraints in a `SEQUENCE` or `SET`
     fn decode_extension_addition_with_constraints<D>(
@@ -466,10 +492,7 @@ pub trait Decoder<const RCL: usize = 0, const ECL: usize = 0>: Sized {
         &mut self,
         default_fn: F,
     ) -> Result<D, Self::Error> {
-        self.decode_extension_addition_with_default_and_constraints(
-            default_fn,
-            Constraints::default(),
-        )
+        self.decode_extension_addition_with_default_and_constraints(default_fn, D::CONSTRAINTS)
     }
     /// Decode a `DEFAULT` value with tag in a `SEQUENCE`'s or `SET`'s extension
     fn decode_extension_addition_with_default_and_tag<D: Decode, F: FnOnce() -> D>(
@@ -480,7 +503,7 @@ pub trait Decoder<const RCL: usize = 0, const ECL: usize = 0>: Sized {
         self.decode_extension_addition_with_default_and_tag_and_constraints::<D, F>(
             tag,
             default_fn,
-            Constraints::default(),
+            D::CONSTRAINTS,
         )
     }
 
@@ -521,6 +544,183 @@ pub trait Decoder<const RCL: usize = 0, const ECL: usize = 0>: Sized {
     >(
         &mut self,
     ) -> Result<Option<D>, Self::Error>;
+
+    /// Decode a value of the type described by `descriptor` into a generic
+    /// [`Value`](types::value::Value) tree, without needing the Rust type of
+    /// the value.
+    ///
+    /// The default implementation returns an error, codecs which support
+    /// decoding with runtime type information override it.
+    fn decode_value<'d>(
+        &mut self,
+        descriptor: &types::TypeDescriptor<'d>,
+    ) -> Result<types::value::Value<'d>, Self::Error> {
+        Err(Self::Error::custom(
+            alloc::format!(
+                "decoding `{}` from a type descriptor is not supported",
+                descriptor.identifier.unwrap_or("value")
+            ),
+            self.codec(),
+        ))
+    }
+}
+
+/// A **data type** that can be decoded from ASN.1 formats while borrowing
+/// from the input, instead of copying into owned values.
+///
+/// Every [`Decode`] type is also `DecodeBorrowed`, so borrowed types such as
+/// [`BorrowedOctetString`](types::BorrowedOctetString) can be mixed freely
+/// with owned types in a `SEQUENCE` deriving `DecodeBorrowed`.
+pub trait DecodeBorrowed<'de>: Sized + AsnType {
+    /// Decode this value from a given ASN.1 decoder, borrowing from its input.
+    fn decode_borrowed<D: BorrowDecoder<'de>>(decoder: &mut D) -> Result<Self, D::Error> {
+        Self::decode_borrowed_with_tag(decoder, Self::TAG)
+    }
+
+    /// Decode this value implicitly tagged with `tag` from a given ASN.1
+    /// decoder, borrowing from its input.
+    fn decode_borrowed_with_tag<D: BorrowDecoder<'de>>(
+        decoder: &mut D,
+        tag: Tag,
+    ) -> Result<Self, D::Error> {
+        Self::decode_borrowed_with_tag_and_constraints(decoder, tag, Self::CONSTRAINTS)
+    }
+
+    /// Decode this value with a set of `constraints` from a given ASN.1
+    /// decoder, borrowing from its input.
+    fn decode_borrowed_with_constraints<D: BorrowDecoder<'de>>(
+        decoder: &mut D,
+        constraints: Constraints,
+    ) -> Result<Self, D::Error> {
+        Self::decode_borrowed_with_tag_and_constraints(decoder, Self::TAG, constraints)
+    }
+
+    /// Decode this value implicitly tagged with `tag` and with a set of
+    /// `constraints` from a given ASN.1 decoder, borrowing from its input.
+    fn decode_borrowed_with_tag_and_constraints<D: BorrowDecoder<'de>>(
+        decoder: &mut D,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<Self, D::Error>;
+}
+
+impl<'de, T: Decode> DecodeBorrowed<'de> for T {
+    fn decode_borrowed<D: BorrowDecoder<'de>>(decoder: &mut D) -> Result<Self, D::Error> {
+        T::decode(decoder)
+    }
+
+    fn decode_borrowed_with_tag<D: BorrowDecoder<'de>>(
+        decoder: &mut D,
+        tag: Tag,
+    ) -> Result<Self, D::Error> {
+        T::decode_with_tag(decoder, tag)
+    }
+
+    fn decode_borrowed_with_constraints<D: BorrowDecoder<'de>>(
+        decoder: &mut D,
+        constraints: Constraints,
+    ) -> Result<Self, D::Error> {
+        T::decode_with_constraints(decoder, constraints)
+    }
+
+    fn decode_borrowed_with_tag_and_constraints<D: BorrowDecoder<'de>>(
+        decoder: &mut D,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<Self, D::Error> {
+        T::decode_with_tag_and_constraints(decoder, tag, constraints)
+    }
+}
+
+/// A **data format** whose input outlives the decoded values, allowing
+/// strings to be returned as slices of the input rather than copies.
+///
+/// Primitive definite length encodings are always borrowed, while values
+/// that need to be reassembled, such as constructed BER strings, fall back
+/// to owned data.
+pub trait BorrowDecoder<'de>: Decoder {
+    /// Helper type for decoding the contents of a `SEQUENCE` with borrowed
+    /// fields.
+    type AnyBorrowDecoder<const R: usize, const E: usize>: BorrowDecoder<'de>
+        + Decoder<Error = Self::Error>;
+
+    /// Decode a `OCTET STRING` identified by `tag` from the available input.
+    fn decode_octet_string_borrowed(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<Cow<'de, [u8]>, Self::Error>;
+
+    /// Decode a `UTF8 STRING` identified by `tag` from the available input.
+    fn decode_utf8_string_borrowed(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<Cow<'de, str>, Self::Error>;
+
+    /// Decode a `IA5String` identified by `tag` from the available input.
+    fn decode_ia5_string_borrowed(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<Cow<'de, str>, Self::Error>;
+
+    /// Decode a `PrintableString` identified by `tag` from the available input.
+    fn decode_printable_string_borrowed(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<Cow<'de, str>, Self::Error>;
+
+    /// Decode a `VisibleString` identified by `tag` from the available input.
+    fn decode_visible_string_borrowed(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<Cow<'de, str>, Self::Error>;
+
+    /// Decode a `NumericString` identified by `tag` from the available input.
+    fn decode_numeric_string_borrowed(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<Cow<'de, str>, Self::Error>;
+
+    /// Decode a `SEQUENCE` identified by `tag` whose fields borrow from the
+    /// available input. See [`Decoder::decode_sequence`] for the meaning of
+    /// the generic parameters.
+    fn decode_sequence_borrowed<const RC: usize, const EC: usize, D, DF, F>(
+        &mut self,
+        tag: Tag,
+        default_initializer_fn: Option<DF>,
+        decode_fn: F,
+    ) -> Result<D, Self::Error>
+    where
+        D: crate::types::Constructed<RC, EC>,
+        DF: FnOnce() -> D,
+        F: FnOnce(&mut Self::AnyBorrowDecoder<RC, EC>) -> Result<D, Self::Error>;
+
+    /// Decode an explicit tag prefixed value identified by `tag`, borrowing
+    /// from the available input.
+    fn decode_explicit_prefix_borrowed<D: DecodeBorrowed<'de>>(
+        &mut self,
+        tag: Tag,
+    ) -> Result<D, Self::Error>;
+
+    /// Decode an optional explicit tag prefixed value identified by `tag`,
+    /// borrowing from the available input.
+    fn decode_optional_with_explicit_prefix_borrowed<D: DecodeBorrowed<'de>>(
+        &mut self,
+        tag: Tag,
+    ) -> Result<Option<D>, Self::Error>;
+
+    /// Decode an optional value in a `SEQUENCE` identified by `tag` with
+    /// `constraints`, borrowing from the available input.
+    fn decode_optional_with_tag_and_constraints_borrowed<D: DecodeBorrowed<'de>>(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<Option<D>, Self::Error>;
 }
 
 /// A generic error that can occur while decoding ASN.1.
@@ -722,6 +922,26 @@ impl Decode for types::ObjectIdentifier {
     }
 }
 
+impl Decode for types::EncodedObjectIdentifier {
+    fn decode_with_tag_and_constraints<D: Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+        _: Constraints,
+    ) -> Result<Self, D::Error> {
+        decoder.decode_encoded_oid(tag)
+    }
+}
+
+impl Decode for types::RelativeObjectIdentifier {
+    fn decode_with_tag_and_constraints<D: Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+        _: Constraints,
+    ) -> Result<Self, D::Error> {
+        decoder.decode_relative_oid(tag)
+    }
+}
+
 impl Decode for types::Utf8String {
     fn decode_with_tag_and_constraints<D: Decoder>(
         decoder: &mut D,
@@ -21,6 +21,74 @@ pub fn decode_with_remainder<T: crate::Decode>(
     Ok((decoded_instance, decoder.remaining()))
 }
 
+/// Attempts to decode `T` from `input` using DER, borrowing from `input`
+/// where possible.
+///
+/// # Errors
+/// Returns `DecodeError` if `input` is not valid DER encoding specific to the expected type.
+pub fn decode_borrowed<'de, T: crate::de::DecodeBorrowed<'de>>(
+    input: &'de [u8],
+) -> Result<T, crate::error::DecodeError> {
+    T::decode_borrowed(&mut de::Decoder::new(input, de::DecoderOptions::der()))
+}
+
+/// Attempts to decode a value of the type described by `descriptor` from
+/// `input` using DER, without needing the Rust type of the value.
+///
+/// # Errors
+/// Returns `DecodeError` if `input` is not valid DER encoding of the described type.
+pub fn decode_value<'d>(
+    descriptor: &crate::types::TypeDescriptor<'d>,
+    input: &[u8],
+) -> Result<crate::types::value::Value<'d>, crate::error::DecodeError> {
+    crate::Decoder::decode_value(
+        &mut crate::ber::de::Decoder::new(input, crate::ber::de::DecoderOptions::der()),
+        descriptor,
+    )
+}
+
+/// Attempts to decode `T` from the first PEM block in `input`, checking
+/// that the block is labelled with one of the [`PemLabel`](crate::pem::PemLabel)
+/// labels of `T`.
+///
+/// # Errors
+/// Returns `DecodeError` if `input` doesn't start with a valid block
+/// labelled for `T`, or if the block isn't valid DER encoding of `T`.
+pub fn decode_pem<T: crate::Decode + crate::pem::PemLabel>(
+    input: &[u8],
+) -> Result<T, crate::error::DecodeError> {
+    let block = crate::pem::parse(input).and_then(|block| {
+        block.check_label::<T>()?;
+        Ok(block)
+    });
+    decode(block.map_err(pem_error)?.contents())
+}
+
+/// Attempts to decode every PEM block in `input` as a `T`, such as the
+/// certificates of a certificate chain. Every block must be labelled with
+/// one of the [`PemLabel`](crate::pem::PemLabel) labels of `T`.
+///
+/// # Errors
+/// Returns `DecodeError` if any block is malformed, labelled for a different
+/// type, or isn't valid DER encoding of `T`.
+pub fn decode_pem_bundle<T: crate::Decode + crate::pem::PemLabel>(
+    input: &[u8],
+) -> Result<alloc::vec::Vec<T>, crate::error::DecodeError> {
+    crate::pem::parse_many(input)
+        .map(|block| {
+            let block = block.and_then(|block| {
+                block.check_label::<T>()?;
+                Ok(block)
+            });
+            decode(block.map_err(pem_error)?.contents())
+        })
+        .collect()
+}
+
+fn pem_error(error: crate::error::PemError) -> crate::error::DecodeError {
+    crate::error::DecodeError::pem(error, crate::Codec::Der)
+}
+
 /// Attempts to encode `value` to DER.
 pub fn encode<T: crate::Encode>(
     value: &T,
@@ -32,6 +100,90 @@ pub fn encode<T: crate::Encode>(
     Ok(enc.output())
 }
 
+/// Attempts to encode `value` to DER, wrapped in a PEM block labelled with
+/// the [`PemLabel`](crate::pem::PemLabel) of `T`.
+pub fn encode_pem<T: crate::Encode + crate::pem::PemLabel>(
+    value: &T,
+) -> Result<alloc::string::String, crate::error::EncodeError> {
+    Ok(crate::pem::encode(T::LABEL, &encode(value)?))
+}
+
+/// Attempts to encode `value` as the type described by `descriptor` to
+/// DER, without needing the Rust type of the value.
+///
+/// # Errors
+/// Returns `EncodeError` if `value` is not a valid value of the described type.
+pub fn encode_value(
+    descriptor: &crate::types::TypeDescriptor,
+    value: &crate::types::value::Value,
+) -> Result<alloc::vec::Vec<u8>, crate::error::EncodeError> {
+    let mut enc = crate::ber::enc::Encoder::new(crate::ber::enc::EncoderOptions::der());
+
+    crate::Encoder::encode_value(&mut enc, descriptor, value)?;
+
+    Ok(enc.output())
+}
+
+/// Attempts to encode `value` to DER into the start of `buffer`, returning
+/// the number of bytes written.
+///
+/// # Errors
+/// Returns `EncodeError` if `value` cannot be encoded, or with
+/// [`EncodeErrorKind::BufferTooSmall`](crate::error::EncodeErrorKind::BufferTooSmall)
+/// as soon as the encoding doesn't fit into `buffer`. The contents of
+/// `buffer` are unspecified on error.
+pub fn encode_into<T: crate::Encode>(
+    value: &T,
+    buffer: &mut [u8],
+) -> Result<usize, crate::error::EncodeError> {
+    crate::ber::enc::Encoder::encode_to_sink(
+        crate::ber::enc::EncoderOptions::der(),
+        value,
+        crate::enc::SliceSink::new(buffer),
+    )
+}
+
+/// Attempts to encode `value` to DER, writing it to `writer` as it is
+/// encoded and returning the number of bytes written.
+///
+/// # Errors
+/// Returns `EncodeError` if `value` cannot be encoded, or with
+/// [`EncodeErrorKind::Io`](crate::error::EncodeErrorKind::Io) if writing
+/// fails, in which case part of the encoding may have been written.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn encode_to_writer<T: crate::Encode, W: std::io::Write>(
+    value: &T,
+    writer: W,
+) -> Result<usize, crate::error::EncodeError> {
+    crate::ber::enc::Encoder::encode_to_sink(
+        crate::ber::enc::EncoderOptions::der(),
+        value,
+        crate::enc::IoSink::new(writer),
+    )
+}
+
+/// Attempts to encode `value` to DER in two passes, first measuring the
+/// length of every constructed value and then writing the encoding forward
+/// into a single buffer. See [`enc::Encoder::encode_two_pass`].
+///
+/// # Errors
+/// Returns `EncodeError` if `value` cannot be encoded.
+pub fn encode_two_pass<T: crate::Encode>(
+    value: &T,
+) -> Result<alloc::vec::Vec<u8>, crate::error::EncodeError> {
+    enc::Encoder::encode_two_pass(enc::EncoderOptions::der(), value)
+}
+
+/// Returns the number of bytes `value` encodes to in DER, without producing
+/// the encoding.
+///
+/// # Errors
+/// Returns `EncodeError` if `value` cannot be encoded.
+pub fn encoded_len<T: crate::Encode>(value: &T) -> Result<usize, crate::error::EncodeError> {
+    enc::Encoder::encoded_len(enc::EncoderOptions::der(), value)
+}
+
 /// Creates a new DER encoder that can be used to encode any value.
 pub fn encode_scope(
     encode_fn: impl FnOnce(&mut crate::ber::enc::Encoder) -> Result<(), crate::error::EncodeError>,
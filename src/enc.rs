@@ -1,5 +1,6 @@
 //! Generic ASN.1 encoding framework.
 
+use crate::error::EncodeError;
 use crate::types::{self, AsnType, Constraints, Enumerated, IntegerType, SetOf, Tag};
 use crate::types::{Identifier, RealType};
 use num_bigint::BigInt;
@@ -106,6 +107,19 @@ pub trait Encode: AsnType {
         constraints: Constraints,
         identifier: Identifier,
     ) -> Result<(), E::Error>;
+
+    /// Returns the number of bytes `self` encodes to in DER, without
+    /// producing the encoding. This runs the measuring pass of
+    /// [`crate::der::encode_two_pass`].
+    ///
+    /// # Errors
+    /// Returns `EncodeError` if `self` cannot be encoded.
+    fn encoded_len(&self) -> Result<usize, crate::error::EncodeError>
+    where
+        Self: Sized,
+    {
+        crate::der::encoded_len(self)
+    }
 }
 
 /// A **data format** encode any ASN.1 data type.
@@ -170,6 +184,47 @@ pub trait Encoder<'encoder, const RCL: usize = 0, const ECL: usize = 0> {
         identifier: Identifier,
     ) -> Result<Self::Ok, Self::Error>;
 
+    /// Encode a `OBJECT IDENTIFIER` value from the contents of its encoding,
+    /// supporting arcs of any size.
+    fn encode_encoded_oid(
+        &mut self,
+        tag: Tag,
+        value: &types::EncodedOid,
+        identifier: Identifier,
+    ) -> Result<Self::Ok, Self::Error>;
+
+    /// Encode a `RELATIVE-OID` value.
+    fn encode_relative_oid(
+        &mut self,
+        tag: Tag,
+        value: &[u32],
+        identifier: Identifier,
+    ) -> Result<Self::Ok, Self::Error>;
+
+    /// Encode a `OID-IRI` value.
+    fn encode_oid_iri(
+        &mut self,
+        tag: Tag,
+        value: &types::OidIri,
+        identifier: Identifier,
+    ) -> Result<Self::Ok, Self::Error>;
+
+    /// Encode a `RELATIVE-OID-IRI` value.
+    fn encode_relative_oid_iri(
+        &mut self,
+        tag: Tag,
+        value: &types::RelativeOidIri,
+        identifier: Identifier,
+    ) -> Result<Self::Ok, Self::Error>;
+
+    /// Encode a `EXTERNAL` value.
+    fn encode_external(
+        &mut self,
+        tag: Tag,
+        value: &types::External,
+        identifier: Identifier,
+    ) -> Result<Self::Ok, Self::Error>;
+
     /// Encode a `INTEGER` value.
     fn encode_integer<I: IntegerType>(
         &mut self,
@@ -305,6 +360,38 @@ pub trait Encoder<'encoder, const RCL: usize = 0, const ECL: usize = 0> {
         identifier: Identifier,
     ) -> Result<Self::Ok, Self::Error>;
 
+    /// Encode a `TIME` value.
+    fn encode_time(
+        &mut self,
+        tag: Tag,
+        value: &types::IsoTime,
+        identifier: Identifier,
+    ) -> Result<Self::Ok, Self::Error>;
+
+    /// Encode a `TIME-OF-DAY` value.
+    fn encode_time_of_day(
+        &mut self,
+        tag: Tag,
+        value: &types::TimeOfDay,
+        identifier: Identifier,
+    ) -> Result<Self::Ok, Self::Error>;
+
+    /// Encode a `DATE-TIME` value.
+    fn encode_date_time(
+        &mut self,
+        tag: Tag,
+        value: &types::DateTime,
+        identifier: Identifier,
+    ) -> Result<Self::Ok, Self::Error>;
+
+    /// Encode a `DURATION` value.
+    fn encode_duration(
+        &mut self,
+        tag: Tag,
+        value: &types::IsoDuration,
+        identifier: Identifier,
+    ) -> Result<Self::Ok, Self::Error>;
+
     /// Encode a explicitly tagged value.
     fn encode_explicit_prefix<V: Encode>(
         &mut self,
@@ -532,6 +619,133 @@ pub trait Encoder<'encoder, const RCL: usize = 0, const ECL: usize = 0> {
     ) -> Result<Self::Ok, Self::Error>
     where
         E: Encode + crate::types::Constructed<RC, EC>;
+
+    /// Encode a generic [`Value`](types::value::Value) tree as the type
+    /// described by `descriptor`, without needing the Rust type of the value.
+    ///
+    /// The default implementation returns an error, codecs which support
+    /// encoding with runtime type information override it.
+    fn encode_value(
+        &mut self,
+        descriptor: &types::TypeDescriptor,
+        _value: &types::value::Value,
+    ) -> Result<Self::Ok, Self::Error> {
+        Err(Self::Error::custom(
+            alloc::format!(
+                "encoding `{}` from a type descriptor is not supported",
+                descriptor.identifier.unwrap_or("value")
+            ),
+            self.codec(),
+        ))
+    }
+}
+
+/// An output that encoders write the encoded bytes into as they are produced.
+///
+/// Implemented for `Vec<u8>`, which grows as needed, for [`SliceSink`],
+/// which writes into a fixed buffer, and, with the `std` feature, for
+/// [`IoSink`], which writes to an [`std::io::Write`].
+pub trait Sink {
+    /// Appends `bytes` to the output.
+    ///
+    /// # Errors
+    /// Returns `EncodeError` if the bytes can't be written, such as when the
+    /// output is full.
+    fn write(&mut self, bytes: &[u8], codec: crate::Codec) -> Result<(), EncodeError>;
+}
+
+impl Sink for alloc::vec::Vec<u8> {
+    fn write(&mut self, bytes: &[u8], _: crate::Codec) -> Result<(), EncodeError> {
+        self.extend_from_slice(bytes);
+        Ok(())
+    }
+}
+
+impl<S: Sink + ?Sized> Sink for &mut S {
+    fn write(&mut self, bytes: &[u8], codec: crate::Codec) -> Result<(), EncodeError> {
+        (**self).write(bytes, codec)
+    }
+}
+
+/// A [`Sink`] writing into a fixed buffer from its start.
+#[derive(Debug)]
+pub struct SliceSink<'buffer> {
+    buffer: &'buffer mut [u8],
+    position: usize,
+}
+
+impl<'buffer> SliceSink<'buffer> {
+    /// Creates a sink writing into `buffer`.
+    pub fn new(buffer: &'buffer mut [u8]) -> Self {
+        Self {
+            buffer,
+            position: 0,
+        }
+    }
+
+    /// Returns the number of bytes written so far.
+    #[must_use]
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl Sink for SliceSink<'_> {
+    /// Copies `bytes` after the bytes written so far, or returns
+    /// [`EncodeErrorKind::BufferTooSmall`](crate::error::EncodeErrorKind::BufferTooSmall)
+    /// if they don't fit, in which case nothing is written.
+    fn write(&mut self, bytes: &[u8], codec: crate::Codec) -> Result<(), EncodeError> {
+        let end = self.position + bytes.len();
+        let available = self.buffer.len();
+        self.buffer
+            .get_mut(self.position..end)
+            .ok_or_else(|| EncodeError::buffer_too_small(end, available, codec))?
+            .copy_from_slice(bytes);
+        self.position = end;
+        Ok(())
+    }
+}
+
+/// A [`Sink`] writing to an [`std::io::Write`].
+///
+/// The sink does not buffer, wrap unbuffered writers such as files or sockets
+/// in a [`std::io::BufWriter`].
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Debug)]
+pub struct IoSink<W> {
+    writer: W,
+    written: usize,
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write> IoSink<W> {
+    /// Creates a sink writing to `writer`.
+    pub fn new(writer: W) -> Self {
+        Self { writer, written: 0 }
+    }
+
+    /// Returns the number of bytes written so far.
+    #[must_use]
+    pub fn written(&self) -> usize {
+        self.written
+    }
+
+    /// Consumes the sink, returning the writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write> Sink for IoSink<W> {
+    fn write(&mut self, bytes: &[u8], codec: crate::Codec) -> Result<(), EncodeError> {
+        self.writer
+            .write_all(bytes)
+            .map_err(|error| EncodeError::io(error, codec))?;
+        self.written += bytes.len();
+        Ok(())
+    }
 }
 
 /// A generic error that occurred while trying to encode ASN.1.
@@ -902,6 +1116,62 @@ impl Encode for types::Oid {
     }
 }
 
+impl Encode for types::EncodedObjectIdentifier {
+    fn encode_with_tag_and_constraints<'b, E: Encoder<'b>>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        _: Constraints,
+        identifier: Identifier,
+    ) -> Result<(), E::Error> {
+        encoder
+            .encode_encoded_oid(tag, self, identifier.or(Self::IDENTIFIER))
+            .map(drop)
+    }
+}
+
+impl Encode for types::EncodedOid {
+    fn encode_with_tag_and_constraints<'b, E: Encoder<'b>>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        _: Constraints,
+        identifier: Identifier,
+    ) -> Result<(), E::Error> {
+        encoder
+            .encode_encoded_oid(tag, self, identifier.or(Self::IDENTIFIER))
+            .map(drop)
+    }
+}
+
+impl Encode for types::RelativeObjectIdentifier {
+    fn encode_with_tag_and_constraints<'b, E: Encoder<'b>>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        _: Constraints,
+        identifier: Identifier,
+    ) -> Result<(), E::Error> {
+        encoder
+            .encode_relative_oid(tag, self, identifier.or(Self::IDENTIFIER))
+            .map(drop)
+    }
+}
+
+impl Encode for types::RelativeOid {
+    fn encode_with_tag_and_constraints<'b, E: Encoder<'b>>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        _: Constraints,
+        identifier: Identifier,
+    ) -> Result<(), E::Error> {
+        encoder
+            .encode_relative_oid(tag, self, identifier.or(Self::IDENTIFIER))
+            .map(drop)
+    }
+}
+
 impl Encode for types::UtcTime {
     fn encode_with_tag_and_constraints<'b, E: Encoder<'b>>(
         &self,
@@ -7,14 +7,19 @@
 mod components;
 mod decode;
 mod encode;
+mod pem;
+#[cfg(feature = "schema")]
+mod schema;
 mod string;
+mod validate;
 
 pub mod strings {
     //! Errors specific to string conversions, permitted alphabets, and other type problems.
     pub use super::string::{
         InvalidBmpString, InvalidGeneralString, InvalidGraphicString, InvalidIA5String,
-        InvalidNumericString, InvalidPrintableString, InvalidRestrictedString,
-        InvalidTeletexString, InvalidVisibleString, PermittedAlphabetError,
+        InvalidIriString, InvalidNumericString, InvalidOidString, InvalidPrintableString,
+        InvalidRestrictedString, InvalidTeletexString, InvalidTimeString, InvalidVisibleString,
+        PermittedAlphabetError,
     };
 }
 
@@ -30,3 +35,7 @@ pub use encode::{
 };
 
 pub use components::InnerSubtypeConstraintError;
+pub use pem::PemError;
+#[cfg(feature = "schema")]
+pub use schema::SchemaError;
+pub use validate::{ConstraintViolation, ConstraintViolationKind, ValidationError};
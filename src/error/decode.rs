@@ -3,7 +3,7 @@
 use core::num::ParseIntError;
 
 use super::strings::PermittedAlphabetError;
-use alloc::{boxed::Box, string::ToString};
+use alloc::{borrow::Cow, boxed::Box, string::ToString};
 
 use snafu::Snafu;
 #[cfg(feature = "backtraces")]
@@ -163,6 +163,19 @@ impl DecodeError {
         Self::from_kind(DecodeErrorKind::Eof, codec)
     }
 
+    /// Creates a wrapper around an I/O error from a given codec.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn io(source: std::io::Error, codec: Codec) -> Self {
+        Self::from_kind(DecodeErrorKind::Io { source }, codec)
+    }
+
+    /// Creates a wrapper around a PEM parsing error from a given codec.
+    #[must_use]
+    pub fn pem(source: super::PemError, codec: Codec) -> Self {
+        Self::from_kind(DecodeErrorKind::Pem { source }, codec)
+    }
+
     /// Creates a wrapper around a permitted alphabet error from a given codec.
     #[must_use]
     pub fn permitted_alphabet_error(reason: PermittedAlphabetError, codec: Codec) -> Self {
@@ -207,6 +220,29 @@ impl DecodeError {
         )
     }
 
+    /// Creates the error of a given codec for a value decoded with strict
+    /// constraint enforcement which violates its constraints.
+    #[must_use]
+    pub fn constraint_violation(kind: super::ConstraintViolationKind, codec: Codec) -> Self {
+        match kind {
+            super::ConstraintViolationKind::Value { value, expected } => {
+                Self::value_constraint_not_satisfied(value, **expected, codec)
+            }
+            super::ConstraintViolationKind::Size { size, expected } => {
+                Self::size_constraint_not_satisfied(Some(size), expected.to_string(), codec)
+            }
+            super::ConstraintViolationKind::PermittedAlphabet { character } => {
+                Self::permitted_alphabet_error(
+                    PermittedAlphabetError::CharacterNotFound { character },
+                    codec,
+                )
+            }
+            super::ConstraintViolationKind::InnerSubtype { source } => {
+                Self::inner_subtype_constraint_not_satisfied(source, codec)
+            }
+        }
+    }
+
     /// Creates a wrapper around a discriminant value error from a given codec.
     #[must_use]
     pub fn discriminant_value_not_found(discriminant: isize, codec: Codec) -> Self {
@@ -216,6 +252,18 @@ impl DecodeError {
         )
     }
 
+    /// Creates a wrapper around an unknown information object error from a
+    /// given codec.
+    #[must_use]
+    pub fn unknown_information_object(identifier: &impl core::fmt::Debug, codec: Codec) -> Self {
+        Self::from_kind(
+            DecodeErrorKind::UnknownInformationObject {
+                identifier: alloc::format!("{identifier:?}"),
+            },
+            codec,
+        )
+    }
+
     /// Creates a wrapper around a range value error from a given codec.
     #[must_use]
     pub fn range_exceeds_platform_width(needed: u32, present: u32, codec: Codec) -> Self {
@@ -381,6 +429,51 @@ impl DecodeError {
         }
     }
 
+    /// Creates a wrapper around an error decoding the field `name` of a type
+    /// described at runtime, copying the name out of its descriptor.
+    pub(crate) fn described_field_error(name: &str, nested: DecodeError, codec: Codec) -> Self {
+        Self::from_kind(
+            DecodeErrorKind::FieldError {
+                name: Cow::Owned(name.into()),
+                nested: Box::new(nested),
+            },
+            codec,
+        )
+    }
+
+    /// Creates a missing field error for the field `name` of a type described
+    /// at runtime.
+    pub(crate) fn described_missing_field(name: &str, codec: Codec) -> Self {
+        Self::from_kind(
+            DecodeErrorKind::MissingField {
+                name: Cow::Owned(name.into()),
+            },
+            codec,
+        )
+    }
+
+    /// Creates a duplicate field error for the field `name` of a type
+    /// described at runtime.
+    pub(crate) fn described_duplicate_field(name: &str, codec: Codec) -> Self {
+        Self::from_kind(
+            DecodeErrorKind::DuplicateField {
+                name: Cow::Owned(name.into()),
+            },
+            codec,
+        )
+    }
+
+    /// Creates a no valid choice error for the `CHOICE` type `name` described
+    /// at runtime.
+    pub(crate) fn described_no_valid_choice(name: &str, codec: Codec) -> Self {
+        Self::from_kind(
+            DecodeErrorKind::NoValidChoice {
+                name: Cow::Owned(name.into()),
+            },
+            codec,
+        )
+    }
+
     pub(crate) fn map_nom_err<T: core::fmt::Debug>(
         error: nom::Err<nom::error::Error<T>>,
         codec: Codec,
@@ -528,7 +621,7 @@ pub enum DecodeErrorKind {
     #[snafu(display("Duplicate field for `{}`", name))]
     DuplicateField {
         /// The field's name.
-        name: &'static str,
+        name: Cow<'static, str>,
     },
 
     /// Exceeds maxmium allowed length.
@@ -549,7 +642,7 @@ pub enum DecodeErrorKind {
     #[snafu(display("Error when decoding field `{}`: {}", name, nested))]
     FieldError {
         /// The field's name.
-        name: &'static str,
+        name: Cow<'static, str>,
         /// The underlying error type.
         nested: Box<DecodeError>,
     },
@@ -567,6 +660,21 @@ pub enum DecodeErrorKind {
     #[snafu(display("EOF when decoding"))]
     Eof,
 
+    /// Reading from the underlying input source failed.
+    #[cfg(feature = "std")]
+    #[snafu(display("I/O error when reading input: {source}"))]
+    Io {
+        /// The error returned by the reader.
+        source: std::io::Error,
+    },
+
+    /// The PEM text surrounding the encoding is invalid.
+    #[snafu(display("Invalid PEM text: {source}"))]
+    Pem {
+        /// The error returned by the PEM parser.
+        source: super::PemError,
+    },
+
     /// Invalid item number in sequence.
     #[snafu(display(
         "Invalid item number in Sequence: expected {}, actual {}",
@@ -635,7 +743,7 @@ pub enum DecodeErrorKind {
     #[snafu(display("Missing field `{}`", name))]
     MissingField {
         /// The field's name.
-        name: &'static str,
+        name: Cow<'static, str>,
     },
     /// When there is a mismatch between the expected and actual tag class or `value`.
     #[snafu(display("Expected class: {}, value: {} in sequence or set Missing tag class or value in sequence or set", class, value))]
@@ -697,7 +805,7 @@ pub enum DecodeErrorKind {
     #[snafu(display("No valid choice for `{}`", name))]
     NoValidChoice {
         /// The field's name.
-        name: &'static str,
+        name: Cow<'static, str>,
     },
 
     /// An error when the type is not extensible when it should.
@@ -722,6 +830,13 @@ pub enum DecodeErrorKind {
         "No input was provided where expected in the given SEQUENCE or INTEGER type"
     ))]
     UnexpectedEmptyInput,
+    /// An open type's identifier didn't match any object of its information
+    /// object set.
+    #[snafu(display("No information object with identifier {identifier} in the object set"))]
+    UnknownInformationObject {
+        /// The identifier, formatted with `Debug`.
+        identifier: alloc::string::String,
+    },
 }
 
 /// `DecodeError` kinds of `Kind::CodecSpecific` which are specific for BER.
@@ -729,6 +844,12 @@ pub enum DecodeErrorKind {
 #[snafu(visibility(pub))]
 #[non_exhaustive]
 pub enum BerDecodeErrorKind {
+    /// An error when values are nested deeper than allowed.
+    #[snafu(display("Values are nested deeper than the maximum depth of {max}."))]
+    ExceedsMaxDepth {
+        /// The maximum depth.
+        max: usize,
+    },
     /// An error when the length is not definite.
     #[snafu(display("Indefinite length encountered but not allowed."))]
     IndefiniteLengthNotAllowed,
@@ -1022,17 +1143,17 @@ impl crate::de::Error for DecodeError {
     }
 
     fn missing_field(name: &'static str, codec: Codec) -> Self {
-        Self::from_kind(DecodeErrorKind::MissingField { name }, codec)
+        Self::from_kind(DecodeErrorKind::MissingField { name: name.into() }, codec)
     }
 
     fn no_valid_choice(name: &'static str, codec: Codec) -> Self {
-        Self::from_kind(DecodeErrorKind::NoValidChoice { name }, codec)
+        Self::from_kind(DecodeErrorKind::NoValidChoice { name: name.into() }, codec)
     }
 
     fn field_error(name: &'static str, nested: DecodeError, codec: Codec) -> Self {
         Self::from_kind(
             DecodeErrorKind::FieldError {
-                name,
+                name: name.into(),
                 nested: Box::new(nested),
             },
             codec,
@@ -1040,7 +1161,7 @@ impl crate::de::Error for DecodeError {
     }
 
     fn duplicate_field(name: &'static str, codec: Codec) -> Self {
-        Self::from_kind(DecodeErrorKind::DuplicateField { name }, codec)
+        Self::from_kind(DecodeErrorKind::DuplicateField { name: name.into() }, codec)
     }
     fn unknown_field(index: usize, tag: Tag, codec: Codec) -> Self {
         Self::from_kind(DecodeErrorKind::UnknownField { index, tag }, codec)
@@ -175,6 +175,32 @@ impl EncodeError {
         )
     }
 
+    /// Returns an encode error for `codec` when a value encoded with strict
+    /// constraint enforcement violates its constraints.
+    #[must_use]
+    pub fn constraint_violation(kind: super::ConstraintViolationKind, codec: crate::Codec) -> Self {
+        match kind {
+            super::ConstraintViolationKind::Value { value, expected } => {
+                Self::value_constraint_not_satisfied(value, &expected, codec)
+            }
+            super::ConstraintViolationKind::Size { size, expected } => {
+                Self::size_constraint_not_satisfied(size, &expected, codec)
+            }
+            super::ConstraintViolationKind::PermittedAlphabet { character } => {
+                Self::alphabet_constraint_not_satisfied(
+                    super::strings::PermittedAlphabetError::CharacterNotFound { character },
+                    codec,
+                )
+            }
+            super::ConstraintViolationKind::InnerSubtype { source } => Self::from_kind(
+                EncodeErrorKind::Custom {
+                    msg: source.to_string(),
+                },
+                codec,
+            ),
+        }
+    }
+
     /// Check the passed `length` against the expected size and return an error if it does not match.
     pub fn check_length(length: usize, expected: &Size, codec: crate::Codec) -> Result<(), Self> {
         expected.contains_or_else(&length, || Self {
@@ -220,6 +246,49 @@ impl EncodeError {
         Self::from_kind(EncodeErrorKind::RealNotSuppored, codec)
     }
 
+    /// Create an error when a `TIME-OF-DAY` or `DATE-TIME` value has a
+    /// fraction of a second.
+    #[must_use]
+    pub fn fractional_seconds(codec: crate::Codec) -> Self {
+        Self::from_kind(EncodeErrorKind::FractionalSeconds, codec)
+    }
+
+    /// Create an error when an open type's value doesn't belong to the
+    /// information object identified by `identifier`.
+    #[must_use]
+    pub fn information_object_mismatch(
+        identifier: &impl core::fmt::Debug,
+        codec: crate::Codec,
+    ) -> Self {
+        Self::from_kind(
+            EncodeErrorKind::InformationObjectMismatch {
+                identifier: alloc::format!("{identifier:?}"),
+            },
+            codec,
+        )
+    }
+
+    /// Create an error when the encoded value of `needed` bytes does not fit
+    /// into a buffer of `available` bytes.
+    #[must_use]
+    pub fn buffer_too_small(needed: usize, available: usize, codec: crate::Codec) -> Self {
+        Self::from_kind(EncodeErrorKind::BufferTooSmall { needed, available }, codec)
+    }
+
+    /// Create an error when a value encodes differently in the writing pass
+    /// than in the measuring pass.
+    #[must_use]
+    pub fn inconsistent_encoding(codec: crate::Codec) -> Self {
+        Self::from_kind(EncodeErrorKind::InconsistentEncoding, codec)
+    }
+
+    /// Create an error when writing the encoded value to the output failed.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn io(source: std::io::Error, codec: crate::Codec) -> Self {
+        Self::from_kind(EncodeErrorKind::Io { source }, codec)
+    }
+
     /// A helper function to construct an `EncodeError` from the given `kind` and `codec`.
     #[must_use]
     pub fn from_kind(kind: EncodeErrorKind, codec: crate::Codec) -> Self {
@@ -288,6 +357,29 @@ pub enum EncodeErrorKind {
         /// Expected number of bytes
         expected: usize,
     },
+    /// Error when the encoded value does not fit into the provided buffer.
+    #[snafu(display(
+        "buffer too small for the encoded value, needed: {needed}; available: {available}"
+    ))]
+    BufferTooSmall {
+        /// The length of the encoded value
+        needed: usize,
+        /// The length of the provided buffer
+        available: usize,
+    },
+    /// Error when a value encodes differently in the second pass of an
+    /// encoding written directly into a sink than it was measured in the
+    /// first pass, such as when its `Encode` implementation isn't
+    /// deterministic.
+    #[snafu(display("Inconsistent encoding between the measuring and writing passes"))]
+    InconsistentEncoding,
+    /// Error when writing the encoded value to the output failed.
+    #[cfg(feature = "std")]
+    #[snafu(display("I/O error when writing output: {source}"))]
+    Io {
+        /// The error returned by the writer
+        source: std::io::Error,
+    },
     /// Error when the custom error is thrown.
     #[snafu(display("custom error:\n{}", msg))]
     Custom {
@@ -341,6 +433,19 @@ pub enum EncodeErrorKind {
     /// Error when we try to encode a `REAL` type with an unspported codec.
     #[snafu(display("Encoder doesn't support `REAL` type"))]
     RealNotSuppored,
+
+    /// Error when a `TIME-OF-DAY` or `DATE-TIME` value has a fraction of a
+    /// second, which those types can't represent.
+    #[snafu(display("TIME-OF-DAY and DATE-TIME values can't have a fraction of a second"))]
+    FractionalSeconds,
+
+    /// Error when an open type's value belongs to a different information
+    /// object than the one identified by its identifier field.
+    #[snafu(display("Open type value doesn't belong to the information object {identifier}"))]
+    InformationObjectMismatch {
+        /// The identifier, formatted with `Debug`.
+        identifier: alloc::string::String,
+    },
 }
 /// `EncodeError` kinds of `Kind::CodecSpecific` which are specific for BER.
 #[derive(Snafu, Debug)]
@@ -358,6 +463,21 @@ pub enum BerEncodeErrorKind {
         /// Bytes of the invalid object identifier
         oid: alloc::vec::Vec<u32>,
     },
+    /// A constructed value was written with a different length than was
+    /// measured for it, when encoding in two passes.
+    #[snafu(display(
+        "Inconsistent encoding, measured length: {measured}; written length: {written}"
+    ))]
+    InconsistentLength {
+        /// The length of the contents in the measuring pass
+        measured: usize,
+        /// The length of the contents in the writing pass
+        written: usize,
+    },
+    /// A constructed value was written that was not seen in the measuring
+    /// pass, when encoding in two passes.
+    #[snafu(display("Inconsistent encoding, constructed value was not measured"))]
+    UnmeasuredValue,
 }
 impl BerEncodeErrorKind {
     /// Create an error [`BerEncodeErrorKind::InvalidObjectIdentifier`}.
@@ -0,0 +1,61 @@
+use alloc::string::String;
+use snafu::Snafu;
+
+/// An error that occurred when parsing textual encodings with
+/// [`pem`](crate::pem).
+#[derive(Debug, Clone, PartialEq, Eq, Snafu)]
+#[snafu(visibility(pub))]
+#[non_exhaustive]
+pub enum PemError {
+    /// The input doesn't contain any encapsulation boundary.
+    #[snafu(display("No PEM block found"))]
+    MissingBlock,
+    /// An encapsulation boundary line that isn't of the form
+    /// `-----BEGIN label-----` or `-----END label-----`.
+    #[snafu(display("Malformed encapsulation boundary on line {line}"))]
+    InvalidBoundary {
+        /// The line the error occurred on, starting from one.
+        line: usize,
+    },
+    /// A label containing characters other than printable ASCII, or
+    /// consecutive, leading or trailing spaces and hyphens.
+    #[snafu(display("Invalid label `{label}` on line {line}"))]
+    InvalidLabel {
+        /// The line the error occurred on, starting from one.
+        line: usize,
+        /// The label that was found.
+        label: String,
+    },
+    /// A block that isn't closed by a post-encapsulation boundary.
+    #[snafu(display("Missing `-----END {label}-----` for the block on line {line}"))]
+    MissingEnd {
+        /// The line the block starts on, starting from one.
+        line: usize,
+        /// The label of the block.
+        label: String,
+    },
+    /// A block closed with a different label than it was opened with.
+    #[snafu(display("Block on line {line} labelled `{begin}` ends with `{end}`"))]
+    MismatchedLabel {
+        /// The line the block starts on, starting from one.
+        line: usize,
+        /// The label of the pre-encapsulation boundary.
+        begin: String,
+        /// The label of the post-encapsulation boundary.
+        end: String,
+    },
+    /// The text between the boundaries isn't valid base64.
+    #[snafu(display("Invalid base64 text in the block on line {line}"))]
+    InvalidBase64 {
+        /// The line the block starts on, starting from one.
+        line: usize,
+    },
+    /// A block whose label doesn't match the type being decoded.
+    #[snafu(display("Expected a `{expected}` block, found `{found}`"))]
+    UnexpectedLabel {
+        /// The label of the type being decoded.
+        expected: &'static str,
+        /// The label that was found.
+        found: String,
+    },
+}
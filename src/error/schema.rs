@@ -0,0 +1,45 @@
+use alloc::string::String;
+use snafu::Snafu;
+
+/// An error that occurred when loading ASN.1 modules with
+/// [`Schema`](crate::schema::Schema).
+#[derive(Debug, Clone, PartialEq, Eq, Snafu)]
+#[snafu(visibility(pub))]
+#[non_exhaustive]
+pub enum SchemaError {
+    /// The module text isn't valid ASN.1 notation.
+    #[snafu(display("Syntax error on line {line}: expected {expected}, found {found}"))]
+    Syntax {
+        /// The line the error occurred on, starting from one.
+        line: usize,
+        /// A description of the expected item.
+        expected: &'static str,
+        /// The item that was found instead.
+        found: String,
+    },
+    /// A type, value or module reference that isn't defined in the schema.
+    #[snafu(display("Undefined reference to `{name}`"))]
+    UndefinedReference {
+        /// The name of the reference.
+        name: String,
+    },
+    /// A type which is defined only in terms of itself, without a type that
+    /// has a tag of its own in between, such as `A ::= B` and `B ::= A`.
+    #[snafu(display("Type `{name}` is defined only in terms of itself"))]
+    RecursiveType {
+        /// The name of the recursive type.
+        name: String,
+    },
+    /// A construct that isn't supported by descriptors.
+    #[snafu(display("Unsupported ASN.1 construct: {construct}"))]
+    Unsupported {
+        /// A description of the construct.
+        construct: &'static str,
+    },
+    /// A type that is syntactically valid, but not a valid ASN.1 type.
+    #[snafu(display("Invalid type: {reason}"))]
+    InvalidType {
+        /// The reason the type is invalid.
+        reason: String,
+    },
+}
@@ -72,6 +72,37 @@ pub struct InvalidPrintableString {
     pub character: u32,
 }
 
+/// A string which isn't a valid value of an ASN.1 time type.
+#[derive(snafu::Snafu, Debug)]
+#[snafu(visibility(pub))]
+#[snafu(display("Invalid {} string: {}", kind, value))]
+pub struct InvalidTimeString {
+    /// The name of the time type.
+    pub kind: &'static str,
+    /// The invalid string.
+    pub value: String,
+}
+
+/// A string which isn't a valid value of `OID-IRI` or `RELATIVE-OID-IRI`.
+#[derive(snafu::Snafu, Debug)]
+#[snafu(visibility(pub))]
+#[snafu(display("Invalid {} string: {}", kind, value))]
+pub struct InvalidIriString {
+    /// The name of the IRI type.
+    pub kind: &'static str,
+    /// The invalid string.
+    pub value: String,
+}
+
+/// A string which isn't an object identifier in dotted or value notation.
+#[derive(snafu::Snafu, Debug)]
+#[snafu(visibility(pub))]
+#[snafu(display("Invalid object identifier string: {}", value))]
+pub struct InvalidOidString {
+    /// The invalid string.
+    pub value: String,
+}
+
 macro_rules! from_u32 {
     ($($type:ident),*) => {
         $(
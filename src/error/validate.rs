@@ -0,0 +1,66 @@
+use alloc::{boxed::Box, string::String, vec::Vec};
+
+use num_bigint::BigInt;
+use snafu::Snafu;
+
+use crate::types::constraints::{Size, Value};
+
+/// A constraint which a value doesn't satisfy.
+#[derive(Debug, Snafu)]
+#[snafu(visibility(pub))]
+#[non_exhaustive]
+pub enum ConstraintViolationKind {
+    /// The value is outside of the value constraint.
+    #[snafu(display("Value {value} is not in {expected}"))]
+    Value {
+        /// The actual value.
+        value: BigInt,
+        /// The permitted values.
+        expected: Box<Value>,
+    },
+    /// The size of the value is outside of the size constraint.
+    #[snafu(display("Size {size} is not in {expected}"))]
+    Size {
+        /// The actual size.
+        size: usize,
+        /// The permitted sizes.
+        expected: Size,
+    },
+    /// The value contains a character outside of the permitted alphabet.
+    #[snafu(display("Character with decimal value {character} is not in the permitted alphabet"))]
+    PermittedAlphabet {
+        /// The character which isn't permitted.
+        character: u32,
+    },
+    /// The components of the value don't satisfy its inner subtype constraint.
+    #[snafu(display("{source}"))]
+    InnerSubtype {
+        /// The inner subtype constraint error.
+        source: super::InnerSubtypeConstraintError,
+    },
+}
+
+/// A violation of a constraint by a value, found with
+/// [`Validate`](crate::types::Validate).
+#[derive(Debug, Snafu)]
+#[snafu(display("{}{kind}", if path.is_empty() { String::new() } else { alloc::format!("{path}: ") }))]
+pub struct ConstraintViolation {
+    /// The path from the validated value to the violating component, e.g.
+    /// `items[2].name`, which is empty for the validated value itself.
+    pub path: String,
+    /// The constraint which isn't satisfied.
+    pub kind: ConstraintViolationKind,
+}
+
+/// The error returned when validating a value which violates any of its
+/// constraints.
+#[derive(Debug, Snafu)]
+#[snafu(display(
+    "{} constraint violation(s): {}",
+    violations.len(),
+    violations.iter().map(alloc::string::ToString::to_string).collect::<Vec<_>>().join("; ")
+))]
+pub struct ValidationError {
+    /// Every violation found, in the order the components were visited.
+    pub violations: Vec<ConstraintViolation>,
+}
@@ -10,6 +10,17 @@ pub fn decode<T: crate::Decode>(input: &str) -> Result<T, crate::error::DecodeEr
     T::decode(&mut de::Decoder::new(input)?)
 }
 
+/// Attempts to decode a value of the type described by `descriptor` from
+/// `input` using JER, without needing the Rust type of the value.
+/// # Errors
+/// Returns error specific to JER decoder if decoding is not possible.
+pub fn decode_value<'d>(
+    descriptor: &crate::types::TypeDescriptor<'d>,
+    input: &str,
+) -> Result<crate::types::value::Value<'d>, crate::error::DecodeError> {
+    crate::Decoder::decode_value(&mut de::Decoder::new(input)?, descriptor)
+}
+
 /// Attempts to encode `value` to JER.
 /// # Errors
 /// Returns error specific to JER encoder if encoding is not possible.
@@ -21,6 +32,19 @@ pub fn encode<T: crate::Encode>(
     Ok(encoder.to_string())
 }
 
+/// Attempts to encode `value` as the type described by `descriptor` to JER,
+/// without needing the Rust type of the value.
+/// # Errors
+/// Returns error specific to JER encoder if encoding is not possible.
+pub fn encode_value(
+    descriptor: &crate::types::TypeDescriptor,
+    value: &crate::types::value::Value,
+) -> Result<alloc::string::String, crate::error::EncodeError> {
+    let mut encoder = enc::Encoder::new();
+    crate::Encoder::encode_value(&mut encoder, descriptor, value)?;
+    Ok(encoder.to_string())
+}
+
 #[cfg(test)]
 mod tests {
     macro_rules! round_trip_jer {
@@ -256,6 +280,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn any() {
+        use core::hash::{BuildHasher, Hash, Hasher};
+
+        let decoded = crate::jer::decode::<Any>("[1,2]").unwrap();
+        let opaque = Any::new(b"[1,2]".to_vec());
+        assert_eq!(opaque, decoded);
+        assert_eq!(core::cmp::Ordering::Equal, opaque.cmp(&decoded));
+
+        let state = std::hash::RandomState::new();
+        let hash = |any: &Any| {
+            let mut hasher = state.build_hasher();
+            any.hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash(&opaque), hash(&decoded));
+
+        // Only the decoded JSON text is written as is.
+        assert_eq!("[1,2]", crate::jer::encode(&decoded).unwrap());
+        assert_eq!("\"5B312C325D\"", crate::jer::encode(&opaque).unwrap());
+    }
+
     #[test]
     fn string_types() {
         round_trip_string_type!(NumericString);
@@ -267,6 +313,52 @@ mod tests {
         round_trip_string_type!(Utf8String);
     }
 
+    #[test]
+    fn time_types() {
+        let time = TimeOfDay::from_hms_opt(12, 30, 45).unwrap();
+        round_trip_jer!(IsoTime, "R/P1D".parse().unwrap(), "\"R/P1D\"");
+        round_trip_jer!(TimeOfDay, time, "\"123045\"");
+        round_trip_jer!(
+            DateTime,
+            Date::from_ymd_opt(2012, 12, 21).unwrap().and_time(time),
+            "\"20121221T123045\""
+        );
+        round_trip_jer!(IsoDuration, "PT0.5S".parse().unwrap(), "\"PT0.5S\"");
+    }
+
+    #[test]
+    fn encoded_oid_types() {
+        round_trip_jer!(
+            EncodedObjectIdentifier,
+            crate::encoded_oid!("2.25.329800735698586629295641978511506172918").to_owned(),
+            "\"2.25.329800735698586629295641978511506172918\""
+        );
+    }
+
+    #[test]
+    fn relative_oid_types() {
+        round_trip_jer!(
+            RelativeObjectIdentifier,
+            RelativeObjectIdentifier::new(vec![8571, 3, 2]).unwrap(),
+            "\"8571.3.2\""
+        );
+        round_trip_jer!(OidIri, "/ISO/0".parse().unwrap(), "\"/ISO/0\"");
+        round_trip_jer!(RelativeOidIri, "ISO/0".parse().unwrap(), "\"ISO/0\"");
+    }
+
+    #[test]
+    fn external() {
+        round_trip_jer!(
+            External,
+            External {
+                identification: Identification::Fixed,
+                data_value_descriptor: None,
+                data_value: OctetString::from_static(&[0xab]),
+            },
+            "{\"data-value\":\"AB\",\"identification\":{\"fixed\":null}}"
+        );
+    }
+
     #[test]
     fn enumerated() {
         round_trip_jer!(SimpleEnum, SimpleEnum::Test1, "\"Test1\"");
@@ -1,15 +1,22 @@
 //! Decoding JSON Encoding Rules data into Rust structures.
 
+use alloc::string::ToString;
+
 use serde_json::Value;
 
 use crate::{
     de::Error,
-    error::{DecodeError, JerDecodeErrorKind},
+    error::{ConstraintViolationKind, DecodeError, JerDecodeErrorKind},
     types::{
-        variants, Any, BitString, BmpString, Constraints, Constructed, Date, DecodeChoice,
-        Enumerated, GeneralString, GeneralizedTime, GraphicString, Ia5String, NumericString,
-        ObjectIdentifier, Oid, PrintableString, SequenceOf, SetOf, Tag, TeletexString, UtcTime,
-        Utf8String, VisibleString,
+        descriptor::{TypeDescriptor, TypeKind},
+        strings::StaticPermittedAlphabet,
+        validate,
+        value::{self, NamedValue},
+        variants, Any, BitString, BmpString, Constraints, Constructed, Date, DateTime,
+        DecodeChoice, EncodedObjectIdentifier, Enumerated, GeneralString, GeneralizedTime,
+        GraphicString, Ia5String, IsoDuration, IsoTime, NumericString, ObjectIdentifier, Oid,
+        OidIri, PrintableString, RelativeObjectIdentifier, RelativeOidIri, SequenceOf, SetOf, Tag,
+        TeletexString, TimeOfDay, UtcTime, Utf8String, VisibleString,
     },
     Decode,
 };
@@ -26,6 +33,7 @@ macro_rules! decode_jer_value {
 /// Decodes JSON Encoding Rules data into Rust structures.
 pub struct Decoder {
     stack: alloc::vec::Vec<Value>,
+    enforce_constraints: bool,
 }
 
 impl Decoder {
@@ -39,14 +47,36 @@ impl Decoder {
         })?;
         Ok(Self {
             stack: alloc::vec![root],
+            enforce_constraints: false,
         })
     }
+
+    /// Sets whether the value, size and permitted alphabet constraints of
+    /// values are checked when decoding, failing on the first violation.
+    #[must_use]
+    pub fn enforce_constraints(mut self, enforce: bool) -> Self {
+        self.enforce_constraints = enforce;
+        self
+    }
+
+    /// Checks a constraint of the decoded value, if constraints are enforced.
+    fn enforce(
+        &self,
+        check: impl FnOnce() -> Result<(), ConstraintViolationKind>,
+    ) -> Result<(), DecodeError> {
+        if self.enforce_constraints {
+            check().map_err(|kind| DecodeError::constraint_violation(kind, crate::Codec::Jer))
+        } else {
+            Ok(())
+        }
+    }
 }
 
 impl From<Value> for Decoder {
     fn from(value: Value) -> Self {
         Self {
             stack: alloc::vec![value],
+            enforce_constraints: false,
         }
     }
 }
@@ -127,6 +157,7 @@ impl crate::Decoder for Decoder {
         }
 
         if bitstring_length == padded.len() {
+            self.enforce(|| validate::check_size(padded.len(), &constraints))?;
             Ok(padded)
         } else {
             Err(DecodeError::custom(
@@ -147,9 +178,12 @@ impl crate::Decoder for Decoder {
     fn decode_integer<I: crate::types::IntegerType>(
         &mut self,
         _t: Tag,
-        _c: Constraints,
+        constraints: Constraints,
     ) -> Result<I, Self::Error> {
-        decode_jer_value!(Self::integer_from_value::<I>, self.stack)
+        let value = decode_jer_value!(Self::integer_from_value::<I>, self.stack)?;
+        self.enforce(|| validate::check_value(&value, &constraints))?;
+
+        Ok(value)
     }
 
     fn decode_real<R: crate::types::RealType>(
@@ -168,6 +202,26 @@ impl crate::Decoder for Decoder {
         decode_jer_value!(Self::object_identifier_from_value, self.stack)
     }
 
+    fn decode_encoded_oid(&mut self, _t: Tag) -> Result<EncodedObjectIdentifier, Self::Error> {
+        decode_jer_value!(Self::encoded_oid_from_value, self.stack)
+    }
+
+    fn decode_relative_oid(&mut self, _t: Tag) -> Result<RelativeObjectIdentifier, Self::Error> {
+        decode_jer_value!(Self::relative_oid_from_value, self.stack)
+    }
+
+    fn decode_oid_iri(&mut self, _t: Tag) -> Result<OidIri, Self::Error> {
+        decode_jer_value!(Self::oid_iri_from_value, self.stack)
+    }
+
+    fn decode_relative_oid_iri(&mut self, _t: Tag) -> Result<RelativeOidIri, Self::Error> {
+        decode_jer_value!(Self::relative_oid_iri_from_value, self.stack)
+    }
+
+    fn decode_external(&mut self, tag: Tag) -> Result<crate::types::External, Self::Error> {
+        crate::types::External::decode_associated(self, tag)
+    }
+
     fn decode_sequence<const RC: usize, const EC: usize, D, DF, F>(
         &mut self,
         _: Tag,
@@ -204,37 +258,55 @@ impl crate::Decoder for Decoder {
     fn decode_sequence_of<D: crate::Decode>(
         &mut self,
         _t: Tag,
-        _c: Constraints,
+        constraints: Constraints,
     ) -> Result<SequenceOf<D>, Self::Error> {
-        decode_jer_value!(|v| self.sequence_of_from_value(v), self.stack)
+        let items = decode_jer_value!(|v| self.sequence_of_from_value(v), self.stack)?;
+        self.enforce(|| validate::check_size(items.len(), &constraints))?;
+
+        Ok(items)
     }
 
     fn decode_set_of<D: crate::Decode + Eq + core::hash::Hash>(
         &mut self,
         _t: Tag,
-        _c: Constraints,
+        constraints: Constraints,
     ) -> Result<SetOf<D>, Self::Error> {
-        decode_jer_value!(|v| self.set_of_from_value(v), self.stack)
+        let items = decode_jer_value!(|v| self.set_of_from_value(v), self.stack)?;
+        self.enforce(|| validate::check_size(items.len(), &constraints))?;
+
+        Ok(items)
     }
 
     fn decode_octet_string<'b, T: From<alloc::vec::Vec<u8>> + From<&'b [u8]>>(
         &'b mut self,
         _: Tag,
-        _c: Constraints,
+        constraints: Constraints,
     ) -> Result<T, Self::Error> {
-        decode_jer_value!(Self::octet_string_from_value, self.stack).map(T::from)
+        let octets = decode_jer_value!(Self::octet_string_from_value, self.stack)?;
+        self.enforce(|| validate::check_size(octets.len(), &constraints))?;
+
+        Ok(T::from(octets))
     }
 
-    fn decode_utf8_string(&mut self, _t: Tag, _c: Constraints) -> Result<Utf8String, Self::Error> {
-        decode_jer_value!(Self::string_from_value, self.stack)
+    fn decode_utf8_string(
+        &mut self,
+        _t: Tag,
+        constraints: Constraints,
+    ) -> Result<Utf8String, Self::Error> {
+        let string = decode_jer_value!(Self::string_from_value, self.stack)?;
+        self.enforce(|| {
+            validate::check_characters(|| string.chars().map(u32::from), &constraints)
+        })?;
+
+        Ok(string)
     }
 
     fn decode_visible_string(
         &mut self,
         _t: Tag,
-        _c: Constraints,
+        constraints: Constraints,
     ) -> Result<VisibleString, Self::Error> {
-        decode_jer_value!(Self::string_from_value, self.stack)?
+        let string: VisibleString = decode_jer_value!(Self::string_from_value, self.stack)?
             .try_into()
             .map_err(|e| {
                 DecodeError::string_conversion_failed(
@@ -242,15 +314,18 @@ impl crate::Decoder for Decoder {
                     alloc::format!("Error transforming VisibleString: {e:?}"),
                     crate::Codec::Jer,
                 )
-            })
+            })?;
+        self.enforce(|| validate::check_characters(|| string.chars(), &constraints))?;
+
+        Ok(string)
     }
 
     fn decode_general_string(
         &mut self,
         _t: Tag,
-        _c: Constraints,
+        constraints: Constraints,
     ) -> Result<GeneralString, Self::Error> {
-        decode_jer_value!(Self::string_from_value, self.stack)?
+        let string: GeneralString = decode_jer_value!(Self::string_from_value, self.stack)?
             .try_into()
             .map_err(|e| {
                 DecodeError::string_conversion_failed(
@@ -258,15 +333,18 @@ impl crate::Decoder for Decoder {
                     alloc::format!("Error transforming GeneralString: {e:?}"),
                     crate::Codec::Jer,
                 )
-            })
+            })?;
+        self.enforce(|| validate::check_characters(|| string.chars(), &constraints))?;
+
+        Ok(string)
     }
 
     fn decode_graphic_string(
         &mut self,
         _t: Tag,
-        _c: Constraints,
+        constraints: Constraints,
     ) -> Result<GraphicString, Self::Error> {
-        decode_jer_value!(Self::string_from_value, self.stack)?
+        let string: GraphicString = decode_jer_value!(Self::string_from_value, self.stack)?
             .try_into()
             .map_err(|e| {
                 DecodeError::string_conversion_failed(
@@ -274,11 +352,18 @@ impl crate::Decoder for Decoder {
                     alloc::format!("Error transforming GeneralString: {e:?}"),
                     crate::Codec::Jer,
                 )
-            })
+            })?;
+        self.enforce(|| validate::check_characters(|| string.chars(), &constraints))?;
+
+        Ok(string)
     }
 
-    fn decode_ia5_string(&mut self, _t: Tag, _c: Constraints) -> Result<Ia5String, Self::Error> {
-        decode_jer_value!(Self::string_from_value, self.stack)?
+    fn decode_ia5_string(
+        &mut self,
+        _t: Tag,
+        constraints: Constraints,
+    ) -> Result<Ia5String, Self::Error> {
+        let string: Ia5String = decode_jer_value!(Self::string_from_value, self.stack)?
             .try_into()
             .map_err(|e| {
                 DecodeError::string_conversion_failed(
@@ -286,15 +371,18 @@ impl crate::Decoder for Decoder {
                     alloc::format!("Error transforming IA5String: {e:?}"),
                     crate::Codec::Jer,
                 )
-            })
+            })?;
+        self.enforce(|| validate::check_characters(|| string.chars(), &constraints))?;
+
+        Ok(string)
     }
 
     fn decode_printable_string(
         &mut self,
         _t: Tag,
-        _c: Constraints,
+        constraints: Constraints,
     ) -> Result<PrintableString, Self::Error> {
-        decode_jer_value!(Self::string_from_value, self.stack)?
+        let string: PrintableString = decode_jer_value!(Self::string_from_value, self.stack)?
             .try_into()
             .map_err(|e| {
                 DecodeError::string_conversion_failed(
@@ -302,15 +390,18 @@ impl crate::Decoder for Decoder {
                     alloc::format!("Error transforming PrintableString: {e:?}"),
                     crate::Codec::Jer,
                 )
-            })
+            })?;
+        self.enforce(|| validate::check_characters(|| string.chars(), &constraints))?;
+
+        Ok(string)
     }
 
     fn decode_numeric_string(
         &mut self,
         _t: Tag,
-        _c: Constraints,
+        constraints: Constraints,
     ) -> Result<NumericString, Self::Error> {
-        decode_jer_value!(Self::string_from_value, self.stack)?
+        let string: NumericString = decode_jer_value!(Self::string_from_value, self.stack)?
             .try_into()
             .map_err(|e| {
                 DecodeError::string_conversion_failed(
@@ -318,19 +409,37 @@ impl crate::Decoder for Decoder {
                     alloc::format!("Error transforming NumericString: {e:?}"),
                     crate::Codec::Jer,
                 )
-            })
+            })?;
+        self.enforce(|| validate::check_characters(|| string.chars(), &constraints))?;
+
+        Ok(string)
     }
 
     fn decode_teletex_string(
         &mut self,
         _t: Tag,
-        _c: Constraints,
+        constraints: Constraints,
     ) -> Result<TeletexString, Self::Error> {
-        todo!()
+        let string: TeletexString = decode_jer_value!(Self::string_from_value, self.stack)?
+            .try_into()
+            .map_err(|e| {
+                DecodeError::string_conversion_failed(
+                    Tag::TELETEX_STRING,
+                    alloc::format!("Error transforming TeletexString: {e:?}"),
+                    crate::Codec::Jer,
+                )
+            })?;
+        self.enforce(|| validate::check_characters(|| string.chars(), &constraints))?;
+
+        Ok(string)
     }
 
-    fn decode_bmp_string(&mut self, _t: Tag, _c: Constraints) -> Result<BmpString, Self::Error> {
-        decode_jer_value!(Self::string_from_value, self.stack)?
+    fn decode_bmp_string(
+        &mut self,
+        _t: Tag,
+        constraints: Constraints,
+    ) -> Result<BmpString, Self::Error> {
+        let string: BmpString = decode_jer_value!(Self::string_from_value, self.stack)?
             .try_into()
             .map_err(|e| {
                 DecodeError::string_conversion_failed(
@@ -338,7 +447,10 @@ impl crate::Decoder for Decoder {
                     alloc::format!("Error transforming BMPString: {e:?}"),
                     crate::Codec::Jer,
                 )
-            })
+            })?;
+        self.enforce(|| validate::check_characters(|| string.chars(), &constraints))?;
+
+        Ok(string)
     }
     fn decode_optional_with_explicit_prefix<D: Decode>(
         &mut self,
@@ -363,6 +475,22 @@ impl crate::Decoder for Decoder {
         decode_jer_value!(Self::date_from_value, self.stack)
     }
 
+    fn decode_time(&mut self, _t: Tag) -> Result<IsoTime, Self::Error> {
+        decode_jer_value!(Self::time_from_value, self.stack)
+    }
+
+    fn decode_time_of_day(&mut self, _t: Tag) -> Result<TimeOfDay, Self::Error> {
+        decode_jer_value!(Self::time_of_day_from_value, self.stack)
+    }
+
+    fn decode_date_time(&mut self, _t: Tag) -> Result<DateTime, Self::Error> {
+        decode_jer_value!(Self::date_time_from_value, self.stack)
+    }
+
+    fn decode_duration(&mut self, _t: Tag) -> Result<IsoDuration, Self::Error> {
+        decode_jer_value!(Self::duration_from_value, self.stack)
+    }
+
     fn decode_set<const RC: usize, const EC: usize, FIELDS, SET, D, F>(
         &mut self,
         _t: Tag,
@@ -483,6 +611,132 @@ impl crate::Decoder for Decoder {
     fn codec(&self) -> crate::Codec {
         crate::Codec::Jer
     }
+
+    fn decode_value<'d>(
+        &mut self,
+        descriptor: &TypeDescriptor<'d>,
+    ) -> Result<value::Value<'d>, Self::Error> {
+        let descriptor = &descriptor.resolve();
+        match descriptor.kind {
+            TypeKind::Enumerated {
+                variants,
+                extended_variants,
+            } => {
+                let value = self.stack.pop().ok_or_else(JerDecodeErrorKind::eoi)?;
+                let identifier =
+                    value
+                        .as_str()
+                        .ok_or_else(|| JerDecodeErrorKind::TypeMismatch {
+                            needed: "enumerated item as string",
+                            found: alloc::format!("{value}"),
+                        })?;
+                Ok(variants
+                    .iter()
+                    .chain(extended_variants.into_iter().flatten())
+                    .find(|variant| variant.name == identifier)
+                    .map(|variant| value::Value::Enumerated(variant.name))
+                    .ok_or_else(|| JerDecodeErrorKind::InvalidEnumDiscriminant {
+                        discriminant: alloc::string::String::from(identifier),
+                    })?)
+            }
+            TypeKind::Sequence {
+                fields,
+                extended_fields,
+            }
+            | TypeKind::Set {
+                fields,
+                extended_fields,
+            } => {
+                let mut last = self.stack.pop().ok_or_else(JerDecodeErrorKind::eoi)?;
+                let value_map =
+                    last.as_object_mut()
+                        .ok_or_else(|| JerDecodeErrorKind::TypeMismatch {
+                            needed: "object",
+                            found: "unknown".into(),
+                        })?;
+                let mut components = alloc::vec::Vec::new();
+                for (index, field) in fields
+                    .iter()
+                    .chain(extended_fields.into_iter().flatten())
+                    .enumerate()
+                {
+                    let value = value_map.remove(field.name).unwrap_or(Value::Null);
+                    if value.is_null()
+                        && (index >= fields.len() || field.presence.is_optional_or_default())
+                    {
+                        continue;
+                    }
+                    self.stack.push(value);
+                    let value = self.decode_value(field.ty).map_err(|error| {
+                        DecodeError::described_field_error(field.name, error, self.codec())
+                    })?;
+                    components.push(NamedValue::new(field.name, value));
+                }
+
+                Ok(if matches!(descriptor.kind, TypeKind::Set { .. }) {
+                    value::Value::Set(components)
+                } else {
+                    value::Value::Sequence(components)
+                })
+            }
+            TypeKind::Choice {
+                variants,
+                extended_variants,
+            } => {
+                let value = self.stack.pop().ok_or_else(JerDecodeErrorKind::eoi)?;
+                let (variant, value) = value
+                    .as_object()
+                    .ok_or_else(|| JerDecodeErrorKind::TypeMismatch {
+                        needed: "object",
+                        found: alloc::format!("{value}"),
+                    })?
+                    .iter()
+                    .next()
+                    .and_then(|(k, v)| {
+                        variants
+                            .iter()
+                            .chain(extended_variants.into_iter().flatten())
+                            .find(|variant| variant.name.eq_ignore_ascii_case(k))
+                            .map(|variant| (variant, v.clone()))
+                    })
+                    .ok_or_else(|| {
+                        DecodeError::described_no_valid_choice(
+                            descriptor.identifier.unwrap_or("CHOICE"),
+                            self.codec(),
+                        )
+                    })?;
+                self.stack.push(value);
+
+                Ok(value::Value::Choice {
+                    variant: variant.name,
+                    value: alloc::boxed::Box::new(self.decode_value(variant.ty)?),
+                })
+            }
+            TypeKind::SequenceOf { element } | TypeKind::SetOf { element } => {
+                let value = self.stack.pop().ok_or_else(JerDecodeErrorKind::eoi)?;
+                let elements = value
+                    .as_array()
+                    .ok_or_else(|| JerDecodeErrorKind::TypeMismatch {
+                        needed: "array",
+                        found: alloc::format!("{value}"),
+                    })?
+                    .iter()
+                    .map(|v| {
+                        self.stack.push(v.clone());
+                        self.decode_value(element)
+                    })
+                    .collect::<Result<_, _>>()?;
+
+                Ok(if matches!(descriptor.kind, TypeKind::SetOf { .. }) {
+                    value::Value::SetOf(elements)
+                } else {
+                    value::Value::SequenceOf(elements)
+                })
+            }
+            TypeKind::Explicit { inner } => self.decode_value(inner),
+            _ => value::Value::decode_primitive(self, descriptor),
+        }
+    }
 }
 
 // -------------------------------------------------------------------
@@ -493,7 +747,10 @@ impl crate::Decoder for Decoder {
 
 impl Decoder {
     fn any_from_value(value: Value) -> Result<Any, <Self as crate::de::Decoder>::Error> {
-        Ok(Any::new(alloc::format!("{value}").as_bytes().to_vec()))
+        Ok(Any::with_codec(
+            crate::Codec::Jer,
+            alloc::format!("{value}").into_bytes(),
+        ))
     }
 
     fn boolean_from_value(value: Value) -> Result<bool, DecodeError> {
@@ -592,6 +849,61 @@ impl Decoder {
             .ok_or_else(|| JerDecodeErrorKind::InvalidOIDString { value })?)
     }
 
+    fn encoded_oid_from_value(value: Value) -> Result<EncodedObjectIdentifier, DecodeError> {
+        // For performance reasons, sometimes it is better to use lazy one
+        #[allow(clippy::unnecessary_lazy_evaluations)]
+        Ok(value
+            .as_str()
+            .ok_or_else(|| JerDecodeErrorKind::TypeMismatch {
+                needed: "number array",
+                found: alloc::format!("{value}"),
+            })?
+            .parse::<EncodedObjectIdentifier>()
+            .ok()
+            .ok_or_else(|| JerDecodeErrorKind::InvalidOIDString { value })?)
+    }
+
+    fn relative_oid_from_value(value: Value) -> Result<RelativeObjectIdentifier, DecodeError> {
+        // For performance reasons, sometimes it is better to use lazy one
+        #[allow(clippy::unnecessary_lazy_evaluations)]
+        Ok(value
+            .as_str()
+            .ok_or_else(|| JerDecodeErrorKind::TypeMismatch {
+                needed: "number array",
+                found: alloc::format!("{value}"),
+            })?
+            .split('.')
+            .map(str::parse::<u32>)
+            .collect::<Result<alloc::vec::Vec<u32>, _>>()
+            .ok()
+            .and_then(RelativeObjectIdentifier::new)
+            .ok_or_else(|| JerDecodeErrorKind::InvalidOIDString { value })?)
+    }
+
+    fn oid_iri_from_value(value: Value) -> Result<OidIri, DecodeError> {
+        Self::string_from_value(value)?
+            .parse::<OidIri>()
+            .map_err(|error| {
+                DecodeError::string_conversion_failed(
+                    Tag::OID_IRI,
+                    error.to_string(),
+                    crate::Codec::Jer,
+                )
+            })
+    }
+
+    fn relative_oid_iri_from_value(value: Value) -> Result<RelativeOidIri, DecodeError> {
+        Self::string_from_value(value)?
+            .parse::<RelativeOidIri>()
+            .map_err(|error| {
+                DecodeError::string_conversion_failed(
+                    Tag::RELATIVE_OID_IRI,
+                    error.to_string(),
+                    crate::Codec::Jer,
+                )
+            })
+    }
+
     fn sequence_of_from_value<D: Decode>(
         &mut self,
         value: Value,
@@ -720,6 +1032,42 @@ impl Decoder {
             }
         })?)
     }
+
+    fn time_from_value(value: Value) -> Result<IsoTime, DecodeError> {
+        crate::ber::de::Decoder::parse_time_string(value.as_str().ok_or_else(|| {
+            JerDecodeErrorKind::TypeMismatch {
+                needed: "TIME string",
+                found: alloc::format!("{value}"),
+            }
+        })?)
+    }
+
+    fn time_of_day_from_value(value: Value) -> Result<TimeOfDay, DecodeError> {
+        crate::ber::de::Decoder::parse_time_of_day_string(value.as_str().ok_or_else(|| {
+            JerDecodeErrorKind::TypeMismatch {
+                needed: "TIME-OF-DAY string",
+                found: alloc::format!("{value}"),
+            }
+        })?)
+    }
+
+    fn date_time_from_value(value: Value) -> Result<DateTime, DecodeError> {
+        crate::ber::de::Decoder::parse_date_time_string(value.as_str().ok_or_else(|| {
+            JerDecodeErrorKind::TypeMismatch {
+                needed: "DATE-TIME string",
+                found: alloc::format!("{value}"),
+            }
+        })?)
+    }
+
+    fn duration_from_value(value: Value) -> Result<IsoDuration, DecodeError> {
+        crate::ber::de::Decoder::parse_duration_string(value.as_str().ok_or_else(|| {
+            JerDecodeErrorKind::TypeMismatch {
+                needed: "DURATION string",
+                found: alloc::format!("{value}"),
+            }
+        })?)
+    }
 }
 
 /// Parses a hex string into bytes.
@@ -1,23 +1,28 @@
 //! Encoding Rust structures into JSON Encoding Rules data.
 
-use alloc::string::ToString;
+use alloc::{borrow::Cow, string::ToString};
 
 use serde_json::{Map, Value};
 
 type ValueMap = Map<alloc::string::String, Value>;
 
 use crate::{
-    error::{EncodeError, JerEncodeErrorKind},
-    types::{variants, Constraints, Identifier, IntegerType, Tag},
+    error::{ConstraintViolationKind, EncodeError, JerEncodeErrorKind},
+    types::{
+        descriptor::{TypeDescriptor, TypeKind},
+        strings::StaticPermittedAlphabet,
+        validate, value, variants, Constraints, Identifier, IntegerType, Tag,
+    },
 };
 
 use crate::types::RealType;
 
 /// Encodes Rust structures into JSON Encoding Rules data.
 pub struct Encoder {
-    stack: alloc::vec::Vec<&'static str>,
+    stack: alloc::vec::Vec<Cow<'static, str>>,
     constructed_stack: alloc::vec::Vec<ValueMap>,
     root_value: Option<Value>,
+    enforce_constraints: bool,
 }
 
 impl Default for Encoder {
@@ -33,6 +38,28 @@ impl Encoder {
             stack: alloc::vec![],
             constructed_stack: alloc::vec![],
             root_value: None,
+            enforce_constraints: false,
+        }
+    }
+
+    /// Sets whether the value, size and permitted alphabet constraints of
+    /// values are checked when encoding, failing on the first violation.
+    #[must_use]
+    pub fn enforce_constraints(mut self, enforce: bool) -> Self {
+        self.enforce_constraints = enforce;
+        self
+    }
+
+    /// Checks a constraint of the value being encoded, if constraints are
+    /// enforced.
+    fn enforce(
+        &self,
+        check: impl FnOnce() -> Result<(), ConstraintViolationKind>,
+    ) -> Result<(), EncodeError> {
+        if self.enforce_constraints {
+            check().map_err(|kind| EncodeError::constraint_violation(kind, crate::Codec::Jer))
+        } else {
+            Ok(())
         }
     }
 
@@ -57,7 +84,7 @@ impl Encoder {
                     .ok_or_else(|| JerEncodeErrorKind::JsonEncoder {
                         msg: "Internal stack mismatch!".into(),
                     })?
-                    .insert(id.to_string(), value);
+                    .insert(id.into_owned(), value);
             }
             None => {
                 self.root_value = Some(value);
@@ -79,12 +106,21 @@ impl crate::Encoder<'_> for Encoder {
         value: &crate::types::Any,
         _: Identifier,
     ) -> Result<Self::Ok, Self::Error> {
-        self.encode_octet_string(
-            t,
-            Constraints::default(),
-            &value.contents,
-            Identifier::EMPTY,
-        )
+        // Values encoded with other codecs are written as octet strings.
+        if !value.json {
+            return self.encode_octet_string(
+                t,
+                Constraints::default(),
+                &value.contents,
+                Identifier::EMPTY,
+            );
+        }
+        let value = serde_json::from_slice(&value.contents).map_err(|e| {
+            JerEncodeErrorKind::JsonEncodingError {
+                upstream: e.to_string(),
+            }
+        })?;
+        self.update_root_or_constructed(value)
     }
 
     fn encode_bool(&mut self, _: Tag, value: bool, _: Identifier) -> Result<Self::Ok, Self::Error> {
@@ -98,6 +134,7 @@ impl crate::Encoder<'_> for Encoder {
         value: &crate::types::BitStr,
         _: Identifier,
     ) -> Result<Self::Ok, Self::Error> {
+        self.enforce(|| validate::check_size(value.len(), &constraints))?;
         let mut bitvec = value.to_bitvec();
         bitvec.force_align();
         let bytes = bitvec
@@ -144,13 +181,59 @@ impl crate::Encoder<'_> for Encoder {
         ))
     }
 
+    fn encode_encoded_oid(
+        &mut self,
+        _t: Tag,
+        value: &crate::types::EncodedOid,
+        _: Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.update_root_or_constructed(Value::String(value.to_string()))
+    }
+
+    fn encode_relative_oid(
+        &mut self,
+        t: Tag,
+        value: &[u32],
+        identifier: Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_object_identifier(t, value, identifier)
+    }
+
+    fn encode_oid_iri(
+        &mut self,
+        _t: Tag,
+        value: &crate::types::OidIri,
+        _: Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.update_root_or_constructed(Value::String(value.to_string()))
+    }
+
+    fn encode_relative_oid_iri(
+        &mut self,
+        _t: Tag,
+        value: &crate::types::RelativeOidIri,
+        _: Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.update_root_or_constructed(Value::String(value.to_string()))
+    }
+
+    fn encode_external(
+        &mut self,
+        tag: Tag,
+        value: &crate::types::External,
+        identifier: Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        value.encode_associated(self, tag, identifier)
+    }
+
     fn encode_integer<I: IntegerType>(
         &mut self,
         _t: Tag,
-        _c: crate::types::Constraints,
+        constraints: crate::types::Constraints,
         value: &I,
         _: Identifier,
     ) -> Result<Self::Ok, Self::Error> {
+        self.enforce(|| validate::check_value(value, &constraints))?;
         if let Some(as_i64) = value.to_i64() {
             self.update_root_or_constructed(Value::Number(as_i64.into()))
         } else {
@@ -198,10 +281,11 @@ impl crate::Encoder<'_> for Encoder {
     fn encode_octet_string(
         &mut self,
         _t: Tag,
-        _c: crate::types::Constraints,
+        constraints: crate::types::Constraints,
         value: &[u8],
         _: Identifier,
     ) -> Result<Self::Ok, Self::Error> {
+        self.enforce(|| validate::check_size(value.len(), &constraints))?;
         self.update_root_or_constructed(Value::String(value.iter().fold(
             alloc::string::String::new(),
             |mut acc, bit| {
@@ -214,10 +298,11 @@ impl crate::Encoder<'_> for Encoder {
     fn encode_general_string(
         &mut self,
         _t: Tag,
-        _c: crate::types::Constraints,
+        constraints: crate::types::Constraints,
         value: &crate::types::GeneralString,
         _: Identifier,
     ) -> Result<Self::Ok, Self::Error> {
+        self.enforce(|| validate::check_characters(|| value.chars(), &constraints))?;
         self.update_root_or_constructed(Value::String(
             alloc::string::String::from_utf8(value.to_vec())
                 .map_err(|e| JerEncodeErrorKind::InvalidCharacter { error: e })?,
@@ -227,10 +312,11 @@ impl crate::Encoder<'_> for Encoder {
     fn encode_graphic_string(
         &mut self,
         _t: Tag,
-        _c: crate::types::Constraints,
+        constraints: crate::types::Constraints,
         value: &crate::types::GraphicString,
         _: Identifier,
     ) -> Result<Self::Ok, Self::Error> {
+        self.enforce(|| validate::check_characters(|| value.chars(), &constraints))?;
         self.update_root_or_constructed(Value::String(
             alloc::string::String::from_utf8(value.to_vec())
                 .map_err(|e| JerEncodeErrorKind::InvalidCharacter { error: e })?,
@@ -240,20 +326,22 @@ impl crate::Encoder<'_> for Encoder {
     fn encode_utf8_string(
         &mut self,
         _t: Tag,
-        _c: crate::types::Constraints,
+        constraints: crate::types::Constraints,
         value: &str,
         _: Identifier,
     ) -> Result<Self::Ok, Self::Error> {
+        self.enforce(|| validate::check_characters(|| value.chars().map(u32::from), &constraints))?;
         self.update_root_or_constructed(Value::String(value.into()))
     }
 
     fn encode_visible_string(
         &mut self,
         _t: Tag,
-        _c: crate::types::Constraints,
+        constraints: crate::types::Constraints,
         value: &crate::types::VisibleString,
         _: Identifier,
     ) -> Result<Self::Ok, Self::Error> {
+        self.enforce(|| validate::check_characters(|| value.chars(), &constraints))?;
         self.update_root_or_constructed(Value::String(
             alloc::string::String::from_utf8(value.as_iso646_bytes().to_vec())
                 .map_err(|e| JerEncodeErrorKind::InvalidCharacter { error: e })?,
@@ -263,10 +351,11 @@ impl crate::Encoder<'_> for Encoder {
     fn encode_ia5_string(
         &mut self,
         _t: Tag,
-        _c: crate::types::Constraints,
+        constraints: crate::types::Constraints,
         value: &crate::types::Ia5String,
         _: Identifier,
     ) -> Result<Self::Ok, Self::Error> {
+        self.enforce(|| validate::check_characters(|| value.chars(), &constraints))?;
         self.update_root_or_constructed(Value::String(
             alloc::string::String::from_utf8(value.as_iso646_bytes().to_vec())
                 .map_err(|e| JerEncodeErrorKind::InvalidCharacter { error: e })?,
@@ -276,10 +365,11 @@ impl crate::Encoder<'_> for Encoder {
     fn encode_printable_string(
         &mut self,
         _t: Tag,
-        _c: crate::types::Constraints,
+        constraints: crate::types::Constraints,
         value: &crate::types::PrintableString,
         _: Identifier,
     ) -> Result<Self::Ok, Self::Error> {
+        self.enforce(|| validate::check_characters(|| value.chars(), &constraints))?;
         self.update_root_or_constructed(Value::String(
             alloc::string::String::from_utf8(value.as_bytes().to_vec())
                 .map_err(|e| JerEncodeErrorKind::InvalidCharacter { error: e })?,
@@ -289,10 +379,11 @@ impl crate::Encoder<'_> for Encoder {
     fn encode_numeric_string(
         &mut self,
         _t: Tag,
-        _c: crate::types::Constraints,
+        constraints: crate::types::Constraints,
         value: &crate::types::NumericString,
         _: Identifier,
     ) -> Result<Self::Ok, Self::Error> {
+        self.enforce(|| validate::check_characters(|| value.chars(), &constraints))?;
         self.update_root_or_constructed(Value::String(
             alloc::string::String::from_utf8(value.as_bytes().to_vec())
                 .map_err(|e| JerEncodeErrorKind::InvalidCharacter { error: e })?,
@@ -302,20 +393,26 @@ impl crate::Encoder<'_> for Encoder {
     fn encode_teletex_string(
         &mut self,
         _t: Tag,
-        _c: crate::types::Constraints,
-        _value: &crate::types::TeletexString,
+        constraints: crate::types::Constraints,
+        value: &crate::types::TeletexString,
         _: Identifier,
     ) -> Result<Self::Ok, Self::Error> {
-        todo!()
+        self.enforce(|| validate::check_characters(|| value.chars(), &constraints))?;
+        self.update_root_or_constructed(Value::String(
+            value.to_unicode().map_err(|e| {
+                EncodeError::alphabet_constraint_not_satisfied(e, crate::Codec::Jer)
+            })?,
+        ))
     }
 
     fn encode_bmp_string(
         &mut self,
         _t: Tag,
-        _c: crate::types::Constraints,
+        constraints: crate::types::Constraints,
         value: &crate::types::BmpString,
         _: Identifier,
     ) -> Result<Self::Ok, Self::Error> {
+        self.enforce(|| validate::check_characters(|| value.chars(), &constraints))?;
         self.update_root_or_constructed(Value::String(
             alloc::string::String::from_utf8(value.to_bytes())
                 .map_err(|e| JerEncodeErrorKind::InvalidCharacter { error: e })?,
@@ -364,6 +461,55 @@ impl crate::Encoder<'_> for Encoder {
         ))
     }
 
+    fn encode_time(
+        &mut self,
+        _t: Tag,
+        value: &crate::types::IsoTime,
+        _: Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.update_root_or_constructed(Value::String(value.to_string()))
+    }
+
+    fn encode_time_of_day(
+        &mut self,
+        _t: Tag,
+        value: &crate::types::TimeOfDay,
+        _: Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.update_root_or_constructed(Value::String(
+            alloc::string::String::from_utf8(
+                crate::ber::enc::Encoder::naivetime_to_time_of_day_bytes(value, crate::Codec::Jer)?,
+            )
+            .map_err(|e| JerEncodeErrorKind::InvalidCharacter { error: e })?,
+        ))
+    }
+
+    fn encode_date_time(
+        &mut self,
+        _t: Tag,
+        value: &crate::types::DateTime,
+        _: Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.update_root_or_constructed(Value::String(
+            alloc::string::String::from_utf8(
+                crate::ber::enc::Encoder::naivedatetime_to_date_time_bytes(
+                    value,
+                    crate::Codec::Jer,
+                )?,
+            )
+            .map_err(|e| JerEncodeErrorKind::InvalidCharacter { error: e })?,
+        ))
+    }
+
+    fn encode_duration(
+        &mut self,
+        _t: Tag,
+        value: &crate::types::IsoDuration,
+        _: Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.update_root_or_constructed(Value::String(value.to_string()))
+    }
+
     fn encode_explicit_prefix<V: crate::Encode>(
         &mut self,
         _: Tag,
@@ -392,7 +538,7 @@ impl crate::Encoder<'_> for Encoder {
         }
         field_names.reverse();
         for name in field_names {
-            self.stack.push(name);
+            self.stack.push(Cow::Borrowed(name));
         }
         self.constructed_stack.push(ValueMap::new());
         (encoder_scope)(self)?;
@@ -409,13 +555,14 @@ impl crate::Encoder<'_> for Encoder {
         &mut self,
         _t: Tag,
         value: &[E],
-        _c: crate::types::Constraints,
+        constraints: crate::types::Constraints,
         _: Identifier,
     ) -> Result<Self::Ok, Self::Error> {
+        self.enforce(|| validate::check_size(value.len(), &constraints))?;
         self.update_root_or_constructed(Value::Array(value.iter().try_fold(
             alloc::vec![],
             |mut acc, v| {
-                let mut item_encoder = Self::new();
+                let mut item_encoder = Self::new().enforce_constraints(self.enforce_constraints);
                 v.encode(&mut item_encoder)
                     .and(item_encoder.to_json().map(|rv| acc.push(rv)).map(|_| acc))
             },
@@ -439,13 +586,14 @@ impl crate::Encoder<'_> for Encoder {
         &mut self,
         _t: Tag,
         value: &crate::types::SetOf<E>,
-        _c: crate::types::Constraints,
+        constraints: crate::types::Constraints,
         _: Identifier,
     ) -> Result<Self::Ok, Self::Error> {
+        self.enforce(|| validate::check_size(value.len(), &constraints))?;
         self.update_root_or_constructed(Value::Array(value.to_vec().iter().try_fold(
             alloc::vec![],
             |mut acc, v| {
-                let mut item_encoder = Self::new();
+                let mut item_encoder = Self::new().enforce_constraints(self.enforce_constraints);
                 v.encode(&mut item_encoder)
                     .and(item_encoder.to_json().map(|rv| acc.push(rv)).map(|_| acc))
             },
@@ -505,7 +653,7 @@ impl crate::Encoder<'_> for Encoder {
             self.update_root_or_constructed(Value::Object(ValueMap::new()))
         } else {
             self.constructed_stack.push(ValueMap::new());
-            self.stack.push(identifier);
+            self.stack.push(Cow::Borrowed(identifier));
             (encode_fn)(self)?;
             let value_map =
                 self.constructed_stack
@@ -544,4 +692,61 @@ impl crate::Encoder<'_> for Encoder {
     fn codec(&self) -> crate::Codec {
         crate::Codec::Jer
     }
+
+    fn encode_value(
+        &mut self,
+        descriptor: &TypeDescriptor,
+        value: &value::Value,
+    ) -> Result<Self::Ok, Self::Error> {
+        let descriptor = &descriptor.resolve();
+        match descriptor.kind {
+            TypeKind::Enumerated { .. } => {
+                let (_, _, variant) = value.enumerated(descriptor, self.codec())?;
+                self.update_root_or_constructed(Value::String(alloc::string::String::from(
+                    variant.name,
+                )))
+            }
+            TypeKind::Sequence { .. } | TypeKind::Set { .. } => {
+                let components = value.components(descriptor, self.codec())?;
+                self.constructed_stack.push(ValueMap::new());
+                for (field, value) in components.present().chain(components.present_extensions()) {
+                    self.stack.push(Cow::Owned(field.name.into()));
+                    self.encode_value(field.ty, value)?;
+                }
+                let value_map = self.constructed_stack.pop().ok_or_else(|| {
+                    JerEncodeErrorKind::JsonEncoder {
+                        msg: "Internal stack mismatch!".into(),
+                    }
+                })?;
+                self.update_root_or_constructed(Value::Object(value_map))
+            }
+            TypeKind::Choice { .. } => {
+                let (_, _, variant, value) = value.chosen(descriptor, self.codec())?;
+                self.constructed_stack.push(ValueMap::new());
+                self.stack.push(Cow::Owned(variant.name.into()));
+                self.encode_value(variant.ty, value)?;
+                let value_map = self.constructed_stack.pop().ok_or_else(|| {
+                    JerEncodeErrorKind::JsonEncoder {
+                        msg: "Internal stack mismatch!".into(),
+                    }
+                })?;
+                self.update_root_or_constructed(Value::Object(value_map))
+            }
+            TypeKind::SequenceOf { element } | TypeKind::SetOf { element } => {
+                let elements = value
+                    .elements(descriptor, self.codec())?
+                    .iter()
+                    .map(|v| {
+                        let mut item_encoder =
+                            Self::new().enforce_constraints(self.enforce_constraints);
+                        item_encoder.encode_value(element, v)?;
+                        item_encoder.to_json()
+                    })
+                    .collect::<Result<_, _>>()?;
+                self.update_root_or_constructed(Value::Array(elements))
+            }
+            TypeKind::Explicit { inner } => self.encode_value(inner, value),
+            _ => value.encode_primitive(self, descriptor),
+        }
+    }
 }
@@ -9,17 +9,29 @@
 )]
 
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 #[macro_use]
 pub mod macros;
 
+#[cfg(feature = "arbitrary")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arbitrary")))]
+pub mod arbitrary;
 mod bits;
 mod codec;
 pub mod de;
 pub mod enc;
 pub mod error;
 mod num;
+pub mod pem;
 mod per;
+#[cfg(feature = "schema")]
+#[cfg_attr(docsrs, doc(cfg(feature = "schema")))]
+pub mod schema;
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod serde;
 pub mod types;
 
 // Data Formats
@@ -52,7 +64,7 @@ pub use rasn_compiler as compiler;
 /// module.
 pub mod prelude {
     pub use crate::{
-        de::{Decode, Decoder},
+        de::{BorrowDecoder, Decode, DecodeBorrowed, Decoder},
         enc::{Encode, Encoder},
         macros,
         types::*,
@@ -202,6 +214,18 @@ mod tests {
         round_trip(&crate::types::Utf8String::from("Jones"));
     }
 
+    #[test]
+    fn time_types() {
+        let date = Date::from_ymd_opt(1582, 10, 15).unwrap();
+        let time = TimeOfDay::from_hms_opt(23, 59, 58).unwrap();
+        round_trip(&"2024-05-01T12:00Z".parse::<IsoTime>().unwrap());
+        round_trip(&time);
+        round_trip(&TimeOfDay::from_hms_milli_opt(23, 59, 59, 1_000).unwrap());
+        round_trip(&date.and_time(time));
+        round_trip(&"P1Y2M10DT2H30.25M".parse::<IsoDuration>().unwrap());
+        round_trip(&"P3W".parse::<IsoDuration>().unwrap());
+    }
+
     #[test]
     fn long_sequence_of() {
         round_trip(&vec![5u8; 0xffff]);
@@ -216,6 +240,58 @@ mod tests {
         round_trip(&ObjectIdentifier::new(vec![0, 3, 0, 3]).unwrap());
     }
 
+    #[test]
+    fn encoded_object_identifier() {
+        round_trip(&EncodedObjectIdentifier::from(
+            Oid::ISO_MEMBER_BODY_US_RSADSI,
+        ));
+        round_trip(&EncodedObjectIdentifier::from_arcs([2u128, 25, u128::MAX]).unwrap());
+        round_trip(
+            &"2.999.18446744073709551616.0"
+                .parse::<EncodedObjectIdentifier>()
+                .unwrap(),
+        );
+    }
+
+    #[test]
+    fn relative_object_identifier() {
+        round_trip(&RelativeObjectIdentifier::new(vec![8571, 3, 2]).unwrap());
+        round_trip(&RelativeObjectIdentifier::new(vec![0]).unwrap());
+        round_trip(&"/ISO/Member-Body/840".parse::<OidIri>().unwrap());
+        round_trip(&"Member-Body/840".parse::<RelativeOidIri>().unwrap());
+    }
+
+    #[test]
+    fn external() {
+        let syntax = ObjectIdentifier::new(vec![2, 1, 1]).unwrap();
+        round_trip(&External {
+            identification: Identification::Syntax(syntax.clone()),
+            data_value_descriptor: None,
+            data_value: OctetString::from_static(&[1, 2, 3]),
+        });
+        round_trip(&External {
+            identification: Identification::ContextNegotiation(ContextNegotiation {
+                presentation_context_id: 3.into(),
+                transfer_syntax: syntax.clone(),
+            }),
+            data_value_descriptor: Some(ObjectDescriptor::new(
+                GraphicString::try_from("BER").unwrap(),
+            )),
+            data_value: OctetString::from_static(&[1, 2, 3]),
+        });
+        round_trip(&EmbeddedPdv {
+            identification: Identification::Fixed,
+            data_value: OctetString::from_static(&[1, 2, 3]),
+        });
+        round_trip(&CharacterString {
+            identification: Identification::Syntaxes(Syntaxes {
+                abstract_syntax: syntax.clone(),
+                transfer_syntax: syntax,
+            }),
+            string_value: OctetString::from_static(b"abc"),
+        });
+    }
+
     #[test]
     fn enumerated() {
         #[derive(AsnType, Clone, Copy, Debug, Decode, Encode, PartialEq)]
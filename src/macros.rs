@@ -2,7 +2,8 @@
 //! constraints.
 
 pub use crate::{
-    constraints, permitted_alphabet_constraint, size_constraint, value_constraint, Decode, Encode,
+    constraints, encoded_oid, permitted_alphabet_constraint, size_constraint, value_constraint,
+    Decode, Encode,
 };
 
 #[macro_use]
@@ -263,3 +264,39 @@ macro_rules! oid {
         OID
     }}
 }
+
+/// Helper macro to create a `&'static EncodedOid` from a string literal of
+/// dot separated arcs, which unlike [`oid!`] can be of any size. The arcs
+/// are encoded at compile time, and must be a valid OID (at least two arcs,
+/// first arc must be <= 2), but also accepts OIDs with and without a leading
+/// `.`.
+///
+/// Usage:
+/// ```rust
+/// use rasn::types::EncodedOid;
+///
+/// const UUID: &'static EncodedOid = rasn::encoded_oid!("2.25.329800735698586629295641978511506172918");
+/// assert_eq!(UUID.as_bytes()[..2], [0x69, 0x83]);
+/// ```
+#[macro_export]
+macro_rules! encoded_oid {
+    ($s:literal) => {{
+        const OID: &'static $crate::types::EncodedOid = const {
+            const ENCODED: ([u8; $s.len()], usize) = $crate::types::EncodedOid::encode_str($s);
+
+            const CONTENTS: [u8; ENCODED.1] = const {
+                let mut contents = [0u8; ENCODED.1];
+                let mut index = 0;
+                while index < contents.len() {
+                    contents[index] = ENCODED.0[index];
+                    index += 1;
+                }
+                contents
+            };
+
+            $crate::types::EncodedOid::const_new(&CONTENTS)
+        };
+
+        OID
+    }};
+}
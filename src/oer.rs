@@ -25,6 +25,32 @@ pub fn decode_with_remainder<T: crate::Decode>(input: &[u8]) -> Result<(T, &[u8]
     let decoded_instance = T::decode(decoder)?;
     Ok((decoded_instance, decoder.remaining()))
 }
+/// Attempts to decode `T` from `input` using OER, borrowing from `input`
+/// where possible.
+///
+/// # Errors
+/// Returns `DecodeError` if `input` is not valid OER encoding specific to the expected type.
+pub fn decode_borrowed<'de, T: crate::de::DecodeBorrowed<'de>>(
+    input: &'de [u8],
+) -> Result<T, DecodeError> {
+    T::decode_borrowed(&mut Decoder::<0, 0>::new(input, de::DecoderOptions::oer()))
+}
+
+/// Attempts to decode a value of the type described by `descriptor` from
+/// `input` using OER, without needing the Rust type of the value.
+///
+/// # Errors
+/// Returns `DecodeError` if `input` is not valid OER encoding of the described type.
+pub fn decode_value<'d>(
+    descriptor: &crate::types::TypeDescriptor<'d>,
+    input: &[u8],
+) -> Result<crate::types::value::Value<'d>, DecodeError> {
+    crate::Decoder::decode_value(
+        &mut Decoder::<0, 0>::new(input, de::DecoderOptions::oer()),
+        descriptor,
+    )
+}
+
 /// Attempts to encode `value` of type `T` to OER.
 ///
 /// # Errors
@@ -38,6 +64,59 @@ pub fn encode<T: crate::Encode>(value: &T) -> Result<alloc::vec::Vec<u8>, Encode
     Ok(enc.output())
 }
 
+/// Attempts to encode `value` as the type described by `descriptor` to
+/// COER, without needing the Rust type of the value.
+///
+/// # Errors
+/// Returns `EncodeError` if `value` is not a valid value of the described
+/// type, or cannot be encoded as COER.
+pub fn encode_value(
+    descriptor: &crate::types::TypeDescriptor,
+    value: &crate::types::value::Value,
+) -> Result<alloc::vec::Vec<u8>, EncodeError> {
+    let mut buffer = alloc::vec::Vec::new();
+    let mut worker = alloc::vec::Vec::new();
+    let mut enc = Encoder::<0>::from_buffer(enc::EncoderOptions::coer(), &mut buffer, &mut worker);
+    crate::Encoder::encode_value(&mut enc, descriptor, value)?;
+    Ok(enc.output())
+}
+
+/// Attempts to encode `value` to COER into the start of `buffer`, returning
+/// the number of bytes written.
+///
+/// # Errors
+/// Returns `EncodeError` if `value` cannot be encoded, or with
+/// [`EncodeErrorKind::BufferTooSmall`](crate::error::EncodeErrorKind::BufferTooSmall)
+/// as soon as the encoding doesn't fit into `buffer`. The contents of
+/// `buffer` are unspecified on error.
+pub fn encode_into<T: crate::Encode>(value: &T, buffer: &mut [u8]) -> Result<usize, EncodeError> {
+    Encoder::encode_to_sink(
+        enc::EncoderOptions::coer(),
+        value,
+        crate::enc::SliceSink::new(buffer),
+    )
+}
+
+/// Attempts to encode `value` to COER, writing it to `writer` as it is
+/// encoded and returning the number of bytes written.
+///
+/// # Errors
+/// Returns `EncodeError` if `value` cannot be encoded, or with
+/// [`EncodeErrorKind::Io`](crate::error::EncodeErrorKind::Io) if writing
+/// fails, in which case part of the encoding may have been written.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn encode_to_writer<T: crate::Encode, W: std::io::Write>(
+    value: &T,
+    writer: W,
+) -> Result<usize, EncodeError> {
+    Encoder::encode_to_sink(
+        enc::EncoderOptions::coer(),
+        value,
+        crate::enc::IoSink::new(writer),
+    )
+}
+
 /// Attempts to encode `value` of type `T` to COER.
 /// Variant of `encode` that writes to a provided existing `buffer`.
 ///
@@ -169,6 +248,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn time_types() {
+        let date = chrono::NaiveDate::from_ymd_opt(2012, 12, 21).unwrap();
+        let time = TimeOfDay::from_hms_opt(12, 30, 45).unwrap();
+        round_trip!(
+            oer,
+            IsoTime,
+            "2024-05-01T12:00Z".parse().unwrap(),
+            b"\x112024-05-01T12:00Z"
+        );
+        round_trip!(oer, TimeOfDay, time, b"\x06123045");
+        round_trip!(oer, DateTime, date.and_time(time), b"\x0f20121221T123045");
+        round_trip!(
+            oer,
+            IsoDuration,
+            "P1Y2M10DT2H30M".parse().unwrap(),
+            b"\x0eP1Y2M10DT2H30M"
+        );
+
+        let fraction = TimeOfDay::from_hms_milli_opt(12, 30, 45, 500).unwrap();
+        encode_error!(oer, TimeOfDay, fraction);
+        encode_error!(oer, DateTime, date.and_time(fraction));
+    }
+
+    #[test]
+    fn extensible_integer() {
+        #[derive(AsnType, Decode, Encode, Debug, Clone, PartialEq)]
+        #[rasn(delegate, value("0..=10", extensible))]
+        struct Extensible(Integer);
+
+        round_trip!(oer, Extensible, Extensible(5.into()), &[0x01, 0x05]);
+        // Values outside of the root are encoded in the same form.
+        round_trip!(oer, Extensible, Extensible(300.into()), &[0x02, 0x01, 0x2c]);
+        round_trip!(oer, Extensible, Extensible((-1).into()), &[0x01, 0xff]);
+    }
+
     #[test]
     fn test_sequence_of() {
         #[derive(AsnType, Decode, Encode, Debug, Clone, PartialEq)]
@@ -262,4 +377,30 @@ mod tests {
         };
         round_trip!(oer, SequenceOptionals, test_seq, &[0x00, 0x01, 0x2A]);
     }
+
+    #[test]
+    fn test_explicit_with_default() {
+        #[derive(AsnType, Decode, Encode, Clone, Debug, PartialEq, Eq)]
+        pub struct SequenceDefaults {
+            #[rasn(tag(explicit(0)), default)]
+            pub version: Integer,
+            #[rasn(tag(explicit(1)))]
+            pub it: Integer,
+        }
+        let test_seq = SequenceDefaults {
+            version: 2.into(),
+            it: 42.into(),
+        };
+        round_trip!(
+            oer,
+            SequenceDefaults,
+            test_seq,
+            &[0x80, 0x01, 0x02, 0x01, 0x2A]
+        );
+        let test_seq = SequenceDefaults {
+            version: 0.into(),
+            it: 42.into(),
+        };
+        round_trip!(oer, SequenceDefaults, test_seq, &[0x00, 0x01, 0x2A]);
+    }
 }
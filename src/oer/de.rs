@@ -15,11 +15,15 @@ use core::num::NonZeroUsize;
 use nom::Needed;
 
 use crate::{
-    de::{Decode, Error as _},
+    de::{Decode, Decoder as _, Error as _},
     oer::EncodingRules,
     types::{
         self,
+        descriptor::{
+            EnumeratedVariant, FieldDescriptor, TypeDescriptor, TypeKind, VariantDescriptor,
+        },
         fields::{Field, Fields},
+        value::{NamedValue, Value},
         Any, BitString, BmpString, Constraints, Constructed, DecodeChoice, Enumerated,
         GeneralString, GeneralizedTime, GraphicString, Ia5String, IntegerType, NumericString,
         ObjectIdentifier, PrintableString, SetOf, Tag, TeletexString, UtcTime, VisibleString,
@@ -27,7 +31,7 @@ use crate::{
     Codec,
 };
 
-use bitvec::{order::Msb0, view::BitView};
+use bitvec::{order::Msb0, slice::BitSlice, view::BitView};
 
 use crate::error::{CoerDecodeErrorKind, DecodeError, DecodeErrorKind, OerDecodeErrorKind};
 
@@ -248,6 +252,10 @@ impl<'input, const RFC: usize, const EFC: usize> Decoder<'input, RFC, EFC> {
                 (value.constraint.get_sign(), value.constraint.get_range())
             };
             let integer = self.decode_integer_from_bytes::<I>(signed, octets.map(usize::from))?;
+            // Values of extensible constraints may be outside of the root
+            if value.extensible.is_some() {
+                return Ok(integer);
+            }
             // if the value is too large for a i128, the constraint isn't satisfied
             if let Some(constraint_integer) = integer.to_i128() {
                 if value.constraint.contains(&constraint_integer) {
@@ -345,6 +353,29 @@ impl<'input, const RFC: usize, const EFC: usize> Decoder<'input, RFC, EFC> {
             .map_err(|e| DecodeError::permitted_alphabet_error(e, self.codec()))
     }
 
+    fn parse_octet_string(
+        &mut self,
+        constraints: &Constraints,
+    ) -> Result<&'input [u8], DecodeError> {
+        if let Some(size) = constraints.size() {
+            // Fixed size, only data is included
+            if size.constraint.is_fixed() && size.extensible.is_none() {
+                return self.extract_data_by_length(*size.constraint.as_start().ok_or_else(
+                    || {
+                        DecodeError::size_constraint_not_satisfied(
+                            None,
+                            "Fixed size constraint should have value when decoding Octet String"
+                                .to_string(),
+                            self.codec(),
+                        )
+                    },
+                )?);
+            }
+        }
+        let length = self.decode_length()?;
+        self.extract_data_by_length(length)
+    }
+
     #[track_caller]
     fn require_field(&mut self, tag: Tag) -> Result<bool, DecodeError> {
         let (fields, index) = &mut self.fields;
@@ -388,6 +419,31 @@ impl<'input, const RFC: usize, const EFC: usize> Decoder<'input, RFC, EFC> {
             Some(None) => (),
             None => return Ok(false),
         }
+        let extension_fields = self
+            .extension_fields
+            .ok_or_else(|| DecodeError::type_not_extensible(self.codec()))?;
+        let bitfield = self.parse_extension_bitfield()?;
+        let mut fields: [Option<Field>; EFC] = [None; EFC];
+        for (i, field) in extension_fields.iter().enumerate() {
+            let is_set = bitfield.get(i).is_some_and(|bit| *bit);
+
+            if field.is_not_optional_or_default() && !is_set {
+                return Err(DecodeError::required_extension_not_present(
+                    field.tag,
+                    self.codec(),
+                ));
+            } else if is_set {
+                fields[i] = Some(field);
+            }
+        }
+
+        self.extensions_present = Some(Some((fields, 0)));
+        Ok(true)
+    }
+
+    /// Parses the presence bitfield of the extension additions, without the
+    /// unused trailing bits.
+    fn parse_extension_bitfield(&mut self) -> Result<&'input BitSlice<u8, Msb0>, DecodeError> {
         let extensions_length = self.decode_length()?;
         // If length is 0, then there is only initial octet
         if extensions_length < 1u8.into() {
@@ -395,9 +451,6 @@ impl<'input, const RFC: usize, const EFC: usize> Decoder<'input, RFC, EFC> {
                 "Extension length should be at least 1 byte".to_string(),
             ));
         }
-        let extension_fields = self
-            .extension_fields
-            .ok_or_else(|| DecodeError::type_not_extensible(self.codec()))?;
         // Must be at least 8 bits at this point or error is already raised
         let bitfield_bytes = self.extract_data_by_length(extensions_length)?;
         let (first_byte, bitfield) = bitfield_bytes.split_first().ok_or_else(|| {
@@ -410,24 +463,9 @@ impl<'input, const RFC: usize, const EFC: usize> Decoder<'input, RFC, EFC> {
                 "Invalid extension bitfield initial octet".to_string(),
             ));
         }
-        let mut fields: [Option<Field>; EFC] = [None; EFC];
-        for (i, field) in extension_fields.iter().enumerate() {
-            let byte_idx = i / 8;
-            let bit_idx = 7 - (i & 7); // This gives us MSB0 ordering within each byte
-            let is_set = byte_idx < bitfield.len() && (bitfield[byte_idx] & (1 << bit_idx)) != 0;
-
-            if field.is_not_optional_or_default() && !is_set {
-                return Err(DecodeError::required_extension_not_present(
-                    field.tag,
-                    self.codec(),
-                ));
-            } else if is_set {
-                fields[i] = Some(field);
-            }
-        }
+        let bitfield = bitfield.view_bits::<Msb0>();
 
-        self.extensions_present = Some(Some((fields, 0)));
-        Ok(true)
+        Ok(&bitfield[..bitfield.len() - unused_bits])
     }
 
     fn parse_preamble<const RC: usize, const EC: usize, D>(
@@ -436,40 +474,203 @@ impl<'input, const RFC: usize, const EFC: usize> Decoder<'input, RFC, EFC> {
     where
         D: Constructed<RC, EC>,
     {
-        let is_extensible = D::IS_EXTENSIBLE;
-        let preamble_width =
-            D::FIELDS.number_of_optional_and_default_fields() + usize::from(is_extensible);
-        let bytes = self.extract_data_by_length(preamble_width.div_ceil(8))?;
+        let (bitmap, extensible_present) = self.parse_preamble_bitmap(
+            D::FIELDS.number_of_optional_and_default_fields(),
+            D::IS_EXTENSIBLE,
+        )?;
 
         let mut result = [false; RC];
-        let mut extensible_present = false;
-
-        // Process each preamble bit we need
-        for i in 0..preamble_width {
-            let byte_idx = i / 8;
-            let bit_idx = 7 - (i & 7);
-            let is_set: bool = (bytes[byte_idx] & (1 << bit_idx)) != 0;
-
-            if i == 0 && is_extensible {
-                extensible_present = is_set;
-            } else if i - usize::from(is_extensible) < RC {
-                result[i - usize::from(is_extensible)] = is_set;
-            }
+        for (present, bit) in result.iter_mut().zip(bitmap) {
+            *present = *bit;
         }
 
+        Ok((result, extensible_present))
+    }
+
+    /// Parses a preamble of `optional_fields` presence bits, preceded by the
+    /// extension bit if `is_extensible`. Returns the presence bits and the
+    /// extension bit.
+    fn parse_preamble_bitmap(
+        &mut self,
+        optional_fields: usize,
+        is_extensible: bool,
+    ) -> Result<(&'input BitSlice<u8, Msb0>, bool), DecodeError> {
+        let preamble_width = optional_fields + usize::from(is_extensible);
+        let bytes = self.extract_data_by_length(preamble_width.div_ceil(8))?;
+        let (preamble, unused_bits) = bytes.view_bits::<Msb0>().split_at(preamble_width);
+
         // Check that remaining bits are zero
-        let remaining_bits_start = preamble_width;
-        for i in remaining_bits_start..bytes.len() * 8 {
-            let byte_idx = i / 8;
-            let bit_idx = 7 - (i & 7);
-            if (bytes[byte_idx] & (1 << bit_idx)) != 0 {
-                return Err(OerDecodeErrorKind::invalid_preamble(
-                    "Preamble unused bits should be all zero.".to_string(),
-                ));
+        if unused_bits.any() {
+            return Err(OerDecodeErrorKind::invalid_preamble(
+                "Preamble unused bits should be all zero.".to_string(),
+            ));
+        }
+
+        let (extension_bit, bitmap) = preamble.split_at(usize::from(is_extensible));
+        Ok((bitmap, extension_bit.any()))
+    }
+
+    fn decode_enumerated_value<'d>(
+        &mut self,
+        variants: &[EnumeratedVariant<'d>],
+        extended_variants: Option<&[EnumeratedVariant<'d>]>,
+    ) -> Result<Value<'d>, DecodeError> {
+        let byte = self.parse_one_byte()?;
+        let discriminant = if byte < 128 {
+            isize::from(byte)
+        } else {
+            let length = byte & 0x7fu8;
+            let discriminant: isize = self.decode_integer_from_bytes(true, Some(length.into()))?;
+            if (0..128).contains(&discriminant) && self.options.encoding_rules.is_coer() {
+                return Err(CoerDecodeErrorKind::NotValidCanonicalEncoding {
+                    msg: "Enumerated discriminant should have been encoded in short form."
+                        .to_string(),
+                }
+                .into());
+            }
+            discriminant
+        };
+
+        variants
+            .iter()
+            .chain(extended_variants.unwrap_or_default())
+            .find(|variant| variant.discriminant == discriminant)
+            .map(|variant| Value::Enumerated(variant.name))
+            .ok_or_else(|| DecodeError::discriminant_value_not_found(discriminant, self.codec()))
+    }
+
+    fn decode_sequence_value<'d>(
+        &mut self,
+        fields: &[FieldDescriptor<'d>],
+        extended_fields: Option<&[FieldDescriptor<'d>]>,
+        is_set: bool,
+    ) -> Result<Vec<NamedValue<'d>>, DecodeError> {
+        let codec = self.codec();
+        let optional_fields = fields
+            .iter()
+            .filter(|field| field.presence.is_optional_or_default())
+            .count();
+        // Mirrors `decode_sequence`, which yields the default value here.
+        if !is_set
+            && (fields.is_empty() || fields.len() == optional_fields && self.input.is_empty())
+        {
+            return Ok(Vec::new());
+        }
+
+        let (bitmap, extensible_present) =
+            self.parse_preamble_bitmap(optional_fields, extended_fields.is_some())?;
+
+        let mut ordered_fields: Vec<_> = fields.iter().enumerate().collect();
+        // `SET` components and their preamble use the canonical order of tags.
+        if is_set {
+            ordered_fields.sort_by_key(|(_, field)| field.ty.smallest_tag());
+        }
+        let mut bitmap = bitmap.iter().by_vals();
+        ordered_fields.retain(|(_, field)| {
+            !field.presence.is_optional_or_default() || bitmap.next().unwrap_or_default()
+        });
+
+        let mut values = Vec::with_capacity(ordered_fields.len());
+        for (index, field) in ordered_fields {
+            let value = self
+                .decode_value(field.ty)
+                .map_err(|error| DecodeError::described_field_error(field.name, error, codec))?;
+            values.push((index, NamedValue::new(field.name, value)));
+        }
+        if is_set {
+            values.sort_by_key(|(index, _)| *index);
+        }
+        let mut values: Vec<_> = values.into_iter().map(|(_, value)| value).collect();
+
+        if extensible_present {
+            let extended_fields = extended_fields.unwrap_or_default();
+            let bitfield = self.parse_extension_bitfield()?;
+            for i in 0..bitfield.len().max(extended_fields.len()) {
+                let field = extended_fields.get(i);
+                if !bitfield.get(i).is_some_and(|bit| *bit) {
+                    if let Some(field) = field.filter(|f| !f.presence.is_optional_or_default()) {
+                        return Err(DecodeError::required_extension_not_present(
+                            field.ty.tag,
+                            codec,
+                        ));
+                    }
+                    continue;
+                }
+
+                // Extension additions are open types, which lets us skip the
+                // ones unknown to the descriptor.
+                let length = self.decode_length()?;
+                let bytes = self.extract_data_by_length(length)?;
+                if let Some(field) = field {
+                    let value = Decoder::<0, 0>::new(bytes, self.options)
+                        .decode_value(field.ty)
+                        .map_err(|error| {
+                            DecodeError::described_field_error(field.name, error, codec)
+                        })?;
+                    values.push(NamedValue::new(field.name, value));
+                }
             }
         }
 
-        Ok((result, extensible_present))
+        Ok(values)
+    }
+
+    fn decode_choice_value<'d>(
+        &mut self,
+        variants: &'d [VariantDescriptor<'d>],
+        extended_variants: Option<&'d [VariantDescriptor<'d>]>,
+    ) -> Result<Value<'d>, DecodeError> {
+        let tag = self.parse_tag()?;
+        let find = |variants: &'d [VariantDescriptor<'d>]| {
+            variants.iter().find(|variant| variant.ty.contains_tag(tag))
+        };
+
+        let (variant, value) = if let Some(variant) = find(variants) {
+            // Untagged `CHOICE` alternatives parse their own tag again.
+            (variant, self.decode_value(variant.ty)?)
+        } else if let Some(variant) = extended_variants.and_then(find) {
+            let length = self.decode_length()?;
+            let bytes = self.extract_data_by_length(length)?;
+            (
+                variant,
+                Decoder::<0, 0>::new(bytes, self.options).decode_value(variant.ty)?,
+            )
+        } else {
+            return Err(OerDecodeErrorKind::invalid_tag_variant_on_choice(
+                tag,
+                extended_variants.is_some(),
+            ));
+        };
+
+        Ok(Value::Choice {
+            variant: variant.name,
+            value: alloc::boxed::Box::new(value),
+        })
+    }
+
+    fn decode_sequence_of_value<'d>(
+        &mut self,
+        element: &TypeDescriptor<'d>,
+    ) -> Result<Vec<Value<'d>>, DecodeError> {
+        let length_of_quantity = self.decode_length()?;
+        let length_bytes = self.extract_data_by_length(length_of_quantity)?;
+        if self.options.encoding_rules.is_coer()
+            && length_bytes.first() == Some(&0)
+            && length_bytes.len() > 1
+        {
+            return Err(CoerDecodeErrorKind::NotValidCanonicalEncoding {
+                msg: "Quantity value in 'sequence/set of' should not have leading zeroes in COER"
+                    .to_string(),
+            }
+            .into());
+        }
+        let length = usize::try_from_unsigned_bytes(length_bytes, self.codec())?;
+        let mut sequence_of = Vec::with_capacity(length.min(self.input.len()));
+        for _ in 0..length {
+            sequence_of.push(self.decode_value(element)?);
+        }
+
+        Ok(sequence_of)
     }
 }
 impl<'input, const RFC: usize, const EFC: usize> crate::Decoder for Decoder<'input, RFC, EFC> {
@@ -481,8 +682,49 @@ impl<'input, const RFC: usize, const EFC: usize> crate::Decoder for Decoder<'inp
         self.codec()
     }
 
+    fn decode_value<'d>(
+        &mut self,
+        descriptor: &TypeDescriptor<'d>,
+    ) -> Result<Value<'d>, Self::Error> {
+        let descriptor = &descriptor.resolve();
+        match descriptor.kind {
+            TypeKind::Enumerated {
+                variants,
+                extended_variants,
+            } => self.decode_enumerated_value(variants, extended_variants),
+            TypeKind::Sequence {
+                fields,
+                extended_fields,
+            } => self
+                .decode_sequence_value(fields, extended_fields, false)
+                .map(Value::Sequence),
+            TypeKind::Set {
+                fields,
+                extended_fields,
+            } => self
+                .decode_sequence_value(fields, extended_fields, true)
+                .map(Value::Set),
+            TypeKind::Choice {
+                variants,
+                extended_variants,
+            } => self.decode_choice_value(variants, extended_variants),
+            TypeKind::SequenceOf { element } => self
+                .decode_sequence_of_value(element)
+                .map(Value::SequenceOf),
+            TypeKind::SetOf { element } => self.decode_sequence_of_value(element).map(Value::SetOf),
+            TypeKind::Explicit { inner } => self.decode_value(inner),
+            TypeKind::Any => Err(DecodeError::custom(
+                "Open types can't be decoded without knowing their type in OER.",
+                self.codec(),
+            )),
+            _ => Value::decode_primitive(self, descriptor),
+        }
+    }
+
+    /// X.696 30. Open types are encoded as a length-prefixed octet string.
     fn decode_any(&mut self) -> Result<Any, Self::Error> {
-        panic!("Not every type can be decoded as Any in OER.")
+        self.parse_octet_string(&Constraints::default())
+            .map(|bytes| Any::new(bytes.to_vec()))
     }
 
     fn decode_bit_string(
@@ -575,6 +817,46 @@ impl<'input, const RFC: usize, const EFC: usize> crate::Decoder for Decoder<'inp
         ber_decoder.decode_object_identifier_from_bytes(self.extract_data_by_length(length)?)
     }
 
+    fn decode_encoded_oid(
+        &mut self,
+        _: Tag,
+    ) -> Result<types::EncodedObjectIdentifier, Self::Error> {
+        let length = self.decode_length()?;
+        let ber_decoder = crate::ber::de::Decoder::new(&[], crate::ber::de::DecoderOptions::ber());
+        ber_decoder.decode_encoded_oid_from_bytes(self.extract_data_by_length(length)?)
+    }
+
+    fn decode_relative_oid(
+        &mut self,
+        _: Tag,
+    ) -> Result<types::RelativeObjectIdentifier, Self::Error> {
+        let length = self.decode_length()?;
+        let ber_decoder = crate::ber::de::Decoder::new(&[], crate::ber::de::DecoderOptions::ber());
+        ber_decoder.decode_relative_oid_from_bytes(self.extract_data_by_length(length)?)
+    }
+
+    fn decode_oid_iri(&mut self, tag: Tag) -> Result<types::OidIri, Self::Error> {
+        let string = self.decode_utf8_string(tag, Constraints::default())?;
+        string.parse::<types::OidIri>().map_err(|error| {
+            DecodeError::string_conversion_failed(Tag::OID_IRI, error.to_string(), self.codec())
+        })
+    }
+
+    fn decode_relative_oid_iri(&mut self, tag: Tag) -> Result<types::RelativeOidIri, Self::Error> {
+        let string = self.decode_utf8_string(tag, Constraints::default())?;
+        string.parse::<types::RelativeOidIri>().map_err(|error| {
+            DecodeError::string_conversion_failed(
+                Tag::RELATIVE_OID_IRI,
+                error.to_string(),
+                self.codec(),
+            )
+        })
+    }
+
+    fn decode_external(&mut self, tag: Tag) -> Result<types::External, Self::Error> {
+        types::External::decode_legacy(self, tag)
+    }
+
     fn decode_sequence<const RC: usize, const EC: usize, D, DF: FnOnce() -> D, F>(
         &mut self,
         _: Tag,
@@ -669,24 +951,7 @@ impl<'input, const RFC: usize, const EFC: usize> crate::Decoder for Decoder<'inp
         _: Tag,
         constraints: Constraints,
     ) -> Result<T, Self::Error> {
-        if let Some(size) = constraints.size() {
-            // Fixed size, only data is included
-            if size.constraint.is_fixed() && size.extensible.is_none() {
-                let data =
-                    self.extract_data_by_length(*size.constraint.as_start().ok_or_else(|| {
-                        DecodeError::size_constraint_not_satisfied(
-                            None,
-                            "Fixed size constraint should have value when decoding Octet String"
-                                .to_string(),
-                            self.codec(),
-                        )
-                    })?)?;
-                return Ok(T::from(data));
-            }
-        }
-        let length = self.decode_length()?;
-        let data = self.extract_data_by_length(length)?;
-        Ok(T::from(data))
+        self.parse_octet_string(&constraints).map(T::from)
     }
 
     fn decode_utf8_string(
@@ -821,6 +1086,54 @@ impl<'input, const RFC: usize, const EFC: usize> crate::Decoder for Decoder<'inp
         crate::der::de::Decoder::parse_date_string(&string)
     }
 
+    fn decode_time(&mut self, tag: Tag) -> Result<types::IsoTime, Self::Error> {
+        let string = String::from_utf8(self.decode_octet_string(tag, Constraints::default())?)
+            .map_err(|_| {
+                DecodeError::string_conversion_failed(
+                    Tag::UTF8_STRING,
+                    "TIME should be UTF8 encoded".to_string(),
+                    self.codec(),
+                )
+            })?;
+        crate::der::de::Decoder::parse_time_string(&string)
+    }
+
+    fn decode_time_of_day(&mut self, tag: Tag) -> Result<types::TimeOfDay, Self::Error> {
+        let string = String::from_utf8(self.decode_octet_string(tag, Constraints::default())?)
+            .map_err(|_| {
+                DecodeError::string_conversion_failed(
+                    Tag::UTF8_STRING,
+                    "TIME-OF-DAY should be UTF8 encoded".to_string(),
+                    self.codec(),
+                )
+            })?;
+        crate::der::de::Decoder::parse_time_of_day_string(&string)
+    }
+
+    fn decode_date_time(&mut self, tag: Tag) -> Result<types::DateTime, Self::Error> {
+        let string = String::from_utf8(self.decode_octet_string(tag, Constraints::default())?)
+            .map_err(|_| {
+                DecodeError::string_conversion_failed(
+                    Tag::UTF8_STRING,
+                    "DATE-TIME should be UTF8 encoded".to_string(),
+                    self.codec(),
+                )
+            })?;
+        crate::der::de::Decoder::parse_date_time_string(&string)
+    }
+
+    fn decode_duration(&mut self, tag: Tag) -> Result<types::IsoDuration, Self::Error> {
+        let string = String::from_utf8(self.decode_octet_string(tag, Constraints::default())?)
+            .map_err(|_| {
+                DecodeError::string_conversion_failed(
+                    Tag::UTF8_STRING,
+                    "DURATION should be UTF8 encoded".to_string(),
+                    self.codec(),
+                )
+            })?;
+        crate::der::de::Decoder::parse_duration_string(&string)
+    }
+
     fn decode_set<const RC: usize, const EC: usize, FIELDS, SET, D, F>(
         &mut self,
         _: Tag,
@@ -1013,6 +1326,118 @@ impl<'input, const RFC: usize, const EFC: usize> crate::Decoder for Decoder<'inp
     }
 }
 
+impl<'input, const RFC: usize, const EFC: usize> crate::de::BorrowDecoder<'input>
+    for Decoder<'input, RFC, EFC>
+{
+    type AnyBorrowDecoder<const R: usize, const E: usize> = Decoder<'input, R, E>;
+
+    fn decode_octet_string_borrowed(
+        &mut self,
+        _: Tag,
+        constraints: Constraints,
+    ) -> Result<Cow<'input, [u8]>, Self::Error> {
+        self.parse_octet_string(&constraints).map(Cow::Borrowed)
+    }
+
+    fn decode_utf8_string_borrowed(
+        &mut self,
+        _: Tag,
+        constraints: Constraints,
+    ) -> Result<Cow<'input, str>, Self::Error> {
+        let bytes = self.parse_octet_string(&constraints)?;
+        core::str::from_utf8(bytes).map(Cow::Borrowed).map_err(|e| {
+            DecodeError::string_conversion_failed(Tag::UTF8_STRING, e.to_string(), self.codec())
+        })
+    }
+
+    // The known-multiplier character strings below have single octet
+    // characters, so they share the length determinant of `OCTET STRING`.
+
+    fn decode_ia5_string_borrowed(
+        &mut self,
+        _: Tag,
+        constraints: Constraints,
+    ) -> Result<Cow<'input, str>, Self::Error> {
+        let bytes = self.parse_octet_string(&constraints)?;
+        types::strings::restricted_str_from_bytes::<Ia5String>(Cow::Borrowed(bytes))
+            .map_err(|e| DecodeError::permitted_alphabet_error(e, self.codec()))
+    }
+
+    fn decode_printable_string_borrowed(
+        &mut self,
+        _: Tag,
+        constraints: Constraints,
+    ) -> Result<Cow<'input, str>, Self::Error> {
+        let bytes = self.parse_octet_string(&constraints)?;
+        types::strings::restricted_str_from_bytes::<PrintableString>(Cow::Borrowed(bytes))
+            .map_err(|e| DecodeError::permitted_alphabet_error(e, self.codec()))
+    }
+
+    fn decode_visible_string_borrowed(
+        &mut self,
+        _: Tag,
+        constraints: Constraints,
+    ) -> Result<Cow<'input, str>, Self::Error> {
+        let bytes = self.parse_octet_string(&constraints)?;
+        types::strings::restricted_str_from_bytes::<VisibleString>(Cow::Borrowed(bytes))
+            .map_err(|e| DecodeError::permitted_alphabet_error(e, self.codec()))
+    }
+
+    fn decode_numeric_string_borrowed(
+        &mut self,
+        _: Tag,
+        constraints: Constraints,
+    ) -> Result<Cow<'input, str>, Self::Error> {
+        let bytes = self.parse_octet_string(&constraints)?;
+        types::strings::restricted_str_from_bytes::<NumericString>(Cow::Borrowed(bytes))
+            .map_err(|e| DecodeError::permitted_alphabet_error(e, self.codec()))
+    }
+
+    fn decode_sequence_borrowed<const RC: usize, const EC: usize, D, DF, F>(
+        &mut self,
+        tag: Tag,
+        default_initializer_fn: Option<DF>,
+        decode_fn: F,
+    ) -> Result<D, Self::Error>
+    where
+        D: Constructed<RC, EC>,
+        DF: FnOnce() -> D,
+        F: FnOnce(&mut Self::AnyBorrowDecoder<RC, EC>) -> Result<D, Self::Error>,
+    {
+        crate::Decoder::decode_sequence(self, tag, default_initializer_fn, decode_fn)
+    }
+
+    fn decode_explicit_prefix_borrowed<D: crate::de::DecodeBorrowed<'input>>(
+        &mut self,
+        tag: Tag,
+    ) -> Result<D, Self::Error> {
+        if D::IS_CHOICE {
+            D::decode_borrowed(self)
+        } else {
+            D::decode_borrowed_with_tag(self, tag)
+        }
+    }
+
+    fn decode_optional_with_explicit_prefix_borrowed<D: crate::de::DecodeBorrowed<'input>>(
+        &mut self,
+        tag: Tag,
+    ) -> Result<Option<D>, Self::Error> {
+        self.decode_optional_with_tag_and_constraints_borrowed(tag, D::CONSTRAINTS)
+    }
+
+    fn decode_optional_with_tag_and_constraints_borrowed<D: crate::de::DecodeBorrowed<'input>>(
+        &mut self,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<Option<D>, Self::Error> {
+        if self.require_field(tag)? {
+            D::decode_borrowed_with_tag_and_constraints(self, tag, constraints).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::assertions_on_constants)]
 mod tests {
@@ -1,15 +1,19 @@
 //! Encoding Rust structures into Octet Encoding Rules data.
 
-use alloc::vec::Vec;
+use alloc::{string::ToString, vec::Vec};
 use bitvec::prelude::*;
 use num_traits::ToPrimitive;
 
 use crate::{
+    enc::{Encoder as _, Sink},
     oer::EncodingRules,
     types::{
-        Any, BitStr, BmpString, Choice, Constraints, Constructed, Date, Enumerated, GeneralString,
-        GeneralizedTime, GraphicString, Ia5String, Identifier, IntegerType, NumericString,
-        PrintableString, RealType, SetOf, Tag, TeletexString, UtcTime, VisibleString,
+        descriptor::{TypeDescriptor, TypeKind},
+        value::Value,
+        Any, BitStr, BmpString, Choice, Constraints, Constructed, Date, DateTime, Enumerated,
+        External, GeneralString, GeneralizedTime, GraphicString, Ia5String, Identifier,
+        IntegerType, IsoDuration, IsoTime, NumericString, OidIri, PrintableString, RealType,
+        RelativeOidIri, SetOf, Tag, TeletexString, TimeOfDay, UtcTime, VisibleString,
     },
     Codec, Encode,
 };
@@ -142,6 +146,69 @@ pub struct Encoder<'buffer, const RCL: usize = 0, const ECL: usize = 0> {
     // Sometimes we need to encode data into separate buffer before length can be calculated.
     // Using a separate buffer comes with a trade-off of reduced allocation count vs. peak memory usage.
     worker: &'buffer mut Vec<u8>,
+    pass: Pass<'buffer>,
+    // Where the components of a SET are written once they have been sorted in the output buffer.
+    set_pass: Pass<'buffer>,
+}
+
+/// How the encoder writes its output, see [`Encoder::encode_to_sink`].
+enum Pass<'buffer> {
+    /// Everything is written into the output buffer, the preambles,
+    /// extension bitmaps and lengths of open types being filled in after the
+    /// components they precede have been encoded.
+    Buffered,
+    /// Nothing is written, the preambles, extension bitmaps and lengths of
+    /// open types are recorded, along with the number of bytes.
+    Measure(&'buffer mut TwoPass),
+    /// Everything is written into the sink, using the preambles, extension
+    /// bitmaps and lengths recorded by a previous [`Pass::Measure`]. `SET`
+    /// values are still encoded into the output buffer first, as their
+    /// components have to be sorted.
+    Write(&'buffer mut TwoPass, &'buffer mut dyn Sink),
+}
+
+impl Pass<'_> {
+    fn reborrow(&mut self) -> Pass<'_> {
+        match self {
+            Self::Buffered => Pass::Buffered,
+            Self::Measure(state) => Pass::Measure(state),
+            Self::Write(state, sink) => Pass::Write(state, *sink),
+        }
+    }
+}
+
+impl core::fmt::Debug for Pass<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Buffered => f.write_str("Buffered"),
+            Self::Measure(state) => f.debug_tuple("Measure").field(state).finish(),
+            Self::Write(state, _) => f.debug_tuple("Write").field(state).finish(),
+        }
+    }
+}
+
+/// The state shared by the encoders of a value encoded in two passes.
+#[derive(Debug, Default)]
+struct TwoPass {
+    written: usize,
+    // The bytes which are only known after the components following them have been encoded,
+    // in the order they are written.
+    deferred: Vec<Deferred>,
+    next: usize,
+}
+
+#[derive(Debug)]
+enum Deferred {
+    /// A preamble or an extension addition presence bitmap.
+    Bytes(Vec<u8>),
+    /// The length of an open type.
+    Length(usize),
+}
+
+/// The start of an open type, see [`Encoder::begin_open_type`].
+struct OpenType {
+    cursor: usize,
+    start: usize,
 }
 
 // ITU-T X.696 8.2.1 Only the following constraints are OER-visible:
@@ -158,6 +225,45 @@ pub struct Encoder<'buffer, const RCL: usize = 0, const ECL: usize = 0> {
 // alternative;
 // g) contained subtype constraints in which the constraining type carries an OER-visible constraint.
 
+impl Encoder<'_> {
+    /// Encodes `value` with the given `options` directly into `sink`,
+    /// returning the number of bytes written.
+    ///
+    /// The preambles, extension bitmaps and lengths of open types precede
+    /// the components they depend on, so the value is encoded in two passes.
+    /// The first pass only records them, which lets the second pass write
+    /// everything in order. If encoding fails, the bytes written into `sink`
+    /// up to that point are left there.
+    ///
+    /// # Errors
+    /// Returns `EncodeError` if `value` cannot be encoded, or if writing into
+    /// `sink` fails.
+    pub fn encode_to_sink<E: Encode, S: Sink>(
+        options: EncoderOptions,
+        value: &E,
+        mut sink: S,
+    ) -> Result<usize, EncodeError> {
+        let mut output = Vec::new();
+        let mut worker = Vec::new();
+        let mut state = TwoPass::default();
+        value.encode(&mut Encoder::<0>::with_pass(
+            options,
+            &mut output,
+            &mut worker,
+            Pass::Measure(&mut state),
+        ))?;
+
+        state.written = 0;
+        value.encode(&mut Encoder::<0>::with_pass(
+            options,
+            &mut output,
+            &mut worker,
+            Pass::Write(&mut state, &mut sink),
+        ))?;
+        Ok(state.written)
+    }
+}
+
 // Tags are encoded only as part of the encoding of a choice type, where the tag indicates
 // which alternative of the choice type is the chosen alternative (see 20.1).
 impl<'buffer, const RCL: usize, const ECL: usize> Encoder<'buffer, RCL, ECL> {
@@ -177,6 +283,20 @@ impl<'buffer, const RCL: usize, const ECL: usize> Encoder<'buffer, RCL, ECL> {
             cursor: ConstructedCursor::default(),
             is_extension_sequence: bool::default(),
             worker,
+            pass: Pass::Buffered,
+            set_pass: Pass::Buffered,
+        }
+    }
+
+    fn with_pass(
+        options: EncoderOptions,
+        output: &'buffer mut Vec<u8>,
+        worker: &'buffer mut Vec<u8>,
+        pass: Pass<'buffer>,
+    ) -> Self {
+        Self {
+            pass,
+            ..Self::from_buffer(options, output, worker)
         }
     }
 
@@ -190,6 +310,149 @@ impl<'buffer, const RCL: usize, const ECL: usize> Encoder<'buffer, RCL, ECL> {
         core::mem::take(self.output)
     }
 
+    fn write(&mut self, bytes: &[u8]) -> Result<(), EncodeError> {
+        let codec = self.codec();
+        match &mut self.pass {
+            Pass::Buffered => self.output.extend_from_slice(bytes),
+            Pass::Measure(state) => state.written += bytes.len(),
+            Pass::Write(state, sink) => {
+                sink.write(bytes, codec)?;
+                state.written += bytes.len();
+            }
+        }
+        Ok(())
+    }
+
+    fn write_length(&mut self, length: usize) -> Result<(), EncodeError> {
+        if let Pass::Buffered = self.pass {
+            return Self::encode_length(self.output, length);
+        }
+        let mut buffer = core::mem::take(self.worker);
+        buffer.clear();
+        let result = Self::encode_length(&mut buffer, length).and_then(|()| self.write(&buffer));
+        *self.worker = buffer;
+        result
+    }
+
+    /// Reserves `width` bytes for a preamble or an extension addition
+    /// presence bitmap, returning the cursor to fill them in with
+    /// [`Self::fill_reserved`] once they are known.
+    fn reserve(&mut self, width: usize) -> Result<usize, EncodeError> {
+        let codec = self.codec();
+        match &mut self.pass {
+            Pass::Buffered => {
+                let cursor = self.output.len();
+                self.output.resize(cursor + width, 0);
+                Ok(cursor)
+            }
+            Pass::Measure(state) => {
+                state.deferred.push(Deferred::Bytes(alloc::vec![0; width]));
+                state.written += width;
+                Ok(state.deferred.len() - 1)
+            }
+            Pass::Write(state, sink) => {
+                let cursor = state.next;
+                match state.deferred.get(cursor) {
+                    Some(Deferred::Bytes(bytes)) if bytes.len() == width => {
+                        sink.write(bytes, codec)?;
+                    }
+                    _ => return Err(EncodeError::inconsistent_encoding(codec)),
+                }
+                state.written += width;
+                state.next += 1;
+                Ok(cursor)
+            }
+        }
+    }
+
+    /// Fills in the start of the bytes reserved at `cursor` with `bytes`.
+    fn fill_reserved(&mut self, cursor: usize, bytes: &[u8]) -> Result<(), EncodeError> {
+        let codec = self.codec();
+        match &mut self.pass {
+            Pass::Buffered => self.output[cursor..cursor + bytes.len()].copy_from_slice(bytes),
+            Pass::Measure(state) => {
+                if let Some(Deferred::Bytes(reserved)) = state.deferred.get_mut(cursor) {
+                    reserved[..bytes.len()].copy_from_slice(bytes);
+                }
+            }
+            Pass::Write(state, _) => match state.deferred.get(cursor) {
+                Some(Deferred::Bytes(reserved)) if reserved.starts_with(bytes) => {}
+                _ => return Err(EncodeError::inconsistent_encoding(codec)),
+            },
+        }
+        Ok(())
+    }
+
+    /// Starts an open type, whose contents are encoded next and then
+    /// prefixed with their length by [`Self::end_open_type`].
+    fn begin_open_type(&mut self) -> Result<OpenType, EncodeError> {
+        let codec = self.codec();
+        match &mut self.pass {
+            Pass::Buffered => Ok(OpenType {
+                cursor: 0,
+                start: self.output.len(),
+            }),
+            Pass::Measure(state) => {
+                state.deferred.push(Deferred::Length(0));
+                Ok(OpenType {
+                    cursor: state.deferred.len() - 1,
+                    start: state.written,
+                })
+            }
+            Pass::Write(state, _) => {
+                let cursor = state.next;
+                let Some(&Deferred::Length(length)) = state.deferred.get(cursor) else {
+                    return Err(EncodeError::inconsistent_encoding(codec));
+                };
+                state.next += 1;
+                self.write_length(length)?;
+                Ok(OpenType {
+                    cursor,
+                    start: self.position(),
+                })
+            }
+        }
+    }
+
+    fn end_open_type(&mut self, open_type: OpenType) -> Result<(), EncodeError> {
+        let codec = self.codec();
+        let position = self.position();
+        match &mut self.pass {
+            Pass::Buffered => {
+                // Unfortunately, the contents have to be moved after the
+                // length, as we don't know the length of the length before.
+                self.worker.clear();
+                self.worker.extend(self.output.drain(open_type.start..));
+                self.write_length(self.worker.len())?;
+                self.output.append(self.worker);
+            }
+            Pass::Measure(state) => {
+                let length = position - open_type.start;
+                state.deferred[open_type.cursor] = Deferred::Length(length);
+                self.worker.clear();
+                Self::encode_length(self.worker, length)?;
+                state.written += self.worker.len();
+            }
+            Pass::Write(state, _) => {
+                if !matches!(
+                    state.deferred[open_type.cursor],
+                    Deferred::Length(length) if length == position - open_type.start
+                ) {
+                    return Err(EncodeError::inconsistent_encoding(codec));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the number of bytes written so far.
+    fn position(&self) -> usize {
+        match &self.pass {
+            Pass::Buffered => self.output.len(),
+            Pass::Measure(state) | Pass::Write(state, _) => state.written,
+        }
+    }
+
     // `BTreeMap` is used to maintain the order of the fields in [SET], relying on the `Ord` trait of the [Tag] type.
     fn collect_set(&mut self) {
         self.output.append(
@@ -337,8 +600,8 @@ impl<'buffer, const RCL: usize, const ECL: usize> Encoder<'buffer, RCL, ECL> {
         // We must swap the first bit to show long form
         // It is always zero by default with u8 type when value being < 128
         length |= 0b_1000_0000;
-        self.output.extend_from_slice(&length.to_be_bytes());
-        self.output.extend_from_slice(&bytes.as_ref()[..needed]);
+        self.write(&length.to_be_bytes())?;
+        self.write(&bytes.as_ref()[..needed])?;
         Ok(())
     }
     /// Encode the length of the value to output.
@@ -380,12 +643,12 @@ impl<'buffer, const RCL: usize, const ECL: usize> Encoder<'buffer, RCL, ECL> {
     ) -> Result<(), EncodeError> {
         if signed {
             let (bytes, needed) = value_to_enc.to_signed_bytes_be();
-            Self::encode_length(self.output, needed)?;
-            self.output.extend_from_slice(&bytes.as_ref()[..needed]);
+            self.write_length(needed)?;
+            self.write(&bytes.as_ref()[..needed])?;
         } else {
             let (bytes, needed) = value_to_enc.to_unsigned_bytes_be();
-            Self::encode_length(self.output, needed)?;
-            self.output.extend_from_slice(&bytes.as_ref()[..needed]);
+            self.write_length(needed)?;
+            self.write(&bytes.as_ref()[..needed])?;
         };
         Ok(())
     }
@@ -465,8 +728,7 @@ impl<'buffer, const RCL: usize, const ECL: usize> Encoder<'buffer, RCL, ECL> {
                 // Branchless selection using array indexing
                 let idx = usize::from(signed && value.is_negative());
                 let padded_bytes = [&PADDED_BYTES_POS, &PADDED_BYTES_NEG][idx];
-                self.output
-                    .extend_from_slice(&padded_bytes[..octets - needed]);
+                self.write(&padded_bytes[..octets - needed])?;
             }
             Ordering::Less => {
                 return Err(EncodeError::from_kind(
@@ -480,7 +742,7 @@ impl<'buffer, const RCL: usize, const ECL: usize> Encoder<'buffer, RCL, ECL> {
             // As is
             Ordering::Equal => {}
         };
-        self.output.extend_from_slice(&bytes[..needed]);
+        self.write(&bytes[..needed])?;
         Ok(())
     }
     fn check_fixed_size_constraint(
@@ -511,12 +773,10 @@ impl<'buffer, const RCL: usize, const ECL: usize> Encoder<'buffer, RCL, ECL> {
 
     // Reserve byte space for extension presence bitmap
     // if we do it early, we avoid most extra allocations
-    fn extension_bitmap_reserve(&mut self) {
-        self.cursor.set_extension_bitmap_cursor(self.output.len());
-        self.output.extend(core::iter::repeat_n(
-            0,
-            self.cursor.extension_bitmap_total_width,
-        ));
+    fn extension_bitmap_reserve(&mut self) -> Result<(), EncodeError> {
+        let cursor = self.reserve(self.cursor.extension_bitmap_total_width)?;
+        self.cursor.set_extension_bitmap_cursor(cursor);
+        Ok(())
     }
 
     /// Encode a constructed type.`RC` is the number root components, `EC` is the number of extension components.
@@ -559,16 +819,16 @@ impl<'buffer, const RCL: usize, const ECL: usize> Encoder<'buffer, RCL, ECL> {
         if needed > 0 || C::IS_EXTENSIBLE {
             // `.as_raw_slice` seems to be faster than `BitSlice::domain()`
             if RC == 0 && C::IS_EXTENSIBLE {
-                self.output.push(u8::from(extensions_present) << 7);
+                self.write(&[u8::from(extensions_present) << 7])?;
             } else {
                 // replace reserved preamble position with correct values, starting from preamble_start index
                 if self.options.set_encoding {
-                    self.output
-                        .extend_from_slice(&preamble.as_raw_slice()[..self.cursor.preamble_width]);
+                    self.write(&preamble.as_raw_slice()[..self.cursor.preamble_width])?;
                 } else {
-                    self.output[self.cursor.preamble_cursor
-                        ..self.cursor.preamble_cursor + self.cursor.preamble_width]
-                        .copy_from_slice(&preamble.as_raw_slice()[..self.cursor.preamble_width]);
+                    self.fill_reserved(
+                        self.cursor.preamble_cursor,
+                        &preamble.as_raw_slice()[..self.cursor.preamble_width],
+                    )?;
                 }
             }
         }
@@ -587,20 +847,20 @@ impl<'buffer, const RCL: usize, const ECL: usize> Encoder<'buffer, RCL, ECL> {
         // We have pre-reserved space for the extension bitmap
         // Replace bytes
         let mut extension_bitmap_buffer: BitArray<[u8; EC], Msb0> = BitArray::default();
-        Self::encode_length(self.worker, self.cursor.extension_bitmap_width)?;
-        let mut cursor = self.cursor.extension_bitmap_cursor + self.worker.len();
-        self.output[self.cursor.extension_bitmap_cursor..cursor].copy_from_slice(self.worker);
-        self.worker.clear();
-        self.output[cursor..=cursor]
-            .copy_from_slice(&self.cursor.extension_missing_bits.to_be_bytes());
-        cursor += 1;
+        let mut bitmap = core::mem::take(self.worker);
+        bitmap.clear();
+        Self::encode_length(&mut bitmap, self.cursor.extension_bitmap_width)?;
+        bitmap.push(self.cursor.extension_missing_bits);
         for (i, bit) in self.extension_bitfield.1.iter().enumerate() {
             extension_bitmap_buffer.set(i, *bit);
         }
         // The size of EC is always at least 1 byte if extensions present, so full octet will always fit
-        self.output[cursor..cursor + self.cursor.extension_bitfield_width].copy_from_slice(
+        bitmap.extend_from_slice(
             &extension_bitmap_buffer.as_raw_slice()[..self.cursor.extension_bitfield_width],
         );
+        let filled = self.fill_reserved(self.cursor.extension_bitmap_cursor, &bitmap);
+        *self.worker = bitmap;
+        filled?;
 
         // NOTE: Length for open type has been already added when encoding extension additions
         // NOTE: Extension data is already in the buffer in correct place
@@ -611,6 +871,143 @@ impl<'buffer, const RCL: usize, const ECL: usize> Encoder<'buffer, RCL, ECL> {
         }
         Ok(())
     }
+
+    /// Encodes a `SEQUENCE` or `SET` value the same way as
+    /// [`Self::encode_constructed`], with the fields known only at runtime.
+    fn encode_constructed_value(
+        &mut self,
+        descriptor: &TypeDescriptor,
+        value: &Value,
+    ) -> Result<(), EncodeError> {
+        let components = value.components(descriptor, self.codec())?;
+        let is_set = matches!(descriptor.kind, TypeKind::Set { .. });
+        let is_extensible = components.extended_fields.is_some();
+        let extensions_present = components.has_extensions();
+
+        // ### PREAMBLE ###
+        // Section 16.2.2
+        let mut preamble = BitVec::<u8, Msb0>::new();
+        if is_extensible {
+            preamble.push(extensions_present);
+        }
+        // Section 16.2.3
+        let mut option_bitfield = components
+            .fields
+            .iter()
+            .zip(&components.root)
+            .filter(|(field, _)| field.presence.is_optional_or_default())
+            .map(|(field, value)| (value.is_some(), field.ty.tag))
+            .collect::<Vec<_>>();
+        if is_set {
+            // In set encoding, tags must be unique so we just sort them to be in canonical order for preamble
+            option_bitfield.sort_by_key(|(_, tag)| *tag);
+        }
+        preamble.extend(option_bitfield.iter().map(|(bit, _tag)| *bit));
+        // 16.2.4 - missing bits of the last octet are zeros
+        let mut output = preamble.as_raw_slice().to_vec();
+
+        // Section 16.3 ### Encodings of the components in the extension root ###
+        let mut root = Vec::new();
+        let mut worker = Vec::new();
+        let mut options = self.options;
+        options.set_encoding = is_set;
+        let mut encoder = Encoder::<0, 0>::from_buffer(options, &mut root, &mut worker);
+        for (field, value) in components.present() {
+            encoder.encode_value(field.ty, value)?;
+        }
+        if is_set {
+            encoder.collect_set();
+        }
+        output.append(&mut root);
+
+        if is_extensible && extensions_present {
+            // Section 16.4 ### Extension addition presence bitmap ###
+            let bitmap = components
+                .extensions
+                .iter()
+                .map(Option::is_some)
+                .collect::<BitVec<u8, Msb0>>();
+            let missing_bits = (8 - components.extensions.len() % 8) % 8;
+            Self::encode_length(&mut output, 1 + bitmap.as_raw_slice().len())?;
+            output.push(missing_bits as u8);
+            output.extend_from_slice(bitmap.as_raw_slice());
+
+            // Section 16.5 ### Extension additions as open types ###
+            for (field, value) in components.present_extensions() {
+                let mut addition = Vec::new();
+                let mut worker = Vec::new();
+                Encoder::<0, 0>::from_buffer(
+                    self.options.without_set_encoding(),
+                    &mut addition,
+                    &mut worker,
+                )
+                .encode_value(field.ty, value)?;
+                Self::encode_length(&mut output, addition.len())?;
+                output.append(&mut addition);
+            }
+        }
+
+        self.write(&output)?;
+        self.extend(descriptor.tag);
+        Ok(())
+    }
+
+    /// Encodes a `CHOICE` value the same way as `encode_choice`, with the
+    /// alternatives known only at runtime.
+    fn encode_choice_value(
+        &mut self,
+        descriptor: &TypeDescriptor,
+        value: &Value,
+    ) -> Result<(), EncodeError> {
+        let (_, is_extension, variant, value) = value.chosen(descriptor, self.codec())?;
+        let tag = variant.ty.tag;
+
+        // Encode tag
+        let mut tag_buffer: BitArray<[u8; core::mem::size_of::<Tag>() + 1], Msb0> =
+            BitArray::default();
+        let needed = self.encode_tag(tag, tag_buffer.as_mut_bitslice());
+        self.write(&tag_buffer.as_raw_slice()[..(needed / 8)])?;
+
+        // Encode the value, extensions with length determinant
+        let mut output = Vec::new();
+        let mut worker = Vec::new();
+        Encoder::<0, 0>::from_buffer(
+            self.options.without_set_encoding(),
+            &mut output,
+            &mut worker,
+        )
+        .encode_value(variant.ty, value)?;
+        if is_extension {
+            self.write_length(output.len())?;
+        }
+        self.write(&output)?;
+        self.extend(tag);
+        Ok(())
+    }
+
+    /// Encodes a `SEQUENCE OF` or `SET OF` value the same way as
+    /// `encode_sequence_of`, with the element type known only at runtime.
+    fn encode_sequence_of_value(
+        &mut self,
+        descriptor: &TypeDescriptor,
+        element: &TypeDescriptor,
+        value: &Value,
+    ) -> Result<(), EncodeError> {
+        let values = value.elements(descriptor, self.codec())?;
+        self.encode_unconstrained_integer(&values.len(), false)?;
+
+        let mut encoder = Encoder::<0>::with_pass(
+            self.options.without_set_encoding(),
+            self.output,
+            self.worker,
+            self.pass.reborrow(),
+        );
+        for value in values {
+            encoder.encode_value(element, value)?;
+        }
+        self.extend(descriptor.tag);
+        Ok(())
+    }
 }
 
 impl<'buffer, const RFC: usize, const EFC: usize> crate::Encoder<'buffer>
@@ -647,8 +1044,7 @@ impl<'buffer, const RFC: usize, const EFC: usize> crate::Encoder<'buffer>
         value: bool,
         _: Identifier,
     ) -> Result<Self::Ok, Self::Error> {
-        self.output
-            .extend_from_slice(if value { &[0xffu8] } else { &[0x00u8] });
+        self.write(if value { &[0xffu8] } else { &[0x00u8] })?;
         self.extend(tag);
         Ok(())
     }
@@ -681,8 +1077,7 @@ impl<'buffer, const RFC: usize, const EFC: usize> crate::Encoder<'buffer>
                     if missing_bits > 0 {
                         bit_string_encoding.extend(core::iter::repeat_n(false, missing_bits));
                     }
-                    self.output
-                        .extend_from_slice(bit_string_encoding.as_raw_slice());
+                    self.write(bit_string_encoding.as_raw_slice())?;
                 } else {
                     return Err(EncodeError::size_constraint_not_satisfied(
                         value.len(),
@@ -697,8 +1092,8 @@ impl<'buffer, const RFC: usize, const EFC: usize> crate::Encoder<'buffer>
 
         // If the BitString is empty, length is one and initial octet is zero
         if value.is_empty() {
-            Self::encode_length(self.output, 1)?;
-            self.output.extend_from_slice(&[0x00u8]);
+            self.write_length(1)?;
+            self.write(&[0x00u8])?;
         } else {
             // TODO 22.7 X.680, NamedBitString and COER
             // if self.options.encoding_rules.is_coer()
@@ -718,9 +1113,8 @@ impl<'buffer, const RFC: usize, const EFC: usize> crate::Encoder<'buffer>
             bit_string_encoding.extend(missing_bits.to_u8().unwrap_or(0).to_be_bytes());
             bit_string_encoding.extend(value);
             bit_string_encoding.extend(trailing);
-            Self::encode_length(self.output, bit_string_encoding.len() / 8)?;
-            self.output
-                .extend_from_slice(bit_string_encoding.as_raw_slice());
+            self.write_length(bit_string_encoding.len() / 8)?;
+            self.write(bit_string_encoding.as_raw_slice())?;
         }
         self.extend(tag);
         Ok(())
@@ -755,13 +1149,76 @@ impl<'buffer, const RFC: usize, const EFC: usize> crate::Encoder<'buffer>
         _: Identifier,
     ) -> Result<Self::Ok, Self::Error> {
         let mut enc = crate::ber::enc::Encoder::new(crate::ber::enc::EncoderOptions::ber());
-        let mut octets = enc.object_identifier_as_bytes(value)?;
-        Self::encode_length(self.output, octets.len())?;
-        self.output.append(&mut octets);
+        let octets = enc.object_identifier_as_bytes(value)?;
+        self.write_length(octets.len())?;
+        self.write(&octets)?;
+        self.extend(tag);
+        Ok(())
+    }
+
+    fn encode_encoded_oid(
+        &mut self,
+        tag: Tag,
+        value: &crate::types::EncodedOid,
+        _: Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.write_length(value.as_bytes().len())?;
+        self.write(value.as_bytes())?;
+        self.extend(tag);
+        Ok(())
+    }
+
+    fn encode_relative_oid(
+        &mut self,
+        tag: Tag,
+        value: &[u32],
+        _: Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        let mut enc = crate::ber::enc::Encoder::new(crate::ber::enc::EncoderOptions::ber());
+        let octets = enc.relative_oid_as_bytes(value)?;
+        self.write_length(octets.len())?;
+        self.write(&octets)?;
         self.extend(tag);
         Ok(())
     }
 
+    fn encode_oid_iri(
+        &mut self,
+        tag: Tag,
+        value: &OidIri,
+        _: Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_utf8_string(
+            tag,
+            Constraints::default(),
+            value.as_str(),
+            Identifier::EMPTY,
+        )
+    }
+
+    fn encode_relative_oid_iri(
+        &mut self,
+        tag: Tag,
+        value: &RelativeOidIri,
+        _: Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_utf8_string(
+            tag,
+            Constraints::default(),
+            value.as_str(),
+            Identifier::EMPTY,
+        )
+    }
+
+    fn encode_external(
+        &mut self,
+        tag: Tag,
+        value: &External,
+        _: Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        value.encode_legacy(self, tag)
+    }
+
     fn encode_integer<I: IntegerType>(
         &mut self,
         tag: Tag,
@@ -780,7 +1237,7 @@ impl<'buffer, const RFC: usize, const EFC: usize> crate::Encoder<'buffer>
         _: Identifier,
     ) -> Result<Self::Ok, Self::Error> {
         let (bytes, len) = value.to_ieee754_bytes();
-        self.output.extend_from_slice(&bytes.as_ref()[..len]);
+        self.write(&bytes.as_ref()[..len])?;
         self.extend(tag);
 
         Ok(())
@@ -798,11 +1255,11 @@ impl<'buffer, const RFC: usize, const EFC: usize> crate::Encoder<'buffer>
         _: Identifier,
     ) -> Result<Self::Ok, Self::Error> {
         if self.check_fixed_size_constraint(value.len(), &constraints)? {
-            self.output.extend_from_slice(value);
+            self.write(value)?;
         } else {
             // Use length determinant on other cases
-            Self::encode_length(self.output, value.len())?;
-            self.output.extend_from_slice(value);
+            self.write_length(value.len())?;
+            self.write(value)?;
         }
         self.extend(tag);
         Ok(())
@@ -944,6 +1401,62 @@ impl<'buffer, const RFC: usize, const EFC: usize> crate::Encoder<'buffer>
             Identifier::EMPTY,
         )
     }
+
+    fn encode_time(
+        &mut self,
+        tag: Tag,
+        value: &IsoTime,
+        _: Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_octet_string(
+            tag,
+            Constraints::default(),
+            value.as_str().as_bytes(),
+            Identifier::EMPTY,
+        )
+    }
+
+    fn encode_time_of_day(
+        &mut self,
+        tag: Tag,
+        value: &TimeOfDay,
+        _: Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_octet_string(
+            tag,
+            Constraints::default(),
+            &crate::der::enc::Encoder::naivetime_to_time_of_day_bytes(value, self.codec())?,
+            Identifier::EMPTY,
+        )
+    }
+
+    fn encode_date_time(
+        &mut self,
+        tag: Tag,
+        value: &DateTime,
+        _: Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_octet_string(
+            tag,
+            Constraints::default(),
+            &crate::der::enc::Encoder::naivedatetime_to_date_time_bytes(value, self.codec())?,
+            Identifier::EMPTY,
+        )
+    }
+
+    fn encode_duration(
+        &mut self,
+        tag: Tag,
+        value: &IsoDuration,
+        _: Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_octet_string(
+            tag,
+            Constraints::default(),
+            value.to_string().as_bytes(),
+            Identifier::EMPTY,
+        )
+    }
     fn encode_explicit_prefix<V: Encode>(
         &mut self,
         tag: Tag,
@@ -968,20 +1481,18 @@ impl<'buffer, const RFC: usize, const EFC: usize> crate::Encoder<'buffer>
         C: Constructed<RL, EL>,
         F: FnOnce(&mut Self::AnyEncoder<'b, RL, EL>) -> Result<(), Self::Error>,
     {
-        let mut encoder = Encoder::<'_, RL, EL>::from_buffer(
+        let mut encoder = Encoder::<'_, RL, EL>::with_pass(
             self.options.without_set_encoding(),
             self.output,
             self.worker,
+            self.pass.reborrow(),
         );
         let mut cursor = ConstructedCursor::<RL, EL>::new(
             C::FIELDS.number_of_optional_and_default_fields(),
             C::IS_EXTENSIBLE,
         );
-        cursor.set_preamble_cursor(encoder.output.len());
         // reserve bytes for preamble
-        for _ in 0..cursor.preamble_width {
-            encoder.output.push(0);
-        }
+        cursor.set_preamble_cursor(encoder.reserve(cursor.preamble_width)?);
 
         encoder.cursor = cursor;
         encoder_scope(&mut encoder)?;
@@ -1002,9 +1513,12 @@ impl<'buffer, const RFC: usize, const EFC: usize> crate::Encoder<'buffer>
     ) -> Result<Self::Ok, Self::Error> {
         // It seems that constraints here are not C/OER visible? No mention in standard...
         self.encode_unconstrained_integer(&value.len(), false)?;
-        self.output.reserve(core::mem::size_of_val(value));
+        if let Pass::Buffered = self.pass {
+            self.output.reserve(core::mem::size_of_val(value));
+        }
 
-        let mut encoder = Encoder::<0>::from_buffer(self.options, self.output, self.worker);
+        let mut encoder =
+            Encoder::<0>::with_pass(self.options, self.output, self.worker, self.pass.reborrow());
         {
             for one in value {
                 E::encode(one, &mut encoder)?;
@@ -1027,6 +1541,7 @@ impl<'buffer, const RFC: usize, const EFC: usize> crate::Encoder<'buffer>
         let mut options = self.options;
         options.set_encoding = true;
         let mut encoder = Encoder::<RL, EL>::from_buffer(options, self.output, self.worker);
+        encoder.set_pass = self.pass.reborrow();
         let cursor = ConstructedCursor::<RL, EL>::new(
             C::FIELDS.number_of_optional_and_default_fields(),
             C::IS_EXTENSIBLE,
@@ -1039,6 +1554,14 @@ impl<'buffer, const RFC: usize, const EFC: usize> crate::Encoder<'buffer>
             encoder.encode_constructed::<RL, EL, C>(tag, None)?;
         }
         encoder.collect_set();
+        if !matches!(encoder.set_pass, Pass::Buffered) {
+            // The output buffer is only used for SET values when encoding in two passes
+            let mut output = core::mem::take(encoder.output);
+            encoder.pass = core::mem::replace(&mut encoder.set_pass, Pass::Buffered);
+            encoder.write(&output)?;
+            output.clear();
+            *encoder.output = output;
+        }
         Ok(())
     }
 
@@ -1082,6 +1605,20 @@ impl<'buffer, const RFC: usize, const EFC: usize> crate::Encoder<'buffer>
         Ok(())
     }
 
+    fn encode_default_with_explicit_prefix<E: Encode + PartialEq>(
+        &mut self,
+        tag: Tag,
+        value: &E,
+        default: impl FnOnce() -> E,
+        identifier: Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        if *value == (default)() {
+            return self.encode_none_with_tag(tag, identifier);
+        }
+        self.set_presence(tag, true);
+        self.encode_explicit_prefix(tag, value, identifier)
+    }
+
     fn encode_choice<E: Encode + Choice>(
         &mut self,
         _: Constraints,
@@ -1093,23 +1630,20 @@ impl<'buffer, const RFC: usize, const EFC: usize> crate::Encoder<'buffer>
         let mut tag_buffer: BitArray<[u8; core::mem::size_of::<Tag>() + 1], Msb0> =
             BitArray::default();
         let needed = self.encode_tag(tag, tag_buffer.as_mut_bitslice());
-        self.output
-            .extend_from_slice(&tag_buffer.as_raw_slice()[..(needed / 8)]);
+        self.write(&tag_buffer.as_raw_slice()[..(needed / 8)])?;
 
-        let buffer_end = self.output.len();
+        let is_root_extension = crate::types::TagTree::tag_contains(&tag, E::VARIANTS);
+        // Extensions are encoded with length determinant
+        let open_type = if is_root_extension {
+            None
+        } else {
+            Some(self.begin_open_type()?)
+        };
         // Encode the value
         let _tag = encode_fn(self)?;
         debug_assert_eq!(_tag, tag);
-        let is_root_extension = crate::types::TagTree::tag_contains(&tag, E::VARIANTS);
-        if is_root_extension {
-            // all good, correct data in the buffer already
-        } else {
-            // Extension with length determinant
-            // Unfortunatelly, we really cannot avoid extra allocating here
-            // We don't know the length of the data length before encoding
-            self.worker.append(&mut self.output.split_off(buffer_end));
-            Self::encode_length(self.output, self.worker.len())?;
-            self.output.append(self.worker);
+        if let Some(open_type) = open_type {
+            self.end_open_type(open_type)?;
         }
         self.extend(tag);
         Ok(())
@@ -1122,16 +1656,18 @@ impl<'buffer, const RFC: usize, const EFC: usize> crate::Encoder<'buffer>
         value: E,
         _: Identifier,
     ) -> Result<Self::Ok, Self::Error> {
-        // let buffer_end = self.output.len();
         if value.is_present() {
             if self.cursor.extension_bitmap_cursor == 0 {
-                self.extension_bitmap_reserve();
+                self.extension_bitmap_reserve()?;
             }
-            let cursor = self.output.len();
-            // Since we need to calculate the unknown length of the data length, data must be encoded at first
-            // Swap the buffers here to avoid extra alloactions
-            // Also helps us on playing with mutability checks...
-            let mut encoder = Encoder::<0>::from_buffer(self.options, self.worker, self.output);
+            // Extension additions are encoded as open types
+            let open_type = self.begin_open_type()?;
+            let mut encoder = Encoder::<0>::with_pass(
+                self.options.without_set_encoding(),
+                self.output,
+                self.worker,
+                self.pass.reborrow(),
+            );
             E::encode_with_tag_and_constraints(
                 &value,
                 &mut encoder,
@@ -1139,10 +1675,7 @@ impl<'buffer, const RFC: usize, const EFC: usize> crate::Encoder<'buffer>
                 constraints,
                 Identifier::EMPTY,
             )?;
-            // Truncate the actual output buffer to the original state
-            encoder.worker.truncate(cursor);
-            Self::encode_length(encoder.worker, encoder.output.len())?;
-            self.output.append(self.worker);
+            self.end_open_type(open_type)?;
             self.set_extension_presence(true);
         } else {
             self.set_extension_presence(false);
@@ -1162,23 +1695,58 @@ impl<'buffer, const RFC: usize, const EFC: usize> crate::Encoder<'buffer>
             return Ok(());
         };
         if self.cursor.extension_bitmap_cursor == 0 {
-            self.extension_bitmap_reserve();
+            self.extension_bitmap_reserve()?;
         }
         self.is_extension_sequence = true;
-        // Since we need to calculate the unknown length of the data length, data must be encoded at first
-        // Swap the buffers here to avoid extra alloactions
-        // Also helps us on playing with mutability checks...
-        let cursor = self.output.len();
-        let mut encoder = Encoder::<0>::from_buffer(self.options, self.worker, self.output);
+        // Extension addition groups are encoded as open types
+        let open_type = self.begin_open_type()?;
+        let mut encoder = Encoder::<0>::with_pass(
+            self.options.without_set_encoding(),
+            self.output,
+            self.worker,
+            self.pass.reborrow(),
+        );
         value.encode(&mut encoder)?;
-        // Truncate the actual output buffer to the original state
-        encoder.worker.truncate(cursor);
         self.is_extension_sequence = false;
-        Self::encode_length(encoder.worker, encoder.output.len())?;
-        self.output.append(self.worker);
+        self.end_open_type(open_type)?;
         self.set_extension_presence(true);
         Ok(())
     }
+
+    fn encode_value(
+        &mut self,
+        descriptor: &TypeDescriptor,
+        value: &Value,
+    ) -> Result<Self::Ok, Self::Error> {
+        let descriptor = &descriptor.resolve();
+        match descriptor.kind {
+            TypeKind::Enumerated { .. } => {
+                // 11.5 The presence of an extension marker in the definition of an enumerated
+                // type does not affect the encoding of the values of the enumerated type.
+                let (_, _, variant) = value.enumerated(descriptor, self.codec())?;
+                let number = variant.discriminant;
+                if 0isize <= number && number <= i8::MAX.into() {
+                    self.encode_constrained_integer_with_padding(1, &number, false)?;
+                } else {
+                    self.encode_unconstrained_enum_index(number)?;
+                }
+                self.extend(descriptor.tag);
+                Ok(())
+            }
+            TypeKind::Sequence { .. } | TypeKind::Set { .. } => {
+                self.encode_constructed_value(descriptor, value)
+            }
+            TypeKind::Choice { .. } => self.encode_choice_value(descriptor, value),
+            TypeKind::SequenceOf { element } | TypeKind::SetOf { element } => {
+                self.encode_sequence_of_value(descriptor, element, value)
+            }
+            TypeKind::Explicit { inner } if inner.is_choice() => self.encode_value(inner, value),
+            TypeKind::Explicit { inner } => {
+                self.encode_value(&inner.with_tag(descriptor.tag), value)
+            }
+            _ => value.encode_primitive(self, descriptor),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1290,4 +1858,94 @@ mod tests {
 
         assert_eq!(encoder.output(), &[128, 2, 1, 77]);
     }
+
+    #[test]
+    fn two_pass() {
+        use crate as rasn;
+        use rasn::prelude::*;
+
+        #[derive(AsnType, Clone, Debug, Encode, PartialEq, Eq, Hash)]
+        #[rasn(automatic_tags)]
+        #[non_exhaustive]
+        struct Inner {
+            id: Integer,
+            name: Option<Utf8String>,
+            flag: bool,
+            #[rasn(extension_addition)]
+            data: Option<OctetString>,
+        }
+
+        #[derive(AsnType, Clone, Debug, Encode, PartialEq)]
+        #[rasn(set, automatic_tags)]
+        struct Attributes {
+            b: bool,
+            a: Vec<Inner>,
+        }
+
+        #[derive(AsnType, Clone, Debug, Encode, PartialEq)]
+        #[rasn(choice, automatic_tags)]
+        #[non_exhaustive]
+        enum Choice {
+            Inner(Inner),
+            Flag(bool),
+            #[rasn(extension_addition)]
+            Nested(Vec<Inner>),
+        }
+
+        #[derive(AsnType, Clone, Debug, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        struct Outer {
+            first: Option<Inner>,
+            attributes: Attributes,
+            choices: Vec<Choice>,
+            last: Choice,
+        }
+
+        let inner = |id: i32, len: usize| Inner {
+            id: id.into(),
+            name: (id % 2 == 0).then(|| "inner".into()),
+            flag: id % 3 == 0,
+            data: (len > 0).then(|| alloc::vec![id as u8; len].into()),
+        };
+        let value = Outer {
+            first: Some(inner(1, 3)),
+            attributes: Attributes {
+                b: true,
+                a: alloc::vec![inner(2, 0), inner(3, 300)],
+            },
+            choices: alloc::vec![
+                Choice::Nested(alloc::vec![inner(4, 20_000)]),
+                Choice::Flag(true),
+                Choice::Inner(inner(5, 0)),
+            ],
+            last: Choice::Inner(inner(6, 7)),
+        };
+
+        {
+            let options = EncoderOptions::coer();
+            let mut output = Vec::new();
+            let mut worker = Vec::new();
+            let mut encoder = super::Encoder::<0>::from_buffer(options, &mut output, &mut worker);
+            value.encode(&mut encoder).unwrap();
+            let expected = encoder.output();
+
+            let mut buffer = alloc::vec![0; expected.len()];
+            let sink = crate::enc::SliceSink::new(&mut buffer);
+            assert_eq!(
+                expected.len(),
+                super::Encoder::encode_to_sink(options, &value, sink).unwrap()
+            );
+            assert_eq!(expected, buffer);
+
+            // Writing stops at the first write which doesn't fit.
+            let mut buffer = alloc::vec![0; expected.len() - 1];
+            let sink = crate::enc::SliceSink::new(&mut buffer);
+            let error = super::Encoder::encode_to_sink(options, &value, sink).unwrap_err();
+            assert!(matches!(
+                *error.kind,
+                crate::error::EncodeErrorKind::BufferTooSmall { needed, available }
+                    if needed > available && available == expected.len() - 1
+            ));
+        }
+    }
 }
@@ -1,5 +1,6 @@
 pub mod de;
 pub mod enc;
+mod time;
 
 use crate::types::Constraints;
 
@@ -12,6 +13,17 @@ const SIXTY_FOUR_K: u32 = 65536;
 const SMALL_UNSIGNED_CONSTRAINT: Constraints = constraints!(value_constraint!(0, 63));
 const LARGE_UNSIGNED_CONSTRAINT: Constraints = constraints!(value_constraint!(start: 0));
 
+/// The constraint of the index of a root alternative of a `CHOICE` type
+/// described at runtime, mirroring `Choice::VARIANCE_CONSTRAINT` which is
+/// derived from the number of all alternatives.
+fn variance_constraint(variance: usize) -> Constraints {
+    use crate::types::constraints::{Bounded, Constraint, Extensible, Value};
+
+    Constraints::new(&[Constraint::Value(Extensible::new(Value::new(
+        Bounded::const_new(0, variance.saturating_sub(1) as i128),
+    )))])
+}
+
 /// Attempts to decode `T` from `input` using PER.
 pub(crate) fn decode<T: crate::Decode>(
     options: de::DecoderOptions,
@@ -39,6 +51,32 @@ pub(crate) fn decode_with_remainder<T: crate::Decode>(
     Ok((decoded_instance, &input[input.len() - remaining_size..]))
 }
 
+/// Attempts to decode a value of the type described by `descriptor` from
+/// `input` using PER.
+pub(crate) fn decode_value<'d>(
+    options: de::DecoderOptions,
+    descriptor: &crate::types::TypeDescriptor<'d>,
+    input: &[u8],
+) -> Result<crate::types::value::Value<'d>, crate::error::DecodeError> {
+    crate::Decoder::decode_value(
+        &mut Decoder::<0, 0>::new(crate::types::BitStr::from_slice(input), options),
+        descriptor,
+    )
+}
+
+/// Attempts to encode `value` as the type described by `descriptor` to PER.
+pub(crate) fn encode_value(
+    options: enc::EncoderOptions,
+    descriptor: &crate::types::TypeDescriptor,
+    value: &crate::types::value::Value,
+) -> Result<alloc::vec::Vec<u8>, crate::error::EncodeError> {
+    let mut enc = crate::per::enc::Encoder::<0, 0>::new(options);
+
+    crate::Encoder::encode_value(&mut enc, descriptor, value)?;
+
+    Ok(enc.output())
+}
+
 /// Attempts to encode `value` to PER.
 pub(crate) fn encode<T: crate::Encode>(
     options: enc::EncoderOptions,
@@ -8,12 +8,16 @@ use super::{
     THIRTY_TWO_K,
 };
 use crate::{
-    de::Error as _,
+    de::{Decoder as _, Error as _},
     types::{
         self,
         constraints::{self, Extensible},
+        descriptor::{
+            EnumeratedVariant, FieldDescriptor, TypeDescriptor, TypeKind, VariantDescriptor,
+        },
         fields::{Field, Fields},
         strings::{should_be_indexed, StaticPermittedAlphabet},
+        value::{NamedValue, Value},
         Constraints, Enumerated, IntegerType, SetOf, Tag,
     },
     Decode,
@@ -182,6 +186,22 @@ impl<'input, const RFC: usize, const EFC: usize> Decoder<'input, RFC, EFC> {
         Ok(())
     }
 
+    /// Decodes the elements of a `SEQUENCE OF` or `SET OF`. Unlike the
+    /// contents of a string, the elements don't start on an octet boundary
+    /// after a constrained length, each element aligns itself as needed.
+    fn decode_extensible_elements(
+        &mut self,
+        constraints: Constraints,
+        mut decode_fn: impl FnMut(InputSlice<'input>, usize) -> Result<InputSlice<'input>>,
+    ) -> Result<()> {
+        let extensible_is_present = self.parse_extensible_bit(&constraints)?;
+        let constraints = constraints.size().filter(|_| !extensible_is_present);
+        let input =
+            self.decode_constrained_length(self.input, constraints, false, &mut decode_fn)?;
+        self.input = input;
+        Ok(())
+    }
+
     fn decode_octets(&mut self) -> Result<types::BitString> {
         let mut buffer = types::BitString::default();
         let codec = self.codec();
@@ -307,9 +327,19 @@ impl<'input, const RFC: usize, const EFC: usize> Decoder<'input, RFC, EFC> {
     }
 
     fn decode_length(
+        &mut self,
+        input: InputSlice<'input>,
+        constraints: Option<&Extensible<constraints::Size>>,
+        decode_fn: &mut impl FnMut(InputSlice<'input>, usize) -> Result<InputSlice<'input>>,
+    ) -> Result<InputSlice<'input>> {
+        self.decode_constrained_length(input, constraints, true, decode_fn)
+    }
+
+    fn decode_constrained_length(
         &mut self,
         mut input: InputSlice<'input>,
         constraints: Option<&Extensible<constraints::Size>>,
+        is_aligned_contents: bool,
         decode_fn: &mut impl FnMut(InputSlice<'input>, usize) -> Result<InputSlice<'input>>,
     ) -> Result<InputSlice<'input>> {
         let Some(constraints) = constraints else {
@@ -341,7 +371,9 @@ impl<'input, const RFC: usize, const EFC: usize> Decoder<'input, RFC, EFC> {
                 let (mut input, length) =
                     nom::bytes::streaming::take(crate::num::log2(range))(input)
                         .map_err(|e| DecodeError::map_nom_err(e, self.codec()))?;
-                input = self.parse_padding(input)?;
+                if is_aligned_contents {
+                    input = self.parse_padding(input)?;
+                }
                 length
                     .load_be::<usize>()
                     .checked_add(size_constraint.minimum())
@@ -641,6 +673,202 @@ impl<'input, const RFC: usize, const EFC: usize> Decoder<'input, RFC, EFC> {
             .map_err(|e| DecodeError::permitted_alphabet_error(e, self.codec())),
         }
     }
+
+    fn decode_enumerated_value<'d>(
+        &mut self,
+        variants: &[EnumeratedVariant<'d>],
+        extended_variants: Option<&[EnumeratedVariant<'d>]>,
+    ) -> Result<Value<'d>> {
+        let extensible = extended_variants
+            .is_some()
+            .then(|| self.parse_one_bit())
+            .transpose()?
+            .unwrap_or_default();
+
+        let (index, variant) = if extensible {
+            let index: usize = self.parse_normally_small_integer()?;
+            (
+                index,
+                extended_variants.and_then(|variants| variants.get(index)),
+            )
+        } else {
+            let index = self.parse_non_negative_binary_integer::<usize>(variants.len() as i128)?;
+            (index, variants.get(index))
+        };
+
+        variant
+            .map(|variant| Value::Enumerated(variant.name))
+            .ok_or_else(|| {
+                DecodeError::enumeration_index_not_found(index, extensible, self.codec())
+            })
+    }
+
+    fn decode_sequence_value<'d>(
+        &mut self,
+        fields: &[FieldDescriptor<'d>],
+        extended_fields: Option<&[FieldDescriptor<'d>]>,
+        is_set: bool,
+    ) -> Result<Vec<NamedValue<'d>>> {
+        let codec = self.codec();
+        let is_extensible = extended_fields
+            .is_some()
+            .then(|| self.parse_one_bit())
+            .transpose()?
+            .unwrap_or_default();
+        let optional_fields = fields
+            .iter()
+            .filter(|field| field.presence.is_optional_or_default())
+            .count();
+        let (input, bitmap) = nom::bytes::streaming::take(optional_fields)(self.input)
+            .map_err(|e| DecodeError::map_nom_err(e, codec))?;
+        self.input = input;
+
+        let mut bitmap = bitmap.iter().map(|b| *b);
+        let mut present_fields: Vec<_> = fields
+            .iter()
+            .enumerate()
+            .filter(|(_, field)| {
+                !field.presence.is_optional_or_default() || bitmap.next().unwrap_or_default()
+            })
+            .collect();
+        // `SET` components are encoded in the canonical order of their tags.
+        if is_set {
+            present_fields.sort_by_key(|(_, field)| field.ty.smallest_tag());
+        }
+
+        let mut values = Vec::with_capacity(present_fields.len());
+        for (index, field) in present_fields {
+            let value = self
+                .decode_value(field.ty)
+                .map_err(|error| DecodeError::described_field_error(field.name, error, codec))?;
+            values.push((index, NamedValue::new(field.name, value)));
+        }
+        if is_set {
+            values.sort_by_key(|(index, _)| *index);
+        }
+        let mut values: Vec<_> = values.into_iter().map(|(_, value)| value).collect();
+
+        if is_extensible {
+            let extended_fields = extended_fields.unwrap_or_default();
+            // The length bitfield has a lower bound of `1..`
+            let extensions_length = self.parse_normally_small_integer::<usize>()? + 1;
+            let (input, bitfield) = nom::bytes::streaming::take(extensions_length)(self.input)
+                .map_err(|e| DecodeError::map_nom_err(e, codec))?;
+            self.input = input;
+
+            for (i, is_present) in bitfield.iter().map(|b| *b).enumerate() {
+                let field = extended_fields.get(i);
+                if !is_present {
+                    if let Some(field) = field.filter(|f| !f.presence.is_optional_or_default()) {
+                        return Err(DecodeError::required_extension_not_present(
+                            field.ty.tag,
+                            codec,
+                        ));
+                    }
+                    continue;
+                }
+
+                // Extension additions are open types, which lets us skip the
+                // ones unknown to the descriptor.
+                let bytes = self.decode_octets()?;
+                if let Some(field) = field {
+                    let value = Decoder::<0, 0>::new(&bytes, self.options)
+                        .decode_value(field.ty)
+                        .map_err(|error| {
+                            DecodeError::described_field_error(field.name, error, codec)
+                        })?;
+                    values.push(NamedValue::new(field.name, value));
+                }
+            }
+        }
+
+        Ok(values)
+    }
+
+    fn decode_choice_value<'d>(
+        &mut self,
+        variants: &[VariantDescriptor<'d>],
+        extended_variants: Option<&[VariantDescriptor<'d>]>,
+    ) -> Result<Value<'d>> {
+        let is_extensible = extended_variants
+            .is_some()
+            .then(|| self.parse_one_bit())
+            .transpose()?
+            .unwrap_or_default();
+        let candidates = if is_extensible {
+            extended_variants.unwrap_or_default()
+        } else {
+            variants
+        };
+
+        let index = if is_extensible {
+            self.parse_normally_small_integer::<usize>()
+                .map_err(|error| {
+                    DecodeError::choice_index_exceeds_platform_width(
+                        usize::BITS,
+                        error,
+                        self.codec(),
+                    )
+                })?
+        } else if candidates.len() != 1 {
+            let variance = variants.len() + extended_variants.map_or(0, <[_]>::len);
+            self.parse_integer(super::variance_constraint(variance))
+                .map_err(|error| {
+                    DecodeError::choice_index_exceeds_platform_width(
+                        usize::BITS,
+                        error,
+                        self.codec(),
+                    )
+                })?
+        } else {
+            0
+        };
+
+        let variant = candidates.get(index).ok_or_else(|| {
+            DecodeError::choice_index_not_found(
+                index,
+                types::variants::Variants::from(alloc::borrow::Cow::Owned(
+                    candidates
+                        .iter()
+                        .map(|variant| types::TagTree::Leaf(variant.ty.smallest_tag()))
+                        .collect(),
+                )),
+                self.codec(),
+            )
+        })?;
+
+        let value = if is_extensible {
+            let bytes = self.decode_octets()?;
+            Decoder::<0, 0>::new(&bytes, self.options).decode_value(variant.ty)?
+        } else {
+            self.decode_value(variant.ty)?
+        };
+
+        Ok(Value::Choice {
+            variant: variant.name,
+            value: alloc::boxed::Box::new(value),
+        })
+    }
+
+    fn decode_sequence_of_value<'d>(
+        &mut self,
+        element: &TypeDescriptor<'d>,
+        constraints: Constraints,
+    ) -> Result<Vec<Value<'d>>> {
+        let mut sequence_of = Vec::new();
+        let options = self.options;
+        self.decode_extensible_elements(constraints, |mut input, length| {
+            for _ in 0..length {
+                let mut decoder = Decoder::<0, 0>::new(input.0, options);
+                sequence_of.push(decoder.decode_value(element)?);
+                input = decoder.input;
+            }
+
+            Ok(input)
+        })?;
+
+        Ok(sequence_of)
+    }
 }
 impl<'input, const RFC: usize, const EFC: usize> crate::Decoder for Decoder<'input, RFC, EFC> {
     type Ok = ();
@@ -650,6 +878,44 @@ impl<'input, const RFC: usize, const EFC: usize> crate::Decoder for Decoder<'inp
     fn codec(&self) -> crate::Codec {
         Self::codec(self)
     }
+
+    fn decode_value<'d>(
+        &mut self,
+        descriptor: &TypeDescriptor<'d>,
+    ) -> Result<Value<'d>, Self::Error> {
+        let descriptor = &descriptor.resolve();
+        match descriptor.kind {
+            TypeKind::Enumerated {
+                variants,
+                extended_variants,
+            } => self.decode_enumerated_value(variants, extended_variants),
+            TypeKind::Sequence {
+                fields,
+                extended_fields,
+            } => self
+                .decode_sequence_value(fields, extended_fields, false)
+                .map(Value::Sequence),
+            TypeKind::Set {
+                fields,
+                extended_fields,
+            } => self
+                .decode_sequence_value(fields, extended_fields, true)
+                .map(Value::Set),
+            TypeKind::Choice {
+                variants,
+                extended_variants,
+            } => self.decode_choice_value(variants, extended_variants),
+            TypeKind::SequenceOf { element } => self
+                .decode_sequence_of_value(element, descriptor.constraints.get())
+                .map(Value::SequenceOf),
+            TypeKind::SetOf { element } => self
+                .decode_sequence_of_value(element, descriptor.constraints.get())
+                .map(Value::SetOf),
+            TypeKind::Explicit { inner } => self.decode_value(inner),
+            _ => Value::decode_primitive(self, descriptor),
+        }
+    }
+
     fn decode_any(&mut self) -> Result<types::Any> {
         let mut octet_string = types::BitString::default();
         let codec = self.codec();
@@ -699,7 +965,11 @@ impl<'input, const RFC: usize, const EFC: usize> crate::Decoder for Decoder<'inp
         _: Tag,
         _: Constraints,
     ) -> Result<R, Self::Error> {
-        Err(DecodeError::real_not_supported(self.codec()))
+        let octets = self.decode_octets()?.into_vec();
+        let decoder = crate::ber::de::Decoder::new(&octets, crate::ber::de::DecoderOptions::cer());
+        decoder
+            .decode_real_from_bytes(&octets)
+            .map_err(|e| DecodeError::from_kind(*e.kind, self.codec()))
     }
 
     fn decode_octet_string<'b, T: From<&'b [u8]> + From<Vec<u8>>>(
@@ -732,6 +1002,40 @@ impl<'input, const RFC: usize, const EFC: usize> crate::Decoder for Decoder<'inp
         decoder.decode_object_identifier_from_bytes(&octets)
     }
 
+    fn decode_encoded_oid(&mut self, _: Tag) -> Result<types::EncodedObjectIdentifier> {
+        let octets = self.decode_octets()?.into_vec();
+        let decoder = crate::ber::de::Decoder::new(&octets, crate::ber::de::DecoderOptions::ber());
+        decoder.decode_encoded_oid_from_bytes(&octets)
+    }
+
+    fn decode_relative_oid(&mut self, _: Tag) -> Result<types::RelativeObjectIdentifier> {
+        let octets = self.decode_octets()?.into_vec();
+        let decoder = crate::ber::de::Decoder::new(&octets, crate::ber::de::DecoderOptions::ber());
+        decoder.decode_relative_oid_from_bytes(&octets)
+    }
+
+    fn decode_oid_iri(&mut self, tag: Tag) -> Result<types::OidIri> {
+        let string = self.decode_utf8_string(tag, Constraints::default())?;
+        string.parse::<types::OidIri>().map_err(|error| {
+            DecodeError::string_conversion_failed(Tag::OID_IRI, error.to_string(), self.codec())
+        })
+    }
+
+    fn decode_relative_oid_iri(&mut self, tag: Tag) -> Result<types::RelativeOidIri> {
+        let string = self.decode_utf8_string(tag, Constraints::default())?;
+        string.parse::<types::RelativeOidIri>().map_err(|error| {
+            DecodeError::string_conversion_failed(
+                Tag::RELATIVE_OID_IRI,
+                error.to_string(),
+                self.codec(),
+            )
+        })
+    }
+
+    fn decode_external(&mut self, tag: Tag) -> Result<types::External> {
+        types::External::decode_legacy(self, tag)
+    }
+
     fn decode_bit_string(&mut self, _: Tag, constraints: Constraints) -> Result<types::BitString> {
         let mut bit_string = types::BitString::default();
         let codec = self.codec();
@@ -774,12 +1078,11 @@ impl<'input, const RFC: usize, const EFC: usize> crate::Decoder for Decoder<'inp
         self.parse_fixed_width_string(constraints)
     }
 
-    fn decode_teletex_string(
-        &mut self,
-        _: Tag,
-        _constraints: Constraints,
-    ) -> Result<types::TeletexString> {
-        todo!()
+    fn decode_teletex_string(&mut self, tag: Tag, _: Constraints) -> Result<types::TeletexString> {
+        types::TeletexString::from_bytes(
+            &self.decode_octet_string::<Vec<u8>>(tag, Constraints::default())?,
+        )
+        .map_err(|e| DecodeError::permitted_alphabet_error(e, self.codec()))
     }
 
     fn decode_bmp_string(&mut self, _: Tag, _constraints: Constraints) -> Result<types::BmpString> {
@@ -851,6 +1154,29 @@ impl<'input, const RFC: usize, const EFC: usize> crate::Decoder for Decoder<'inp
         crate::ber::decode(&bytes)
     }
 
+    fn decode_time(&mut self, _: Tag) -> Result<types::IsoTime> {
+        let string: types::VisibleString = self.parse_fixed_width_string(Constraints::default())?;
+        crate::ber::de::Decoder::parse_time_string(&alloc::string::String::from(string))
+    }
+
+    fn decode_time_of_day(&mut self, _: Tag) -> Result<types::TimeOfDay> {
+        super::time::HmsEncoding::decode(self)?
+            .time_of_day()
+            .ok_or_else(|| DecodeError::custom("invalid TIME-OF-DAY value", self.codec()))
+    }
+
+    fn decode_date_time(&mut self, _: Tag) -> Result<types::DateTime> {
+        super::time::DateTimeEncoding::decode(self)?
+            .date_time()
+            .ok_or_else(|| DecodeError::custom("invalid DATE-TIME value", self.codec()))
+    }
+
+    fn decode_duration(&mut self, _: Tag) -> Result<types::IsoDuration> {
+        super::time::DurationIntervalEncoding::decode(self)?
+            .duration()
+            .ok_or_else(|| DecodeError::custom("invalid DURATION value", self.codec()))
+    }
+
     fn decode_sequence_of<D: Decode>(
         &mut self,
         _: Tag,
@@ -858,7 +1184,7 @@ impl<'input, const RFC: usize, const EFC: usize> crate::Decoder for Decoder<'inp
     ) -> Result<Vec<D>, Self::Error> {
         let mut sequence_of = Vec::new();
         let options = self.options;
-        self.decode_extensible_container(constraints, |mut input, length| {
+        self.decode_extensible_elements(constraints, |mut input, length| {
             sequence_of.append(
                 &mut (0..length)
                     .map(|_| {
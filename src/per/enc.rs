@@ -1,6 +1,7 @@
 //! Encoding Rust structures into Packed Encoding Rules data.
 
 use alloc::{borrow::ToOwned, string::ToString, vec::Vec};
+use core::cell::{Cell, RefCell};
 
 use bitvec::prelude::*;
 
@@ -9,12 +10,15 @@ use super::{
     THIRTY_TWO_K,
 };
 use crate::{
+    enc::{Encoder as _, Sink},
     types::{
         self,
         constraints::{self, Extensible, Size},
+        descriptor::{TypeDescriptor, TypeKind},
         strings::{
             should_be_indexed, BitStr, DynConstrainedCharacterString, StaticPermittedAlphabet,
         },
+        value::Value,
         BitString, Constraints, Enumerated, Identifier, IntegerType, Tag,
     },
     Encode,
@@ -68,25 +72,133 @@ impl EncoderOptions {
 ///
 /// Const `RCL` is the count of root components in the root component list of a sequence or set.
 /// Const `ECL` is the count of extension additions in the extension addition component type list in a sequence or set.
+///
+/// `S` is the [`Sink`] written into by [`Encoder::encode_to_sink`], every
+/// other encoder writes into its own buffer.
 #[derive(Debug)]
-pub struct Encoder<const RCL: usize = 0, const ECL: usize = 0> {
+pub struct Encoder<const RCL: usize = 0, const ECL: usize = 0, S = Vec<u8>> {
     options: EncoderOptions,
     output: BitString,
-    set_output: alloc::collections::BTreeMap<Tag, BitString>,
+    pass: Pass<S>,
+    // The number of bits written through `pass` rather than into `output`.
+    streamed: usize,
+    // Where the preamble of the SEQUENCE being encoded is recorded in `Pass::Measure`.
+    preamble: usize,
+    // The components of a SET by their tag, along with where they start when placed in the
+    // order they are encoded.
+    set_output: alloc::collections::BTreeMap<Tag, (usize, BitString)>,
     number_optional_default_fields: usize,
     root_bitfield: (usize, [(bool, Tag); RCL]),
     extension_bitfield: (usize, [bool; ECL]),
     extension_fields: [Option<Vec<u8>>; ECL],
     is_extension_sequence: bool,
     parent_output_length: Option<usize>,
+    // The alignment points within the SET values being encoded, as the position padded from and
+    // the number of padding bits. See `Encoder::place_set_components`.
+    alignments: RefCell<Option<Vec<(usize, usize)>>>,
+}
+
+/// How the encoder writes its output, see [`Encoder::encode_to_sink`]. The
+/// state of the two passes is moved into the encoder of each nested value,
+/// and back once it is encoded.
+#[derive(Default)]
+enum Pass<S> {
+    /// Everything is written into the output buffer, the preamble of a
+    /// `SEQUENCE` being prepended once its components have been encoded.
+    #[default]
+    Buffered,
+    /// Nothing is written, the preambles of `SEQUENCE` values are recorded
+    /// along with the number of bits.
+    Measure(TwoPass),
+    /// Everything is written into the sink, using the preambles recorded by a
+    /// previous [`Pass::Measure`]. `SET`, `SEQUENCE OF` and open type values
+    /// are still encoded into an output buffer first, as their components
+    /// have to be sorted, counted or measured before they are written.
+    Write(TwoPass, S),
+}
+
+impl<S> core::fmt::Debug for Pass<S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Buffered => f.write_str("Buffered"),
+            Self::Measure(state) => f.debug_tuple("Measure").field(state).finish(),
+            Self::Write(state, _) => f.debug_tuple("Write").field(state).finish(),
+        }
+    }
+}
+
+/// The state shared by the encoders of a value encoded in two passes.
+#[derive(Debug, Default)]
+struct TwoPass {
+    written: usize,
+    // The preambles of the `SEQUENCE` values, in the order they are written.
+    preambles: Vec<BitString>,
+    next: usize,
+    // The bits written which don't make up a whole octet yet.
+    pending: BitString,
 }
 
 impl<const RCL: usize, const ECL: usize> Encoder<RCL, ECL> {
     /// Constructs a new encoder from the provided options.
     pub fn new(options: EncoderOptions) -> Self {
+        Self::buffered(options)
+    }
+}
+
+impl Encoder {
+    /// Encodes `value` with the given `options` directly into `sink`,
+    /// returning the number of bytes written.
+    ///
+    /// The preamble of a `SEQUENCE` precedes the components it depends on, so
+    /// the value is encoded in two passes. The first pass only records the
+    /// preambles, which lets the second pass write everything in order. If
+    /// encoding fails, the bytes written into `sink` up to that point are left
+    /// there.
+    ///
+    /// # Errors
+    /// Returns `EncodeError` if `value` cannot be encoded, or if writing into
+    /// `sink` fails.
+    pub fn encode_to_sink<E: Encode, S: Sink>(
+        options: EncoderOptions,
+        value: &E,
+        sink: S,
+    ) -> Result<usize> {
+        let codec = options.current_codec();
+        let mut encoder = Encoder::<0, 0, S>::buffered(options);
+        encoder.pass = Pass::Measure(TwoPass::default());
+        value.encode(&mut encoder)?;
+        let Pass::Measure(state) = core::mem::take(&mut encoder.pass) else {
+            return Err(Error::inconsistent_encoding(codec));
+        };
+
+        let mut encoder = Encoder::<0, 0, S>::buffered(options);
+        encoder.pass = Pass::Write(state, sink);
+        value.encode(&mut encoder)?;
+        let Pass::Write(mut state, mut sink) = core::mem::take(&mut encoder.pass) else {
+            return Err(Error::inconsistent_encoding(codec));
+        };
+
+        if state.next != state.preambles.len() {
+            return Err(Error::inconsistent_encoding(codec));
+        }
+        if !state.pending.is_empty() {
+            Self::force_pad_to_alignment(&mut state.pending);
+            sink.write(state.pending.as_raw_slice(), codec)?;
+            state.written += state.pending.len() / 8;
+        }
+        Ok(state.written)
+    }
+}
+
+impl<const RCL: usize, const ECL: usize, S: Sink> Encoder<RCL, ECL, S> {
+    /// Constructs an encoder writing into its own buffer.
+    fn buffered(options: EncoderOptions) -> Self {
         Self {
             options,
             output: <_>::default(),
+            pass: Pass::Buffered,
+            streamed: 0,
+            preamble: 0,
             set_output: <_>::default(),
             number_optional_default_fields: 0,
             root_bitfield: (0, [(false, Tag::new_private(0)); RCL]),
@@ -94,17 +206,43 @@ impl<const RCL: usize, const ECL: usize> Encoder<RCL, ECL> {
             is_extension_sequence: <_>::default(),
             extension_fields: [(); ECL].map(|_| None),
             parent_output_length: <_>::default(),
+            alignments: <_>::default(),
         }
     }
     fn codec(&self) -> crate::Codec {
         self.options.current_codec()
     }
+
+    /// Hands `pass` over to the encoder of the components of a `SEQUENCE`,
+    /// writing the preamble recorded for it in [`Pass::Write`].
+    fn begin_sequence(&mut self, pass: Pass<S>) -> Result<()> {
+        self.pass = pass;
+        let preamble = match &mut self.pass {
+            Pass::Buffered => return Ok(()),
+            Pass::Measure(state) => {
+                self.preamble = state.preambles.len();
+                state.preambles.push(BitString::new());
+                return Ok(());
+            }
+            Pass::Write(state, _) => {
+                self.preamble = state.next;
+                state.next += 1;
+                state.preambles.get(self.preamble).cloned()
+            }
+        };
+        let preamble = preamble.ok_or_else(|| Error::inconsistent_encoding(self.codec()))?;
+        self.stream(&preamble)?;
+        // The preamble is already accounted for in `output_length`.
+        self.streamed = 0;
+        Ok(())
+    }
+
     fn new_set_encoder<const RL: usize, const EL: usize, C: crate::types::Constructed<RL, EL>>(
         &self,
-    ) -> Encoder<RL, EL> {
+    ) -> Encoder<RL, EL, S> {
         let mut options = self.options;
         options.set_encoding = true;
-        let mut encoder = Encoder::<RL, EL>::new(options);
+        let mut encoder = Encoder::<RL, EL, S>::buffered(options);
         encoder.number_optional_default_fields = C::FIELDS.number_of_optional_and_default_fields();
         encoder.is_extension_sequence = C::IS_EXTENSIBLE;
         encoder.parent_output_length = Some(self.output_length());
@@ -117,14 +255,28 @@ impl<const RCL: usize, const ECL: usize> Encoder<RCL, ECL> {
         C: crate::types::Constructed<RL, EL>,
     >(
         &self,
-    ) -> Encoder<RL, EL> {
-        let mut encoder = Encoder::<RL, EL>::new(self.options.without_set_encoding());
+    ) -> Encoder<RL, EL, S> {
+        let mut encoder = Encoder::<RL, EL, S>::buffered(self.options.without_set_encoding());
         encoder.number_optional_default_fields = C::FIELDS.number_of_optional_and_default_fields();
         encoder.is_extension_sequence = C::IS_EXTENSIBLE;
         encoder.parent_output_length = Some(self.output_length());
         encoder
     }
 
+    /// Hands the alignment points recorded for the enclosing `SET` values to
+    /// `encoder`, which encodes the bits following the ones of `self`.
+    fn lend_alignments<const RL: usize, const EL: usize>(&self, encoder: &Encoder<RL, EL, S>) {
+        encoder.alignments.replace(self.alignments.take());
+    }
+
+    /// Takes back the alignment points lent to `encoder`, along with the ones
+    /// it recorded.
+    fn take_alignments<const RL: usize, const EL: usize>(&self, encoder: &Encoder<RL, EL, S>) {
+        if let Some(alignments) = encoder.alignments.take() {
+            self.alignments.replace(Some(alignments));
+        }
+    }
+
     /// Returns the octet aligned output for the encoder.
     pub fn output(&mut self) -> Vec<u8> {
         let mut output = self.bitstring_output();
@@ -135,12 +287,59 @@ impl<const RCL: usize, const ECL: usize> Encoder<RCL, ECL> {
     /// Returns the bit level output for the encoder.
     fn bitstring_output(&mut self) -> BitString {
         if self.options.set_encoding {
-            self.set_output.values().flatten().collect::<BitString>()
+            let components = core::mem::take(&mut self.set_output);
+            self.place_set_components(components)
         } else {
             core::mem::take(&mut *self.output.as_mut())
         }
     }
 
+    /// Concatenates the components of a `SET` in canonical tag order.
+    ///
+    /// The components are encoded in the order they appear, and so are padded
+    /// as if they were placed in that order. In aligned PER, the padding at
+    /// each alignment point recorded within a component is recomputed from
+    /// where the component is actually placed.
+    fn place_set_components(
+        &self,
+        components: alloc::collections::BTreeMap<Tag, (usize, BitString)>,
+    ) -> BitString {
+        let mut alignments = self.alignments.borrow_mut();
+        let Some(alignments) = alignments.as_mut().filter(|_| self.options.aligned) else {
+            return components
+                .into_values()
+                .flat_map(|(_, bits)| bits)
+                .collect();
+        };
+        let Some(start) = components.values().map(|(start, _)| *start).min() else {
+            return BitString::new();
+        };
+        // The alignment points are recorded in the order they are encoded, so
+        // the ones of the components are the last ones.
+        let recorded =
+            alignments.split_off(alignments.partition_point(|(position, _)| *position < start));
+
+        let mut output = BitString::new();
+        for (component_start, bits) in components.into_values() {
+            let component = component_start..component_start + bits.len();
+            let mut copied = 0;
+            for (position, padding) in recorded
+                .iter()
+                .filter(|(position, _)| component.contains(position))
+            {
+                let offset = position - component_start;
+                output.extend_from_bitslice(&bits[copied..offset]);
+                let position = start + output.len();
+                let padding_needed = (8 - position % 8) % 8;
+                output.extend(core::iter::repeat_n(false, padding_needed));
+                alignments.push((position, padding_needed));
+                copied = offset + padding;
+            }
+            output.extend_from_bitslice(&bits[copied..]);
+        }
+        output
+    }
+
     /// Sets the presence of a `OPTIONAL` or `DEFAULT` field in the bitfield.
     /// The presence is ordered based on the field index.
     fn set_presence(&mut self, tag: Tag, bit: bool) {
@@ -167,7 +366,7 @@ impl<const RCL: usize, const ECL: usize> Encoder<RCL, ECL> {
     }
 
     fn output_length(&self) -> usize {
-        let mut output_length = self.output.len();
+        let mut output_length = self.output.len() + self.streamed;
         output_length += usize::from(self.is_extension_sequence);
         output_length += self.number_optional_default_fields;
         output_length += self.parent_output_length.unwrap_or_default();
@@ -176,7 +375,7 @@ impl<const RCL: usize, const ECL: usize> Encoder<RCL, ECL> {
             output_length += self
                 .set_output
                 .values()
-                .map(bitvec::vec::BitVec::len)
+                .map(|(_, bits)| bits.len())
                 .sum::<usize>();
         }
 
@@ -187,6 +386,9 @@ impl<const RCL: usize, const ECL: usize> Encoder<RCL, ECL> {
         if self.options.aligned {
             let mut output_length = self.output_length();
             output_length += buffer.len();
+            if let Some(alignments) = self.alignments.borrow_mut().as_mut() {
+                alignments.push((output_length, (8 - output_length % 8) % 8));
+            }
             if !output_length.is_multiple_of(8) {
                 for _ in 0..(8 - output_length % 8) {
                     buffer.push(false);
@@ -223,11 +425,11 @@ impl<const RCL: usize, const ECL: usize> Encoder<RCL, ECL> {
     }
 
     #[allow(clippy::too_many_lines)]
-    fn encode_known_multiplier_string<S: StaticPermittedAlphabet>(
+    fn encode_known_multiplier_string<A: StaticPermittedAlphabet>(
         &mut self,
         tag: Tag,
         constraints: &Constraints,
-        value: &S,
+        value: &A,
     ) -> Result<()> {
         use crate::types::constraints::Bounded;
         let mut buffer = BitString::default();
@@ -244,7 +446,7 @@ impl<const RCL: usize, const ECL: usize> Encoder<RCL, ECL> {
             let width = match constraints.permitted_alphabet() {
                 Some(alphabet) => self
                     .character_width(crate::num::log2(alphabet.constraint.len() as i128) as usize),
-                None => self.character_width(S::CHARACTER_SET_WIDTH),
+                None => self.character_width(A::CHARACTER_SET_WIDTH),
             };
 
             match *size.constraint {
@@ -271,9 +473,9 @@ impl<const RCL: usize, const ECL: usize> Encoder<RCL, ECL> {
 
         match (
             constraints.permitted_alphabet(),
-            should_be_indexed(S::CHARACTER_SET_WIDTH as u32, S::CHARACTER_SET),
+            should_be_indexed(A::CHARACTER_SET_WIDTH as u32, A::CHARACTER_SET),
             constraints.permitted_alphabet().map(|alphabet| {
-                S::CHARACTER_SET_WIDTH
+                A::CHARACTER_SET_WIDTH
                     > self.character_width(
                         crate::num::log2(alphabet.constraint.len() as i128) as usize
                     )
@@ -296,7 +498,7 @@ impl<const RCL: usize, const ECL: usize> Encoder<RCL, ECL> {
             }
             (None, true, _) => {
                 let characters =
-                    &DynConstrainedCharacterString::from_bits(value.chars(), S::CHARACTER_SET)
+                    &DynConstrainedCharacterString::from_bits(value.chars(), A::CHARACTER_SET)
                         .map_err(|e| Error::alphabet_constraint_not_satisfied(e, self.codec()))?;
 
                 self.encode_length(
@@ -312,7 +514,7 @@ impl<const RCL: usize, const ECL: usize> Encoder<RCL, ECL> {
             _ => {
                 let char_length = value.len();
                 let octet_aligned_value = self.options.aligned.then(|| {
-                    if S::CHARACTER_SET_WIDTH <= self.character_width(S::CHARACTER_SET_WIDTH) {
+                    if A::CHARACTER_SET_WIDTH <= self.character_width(A::CHARACTER_SET_WIDTH) {
                         value.to_octet_aligned_string()
                     } else {
                         value.to_octet_aligned_index_string()
@@ -333,14 +535,14 @@ impl<const RCL: usize, const ECL: usize> Encoder<RCL, ECL> {
                     |range| {
                         Ok(match octet_aligned_value {
                             Some(value) => types::BitString::from_slice(&value[range]),
-                            None => value[S::char_range_to_bit_range(range)].to_bitvec(),
+                            None => value[A::char_range_to_bit_range(range)].to_bitvec(),
                         })
                     },
                 )?;
             }
         };
 
-        self.extend(tag, &buffer);
+        self.extend(tag, &buffer)?;
         Ok(())
     }
 
@@ -365,7 +567,7 @@ impl<const RCL: usize, const ECL: usize> Encoder<RCL, ECL> {
     >(
         &mut self,
         tag: Tag,
-        mut encoder: Encoder<RL, EL>,
+        mut encoder: Encoder<RL, EL, S>,
     ) -> Result<()> {
         let mut buffer = BitString::with_capacity(core::mem::size_of::<C>());
         let mut extensions_present = false;
@@ -390,13 +592,26 @@ impl<const RCL: usize, const ECL: usize> Encoder<RCL, ECL> {
                 buffer.push(*bit);
             }
         }
-        if option_bitfield[..needed].iter().any(|(bit, _tag)| *bit) || required_present {
+        if !matches!(encoder.pass, Pass::Buffered) {
+            // The preamble and the components have already been written through the pass.
+            match &mut encoder.pass {
+                Pass::Measure(state) => state.preambles[encoder.preamble] = buffer.clone(),
+                Pass::Write(state, _) if state.preambles[encoder.preamble] != buffer => {
+                    return Err(Error::inconsistent_encoding(self.codec()));
+                }
+                _ => {}
+            }
+            self.pass = core::mem::take(&mut encoder.pass);
+            self.streamed += buffer.len() + encoder.streamed;
+            buffer.clear();
+        } else if option_bitfield[..needed].iter().any(|(bit, _tag)| *bit) || required_present {
             let mut out = encoder.bitstring_output();
             buffer.append(&mut out);
         }
+        self.take_alignments(&encoder);
 
         if !C::IS_EXTENSIBLE || !extensions_present {
-            self.extend(tag, &buffer);
+            self.extend(tag, &buffer)?;
             return Ok(());
         }
         self.encode_normally_small_length(EL, &mut buffer)?;
@@ -409,7 +624,209 @@ impl<const RCL: usize, const ECL: usize> Encoder<RCL, ECL> {
                 Ok(BitString::from_slice(&field[range]))
             })?;
         }
-        self.extend(tag, &buffer);
+        self.extend(tag, &buffer)?;
+
+        Ok(())
+    }
+
+    /// Encodes a `SEQUENCE` or `SET` value the same way as
+    /// [`Self::encode_constructed`], with the fields known only at runtime.
+    fn encode_constructed_value(
+        &mut self,
+        descriptor: &TypeDescriptor,
+        value: &Value,
+    ) -> Result<()> {
+        let components = value.components(descriptor, self.codec())?;
+        let mut options = self.options;
+        options.set_encoding = matches!(descriptor.kind, TypeKind::Set { .. });
+        let mut encoder = Encoder::<0, 0, S>::buffered(options);
+        encoder.number_optional_default_fields = components
+            .fields
+            .iter()
+            .filter(|field| field.presence.is_optional_or_default())
+            .count();
+        encoder.is_extension_sequence = components.extended_fields.is_some();
+        encoder.parent_output_length = Some(self.output_length());
+
+        let mut option_bitfield = Vec::with_capacity(encoder.number_optional_default_fields);
+        let mut root: Vec<_> = components.fields.iter().zip(&components.root).collect();
+        if options.set_encoding {
+            // Each component is padded from where it is placed in the encoding.
+            root.sort_by_key(|(field, _)| field.ty.smallest_tag());
+        }
+        for (field, value) in root {
+            if field.presence.is_optional_or_default() {
+                option_bitfield.push((value.is_some(), field.ty.tag));
+            }
+            if let Some(value) = value {
+                encoder.encode_value(field.ty, value)?;
+            }
+        }
+
+        let mut buffer = BitString::new();
+        let extensions_present = components.has_extensions();
+        if components.extended_fields.is_some() {
+            buffer.push(extensions_present);
+        }
+        if options.set_encoding {
+            // In set encoding, tags must be unique so we just sort them to be in canonical order for preamble
+            option_bitfield.sort_by_key(|(_, tag)| *tag);
+        }
+        buffer.extend(option_bitfield.iter().map(|(bit, _tag)| *bit));
+        let required_present = components
+            .fields
+            .iter()
+            .any(|field| !field.presence.is_optional_or_default());
+        if option_bitfield.iter().any(|(bit, _tag)| *bit) || required_present {
+            buffer.append(&mut encoder.bitstring_output());
+        }
+
+        if !extensions_present {
+            self.extend(descriptor.tag, &buffer)?;
+            return Ok(());
+        }
+        self.encode_normally_small_length(components.extensions.len(), &mut buffer)?;
+        buffer.extend(components.extensions.iter().map(Option::is_some));
+
+        for (field, value) in components.present_extensions() {
+            let mut encoder = Encoder::<0, 0, S>::buffered(self.options.without_set_encoding());
+            encoder.encode_value(field.ty, value)?;
+            let field = encoder.output();
+            self.encode_length(&mut buffer, field.len(), <_>::default(), |range| {
+                Ok(BitString::from_slice(&field[range]))
+            })?;
+        }
+        self.extend(descriptor.tag, &buffer)?;
+
+        Ok(())
+    }
+
+    /// Encodes a `CHOICE` value the same way as `encode_choice`, with the
+    /// alternatives known only at runtime.
+    fn encode_choice_value(&mut self, descriptor: &TypeDescriptor, value: &Value) -> Result<()> {
+        let TypeKind::Choice {
+            variants,
+            extended_variants,
+        } = descriptor.kind
+        else {
+            unreachable!("only called for `CHOICE` descriptors")
+        };
+        let (index, is_extension, variant, value) = value.chosen(descriptor, self.codec())?;
+
+        let mut buffer = BitString::new();
+        if extended_variants.is_some() {
+            buffer.push(is_extension);
+        }
+
+        let bounds = if is_extension {
+            Some(None)
+        } else if variants.len() == 1 {
+            None
+        } else {
+            Some(Some(variants.len()))
+        };
+
+        let mut choice_encoder = Encoder::<0, 0, S>::buffered(self.options.without_set_encoding());
+        if let Some(None) = bounds {
+            // The value of an extension alternative is an open type, which is
+            // encoded on its own.
+            choice_encoder.encode_value(variant.ty, value)?;
+            self.encode_normally_small_integer(index, &mut buffer)?;
+            let mut output = choice_encoder.output();
+
+            if output.is_empty() {
+                output.push(0);
+            }
+            self.encode_octet_string_into_buffer(Constraints::default(), &output, &mut buffer)?;
+            return self.extend(variant.ty.tag, &buffer);
+        }
+
+        if bounds.is_some() {
+            let variance = variants.len() + extended_variants.map_or(0, <[_]>::len);
+            self.encode_integer_into_buffer::<usize>(
+                super::variance_constraint(variance),
+                &index,
+                &mut buffer,
+            )?;
+        }
+        // The value of a root alternative is aligned from where it follows its index.
+        choice_encoder.parent_output_length = Some(self.output_length() + buffer.len());
+        choice_encoder.encode_value(variant.ty, value)?;
+        buffer.extend(choice_encoder.output);
+
+        self.extend(variant.ty.tag, &buffer)
+    }
+
+    /// Encodes an `ENUMERATED` value the same way as `encode_enumerated`,
+    /// with the variants known only at runtime.
+    fn encode_enumerated_value(
+        &mut self,
+        descriptor: &TypeDescriptor,
+        value: &Value,
+    ) -> Result<()> {
+        let TypeKind::Enumerated {
+            variants,
+            extended_variants,
+        } = descriptor.kind
+        else {
+            unreachable!("only called for `ENUMERATED` descriptors")
+        };
+        let (index, is_extension, _) = value.enumerated(descriptor, self.codec())?;
+
+        let mut buffer = BitString::default();
+        if extended_variants.is_some() {
+            buffer.push(is_extension);
+        }
+
+        if is_extension {
+            self.encode_normally_small_integer(index, &mut buffer)?;
+        } else {
+            self.encode_non_negative_binary_integer(
+                &mut buffer,
+                variants.len() as i128,
+                &index.to_be_bytes(),
+            );
+        }
+
+        self.extend(descriptor.tag, &buffer)?;
+        Ok(())
+    }
+
+    /// Encodes a `SEQUENCE OF` or `SET OF` value the same way as
+    /// `encode_sequence_of`, with the element type known only at runtime.
+    fn encode_sequence_of_value(
+        &mut self,
+        descriptor: &TypeDescriptor,
+        element: &TypeDescriptor,
+        value: &Value,
+    ) -> Result<()> {
+        let values = value.elements(descriptor, self.codec())?;
+        let constraints = descriptor.constraints.get();
+        let mut buffer = BitString::default();
+        let options = self.options;
+
+        self.encode_extensible_bit(&constraints, &mut buffer, || {
+            constraints.size().is_some_and(|size_constraint| {
+                size_constraint.extensible.is_some()
+                    && size_constraint.constraint.contains(&values.len())
+            })
+        });
+        let position = self.sequence_of_position(&buffer, values.len(), constraints.size())?;
+
+        self.encode_length(&mut buffer, values.len(), constraints.size(), |range| {
+            let mut buffer = BitString::default();
+            position.set(Self::fragment_position(position.get(), &range));
+            for value in &values[range] {
+                let mut encoder = Encoder::<0, 0, S>::buffered(options);
+                encoder.parent_output_length = Some(position.get() + buffer.len());
+                encoder.encode_value(element, value)?;
+                buffer.extend(encoder.bitstring_output());
+            }
+            position.set(position.get() + buffer.len());
+            Ok(buffer)
+        })?;
+
+        self.extend(descriptor.tag, &buffer)?;
 
         Ok(())
     }
@@ -505,6 +922,40 @@ impl<const RCL: usize, const ECL: usize> Encoder<RCL, ECL> {
         self.encode_string_length(buffer, false, length, constraints, encode_fn)
     }
 
+    /// Returns the position in the output at which the first element of a
+    /// `SEQUENCE OF` starts, given the `buffer` holding its preamble.
+    ///
+    /// Elements are encoded by their own encoders, which need to know where
+    /// they start to align against the whole output. Only the position
+    /// modulo eight matters, so a dry run of the length determinant with
+    /// empty fragments gives it even when the elements are fragmented.
+    fn sequence_of_position(
+        &self,
+        buffer: &BitString,
+        length: usize,
+        constraints: Option<&Extensible<constraints::Size>>,
+    ) -> Result<Cell<usize>> {
+        let mut preamble = buffer.clone();
+        // The alignment points of the dry run aren't part of the encoding.
+        let alignments = self.alignments.take();
+        let result =
+            self.encode_length(&mut preamble, length, constraints, |_| Ok(BitString::new()));
+        self.alignments.replace(alignments);
+        result?;
+        Ok(Cell::new(self.output_length() + preamble.len()))
+    }
+
+    /// Returns the position at which the elements in `range` start, given
+    /// the position at which the previous fragment ended. Fragment headers
+    /// are whole octets, and the last fragment is preceded by padding.
+    fn fragment_position(end: usize, range: &core::ops::Range<usize>) -> usize {
+        if range.start > 0 && range.len() < SIXTEEN_K.into() {
+            0
+        } else {
+            end
+        }
+    }
+
     fn encode_unconstrained_length(
         &self,
         buffer: &mut BitString,
@@ -517,11 +968,11 @@ impl<const RCL: usize, const ECL: usize> Encoder<RCL, ECL> {
         self.pad_to_alignment(&mut *buffer);
         if length <= 127 {
             buffer.extend((length as u8).to_be_bytes());
-            buffer.extend((encode_fn)(0..length)?);
+            buffer.extend((encode_fn)(min..min + length)?);
         } else if length < SIXTEEN_K.into() {
             const SIXTEENTH_BIT: u16 = 0x8000;
             buffer.extend((SIXTEENTH_BIT | length as u16).to_be_bytes());
-            buffer.extend((encode_fn)(0..length)?);
+            buffer.extend((encode_fn)(min..min + length)?);
         } else {
             loop {
                 // Hack to get around no exclusive syntax.
@@ -565,10 +1016,10 @@ impl<const RCL: usize, const ECL: usize> Encoder<RCL, ECL> {
         Ok(())
     }
 
-    fn extend<'input>(&mut self, tag: Tag, input: impl Into<Input<'input>>) {
+    fn extend<'input>(&mut self, tag: Tag, input: impl Into<Input<'input>>) -> Result<()> {
         use bitvec::field::BitField;
         let mut set_buffer = <_>::default();
-        let buffer = if self.options.set_encoding {
+        let buffer = if self.options.set_encoding || !matches!(self.pass, Pass::Buffered) {
             &mut set_buffer
         } else {
             &mut self.output
@@ -589,8 +1040,37 @@ impl<const RCL: usize, const ECL: usize> Encoder<RCL, ECL> {
             }
         }
         if self.options.set_encoding {
-            self.set_output.insert(tag, set_buffer);
+            let start = self.output_length();
+            self.set_output.insert(tag, (start, set_buffer));
+        } else if !matches!(self.pass, Pass::Buffered) {
+            self.stream(&set_buffer)?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes `bits` through the pass, flushing every whole octet into the
+    /// sink in [`Pass::Write`].
+    fn stream(&mut self, bits: &BitStr) -> Result<()> {
+        let codec = self.codec();
+        match &mut self.pass {
+            Pass::Buffered => {
+                self.output.extend_from_bitslice(bits);
+                return Ok(());
+            }
+            Pass::Measure(_) => {}
+            Pass::Write(state, sink) => {
+                state.pending.extend_from_bitslice(bits);
+                let octets = state.pending.len() / 8;
+                if octets > 0 {
+                    sink.write(&state.pending.as_raw_slice()[..octets], codec)?;
+                    state.written += octets;
+                    state.pending.drain(..octets * 8);
+                }
+            }
         }
+        self.streamed += bits.len();
+        Ok(())
     }
 
     fn encode_octet_string_into_buffer(
@@ -785,10 +1265,10 @@ impl<const RCL: usize, const ECL: usize> Encoder<RCL, ECL> {
     }
 }
 
-impl<const RFC: usize, const EFC: usize> crate::Encoder<'_> for Encoder<RFC, EFC> {
+impl<const RFC: usize, const EFC: usize, S: Sink> crate::Encoder<'_> for Encoder<RFC, EFC, S> {
     type Ok = ();
     type Error = Error;
-    type AnyEncoder<'this, const R: usize, const E: usize> = Encoder<R, E>;
+    type AnyEncoder<'this, const R: usize, const E: usize> = Encoder<R, E, S>;
 
     fn codec(&self) -> crate::Codec {
         Self::codec(self)
@@ -852,7 +1332,7 @@ impl<const RFC: usize, const EFC: usize> crate::Encoder<'_> for Encoder<RFC, EFC
             )?;
         }
 
-        self.extend(tag, &buffer);
+        self.extend(tag, &buffer)?;
         Ok(())
     }
 
@@ -862,7 +1342,7 @@ impl<const RFC: usize, const EFC: usize> crate::Encoder<'_> for Encoder<RFC, EFC
         value: bool,
         _: Identifier,
     ) -> Result<Self::Ok, Self::Error> {
-        self.extend(tag, value);
+        self.extend(tag, value)?;
         Ok(())
     }
 
@@ -900,7 +1380,7 @@ impl<const RFC: usize, const EFC: usize> crate::Encoder<'_> for Encoder<RFC, EFC
             );
         }
 
-        self.extend(tag, &buffer);
+        self.extend(tag, &buffer)?;
         Ok(())
     }
 
@@ -913,18 +1393,22 @@ impl<const RFC: usize, const EFC: usize> crate::Encoder<'_> for Encoder<RFC, EFC
     ) -> Result<Self::Ok, Self::Error> {
         let mut buffer = BitString::new();
         self.encode_integer_into_buffer(constraints, value, &mut buffer)?;
-        self.extend(tag, &buffer);
+        self.extend(tag, &buffer)?;
         Ok(())
     }
 
     fn encode_real<R: types::RealType>(
         &mut self,
-        _: Tag,
+        tag: Tag,
         _: Constraints,
-        _: &R,
+        value: &R,
         _: Identifier,
     ) -> Result<Self::Ok, Self::Error> {
-        Err(Error::real_not_supported(self.codec()))
+        // X.691 §15, the contents octets of the CER encoding with an
+        // unconstrained length determinant.
+        let cer = crate::ber::enc::Encoder::real_to_canonical_bytes(value)
+            .ok_or_else(|| Error::real_not_supported(self.codec()))?;
+        self.encode_octet_string(tag, Constraints::default(), &cer, Identifier::EMPTY)
     }
 
     fn encode_null(&mut self, _tag: Tag, _: Identifier) -> Result<Self::Ok, Self::Error> {
@@ -942,6 +1426,68 @@ impl<const RFC: usize, const EFC: usize> crate::Encoder<'_> for Encoder<RFC, EFC
         self.encode_octet_string(tag, Constraints::default(), &der, Identifier::EMPTY)
     }
 
+    fn encode_encoded_oid(
+        &mut self,
+        tag: Tag,
+        value: &types::EncodedOid,
+        _: Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_octet_string(
+            tag,
+            Constraints::default(),
+            value.as_bytes(),
+            Identifier::EMPTY,
+        )
+    }
+
+    fn encode_relative_oid(
+        &mut self,
+        tag: Tag,
+        value: &[u32],
+        _: Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        let mut encoder = crate::der::enc::Encoder::new(crate::der::enc::EncoderOptions::der());
+        let der = encoder.relative_oid_as_bytes(value)?;
+        self.encode_octet_string(tag, Constraints::default(), &der, Identifier::EMPTY)
+    }
+
+    fn encode_oid_iri(
+        &mut self,
+        tag: Tag,
+        value: &types::OidIri,
+        _: Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_utf8_string(
+            tag,
+            Constraints::default(),
+            value.as_str(),
+            Identifier::EMPTY,
+        )
+    }
+
+    fn encode_relative_oid_iri(
+        &mut self,
+        tag: Tag,
+        value: &types::RelativeOidIri,
+        _: Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.encode_utf8_string(
+            tag,
+            Constraints::default(),
+            value.as_str(),
+            Identifier::EMPTY,
+        )
+    }
+
+    fn encode_external(
+        &mut self,
+        tag: Tag,
+        value: &types::External,
+        _: Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        value.encode_legacy(self, tag)
+    }
+
     fn encode_octet_string(
         &mut self,
         tag: Tag,
@@ -951,7 +1497,7 @@ impl<const RFC: usize, const EFC: usize> crate::Encoder<'_> for Encoder<RFC, EFC
     ) -> Result<Self::Ok, Self::Error> {
         let mut buffer = BitString::default();
         self.encode_octet_string_into_buffer(constraints, value, &mut buffer)?;
-        self.extend(tag, &buffer);
+        self.extend(tag, &buffer)?;
         Ok(())
     }
 
@@ -1018,11 +1564,19 @@ impl<const RFC: usize, const EFC: usize> crate::Encoder<'_> for Encoder<RFC, EFC
     fn encode_teletex_string(
         &mut self,
         tag: Tag,
-        constraints: Constraints,
+        _: Constraints,
         value: &types::TeletexString,
         _: Identifier,
     ) -> Result<Self::Ok, Self::Error> {
-        self.encode_known_multiplier_string(tag, &constraints, value)
+        // X.691 §30.6, TeletexString is not a known-multiplier character string
+        // type, so it is encoded as the BER content octets without PER-visible
+        // constraints.
+        self.encode_octet_string(
+            tag,
+            Constraints::default(),
+            &value.to_bytes(),
+            Identifier::EMPTY,
+        )
     }
 
     fn encode_bmp_string(
@@ -1092,6 +1646,48 @@ impl<const RFC: usize, const EFC: usize> crate::Encoder<'_> for Encoder<RFC, EFC
         )
     }
 
+    fn encode_time(
+        &mut self,
+        tag: Tag,
+        value: &types::IsoTime,
+        _: Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        // Values with arbitrary property settings have no structured encoding,
+        // and are encoded as their character string.
+        let string = types::VisibleString::from_iso646_bytes(value.as_str().as_bytes())
+            .map_err(|error| Error::alphabet_constraint_not_satisfied(error, self.codec()))?;
+        self.encode_known_multiplier_string(tag, &Constraints::default(), &string)
+    }
+
+    fn encode_time_of_day(
+        &mut self,
+        _: Tag,
+        value: &types::TimeOfDay,
+        _: Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        types::time::whole_seconds(value, self.codec())?;
+        super::time::HmsEncoding::from(value).encode(self)
+    }
+
+    fn encode_date_time(
+        &mut self,
+        _: Tag,
+        value: &types::DateTime,
+        _: Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        types::time::whole_seconds(&value.time(), self.codec())?;
+        super::time::DateTimeEncoding::from(value).encode(self)
+    }
+
+    fn encode_duration(
+        &mut self,
+        _: Tag,
+        value: &types::IsoDuration,
+        _: Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        super::time::DurationIntervalEncoding::from(value).encode(self)
+    }
+
     fn encode_sequence_of<E: Encode>(
         &mut self,
         tag: Tag,
@@ -1108,24 +1704,28 @@ impl<const RFC: usize, const EFC: usize> crate::Encoder<'_> for Encoder<RFC, EFC
                     && size_constraint.constraint.contains(&values.len())
             })
         });
-        let extension_bits = buffer.clone();
+        let position = self.sequence_of_position(&buffer, values.len(), constraints.size())?;
 
         self.encode_length(&mut buffer, values.len(), constraints.size(), |range| {
             let mut buffer = BitString::default();
-            let mut first_round = true;
+            position.set(Self::fragment_position(position.get(), &range));
+            // Fragments after the first are positioned from an octet boundary.
+            let is_first_fragment = range.start == 0;
             for value in &values[range] {
-                let mut encoder = Self::new(options);
-                if first_round {
-                    encoder.parent_output_length = Some(extension_bits.len());
-                    first_round = false;
+                let mut encoder = Self::buffered(options);
+                encoder.parent_output_length = Some(position.get() + buffer.len());
+                if is_first_fragment {
+                    self.lend_alignments(&encoder);
                 }
                 E::encode(value, &mut encoder)?;
+                self.take_alignments(&encoder);
                 buffer.extend(encoder.bitstring_output());
             }
+            position.set(position.get() + buffer.len());
             Ok(buffer)
         })?;
 
-        self.extend(tag, &buffer);
+        self.extend(tag, &buffer)?;
 
         Ok(())
     }
@@ -1193,6 +1793,20 @@ impl<const RFC: usize, const EFC: usize> crate::Encoder<'_> for Encoder<RFC, EFC
         Ok(())
     }
 
+    fn encode_default_with_explicit_prefix<E: Encode + PartialEq>(
+        &mut self,
+        tag: Tag,
+        value: &E,
+        default: impl FnOnce() -> E,
+        identifier: Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        if *value == (default)() {
+            return self.encode_none_with_tag(tag, identifier);
+        }
+        self.set_presence(tag, true);
+        self.encode_explicit_prefix(tag, value, identifier)
+    }
+
     fn encode_sequence<'b, const RL: usize, const EL: usize, C, F>(
         &'b mut self,
         tag: Tag,
@@ -1204,6 +1818,8 @@ impl<const RFC: usize, const EFC: usize> crate::Encoder<'_> for Encoder<RFC, EFC
         F: FnOnce(&mut Self::AnyEncoder<'b, RL, EL>) -> Result<(), Self::Error>,
     {
         let mut encoder = self.new_sequence_encoder::<RL, EL, C>();
+        self.lend_alignments(&encoder);
+        encoder.begin_sequence(core::mem::take(&mut self.pass))?;
         (encoder_scope)(&mut encoder)?;
         self.encode_constructed::<RL, EL, C>(tag, encoder)
     }
@@ -1219,10 +1835,22 @@ impl<const RFC: usize, const EFC: usize> crate::Encoder<'_> for Encoder<RFC, EFC
         F: FnOnce(&mut Self::AnyEncoder<'b, RL, EL>) -> Result<(), Self::Error>,
     {
         let mut set = self.new_set_encoder::<RL, EL, C>();
+        // The outermost `SET` value keeps the alignment points of its
+        // components, and of the `SET` values within them.
+        let outermost = self.options.aligned && self.alignments.borrow().is_none();
+        if outermost {
+            set.alignments.replace(Some(Vec::new()));
+        } else {
+            self.lend_alignments(&set);
+        }
 
         (encoder_scope)(&mut set)?;
 
-        self.encode_constructed::<RL, EL, C>(tag, set)
+        self.encode_constructed::<RL, EL, C>(tag, set)?;
+        if outermost {
+            self.alignments.take();
+        }
+        Ok(())
     }
 
     fn encode_choice<E: Encode + crate::types::Choice>(
@@ -1260,46 +1888,42 @@ impl<const RFC: usize, const EFC: usize> crate::Encoder<'_> for Encoder<RFC, EFC
             Some(None)
         };
 
-        let mut choice_encoder = Self::new(self.options.without_set_encoding());
-        // Extensibility and index encoding size must be noted for byte alignment
-        let mut choice_bits_len = 0;
-        if E::EXTENDED_VARIANTS.is_some() && self.options.aligned {
-            choice_bits_len += 1;
-        }
-        choice_bits_len += if let Some(Some(variance)) = bounds {
-            crate::num::log2(variance as i128) as usize
-        } else {
-            0
-        };
-
-        choice_encoder.parent_output_length = Some(choice_bits_len);
-        let _tag = (encode_fn)(&mut choice_encoder)?;
-
-        match (index, bounds) {
-            (index, Some(Some(_))) => {
-                self.encode_integer_into_buffer::<usize>(
-                    E::VARIANCE_CONSTRAINT,
-                    &index,
-                    &mut buffer,
-                )?;
+        let mut choice_encoder = Self::buffered(self.options.without_set_encoding());
+        if let Some(None) = bounds {
+            // The value of an extension alternative is an open type, which is
+            // encoded on its own.
+            (encode_fn)(&mut choice_encoder)?;
+            self.encode_normally_small_integer(index, &mut buffer)?;
+            let mut output = choice_encoder.output();
 
-                buffer.extend(choice_encoder.output);
+            if output.is_empty() {
+                output.push(0);
             }
-            (index, Some(None)) => {
-                self.encode_normally_small_integer(index, &mut buffer)?;
-                let mut output = choice_encoder.output();
+            self.encode_octet_string_into_buffer(Constraints::default(), &output, &mut buffer)?;
+            return self.extend(tag, &buffer);
+        }
 
-                if output.is_empty() {
-                    output.push(0);
-                }
-                self.encode_octet_string_into_buffer(Constraints::default(), &output, &mut buffer)?;
-            }
-            (_, None) => {
-                buffer.extend(choice_encoder.output);
-            }
+        if bounds.is_some() {
+            self.encode_integer_into_buffer::<usize>(E::VARIANCE_CONSTRAINT, &index, &mut buffer)?;
+        }
+        // The value of a root alternative is aligned from where it follows its index.
+        choice_encoder.parent_output_length = Some(self.output_length() + buffer.len());
+        if let Pass::Buffered = self.pass {
+            self.lend_alignments(&choice_encoder);
+            (encode_fn)(&mut choice_encoder)?;
+            self.take_alignments(&choice_encoder);
+            buffer.extend(choice_encoder.output);
+            return self.extend(tag, &buffer);
         }
 
-        self.extend(tag, &buffer);
+        // Otherwise the value is written through the pass right after its index.
+        self.extend(tag, &buffer)?;
+        choice_encoder.pass = core::mem::take(&mut self.pass);
+        self.lend_alignments(&choice_encoder);
+        (encode_fn)(&mut choice_encoder)?;
+        self.take_alignments(&choice_encoder);
+        self.pass = core::mem::take(&mut choice_encoder.pass);
+        self.streamed += choice_encoder.streamed;
         Ok(())
     }
 
@@ -1310,7 +1934,7 @@ impl<const RFC: usize, const EFC: usize> crate::Encoder<'_> for Encoder<RFC, EFC
         value: E,
         _: Identifier,
     ) -> Result<Self::Ok, Self::Error> {
-        let mut encoder = Self::new(self.options.without_set_encoding());
+        let mut encoder = Self::buffered(self.options.without_set_encoding());
         if value.is_present() {
             E::encode_with_tag_and_constraints(
                 &value,
@@ -1351,6 +1975,29 @@ impl<const RFC: usize, const EFC: usize> crate::Encoder<'_> for Encoder<RFC, EFC
         self.set_extension_presence(true);
         Ok(())
     }
+
+    fn encode_value(
+        &mut self,
+        descriptor: &TypeDescriptor,
+        value: &Value,
+    ) -> Result<Self::Ok, Self::Error> {
+        let descriptor = &descriptor.resolve();
+        match descriptor.kind {
+            TypeKind::Enumerated { .. } => self.encode_enumerated_value(descriptor, value),
+            TypeKind::Sequence { .. } | TypeKind::Set { .. } => {
+                self.encode_constructed_value(descriptor, value)
+            }
+            TypeKind::Choice { .. } => self.encode_choice_value(descriptor, value),
+            TypeKind::SequenceOf { element } | TypeKind::SetOf { element } => {
+                self.encode_sequence_of_value(descriptor, element, value)
+            }
+            TypeKind::Explicit { inner } if inner.is_choice() => self.encode_value(inner, value),
+            TypeKind::Explicit { inner } => {
+                self.encode_value(&inner.with_tag(descriptor.tag), value)
+            }
+            _ => value.encode_primitive(self, descriptor),
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -1395,8 +2042,6 @@ impl From<u8> for Input<'_> {
 mod tests {
     use super::*;
 
-    use crate::Encoder as _;
-
     #[derive(crate::AsnType, Default, crate::Encode, Clone, Copy)]
     #[rasn(crate_root = "crate")]
     struct Byte {
@@ -1702,4 +2347,91 @@ mod tests {
         assert!(buffer[SIXTY_FOUR_K as usize + THIRTY_TWO_K as usize + 2..]
             .starts_with(&[0b10000010, 0b10111000]));
     }
+
+    #[test]
+    fn two_pass() {
+        use crate as rasn;
+        use rasn::prelude::*;
+
+        #[derive(AsnType, Clone, Debug, Encode, PartialEq, Eq, Hash)]
+        #[rasn(automatic_tags)]
+        #[non_exhaustive]
+        struct Inner {
+            id: Integer,
+            name: Option<Utf8String>,
+            flag: bool,
+            #[rasn(extension_addition)]
+            data: Option<OctetString>,
+        }
+
+        #[derive(AsnType, Clone, Debug, Encode, PartialEq)]
+        #[rasn(set, automatic_tags)]
+        struct Attributes {
+            b: bool,
+            a: Vec<Inner>,
+        }
+
+        #[derive(AsnType, Clone, Debug, Encode, PartialEq)]
+        #[rasn(choice, automatic_tags)]
+        #[non_exhaustive]
+        enum Choice {
+            Inner(Inner),
+            Flag(bool),
+            #[rasn(extension_addition)]
+            Nested(Vec<Inner>),
+        }
+
+        #[derive(AsnType, Clone, Debug, Encode, PartialEq)]
+        #[rasn(automatic_tags)]
+        struct Outer {
+            first: Option<Inner>,
+            attributes: Attributes,
+            choices: Vec<Choice>,
+            last: Choice,
+        }
+
+        let inner = |id: i32, len: usize| Inner {
+            id: id.into(),
+            name: (id % 2 == 0).then(|| "inner".into()),
+            flag: id % 3 == 0,
+            data: (len > 0).then(|| alloc::vec![id as u8; len].into()),
+        };
+        let value = Outer {
+            first: Some(inner(1, 3)),
+            attributes: Attributes {
+                b: true,
+                a: alloc::vec![inner(2, 0), inner(3, 300)],
+            },
+            choices: alloc::vec![
+                Choice::Nested(alloc::vec![inner(4, 20_000)]),
+                Choice::Flag(true),
+                Choice::Inner(inner(5, 0)),
+            ],
+            last: Choice::Inner(inner(6, 7)),
+        };
+
+        for options in [EncoderOptions::aligned(), EncoderOptions::unaligned()] {
+            let mut encoder = super::Encoder::<0>::new(options);
+            value.encode(&mut encoder).unwrap();
+            let expected = encoder.output();
+
+            let mut buffer = alloc::vec![0; expected.len()];
+            let sink = crate::enc::SliceSink::new(&mut buffer);
+            assert_eq!(
+                expected.len(),
+                super::Encoder::encode_to_sink(options, &value, sink).unwrap()
+            );
+            assert_eq!(expected, buffer);
+
+            // Writing stops at the first write which doesn't fit.
+            let mut buffer = alloc::vec![0; expected.len() - 1];
+            let sink = crate::enc::SliceSink::new(&mut buffer);
+            let error = super::Encoder::encode_to_sink(options, &value, sink).unwrap_err();
+            assert!(matches!(
+                *error.kind,
+                crate::error::EncodeErrorKind::BufferTooSmall { needed, available }
+                    if needed > available && available == expected.len() - 1
+            ));
+        }
+    }
 }
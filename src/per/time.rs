@@ -0,0 +1,221 @@
+//! The types of X.691 clause 32, which replace values of the useful time
+//! types in PER.
+
+use chrono::{Datelike, Timelike};
+
+use crate::types::{self, Integer};
+use crate::{AsnType, Decode, Decoder as _, Encode};
+
+/// `YEAR-ENCODING`
+#[derive(AsnType, Decode, Encode)]
+#[rasn(crate_root = "crate")]
+#[rasn(choice, automatic_tags)]
+enum YearEncoding {
+    #[rasn(value("2005..=2020"))]
+    Immediate(i32),
+    #[rasn(value("2021..=2276"))]
+    NearFuture(i32),
+    #[rasn(value("1749..=2004"))]
+    NearPast(i32),
+    Remainder(Integer),
+}
+
+/// `YMD-ENCODING`, the encoding of `DATE`.
+#[derive(AsnType, Decode, Encode)]
+#[rasn(crate_root = "crate")]
+#[rasn(automatic_tags)]
+struct YmdEncoding {
+    year: YearEncoding,
+    #[rasn(value("1..=12"))]
+    month: u8,
+    #[rasn(value("1..=31"))]
+    day: u8,
+}
+
+/// `HMS-ENCODING`, the encoding of `TIME-OF-DAY`.
+#[derive(AsnType, Decode, Encode)]
+#[rasn(crate_root = "crate")]
+#[rasn(automatic_tags)]
+pub(super) struct HmsEncoding {
+    #[rasn(value("0..=24"))]
+    hours: u8,
+    #[rasn(value("0..=59"))]
+    minutes: u8,
+    #[rasn(value("0..=60"))]
+    seconds: u8,
+}
+
+/// `DATE-TIME-ENCODING`, the encoding of `DATE-TIME`.
+#[derive(AsnType, Decode, Encode)]
+#[rasn(crate_root = "crate")]
+#[rasn(automatic_tags)]
+pub(super) struct DateTimeEncoding {
+    date: YmdEncoding,
+    time: HmsEncoding,
+}
+
+/// `DURATION-INTERVAL-ENCODING`, the encoding of `DURATION`.
+#[derive(AsnType, Decode, Encode)]
+#[rasn(crate_root = "crate")]
+#[rasn(automatic_tags)]
+pub(super) struct DurationIntervalEncoding {
+    #[rasn(value("0.."))]
+    years: Option<Integer>,
+    #[rasn(value("0.."))]
+    months: Option<Integer>,
+    #[rasn(value("0.."))]
+    weeks: Option<Integer>,
+    #[rasn(value("0.."))]
+    days: Option<Integer>,
+    #[rasn(value("0.."))]
+    hours: Option<Integer>,
+    #[rasn(value("0.."))]
+    minutes: Option<Integer>,
+    #[rasn(value("0.."))]
+    seconds: Option<Integer>,
+    fractional_part: Option<FractionalPart>,
+}
+
+#[derive(AsnType, Decode, Encode)]
+#[rasn(crate_root = "crate")]
+#[rasn(automatic_tags)]
+struct FractionalPart {
+    #[rasn(value("1.."))]
+    number_of_digits: Integer,
+    #[rasn(value("0.."))]
+    fractional_value: Integer,
+}
+
+impl From<i32> for YearEncoding {
+    fn from(year: i32) -> Self {
+        match year {
+            2005..=2020 => Self::Immediate(year),
+            2021..=2276 => Self::NearFuture(year),
+            1749..=2004 => Self::NearPast(year),
+            _ => Self::Remainder(year.into()),
+        }
+    }
+}
+
+impl YearEncoding {
+    fn year(&self) -> Option<i32> {
+        match self {
+            Self::Immediate(year) | Self::NearFuture(year) | Self::NearPast(year) => Some(*year),
+            Self::Remainder(year) => year.try_into().ok(),
+        }
+    }
+}
+
+impl From<&types::Date> for YmdEncoding {
+    fn from(date: &types::Date) -> Self {
+        Self {
+            year: date.year().into(),
+            month: date.month() as u8,
+            day: date.day() as u8,
+        }
+    }
+}
+
+impl YmdEncoding {
+    fn date(&self) -> Option<types::Date> {
+        types::Date::from_ymd_opt(self.year.year()?, self.month.into(), self.day.into())
+    }
+}
+
+impl From<&types::TimeOfDay> for HmsEncoding {
+    fn from(time: &types::TimeOfDay) -> Self {
+        // chrono represents a leap second as a fraction of at least one
+        // second.
+        let leap_second = u8::from(time.nanosecond() >= 1_000_000_000);
+
+        Self {
+            hours: time.hour() as u8,
+            minutes: time.minute() as u8,
+            seconds: time.second() as u8 + leap_second,
+        }
+    }
+}
+
+impl HmsEncoding {
+    /// Returns the time of day, or `None` if the value is invalid or the end
+    /// of the day (`24:00:00`), which chrono can't represent.
+    pub(super) fn time_of_day(&self) -> Option<types::TimeOfDay> {
+        let (seconds, nanoseconds) = match self.seconds {
+            60 => (59, 1_000_000_000),
+            seconds => (seconds, 0),
+        };
+
+        types::TimeOfDay::from_hms_nano_opt(
+            self.hours.into(),
+            self.minutes.into(),
+            seconds.into(),
+            nanoseconds,
+        )
+    }
+}
+
+impl From<&types::DateTime> for DateTimeEncoding {
+    fn from(date_time: &types::DateTime) -> Self {
+        Self {
+            date: (&date_time.date()).into(),
+            time: (&date_time.time()).into(),
+        }
+    }
+}
+
+impl DateTimeEncoding {
+    pub(super) fn date_time(&self) -> Option<types::DateTime> {
+        Some(self.date.date()?.and_time(self.time.time_of_day()?))
+    }
+}
+
+impl From<&types::IsoDuration> for DurationIntervalEncoding {
+    fn from(duration: &types::IsoDuration) -> Self {
+        let integer = |component: Option<u32>| component.map(Integer::from);
+
+        Self {
+            years: integer(duration.years),
+            months: integer(duration.months),
+            weeks: integer(duration.weeks),
+            days: integer(duration.days),
+            hours: integer(duration.hours),
+            minutes: integer(duration.minutes),
+            seconds: integer(duration.seconds),
+            fractional_part: duration.fraction.map(|fraction| FractionalPart {
+                number_of_digits: fraction.digits.into(),
+                fractional_value: fraction.value.into(),
+            }),
+        }
+    }
+}
+
+impl DurationIntervalEncoding {
+    /// Returns the duration, or `None` if a component is too large or the
+    /// fraction has more than nine digits.
+    pub(super) fn duration(&self) -> Option<types::IsoDuration> {
+        fn component(integer: Option<&Integer>) -> Option<Option<u32>> {
+            integer.map(u32::try_from).transpose().ok()
+        }
+
+        let fraction = match &self.fractional_part {
+            Some(fraction) => Some(types::DurationFraction {
+                digits: u8::try_from(&fraction.number_of_digits)
+                    .ok()
+                    .filter(|digits| *digits <= 9)?,
+                value: u32::try_from(&fraction.fractional_value).ok()?,
+            }),
+            None => None,
+        };
+
+        Some(types::IsoDuration {
+            years: component(self.years.as_ref())?,
+            months: component(self.months.as_ref())?,
+            weeks: component(self.weeks.as_ref())?,
+            days: component(self.days.as_ref())?,
+            hours: component(self.hours.as_ref())?,
+            minutes: component(self.minutes.as_ref())?,
+            seconds: component(self.seconds.as_ref())?,
+            fraction,
+        })
+    }
+}
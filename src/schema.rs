@@ -0,0 +1,1542 @@
+//! Loading ASN.1 modules at runtime.
+//!
+//! A [`Schema`] parses the text of one or more ASN.1 modules into
+//! [`TypeDescriptor`]s, which can be used with any codec to decode and encode
+//! [`Value`]s, without generating and compiling Rust code for the module
+//! first.
+//!
+//! ```rust
+//! use rasn::{schema::Schema, types::value::Value};
+//!
+//! let schema = Schema::parse(r#"
+//!     Messages DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+//!         Message ::= SEQUENCE {
+//!             id      INTEGER (0..65535),
+//!             payload CHOICE { text UTF8String, ping NULL },
+//!             ...
+//!         }
+//!     END
+//! "#).unwrap();
+//!
+//! let message = schema.get("Message").unwrap();
+//! let value = rasn::jer::decode_value(message, r#"{"id": 42, "payload": {"ping": null}}"#).unwrap();
+//! let bytes = rasn::uper::encode_value(message, &value).unwrap();
+//!
+//! assert_eq!(value.get("id"), Some(&Value::Integer(42.into())));
+//! assert_eq!(rasn::uper::decode_value(message, &bytes).unwrap(), value);
+//! ```
+//!
+//! The descriptors are owned by the schema, along with the names and the
+//! tables of constraints, such as permitted alphabets, they refer to, and
+//! are freed when it's dropped. They're only handed out with the lifetime of
+//! a borrow of the schema, which the [`Value`]s decoded with them borrow
+//! their names for as well.
+//!
+//! Recursive types, which contain themselves, refer to themselves with a
+//! [`TypeKind::Reference`]. Type assignments which can't be represented by
+//! descriptors, such as parameterized types, don't prevent the rest of a
+//! module from being loaded. Instead the error is returned when the type is
+//! requested.
+//! Constraints which aren't visible to the encoding rules, such as table and
+//! inner subtype constraints, are ignored.
+//!
+//! [`Value`]: crate::types::value::Value
+
+mod lexer;
+mod parser;
+
+use alloc::{
+    borrow::ToOwned,
+    boxed::Box,
+    collections::{BTreeMap, BTreeSet},
+    string::String,
+    sync::Arc,
+    vec::Vec,
+};
+use core::any::Any;
+
+use self::parser::{
+    Addition, Component, ComponentList, ElementSet, ModuleDefinition, Presence, SubtypeConstraint,
+    TagDefault, TagMode, Type, Value,
+};
+use crate::{
+    error::SchemaError,
+    types::{
+        constraints::{self, Bounded, Constraint, Constraints, Extensible},
+        descriptor::{
+            EnumeratedVariant, FieldDescriptor, TypeDescriptor, TypeKind, TypeReference,
+            VariantDescriptor,
+        },
+        fields::FieldPresence,
+        Class, Identifier, Tag,
+    },
+};
+
+/// A set of ASN.1 modules, and the descriptors of the types they define.
+#[derive(Debug, Clone)]
+pub struct Schema {
+    modules: Arc<[Module]>,
+    /// Owns the descriptors `modules` refer to. Declared after `modules` so
+    /// that it's dropped last.
+    _arena: Arc<Arena>,
+}
+
+/// A module of a [`Schema`].
+#[derive(Debug)]
+pub struct Module {
+    name: String,
+    /// The descriptors live in the arena of the schema, they're only handed
+    /// out with the lifetime of a borrow of it.
+    types: BTreeMap<String, Result<&'static TypeDescriptor<'static>, SchemaError>>,
+}
+
+impl Schema {
+    /// Parses the text of one or more ASN.1 modules.
+    ///
+    /// # Errors
+    /// Returns an error if the module headers or the structure of the
+    /// modules are invalid. Errors in individual type assignments are
+    /// returned by [`Schema::get`].
+    pub fn parse(text: &str) -> Result<Self, SchemaError> {
+        Self::parse_all([text])
+    }
+
+    /// Parses the text of several files containing ASN.1 modules, which can
+    /// import types from each other.
+    ///
+    /// # Errors
+    /// Returns an error if the module headers or the structure of the
+    /// modules are invalid. Errors in individual type assignments are
+    /// returned by [`Schema::get`].
+    pub fn parse_all<'a>(texts: impl IntoIterator<Item = &'a str>) -> Result<Self, SchemaError> {
+        let mut definitions = Vec::new();
+        for text in texts {
+            definitions.extend(parser::parse_modules(&lexer::tokenize(text)?)?);
+        }
+
+        let mut resolver = Resolver {
+            modules: &definitions,
+            arena: Arena::default(),
+            names: BTreeSet::new(),
+            types: BTreeMap::new(),
+            resolved: Vec::new(),
+            in_progress: Vec::new(),
+            smallest_tags: Vec::new(),
+        };
+        let modules = definitions
+            .iter()
+            .enumerate()
+            .map(|(index, definition)| Module {
+                name: definition.name.clone(),
+                types: definition
+                    .types
+                    .iter()
+                    .map(|(name, _)| (name.clone(), resolver.assignment(index, name)))
+                    .collect(),
+            })
+            .collect();
+
+        Ok(Self {
+            modules,
+            _arena: Arc::new(resolver.arena),
+        })
+    }
+
+    /// Returns the descriptor of the type named `name`, from the first module
+    /// that defines it.
+    ///
+    /// # Errors
+    /// Returns an error if no module defines the type, or if the type can't
+    /// be represented by a descriptor.
+    pub fn get(&self, name: &str) -> Result<&TypeDescriptor<'_>, SchemaError> {
+        self.modules
+            .iter()
+            .find_map(|module| module.types.get(name))
+            .cloned()
+            .unwrap_or_else(|| {
+                Err(SchemaError::UndefinedReference {
+                    name: name.to_owned(),
+                })
+            })
+    }
+
+    /// Returns the descriptor of the type named `name` in the module named
+    /// `module`.
+    ///
+    /// # Errors
+    /// Returns an error if the module or type isn't defined, or if the type
+    /// can't be represented by a descriptor.
+    pub fn get_in(&self, module: &str, name: &str) -> Result<&TypeDescriptor<'_>, SchemaError> {
+        self.module(module)
+            .ok_or_else(|| SchemaError::UndefinedReference {
+                name: module.to_owned(),
+            })?
+            .get(name)
+    }
+
+    /// Returns the module named `name`.
+    #[must_use]
+    pub fn module(&self, name: &str) -> Option<&Module> {
+        self.modules.iter().find(|module| module.name == name)
+    }
+
+    /// Returns the modules of the schema, in the order they were parsed.
+    pub fn modules(&self) -> impl Iterator<Item = &Module> {
+        self.modules.iter()
+    }
+}
+
+impl Module {
+    /// Returns the name of the module.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the descriptor of the type named `name`.
+    ///
+    /// # Errors
+    /// Returns an error if the module doesn't define the type, or if the type
+    /// can't be represented by a descriptor.
+    pub fn get(&self, name: &str) -> Result<&TypeDescriptor<'_>, SchemaError> {
+        self.types.get(name).cloned().unwrap_or_else(|| {
+            Err(SchemaError::UndefinedReference {
+                name: name.to_owned(),
+            })
+        })
+    }
+
+    /// Returns the names of the types defined by the module, in alphabetical
+    /// order.
+    pub fn type_names(&self) -> impl Iterator<Item = &str> {
+        self.types.keys().map(String::as_str)
+    }
+}
+
+/// Owns the allocations of the descriptors of a [`Schema`], which refer to
+/// each other.
+#[derive(Default)]
+struct Arena {
+    allocations: Vec<*mut (dyn Any + Send + Sync)>,
+}
+
+// SAFETY: The allocations are only read after the schema has been built, and
+// are `Send + Sync` themselves.
+unsafe impl Send for Arena {}
+// SAFETY: See above.
+unsafe impl Sync for Arena {}
+
+impl Arena {
+    /// Moves `value` into the arena. The reference must not outlive the
+    /// arena, which `Schema` ensures by only handing out references with
+    /// the lifetime of a borrow of itself.
+    fn alloc<T: Any + Send + Sync>(&mut self, value: T) -> &'static T {
+        let allocation = Box::into_raw(Box::new(value));
+        self.allocations.push(allocation);
+        // SAFETY: The allocation is valid until the arena is dropped.
+        unsafe { &*allocation }
+    }
+
+    fn alloc_slice<T: Any + Send + Sync>(&mut self, values: Vec<T>) -> &'static [T] {
+        self.alloc(values)
+    }
+
+    fn alloc_str(&mut self, value: &str) -> &'static str {
+        self.alloc(String::from(value))
+    }
+}
+
+impl Drop for Arena {
+    fn drop(&mut self) {
+        for allocation in self.allocations.drain(..).rev() {
+            // SAFETY: The allocation was created by `Box::into_raw` and is
+            // no longer referenced.
+            drop(unsafe { Box::from_raw(allocation) });
+        }
+    }
+}
+
+impl core::fmt::Debug for Arena {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Arena")
+            .field("allocations", &self.allocations.len())
+            .finish()
+    }
+}
+
+/// Resolves the type assignments of parsed modules into descriptors.
+struct Resolver<'a> {
+    modules: &'a [ModuleDefinition],
+    arena: Arena,
+    /// The names allocated in `arena` so far, which are shared by every
+    /// descriptor that uses them.
+    names: BTreeSet<&'static str>,
+    types: BTreeMap<(usize, String), Result<&'static TypeDescriptor<'static>, SchemaError>>,
+    /// The keys of `types`, in the order they were resolved.
+    resolved: Vec<(usize, String)>,
+    /// The assignments currently being resolved, used to detect recursion,
+    /// with the reference that the types they contain use to refer to them.
+    in_progress: Vec<((usize, String), Option<&'static TypeReference<'static>>)>,
+    /// The smallest tags of untagged `CHOICE` types that are referred to while
+    /// being resolved, which determine the order of alternatives.
+    smallest_tags: Vec<(&'static TypeReference<'static>, Tag)>,
+}
+
+/// The constraints of a type, as they are evaluated from an element set.
+///
+/// The constraints are the effective ones visible to the encodings, along
+/// with the exact sets of values they permit when those differ, e.g. due to
+/// an `EXCEPT` clause.
+#[derive(Debug, Clone, Default)]
+struct Effective {
+    value: Option<Bounds>,
+    value_set: Option<Ranges>,
+    size: Option<Bounds>,
+    size_set: Option<Ranges>,
+    size_extensible: bool,
+    /// The characters of string values, which form an alphabet when used in a
+    /// `FROM` constraint.
+    characters: Option<Vec<u32>>,
+    character_set: Option<Vec<u32>>,
+    alphabet: Option<Vec<u32>>,
+    alphabet_set: Option<Vec<u32>>,
+    alphabet_extensible: bool,
+}
+
+type Bounds = (Option<i128>, Option<i128>);
+
+/// Sorted, disjoint and inclusive ranges, with unbounded ends as `i128::MIN`
+/// and `i128::MAX`.
+type Ranges = Vec<(i128, i128)>;
+
+/// How deep references are followed to find the tag of a type.
+const MAX_REFERENCE_DEPTH: usize = 64;
+
+fn span((start, end): Bounds) -> (i128, i128) {
+    (start.unwrap_or(i128::MIN), end.unwrap_or(i128::MAX))
+}
+
+fn normalized(mut ranges: Ranges) -> Ranges {
+    ranges.sort_unstable();
+    let mut merged: Ranges = Vec::with_capacity(ranges.len());
+    for (start, end) in ranges {
+        match merged.last_mut() {
+            Some(last) if start <= last.1.saturating_add(1) => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+fn ranges_union(mut a: Ranges, b: Ranges) -> Ranges {
+    a.extend(b);
+    normalized(a)
+}
+
+fn ranges_intersection(a: Ranges, b: Ranges) -> Ranges {
+    normalized(
+        a.iter()
+            .flat_map(|a| b.iter().map(move |b| (a.0.max(b.0), a.1.min(b.1))))
+            .filter(|(start, end)| start <= end)
+            .collect(),
+    )
+}
+
+fn ranges_difference(mut a: Ranges, b: Ranges) -> Ranges {
+    for (start, end) in b {
+        a = a
+            .into_iter()
+            .flat_map(|(a_start, a_end)| {
+                let below = (a_start < start).then(|| (a_start, a_end.min(start - 1)));
+                let above = (a_end > end).then(|| (a_start.max(end + 1), a_end));
+                below.into_iter().chain(above)
+            })
+            .collect();
+    }
+    a
+}
+
+fn set_ranges<T: Copy + TryInto<i128>>(set: &[Bounded<T>]) -> Ranges {
+    normalized(set.iter().map(|bounded| span(bounds(bounded))).collect())
+}
+
+/// The bounded ranges of `ranges`, if they differ from `effective`.
+fn bounded_set<T: TryFrom<i128> + PartialEq>(
+    ranges: Option<Ranges>,
+    effective: Bounds,
+) -> Option<Vec<Bounded<T>>> {
+    let ranges = ranges.filter(|ranges| *ranges != [span(effective)])?;
+    let unbounded = |value, bound| (value != bound).then_some(value);
+    Some(
+        ranges
+            .into_iter()
+            .map(|(start, end)| bounded((unbounded(start, i128::MIN), unbounded(end, i128::MAX))))
+            .collect(),
+    )
+}
+
+fn hull((a_start, a_end): Bounds, (b_start, b_end): Bounds) -> Bounds {
+    (
+        a_start.zip(b_start).map(|(a, b)| a.min(b)),
+        a_end.zip(b_end).map(|(a, b)| a.max(b)),
+    )
+}
+
+fn narrow((a_start, a_end): Bounds, (b_start, b_end): Bounds) -> Bounds {
+    let pick = |a: Option<i128>, b: Option<i128>, f: fn(i128, i128) -> i128| match (a, b) {
+        (Some(a), Some(b)) => Some(f(a, b)),
+        (a, b) => a.or(b),
+    };
+    (
+        pick(a_start, b_start, i128::max),
+        pick(a_end, b_end, i128::min),
+    )
+}
+
+fn merge<T>(a: Option<T>, b: Option<T>, f: impl FnOnce(T, T) -> T) -> Option<T> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(f(a, b)),
+        (a, b) => a.or(b),
+    }
+}
+
+fn bounded<T: TryFrom<i128> + PartialEq>((start, end): Bounds) -> Bounded<T> {
+    let start = start.and_then(|start| T::try_from(start).ok());
+    let end = end.and_then(|end| T::try_from(end).ok());
+    match (start, end) {
+        (None, None) => Bounded::None,
+        (Some(start), Some(end)) if start == end => Bounded::Single(start),
+        (start, end) => Bounded::Range { start, end },
+    }
+}
+
+fn bounds<T: Copy + TryInto<i128>>(bounded: &Bounded<T>) -> Bounds {
+    let convert = |value: T| value.try_into().ok();
+    match *bounded {
+        Bounded::None => (None, None),
+        Bounded::Single(value) => (convert(value), convert(value)),
+        Bounded::Range { start, end } => (start.and_then(convert), end.and_then(convert)),
+    }
+}
+
+impl Effective {
+    fn exact_values(&self) -> Option<Ranges> {
+        self.value_set
+            .clone()
+            .or_else(|| self.value.map(|value| alloc::vec![span(value)]))
+    }
+
+    fn exact_sizes(&self) -> Option<Ranges> {
+        self.size_set
+            .clone()
+            .or_else(|| self.size.map(|size| alloc::vec![span(size)]))
+    }
+
+    fn exact_characters(&self) -> Option<Vec<u32>> {
+        self.character_set
+            .clone()
+            .or_else(|| self.characters.clone())
+    }
+
+    fn exact_alphabet(&self) -> Option<Vec<u32>> {
+        self.alphabet_set.clone().or_else(|| self.alphabet.clone())
+    }
+
+    fn union(self, other: Self) -> Self {
+        let extend = |(mut a, b): (Vec<u32>, Vec<u32>)| {
+            a.extend(b);
+            a
+        };
+        Self {
+            value_set: self
+                .exact_values()
+                .zip(other.exact_values())
+                .map(|(a, b)| ranges_union(a, b)),
+            size_set: self
+                .exact_sizes()
+                .zip(other.exact_sizes())
+                .map(|(a, b)| ranges_union(a, b)),
+            character_set: self
+                .exact_characters()
+                .zip(other.exact_characters())
+                .map(extend),
+            alphabet_set: self
+                .exact_alphabet()
+                .zip(other.exact_alphabet())
+                .map(extend),
+            value: self.value.zip(other.value).map(|(a, b)| hull(a, b)),
+            size: self.size.zip(other.size).map(|(a, b)| hull(a, b)),
+            size_extensible: self.size_extensible || other.size_extensible,
+            characters: self.characters.zip(other.characters).map(extend),
+            alphabet: self.alphabet.zip(other.alphabet).map(extend),
+            alphabet_extensible: self.alphabet_extensible || other.alphabet_extensible,
+        }
+    }
+
+    fn intersection(self, other: Self) -> Self {
+        let common = |a: Vec<u32>, b: Vec<u32>| a.into_iter().filter(|c| b.contains(c)).collect();
+        Self {
+            value_set: merge(
+                self.exact_values(),
+                other.exact_values(),
+                ranges_intersection,
+            ),
+            size_set: merge(self.exact_sizes(), other.exact_sizes(), ranges_intersection),
+            character_set: merge(self.exact_characters(), other.exact_characters(), common),
+            alphabet_set: merge(self.exact_alphabet(), other.exact_alphabet(), common),
+            value: merge(self.value, other.value, narrow),
+            size: merge(self.size, other.size, narrow),
+            size_extensible: self.size_extensible && other.size_extensible,
+            characters: merge(self.characters, other.characters, common),
+            alphabet: merge(self.alphabet, other.alphabet, common),
+            alphabet_extensible: self.alphabet_extensible && other.alphabet_extensible,
+        }
+    }
+
+    /// Excludes the values of `other`, which only changes the exact sets, as
+    /// `EXCEPT` clauses aren't visible to the encodings.
+    fn except(self, other: &Self) -> Self {
+        let without = |a: Vec<u32>, b: Option<Vec<u32>>| {
+            a.into_iter()
+                .filter(|c| b.as_ref().is_none_or(|b| !b.contains(c)))
+                .collect()
+        };
+        Self {
+            value_set: self.exact_values().map(|a| match other.exact_values() {
+                Some(b) => ranges_difference(a, b),
+                None => a,
+            }),
+            size_set: self.exact_sizes().map(|a| match other.exact_sizes() {
+                Some(b) => ranges_difference(a, b),
+                None => a,
+            }),
+            character_set: self
+                .exact_characters()
+                .map(|a| without(a, other.exact_characters())),
+            alphabet_set: self
+                .exact_alphabet()
+                .map(|a| without(a, other.exact_alphabet())),
+            ..self
+        }
+    }
+
+    fn into_constraints(self, arena: &mut Arena, extensible: bool) -> Constraints {
+        let mut constraints = Vec::new();
+        if let Some(value) = self.value {
+            let mut constraint = constraints::Value::new(bounded(value));
+            if let Some(set) = bounded_set(self.value_set, value) {
+                constraint = constraint.with_set(arena.alloc_slice(set));
+            }
+            constraints.push(Constraint::Value(
+                Extensible::new(constraint).set_extensible(extensible),
+            ));
+        }
+        if let Some(size) = self.size {
+            let mut constraint = constraints::Size::new(bounded(size));
+            if let Some(set) = bounded_set(self.size_set, size) {
+                constraint = constraint.with_set(arena.alloc_slice(set));
+            }
+            constraints.push(Constraint::Size(
+                Extensible::new(constraint).set_extensible(extensible || self.size_extensible),
+            ));
+        }
+        if let Some(mut alphabet) = self.alphabet {
+            alphabet.sort_unstable();
+            alphabet.dedup();
+            let set = self.alphabet_set.map(|mut set| {
+                set.sort_unstable();
+                set.dedup();
+                set
+            });
+            let mut constraint =
+                constraints::PermittedAlphabet::new(arena.alloc_slice(alphabet.clone()));
+            if let Some(set) = set.filter(|set| *set != alphabet) {
+                constraint = constraint.with_set(arena.alloc_slice(set));
+            }
+            constraints.push(Constraint::PermittedAlphabet(
+                Extensible::new(constraint).set_extensible(extensible || self.alphabet_extensible),
+            ));
+        }
+        if extensible && constraints.is_empty() {
+            constraints.push(Constraint::Extensible);
+        }
+        Constraints::new(&constraints)
+    }
+}
+
+impl<'a> Resolver<'a> {
+    /// Returns the copy of `name` in the arena, allocating it if it isn't
+    /// there yet.
+    fn name(&mut self, name: &str) -> &'static str {
+        if let Some(allocated) = self.names.get(name) {
+            return allocated;
+        }
+        let allocated = self.arena.alloc_str(name);
+        self.names.insert(allocated);
+        allocated
+    }
+
+    /// Returns the descriptor of the type assigned to `name` in `module`.
+    fn assignment(
+        &mut self,
+        module: usize,
+        name: &str,
+    ) -> Result<&'static TypeDescriptor<'static>, SchemaError> {
+        let key = (module, name.to_owned());
+        if let Some(result) = self.types.get(&key) {
+            return result.clone();
+        }
+        if let Some(index) = self.in_progress.iter().position(|(other, _)| *other == key) {
+            return self.reference(index);
+        }
+
+        let result = match self.lookup(module, name, |module| &module.types) {
+            Some((module, Ok(ty))) => {
+                let start = self.resolved.len();
+                self.in_progress.push((key.clone(), None));
+                let result = self.build(module, ty);
+                let reference = self.in_progress.pop().and_then(|(_, reference)| reference);
+                let result = result.and_then(|descriptor| {
+                    if let TypeKind::Reference(target) = descriptor.kind {
+                        if reference.is_some_and(|reference| core::ptr::eq(reference, target)) {
+                            // The type is only an alias of itself.
+                            return Err(SchemaError::RecursiveType {
+                                name: name.to_owned(),
+                            });
+                        }
+                    }
+                    let identifier = Some(self.name(name));
+                    let descriptor = self.arena.alloc(TypeDescriptor {
+                        identifier,
+                        ..descriptor
+                    });
+                    if let Some(reference) = reference {
+                        reference.set(descriptor);
+                    }
+                    Ok(descriptor)
+                });
+                if result.is_err() && reference.is_some() {
+                    // The types resolved in the meantime may contain the
+                    // reference, which is never set, so they're resolved
+                    // again when they're requested.
+                    for key in self.resolved.drain(start..) {
+                        self.types.remove(&key);
+                    }
+                }
+                result
+            }
+            Some((_, Err(error))) => Err(error.clone()),
+            None => Err(SchemaError::UndefinedReference {
+                name: name.to_owned(),
+            }),
+        };
+        self.types.insert(key.clone(), result.clone());
+        self.resolved.push(key);
+        result
+    }
+
+    /// Returns a [`TypeKind::Reference`] to the assignment at `index` of the
+    /// assignments in progress, for a type which contains itself.
+    fn reference(&mut self, index: usize) -> Result<&'static TypeDescriptor<'static>, SchemaError> {
+        let ((module, name), reference) = self.in_progress[index].clone();
+        let reference = match reference {
+            Some(reference) => reference,
+            None => {
+                let reference = self.arena.alloc(TypeReference::new());
+                self.in_progress[index].1 = Some(reference);
+                reference
+            }
+        };
+        let (tag, smallest_tag) = self.tags(module, &name, 0)?;
+        if tag == Tag::EOC {
+            self.smallest_tags.push((reference, smallest_tag));
+        }
+        let identifier = Some(self.name(&name));
+        Ok(self.arena.alloc(TypeDescriptor::new(
+            identifier,
+            tag,
+            TypeKind::Reference(reference),
+        )))
+    }
+
+    /// Returns the tag and the smallest tag of the type assigned to `name`
+    /// in `module`, from its definition rather than its descriptor, which
+    /// isn't built yet for types that contain themselves.
+    fn tags(&self, module: usize, name: &str, depth: usize) -> Result<(Tag, Tag), SchemaError> {
+        // Only references that refer to each other without a tag in between
+        // nest this deeply.
+        if depth > MAX_REFERENCE_DEPTH {
+            return Err(SchemaError::RecursiveType {
+                name: name.to_owned(),
+            });
+        }
+        match self.lookup(module, name, |module| &module.types) {
+            Some((module, Ok(ty))) => self.type_tags(module, ty, depth),
+            Some((_, Err(error))) => Err(error.clone()),
+            None => Err(SchemaError::UndefinedReference {
+                name: name.to_owned(),
+            }),
+        }
+    }
+
+    fn type_tags(&self, module: usize, ty: &Type, depth: usize) -> Result<(Tag, Tag), SchemaError> {
+        Ok(match ty {
+            Type::Builtin(descriptor) => (descriptor.tag, descriptor.smallest_tag()),
+            Type::Reference { module: None, name } => self.tags(module, name, depth + 1)?,
+            Type::Reference {
+                module: Some(from),
+                name,
+            } => {
+                let index = self
+                    .modules
+                    .iter()
+                    .position(|module| &module.name == from)
+                    .ok_or_else(|| SchemaError::UndefinedReference { name: from.clone() })?;
+                self.tags(index, name, depth + 1)?
+            }
+            Type::Unsupported(construct) => return Err(SchemaError::Unsupported { construct }),
+            Type::Enumerated { .. } => (Tag::ENUMERATED, Tag::ENUMERATED),
+            Type::Sequence(_) | Type::SequenceOf(_) => (Tag::SEQUENCE, Tag::SEQUENCE),
+            Type::Set(_) | Type::SetOf(_) => (Tag::SET, Tag::SET),
+            Type::Choice(list) => {
+                if self.is_automatic(module, list) {
+                    return Ok((Tag::EOC, Tag::new(Class::Context, 0)));
+                }
+                let additions =
+                    list.extension
+                        .iter()
+                        .flatten()
+                        .flat_map(|addition| match addition {
+                            Addition::Component(component) => core::slice::from_ref(component),
+                            Addition::Group(components) => components,
+                        });
+                let mut smallest = None;
+                for component in list.root.iter().chain(additions) {
+                    if let Component::Named { ty, .. } = component {
+                        let (_, tag) = self.type_tags(module, ty, depth + 1)?;
+                        smallest = Some(smallest.map_or(tag, |smallest: Tag| smallest.min(tag)));
+                    }
+                }
+                (Tag::EOC, smallest.unwrap_or(Tag::EOC))
+            }
+            Type::Tagged { tag, .. } => (*tag, *tag),
+            Type::Constrained { inner, .. } => self.type_tags(module, inner, depth + 1)?,
+        })
+    }
+
+    /// Returns the smallest tag of `descriptor`, which for references to
+    /// untagged `CHOICE` types that are still being resolved is taken from
+    /// their definition.
+    fn smallest_tag(&self, descriptor: &TypeDescriptor) -> Tag {
+        match descriptor.kind {
+            TypeKind::Reference(reference) if reference.get().is_none() => self
+                .smallest_tags
+                .iter()
+                .find(|(other, _)| core::ptr::eq(*other, reference))
+                .map_or(descriptor.tag, |(_, tag)| *tag),
+            _ => descriptor.smallest_tag(),
+        }
+    }
+
+    /// Finds the assignment of `name` visible in `module`, either defined in
+    /// the module itself or imported from another module.
+    fn lookup<T>(
+        &self,
+        module: usize,
+        name: &str,
+        assignments: fn(&ModuleDefinition) -> &Vec<(String, T)>,
+    ) -> Option<(usize, &'a T)> {
+        let modules = self.modules;
+        let definition = &modules[module];
+        if let Some((_, item)) = assignments(definition)
+            .iter()
+            .find(|(assigned, _)| assigned == name)
+        {
+            return Some((module, item));
+        }
+        let (_, from) = definition
+            .imports
+            .iter()
+            .find(|(symbol, _)| symbol == name)?;
+        let index = modules.iter().position(|module| &module.name == from)?;
+        assignments(&modules[index])
+            .iter()
+            .find(|(assigned, _)| assigned == name)
+            .map(|(_, item)| (index, item))
+    }
+
+    fn integer(&self, module: usize, value: &Value) -> Result<Option<i128>, SchemaError> {
+        let mut value = (module, value);
+        // Guards against value references that refer to each other.
+        for _ in 0..64 {
+            match value.1 {
+                Value::Integer(integer) => return Ok(Some(*integer)),
+                Value::Min | Value::Max => return Ok(None),
+                Value::Reference(name) => {
+                    value = self
+                        .lookup(value.0, name, |module| &module.values)
+                        .ok_or_else(|| SchemaError::UndefinedReference { name: name.clone() })?;
+                }
+                Value::String(_) | Value::Other => break,
+            }
+        }
+        Err(SchemaError::Unsupported {
+            construct: "values other than integers",
+        })
+    }
+
+    fn build(&mut self, module: usize, ty: &Type) -> Result<TypeDescriptor<'static>, SchemaError> {
+        let definition = &self.modules[module];
+        let extensible = |extension: bool| extension || definition.extensibility_implied;
+
+        Ok(match ty {
+            Type::Builtin(descriptor) => **descriptor,
+            Type::Reference { module: None, name } => *self.assignment(module, name)?,
+            Type::Reference {
+                module: Some(from),
+                name,
+            } => {
+                let index = self
+                    .modules
+                    .iter()
+                    .position(|module| &module.name == from)
+                    .ok_or_else(|| SchemaError::UndefinedReference { name: from.clone() })?;
+                *self.assignment(index, name)?
+            }
+            Type::Unsupported(construct) => return Err(SchemaError::Unsupported { construct }),
+            Type::Enumerated { root, extension } => {
+                let variants = self.enumerated_root(module, root)?;
+                let extended_variants = extension
+                    .as_ref()
+                    .map(|extension| self.enumerated_additions(module, &variants, extension))
+                    .transpose()?
+                    .or_else(|| definition.extensibility_implied.then(Vec::new));
+                TypeDescriptor::new(
+                    Identifier::ENUMERATED.0,
+                    Tag::ENUMERATED,
+                    TypeKind::Enumerated {
+                        variants: self.arena.alloc_slice(variants),
+                        extended_variants: extended_variants
+                            .map(|variants| self.arena.alloc_slice(variants)),
+                    },
+                )
+            }
+            Type::Sequence(list) | Type::Set(list) => {
+                let automatic = self.is_automatic(module, list);
+                let (fields, additions) = self.fields(module, list, automatic)?;
+                let extended_fields = (list.extension.is_some() || extensible(false))
+                    .then(|| self.arena.alloc_slice(additions));
+                let fields = self.arena.alloc_slice(fields);
+                if matches!(ty, Type::Set(_)) {
+                    TypeDescriptor::new(
+                        Identifier::SET.0,
+                        Tag::SET,
+                        TypeKind::Set {
+                            fields,
+                            extended_fields,
+                        },
+                    )
+                } else {
+                    TypeDescriptor::new(
+                        Identifier::SEQUENCE.0,
+                        Tag::SEQUENCE,
+                        TypeKind::Sequence {
+                            fields,
+                            extended_fields,
+                        },
+                    )
+                }
+            }
+            Type::Choice(list) => {
+                let automatic = self.is_automatic(module, list);
+                let (fields, additions) = self.fields(module, list, automatic)?;
+                let mut alternatives = |fields: Vec<FieldDescriptor<'static>>| {
+                    let mut variants = fields
+                        .into_iter()
+                        .map(|field| VariantDescriptor {
+                            name: field.name,
+                            ty: field.ty,
+                        })
+                        .collect::<Vec<_>>();
+                    // Alternatives are indexed in the canonical order of
+                    // their tags.
+                    variants.sort_by_key(|variant| self.smallest_tag(variant.ty));
+                    self.arena.alloc_slice(variants)
+                };
+                let variants = alternatives(fields);
+                let extended_variants = (list.extension.is_some() || extensible(false))
+                    .then(|| alternatives(additions));
+                TypeDescriptor::new(
+                    Identifier::CHOICE.0,
+                    Tag::EOC,
+                    TypeKind::Choice {
+                        variants,
+                        extended_variants,
+                    },
+                )
+            }
+            Type::SequenceOf(element) => {
+                let element = self.build(module, element)?;
+                TypeDescriptor::new(
+                    Identifier::SEQUENCE_OF.0,
+                    Tag::SEQUENCE,
+                    TypeKind::SequenceOf {
+                        element: self.arena.alloc(element),
+                    },
+                )
+            }
+            Type::SetOf(element) => {
+                let element = self.build(module, element)?;
+                TypeDescriptor::new(
+                    Identifier::SET_OF.0,
+                    Tag::SET,
+                    TypeKind::SetOf {
+                        element: self.arena.alloc(element),
+                    },
+                )
+            }
+            Type::Tagged { tag, mode, inner } => {
+                let inner = self.build(module, inner)?;
+                let explicit = match mode {
+                    Some(TagMode::Explicit) => true,
+                    Some(TagMode::Implicit) => false,
+                    None => definition.tagging == TagDefault::Explicit,
+                };
+                tagged(&mut self.arena, inner, *tag, explicit)
+            }
+            Type::Constrained { inner, constraint } => {
+                let inner = self.build(module, inner)?;
+                let constraints = self.constraints(module, constraint)?;
+                constrained(&mut self.arena, inner, constraints)
+            }
+        })
+    }
+
+    fn enumerated_root(
+        &mut self,
+        module: usize,
+        items: &[(String, Option<Value>)],
+    ) -> Result<Vec<EnumeratedVariant<'static>>, SchemaError> {
+        let mut numbered = Vec::new();
+        for (_, value) in items {
+            if let Some(value) = value {
+                numbered.push(self.discriminant(module, value)?);
+            }
+        }
+
+        // Unnumbered items take the smallest non-negative value not used by
+        // any other item.
+        let mut next = 0;
+        let mut variants = Vec::new();
+        for (name, value) in items {
+            let discriminant = match value {
+                Some(value) => self.discriminant(module, value)?,
+                None => {
+                    while numbered.contains(&next) {
+                        next += 1;
+                    }
+                    numbered.push(next);
+                    next
+                }
+            };
+            variants.push(EnumeratedVariant {
+                name: self.name(name),
+                discriminant,
+            });
+        }
+        variants.sort_by_key(|variant| variant.discriminant);
+        if variants
+            .windows(2)
+            .any(|pair| pair[0].discriminant == pair[1].discriminant)
+        {
+            return Err(SchemaError::InvalidType {
+                reason: "duplicate enumeration values".into(),
+            });
+        }
+        Ok(variants)
+    }
+
+    fn enumerated_additions(
+        &mut self,
+        module: usize,
+        root: &[EnumeratedVariant<'static>],
+        items: &[(String, Option<Value>)],
+    ) -> Result<Vec<EnumeratedVariant<'static>>, SchemaError> {
+        let mut additions: Vec<EnumeratedVariant<'static>> = Vec::new();
+        for (name, value) in items {
+            let discriminant = match value {
+                Some(value) => self.discriminant(module, value)?,
+                None => {
+                    // Unnumbered additions take the smallest value that's
+                    // larger than the previous additions and not in the root.
+                    let mut next = additions.last().map_or(0, |last| last.discriminant + 1);
+                    while root.iter().any(|variant| variant.discriminant == next) {
+                        next += 1;
+                    }
+                    next
+                }
+            };
+            if root
+                .iter()
+                .chain(&additions)
+                .any(|variant| variant.discriminant == discriminant)
+            {
+                return Err(SchemaError::InvalidType {
+                    reason: "duplicate enumeration values".into(),
+                });
+            }
+            additions.push(EnumeratedVariant {
+                name: self.name(name),
+                discriminant,
+            });
+        }
+        Ok(additions)
+    }
+
+    fn discriminant(&self, module: usize, value: &Value) -> Result<isize, SchemaError> {
+        self.integer(module, value)?
+            .and_then(|value| isize::try_from(value).ok())
+            .ok_or_else(|| SchemaError::InvalidType {
+                reason: "enumeration value out of range".into(),
+            })
+    }
+
+    /// Returns whether automatic tagging applies to `list`, which is the case
+    /// if none of the root components are tagged.
+    fn is_automatic(&self, module: usize, list: &ComponentList) -> bool {
+        self.modules[module].tagging == TagDefault::Automatic
+            && !list.root.iter().any(|component| {
+                matches!(
+                    component,
+                    Component::Named {
+                        ty: Type::Tagged { .. },
+                        ..
+                    }
+                )
+            })
+    }
+
+    /// Returns the fields of the root component list and the extension
+    /// additions of `list`.
+    fn fields(
+        &mut self,
+        module: usize,
+        list: &ComponentList,
+        automatic: bool,
+    ) -> Result<(Vec<FieldDescriptor<'static>>, Vec<FieldDescriptor<'static>>), SchemaError> {
+        let mut fields = Vec::new();
+        for component in &list.root {
+            self.component(module, component, &mut fields)?;
+        }
+
+        let mut additions = Vec::new();
+        for (index, addition) in list.extension.iter().flatten().enumerate() {
+            match addition {
+                Addition::Component(component) => {
+                    self.component(module, component, &mut additions)?;
+                }
+                // Groups are represented as optional `SEQUENCE` fields, like
+                // `#[rasn(extension_addition_group)]` fields.
+                Addition::Group(components) => {
+                    let mut group = Vec::new();
+                    for component in components {
+                        self.component(module, component, &mut group)?;
+                    }
+                    if automatic {
+                        automatic_tags(&mut self.arena, &mut group);
+                    }
+                    let fields = self.arena.alloc_slice(group);
+                    additions.push(FieldDescriptor {
+                        name: self.name(&alloc::format!("ext_group_{index}")),
+                        presence: FieldPresence::Optional,
+                        ty: self.arena.alloc(TypeDescriptor::new(
+                            Identifier::SEQUENCE.0,
+                            Tag::SEQUENCE,
+                            TypeKind::Sequence {
+                                fields,
+                                extended_fields: None,
+                            },
+                        )),
+                    });
+                }
+            }
+        }
+
+        if automatic {
+            let count = fields.len();
+            automatic_tags(&mut self.arena, &mut fields);
+            for (number, field) in (0..).skip(count).zip(&mut additions) {
+                let ty = automatic_tag(&mut self.arena, *field.ty, number);
+                field.ty = self.arena.alloc(ty);
+            }
+        }
+
+        Ok((fields, additions))
+    }
+
+    fn component(
+        &mut self,
+        module: usize,
+        component: &Component,
+        fields: &mut Vec<FieldDescriptor<'static>>,
+    ) -> Result<(), SchemaError> {
+        match component {
+            Component::Named { name, ty, presence } => fields.push(FieldDescriptor {
+                name: self.name(name),
+                presence: match presence {
+                    Presence::Required => FieldPresence::Required,
+                    Presence::Optional => FieldPresence::Optional,
+                    Presence::Default => FieldPresence::Default,
+                },
+                ty: {
+                    let descriptor = self.build(module, ty)?;
+                    self.arena.alloc(descriptor)
+                },
+            }),
+            Component::ComponentsOf(ty) => {
+                let mut descriptor = self.build(module, ty)?;
+                while let TypeKind::Explicit { inner } = descriptor.kind {
+                    descriptor = *inner;
+                }
+                match descriptor.kind {
+                    TypeKind::Sequence {
+                        fields: included, ..
+                    }
+                    | TypeKind::Set {
+                        fields: included, ..
+                    } => fields.extend_from_slice(included),
+                    _ => {
+                        return Err(SchemaError::InvalidType {
+                            reason: "`COMPONENTS OF` must refer to a `SEQUENCE` or `SET` type"
+                                .into(),
+                        })
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn constraints(
+        &mut self,
+        module: usize,
+        constraint: &SubtypeConstraint,
+    ) -> Result<Constraints, SchemaError> {
+        Ok(self
+            .effective(module, &constraint.root)?
+            .into_constraints(&mut self.arena, constraint.extensible))
+    }
+
+    fn effective(&mut self, module: usize, set: &ElementSet) -> Result<Effective, SchemaError> {
+        Ok(match set {
+            ElementSet::Union(sets) => {
+                let mut sets = sets.iter();
+                let mut effective = sets
+                    .next()
+                    .map(|set| self.effective(module, set))
+                    .transpose()?
+                    .unwrap_or_default();
+                for set in sets {
+                    effective = effective.union(self.effective(module, set)?);
+                }
+                effective
+            }
+            ElementSet::Intersection(sets) => {
+                let mut effective = Effective::default();
+                for set in sets {
+                    effective = effective.intersection(self.effective(module, set)?);
+                }
+                effective
+            }
+            ElementSet::Single(Value::String(string)) => Effective {
+                characters: Some(string.chars().map(u32::from).collect()),
+                ..Effective::default()
+            },
+            ElementSet::Single(value) => {
+                // Other single values, such as object identifiers, aren't
+                // visible to the encodings.
+                let value = match self.integer(module, value) {
+                    Err(SchemaError::Unsupported { .. }) => return Ok(Effective::default()),
+                    value => value?,
+                };
+                Effective {
+                    value: Some((value, value)),
+                    ..Effective::default()
+                }
+            }
+            ElementSet::Range {
+                start: Value::String(start),
+                end: Value::String(end),
+                ..
+            } => {
+                let (Some(start), Some(end)) = (start.chars().next(), end.chars().next()) else {
+                    return Err(SchemaError::InvalidType {
+                        reason: "empty character range".into(),
+                    });
+                };
+                Effective {
+                    characters: Some((u32::from(start)..=u32::from(end)).collect()),
+                    ..Effective::default()
+                }
+            }
+            ElementSet::Range {
+                start,
+                start_exclusive,
+                end,
+                end_exclusive,
+            } => {
+                let start = self.integer(module, start)?;
+                let end = self.integer(module, end)?;
+                Effective {
+                    value: Some((
+                        start.map(|start| start + i128::from(*start_exclusive)),
+                        end.map(|end| end - i128::from(*end_exclusive)),
+                    )),
+                    ..Effective::default()
+                }
+            }
+            ElementSet::Except(set, excluded) => {
+                let excluded = self.effective(module, excluded)?;
+                self.effective(module, set)?.except(&excluded)
+            }
+            ElementSet::Size(constraint) => {
+                let inner = self.effective(module, &constraint.root)?;
+                Effective {
+                    size: inner.value,
+                    size_set: inner.value_set,
+                    size_extensible: constraint.extensible,
+                    ..Effective::default()
+                }
+            }
+            ElementSet::From(constraint) => {
+                let inner = self.effective(module, &constraint.root)?;
+                Effective {
+                    alphabet: inner.characters,
+                    alphabet_set: inner.character_set,
+                    alphabet_extensible: constraint.extensible,
+                    ..Effective::default()
+                }
+            }
+            ElementSet::Contained(ty) => {
+                // References to information object sets are parsed as types,
+                // and are ignored like other table constraints.
+                let Ok(descriptor) = self.build(module, ty) else {
+                    return Ok(Effective::default());
+                };
+                let constraints = descriptor.constraints.get();
+                Effective {
+                    value: constraints.value().map(|value| bounds(&value.constraint)),
+                    value_set: constraints
+                        .value()
+                        .and_then(|value| value.constraint.set())
+                        .map(|set| set_ranges(&set)),
+                    size: constraints.size().map(|size| bounds(&size.constraint)),
+                    size_set: constraints
+                        .size()
+                        .and_then(|size| size.constraint.set())
+                        .map(|set| set_ranges(&set)),
+                    alphabet: constraints
+                        .permitted_alphabet()
+                        .map(|alphabet| alphabet.constraint.as_inner().to_vec()),
+                    alphabet_set: constraints
+                        .permitted_alphabet()
+                        .and_then(|alphabet| alphabet.constraint.set()),
+                    ..Effective::default()
+                }
+            }
+            ElementSet::Unsupported => Effective::default(),
+        })
+    }
+}
+
+/// Applies `tag` to `descriptor`. Untagged `CHOICE` types and open types,
+/// which are the types without a tag of their own, are always tagged
+/// explicitly.
+fn tagged(
+    arena: &mut Arena,
+    descriptor: TypeDescriptor<'static>,
+    tag: Tag,
+    explicit: bool,
+) -> TypeDescriptor<'static> {
+    if explicit || descriptor.tag == Tag::EOC {
+        TypeDescriptor::new(
+            descriptor.identifier,
+            tag,
+            TypeKind::Explicit {
+                inner: arena.alloc(descriptor),
+            },
+        )
+    } else {
+        descriptor.with_tag(tag)
+    }
+}
+
+fn automatic_tag(
+    arena: &mut Arena,
+    descriptor: TypeDescriptor<'static>,
+    number: u32,
+) -> TypeDescriptor<'static> {
+    tagged(arena, descriptor, Tag::new(Class::Context, number), false)
+}
+
+fn automatic_tags(arena: &mut Arena, fields: &mut [FieldDescriptor<'static>]) {
+    for (number, field) in (0..).zip(fields) {
+        let ty = automatic_tag(arena, *field.ty, number);
+        field.ty = arena.alloc(ty);
+    }
+}
+
+/// Applies `constraints` to `descriptor`, which for explicitly tagged types
+/// applies to the type inside of the prefix.
+fn constrained(
+    arena: &mut Arena,
+    descriptor: TypeDescriptor<'static>,
+    constraints: Constraints,
+) -> TypeDescriptor<'static> {
+    match descriptor.kind {
+        TypeKind::Explicit { inner } => {
+            let inner = constrained(arena, *inner, constraints);
+            TypeDescriptor {
+                kind: TypeKind::Explicit {
+                    inner: arena.alloc(inner),
+                },
+                ..descriptor
+            }
+        }
+        _ => descriptor.with_constraints(constraints),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schema(body: &str) -> Schema {
+        Schema::parse(&alloc::format!(
+            "Test DEFINITIONS AUTOMATIC TAGS ::= BEGIN {body} END"
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn automatic_tags() {
+        let schema = schema(
+            "Seq ::= SEQUENCE { a INTEGER, b CHOICE { x BOOLEAN, y NULL }, ..., c BOOLEAN }",
+        );
+        let TypeKind::Sequence {
+            fields,
+            extended_fields: Some(extended_fields),
+        } = schema.get("Seq").unwrap().kind
+        else {
+            panic!("expected an extensible SEQUENCE");
+        };
+        assert_eq!(fields[0].ty.tag, Tag::new(Class::Context, 0));
+        assert!(matches!(fields[0].ty.kind, TypeKind::Integer));
+        assert_eq!(fields[1].ty.tag, Tag::new(Class::Context, 1));
+        assert!(matches!(fields[1].ty.kind, TypeKind::Explicit { .. }));
+        assert_eq!(extended_fields[0].ty.tag, Tag::new(Class::Context, 2));
+    }
+
+    #[test]
+    fn constraints() {
+        let schema = schema(
+            "max INTEGER ::= 10
+             Small ::= INTEGER (0..max, ...)
+             Union ::= INTEGER (1 | 5..7)
+             Name ::= IA5String (SIZE (1..8) ^ FROM (\"a\"..\"z\" | \"-\"))
+             Key ::= OCTET STRING (SIZE (8 | 16 | 32))
+             Code ::= PrintableString (FROM (\"A\"..\"Z\" EXCEPT \"O\"))",
+        );
+        let small = schema.get("Small").unwrap().constraints.get();
+        assert_eq!(
+            small.value().map(|value| *value.constraint),
+            Some(Bounded::const_new(0, 10))
+        );
+        assert!(small.extensible());
+        assert_eq!(
+            schema
+                .get("Union")
+                .unwrap()
+                .constraints
+                .get()
+                .value()
+                .map(|value| *value.constraint),
+            Some(Bounded::const_new(1, 7))
+        );
+        let union = schema.get("Union").unwrap().constraints.get();
+        let union = union.value().unwrap().constraint;
+        assert!(union.permits(&1) && !union.permits(&3) && union.permits(&6));
+
+        let name = schema.get("Name").unwrap().constraints.get();
+        assert_eq!(
+            name.size().map(|size| *size.constraint),
+            Some(Bounded::const_new(1, 8))
+        );
+        assert_eq!(
+            name.permitted_alphabet()
+                .map(|alphabet| alphabet.constraint.as_inner().len()),
+            Some(27)
+        );
+
+        let key = schema.get("Key").unwrap().constraints.get();
+        let key = key.size().unwrap().constraint;
+        assert_eq!(*key, Bounded::const_new(8, 32));
+        assert_eq!(key.to_string(), "8 | 16 | 32");
+
+        // `EXCEPT` isn't visible to the encodings.
+        let code = schema.get("Code").unwrap().constraints.get();
+        let code = code.permitted_alphabet().unwrap().constraint;
+        assert_eq!(code.as_inner().len(), 26);
+        assert!(code.permits(u32::from('A')) && !code.permits(u32::from('O')));
+    }
+
+    #[test]
+    fn components() {
+        let schema = Schema::parse(
+            "Test DEFINITIONS IMPLICIT TAGS EXTENSIBILITY IMPLIED ::= BEGIN
+                Base ::= SEQUENCE { a [0] INTEGER, b [1] BOOLEAN OPTIONAL }
+                Derived ::= SEQUENCE {
+                    COMPONENTS OF Base,
+                    c [2] EXPLICIT NULL,
+                    ...,
+                    [[ d [3] INTEGER, e [4] INTEGER ]]
+                }
+                Level ::= ENUMERATED { high(2), low, medium(1) }
+            END",
+        )
+        .unwrap();
+
+        let TypeKind::Sequence {
+            fields,
+            extended_fields: Some(extended_fields),
+        } = schema.get("Derived").unwrap().kind
+        else {
+            panic!("expected an extensible SEQUENCE");
+        };
+        assert_eq!(
+            fields.iter().map(|field| field.name).collect::<Vec<_>>(),
+            ["a", "b", "c"]
+        );
+        assert_eq!(fields[1].presence, FieldPresence::Optional);
+        assert!(matches!(fields[2].ty.kind, TypeKind::Explicit { .. }));
+        assert_eq!(extended_fields.len(), 1);
+        assert!(matches!(
+            extended_fields[0].ty.kind,
+            TypeKind::Sequence { fields, .. } if fields.len() == 2
+        ));
+
+        let TypeKind::Enumerated {
+            variants,
+            extended_variants: Some(_),
+        } = schema.get("Level").unwrap().kind
+        else {
+            panic!("expected an extensible ENUMERATED");
+        };
+        assert_eq!(
+            variants
+                .iter()
+                .map(|variant| (variant.name, variant.discriminant))
+                .collect::<Vec<_>>(),
+            [("low", 0), ("medium", 1), ("high", 2)]
+        );
+    }
+
+    #[test]
+    fn recursive_types() {
+        let schema = schema(
+            "List ::= SEQUENCE { next List OPTIONAL }
+             Alias ::= List
+             Node ::= CHOICE { branch SEQUENCE OF Node, leaf Leaf }
+             Leaf ::= CHOICE { flag BOOLEAN, ... }",
+        );
+        let list = schema.get("List").unwrap();
+        let TypeKind::Sequence { fields, .. } = list.kind else {
+            panic!("expected a SEQUENCE");
+        };
+        let TypeKind::Reference(reference) = fields[0].ty.kind else {
+            panic!("expected a reference");
+        };
+        assert!(core::ptr::eq(reference.get().unwrap(), list));
+        assert_eq!(fields[0].ty.tag, Tag::new(Class::Context, 0));
+        assert!(matches!(
+            fields[0].ty.resolve().kind,
+            TypeKind::Sequence { .. }
+        ));
+        assert_eq!(schema.get("Alias").unwrap().identifier, Some("Alias"));
+
+        let node = schema.get("Node").unwrap();
+        assert!(node.is_choice());
+        assert!(node.contains_tag(Tag::new(Class::Context, 1)));
+        assert!(!node.contains_tag(Tag::BOOL));
+    }
+
+    #[test]
+    fn errors() {
+        let schema = schema(
+            "A ::= B
+             B ::= A
+             Tagged ::= [0] Tagged
+             Broken ::= SEQUENCE { next Dependent, value Undefined }
+             Dependent ::= SEQUENCE { value Broken OPTIONAL }
+             Param { T } ::= SEQUENCE { value T }
+             Missing ::= SEQUENCE { value Undefined }",
+        );
+        for name in ["A", "B", "Tagged"] {
+            assert!(matches!(
+                schema.get(name),
+                Err(SchemaError::RecursiveType { .. })
+            ));
+        }
+        // Types that contain a type which fails to resolve fail too, even
+        // when they were resolved while it was in progress.
+        for name in ["Broken", "Dependent"] {
+            assert_eq!(
+                schema.get(name).unwrap_err(),
+                SchemaError::UndefinedReference {
+                    name: "Undefined".into()
+                }
+            );
+        }
+        assert!(matches!(
+            schema.get("Param"),
+            Err(SchemaError::Unsupported { .. })
+        ));
+        assert_eq!(
+            schema.get("Missing").unwrap_err(),
+            SchemaError::UndefinedReference {
+                name: "Undefined".into()
+            }
+        );
+        assert!(matches!(
+            Schema::parse("Test DEFINITIONS ::= BEGIN A ::= SEQUENCE { a INTEGER"),
+            Err(SchemaError::Syntax { .. })
+        ));
+    }
+
+    #[test]
+    fn owned_names() {
+        let body = "Seq ::= SEQUENCE { name IA5String (FROM (\"a\"..\"z\")), alias Seq OPTIONAL }";
+        let first = schema(body);
+        let second = schema(body);
+        fn fields(schema: &Schema) -> (FieldDescriptor<'_>, FieldDescriptor<'_>) {
+            let TypeKind::Sequence { fields, .. } = schema.get("Seq").unwrap().kind else {
+                panic!("expected a SEQUENCE");
+            };
+            (fields[0], fields[1])
+        }
+
+        // Names and tables are allocated by each schema, and shared between
+        // the descriptors of a schema only.
+        let ((first_name, first_alias), (second_name, _)) = (fields(&first), fields(&second));
+        assert_eq!(first_name.name, second_name.name);
+        assert!(!core::ptr::eq(first_name.name, second_name.name));
+        assert!(!core::ptr::eq(
+            first_name.ty.constraints.permitted_alphabet().unwrap(),
+            second_name.ty.constraints.permitted_alphabet().unwrap(),
+        ));
+        assert!(core::ptr::eq(
+            first_alias.ty.identifier.unwrap(),
+            first.get("Seq").unwrap().identifier.unwrap(),
+        ));
+    }
+}
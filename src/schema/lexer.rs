@@ -0,0 +1,239 @@
+//! Splits ASN.1 module text into lexical items (X.680 clause 12).
+
+use alloc::{string::String, vec::Vec};
+
+use crate::error::SchemaError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum Token {
+    /// A reference or identifier, including keywords.
+    Word(String),
+    Number(u128),
+    /// A character string literal, with doubled quotes unescaped.
+    CString(String),
+    /// A binary or hexadecimal string literal, the radix is the suffix.
+    BinaryString(String, char),
+    Assignment,
+    Ellipsis,
+    Range,
+    LeftVersionBrackets,
+    RightVersionBrackets,
+    Symbol(char),
+}
+
+impl Token {
+    pub(super) fn describe(&self) -> String {
+        match self {
+            Self::Word(word) => alloc::format!("`{word}`"),
+            Self::Number(number) => alloc::format!("`{number}`"),
+            Self::CString(string) => alloc::format!("\"{string}\""),
+            Self::BinaryString(string, radix) => alloc::format!("'{string}'{radix}"),
+            Self::Assignment => "`::=`".into(),
+            Self::Ellipsis => "`...`".into(),
+            Self::Range => "`..`".into(),
+            Self::LeftVersionBrackets => "`[[`".into(),
+            Self::RightVersionBrackets => "`]]`".into(),
+            Self::Symbol(symbol) => alloc::format!("`{symbol}`"),
+        }
+    }
+}
+
+/// Returns the tokens of `input` along with the line they start on.
+pub(super) fn tokenize(input: &str) -> Result<Vec<(Token, usize)>, SchemaError> {
+    let mut tokens = Vec::new();
+    let mut chars = input.char_indices().peekable();
+    let mut line = 1;
+
+    let syntax = |line: usize, expected: &'static str, found: &str| SchemaError::Syntax {
+        line,
+        expected,
+        found: found.into(),
+    };
+
+    while let Some((start, c)) = chars.next() {
+        let rest = &input[start..];
+        match c {
+            '\n' => line += 1,
+            c if c.is_whitespace() => {}
+            '-' if rest.starts_with("--") => {
+                // A comment ends at the next `--` or the end of the line.
+                chars.next();
+                loop {
+                    match chars.next() {
+                        None => break,
+                        Some((_, '\n')) => {
+                            line += 1;
+                            break;
+                        }
+                        Some((_, '-')) if chars.peek().is_some_and(|(_, c)| *c == '-') => {
+                            chars.next();
+                            break;
+                        }
+                        Some(_) => {}
+                    }
+                }
+            }
+            '/' if rest.starts_with("/*") => {
+                chars.next();
+                let mut depth = 1;
+                while depth > 0 {
+                    match chars.next() {
+                        None => return Err(syntax(line, "end of comment", "end of input")),
+                        Some((_, '\n')) => line += 1,
+                        Some((_, '/')) if chars.peek().is_some_and(|(_, c)| *c == '*') => {
+                            chars.next();
+                            depth += 1;
+                        }
+                        Some((_, '*')) if chars.peek().is_some_and(|(_, c)| *c == '/') => {
+                            chars.next();
+                            depth -= 1;
+                        }
+                        Some(_) => {}
+                    }
+                }
+            }
+            c if c.is_ascii_alphabetic() => {
+                let mut word = String::from(c);
+                while let Some(&(index, c)) = chars.peek() {
+                    // A hyphen is part of a reference unless it is trailing or
+                    // starts a comment.
+                    let hyphen = c == '-'
+                        && input[index + 1..]
+                            .chars()
+                            .next()
+                            .is_some_and(|next| next.is_ascii_alphanumeric());
+                    if !(c.is_ascii_alphanumeric() || hyphen) {
+                        break;
+                    }
+                    word.push(c);
+                    chars.next();
+                }
+                tokens.push((Token::Word(word), line));
+            }
+            c if c.is_ascii_digit() => {
+                let mut number = String::from(c);
+                while let Some(&(_, c)) = chars.peek() {
+                    if !c.is_ascii_digit() {
+                        break;
+                    }
+                    number.push(c);
+                    chars.next();
+                }
+                let number = number
+                    .parse()
+                    .map_err(|_| syntax(line, "a number", &number))?;
+                tokens.push((Token::Number(number), line));
+            }
+            '"' => {
+                let mut string = String::new();
+                loop {
+                    match chars.next() {
+                        None => return Err(syntax(line, "`\"`", "end of input")),
+                        Some((_, '"')) if chars.peek().is_some_and(|(_, c)| *c == '"') => {
+                            chars.next();
+                            string.push('"');
+                        }
+                        Some((_, '"')) => break,
+                        Some((_, c)) => {
+                            if c == '\n' {
+                                line += 1;
+                            }
+                            string.push(c);
+                        }
+                    }
+                }
+                tokens.push((Token::CString(string), line));
+            }
+            '\'' => {
+                let mut string = String::new();
+                loop {
+                    match chars.next() {
+                        None => return Err(syntax(line, "`'`", "end of input")),
+                        Some((_, '\'')) => break,
+                        Some((_, c)) if c.is_whitespace() => {
+                            if c == '\n' {
+                                line += 1;
+                            }
+                        }
+                        Some((_, c)) => string.push(c),
+                    }
+                }
+                match chars.next() {
+                    Some((_, radix @ ('B' | 'H'))) => {
+                        tokens.push((Token::BinaryString(string, radix), line));
+                    }
+                    _ => return Err(syntax(line, "`B` or `H`", "end of string")),
+                }
+            }
+            ':' if rest.starts_with("::=") => {
+                chars.next();
+                chars.next();
+                tokens.push((Token::Assignment, line));
+            }
+            '.' if rest.starts_with("...") => {
+                chars.next();
+                chars.next();
+                tokens.push((Token::Ellipsis, line));
+            }
+            '.' if rest.starts_with("..") => {
+                chars.next();
+                tokens.push((Token::Range, line));
+            }
+            '[' if rest.starts_with("[[") => {
+                chars.next();
+                tokens.push((Token::LeftVersionBrackets, line));
+            }
+            ']' if rest.starts_with("]]") => {
+                chars.next();
+                tokens.push((Token::RightVersionBrackets, line));
+            }
+            '{' | '}' | '(' | ')' | '[' | ']' | ',' | ';' | ':' | '|' | '^' | '<' | '>' | '@'
+            | '!' | '.' | '&' | '-' | '=' | '*' => tokens.push((Token::Symbol(c), line)),
+            c => {
+                let mut found = String::new();
+                found.push(c);
+                return Err(syntax(line, "a lexical item", &found));
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(input: &str) -> Vec<Token> {
+        tokenize(input)
+            .unwrap()
+            .into_iter()
+            .map(|(token, _)| token)
+            .collect()
+    }
+
+    #[test]
+    fn comments() {
+        assert_eq!(
+            words("a-b -- comment -- c\nd /* nested /* comment */ */ e--"),
+            ["a-b", "c", "d", "e"]
+                .map(|word| Token::Word(word.into()))
+                .to_vec()
+        );
+    }
+
+    #[test]
+    fn symbols() {
+        assert_eq!(
+            words("::= ... .. [[ ]] 'FF'H"),
+            [
+                Token::Assignment,
+                Token::Ellipsis,
+                Token::Range,
+                Token::LeftVersionBrackets,
+                Token::RightVersionBrackets,
+                Token::BinaryString("FF".into(), 'H'),
+            ]
+        );
+    }
+}
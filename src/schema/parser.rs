@@ -0,0 +1,1070 @@
+//! Parses the lexical items of ASN.1 modules into an abstract syntax tree,
+//! which is resolved into type descriptors by the parent module.
+
+use alloc::{boxed::Box, string::String, vec::Vec};
+
+use super::lexer::Token;
+use crate::{
+    error::SchemaError,
+    types::{
+        descriptor::{Describe, TypeDescriptor, TypeKind},
+        Class, Identifier, Tag,
+    },
+};
+
+/// The default tagging mode of a module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum TagDefault {
+    Explicit,
+    Implicit,
+    Automatic,
+}
+
+#[derive(Debug)]
+pub(super) struct ModuleDefinition {
+    pub name: String,
+    pub tagging: TagDefault,
+    pub extensibility_implied: bool,
+    /// The imported symbols and the module they're imported from.
+    pub imports: Vec<(String, String)>,
+    pub types: Vec<(String, Result<Type, SchemaError>)>,
+    pub values: Vec<(String, Value)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum TagMode {
+    Explicit,
+    Implicit,
+}
+
+#[derive(Debug, Clone)]
+pub(super) enum Type {
+    Builtin(&'static TypeDescriptor<'static>),
+    Enumerated {
+        root: Vec<(String, Option<Value>)>,
+        extension: Option<Vec<(String, Option<Value>)>>,
+    },
+    Sequence(ComponentList),
+    Set(ComponentList),
+    Choice(ComponentList),
+    SequenceOf(Box<Type>),
+    SetOf(Box<Type>),
+    Tagged {
+        tag: Tag,
+        mode: Option<TagMode>,
+        inner: Box<Type>,
+    },
+    Constrained {
+        inner: Box<Type>,
+        constraint: SubtypeConstraint,
+    },
+    Reference {
+        module: Option<String>,
+        name: String,
+    },
+    Unsupported(&'static str),
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct ComponentList {
+    pub root: Vec<Component>,
+    /// The extension additions, `Some` if the list has an extension marker.
+    pub extension: Option<Vec<Addition>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Presence {
+    Required,
+    Optional,
+    Default,
+}
+
+#[derive(Debug, Clone)]
+pub(super) enum Component {
+    Named {
+        name: String,
+        ty: Type,
+        presence: Presence,
+    },
+    ComponentsOf(Type),
+}
+
+#[derive(Debug, Clone)]
+pub(super) enum Addition {
+    Component(Component),
+    Group(Vec<Component>),
+}
+
+#[derive(Debug, Clone)]
+pub(super) enum Value {
+    Integer(i128),
+    Reference(String),
+    Min,
+    Max,
+    String(String),
+    Other,
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct SubtypeConstraint {
+    pub root: ElementSet,
+    pub extensible: bool,
+}
+
+#[derive(Debug, Clone)]
+pub(super) enum ElementSet {
+    Union(Vec<ElementSet>),
+    Intersection(Vec<ElementSet>),
+    Single(Value),
+    Range {
+        start: Value,
+        start_exclusive: bool,
+        end: Value,
+        end_exclusive: bool,
+    },
+    /// The elements of the first set which aren't in the second.
+    Except(Box<ElementSet>, Box<ElementSet>),
+    Size(Box<SubtypeConstraint>),
+    From(Box<SubtypeConstraint>),
+    Contained(Box<Type>),
+    /// A constraint that isn't applied, such as an inner subtype or a table
+    /// constraint.
+    Unsupported,
+}
+
+/// Parses every module definition in `tokens`.
+pub(super) fn parse_modules(
+    tokens: &[(Token, usize)],
+) -> Result<Vec<ModuleDefinition>, SchemaError> {
+    let mut parser = Parser::new(tokens);
+    let mut modules = Vec::new();
+    while parser.peek().is_some() {
+        modules.push(parser.module()?);
+    }
+    Ok(modules)
+}
+
+struct Parser<'t> {
+    tokens: &'t [(Token, usize)],
+    position: usize,
+}
+
+/// The kind of an assignment, determined by the items before `::=`.
+enum Header {
+    Type(String),
+    Value(String),
+    Parameterized(String),
+    /// Object set assignments, which aren't represented by descriptors.
+    Ignored,
+}
+
+fn is_type_reference(word: &str) -> bool {
+    word.starts_with(|c: char| c.is_ascii_uppercase())
+}
+
+impl<'t> Parser<'t> {
+    fn new(tokens: &'t [(Token, usize)]) -> Self {
+        Self {
+            tokens,
+            position: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<&'t Token> {
+        self.peek_at(0)
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<&'t Token> {
+        self.tokens
+            .get(self.position + offset)
+            .map(|(token, _)| token)
+    }
+
+    fn next(&mut self) -> Option<&'t Token> {
+        let token = self.peek();
+        self.position += usize::from(token.is_some());
+        token
+    }
+
+    fn error(&self, expected: &'static str) -> SchemaError {
+        let (found, line) = match self.tokens.get(self.position) {
+            Some((token, line)) => (token.describe(), *line),
+            None => (
+                "end of input".into(),
+                self.tokens.last().map_or(1, |(_, line)| *line),
+            ),
+        };
+        SchemaError::Syntax {
+            line,
+            expected,
+            found,
+        }
+    }
+
+    fn peek_word(&self) -> Option<&'t str> {
+        match self.peek() {
+            Some(Token::Word(word)) => Some(word),
+            _ => None,
+        }
+    }
+
+    fn is_symbol(&self, symbol: char) -> bool {
+        self.peek() == Some(&Token::Symbol(symbol))
+    }
+
+    fn is_word(&self, word: &str) -> bool {
+        self.peek_word() == Some(word)
+    }
+
+    fn eat(&mut self, token: &Token) -> bool {
+        let matches = self.peek() == Some(token);
+        self.position += usize::from(matches);
+        matches
+    }
+
+    fn eat_symbol(&mut self, symbol: char) -> bool {
+        self.eat(&Token::Symbol(symbol))
+    }
+
+    fn eat_word(&mut self, word: &str) -> bool {
+        let matches = self.is_word(word);
+        self.position += usize::from(matches);
+        matches
+    }
+
+    fn expect(&mut self, token: &Token, expected: &'static str) -> Result<(), SchemaError> {
+        if self.eat(token) {
+            Ok(())
+        } else {
+            Err(self.error(expected))
+        }
+    }
+
+    fn expect_symbol(&mut self, symbol: char, expected: &'static str) -> Result<(), SchemaError> {
+        self.expect(&Token::Symbol(symbol), expected)
+    }
+
+    fn expect_word(&mut self, word: &str, expected: &'static str) -> Result<(), SchemaError> {
+        if self.eat_word(word) {
+            Ok(())
+        } else {
+            Err(self.error(expected))
+        }
+    }
+
+    fn identifier(&mut self, expected: &'static str) -> Result<String, SchemaError> {
+        match self.peek_word() {
+            Some(word) => {
+                self.position += 1;
+                Ok(word.into())
+            }
+            None => Err(self.error(expected)),
+        }
+    }
+
+    /// Skips a bracketed group of items, starting at an opening bracket.
+    fn skip_group(&mut self) -> Result<(), SchemaError> {
+        let mut depth = 0usize;
+        loop {
+            match self.next() {
+                Some(Token::Symbol('{' | '(' | '[') | Token::LeftVersionBrackets) => depth += 1,
+                Some(Token::Symbol('}' | ')' | ']') | Token::RightVersionBrackets) => {
+                    depth = depth.saturating_sub(1);
+                    if depth == 0 {
+                        return Ok(());
+                    }
+                }
+                Some(_) => {}
+                None => return Err(self.error("closing bracket")),
+            }
+        }
+    }
+
+    fn module(&mut self) -> Result<ModuleDefinition, SchemaError> {
+        let name = self.identifier("module reference")?;
+        if self.is_symbol('{') {
+            self.skip_group()?;
+        }
+        // An optional IRI value for the module.
+        if matches!(self.peek(), Some(Token::CString(_))) {
+            self.next();
+        }
+        self.expect_word("DEFINITIONS", "`DEFINITIONS`")?;
+
+        let mut tagging = TagDefault::Explicit;
+        let mut extensibility_implied = false;
+        while !self.eat(&Token::Assignment) {
+            match self.next() {
+                Some(Token::Word(word)) if word == "EXPLICIT" => tagging = TagDefault::Explicit,
+                Some(Token::Word(word)) if word == "IMPLICIT" => tagging = TagDefault::Implicit,
+                Some(Token::Word(word)) if word == "AUTOMATIC" => {
+                    tagging = TagDefault::Automatic;
+                }
+                Some(Token::Word(word)) if word == "IMPLIED" => extensibility_implied = true,
+                // `TAGS`, `EXTENSIBILITY` and encoding references.
+                Some(Token::Word(_)) => {}
+                _ => {
+                    self.position = self.position.saturating_sub(1);
+                    return Err(self.error("`::=`"));
+                }
+            }
+        }
+        self.expect_word("BEGIN", "`BEGIN`")?;
+
+        if self.eat_word("EXPORTS") {
+            while !self.eat_symbol(';') {
+                if self.next().is_none() {
+                    return Err(self.error("`;`"));
+                }
+            }
+        }
+
+        let imports = if self.eat_word("IMPORTS") {
+            self.imports()?
+        } else {
+            Vec::new()
+        };
+
+        let end = self.module_end()?;
+        let body = Parser::new(&self.tokens[..end]);
+        let mut module = ModuleDefinition {
+            name,
+            tagging,
+            extensibility_implied,
+            imports,
+            types: Vec::new(),
+            values: Vec::new(),
+        };
+        body.assignments(self.position, &mut module);
+        self.position = end + 1;
+
+        Ok(module)
+    }
+
+    fn imports(&mut self) -> Result<Vec<(String, String)>, SchemaError> {
+        let mut imports = Vec::new();
+        let mut symbols = Vec::new();
+        loop {
+            if self.eat_symbol(';') {
+                return Ok(imports);
+            }
+            symbols.push(self.identifier("imported symbol")?);
+            if self.is_symbol('{') {
+                self.skip_group()?;
+            }
+            if self.eat_symbol(',') {
+                continue;
+            }
+            self.expect_word("FROM", "`FROM`")?;
+            let module = self.identifier("module reference")?;
+            // The assigned identifier of the module, which is ambiguous with
+            // the next symbol unless that symbol is followed by `,` or `FROM`.
+            if self.is_symbol('{') {
+                self.skip_group()?;
+            } else if self
+                .peek_word()
+                .is_some_and(|word| !is_type_reference(word))
+                && !matches!(self.peek_at(1), Some(Token::Symbol(',' | '{')))
+                && !matches!(self.peek_at(1), Some(Token::Word(word)) if word == "FROM")
+            {
+                self.next();
+            }
+            imports.extend(symbols.drain(..).map(|symbol| (symbol, module.clone())));
+        }
+    }
+
+    /// Returns the position of the `END` of the current module.
+    fn module_end(&self) -> Result<usize, SchemaError> {
+        let mut depth = 0usize;
+        for (index, (token, _)) in self.tokens.iter().enumerate().skip(self.position) {
+            match token {
+                Token::Symbol('{' | '(' | '[') | Token::LeftVersionBrackets => depth += 1,
+                Token::Symbol('}' | ')' | ']') | Token::RightVersionBrackets => {
+                    depth = depth.saturating_sub(1);
+                }
+                Token::Word(word) if depth == 0 && word == "END" => return Ok(index),
+                _ => {}
+            }
+        }
+        Err(SchemaError::Syntax {
+            line: self.tokens.last().map_or(1, |(_, line)| *line),
+            expected: "`END`",
+            found: "end of input".into(),
+        })
+    }
+
+    /// Splits the module body starting at `start` into assignments and parses
+    /// each of them, so that an error in one assignment doesn't prevent the
+    /// others from being used.
+    fn assignments(&self, start: usize, module: &mut ModuleDefinition) {
+        let words = |index: usize| match self.tokens.get(index) {
+            Some((Token::Word(word), _)) if index >= start => Some(word.as_str()),
+            _ => None,
+        };
+
+        let mut classes: Vec<&str> = ["TYPE-IDENTIFIER", "ABSTRACT-SYNTAX"].into();
+        let mut depth = 0usize;
+        let mut assignments = Vec::new();
+        for (index, (token, _)) in self.tokens.iter().enumerate().skip(start) {
+            match token {
+                Token::Symbol('{' | '(' | '[') | Token::LeftVersionBrackets => depth += 1,
+                Token::Symbol('}' | ')' | ']') | Token::RightVersionBrackets => {
+                    depth = depth.saturating_sub(1);
+                }
+                Token::Assignment if depth == 0 => {
+                    assignments.push(index);
+                    if let (Some(name), Some(body)) = (words(index - 1), words(index + 1)) {
+                        if classes.contains(&body) || body == "CLASS" {
+                            classes.push(name);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        // By convention classes are written in upper case, so an imported
+        // symbol in upper case is taken to be a class.
+        classes.extend(
+            module
+                .imports
+                .iter()
+                .map(|(symbol, _)| symbol.as_str())
+                .filter(|symbol| {
+                    symbol.len() > 1
+                        && symbol
+                            .chars()
+                            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '-')
+                }),
+        );
+
+        let headers = assignments
+            .iter()
+            .map(|&index| {
+                if let Some((Token::Symbol('}'), _)) = self.tokens.get(index - 1) {
+                    let mut depth = 0usize;
+                    let mut open = index - 1;
+                    while open > start {
+                        match &self.tokens[open].0 {
+                            Token::Symbol('}') => depth += 1,
+                            Token::Symbol('{') => {
+                                depth -= 1;
+                                if depth == 0 {
+                                    break;
+                                }
+                            }
+                            _ => {}
+                        }
+                        open -= 1;
+                    }
+                    let name = words(open - 1).unwrap_or_default();
+                    return (open - 1, Header::Parameterized(name.into()));
+                }
+                let body = self.tokens.get(index + 1).map(|(token, _)| token);
+                // The types of value assignments which are written as two words.
+                if let (Some(name), Some(first), Some(second)) = (
+                    words(index.saturating_sub(3)),
+                    words(index.saturating_sub(2)),
+                    words(index - 1),
+                ) {
+                    if !is_type_reference(name)
+                        && matches!(
+                            (first, second),
+                            ("OBJECT", "IDENTIFIER") | ("OCTET" | "BIT", "STRING")
+                        )
+                    {
+                        return (index - 3, Header::Value(name.into()));
+                    }
+                }
+                match (words(index.saturating_sub(2)), words(index - 1)) {
+                    (Some(name), Some(_)) if !is_type_reference(name) => {
+                        (index - 2, Header::Value(name.into()))
+                    }
+                    (Some(_), Some(class))
+                        if classes.contains(&class) && body == Some(&Token::Symbol('{')) =>
+                    {
+                        (index - 2, Header::Ignored)
+                    }
+                    (_, Some(name)) => (index - 1, Header::Type(name.into())),
+                    _ => (index, Header::Ignored),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        for (position, &index) in assignments.iter().enumerate() {
+            let end = headers
+                .get(position + 1)
+                .map_or(self.tokens.len(), |(start, _)| *start);
+            let mut parser = Parser::new(&self.tokens[..end]);
+            parser.position = index + 1;
+            match &headers[position].1 {
+                Header::Type(name) => {
+                    if parser.is_word("CLASS") || classes.contains(&name.as_str()) {
+                        continue;
+                    }
+                    let ty = parser.ty().and_then(|ty| match parser.peek() {
+                        None => Ok(ty),
+                        Some(_) => Err(parser.error("end of type assignment")),
+                    });
+                    module.types.push((name.clone(), ty));
+                }
+                Header::Parameterized(name) => module
+                    .types
+                    .push((name.clone(), Ok(Type::Unsupported("parameterized types")))),
+                Header::Value(name) => {
+                    let value = match parser.value() {
+                        Ok(value) if parser.peek().is_none() => value,
+                        _ => Value::Other,
+                    };
+                    module.values.push((name.clone(), value));
+                }
+                Header::Ignored => {}
+            }
+        }
+    }
+
+    fn ty(&mut self) -> Result<Type, SchemaError> {
+        let mut ty = if self.is_symbol('[') {
+            self.tagged()?
+        } else {
+            self.unconstrained()?
+        };
+        while self.is_symbol('(') {
+            ty = Type::Constrained {
+                inner: Box::new(ty),
+                constraint: self.constraint()?,
+            };
+        }
+        Ok(ty)
+    }
+
+    fn tagged(&mut self) -> Result<Type, SchemaError> {
+        self.expect_symbol('[', "`[`")?;
+        let class = match self.peek_word() {
+            Some("UNIVERSAL") => Class::Universal,
+            Some("APPLICATION") => Class::Application,
+            Some("PRIVATE") => Class::Private,
+            _ => Class::Context,
+        };
+        if class != Class::Context {
+            self.next();
+        }
+        let number = match self.next() {
+            Some(Token::Number(number)) => {
+                u32::try_from(*number).map_err(|_| SchemaError::Unsupported {
+                    construct: "tag numbers larger than 32 bits",
+                })?
+            }
+            Some(Token::Word(_)) => {
+                return Err(SchemaError::Unsupported {
+                    construct: "tag numbers defined by value references",
+                })
+            }
+            _ => {
+                self.position -= 1;
+                return Err(self.error("tag number"));
+            }
+        };
+        self.expect_symbol(']', "`]`")?;
+        let mode = if self.eat_word("EXPLICIT") {
+            Some(TagMode::Explicit)
+        } else if self.eat_word("IMPLICIT") {
+            Some(TagMode::Implicit)
+        } else {
+            None
+        };
+        Ok(Type::Tagged {
+            tag: Tag::new(class, number),
+            mode,
+            inner: Box::new(self.ty()?),
+        })
+    }
+
+    fn unconstrained(&mut self) -> Result<Type, SchemaError> {
+        fn builtin<T: Describe>() -> Type {
+            Type::Builtin(const { &T::DESCRIPTOR })
+        }
+
+        let Some(word) = self.peek_word() else {
+            return Err(self.error("type"));
+        };
+        self.next();
+
+        let ty = match word {
+            "BOOLEAN" => builtin::<bool>(),
+            "INTEGER" => {
+                if self.is_symbol('{') {
+                    self.skip_group()?;
+                }
+                builtin::<crate::types::Integer>()
+            }
+            "REAL" => Type::Builtin(
+                const { &TypeDescriptor::new(Identifier::REAL.0, Tag::REAL, TypeKind::Real) },
+            ),
+            "NULL" => builtin::<()>(),
+            "OBJECT" => {
+                self.expect_word("IDENTIFIER", "`IDENTIFIER`")?;
+                builtin::<crate::types::ObjectIdentifier>()
+            }
+            "BIT" => {
+                self.expect_word("STRING", "`STRING`")?;
+                if self.is_symbol('{') {
+                    self.skip_group()?;
+                }
+                builtin::<crate::types::BitString>()
+            }
+            "OCTET" => {
+                self.expect_word("STRING", "`STRING`")?;
+                builtin::<crate::types::OctetString>()
+            }
+            "UTF8String" => builtin::<crate::types::Utf8String>(),
+            "IA5String" => builtin::<crate::types::Ia5String>(),
+            "PrintableString" => builtin::<crate::types::PrintableString>(),
+            "VisibleString" | "ISO646String" => builtin::<crate::types::VisibleString>(),
+            "NumericString" => builtin::<crate::types::NumericString>(),
+            "BMPString" => builtin::<crate::types::BmpString>(),
+            "GeneralString" => builtin::<crate::types::GeneralString>(),
+            "GraphicString" => builtin::<crate::types::GraphicString>(),
+            "TeletexString" | "T61String" => builtin::<crate::types::TeletexString>(),
+            "UTCTime" => builtin::<crate::types::UtcTime>(),
+            "GeneralizedTime" => builtin::<crate::types::GeneralizedTime>(),
+            "DATE" => builtin::<crate::types::Date>(),
+            "TIME" => builtin::<crate::types::IsoTime>(),
+            "TIME-OF-DAY" => builtin::<crate::types::TimeOfDay>(),
+            "DATE-TIME" => builtin::<crate::types::DateTime>(),
+            "DURATION" => builtin::<crate::types::IsoDuration>(),
+            "RELATIVE-OID" => builtin::<crate::types::RelativeObjectIdentifier>(),
+            "OID-IRI" => builtin::<crate::types::OidIri>(),
+            "RELATIVE-OID-IRI" => builtin::<crate::types::RelativeOidIri>(),
+            "ANY" => {
+                if self.eat_word("DEFINED") {
+                    self.expect_word("BY", "`BY`")?;
+                    self.identifier("identifier")?;
+                }
+                builtin::<crate::types::Any>()
+            }
+            "ENUMERATED" => self.enumerated()?,
+            "SEQUENCE" | "SET" => {
+                let set = word == "SET";
+                if self.is_symbol('{') {
+                    let components = self.components(false)?;
+                    if set {
+                        Type::Set(components)
+                    } else {
+                        Type::Sequence(components)
+                    }
+                } else {
+                    self.collection(set)?
+                }
+            }
+            "CHOICE" => Type::Choice(self.components(true)?),
+            "ObjectDescriptor" => Type::Builtin(
+                const { &crate::types::GraphicString::DESCRIPTOR.with_tag(Tag::OBJECT_DESCRIPTOR) },
+            ),
+            "UniversalString" | "VideotexString" => {
+                Type::Unsupported("`UniversalString` and `VideotexString`")
+            }
+            "EXTERNAL" => builtin::<crate::types::External>(),
+            "EMBEDDED" => {
+                self.expect_word("PDV", "`PDV`")?;
+                builtin::<crate::types::EmbeddedPdv>()
+            }
+            "CHARACTER" => {
+                self.expect_word("STRING", "`STRING`")?;
+                builtin::<crate::types::CharacterString>()
+            }
+            "INSTANCE" => {
+                self.expect_word("OF", "`OF`")?;
+                self.identifier("class reference")?;
+                Type::Unsupported("`INSTANCE OF`")
+            }
+            word if !is_type_reference(word) && self.eat_symbol('<') => {
+                self.ty()?;
+                Type::Unsupported("selection types")
+            }
+            word if is_type_reference(word) => self.reference(word)?,
+            _ => {
+                self.position -= 1;
+                return Err(self.error("type"));
+            }
+        };
+        Ok(ty)
+    }
+
+    fn reference(&mut self, word: &str) -> Result<Type, SchemaError> {
+        let (module, name) = match (self.peek(), self.peek_at(1)) {
+            (Some(Token::Symbol('.')), Some(Token::Word(name))) => {
+                self.position += 2;
+                (Some(String::from(word)), name.clone())
+            }
+            _ => (None, String::from(word)),
+        };
+
+        // A reference to a field of an information object class.
+        if self.is_symbol('.') && self.peek_at(1) == Some(&Token::Symbol('&')) {
+            let mut field = String::new();
+            while self.eat_symbol('.') {
+                self.expect_symbol('&', "`&`")?;
+                field = self.identifier("field reference")?;
+            }
+            return Ok(if is_type_reference(&field) {
+                builtin_any()
+            } else if name == "TYPE-IDENTIFIER" || name == "ABSTRACT-SYNTAX" {
+                Type::Builtin(const { &crate::types::ObjectIdentifier::DESCRIPTOR })
+            } else {
+                Type::Unsupported("value fields of information object classes")
+            });
+        }
+
+        if self.is_symbol('{') {
+            self.skip_group()?;
+            return Ok(Type::Unsupported("parameterized types"));
+        }
+
+        Ok(Type::Reference { module, name })
+    }
+
+    /// Parses the rest of a `SEQUENCE OF` or `SET OF` type.
+    fn collection(&mut self, set: bool) -> Result<Type, SchemaError> {
+        let constraint = if self.is_symbol('(') {
+            Some(self.constraint()?)
+        } else if self.eat_word("SIZE") {
+            Some(SubtypeConstraint {
+                root: ElementSet::Size(Box::new(self.constraint()?)),
+                extensible: false,
+            })
+        } else {
+            None
+        };
+        self.expect_word("OF", "`OF`")?;
+        // The element may be named.
+        if self.peek_word().is_some_and(|word| {
+            !is_type_reference(word) && self.peek_at(1) != Some(&Token::Symbol('<'))
+        }) {
+            self.next();
+        }
+        let element = Box::new(self.ty()?);
+        let ty = if set {
+            Type::SetOf(element)
+        } else {
+            Type::SequenceOf(element)
+        };
+        Ok(match constraint {
+            Some(constraint) => Type::Constrained {
+                inner: Box::new(ty),
+                constraint,
+            },
+            None => ty,
+        })
+    }
+
+    fn enumerated(&mut self) -> Result<Type, SchemaError> {
+        self.expect_symbol('{', "`{`")?;
+        let mut root = Vec::new();
+        let mut extension: Option<Vec<_>> = None;
+        loop {
+            if self.eat(&Token::Ellipsis) {
+                if self.eat_symbol('!') {
+                    self.value()?;
+                }
+                extension = Some(Vec::new());
+            } else {
+                let name = self.identifier("enumeration item")?;
+                let value = if self.eat_symbol('(') {
+                    let value = self.value()?;
+                    self.expect_symbol(')', "`)`")?;
+                    Some(value)
+                } else {
+                    None
+                };
+                extension.as_mut().unwrap_or(&mut root).push((name, value));
+            }
+            if !self.eat_symbol(',') {
+                self.expect_symbol('}', "`,` or `}`")?;
+                return Ok(Type::Enumerated { root, extension });
+            }
+        }
+    }
+
+    /// Parses the components of a `SEQUENCE`, `SET` or `CHOICE` type.
+    fn components(&mut self, choice: bool) -> Result<ComponentList, SchemaError> {
+        self.expect_symbol('{', "`{`")?;
+        let mut list = ComponentList {
+            root: Vec::new(),
+            extension: None,
+        };
+        // Whether the second extension marker has been seen, after which
+        // components are in the root again.
+        let mut closed = false;
+        if self.eat_symbol('}') {
+            return Ok(list);
+        }
+        loop {
+            if self.eat(&Token::Ellipsis) {
+                if list.extension.is_some() {
+                    closed = true;
+                } else {
+                    if self.eat_symbol('!') {
+                        self.value()?;
+                    }
+                    list.extension = Some(Vec::new());
+                }
+            } else if self.eat(&Token::LeftVersionBrackets) {
+                let Some(additions) = list.extension.as_mut().filter(|_| !closed) else {
+                    self.position -= 1;
+                    return Err(self.error("component"));
+                };
+                // An optional version number.
+                if matches!(self.peek(), Some(Token::Number(_)))
+                    && self.peek_at(1) == Some(&Token::Symbol(':'))
+                {
+                    self.position += 2;
+                }
+                let mut group = Vec::new();
+                loop {
+                    group.push(self.component(choice)?);
+                    if !self.eat_symbol(',') {
+                        break;
+                    }
+                }
+                self.expect(&Token::RightVersionBrackets, "`]]`")?;
+                additions.push(Addition::Group(group));
+            } else {
+                let component = self.component(choice)?;
+                match list.extension.as_mut() {
+                    Some(additions) if !closed => additions.push(Addition::Component(component)),
+                    _ => list.root.push(component),
+                }
+            }
+            if !self.eat_symbol(',') {
+                self.expect_symbol('}', "`,` or `}`")?;
+                return Ok(list);
+            }
+        }
+    }
+
+    fn component(&mut self, choice: bool) -> Result<Component, SchemaError> {
+        if !choice
+            && self.is_word("COMPONENTS")
+            && self.peek_at(1) == Some(&Token::Word("OF".into()))
+        {
+            self.position += 2;
+            return Ok(Component::ComponentsOf(self.ty()?));
+        }
+        let name = match self.peek_word() {
+            Some(word) if !is_type_reference(word) => {
+                self.next();
+                String::from(word)
+            }
+            _ => return Err(self.error("component name")),
+        };
+        let ty = self.ty()?;
+        let presence = if choice {
+            Presence::Required
+        } else if self.eat_word("OPTIONAL") {
+            Presence::Optional
+        } else if self.eat_word("DEFAULT") {
+            self.value()?;
+            Presence::Default
+        } else {
+            Presence::Required
+        };
+        Ok(Component::Named { name, ty, presence })
+    }
+
+    fn value(&mut self) -> Result<Value, SchemaError> {
+        let value = match self.peek() {
+            Some(Token::Number(number)) => {
+                Value::Integer(i128::try_from(*number).map_err(|_| self.error("integer value"))?)
+            }
+            Some(Token::Symbol('-')) => {
+                self.next();
+                match self.peek() {
+                    Some(Token::Number(number)) => Value::Integer(
+                        i128::try_from(*number)
+                            .map(|number| -number)
+                            .map_err(|_| self.error("integer value"))?,
+                    ),
+                    _ => return Err(self.error("number")),
+                }
+            }
+            Some(Token::CString(string)) => Value::String(string.clone()),
+            Some(Token::Word(word)) if word == "MIN" => Value::Min,
+            Some(Token::Word(word)) if word == "MAX" => Value::Max,
+            Some(Token::Word(word)) if !is_type_reference(word) => {
+                self.next();
+                // A value reference from another module.
+                if self.is_symbol('.') {
+                    if let Some(Token::Word(name)) = self.peek_at(1) {
+                        self.position += 2;
+                        return Ok(Value::Reference(name.clone()));
+                    }
+                }
+                return Ok(Value::Reference(word.clone()));
+            }
+            Some(Token::Symbol('{')) => {
+                self.skip_group()?;
+                return Ok(Value::Other);
+            }
+            Some(Token::Word(_) | Token::BinaryString(..)) => Value::Other,
+            _ => return Err(self.error("value")),
+        };
+        self.next();
+        Ok(value)
+    }
+
+    fn constraint(&mut self) -> Result<SubtypeConstraint, SchemaError> {
+        self.expect_symbol('(', "`(`")?;
+        let constraint = match self.peek_word() {
+            // General constraints, which aren't applied.
+            Some("CONSTRAINED" | "CONTAINING" | "ENCODED") => {
+                self.position -= 1;
+                self.skip_group()?;
+                return Ok(SubtypeConstraint {
+                    root: ElementSet::Unsupported,
+                    extensible: false,
+                });
+            }
+            _ if self.is_symbol('{') => {
+                // A table constraint.
+                self.position -= 1;
+                self.skip_group()?;
+                return Ok(SubtypeConstraint {
+                    root: ElementSet::Unsupported,
+                    extensible: false,
+                });
+            }
+            _ => self.element_set_specs()?,
+        };
+        if self.eat_symbol('!') {
+            while !self.is_symbol(')') {
+                if self.is_symbol('{') || self.is_symbol('(') {
+                    self.skip_group()?;
+                } else if self.next().is_none() {
+                    return Err(self.error("`)`"));
+                }
+            }
+        }
+        self.expect_symbol(')', "`)`")?;
+        Ok(constraint)
+    }
+
+    fn element_set_specs(&mut self) -> Result<SubtypeConstraint, SchemaError> {
+        if self.eat(&Token::Ellipsis) {
+            return Ok(SubtypeConstraint {
+                root: ElementSet::Unsupported,
+                extensible: true,
+            });
+        }
+        let root = self.element_set()?;
+        let mut extensible = false;
+        if self.eat_symbol(',') {
+            self.expect(&Token::Ellipsis, "`...`")?;
+            extensible = true;
+            // Additional elements aren't visible to the encodings.
+            if self.eat_symbol(',') {
+                self.element_set()?;
+            }
+        }
+        Ok(SubtypeConstraint { root, extensible })
+    }
+
+    fn element_set(&mut self) -> Result<ElementSet, SchemaError> {
+        if self.eat_word("ALL") {
+            self.expect_word("EXCEPT", "`EXCEPT`")?;
+            self.elements()?;
+            return Ok(ElementSet::Unsupported);
+        }
+        let mut unions = Vec::new();
+        loop {
+            let mut intersections = Vec::new();
+            loop {
+                let mut elements = self.elements()?;
+                if self.eat_word("EXCEPT") {
+                    elements = ElementSet::Except(Box::new(elements), Box::new(self.elements()?));
+                }
+                intersections.push(elements);
+                if !(self.eat_symbol('^') || self.eat_word("INTERSECTION")) {
+                    break;
+                }
+            }
+            unions.push(if intersections.len() == 1 {
+                intersections.remove(0)
+            } else {
+                ElementSet::Intersection(intersections)
+            });
+            if !(self.eat_symbol('|') || self.eat_word("UNION")) {
+                break;
+            }
+        }
+        Ok(if unions.len() == 1 {
+            unions.remove(0)
+        } else {
+            ElementSet::Union(unions)
+        })
+    }
+
+    fn elements(&mut self) -> Result<ElementSet, SchemaError> {
+        if self.is_symbol('(') {
+            self.next();
+            let set = self.element_set()?;
+            self.expect_symbol(')', "`)`")?;
+            return Ok(set);
+        }
+        if self.is_symbol('{') {
+            self.skip_group()?;
+            return Ok(ElementSet::Unsupported);
+        }
+        match self.peek_word() {
+            Some("SIZE") => {
+                self.next();
+                return Ok(ElementSet::Size(Box::new(self.constraint()?)));
+            }
+            Some("FROM") => {
+                self.next();
+                return Ok(ElementSet::From(Box::new(self.constraint()?)));
+            }
+            Some("WITH") => {
+                self.next();
+                if self.eat_word("COMPONENT") {
+                    self.constraint()?;
+                } else {
+                    self.expect_word("COMPONENTS", "`COMPONENTS`")?;
+                    self.skip_group()?;
+                }
+                return Ok(ElementSet::Unsupported);
+            }
+            Some("PATTERN" | "SETTINGS") => {
+                self.next();
+                self.value()?;
+                return Ok(ElementSet::Unsupported);
+            }
+            Some("INCLUDES") => {
+                self.next();
+                return Ok(ElementSet::Contained(Box::new(self.ty()?)));
+            }
+            Some("MIN" | "MAX") => {}
+            Some(word) if is_type_reference(word) => {
+                return Ok(ElementSet::Contained(Box::new(self.ty()?)));
+            }
+            _ => {}
+        }
+
+        let start = self.value()?;
+        let start_exclusive = self.eat_symbol('<');
+        if !self.eat(&Token::Range) {
+            return Ok(ElementSet::Single(start));
+        }
+        let end_exclusive = self.eat_symbol('<');
+        let end = self.value()?;
+        Ok(ElementSet::Range {
+            start,
+            start_exclusive,
+            end,
+            end_exclusive,
+        })
+    }
+}
+
+fn builtin_any() -> Type {
+    Type::Builtin(const { &crate::types::Any::DESCRIPTOR })
+}
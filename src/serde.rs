@@ -0,0 +1,275 @@
+//! Support for [`serde`], mapping values to and from the same JSON
+//! representation as the JSON Encoding Rules of [`jer`](crate::jer), so that
+//! both representations of a value agree.
+//!
+//! [`Serialize`] and [`Deserialize`] are implemented for the types defined
+//! in [`types`](crate::types), and can be derived for your own types with
+//! `#[rasn(serde)]` alongside `Encode` and `Decode`. Types which are
+//! aliases of types from other crates, such as [`BitString`] or
+//! [`UtcTime`], can use the JER mapping with `#[serde(with = "rasn::serde")]`.
+//!
+//! ```
+//! use rasn::prelude::*;
+//!
+//! #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+//! #[rasn(automatic_tags, serde)]
+//! struct Config {
+//!     id: ObjectIdentifier,
+//!     key: OctetString,
+//! }
+//!
+//! let config = Config {
+//!     id: ObjectIdentifier::new(vec![1, 3, 6, 1]).unwrap(),
+//!     key: OctetString::from_static(&[0xca, 0xfe]),
+//! };
+//! let json = serde_json::to_string(&config).unwrap();
+//! assert_eq!(json, rasn::jer::encode(&config).unwrap());
+//! assert_eq!(config, serde_json::from_str(&json).unwrap());
+//! ```
+//!
+//! [`BitString`]: crate::types::BitString
+//! [`UtcTime`]: crate::types::UtcTime
+
+use ::serde::{de::Error as _, ser::Error as _};
+
+use crate::{
+    jer,
+    types::{self, AsnType},
+    Decode, Encode,
+};
+
+#[doc(no_inline)]
+pub use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Serializes `value` as its JER representation.
+/// # Errors
+/// Returns an error if `value` can't be encoded with JER, or if `serializer`
+/// fails.
+pub fn serialize<T: Encode + ?Sized, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut encoder = jer::enc::Encoder::new();
+    value.encode(&mut encoder).map_err(S::Error::custom)?;
+    encoder
+        .to_json()
+        .map_err(S::Error::custom)?
+        .serialize(serializer)
+}
+
+/// Deserializes a value from its JER representation.
+/// # Errors
+/// Returns an error if `deserializer` fails, or if its data isn't a JER
+/// encoding of `T`.
+pub fn deserialize<'de, T: Decode, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+    let value = serde_json::Value::deserialize(deserializer)?;
+    T::decode(&mut jer::de::Decoder::from(value)).map_err(D::Error::custom)
+}
+
+macro_rules! impl_serde {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl Serialize for $ty {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serialize(self, serializer)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $ty {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    deserialize(deserializer)
+                }
+            }
+        )+
+    }
+}
+
+impl_serde! {
+    types::Any,
+    types::BmpString,
+    types::CharacterString,
+    types::ContextNegotiation,
+    types::EmbeddedPdv,
+    types::EncodedObjectIdentifier,
+    types::External,
+    types::GeneralString,
+    types::GraphicString,
+    types::Ia5String,
+    types::Identification,
+    types::Integer,
+    types::IsoDuration,
+    types::IsoTime,
+    types::NumericString,
+    types::ObjectIdentifier,
+    types::OctetString,
+    types::OidIri,
+    types::Open,
+    types::PrintableString,
+    types::RelativeObjectIdentifier,
+    types::RelativeOidIri,
+    types::Syntaxes,
+    types::TeletexString,
+    types::VisibleString,
+}
+
+impl Serialize for types::Oid {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(self, serializer)
+    }
+}
+
+impl Serialize for types::EncodedOid {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(self, serializer)
+    }
+}
+
+impl Serialize for types::RelativeOid {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(self, serializer)
+    }
+}
+
+impl<const START: i128, const END: i128> Serialize for types::ConstrainedInteger<START, END> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(self, serializer)
+    }
+}
+
+impl<'de, const START: i128, const END: i128> Deserialize<'de>
+    for types::ConstrainedInteger<START, END>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize(deserializer)
+    }
+}
+
+impl<const N: usize> Serialize for types::FixedOctetString<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(self, serializer)
+    }
+}
+
+impl<'de, const N: usize> Deserialize<'de> for types::FixedOctetString<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize(deserializer)
+    }
+}
+
+impl<T: Encode + Eq + core::hash::Hash> Serialize for types::SetOf<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(self, serializer)
+    }
+}
+
+impl<'de, T: Decode + Eq + core::hash::Hash> Deserialize<'de> for types::SetOf<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize(deserializer)
+    }
+}
+
+impl<T: Encode> Serialize for types::InstanceOf<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(self, serializer)
+    }
+}
+
+impl<'de, T: Decode> Deserialize<'de> for types::InstanceOf<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize(deserializer)
+    }
+}
+
+impl<T: AsnType, V: Encode> Serialize for types::Implicit<T, V> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(self, serializer)
+    }
+}
+
+impl<'de, T: AsnType, V: Decode> Deserialize<'de> for types::Implicit<T, V> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize(deserializer)
+    }
+}
+
+impl<T: AsnType, V: Encode> Serialize for types::Explicit<T, V> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(self, serializer)
+    }
+}
+
+impl<'de, T: AsnType, V: Decode> Deserialize<'de> for types::Explicit<T, V> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize(deserializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::String, vec};
+
+    use crate::prelude::*;
+
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+    #[rasn(crate_root = "crate", automatic_tags, serde)]
+    struct Record {
+        id: ObjectIdentifier,
+        flags: BitString,
+        name: Option<Ia5String>,
+        choice: Selection,
+    }
+
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+    #[rasn(crate_root = "crate", choice, automatic_tags, serde)]
+    enum Selection {
+        Number(Integer),
+        Text(Utf8String),
+    }
+
+    #[test]
+    fn types_agree_with_jer() {
+        macro_rules! agree {
+            ($ty:ty, $value:expr) => {{
+                let value: $ty = $value;
+                let json = serde_json::to_string(&value).unwrap();
+                assert_eq!(json, crate::jer::encode(&value).unwrap());
+                assert_eq!(value, serde_json::from_str::<$ty>(&json).unwrap());
+            }};
+        }
+
+        agree!(Integer, 1_000_000.into());
+        agree!(OctetString, OctetString::from_static(&[0xde, 0xad]));
+        agree!(
+            ObjectIdentifier,
+            ObjectIdentifier::new(vec![1, 2, 3]).unwrap()
+        );
+        agree!(Ia5String, Ia5String::try_from(String::from("ia5")).unwrap());
+        agree!(SetOf<Integer>, SetOf::from_vec(vec![1.into(), 2.into()]));
+        agree!(
+            External,
+            External {
+                identification: Identification::PresentationContextId(1.into()),
+                data_value_descriptor: None,
+                data_value: OctetString::from_static(&[1]),
+            }
+        );
+        assert_eq!(
+            serde_json::to_string(Oid::ISO_MEMBER_BODY).unwrap(),
+            "\"1.2\""
+        );
+    }
+
+    #[test]
+    fn derive() {
+        let record = Record {
+            id: ObjectIdentifier::new(vec![2, 5, 4, 3]).unwrap(),
+            flags: BitString::from_slice(&[0xf0]),
+            name: None,
+            choice: Selection::Text("text".into()),
+        };
+        let json = serde_json::to_string(&record).unwrap();
+        assert_eq!(json, crate::jer::encode(&record).unwrap());
+        assert_eq!(record, serde_json::from_str(&json).unwrap());
+        assert!(serde_json::from_str::<Record>("{\"id\":\"2.5.4.3\"}").is_err());
+    }
+}
@@ -6,22 +6,29 @@
 mod any;
 mod identifier;
 mod instance;
+mod object_set;
 mod open;
 mod prefix;
 mod tag;
 
 pub mod constraints;
+pub mod descriptor;
 pub mod fields;
+pub mod validate;
+pub mod value;
 pub mod variants;
 
 pub(crate) mod constructed;
 pub(crate) mod date;
+pub(crate) mod external;
 pub(crate) mod integer;
+pub(crate) mod iri;
 pub(crate) mod oid;
 
 pub(crate) mod real;
 
 pub(crate) mod strings;
+pub(crate) mod time;
 
 use crate::macros::constraints;
 use alloc::boxed::Box;
@@ -31,32 +38,51 @@ pub use {
         any::Any,
         constraints::{Constraint, Constraints, Extensible, InnerSubtypeConstraint},
         constructed::{Constructed, SequenceOf, SetOf},
+        descriptor::{Describe, TypeDescriptor},
+        external::{
+            CharacterString, ContextNegotiation, EmbeddedPdv, External, Identification, Syntaxes,
+        },
         identifier::Identifier,
         instance::InstanceOf,
         integer::{ConstrainedInteger, Integer, IntegerType},
-        oid::{ObjectIdentifier, Oid},
+        iri::{OidIri, RelativeOidIri},
+        object_set::InformationObjectSet,
+        oid::{
+            EncodedObjectIdentifier, EncodedOid, ObjectIdentifier, Oid, RelativeObjectIdentifier,
+            RelativeOid,
+        },
         open::Open,
         prefix::{Explicit, Implicit},
         strings::{
-            BitStr, BitString, BmpString, FixedBitString, FixedOctetString, GeneralString,
-            GraphicString, Ia5String, NumericString, OctetString, PrintableString, TeletexString,
-            Utf8String, VisibleString,
+            BitStr, BitString, BmpString, BorrowedIa5String, BorrowedNumericString,
+            BorrowedOctetString, BorrowedPrintableString, BorrowedUtf8String,
+            BorrowedVisibleString, FixedBitString, FixedOctetString, GeneralString, GraphicString,
+            Ia5String, NumericString, OctetString, PrintableString, TeletexString, Utf8String,
+            VisibleString,
         },
         tag::{Class, Tag, TagTree},
+        time::{DurationFraction, IsoDuration, IsoTime},
+        validate::{Validate, Validator},
     },
-    rasn_derive::AsnType,
+    rasn_derive::{AsnType, Describe, InformationObjectSet, Validate},
 };
 
 pub use self::real::RealType;
 
 ///  The `UniversalString` type.
 pub type UniversalString = Implicit<tag::UNIVERSAL_STRING, Utf8String>;
+///  The `ObjectDescriptor` type.
+pub type ObjectDescriptor = Implicit<tag::OBJECT_DESCRIPTOR, GraphicString>;
 ///  The `UTCTime` type.
 pub type UtcTime = chrono::DateTime<chrono::Utc>;
 ///  The `GeneralizedTime` type.
 pub type GeneralizedTime = chrono::DateTime<chrono::FixedOffset>;
 /// The `Date` type.
 pub type Date = chrono::NaiveDate;
+/// The `TIME-OF-DAY` type, with a precision of seconds.
+pub type TimeOfDay = chrono::NaiveTime;
+/// The `DATE-TIME` type, a local date and time with a precision of seconds.
+pub type DateTime = chrono::NaiveDateTime;
 
 /// A trait representing any type that can represented in ASN.1.
 pub trait AsnType {
@@ -250,6 +276,10 @@ asn_type! {
     OctetString: OCTET_STRING,
     ObjectIdentifier: OBJECT_IDENTIFIER,
     Oid: OBJECT_IDENTIFIER,
+    EncodedObjectIdentifier: OBJECT_IDENTIFIER,
+    EncodedOid: OBJECT_IDENTIFIER,
+    RelativeObjectIdentifier: RELATIVE_OID,
+    RelativeOid: RELATIVE_OID,
     Utf8String: UTF8_STRING,
     UtcTime: UTC_TIME,
     GeneralizedTime: GENERALIZED_TIME,
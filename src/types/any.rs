@@ -1,17 +1,40 @@
 use alloc::vec::Vec;
 
+use crate::Codec;
+
 /// Represents a complete encoded ASN.1 value of any type. Usually identified
 /// with an [`ObjectIdentifier`][crate::types::ObjectIdentifier].
-#[derive(Clone, Debug, PartialOrd, Ord, PartialEq, Eq, Hash)]
+///
+/// Values are compared and hashed by their contents only, whichever codec
+/// they were decoded with.
+#[derive(Clone, Debug)]
 pub struct Any {
     pub(crate) contents: Vec<u8>,
+    /// Whether the contents are the JSON text of the value, as decoded or
+    /// encoded with JER, rather than an encoding which JER wraps in an octet
+    /// string.
+    pub(crate) json: bool,
 }
 
 impl Any {
     /// Creates a new wrapper around the opaque value.
     #[must_use]
     pub fn new(contents: Vec<u8>) -> Self {
-        Self { contents }
+        Self {
+            contents,
+            json: false,
+        }
+    }
+
+    /// Creates a new wrapper around a value encoded with `codec`. Values
+    /// encoded with JER are JSON text, which JER encodes as is instead of
+    /// wrapping it in an octet string.
+    #[must_use]
+    pub fn with_codec(codec: Codec, contents: Vec<u8>) -> Self {
+        Self {
+            contents,
+            json: codec == Codec::Jer,
+        }
     }
 
     /// Provides the raw representation of the value as bytes.
@@ -27,6 +50,32 @@ impl Any {
     }
 }
 
+impl PartialEq for Any {
+    fn eq(&self, other: &Self) -> bool {
+        self.contents == other.contents
+    }
+}
+
+impl Eq for Any {}
+
+impl PartialOrd for Any {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Any {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.contents.cmp(&other.contents)
+    }
+}
+
+impl core::hash::Hash for Any {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.contents.hash(state);
+    }
+}
+
 impl AsRef<[u8]> for Any {
     fn as_ref(&self) -> &[u8] {
         self.contents.as_ref()
@@ -1,6 +1,7 @@
 //! Constraints of values on a given type.
 
 use super::IntegerType;
+use alloc::vec::Vec;
 use num_bigint::BigInt;
 
 /// A marker trait with validation methods for types that have ASN.1 inner subtype constraints.
@@ -25,14 +26,14 @@ pub trait InnerSubtypeConstraint: Sized {
 /// A set of constraints for a given type on what kinds of values are allowed.
 /// Used in certain codecs to optimise encoding and decoding values.
 ///
-/// The effective constraint is typically the intersection or union among other constraints.
-/// As a result, we can store one constraint of each kind, updated with the latest constraint.
-///
-/// TODO architecture needs a re-design - multiple constraints with same type are allowed forming on non-contiguous set of values.
-/// We can't currently present this.
-/// For example, value constraint can have multiple single values, or a range of values which do not overlap, and are effective at the same time.
-/// This is challenging to implement in compile-time, and we may need to use runtime checks.
-/// E.g effective constraint can have up to infinite single value constraints, and overall constraint value is not continuous.
+/// One constraint of each kind is stored, holding the effective constraint
+/// as defined by X.691 §8.3, which is what the codecs use: unions become the
+/// smallest range containing every value, intersections keep the common
+/// values, and `EXCEPT` clauses are ignored. Constraints which aren't
+/// contiguous, such as `INTEGER (1..5 | 10..20)` or `SIZE (8 | 16 | 32)`,
+/// additionally keep the exact sets of values they permit, which are checked
+/// when validating values. Intersecting constraints keeps the sets of both,
+/// so a value is only permitted if it's in every one of them.
 #[derive(Debug, Copy, Clone)]
 #[non_exhaustive]
 pub struct Constraints {
@@ -376,6 +377,9 @@ pub struct Value {
     pub(crate) signed: bool,
     /// Range of the bound in bytes, used for numeric values
     pub(crate) range: Option<u8>,
+    /// The exact sets of values permitted within the bound, if they aren't
+    /// contiguous.
+    pub(crate) sets: Sets<Bounded<i128>>,
 }
 
 impl Value {
@@ -387,8 +391,40 @@ impl Value {
             value,
             signed,
             range,
+            sets: Sets::NONE,
         }
     }
+
+    /// Restricts the constraint to the values in `set`, a list of disjoint
+    /// ranges within the bound of the constraint, for constraints such as
+    /// `(1..5 | 10..20)` whose values aren't contiguous. The bound remains
+    /// the effective constraint used by the codecs.
+    ///
+    /// If the constraint already has [`MAX_SETS`] different sets, `set` is
+    /// dropped, and only the bound and existing sets are checked.
+    #[must_use]
+    pub const fn with_set(mut self, set: &'static [Bounded<i128>]) -> Self {
+        self.sets = self.sets.with(set);
+        self
+    }
+
+    /// Returns the exact ranges of values permitted within the bound, which
+    /// are in every set of the constraint, if it has any sets.
+    #[must_use]
+    pub fn set(&self) -> Option<Vec<Bounded<i128>>> {
+        self.sets.ranges(self.value, Bounded::<i128>::intersect)
+    }
+
+    /// Returns whether `element` is permitted by the constraint, including
+    /// its exact sets of values.
+    pub fn permits<I: IntegerType>(&self, element: &I) -> bool {
+        self.value.in_bound(element)
+            && self
+                .sets
+                .iter()
+                .all(|set| set.iter().any(|range| range.in_bound(element)))
+    }
+
     /// Gets the sign of the value constraint.
     #[must_use]
     pub const fn get_sign(&self) -> bool {
@@ -400,6 +436,10 @@ impl Value {
         self.range
     }
     /// Intersect between two `Value` constraints
+    ///
+    /// The exact sets of both constraints are kept, within the intersection
+    /// of the bounds. Sets beyond [`MAX_SETS`] are dropped, as the intersected
+    /// bound remains the effective constraint.
     #[must_use]
     pub const fn intersect(&self, other: &Self) -> Self {
         let value = match self.value.intersect(other.value) {
@@ -415,10 +455,17 @@ impl Value {
             value,
             signed,
             range,
+            sets: self.sets.intersect(other.sets),
         }
     }
 }
 
+impl core::fmt::Display for Value {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        fmt_set(f, &self.value, self.set())
+    }
+}
+
 impl core::ops::Deref for Value {
     type Target = Bounded<i128>;
 
@@ -468,19 +515,55 @@ impl TryFrom<Bounded<usize>> for Value {
 
 /// A single or range of length values a type can have.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct Size(pub(crate) Bounded<usize>);
+pub struct Size(
+    pub(crate) Bounded<usize>,
+    /// The exact sets of sizes permitted within the bound, if they aren't
+    /// contiguous.
+    pub(crate) Sets<Bounded<usize>>,
+);
 
 impl Size {
     /// Creates a varying range constraint.
     #[must_use]
     pub const fn new(range: Bounded<usize>) -> Self {
-        Self(range)
+        Self(range, Sets::NONE)
     }
 
     /// Creates a fixed size constraint.
     #[must_use]
     pub const fn fixed(length: usize) -> Self {
-        Self(Bounded::Single(length))
+        Self(Bounded::Single(length), Sets::NONE)
+    }
+
+    /// Restricts the constraint to the sizes in `set`, a list of disjoint
+    /// ranges within the bound of the constraint, for constraints such as
+    /// `SIZE (8 | 16 | 32)` whose sizes aren't contiguous. The bound remains
+    /// the effective constraint used by the codecs.
+    ///
+    /// If the constraint already has [`MAX_SETS`] different sets, `set` is
+    /// dropped, and only the bound and existing sets are checked.
+    #[must_use]
+    pub const fn with_set(mut self, set: &'static [Bounded<usize>]) -> Self {
+        self.1 = self.1.with(set);
+        self
+    }
+
+    /// Returns the exact ranges of sizes permitted within the bound, which
+    /// are in every set of the constraint, if it has any sets.
+    #[must_use]
+    pub fn set(&self) -> Option<Vec<Bounded<usize>>> {
+        self.1.ranges(self.0, Bounded::<usize>::intersect)
+    }
+
+    /// Returns whether `size` is permitted by the constraint, including its
+    /// exact sets of sizes.
+    #[must_use]
+    pub fn permits(&self, size: usize) -> bool {
+        self.0.contains(&size)
+            && self
+                .1
+                .iter()
+                .all(|set| set.iter().any(|range| range.contains(&size)))
     }
 
     /// Returns whether the size is fixed.
@@ -494,16 +577,159 @@ impl Size {
         matches!(self.0, Bounded::Range { .. })
     }
     /// Intersect between two `Size` constraints
+    ///
+    /// The exact sets of both constraints are kept, within the intersection
+    /// of the bounds. Sets beyond [`MAX_SETS`] are dropped, as the intersected
+    /// bound remains the effective constraint.
     #[must_use]
     pub const fn intersect(&self, other: &Self) -> Self {
+        let sets = self.1.intersect(other.1);
         match self.0.intersect(other.0) {
-            Some(value) => Self(value),
+            Some(value) => Self(value, sets),
             // if the intersection is empty, return a zero size
-            None => Self(Bounded::Single(0)),
+            None => Self(Bounded::Single(0), sets),
+        }
+    }
+}
+
+impl core::fmt::Display for Size {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        fmt_set(f, &self.0, self.set())
+    }
+}
+
+/// Formats the exact `set` of a constraint as a union, or its `bound` if it
+/// has no set.
+fn fmt_set<T: core::fmt::Display>(
+    f: &mut core::fmt::Formatter,
+    bound: &Bounded<T>,
+    set: Option<Vec<Bounded<T>>>,
+) -> core::fmt::Result {
+    let Some(set) = set else {
+        return write!(f, "{bound}");
+    };
+    if set.is_empty() {
+        return f.write_str("none");
+    }
+    for (i, range) in set.iter().enumerate() {
+        if i > 0 {
+            f.write_str(" | ")?;
+        }
+        write!(f, "{range}")?;
+    }
+    Ok(())
+}
+
+/// The maximum number of different exact sets a constraint can keep.
+pub const MAX_SETS: usize = 4;
+
+/// The exact sets of a constraint whose values aren't contiguous. A value is
+/// permitted if it's in every set, so intersecting constraints keeps the
+/// sets of both.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct Sets<T: 'static>([Option<&'static [T]>; MAX_SETS]);
+
+impl<T> Default for Sets<T> {
+    fn default() -> Self {
+        Self::NONE
+    }
+}
+
+impl<T> Sets<T> {
+    const NONE: Self = Self([None; MAX_SETS]);
+
+    const fn is_empty(&self) -> bool {
+        self.0[0].is_none()
+    }
+
+    fn iter(&self) -> impl Iterator<Item = &'static [T]> + '_ {
+        self.0.iter().flatten().copied()
+    }
+}
+
+impl<T: Copy> Sets<Bounded<T>> {
+    /// Returns the ranges within `bound` which are in every set, in order,
+    /// or `None` if there are no sets.
+    fn ranges(
+        &self,
+        bound: Bounded<T>,
+        intersect: fn(&Bounded<T>, Bounded<T>) -> Option<Bounded<T>>,
+    ) -> Option<Vec<Bounded<T>>> {
+        if self.is_empty() {
+            return None;
         }
+        let mut ranges = alloc::vec![bound];
+        for set in self.iter() {
+            ranges = ranges
+                .iter()
+                .flat_map(|range| set.iter().filter_map(|member| intersect(range, *member)))
+                .collect();
+        }
+        Some(ranges)
     }
 }
 
+macro_rules! impl_sets {
+    ($($type:ty => |$a:ident, $b:ident| $eq:expr),+ $(,)?) => {
+        $(
+            impl Sets<$type> {
+                /// Adds `set`, unless an equal set is already present. `set`
+                /// is dropped when there are already `MAX_SETS` different
+                /// sets, which only loosens the exact constraint.
+                const fn with(mut self, set: &'static [$type]) -> Self {
+                    let mut i = 0;
+                    while i < MAX_SETS {
+                        match self.0[i] {
+                            Some(existing) if Self::equal(existing, set) => return self,
+                            Some(_) => {}
+                            None => {
+                                self.0[i] = Some(set);
+                                return self;
+                            }
+                        }
+                        i += 1;
+                    }
+                    self
+                }
+
+                /// Returns the sets of both `self` and `other`.
+                const fn intersect(self, other: Self) -> Self {
+                    let mut sets = self;
+                    let mut i = 0;
+                    while i < MAX_SETS {
+                        if let Some(set) = other.0[i] {
+                            sets = sets.with(set);
+                        }
+                        i += 1;
+                    }
+                    sets
+                }
+
+                const fn equal(a: &[$type], b: &[$type]) -> bool {
+                    if a.len() != b.len() {
+                        return false;
+                    }
+                    let mut i = 0;
+                    while i < a.len() {
+                        let ($a, $b) = (&a[i], &b[i]);
+                        if !$eq {
+                            return false;
+                        }
+                        i += 1;
+                    }
+                    true
+                }
+            }
+        )+
+    };
+}
+
+impl_sets! {
+    Bounded<i128> => |a, b| a.const_eq(b),
+    Bounded<usize> => |a, b| a.const_eq(b),
+    u32 => |a, b| *a == *b,
+}
+
 impl core::ops::Deref for Size {
     type Target = Bounded<usize>;
 
@@ -520,13 +746,53 @@ impl core::ops::DerefMut for Size {
 
 /// A range of alphabet characters a type can have.
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct PermittedAlphabet(&'static [u32]);
+pub struct PermittedAlphabet(
+    &'static [u32],
+    /// The exact sets of characters permitted, if some of the alphabet are
+    /// excluded.
+    Sets<u32>,
+);
 
 impl PermittedAlphabet {
     /// Creates a new constraint from a given range.
     #[must_use]
     pub const fn new(range: &'static [u32]) -> Self {
-        Self(range)
+        Self(range, Sets::NONE)
+    }
+
+    /// Restricts the constraint to the characters in `set`, for alphabets
+    /// such as `FROM ("a".."z" EXCEPT "q")` whose `EXCEPT` clause isn't part
+    /// of the effective alphabet used by the codecs.
+    ///
+    /// If the constraint already has [`MAX_SETS`] different sets, `set` is
+    /// dropped, and only the alphabet and existing sets are checked.
+    #[must_use]
+    pub const fn with_set(mut self, set: &'static [u32]) -> Self {
+        self.1 = self.1.with(set);
+        self
+    }
+
+    /// Returns the exact characters of the alphabet which are in every set
+    /// of the constraint, if it has any sets.
+    #[must_use]
+    pub fn set(&self) -> Option<Vec<u32>> {
+        if self.1.is_empty() {
+            return None;
+        }
+        Some(
+            self.0
+                .iter()
+                .copied()
+                .filter(|character| self.permits(*character))
+                .collect(),
+        )
+    }
+
+    /// Returns whether `character` is permitted by the constraint, including
+    /// its exact sets of characters.
+    #[must_use]
+    pub fn permits(&self, character: u32) -> bool {
+        self.0.contains(&character) && self.1.iter().all(|set| set.contains(&character))
     }
 
     /// Returns the range of allowed possible values.
@@ -536,15 +802,67 @@ impl PermittedAlphabet {
     }
     /// Intersect between two `PermittedAlphabet` constraints.
     ///
-    /// TODO not currently possible to intersect
-    /// because new instance requires a static lifetime,
-    /// so we just override for now.
+    /// The effective alphabet is the one which is a subset of the other, and
+    /// the exact sets of both constraints are kept. When neither alphabet is
+    /// a subset of the other, their common characters can't be borrowed for
+    /// `'static`, so the narrower alphabet is the effective one and the other
+    /// is kept as a set.
     #[must_use]
     pub const fn intersect(&self, other: &Self) -> Self {
-        Self(other.0)
+        let sets = self.1.intersect(other.1);
+        if is_subset(other.0, self.0) {
+            Self(other.0, sets)
+        } else if is_subset(self.0, other.0) {
+            Self(self.0, sets)
+        } else if other.0.len() <= self.0.len() {
+            Self(other.0, sets.with(self.0))
+        } else {
+            Self(self.0, sets.with(other.0))
+        }
     }
 }
 
+/// Returns whether every character of `a` is in `b`.
+const fn is_subset(a: &[u32], b: &[u32]) -> bool {
+    let mut i = 0;
+    // Alphabets are usually sorted, which allows comparing them in one pass.
+    if is_sorted(a) && is_sorted(b) {
+        let mut j = 0;
+        while i < a.len() {
+            while j < b.len() && b[j] < a[i] {
+                j += 1;
+            }
+            if j == b.len() || b[j] != a[i] {
+                return false;
+            }
+            i += 1;
+        }
+        return true;
+    }
+    while i < a.len() {
+        let mut j = 0;
+        while j < b.len() && b[j] != a[i] {
+            j += 1;
+        }
+        if j == b.len() {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+const fn is_sorted(alphabet: &[u32]) -> bool {
+    let mut i = 1;
+    while i < alphabet.len() {
+        if alphabet[i - 1] > alphabet[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
 impl core::ops::Deref for PermittedAlphabet {
     type Target = [u32];
 
@@ -672,6 +990,25 @@ macro_rules! impl_bounded_range {
                         _ => either::Right(value),
                     }
                 }
+                /// Returns whether two bounds are equal, in const contexts.
+                const fn const_eq(&self, other: &Self) -> bool {
+                    const fn option_eq(a: Option<$type>, b: Option<$type>) -> bool {
+                        match (a, b) {
+                            (Some(a), Some(b)) => a == b,
+                            (None, None) => true,
+                            _ => false,
+                        }
+                    }
+                    match (self, other) {
+                        (Self::None, Self::None) => true,
+                        (Self::Single(a), Self::Single(b)) => *a == *b,
+                        (
+                            Self::Range { start: a, end: b },
+                            Self::Range { start: c, end: d },
+                        ) => option_eq(*a, *c) && option_eq(*b, *d),
+                        _ => false,
+                    }
+                }
                 /// Intersect the values of two bounded ranges.
                 ///
                 /// # Returns
@@ -953,6 +1290,83 @@ mod tests {
         assert_eq!(256, constraints.range().unwrap());
     }
 
+    #[test]
+    fn sets() {
+        const SIZE: Size = Size::new(Bounded::const_new(8, 32)).with_set(&[
+            Bounded::Single(8),
+            Bounded::Single(16),
+            Bounded::Single(32),
+        ]);
+        assert!(SIZE.permits(16) && !SIZE.permits(12));
+
+        // The set is kept within the intersection of the bounds.
+        let size = Size::new(Bounded::const_new(0, 16)).intersect(&SIZE);
+        assert_eq!(*size, Bounded::const_new(8, 16));
+        assert!(size.permits(8) && !size.permits(32));
+        assert_eq!(size.to_string(), "8 | 16");
+
+        // Both sets are kept when intersecting constraints which have one.
+        const VALUE: Value = Value::new(Bounded::const_new(1, 20))
+            .with_set(&[Bounded::const_new(1, 5), Bounded::const_new(10, 20)])
+            .intersect(
+                &Value::new(Bounded::const_new(0, 12))
+                    .with_set(&[Bounded::Single(0), Bounded::const_new(4, 12)]),
+            );
+        assert_eq!(*VALUE, Bounded::const_new(1, 12));
+        assert!(VALUE.permits(&4) && VALUE.permits(&11));
+        assert!(!VALUE.permits(&1) && !VALUE.permits(&7) && !VALUE.permits(&13));
+        assert_eq!(VALUE.to_string(), "4..5 | 10..12");
+        assert_eq!(VALUE.intersect(&VALUE), VALUE);
+
+        const ALPHABET: PermittedAlphabet =
+            PermittedAlphabet::new(&[97, 98, 99]).with_set(&[97, 99]);
+        assert!(ALPHABET.permits(97) && !ALPHABET.permits(98) && !ALPHABET.permits(100));
+
+        const NARROWED: PermittedAlphabet = ALPHABET
+            .intersect(&PermittedAlphabet::new(&[97, 98, 99, 100]).with_set(&[98, 99, 100]));
+        assert_eq!(NARROWED.as_inner(), &[97, 98, 99]);
+        assert_eq!(NARROWED.set(), Some(alloc::vec![99]));
+    }
+
+    #[test]
+    fn overlapping_alphabets() {
+        // The narrower alphabet is effective, and the other is kept as a set.
+        const ALPHABET: PermittedAlphabet =
+            PermittedAlphabet::new(&[97, 98]).intersect(&PermittedAlphabet::new(&[98, 99, 100]));
+        assert_eq!(ALPHABET.as_inner(), &[97, 98]);
+        assert_eq!(ALPHABET.set(), Some(alloc::vec![98]));
+        assert!(ALPHABET.permits(98) && !ALPHABET.permits(97) && !ALPHABET.permits(99));
+
+        let disjoint = PermittedAlphabet::new(&[97]).intersect(&PermittedAlphabet::new(&[98]));
+        assert_eq!(disjoint.set(), Some(alloc::vec![]));
+    }
+
+    #[test]
+    fn too_many_sets() {
+        const SETS: [&[Bounded<usize>]; MAX_SETS + 1] = [
+            &[Bounded::Single(1), Bounded::Single(2), Bounded::Single(3)],
+            &[Bounded::Single(1), Bounded::Single(2)],
+            &[Bounded::Single(1), Bounded::Single(3)],
+            &[Bounded::Single(2), Bounded::Single(3)],
+            &[Bounded::Single(3)],
+        ];
+        // The extra set is dropped, and the bound and other sets still hold.
+        let mut size = Size::new(Bounded::const_new(0, 3));
+        for set in SETS {
+            size = size.with_set(set);
+        }
+        assert!(!size.permits(0) && !size.permits(4));
+        assert!(!size.permits(1) && !size.permits(2) && !size.permits(3));
+        assert_eq!(size.set(), Some(alloc::vec![]));
+
+        let mut size = Size::new(Bounded::const_new(0, 3));
+        for set in &SETS[..MAX_SETS] {
+            size = size.with_set(set);
+        }
+        let other = Size::new(Bounded::const_new(0, 3)).with_set(SETS[MAX_SETS]);
+        assert_eq!(size.intersect(&other), size);
+    }
+
     #[test]
     #[allow(clippy::too_many_lines)]
     fn test_bounded_intersections() {
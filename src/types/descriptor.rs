@@ -0,0 +1,577 @@
+//! Runtime descriptions of ASN.1 types.
+//!
+//! A [`TypeDescriptor`] is a dynamic mirror of the metadata that [`AsnType`],
+//! [`Constructed`](super::Constructed), [`Choice`](super::Choice) and
+//! [`Enumerated`](super::Enumerated) provide at compile time. Descriptors are
+//! plain data borrowing the descriptors of their components, so they can be
+//! written by hand as constants, built from a schema at runtime, or exported
+//! from a Rust type with `#[derive(Describe)]`.
+//!
+//! Every codec can use a descriptor to decode a value into a generic
+//! [`Value`] tree without the Rust type being available, and to encode such a
+//! tree back, which also allows transcoding a value between codecs. This is
+//! most useful for codecs which can't be parsed without a schema, such as PER
+//! and OER, see [`uper::decode_value`] for example.
+//!
+//! ```rust
+//! use rasn::prelude::*;
+//! use rasn::types::{descriptor::Describe, value::Value};
+//!
+//! #[derive(AsnType, Decode, Encode, Describe)]
+//! #[rasn(automatic_tags)]
+//! struct Position {
+//!     #[rasn(value("-900000000..=900000001"))]
+//!     latitude: i32,
+//!     #[rasn(value("-1799999999..=1800000001"))]
+//!     longitude: i32,
+//!     elevation: Option<u16>,
+//! }
+//!
+//! let bytes = rasn::uper::encode(&Position { latitude: 1, longitude: 2, elevation: None }).unwrap();
+//! let value = rasn::uper::decode_value(&Position::DESCRIPTOR, &bytes).unwrap();
+//!
+//! assert_eq!(value.get("latitude"), Some(&Value::Integer(1.into())));
+//! assert_eq!(value.get("elevation"), None);
+//! ```
+//!
+//! [`Value`]: super::value::Value
+//! [`uper::decode_value`]: crate::uper::decode_value
+
+use alloc::boxed::Box;
+use core::{
+    marker::PhantomData,
+    sync::atomic::{AtomicPtr, Ordering},
+};
+
+use super::{
+    constraints::Bounded, fields::FieldPresence, AsnType, Constraints, Implicit, SetOf, Tag,
+};
+
+/// A runtime description of an ASN.1 type.
+#[derive(Debug, Clone, Copy)]
+pub struct TypeDescriptor<'a> {
+    /// The identifier of the type, if any.
+    pub identifier: Option<&'a str>,
+    /// The tag of the type, [`Tag::EOC`] for untagged `CHOICE` types.
+    pub tag: Tag,
+    /// The effective constraints of the type.
+    pub constraints: DescribedConstraints<'a>,
+    /// The structure of the type.
+    pub kind: TypeKind<'a>,
+}
+
+impl<'a> TypeDescriptor<'a> {
+    /// Creates a new descriptor with no constraints.
+    #[must_use]
+    pub const fn new(identifier: Option<&'a str>, tag: Tag, kind: TypeKind<'a>) -> Self {
+        Self {
+            identifier,
+            tag,
+            constraints: DescribedConstraints::new(Constraints::NONE),
+            kind,
+        }
+    }
+
+    /// Creates a new descriptor of `kind`, using the identifier, tag and
+    /// constraints of `T`.
+    #[must_use]
+    pub const fn of<T: AsnType + ?Sized>(kind: TypeKind<'a>) -> Self {
+        Self {
+            identifier: T::IDENTIFIER.0,
+            tag: T::TAG,
+            constraints: DescribedConstraints::new(T::CONSTRAINTS),
+            kind,
+        }
+    }
+
+    /// Returns a copy of the descriptor with its tag replaced by `tag`.
+    #[must_use]
+    pub const fn with_tag(mut self, tag: Tag) -> Self {
+        self.tag = tag;
+        self
+    }
+
+    /// Returns a copy of the descriptor with `constraints` intersected with
+    /// its own constraints.
+    #[must_use]
+    pub const fn with_constraints(mut self, constraints: Constraints) -> Self {
+        self.constraints = DescribedConstraints {
+            constraints: self.constraints.constraints.intersect(constraints),
+            _marker: PhantomData,
+        };
+        self
+    }
+
+    /// Returns the descriptor of the type a [`TypeKind::Reference`] refers
+    /// to, with the identifier, tag and constraints of `self` applied, or a
+    /// copy of `self` for any other kind.
+    #[must_use]
+    pub fn resolve(&self) -> Self {
+        match self.kind {
+            TypeKind::Reference(reference) => reference.get().map_or(*self, |target| Self {
+                identifier: self.identifier,
+                ..target
+                    .with_tag(self.tag)
+                    .with_constraints(self.constraints.constraints)
+            }),
+            _ => *self,
+        }
+    }
+
+    /// Returns whether the descriptor describes an untagged `CHOICE` type.
+    #[must_use]
+    pub fn is_choice(&self) -> bool {
+        matches!(self.resolve().kind, TypeKind::Choice { .. }) && self.tag == Tag::EOC
+    }
+
+    /// Returns the smallest tag of the type, which is the smallest tag of
+    /// any of its variants for untagged `CHOICE` types. Used to determine the
+    /// canonical order of `SET` components.
+    #[must_use]
+    pub fn smallest_tag(&self) -> Tag {
+        self.smallest_tag_within(MAX_CHOICE_DEPTH)
+    }
+
+    fn smallest_tag_within(&self, depth: usize) -> Tag {
+        match self.resolve().kind {
+            TypeKind::Choice {
+                variants,
+                extended_variants,
+            } if self.tag == Tag::EOC && depth > 0 => variants
+                .iter()
+                .chain(extended_variants.into_iter().flatten())
+                .map(|variant| variant.ty.smallest_tag_within(depth - 1))
+                .min()
+                .unwrap_or(Tag::EOC),
+            _ => self.tag,
+        }
+    }
+
+    /// Returns whether a value of this type can be identified by `tag`, which
+    /// for untagged `CHOICE` types is the case if any of its variants can.
+    #[must_use]
+    pub fn contains_tag(&self, tag: Tag) -> bool {
+        self.contains_tag_within(tag, MAX_CHOICE_DEPTH)
+    }
+
+    fn contains_tag_within(&self, tag: Tag, depth: usize) -> bool {
+        match self.resolve().kind {
+            TypeKind::Choice {
+                variants,
+                extended_variants,
+            } if self.tag == Tag::EOC && depth > 0 => variants
+                .iter()
+                .chain(extended_variants.into_iter().flatten())
+                .any(|variant| variant.ty.contains_tag_within(tag, depth - 1)),
+            _ => self.tag == tag,
+        }
+    }
+}
+
+/// How deep untagged `CHOICE` types are searched for the tags of their
+/// variants, which guards against schemas where such a type contains itself.
+const MAX_CHOICE_DEPTH: usize = 64;
+
+/// The effective constraints of a [`TypeDescriptor`].
+///
+/// Constraints built at runtime, such as those of a
+/// [`Schema`](crate::schema::Schema), borrow their permitted alphabets and
+/// exact sets of values for `'a`, so only the constraints of `'static`
+/// descriptors can be taken out as [`Constraints`].
+#[derive(Debug, Clone, Copy)]
+pub struct DescribedConstraints<'a> {
+    constraints: Constraints,
+    _marker: PhantomData<&'a [u32]>,
+}
+
+impl DescribedConstraints<'_> {
+    /// Wraps constraints whose tables are `'static`.
+    #[must_use]
+    pub const fn new(constraints: Constraints) -> Self {
+        Self {
+            constraints,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the constraints for passing them to a codec, which must not
+    /// keep them beyond `'a`.
+    pub(crate) const fn get(&self) -> Constraints {
+        self.constraints
+    }
+
+    /// Returns the bound of the value constraint, if any.
+    #[must_use]
+    pub fn value(&self) -> Option<Bounded<i128>> {
+        self.constraints.value().map(|value| *value.constraint)
+    }
+
+    /// Returns the bound of the size constraint, if any.
+    #[must_use]
+    pub fn size(&self) -> Option<Bounded<usize>> {
+        self.constraints.size().map(|size| *size.constraint)
+    }
+
+    /// Returns the characters of the permitted alphabet constraint, if any.
+    #[must_use]
+    pub fn permitted_alphabet(&self) -> Option<&[u32]> {
+        self.constraints
+            .permitted_alphabet()
+            .map(|alphabet| alphabet.constraint.as_inner())
+    }
+
+    /// Returns whether the type is extensible.
+    #[must_use]
+    pub const fn extensible(&self) -> bool {
+        self.constraints.extensible()
+    }
+}
+
+impl DescribedConstraints<'static> {
+    /// Returns the constraints of a `'static` descriptor.
+    #[must_use]
+    pub const fn constraints(&self) -> Constraints {
+        self.constraints
+    }
+}
+
+/// The structure of a type described by a [`TypeDescriptor`].
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub enum TypeKind<'a> {
+    /// `BOOLEAN`
+    Boolean,
+    /// `INTEGER`
+    Integer,
+    /// `REAL`
+    Real,
+    /// `NULL`
+    Null,
+    /// `OBJECT IDENTIFIER`
+    ObjectIdentifier,
+    /// `RELATIVE-OID`
+    RelativeOid,
+    /// `OID-IRI`
+    OidIri,
+    /// `RELATIVE-OID-IRI`
+    RelativeOidIri,
+    /// `EXTERNAL`
+    External,
+    /// `BIT STRING`
+    BitString,
+    /// `OCTET STRING`
+    OctetString,
+    /// `UTF8String`
+    Utf8String,
+    /// `IA5String`
+    Ia5String,
+    /// `PrintableString`
+    PrintableString,
+    /// `VisibleString`
+    VisibleString,
+    /// `NumericString`
+    NumericString,
+    /// `BMPString`
+    BmpString,
+    /// `GeneralString`
+    GeneralString,
+    /// `GraphicString`
+    GraphicString,
+    /// `TeletexString`
+    TeletexString,
+    /// `UTCTime`
+    UtcTime,
+    /// `GeneralizedTime`
+    GeneralizedTime,
+    /// `DATE`
+    Date,
+    /// `TIME`
+    Time,
+    /// `TIME-OF-DAY`
+    TimeOfDay,
+    /// `DATE-TIME`
+    DateTime,
+    /// `DURATION`
+    Duration,
+    /// An open type, decoded as its raw encoding.
+    Any,
+    /// `ENUMERATED`
+    Enumerated {
+        /// The variants of the root component list.
+        variants: &'a [EnumeratedVariant<'a>],
+        /// The variants of the extension list, `Some` if the type is
+        /// extensible even if no additions are known.
+        extended_variants: Option<&'a [EnumeratedVariant<'a>]>,
+    },
+    /// `SEQUENCE`
+    Sequence {
+        /// The fields of the root component list.
+        fields: &'a [FieldDescriptor<'a>],
+        /// The extension additions, `Some` if the type is extensible even if
+        /// no additions are known.
+        extended_fields: Option<&'a [FieldDescriptor<'a>]>,
+    },
+    /// `SET`
+    Set {
+        /// The fields of the root component list.
+        fields: &'a [FieldDescriptor<'a>],
+        /// The extension additions, `Some` if the type is extensible even if
+        /// no additions are known.
+        extended_fields: Option<&'a [FieldDescriptor<'a>]>,
+    },
+    /// `CHOICE`
+    Choice {
+        /// The variants of the root alternative list.
+        variants: &'a [VariantDescriptor<'a>],
+        /// The extension additions, `Some` if the type is extensible even if
+        /// no additions are known.
+        extended_variants: Option<&'a [VariantDescriptor<'a>]>,
+    },
+    /// `SEQUENCE OF`
+    SequenceOf {
+        /// The type of each element.
+        element: &'a TypeDescriptor<'a>,
+    },
+    /// `SET OF`
+    SetOf {
+        /// The type of each element.
+        element: &'a TypeDescriptor<'a>,
+    },
+    /// An explicitly tagged type, [`TypeDescriptor::tag`] is the tag of the
+    /// outer prefix.
+    Explicit {
+        /// The type inside of the prefix.
+        inner: &'a TypeDescriptor<'a>,
+    },
+    /// A reference to a type which contains itself, such as a recursive type
+    /// of a [`Schema`](crate::schema::Schema). [`TypeDescriptor::tag`] and
+    /// [`TypeDescriptor::constraints`] are those of the referenced type, see
+    /// [`TypeDescriptor::resolve`].
+    Reference(&'a TypeReference<'a>),
+}
+
+/// The target of a [`TypeKind::Reference`], which is set once the type it
+/// refers to has been described.
+pub struct TypeReference<'a> {
+    target: AtomicPtr<()>,
+    _marker: PhantomData<&'a TypeDescriptor<'a>>,
+}
+
+impl<'a> TypeReference<'a> {
+    /// Creates a reference to a type that hasn't been described yet.
+    #[cfg(feature = "schema")]
+    pub(crate) const fn new() -> Self {
+        Self {
+            target: AtomicPtr::new(core::ptr::null_mut()),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the descriptor of the referenced type, or `None` while it's
+    /// still being described.
+    #[must_use]
+    pub fn get(&self) -> Option<&'a TypeDescriptor<'a>> {
+        let target = self.target.load(Ordering::Acquire);
+        // SAFETY: The target is either null or was set from a reference of
+        // the same lifetime by `set`.
+        unsafe { target.cast::<TypeDescriptor<'a>>().as_ref() }
+    }
+
+    /// Sets the descriptor of the referenced type. Must only be called before
+    /// the reference is shared, as `'a` can be shortened afterwards.
+    #[cfg(feature = "schema")]
+    pub(crate) fn set(&self, target: &'a TypeDescriptor<'a>) {
+        let target = core::ptr::from_ref(target).cast_mut().cast();
+        self.target.store(target, Ordering::Release);
+    }
+}
+
+impl core::fmt::Debug for TypeReference<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // The referenced type contains the reference, so only its name is
+        // written.
+        f.debug_tuple("TypeReference")
+            .field(&self.get().map(|target| target.identifier))
+            .finish()
+    }
+}
+
+/// A field in a `SEQUENCE` or `SET` type.
+#[derive(Debug, Clone, Copy)]
+pub struct FieldDescriptor<'a> {
+    /// The name of the field.
+    pub name: &'a str,
+    /// The presence requirement of the field.
+    pub presence: FieldPresence,
+    /// The type of the field, including any tag or constraints applied to it.
+    pub ty: &'a TypeDescriptor<'a>,
+}
+
+/// An alternative of a `CHOICE` type.
+#[derive(Debug, Clone, Copy)]
+pub struct VariantDescriptor<'a> {
+    /// The name of the alternative.
+    pub name: &'a str,
+    /// The type of the alternative, including any tag or constraints applied to it.
+    pub ty: &'a TypeDescriptor<'a>,
+}
+
+/// A named value of an `ENUMERATED` type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EnumeratedVariant<'a> {
+    /// The identifier of the value.
+    pub name: &'a str,
+    /// The discriminant of the value.
+    pub discriminant: isize,
+}
+
+/// A type with a runtime description, that can be derived with
+/// `#[derive(Describe)]`.
+pub trait Describe: AsnType {
+    /// The description of the type.
+    const DESCRIPTOR: TypeDescriptor<'static>;
+}
+
+macro_rules! describe {
+    ($($ty:ty: $kind:ident),+ $(,)?) => {
+        $(
+            impl Describe for $ty {
+                const DESCRIPTOR: TypeDescriptor<'static> = TypeDescriptor::of::<Self>(TypeKind::$kind);
+            }
+        )+
+    }
+}
+
+describe! {
+    bool: Boolean,
+    i8: Integer,
+    i16: Integer,
+    i32: Integer,
+    i64: Integer,
+    i128: Integer,
+    isize: Integer,
+    u8: Integer,
+    u16: Integer,
+    u32: Integer,
+    u64: Integer,
+    u128: Integer,
+    usize: Integer,
+    super::Integer: Integer,
+    (): Null,
+    super::ObjectIdentifier: ObjectIdentifier,
+    super::EncodedObjectIdentifier: ObjectIdentifier,
+    super::RelativeObjectIdentifier: RelativeOid,
+    super::OidIri: OidIri,
+    super::RelativeOidIri: RelativeOidIri,
+    super::External: External,
+    super::BitString: BitString,
+    super::OctetString: OctetString,
+    super::Utf8String: Utf8String,
+    super::Ia5String: Ia5String,
+    super::PrintableString: PrintableString,
+    super::VisibleString: VisibleString,
+    super::NumericString: NumericString,
+    super::BmpString: BmpString,
+    super::GeneralString: GeneralString,
+    super::GraphicString: GraphicString,
+    super::TeletexString: TeletexString,
+    super::UtcTime: UtcTime,
+    super::GeneralizedTime: GeneralizedTime,
+    super::Date: Date,
+    super::IsoTime: Time,
+    super::TimeOfDay: TimeOfDay,
+    super::DateTime: DateTime,
+    super::IsoDuration: Duration,
+    super::Any: Any,
+}
+
+#[cfg(feature = "f32")]
+describe!(f32: Real);
+#[cfg(feature = "f64")]
+describe!(f64: Real);
+
+impl<const START: i128, const END: i128> Describe for super::ConstrainedInteger<START, END> {
+    const DESCRIPTOR: TypeDescriptor<'static> = TypeDescriptor::of::<Self>(TypeKind::Integer);
+}
+
+impl<const N: usize> Describe for super::FixedBitString<N> {
+    const DESCRIPTOR: TypeDescriptor<'static> = TypeDescriptor::of::<Self>(TypeKind::BitString);
+}
+
+impl<const N: usize> Describe for super::FixedOctetString<N> {
+    const DESCRIPTOR: TypeDescriptor<'static> = TypeDescriptor::of::<Self>(TypeKind::OctetString);
+}
+
+impl<T: Describe> Describe for alloc::vec::Vec<T> {
+    const DESCRIPTOR: TypeDescriptor<'static> = TypeDescriptor::of::<Self>(TypeKind::SequenceOf {
+        element: const { &T::DESCRIPTOR },
+    });
+}
+
+impl<T: Describe, const N: usize> Describe for [T; N] {
+    const DESCRIPTOR: TypeDescriptor<'static> = TypeDescriptor::of::<Self>(TypeKind::SequenceOf {
+        element: const { &T::DESCRIPTOR },
+    });
+}
+
+impl<T: Describe> Describe for SetOf<T> {
+    const DESCRIPTOR: TypeDescriptor<'static> = TypeDescriptor::of::<Self>(TypeKind::SetOf {
+        element: const { &T::DESCRIPTOR },
+    });
+}
+
+impl<T: Describe> Describe for Option<T> {
+    const DESCRIPTOR: TypeDescriptor<'static> = T::DESCRIPTOR;
+}
+
+impl<T: Describe> Describe for Box<T> {
+    const DESCRIPTOR: TypeDescriptor<'static> = T::DESCRIPTOR;
+}
+
+impl<T: AsnType, V: Describe> Describe for Implicit<T, V> {
+    const DESCRIPTOR: TypeDescriptor<'static> = V::DESCRIPTOR.with_tag(T::TAG);
+}
+
+impl<T: AsnType, V: Describe> Describe for super::Explicit<T, V> {
+    const DESCRIPTOR: TypeDescriptor<'static> = TypeDescriptor::new(
+        V::IDENTIFIER.0,
+        T::TAG,
+        TypeKind::Explicit {
+            inner: const { &V::DESCRIPTOR },
+        },
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::constraints::Bounded;
+
+    #[test]
+    fn builtin() {
+        assert!(matches!(u8::DESCRIPTOR.kind, TypeKind::Integer));
+        assert_eq!(u8::DESCRIPTOR.tag, Tag::INTEGER);
+        assert_eq!(
+            u8::DESCRIPTOR.constraints.value(),
+            Some(Bounded::const_new(0, 255))
+        );
+
+        let TypeKind::SequenceOf { element } = <alloc::vec::Vec<bool>>::DESCRIPTOR.kind else {
+            panic!("expected SEQUENCE OF");
+        };
+        assert_eq!(<alloc::vec::Vec<bool>>::DESCRIPTOR.tag, Tag::SEQUENCE);
+        assert!(matches!(element.kind, TypeKind::Boolean));
+
+        assert!(matches!(
+            <Option<super::super::Utf8String>>::DESCRIPTOR.kind,
+            TypeKind::Utf8String
+        ));
+    }
+
+    #[test]
+    fn prefixed() {
+        type Tagged = Implicit<crate::types::tag::BIT_STRING, super::super::OctetString>;
+        assert_eq!(Tagged::DESCRIPTOR.tag, Tag::BIT_STRING);
+        assert!(matches!(Tagged::DESCRIPTOR.kind, TypeKind::OctetString));
+    }
+}
@@ -0,0 +1,262 @@
+use crate::{
+    de::Error as _,
+    enc::Error as _,
+    types::{
+        Any, BitString, Describe, Integer, ObjectDescriptor, ObjectIdentifier, OctetString, Tag,
+    },
+    AsnType, Decode, Decoder, Encode, Encoder,
+};
+
+use super::{Constraints, Identifier};
+
+/// The `identification` of an [`External`], [`EmbeddedPdv`] or
+/// [`CharacterString`] value, which identifies the abstract and transfer
+/// syntax of its data.
+#[derive(AsnType, Clone, Debug, Decode, Describe, Encode, PartialEq, Eq, Hash)]
+#[rasn(crate_root = "crate", choice, automatic_tags)]
+pub enum Identification {
+    /// The abstract and transfer syntax.
+    #[rasn(identifier = "syntaxes")]
+    Syntaxes(Syntaxes),
+    /// A single object identifier for both the abstract and transfer syntax.
+    #[rasn(identifier = "syntax")]
+    Syntax(ObjectIdentifier),
+    /// A presentation context negotiated by the OSI presentation layer.
+    #[rasn(identifier = "presentation-context-id")]
+    PresentationContextId(Integer),
+    /// A presentation context which is still being negotiated.
+    #[rasn(identifier = "context-negotiation")]
+    ContextNegotiation(ContextNegotiation),
+    /// The transfer syntax, with the abstract syntax known by both parties.
+    #[rasn(identifier = "transfer-syntax")]
+    TransferSyntax(ObjectIdentifier),
+    /// Both syntaxes are known by both parties.
+    #[rasn(identifier = "fixed")]
+    Fixed,
+}
+
+/// The `syntaxes` alternative of an [`Identification`].
+#[derive(AsnType, Clone, Debug, Decode, Describe, Encode, PartialEq, Eq, Hash)]
+#[rasn(crate_root = "crate", automatic_tags)]
+pub struct Syntaxes {
+    /// The abstract syntax of the data.
+    #[rasn(identifier = "abstract")]
+    pub abstract_syntax: ObjectIdentifier,
+    /// The transfer syntax the data is encoded with.
+    #[rasn(identifier = "transfer")]
+    pub transfer_syntax: ObjectIdentifier,
+}
+
+/// The `context-negotiation` alternative of an [`Identification`].
+#[derive(AsnType, Clone, Debug, Decode, Describe, Encode, PartialEq, Eq, Hash)]
+#[rasn(crate_root = "crate", automatic_tags)]
+pub struct ContextNegotiation {
+    /// The presentation context being negotiated.
+    #[rasn(identifier = "presentation-context-id")]
+    pub presentation_context_id: Integer,
+    /// The transfer syntax the data is encoded with.
+    #[rasn(identifier = "transfer-syntax")]
+    pub transfer_syntax: ObjectIdentifier,
+}
+
+/// The `EXTERNAL` type, a value of a type which is defined elsewhere and
+/// carried as its encoding.
+///
+/// Only the `syntax`, `presentation-context-id` and `context-negotiation`
+/// identifications can be encoded, as X.690 §8.18 encodes `EXTERNAL` values
+/// with its 1990 definition.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct External {
+    /// The syntax of the data.
+    pub identification: Identification,
+    /// A description of the data.
+    pub data_value_descriptor: Option<ObjectDescriptor>,
+    /// The encoding of the data.
+    pub data_value: OctetString,
+}
+
+/// The `EMBEDDED PDV` type, a value of a type which is defined elsewhere and
+/// carried as its encoding.
+#[derive(AsnType, Clone, Debug, Decode, Describe, Encode, PartialEq, Eq, Hash)]
+#[rasn(crate_root = "crate", tag(universal, 11), identifier = "SEQUENCE")]
+pub struct EmbeddedPdv {
+    /// The syntax of the data.
+    #[rasn(tag(explicit(0)))]
+    pub identification: Identification,
+    /// The encoding of the data.
+    #[rasn(tag(2), identifier = "data-value")]
+    pub data_value: OctetString,
+}
+
+/// The `CHARACTER STRING` type, a string of any character repertoire and
+/// character encoding.
+#[derive(AsnType, Clone, Debug, Decode, Describe, Encode, PartialEq, Eq, Hash)]
+#[rasn(crate_root = "crate", tag(universal, 29), identifier = "SEQUENCE")]
+pub struct CharacterString {
+    /// The character repertoire and encoding of the string.
+    #[rasn(tag(explicit(0)))]
+    pub identification: Identification,
+    /// The encoded string.
+    #[rasn(tag(2), identifier = "string-value")]
+    pub string_value: OctetString,
+}
+
+/// The associated type of `EXTERNAL` of X.680 §37.5, which is used by JER and
+/// XER.
+#[derive(AsnType, Decode, Encode)]
+#[rasn(crate_root = "crate", automatic_tags, identifier = "EXTERNAL")]
+struct AssociatedExternal {
+    identification: Identification,
+    #[rasn(identifier = "data-value-descriptor")]
+    data_value_descriptor: Option<ObjectDescriptor>,
+    #[rasn(identifier = "data-value")]
+    data_value: OctetString,
+}
+
+/// The definition of `EXTERNAL` of X.690 §8.18.1, which is used by BER, PER
+/// and OER.
+#[derive(AsnType, Decode, Encode)]
+#[rasn(crate_root = "crate")]
+struct LegacyExternal {
+    direct_reference: Option<ObjectIdentifier>,
+    indirect_reference: Option<Integer>,
+    data_value_descriptor: Option<ObjectDescriptor>,
+    encoding: LegacyEncoding,
+}
+
+#[derive(AsnType, Decode, Encode)]
+#[rasn(crate_root = "crate", choice)]
+enum LegacyEncoding {
+    #[rasn(tag(explicit(0)))]
+    SingleAsn1Type(Any),
+    #[rasn(tag(1))]
+    OctetAligned(OctetString),
+    #[rasn(tag(2))]
+    Arbitrary(BitString),
+}
+
+impl External {
+    /// Encodes the value with the definition of X.690 §8.18.1.
+    pub(crate) fn encode_legacy<'b, E: Encoder<'b>>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+    ) -> Result<(), E::Error> {
+        let (direct_reference, indirect_reference) = match &self.identification {
+            Identification::Syntax(syntax) => (Some(syntax.clone()), None),
+            Identification::PresentationContextId(id) => (None, Some(id.clone())),
+            Identification::ContextNegotiation(negotiation) => (
+                Some(negotiation.transfer_syntax.clone()),
+                Some(negotiation.presentation_context_id.clone()),
+            ),
+            _ => {
+                return Err(E::Error::custom(
+                    "EXTERNAL identification must be `syntax`, `presentation-context-id` or `context-negotiation`",
+                    encoder.codec(),
+                ))
+            }
+        };
+
+        LegacyExternal {
+            direct_reference,
+            indirect_reference,
+            data_value_descriptor: self.data_value_descriptor.clone(),
+            encoding: LegacyEncoding::OctetAligned(self.data_value.clone()),
+        }
+        .encode_with_tag(encoder, tag)
+    }
+
+    /// Decodes a value with the definition of X.690 §8.18.1.
+    pub(crate) fn decode_legacy<D: Decoder>(decoder: &mut D, tag: Tag) -> Result<Self, D::Error> {
+        let external = LegacyExternal::decode_with_tag(decoder, tag)?;
+        let identification = match (external.direct_reference, external.indirect_reference) {
+            (Some(syntax), None) => Identification::Syntax(syntax),
+            (None, Some(id)) => Identification::PresentationContextId(id),
+            (Some(transfer_syntax), Some(presentation_context_id)) => {
+                Identification::ContextNegotiation(ContextNegotiation {
+                    presentation_context_id,
+                    transfer_syntax,
+                })
+            }
+            (None, None) => {
+                return Err(D::Error::custom(
+                    "EXTERNAL has neither a direct nor an indirect reference",
+                    decoder.codec(),
+                ))
+            }
+        };
+        let data_value = match external.encoding {
+            LegacyEncoding::SingleAsn1Type(any) => any.into_bytes().into(),
+            LegacyEncoding::OctetAligned(octets) => octets,
+            LegacyEncoding::Arbitrary(bits) if bits.len() % 8 == 0 => bits.into_vec().into(),
+            LegacyEncoding::Arbitrary(_) => {
+                return Err(D::Error::custom(
+                    "EXTERNAL `arbitrary` encodings must be a multiple of eight bits",
+                    decoder.codec(),
+                ))
+            }
+        };
+
+        Ok(Self {
+            identification,
+            data_value_descriptor: external.data_value_descriptor,
+            data_value,
+        })
+    }
+
+    /// Encodes the value as its associated type.
+    pub(crate) fn encode_associated<'b, E: Encoder<'b>>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        identifier: Identifier,
+    ) -> Result<(), E::Error> {
+        AssociatedExternal {
+            identification: self.identification.clone(),
+            data_value_descriptor: self.data_value_descriptor.clone(),
+            data_value: self.data_value.clone(),
+        }
+        .encode_with_tag_and_constraints(encoder, tag, Constraints::default(), identifier)
+    }
+
+    /// Decodes a value of its associated type.
+    pub(crate) fn decode_associated<D: Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+    ) -> Result<Self, D::Error> {
+        let external = AssociatedExternal::decode_with_tag(decoder, tag)?;
+
+        Ok(Self {
+            identification: external.identification,
+            data_value_descriptor: external.data_value_descriptor,
+            data_value: external.data_value,
+        })
+    }
+}
+
+impl AsnType for External {
+    const TAG: Tag = Tag::EXTERNAL;
+    const IDENTIFIER: Identifier = Identifier::EXTERNAL;
+}
+
+impl Decode for External {
+    fn decode_with_tag_and_constraints<D: Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+        _constraints: Constraints,
+    ) -> Result<Self, D::Error> {
+        decoder.decode_external(tag)
+    }
+}
+
+impl Encode for External {
+    fn encode_with_tag_and_constraints<'b, E: Encoder<'b>>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        _constraints: Constraints,
+        identifier: Identifier,
+    ) -> Result<(), E::Error> {
+        encoder.encode_external(tag, self, identifier).map(drop)
+    }
+}
@@ -0,0 +1,203 @@
+use alloc::string::String;
+use core::{fmt, str::FromStr};
+
+use crate::error::strings::InvalidIriString;
+use crate::prelude::Constraints;
+use crate::{types::Tag, AsnType, Decode, Decoder, Encode, Encoder};
+
+use super::Identifier;
+
+/// The `OID-IRI` type, an object identifier written as a `/` separated path
+/// of Unicode labels from the root of the international OID tree, such as
+/// `"/ISO/Registration-Authority/19785.CBEFF"`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct OidIri(String);
+
+/// The `RELATIVE-OID-IRI` type, a `/` separated path of Unicode labels below
+/// some known base [`OidIri`], such as `"Registration-Authority/19785.CBEFF"`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RelativeOidIri(String);
+
+impl OidIri {
+    /// Returns the string of the value.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the Unicode labels of the arcs, from the root down.
+    pub fn labels(&self) -> impl Iterator<Item = &str> {
+        self.0[1..].split('/')
+    }
+}
+
+impl RelativeOidIri {
+    /// Returns the string of the value.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the Unicode labels of the arcs.
+    pub fn labels(&self) -> impl Iterator<Item = &str> {
+        self.0.split('/')
+    }
+}
+
+/// Whether `label` is an integer or non-integer Unicode label of X.680
+/// clause 12.26.
+fn is_valid_label(label: &str) -> bool {
+    if label.bytes().all(|byte| byte.is_ascii_digit()) {
+        return label == "0" || (!label.is_empty() && !label.starts_with('0'));
+    }
+
+    label.chars().all(|c| {
+        c.is_ascii_alphanumeric()
+            || "-._~".contains(c)
+            || (!c.is_ascii() && !c.is_control() && !c.is_whitespace())
+    }) && !label.starts_with('-')
+        && !label.ends_with('-')
+        && label.get(2..4) != Some("--")
+}
+
+impl FromStr for OidIri {
+    type Err = InvalidIriString;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string.strip_prefix('/') {
+            Some(path) if path.split('/').all(is_valid_label) => Ok(Self(string.into())),
+            _ => Err(InvalidIriString {
+                kind: "OID-IRI",
+                value: string.into(),
+            }),
+        }
+    }
+}
+
+impl FromStr for RelativeOidIri {
+    type Err = InvalidIriString;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        if string.split('/').all(is_valid_label) {
+            Ok(Self(string.into()))
+        } else {
+            Err(InvalidIriString {
+                kind: "RELATIVE-OID-IRI",
+                value: string.into(),
+            })
+        }
+    }
+}
+
+impl fmt::Display for OidIri {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl fmt::Display for RelativeOidIri {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl AsnType for OidIri {
+    const TAG: Tag = Tag::OID_IRI;
+    const IDENTIFIER: Identifier = Identifier::IRI;
+}
+
+impl Decode for OidIri {
+    fn decode_with_tag_and_constraints<D: Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+        _constraints: Constraints,
+    ) -> Result<Self, D::Error> {
+        decoder.decode_oid_iri(tag)
+    }
+}
+
+impl Encode for OidIri {
+    fn encode_with_tag_and_constraints<'b, E: Encoder<'b>>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        _constraints: Constraints,
+        identifier: Identifier,
+    ) -> Result<(), E::Error> {
+        encoder.encode_oid_iri(tag, self, identifier).map(drop)
+    }
+}
+
+impl AsnType for RelativeOidIri {
+    const TAG: Tag = Tag::RELATIVE_OID_IRI;
+    const IDENTIFIER: Identifier = Identifier::RELATIVE_IRI;
+}
+
+impl Decode for RelativeOidIri {
+    fn decode_with_tag_and_constraints<D: Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+        _constraints: Constraints,
+    ) -> Result<Self, D::Error> {
+        decoder.decode_relative_oid_iri(tag)
+    }
+}
+
+impl Encode for RelativeOidIri {
+    fn encode_with_tag_and_constraints<'b, E: Encoder<'b>>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        _constraints: Constraints,
+        identifier: Identifier,
+    ) -> Result<(), E::Error> {
+        encoder
+            .encode_relative_oid_iri(tag, self, identifier)
+            .map(drop)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn iri_strings() {
+        for string in [
+            "/ISO/Registration-Authority/19785.CBEFF",
+            "/Joint-ISO-ITU-T/0",
+            "/ITU-T/Identification_Cards~1",
+            "/Übung",
+        ] {
+            let iri = string.parse::<OidIri>().unwrap();
+            assert_eq!(iri.to_string(), string);
+        }
+        assert_eq!(
+            "/ISO/Member-Body/840"
+                .parse::<OidIri>()
+                .unwrap()
+                .labels()
+                .collect::<alloc::vec::Vec<_>>(),
+            ["ISO", "Member-Body", "840"]
+        );
+
+        for string in [
+            "", "/", "ISO", "/ISO/", "/ISO//0", "/-ISO", "/01", "/ab--c", "/a b",
+        ] {
+            assert!(string.parse::<OidIri>().is_err(), "{string}");
+        }
+    }
+
+    #[test]
+    fn relative_iri_strings() {
+        let iri = "Registration-Authority/19785.CBEFF"
+            .parse::<RelativeOidIri>()
+            .unwrap();
+        assert_eq!(iri.labels().count(), 2);
+        assert_eq!(iri.as_str(), "Registration-Authority/19785.CBEFF");
+
+        for string in ["", "/ISO", "ISO/", "007"] {
+            assert!(string.parse::<RelativeOidIri>().is_err(), "{string}");
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use super::Any;
+use crate::{
+    error::{DecodeError, EncodeError},
+    Codec,
+};
+
+/// A set of information objects, each identified by a unique field (e.g.
+/// `&id` of an `EXTENSION` or `ALGORITHM` class) and determining the type of
+/// an open type value.
+///
+/// An open type field constrained by an object set and a component relation
+/// constraint (`{ObjectSet}{@id}`) can be declared with the type implementing
+/// the object set and `#[rasn(table(id))]`. It's then decoded as the value of
+/// the object identified by the sibling field `id`, which must precede it,
+/// and encoding it fails if it belongs to a different object.
+///
+/// Usually derived on an enum with a single-field tuple variant per object,
+/// annotated with `#[rasn(id = ...)]`. A variant without an identifier holds
+/// the [`Any`] value of unknown objects, otherwise decoding them fails. The
+/// derive also implements `AsnType` and `Encode` to encode the value as an
+/// open type.
+///
+/// ```rust
+/// use rasn::prelude::*;
+///
+/// #[derive(Debug, PartialEq, InformationObjectSet)]
+/// #[rasn(id_type = Integer)]
+/// enum Payload {
+///     #[rasn(id = 1)]
+///     Text(Utf8String),
+///     #[rasn(id = 2)]
+///     Counter(u32),
+///     Unknown(Any),
+/// }
+///
+/// #[derive(Debug, PartialEq, AsnType, Decode, Encode)]
+/// struct Message {
+///     kind: Integer,
+///     #[rasn(table(kind))]
+///     payload: Payload,
+/// }
+///
+/// let message = Message {
+///     kind: 2.into(),
+///     payload: Payload::Counter(7),
+/// };
+/// let encoded = rasn::uper::encode(&message).unwrap();
+/// assert_eq!(message, rasn::uper::decode(&encoded).unwrap());
+/// ```
+pub trait InformationObjectSet: Sized {
+    /// The type of the identifying field, usually an
+    /// [`ObjectIdentifier`][super::ObjectIdentifier] or an
+    /// [`Integer`][super::Integer].
+    type Identifier: core::fmt::Debug + PartialEq;
+
+    /// Returns the identifier of the object of this value, or `None` if the
+    /// object is unknown.
+    fn identifier(&self) -> Option<Self::Identifier>;
+
+    /// Decodes the value of the object identified by `identifier` from
+    /// `value`, an open type encoded with `codec`.
+    ///
+    /// # Errors
+    /// Returns an error if no object is identified by `identifier`, or if
+    /// `value` isn't a valid value of the object.
+    fn decode_value(
+        codec: Codec,
+        identifier: &Self::Identifier,
+        value: &Any,
+    ) -> Result<Self, DecodeError>;
+
+    /// Encodes this value as an open type with `codec`.
+    ///
+    /// # Errors
+    /// Returns an error if the value fails to be encoded.
+    fn encode_value(&self, codec: Codec) -> Result<Any, EncodeError>;
+}
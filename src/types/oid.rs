@@ -1,5 +1,14 @@
 #![allow(clippy::unreadable_literal)]
-use core::ops;
+use alloc::vec::Vec;
+use core::{ops, str::FromStr};
+
+use crate::error::strings::InvalidOidString;
+
+mod encoded;
+#[cfg(feature = "oid-registry")]
+mod registry;
+
+pub use encoded::{EncodedObjectIdentifier, EncodedOid};
 
 pub(crate) const MAX_OID_FIRST_OCTET: u32 = 2;
 pub(crate) const MAX_OID_SECOND_OCTET: u32 = 39;
@@ -82,15 +91,60 @@ impl Oid {
     }
 }
 
+#[cfg(feature = "oid-registry")]
+#[cfg_attr(docsrs, doc(cfg(feature = "oid-registry")))]
+impl Oid {
+    /// Returns the name of the object identifier if it's in the registry of
+    /// well-known object identifiers.
+    /// ```
+    /// use rasn::types::Oid;
+    ///
+    /// assert_eq!(Oid::ISO_MEMBER_BODY_US_RSADSI_PKCS1_RSA.name(), Some("rsaEncryption"));
+    /// ```
+    #[must_use]
+    pub fn name(&self) -> Option<&'static str> {
+        registry::REGISTRY
+            .iter()
+            .find(|(arcs, _)| *arcs == &self.0)
+            .map(|(_, name)| *name)
+    }
+
+    /// Returns the well-known object identifier registered as `name`.
+    /// ```
+    /// use rasn::types::Oid;
+    ///
+    /// let oid = Oid::from_name("sha256WithRSAEncryption").unwrap();
+    /// assert_eq!(*oid, [1, 2, 840, 113549, 1, 1, 11]);
+    /// ```
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<&'static Self> {
+        registry::REGISTRY
+            .iter()
+            .find(|(_, registered)| *registered == name)
+            .map(|(arcs, _)| Self::new_unchecked(arcs))
+    }
+}
+
 impl core::fmt::Display for Oid {
-    /// Formats the object identifier as dot separated components.
+    /// Formats the object identifier as dot separated components, or with
+    /// the alternate flag (`{:#}`) as its registered name if it has one and
+    /// the `oid-registry` feature is enabled.
     /// ```
     /// use rasn::types::Oid;
     ///
     /// let internet = Oid::new(&[1, 3, 6, 1]).unwrap();
     /// assert_eq!(&format!("{internet}"), "1.3.6.1");
+    /// # #[cfg(feature = "oid-registry")]
+    /// assert_eq!(&format!("{internet:#}"), "internet");
     /// ```
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        #[cfg(feature = "oid-registry")]
+        if f.alternate() {
+            if let Some(name) = self.name() {
+                return f.write_str(name);
+            }
+        }
+
         let mut oid_iter = self.0.iter();
         if let Some(first) = oid_iter.next() {
             write!(f, "{first}")?;
@@ -174,6 +228,12 @@ impl ops::DerefMut for Oid {
 
 /// A global unique identifier that identifies an concept, such as a
 /// organisation, or encoding rules. The "owned" version of [`Oid`].
+///
+/// Its arcs are limited to `u32`, so decoding an object identifier with a
+/// larger arc, such as the UUID arcs below `2.25` (X.667), fails. Types
+/// which must decode any object identifier can use
+/// [`EncodedObjectIdentifier`] instead, which compares equal to the [`Oid`]
+/// with the same arcs.
 #[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
 pub struct ObjectIdentifier(alloc::borrow::Cow<'static, [u32]>);
 
@@ -211,6 +271,105 @@ impl core::fmt::Display for ObjectIdentifier {
     }
 }
 
+impl FromStr for ObjectIdentifier {
+    type Err = InvalidOidString;
+
+    /// Parses an object identifier from dot separated components, or from
+    /// ASN.1 value notation with number, name and number, and the name forms
+    /// of X.660 for the top arcs. With the `oid-registry` feature, the first
+    /// component in value notation can also be a registered name.
+    /// ```
+    /// use rasn::types::ObjectIdentifier;
+    ///
+    /// let rsadsi: ObjectIdentifier = "1.2.840.113549".parse().unwrap();
+    /// let value = "{ iso member-body(2) us(840) 113549 }";
+    /// assert_eq!(rsadsi, value.parse::<ObjectIdentifier>().unwrap());
+    /// ```
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let trimmed = string.trim();
+        let arcs = match trimmed
+            .strip_prefix('{')
+            .and_then(|components| components.strip_suffix('}'))
+        {
+            Some(components) => parse_value_notation(components),
+            None => trimmed.split('.').map(parse_arc).collect(),
+        };
+
+        arcs.and_then(Self::new).ok_or_else(|| InvalidOidString {
+            value: string.into(),
+        })
+    }
+}
+
+/// The arcs which can be written with only their name in value notation, by
+/// the arcs above them.
+const NAME_FORMS: &[(&[u32], &str, u32)] = &[
+    (&[], "itu-t", 0),
+    (&[], "ccitt", 0),
+    (&[], "iso", 1),
+    (&[], "joint-iso-itu-t", 2),
+    (&[], "joint-iso-ccitt", 2),
+    (&[0], "recommendation", 0),
+    (&[0], "question", 1),
+    (&[0], "administration", 2),
+    (&[0], "network-operator", 3),
+    (&[0], "identified-organization", 4),
+    (&[1], "standard", 0),
+    (&[1], "registration-authority", 1),
+    (&[1], "member-body", 2),
+    (&[1], "identified-organization", 3),
+];
+
+fn parse_arc(arc: &str) -> Option<u32> {
+    if arc.is_empty() || !arc.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    arc.parse().ok()
+}
+
+/// Parses the components between the braces of an object identifier value.
+fn parse_value_notation(components: &str) -> Option<Vec<u32>> {
+    let mut arcs = Vec::new();
+    let mut rest = components.trim_start();
+    while !rest.is_empty() {
+        let end = rest
+            .find(|c: char| c.is_whitespace() || c == '(')
+            .unwrap_or(rest.len());
+        let (word, tail) = rest.split_at(end);
+        let tail = tail.trim_start();
+
+        if let Some(tail) = tail.strip_prefix('(') {
+            let (number, tail) = tail.split_once(')')?;
+            if !word.starts_with(|c: char| c.is_ascii_lowercase()) {
+                return None;
+            }
+            arcs.push(parse_arc(number.trim())?);
+            rest = tail.trim_start();
+            continue;
+        }
+
+        if let Some(arc) = parse_arc(word) {
+            arcs.push(arc);
+        } else if let Some(&(_, _, arc)) = NAME_FORMS
+            .iter()
+            .find(|(above, name, _)| *above == arcs.as_slice() && *name == word)
+        {
+            arcs.push(arc);
+        } else {
+            #[cfg(feature = "oid-registry")]
+            if arcs.is_empty() {
+                arcs.extend_from_slice(Oid::from_name(word)?);
+                rest = tail;
+                continue;
+            }
+            return None;
+        }
+        rest = tail;
+    }
+
+    Some(arcs)
+}
+
 impl AsRef<[u32]> for ObjectIdentifier {
     fn as_ref(&self) -> &[u32] {
         self.0.as_ref()
@@ -267,6 +426,261 @@ impl PartialEq<[u32]> for ObjectIdentifier {
     }
 }
 
+/// A reference to a relative object identifier, the arcs which identify a
+/// concept below some known base [`Oid`].
+///
+/// Adding a relative object identifier to an object identifier appends its
+/// arcs.
+/// ```
+/// use rasn::types::{Oid, RelativeOid};
+///
+/// let mut microsoft =
+///     Oid::ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_PRIVATE + RelativeOid::new(&[1, 311]).unwrap();
+/// assert_eq!(microsoft.to_string(), "1.3.6.1.4.1.311");
+/// microsoft += RelativeOid::new(&[2, 1, 4]).unwrap();
+/// assert_eq!(
+///     microsoft,
+///     Oid::ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_PRIVATE_ENTERPRISES_MICROSOFT_SPC_INDIRECT_DATA_OBJID
+/// );
+/// ```
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct RelativeOid([u32]);
+
+impl RelativeOid {
+    /// Creates a new reference to a relative object identifier from `slice`.
+    ///
+    /// Returns `None` if `slice` is empty.
+    /// ```
+    /// use rasn::types::RelativeOid;
+    ///
+    /// let private_enterprise = RelativeOid::new(&[4, 1]).unwrap();
+    /// ```
+    #[must_use]
+    pub const fn new(slice: &[u32]) -> Option<&Self> {
+        if slice.is_empty() {
+            None
+        } else {
+            Some(Self::new_unchecked(slice))
+        }
+    }
+
+    /// Creates a new reference to a relative object identifier from `slice`.
+    ///
+    /// Panics if `slice` is empty.
+    #[must_use]
+    pub const fn const_new(slice: &'static [u32]) -> &'static Self {
+        match Self::new(slice) {
+            Some(oid) => oid,
+            None => panic!("not a valid relative OID"),
+        }
+    }
+
+    /// Creates a new reference to a relative object identifier from `slice`.
+    ///
+    /// # Safety
+    /// This allows you to create empty relative object identifiers which
+    /// may affect encoding validity.
+    #[must_use]
+    pub const fn new_unchecked(slice: &[u32]) -> &Self {
+        unsafe { &*(core::ptr::from_ref::<[u32]>(slice) as *const Self) }
+    }
+}
+
+impl Oid {
+    /// Returns the arcs of `self` which follow `base`, or `None` if `self`
+    /// isn't below `base`.
+    /// ```
+    /// use rasn::types::Oid;
+    ///
+    /// let relative = Oid::ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_PRIVATE_ENTERPRISES_MICROSOFT_SPC_INDIRECT_DATA_OBJID
+    ///     .relative_to(Oid::ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_PRIVATE)
+    ///     .unwrap();
+    /// assert_eq!(relative.to_string(), "1.311.2.1.4");
+    /// ```
+    #[must_use]
+    pub fn relative_to(&self, base: &Oid) -> Option<&RelativeOid> {
+        self.0.strip_prefix(&base.0).and_then(RelativeOid::new)
+    }
+}
+
+impl core::fmt::Display for RelativeOid {
+    /// Formats the relative object identifier as dot separated components.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Oid::new_unchecked(&self.0).fmt(f)
+    }
+}
+
+impl alloc::borrow::ToOwned for RelativeOid {
+    type Owned = RelativeObjectIdentifier;
+
+    fn to_owned(&self) -> Self::Owned {
+        Self::Owned::new_unchecked(self.0.to_owned().into())
+    }
+}
+
+impl AsRef<[u32]> for RelativeOid {
+    fn as_ref(&self) -> &[u32] {
+        &self.0
+    }
+}
+
+impl PartialEq<[u32]> for RelativeOid {
+    fn eq(&self, rhs: &[u32]) -> bool {
+        &self.0 == rhs
+    }
+}
+
+impl<const N: usize> PartialEq<[u32; N]> for RelativeOid {
+    fn eq(&self, rhs: &[u32; N]) -> bool {
+        &self.0 == rhs
+    }
+}
+
+impl PartialEq<RelativeOid> for RelativeObjectIdentifier {
+    fn eq(&self, rhs: &RelativeOid) -> bool {
+        *self.0 == rhs.0
+    }
+}
+
+impl PartialEq<RelativeObjectIdentifier> for RelativeOid {
+    fn eq(&self, rhs: &RelativeObjectIdentifier) -> bool {
+        self.0 == *rhs.0
+    }
+}
+
+impl ops::Deref for RelativeOid {
+    type Target = [u32];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// A relative object identifier, the "owned" version of [`RelativeOid`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct RelativeObjectIdentifier(alloc::borrow::Cow<'static, [u32]>);
+
+impl RelativeObjectIdentifier {
+    /// Creates a new relative object identifier from `arcs`.
+    ///
+    /// Returns `None` if `arcs` is empty.
+    pub fn new(arcs: impl Into<alloc::borrow::Cow<'static, [u32]>>) -> Option<Self> {
+        let arcs = arcs.into();
+        (!arcs.is_empty()).then_some(Self(arcs))
+    }
+
+    /// Creates a new relative object identifier from `vec`.
+    ///
+    /// # Safety
+    /// This allows you to create empty relative object identifiers which
+    /// may affect encoding validity.
+    #[must_use]
+    pub const fn new_unchecked(vec: alloc::borrow::Cow<'static, [u32]>) -> Self {
+        Self(vec)
+    }
+}
+
+impl core::fmt::Display for RelativeObjectIdentifier {
+    /// Formats the relative object identifier as dot separated components.
+    /// ```
+    /// use rasn::types::RelativeObjectIdentifier;
+    ///
+    /// let relative = RelativeObjectIdentifier::new(&[4, 1, 311]).unwrap();
+    /// assert_eq!(&format!("{relative}"), "4.1.311");
+    /// ```
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        ops::Deref::deref(self).fmt(f)
+    }
+}
+
+impl AsRef<[u32]> for RelativeObjectIdentifier {
+    fn as_ref(&self) -> &[u32] {
+        self.0.as_ref()
+    }
+}
+
+impl alloc::borrow::Borrow<RelativeOid> for RelativeObjectIdentifier {
+    fn borrow(&self) -> &RelativeOid {
+        self
+    }
+}
+
+impl<'a> From<&'a RelativeOid> for RelativeObjectIdentifier {
+    fn from(oid: &'a RelativeOid) -> Self {
+        alloc::borrow::ToOwned::to_owned(oid)
+    }
+}
+
+impl ops::Deref for RelativeObjectIdentifier {
+    type Target = RelativeOid;
+
+    fn deref(&self) -> &Self::Target {
+        RelativeOid::new_unchecked(&self.0)
+    }
+}
+
+impl PartialEq<[u32]> for RelativeObjectIdentifier {
+    fn eq(&self, rhs: &[u32]) -> bool {
+        *self.0 == *rhs
+    }
+}
+
+// `base + relative` is the object identifier of `relative` below `base`.
+macro_rules! append_relative_oid {
+    ($($rhs:ty),+) => {
+        $(
+            impl ops::Add<$rhs> for &Oid {
+                type Output = ObjectIdentifier;
+
+                fn add(self, rhs: $rhs) -> Self::Output {
+                    ObjectIdentifier::new_unchecked([&self.0, rhs.as_ref()].concat().into())
+                }
+            }
+
+            impl ops::Add<$rhs> for ObjectIdentifier {
+                type Output = ObjectIdentifier;
+
+                fn add(mut self, rhs: $rhs) -> Self::Output {
+                    self += rhs;
+                    self
+                }
+            }
+
+            impl ops::AddAssign<$rhs> for ObjectIdentifier {
+                fn add_assign(&mut self, rhs: $rhs) {
+                    self.0.to_mut().extend_from_slice(rhs.as_ref());
+                }
+            }
+
+            impl ops::Add<$rhs> for &RelativeOid {
+                type Output = RelativeObjectIdentifier;
+
+                fn add(self, rhs: $rhs) -> Self::Output {
+                    RelativeObjectIdentifier::new_unchecked([&self.0, rhs.as_ref()].concat().into())
+                }
+            }
+
+            impl ops::Add<$rhs> for RelativeObjectIdentifier {
+                type Output = RelativeObjectIdentifier;
+
+                fn add(mut self, rhs: $rhs) -> Self::Output {
+                    self += rhs;
+                    self
+                }
+            }
+
+            impl ops::AddAssign<$rhs> for RelativeObjectIdentifier {
+                fn add_assign(&mut self, rhs: $rhs) {
+                    self.0.to_mut().extend_from_slice(rhs.as_ref());
+                }
+            }
+        )+
+    }
+}
+
+append_relative_oid!(&RelativeOid, &RelativeObjectIdentifier);
+
 macro_rules! oids {
     ($($name:ident => $($num:literal),+ $(,)?);+ $(;)?) => {
         impl Oid {
@@ -348,6 +762,9 @@ oids! {
 
     ISO_MEMBER_BODY_US_ANSI_X942_NUMBER_TYPE_PUBLIC => 1, 2, 840, 10046, 2, 1;
 
+    ISO_MEMBER_BODY_US_ENTRUST_PASSWORD_BASED_MAC => 1, 2, 840, 113533, 7, 66, 13;
+    ISO_MEMBER_BODY_US_ENTRUST_DH_BASED_MAC => 1, 2, 840, 113533, 7, 66, 30;
+
     ISO_MEMBER_BODY_US_RSADSI_PKCS => 1, 2, 840, 113549, 1;
     ISO_MEMBER_BODY_US_RSADSI_PKCS1 => 1, 2, 840, 113549, 1, 1;
     ISO_MEMBER_BODY_US_RSADSI_PKCS1_RSA => 1, 2, 840, 113549, 1, 1, 1;
@@ -365,6 +782,7 @@ oids! {
     ISO_MEMBER_BODY_US_RSADSI_ENCRYPTION_ALGORITHM_DES_EDE3_CBC => 1, 2, 840, 113549, 1, 3, 7;
 
     ISO_MEMBER_BODY_US_RSADSI_PKCS5_PBKDF2 => 1, 2, 840, 113549, 1, 5, 12;
+    ISO_MEMBER_BODY_US_RSADSI_PKCS5_PBES2 => 1, 2, 840, 113549, 1, 5, 13;
     ISO_MEMBER_BODY_US_RSADSI_PKCS5_PBMAC1 => 1, 2, 840, 113549, 1, 5, 14;
 
     ISO_MEMBER_BODY_US_RSADSI_PKCS7_DATA => 1, 2, 840, 113549, 1, 7, 1;
@@ -378,6 +796,8 @@ oids! {
     ISO_MEMBER_BODY_US_RSADSI_PKCS9_MESSAGE_DIGEST => 1, 2, 840, 113549, 1, 9, 4;
     ISO_MEMBER_BODY_US_RSADSI_PKCS9_SIGNING_TIME => 1, 2, 840, 113549, 1, 9, 5;
     ISO_MEMBER_BODY_US_RSADSI_PKCS9_COUNTER_SIGNATURE => 1, 2, 840, 113549, 1, 9, 6;
+    ISO_MEMBER_BODY_US_RSADSI_PKCS9_CHALLENGE_PASSWORD => 1, 2, 840, 113549, 1, 9, 7;
+    ISO_MEMBER_BODY_US_RSADSI_PKCS9_EXTENSION_REQUEST => 1, 2, 840, 113549, 1, 9, 14;
 
     ISO_MEMBER_BODY_US_RSADSI_PKCS9_CAPABILITIES => 1, 2, 840, 113549, 1, 9, 15;
     ISO_MEMBER_BODY_US_RSADSI_PKCS9_SMIME => 1, 2, 840, 113549, 1, 9, 16;
@@ -389,6 +809,7 @@ oids! {
     ISO_MEMBER_BODY_US_RSADSI_PKCS9_SMIME_CT_FIRMWARE_PACKAGE => 1, 2, 840, 113549, 1, 9, 16, 1, 16;
     ISO_MEMBER_BODY_US_RSADSI_PKCS9_SMIME_CT_FIRMWARE_LOAD_RECEIPT => 1, 2, 840, 113549, 1, 9, 16, 1, 17;
     ISO_MEMBER_BODY_US_RSADSI_PKCS9_SMIME_CT_FIRMWARE_LOAD_ERROR => 1, 2, 840, 113549, 1, 9, 16, 1, 18;
+    ISO_MEMBER_BODY_US_RSADSI_PKCS9_SMIME_CT_ENC_KEY_WITH_ID => 1, 2, 840, 113549, 1, 9, 16, 1, 21;
     ISO_MEMBER_BODY_US_RSADSI_PKCS9_SMIME_CT_AUTH_ENVELOPED_DATA => 1, 2, 840, 113549, 1, 9, 16, 1, 23;
      ISO_MEMBER_BODY_US_RSADSI_PKCS9_SMIME_CT_TRUST_ANCHOR_LIST => 1, 2, 840, 113549, 1, 9, 16, 1, 24;
     ISO_MEMBER_BODY_US_RSADSI_PKCS9_SMIME_AA => 1, 2, 840, 113549, 1, 9, 16, 2;
@@ -439,6 +860,25 @@ oids! {
     ISO_MEMBER_BODY_US_RSADSI_PKCS9_SMIME_SKD_MANAGE_CERT => 1, 2, 840, 113549, 1, 9, 16, 8, 14;
     ISO_MEMBER_BODY_US_RSADSI_PKCS9_SMIME_SKD_GLKEY => 1, 2, 840, 113549, 1, 9, 16, 8, 15;
 
+    ISO_MEMBER_BODY_US_RSADSI_PKCS9_FRIENDLY_NAME => 1, 2, 840, 113549, 1, 9, 20;
+    ISO_MEMBER_BODY_US_RSADSI_PKCS9_LOCAL_KEY_ID => 1, 2, 840, 113549, 1, 9, 21;
+    ISO_MEMBER_BODY_US_RSADSI_PKCS9_CERT_TYPES_X509_CERTIFICATE => 1, 2, 840, 113549, 1, 9, 22, 1;
+    ISO_MEMBER_BODY_US_RSADSI_PKCS9_CERT_TYPES_SDSI_CERTIFICATE => 1, 2, 840, 113549, 1, 9, 22, 2;
+    ISO_MEMBER_BODY_US_RSADSI_PKCS9_CRL_TYPES_X509_CRL => 1, 2, 840, 113549, 1, 9, 23, 1;
+
+    ISO_MEMBER_BODY_US_RSADSI_PKCS12_PBE_SHA_AND_128_BIT_RC4 => 1, 2, 840, 113549, 1, 12, 1, 1;
+    ISO_MEMBER_BODY_US_RSADSI_PKCS12_PBE_SHA_AND_40_BIT_RC4 => 1, 2, 840, 113549, 1, 12, 1, 2;
+    ISO_MEMBER_BODY_US_RSADSI_PKCS12_PBE_SHA_AND_3_KEY_TRIPLE_DES_CBC => 1, 2, 840, 113549, 1, 12, 1, 3;
+    ISO_MEMBER_BODY_US_RSADSI_PKCS12_PBE_SHA_AND_2_KEY_TRIPLE_DES_CBC => 1, 2, 840, 113549, 1, 12, 1, 4;
+    ISO_MEMBER_BODY_US_RSADSI_PKCS12_PBE_SHA_AND_128_BIT_RC2_CBC => 1, 2, 840, 113549, 1, 12, 1, 5;
+    ISO_MEMBER_BODY_US_RSADSI_PKCS12_PBE_SHA_AND_40_BIT_RC2_CBC => 1, 2, 840, 113549, 1, 12, 1, 6;
+    ISO_MEMBER_BODY_US_RSADSI_PKCS12_BAG_TYPES_KEY_BAG => 1, 2, 840, 113549, 1, 12, 10, 1, 1;
+    ISO_MEMBER_BODY_US_RSADSI_PKCS12_BAG_TYPES_PKCS8_SHROUDED_KEY_BAG => 1, 2, 840, 113549, 1, 12, 10, 1, 2;
+    ISO_MEMBER_BODY_US_RSADSI_PKCS12_BAG_TYPES_CERT_BAG => 1, 2, 840, 113549, 1, 12, 10, 1, 3;
+    ISO_MEMBER_BODY_US_RSADSI_PKCS12_BAG_TYPES_CRL_BAG => 1, 2, 840, 113549, 1, 12, 10, 1, 4;
+    ISO_MEMBER_BODY_US_RSADSI_PKCS12_BAG_TYPES_SECRET_BAG => 1, 2, 840, 113549, 1, 12, 10, 1, 5;
+    ISO_MEMBER_BODY_US_RSADSI_PKCS12_BAG_TYPES_SAFE_CONTENTS_BAG => 1, 2, 840, 113549, 1, 12, 10, 1, 6;
+
     ISO_MEMBER_BODY_US_RSADSI_DIGEST => 1, 2, 840, 113549, 2;
     ISO_MEMBER_BODY_US_RSADSI_DIGEST_MD2 => 1, 2, 840, 113549, 2, 2;
     ISO_MEMBER_BODY_US_RSADSI_DIGEST_MD5 => 1, 2, 840, 113549, 2, 5;
@@ -505,6 +945,34 @@ oids! {
     ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_KP_TIME_STAMPING => 1, 3, 6, 1, 5, 5, 7, 3, 8;
     ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_KP_OCSP_SIGNING => 1, 3, 6, 1, 5, 5, 7, 3, 9;
 
+    ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_IT => 1, 3, 6, 1, 5, 5, 7, 4;
+    ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_IT_CA_PROT_ENC_CERT => 1, 3, 6, 1, 5, 5, 7, 4, 1;
+    ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_IT_SIGN_KEY_PAIR_TYPES => 1, 3, 6, 1, 5, 5, 7, 4, 2;
+    ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_IT_ENC_KEY_PAIR_TYPES => 1, 3, 6, 1, 5, 5, 7, 4, 3;
+    ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_IT_PREFERRED_SYMM_ALG => 1, 3, 6, 1, 5, 5, 7, 4, 4;
+    ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_IT_CA_KEY_UPDATE_INFO => 1, 3, 6, 1, 5, 5, 7, 4, 5;
+    ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_IT_CURRENT_CRL => 1, 3, 6, 1, 5, 5, 7, 4, 6;
+    ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_IT_UNSUPPORTED_OIDS => 1, 3, 6, 1, 5, 5, 7, 4, 7;
+    ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_IT_KEY_PAIR_PARAM_REQ => 1, 3, 6, 1, 5, 5, 7, 4, 10;
+    ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_IT_KEY_PAIR_PARAM_REP => 1, 3, 6, 1, 5, 5, 7, 4, 11;
+    ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_IT_REV_PASSPHRASE => 1, 3, 6, 1, 5, 5, 7, 4, 12;
+    ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_IT_IMPLICIT_CONFIRM => 1, 3, 6, 1, 5, 5, 7, 4, 13;
+    ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_IT_CONFIRM_WAIT_TIME => 1, 3, 6, 1, 5, 5, 7, 4, 14;
+    ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_IT_ORIG_PKI_MESSAGE => 1, 3, 6, 1, 5, 5, 7, 4, 15;
+    ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_IT_SUPP_LANG_TAGS => 1, 3, 6, 1, 5, 5, 7, 4, 16;
+
+    ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_PKIP => 1, 3, 6, 1, 5, 5, 7, 5;
+    ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_PKIP_REG_CTRL => 1, 3, 6, 1, 5, 5, 7, 5, 1;
+    ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_PKIP_REG_CTRL_REG_TOKEN => 1, 3, 6, 1, 5, 5, 7, 5, 1, 1;
+    ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_PKIP_REG_CTRL_AUTHENTICATOR => 1, 3, 6, 1, 5, 5, 7, 5, 1, 2;
+    ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_PKIP_REG_CTRL_PKI_PUBLICATION_INFO => 1, 3, 6, 1, 5, 5, 7, 5, 1, 3;
+    ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_PKIP_REG_CTRL_PKI_ARCHIVE_OPTIONS => 1, 3, 6, 1, 5, 5, 7, 5, 1, 4;
+    ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_PKIP_REG_CTRL_OLD_CERT_ID => 1, 3, 6, 1, 5, 5, 7, 5, 1, 5;
+    ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_PKIP_REG_CTRL_PROTOCOL_ENCR_KEY => 1, 3, 6, 1, 5, 5, 7, 5, 1, 6;
+    ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_PKIP_REG_INFO => 1, 3, 6, 1, 5, 5, 7, 5, 2;
+    ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_PKIP_REG_INFO_UTF8_PAIRS => 1, 3, 6, 1, 5, 5, 7, 5, 2, 1;
+    ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_PKIP_REG_INFO_CERT_REQ => 1, 3, 6, 1, 5, 5, 7, 5, 2, 2;
+
     ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_CMC => 1, 3, 6, 1, 5, 5, 7, 7;
     ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_CMC_GLARR => 1, 3, 6, 1, 5, 5, 7, 7, 99;
     ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_CMC_GLARR_SKD_ALG_REQUEST => 1, 3, 6, 1, 5, 5, 7, 7, 99, 1;
@@ -770,6 +1238,7 @@ oids! {
 mod test {
     use super::ObjectIdentifier;
     use super::Oid;
+    use super::{RelativeObjectIdentifier, RelativeOid};
 
     #[test]
     fn transmute() {
@@ -801,4 +1270,82 @@ mod test {
             ObjectIdentifier::new(vec![1, 2]).unwrap()
         );
     }
+
+    #[test]
+    fn relative() {
+        let base = Oid::ISO_MEMBER_BODY;
+        let relative = RelativeOid::new(&[840, 113549]).unwrap();
+        let rsadsi = base + relative;
+        assert_eq!([1, 2, 840, 113549], rsadsi);
+        assert_eq!(rsadsi.relative_to(base), Some(relative));
+        assert_eq!(rsadsi.relative_to(&rsadsi), None);
+        assert_eq!(base.relative_to(&rsadsi), None);
+
+        let pkcs = relative + RelativeOid::new(&[1]).unwrap();
+        assert_eq!(*pkcs, [840, 113549, 1]);
+        let mut oid = base.to_owned();
+        oid += &pkcs;
+        assert_eq!([1, 2, 840, 113549, 1, 840, 113549, 1], oid.clone() + &pkcs);
+        assert_eq!([1, 2, 840, 113549, 1], oid);
+
+        assert!(RelativeOid::new(&[]).is_none());
+        assert!(RelativeObjectIdentifier::new(alloc::vec![]).is_none());
+    }
+
+    #[test]
+    fn parse() {
+        let rsadsi = ObjectIdentifier::new(vec![1, 2, 840, 113549]).unwrap();
+        for string in [
+            "1.2.840.113549",
+            "{ 1 2 840 113549 }",
+            "{iso(1) member-body(2) us(840) rsadsi(113549)}",
+            "{ iso member-body us (840) 113549 }",
+        ] {
+            assert_eq!(
+                rsadsi,
+                string.parse::<ObjectIdentifier>().unwrap(),
+                "{string}"
+            );
+        }
+        assert_eq!(
+            [0, 4, 0, 5],
+            "{ itu-t identified-organization etsi(0) 5 }"
+                .parse::<ObjectIdentifier>()
+                .unwrap()
+        );
+
+        for string in [
+            "",
+            "1..2",
+            "1.2.",
+            "3.1",
+            "+1.2",
+            "{}",
+            "{ 1 2",
+            "{ iso us }",
+            "{ iso(x) }",
+            "{ Iso(1) }",
+        ] {
+            assert!(string.parse::<ObjectIdentifier>().is_err(), "{string}");
+        }
+    }
+
+    #[cfg(feature = "oid-registry")]
+    #[test]
+    fn registry() {
+        let oid = Oid::ISO_MEMBER_BODY_US_RSADSI_PKCS1_RSA;
+        assert_eq!(oid.name(), Some("rsaEncryption"));
+        assert_eq!(Oid::from_name("rsaEncryption"), Some(oid));
+        assert_eq!(alloc::format!("{oid:#}"), "rsaEncryption");
+        assert_eq!(alloc::format!("{:#}", oid.to_owned()), "rsaEncryption");
+        assert_eq!(alloc::format!("{oid}"), "1.2.840.113549.1.1.1");
+        assert_eq!(alloc::format!("{:#}", Oid::ISO_MEMBER_BODY), "1.2");
+        assert_eq!(Oid::from_name("1.2"), None);
+
+        assert_eq!(
+            [1, 3, 6, 1, 2, 1, 1, 5, 0],
+            "{ sysName 0 }".parse::<ObjectIdentifier>().unwrap()
+        );
+        assert!("{ 1 sysName }".parse::<ObjectIdentifier>().is_err());
+    }
 }
@@ -0,0 +1,562 @@
+use alloc::{borrow::Cow, vec::Vec};
+use core::{ops, str::FromStr};
+
+use num_bigint::BigUint;
+
+use super::{ObjectIdentifier, Oid, MAX_OID_FIRST_OCTET, MAX_OID_SECOND_OCTET};
+use crate::error::strings::InvalidOidString;
+
+/// Whether `contents` are the contents octets of a BER encoded object
+/// identifier, with every subidentifier encoded in the fewest octets.
+const fn is_valid_contents(contents: &[u8]) -> bool {
+    if contents.is_empty() || contents[contents.len() - 1] & 0x80 != 0 {
+        return false;
+    }
+    let mut index = 0;
+    let mut starts_subidentifier = true;
+    while index < contents.len() {
+        if starts_subidentifier && contents[index] == 0x80 {
+            return false;
+        }
+        starts_subidentifier = contents[index] & 0x80 == 0;
+        index += 1;
+    }
+    true
+}
+
+/// A reference to an object identifier stored as the contents octets of its
+/// BER encoding (X.690 8.19).
+///
+/// Unlike [`Oid`][super::Oid], whose arcs are limited to `u32`, the arcs of
+/// an encoded object identifier can be of any size, such as the UUID arcs
+/// below `2.25` (X.667). Constant encoded object identifiers are created from
+/// their dot separated arcs with [`encoded_oid!`][crate::encoded_oid].
+/// ```
+/// use rasn::types::EncodedOid;
+///
+/// const UUID: &EncodedOid = rasn::encoded_oid!("2.25.329800735698586629295641978511506172918");
+/// assert_eq!(UUID.to_string(), "2.25.329800735698586629295641978511506172918");
+/// assert!(UUID.to_object_identifier().is_none());
+/// ```
+#[derive(Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct EncodedOid([u8]);
+
+impl EncodedOid {
+    /// Creates a new reference to an encoded object identifier from its
+    /// `contents` octets.
+    ///
+    /// Returns `None` if `contents` is empty, ends within a subidentifier,
+    /// or a subidentifier isn't encoded in the fewest octets.
+    /// ```
+    /// use rasn::types::EncodedOid;
+    ///
+    /// let internet = EncodedOid::new(&[0x2B, 6, 1]).unwrap();
+    /// assert_eq!(internet.to_string(), "1.3.6.1");
+    /// ```
+    #[must_use]
+    pub const fn new(contents: &[u8]) -> Option<&Self> {
+        if is_valid_contents(contents) {
+            Some(Self::new_unchecked(contents))
+        } else {
+            None
+        }
+    }
+
+    /// Creates a new reference to an encoded object identifier from its
+    /// `contents` octets.
+    ///
+    /// Panics if `contents` isn't a valid encoding.
+    #[must_use]
+    pub const fn const_new(contents: &'static [u8]) -> &'static Self {
+        match Self::new(contents) {
+            Some(oid) => oid,
+            None => panic!("not a valid encoded OID"),
+        }
+    }
+
+    /// Creates a new reference to an encoded object identifier from its
+    /// `contents` octets.
+    ///
+    /// # Safety
+    /// This allows you to create potentially invalid object identifiers which
+    /// may affect encoding validity.
+    #[must_use]
+    pub const fn new_unchecked(contents: &[u8]) -> &Self {
+        unsafe { &*(core::ptr::from_ref::<[u8]>(contents) as *const Self) }
+    }
+
+    /// Returns the contents octets of the object identifier.
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Returns an iterator over the arcs of the object identifier.
+    /// ```
+    /// use rasn::types::EncodedOid;
+    ///
+    /// let arcs: Vec<_> = rasn::encoded_oid!("2.999.3").arcs().collect();
+    /// assert_eq!(arcs, [2u32.into(), 999u32.into(), 3u32.into()]);
+    /// ```
+    pub fn arcs(&self) -> impl Iterator<Item = BigUint> + '_ {
+        let mut subidentifiers = self
+            .0
+            .split_inclusive(|octet| octet & 0x80 == 0)
+            .map(|octets| {
+                octets.iter().fold(BigUint::default(), |arc, octet| {
+                    (arc << 7u8) | BigUint::from(octet & 0x7F)
+                })
+            });
+        let first = subidentifiers.next().unwrap_or_default();
+        let threshold = BigUint::from(MAX_OID_SECOND_OCTET + 1);
+        let root = (first.clone() / &threshold).min(BigUint::from(MAX_OID_FIRST_OCTET));
+        let second = first - &root * threshold;
+        [root, second].into_iter().chain(subidentifiers)
+    }
+
+    /// Converts the object identifier to an [`ObjectIdentifier`], returning
+    /// `None` if any of its arcs doesn't fit in a `u32`.
+    #[must_use]
+    pub fn to_object_identifier(&self) -> Option<ObjectIdentifier> {
+        self.arcs()
+            .map(|arc| u32::try_from(arc).ok())
+            .collect::<Option<Vec<_>>>()
+            .and_then(ObjectIdentifier::new)
+    }
+
+    /// Returns whether the object identifier has the same arcs as `oid`,
+    /// without allocating.
+    fn has_arcs(&self, oid: &Oid) -> bool {
+        let [first, second, rest @ ..] = oid.as_ref() else {
+            return false;
+        };
+        if *first > MAX_OID_FIRST_OCTET
+            || (*first < MAX_OID_FIRST_OCTET && *second > MAX_OID_SECOND_OCTET)
+        {
+            return false;
+        }
+
+        let mut contents = self.0.iter();
+        let first = u64::from(*first) * u64::from(MAX_OID_SECOND_OCTET + 1) + u64::from(*second);
+        core::iter::once(first)
+            .chain(rest.iter().map(|arc| u64::from(*arc)))
+            .all(|subidentifier| {
+                let octets = (u64::BITS - subidentifier.leading_zeros())
+                    .div_ceil(7)
+                    .max(1);
+                (0..octets).rev().all(|i| {
+                    let more = if i > 0 { 0x80 } else { 0 };
+                    let octet = ((subidentifier >> (7 * i)) & 0x7F) as u8 | more;
+                    contents.next() == Some(&octet)
+                })
+            })
+            && contents.next().is_none()
+    }
+
+    /// Encodes the dot separated arcs of `string` as the contents of an
+    /// object identifier, which are the first `len` octets of the returned
+    /// array. `N` must be at least the length of `string`.
+    ///
+    /// Used by [`encoded_oid!`][crate::encoded_oid] to encode arcs of any size
+    /// at compile time.
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn encode_str<const N: usize>(string: &str) -> ([u8; N], usize) {
+        let bytes = match string.as_bytes() {
+            [b'.', rest @ ..] => rest,
+            bytes => bytes,
+        };
+        assert!(!bytes.is_empty(), "OID string literals cannot be empty");
+
+        let mut contents = [0u8; N];
+        let mut len = 0;
+        // The base 128 digits of the current arc, least significant first.
+        let mut digits = [0u8; N];
+        let mut digits_len = 0;
+        let mut has_digits = false;
+        let mut first = 0;
+        let mut arc_index = 0;
+        let mut index = 0;
+        while index <= bytes.len() {
+            if index < bytes.len() && bytes[index] != b'.' {
+                let byte = bytes[index];
+                assert!(
+                    byte.is_ascii_digit(),
+                    "OID string literals can only contain ASCII digits and periods"
+                );
+                let mut carry = (byte - b'0') as u16;
+                let mut i = 0;
+                while i < digits_len || carry > 0 {
+                    let value = digits[i] as u16 * 10 + carry;
+                    digits[i] = (value & 0x7F) as u8;
+                    carry = value >> 7;
+                    i += 1;
+                }
+                if i > digits_len {
+                    digits_len = i;
+                }
+                has_digits = true;
+                index += 1;
+                continue;
+            }
+
+            assert!(has_digits, "OID string literals cannot contain empty arcs");
+            if arc_index == 0 {
+                assert!(
+                    digits_len <= 1 && digits[0] as u32 <= MAX_OID_FIRST_OCTET,
+                    "the first OID arc must be <= 2"
+                );
+                first = digits[0];
+            } else {
+                if arc_index == 1 {
+                    assert!(
+                        first as u32 == MAX_OID_FIRST_OCTET
+                            || (digits_len <= 1 && digits[0] as u32 <= MAX_OID_SECOND_OCTET),
+                        "the second OID arc must be <= 39 below the arcs 0 and 1"
+                    );
+                    let mut carry = first as u16 * (MAX_OID_SECOND_OCTET as u16 + 1);
+                    let mut i = 0;
+                    while carry > 0 {
+                        let value = digits[i] as u16 + carry;
+                        digits[i] = (value & 0x7F) as u8;
+                        carry = value >> 7;
+                        i += 1;
+                    }
+                    if i > digits_len {
+                        digits_len = i;
+                    }
+                }
+                let mut i = if digits_len == 0 { 1 } else { digits_len };
+                while i > 0 {
+                    i -= 1;
+                    contents[len] = if i > 0 { digits[i] | 0x80 } else { digits[i] };
+                    len += 1;
+                }
+            }
+
+            digits = [0u8; N];
+            digits_len = 0;
+            has_digits = false;
+            arc_index += 1;
+            index += 1;
+        }
+        assert!(
+            arc_index >= 2,
+            "OID string literals must have at least two arcs"
+        );
+
+        (contents, len)
+    }
+}
+
+impl core::fmt::Display for EncodedOid {
+    /// Formats the object identifier as dot separated components.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut arcs = self.arcs();
+        if let Some(first) = arcs.next() {
+            write!(f, "{first}")?;
+            for arc in arcs {
+                write!(f, ".{arc}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl alloc::borrow::ToOwned for EncodedOid {
+    type Owned = EncodedObjectIdentifier;
+
+    fn to_owned(&self) -> Self::Owned {
+        Self::Owned::new_unchecked(self.0.to_owned().into())
+    }
+}
+
+impl AsRef<[u8]> for EncodedOid {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// An object identifier stored as the contents octets of its BER encoding,
+/// the "owned" version of [`EncodedOid`].
+/// ```
+/// use rasn::types::{EncodedObjectIdentifier, Oid};
+///
+/// let uuid: EncodedObjectIdentifier = "2.25.329800735698586629295641978511506172918"
+///     .parse()
+///     .unwrap();
+/// assert_eq!(uuid, *rasn::encoded_oid!("2.25.329800735698586629295641978511506172918"));
+///
+/// let rsadsi = EncodedObjectIdentifier::from(Oid::ISO_MEMBER_BODY_US_RSADSI);
+/// assert_eq!(rsadsi.as_bytes(), [0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D]);
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct EncodedObjectIdentifier(Cow<'static, [u8]>);
+
+impl EncodedObjectIdentifier {
+    /// Creates a new encoded object identifier from its `contents` octets.
+    ///
+    /// Returns `None` if `contents` is empty, ends within a subidentifier,
+    /// or a subidentifier isn't encoded in the fewest octets.
+    pub fn new(contents: impl Into<Cow<'static, [u8]>>) -> Option<Self> {
+        let contents = contents.into();
+        is_valid_contents(&contents).then_some(Self(contents))
+    }
+
+    /// Creates a new encoded object identifier from its `contents` octets.
+    ///
+    /// # Safety
+    /// This allows you to create potentially invalid object identifiers which
+    /// may affect encoding validity.
+    #[must_use]
+    pub const fn new_unchecked(contents: Cow<'static, [u8]>) -> Self {
+        Self(contents)
+    }
+
+    /// Creates a new encoded object identifier from its `arcs`.
+    ///
+    /// Returns `None` if there are less than two arcs, the first arc is
+    /// greater than 2, or the first arc is 0 or 1 and the second arc is
+    /// greater than 39.
+    /// ```
+    /// use rasn::types::EncodedObjectIdentifier;
+    ///
+    /// let oid = EncodedObjectIdentifier::from_arcs([2u128, 25, u128::MAX]).unwrap();
+    /// assert_eq!(oid.to_string(), format!("2.25.{}", u128::MAX));
+    /// ```
+    pub fn from_arcs(arcs: impl IntoIterator<Item = impl Into<BigUint>>) -> Option<Self> {
+        let mut arcs = arcs.into_iter().map(Into::into);
+        let (first, second) = (arcs.next()?, arcs.next()?);
+        if first > BigUint::from(MAX_OID_FIRST_OCTET)
+            || (first < BigUint::from(MAX_OID_FIRST_OCTET)
+                && second > BigUint::from(MAX_OID_SECOND_OCTET))
+        {
+            return None;
+        }
+
+        let mut contents = Vec::new();
+        for arc in core::iter::once(first * (MAX_OID_SECOND_OCTET + 1) + second).chain(arcs) {
+            let digits = arc.to_radix_be(128);
+            let last = digits.len() - 1;
+            contents.extend(digits.iter().enumerate().map(|(i, digit)| {
+                if i == last {
+                    *digit
+                } else {
+                    digit | 0x80
+                }
+            }));
+        }
+        Some(Self(contents.into()))
+    }
+}
+
+impl core::fmt::Display for EncodedObjectIdentifier {
+    /// Formats the object identifier as dot separated components.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        ops::Deref::deref(self).fmt(f)
+    }
+}
+
+impl FromStr for EncodedObjectIdentifier {
+    type Err = InvalidOidString;
+
+    /// Parses an object identifier from dot separated components.
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        string
+            .trim()
+            .split('.')
+            .map(|arc| {
+                (!arc.is_empty() && arc.bytes().all(|byte| byte.is_ascii_digit()))
+                    .then(|| BigUint::parse_bytes(arc.as_bytes(), 10))
+                    .flatten()
+            })
+            .collect::<Option<Vec<_>>>()
+            .and_then(Self::from_arcs)
+            .ok_or_else(|| InvalidOidString {
+                value: string.into(),
+            })
+    }
+}
+
+impl AsRef<[u8]> for EncodedObjectIdentifier {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl alloc::borrow::Borrow<EncodedOid> for EncodedObjectIdentifier {
+    fn borrow(&self) -> &EncodedOid {
+        self
+    }
+}
+
+impl<'a> From<&'a EncodedOid> for EncodedObjectIdentifier {
+    fn from(oid: &'a EncodedOid) -> Self {
+        alloc::borrow::ToOwned::to_owned(oid)
+    }
+}
+
+impl From<&'_ super::Oid> for EncodedObjectIdentifier {
+    /// Encodes the arcs of `oid`.
+    ///
+    /// # Panics
+    /// If `oid` has less than two arcs, as it can't be encoded.
+    fn from(oid: &super::Oid) -> Self {
+        Self::from_arcs(oid.iter().copied())
+            .expect("OIDs must have at least two arcs to be encoded")
+    }
+}
+
+impl From<ObjectIdentifier> for EncodedObjectIdentifier {
+    /// Encodes the arcs of `oid`.
+    ///
+    /// # Panics
+    /// If `oid` has less than two arcs, as it can't be encoded.
+    fn from(oid: ObjectIdentifier) -> Self {
+        Self::from(&*oid)
+    }
+}
+
+impl ops::Deref for EncodedObjectIdentifier {
+    type Target = EncodedOid;
+
+    fn deref(&self) -> &Self::Target {
+        EncodedOid::new_unchecked(&self.0)
+    }
+}
+
+impl PartialEq<EncodedOid> for EncodedObjectIdentifier {
+    fn eq(&self, rhs: &EncodedOid) -> bool {
+        *self.0 == rhs.0
+    }
+}
+
+impl PartialEq<EncodedObjectIdentifier> for EncodedOid {
+    fn eq(&self, rhs: &EncodedObjectIdentifier) -> bool {
+        self.0 == *rhs.0
+    }
+}
+
+impl PartialEq<Oid> for EncodedOid {
+    fn eq(&self, rhs: &Oid) -> bool {
+        self.has_arcs(rhs)
+    }
+}
+
+impl PartialEq<Oid> for EncodedObjectIdentifier {
+    fn eq(&self, rhs: &Oid) -> bool {
+        self.has_arcs(rhs)
+    }
+}
+
+impl PartialEq<&Oid> for EncodedObjectIdentifier {
+    fn eq(&self, rhs: &&Oid) -> bool {
+        self.has_arcs(rhs)
+    }
+}
+
+impl PartialEq<ObjectIdentifier> for EncodedObjectIdentifier {
+    fn eq(&self, rhs: &ObjectIdentifier) -> bool {
+        self.has_arcs(rhs)
+    }
+}
+
+impl PartialEq<EncodedObjectIdentifier> for Oid {
+    fn eq(&self, rhs: &EncodedObjectIdentifier) -> bool {
+        rhs.has_arcs(self)
+    }
+}
+
+impl PartialEq<EncodedObjectIdentifier> for &Oid {
+    fn eq(&self, rhs: &EncodedObjectIdentifier) -> bool {
+        rhs.has_arcs(self)
+    }
+}
+
+impl PartialEq<EncodedObjectIdentifier> for ObjectIdentifier {
+    fn eq(&self, rhs: &EncodedObjectIdentifier) -> bool {
+        rhs.has_arcs(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const UUID: &str = "2.25.329800735698586629295641978511506172918";
+
+    #[test]
+    fn const_encoding() {
+        assert_eq!(crate::encoded_oid!("1.3.6.1").as_bytes(), [0x2B, 6, 1]);
+        assert_eq!(crate::encoded_oid!(".0.0").as_bytes(), [0]);
+        assert_eq!(crate::encoded_oid!("2.999.3").as_bytes(), [0x88, 0x37, 3]);
+        assert_eq!(
+            crate::encoded_oid!("1.2.840.113549").as_bytes(),
+            [0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D]
+        );
+        assert_eq!(
+            crate::encoded_oid!("2.25.340282366920938463463374607431768211455").as_bytes(),
+            EncodedObjectIdentifier::from_arcs([2, 25, u128::MAX])
+                .unwrap()
+                .as_bytes()
+        );
+    }
+
+    #[test]
+    fn arcs() {
+        let uuid: EncodedObjectIdentifier = UUID.parse().unwrap();
+        assert_eq!(uuid.to_string(), UUID);
+        assert_eq!(
+            &*uuid,
+            crate::encoded_oid!("2.25.329800735698586629295641978511506172918")
+        );
+        assert_eq!(uuid.arcs().count(), 3);
+        assert_eq!(uuid.to_object_identifier(), None);
+
+        let oid = crate::encoded_oid!("0.39.0");
+        assert_eq!(oid.to_string(), "0.39.0");
+        assert_eq!(
+            oid.to_object_identifier(),
+            ObjectIdentifier::new(alloc::vec![0, 39, 0])
+        );
+        assert_eq!(
+            EncodedObjectIdentifier::from(ObjectIdentifier::new(alloc::vec![0, 39, 0]).unwrap()),
+            *oid
+        );
+    }
+
+    #[test]
+    fn oid_equality() {
+        let rsadsi = Oid::ISO_MEMBER_BODY_US_RSADSI;
+        assert_eq!(*crate::encoded_oid!("1.2.840.113549"), *rsadsi);
+        assert_eq!(EncodedObjectIdentifier::from(rsadsi), rsadsi);
+        assert_eq!(rsadsi, EncodedObjectIdentifier::from(rsadsi));
+        assert_eq!(
+            *crate::encoded_oid!("2.999.4294967295"),
+            *Oid::const_new(&[2, 999, u32::MAX])
+        );
+        for oid in [
+            "1.2.840",
+            "1.2.840.113549.1",
+            "2.25.329800735698586629295641978511506172918",
+        ] {
+            let oid: EncodedObjectIdentifier = oid.parse().unwrap();
+            assert_ne!(oid, rsadsi, "{oid}");
+        }
+        assert_ne!(*crate::encoded_oid!("1.0"), *Oid::new_unchecked(&[1]));
+    }
+
+    #[test]
+    fn invalid() {
+        for contents in [&[][..], &[0x2B, 0x86], &[0x2B, 0x80, 0x01]] {
+            assert!(EncodedOid::new(contents).is_none(), "{contents:?}");
+        }
+        for string in ["", "1", "1..2", "1.2.", "3.1", "1.40", "+1.2", "1.x"] {
+            assert!(
+                string.parse::<EncodedObjectIdentifier>().is_err(),
+                "{string}"
+            );
+        }
+    }
+}
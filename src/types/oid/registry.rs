@@ -0,0 +1,282 @@
+//! Names of well-known object identifiers, used to render and parse object
+//! identifiers symbolically.
+//!
+//! The registry is curated by hand rather than derived from the constants of
+//! [`Oid`](super::Oid) or the `standards` crates, as neither of those carry
+//! the names the specifications use. An object identifier that isn't listed
+//! is rendered as its arcs, so adding one to a `standards` crate doesn't
+//! require adding it here, but it only gets a name if it is.
+
+/// Object identifiers defined by the modules in the `standards` crates and
+/// the specifications they import from, with the names those specifications
+/// give them, grouped by specification.
+pub(super) const REGISTRY: &[(&[u32], &str)] = &[
+    // ITS (ETSI TS 103 097, IEEE 1609.2)
+    (&[0, 4, 0, 5], "itsDomain"),
+    (&[0, 4, 0, 5, 5, 103_097, 1, 3, 1], "EtsiTs103097Module"),
+    (
+        &[0, 4, 0, 5, 5, 103_097, 2, 1, 1],
+        "EtsiTs103097ExtensionModule",
+    ),
+    (&[1, 3, 111, 2, 1609], "wave-stds"),
+    (&[1, 3, 111, 2, 1609, 2, 1, 1, 2, 6], "Ieee1609Dot2"),
+    (
+        &[1, 3, 111, 2, 1609, 2, 1, 2, 2, 4],
+        "Ieee1609Dot2BaseTypes",
+    ),
+    // Directory (X.500, X.509)
+    (&[0, 9, 2342, 19_200_300, 100, 1, 1], "userId"),
+    (&[0, 9, 2342, 19_200_300, 100, 1, 25], "domainComponent"),
+    (&[2, 5, 4, 3], "commonName"),
+    (&[2, 5, 4, 4], "surname"),
+    (&[2, 5, 4, 5], "serialNumber"),
+    (&[2, 5, 4, 6], "countryName"),
+    (&[2, 5, 4, 7], "localityName"),
+    (&[2, 5, 4, 8], "stateOrProvinceName"),
+    (&[2, 5, 4, 9], "streetAddress"),
+    (&[2, 5, 4, 10], "organizationName"),
+    (&[2, 5, 4, 11], "organizationalUnitName"),
+    (&[2, 5, 4, 12], "title"),
+    (&[2, 5, 4, 42], "givenName"),
+    (&[2, 5, 4, 43], "initials"),
+    (&[2, 5, 4, 44], "generationQualifier"),
+    (&[2, 5, 4, 46], "dnQualifier"),
+    (&[2, 5, 4, 65], "pseudonym"),
+    (&[2, 5, 4, 97], "organizationIdentifier"),
+    (&[2, 5, 29, 9], "subjectDirectoryAttributes"),
+    (&[2, 5, 29, 14], "subjectKeyIdentifier"),
+    (&[2, 5, 29, 15], "keyUsage"),
+    (&[2, 5, 29, 16], "privateKeyUsagePeriod"),
+    (&[2, 5, 29, 17], "subjectAltName"),
+    (&[2, 5, 29, 18], "issuerAltName"),
+    (&[2, 5, 29, 19], "basicConstraints"),
+    (&[2, 5, 29, 20], "cRLNumber"),
+    (&[2, 5, 29, 21], "cRLReasons"),
+    (&[2, 5, 29, 23], "holdInstructionCode"),
+    (&[2, 5, 29, 24], "invalidityDate"),
+    (&[2, 5, 29, 27], "deltaCRLIndicator"),
+    (&[2, 5, 29, 28], "issuingDistributionPoint"),
+    (&[2, 5, 29, 29], "certificateIssuer"),
+    (&[2, 5, 29, 30], "nameConstraints"),
+    (&[2, 5, 29, 31], "cRLDistributionPoints"),
+    (&[2, 5, 29, 32], "certificatePolicies"),
+    (&[2, 5, 29, 32, 0], "anyPolicy"),
+    (&[2, 5, 29, 33], "policyMappings"),
+    (&[2, 5, 29, 35], "authorityKeyIdentifier"),
+    (&[2, 5, 29, 36], "policyConstraints"),
+    (&[2, 5, 29, 37], "extKeyUsage"),
+    (&[2, 5, 29, 37, 0], "anyExtendedKeyUsage"),
+    (&[2, 5, 29, 46], "freshestCRL"),
+    (&[2, 5, 29, 54], "inhibitAnyPolicy"),
+    // PKIX (RFC 5280, RFC 6960)
+    (&[1, 3, 6, 1, 5, 5, 7, 1, 1], "authorityInfoAccess"),
+    (&[1, 3, 6, 1, 5, 5, 7, 1, 11], "subjectInfoAccess"),
+    (&[1, 3, 6, 1, 5, 5, 7, 2, 1], "cps"),
+    (&[1, 3, 6, 1, 5, 5, 7, 2, 2], "unotice"),
+    (&[1, 3, 6, 1, 5, 5, 7, 3, 1], "serverAuth"),
+    (&[1, 3, 6, 1, 5, 5, 7, 3, 2], "clientAuth"),
+    (&[1, 3, 6, 1, 5, 5, 7, 3, 3], "codeSigning"),
+    (&[1, 3, 6, 1, 5, 5, 7, 3, 4], "emailProtection"),
+    (&[1, 3, 6, 1, 5, 5, 7, 3, 8], "timeStamping"),
+    (&[1, 3, 6, 1, 5, 5, 7, 3, 9], "OCSPSigning"),
+    (&[1, 3, 6, 1, 5, 5, 7, 48, 1], "ocsp"),
+    (&[1, 3, 6, 1, 5, 5, 7, 48, 1, 1], "basicOCSPResponse"),
+    (&[1, 3, 6, 1, 5, 5, 7, 48, 1, 2], "ocspNonce"),
+    (&[1, 3, 6, 1, 5, 5, 7, 48, 2], "caIssuers"),
+    (&[1, 3, 6, 1, 5, 5, 7, 48, 5], "caRepository"),
+    // Algorithms (RFC 3279, RFC 4055, RFC 5480, RFC 8410, NIST)
+    (&[1, 2, 840, 10040, 4, 1], "dsa"),
+    (&[1, 2, 840, 10040, 4, 3], "dsa-with-sha1"),
+    (&[1, 2, 840, 10045, 2, 1], "ecPublicKey"),
+    (&[1, 2, 840, 10045, 3, 1, 7], "prime256v1"),
+    (&[1, 2, 840, 10045, 4, 1], "ecdsa-with-SHA1"),
+    (&[1, 2, 840, 10045, 4, 3, 1], "ecdsa-with-SHA224"),
+    (&[1, 2, 840, 10045, 4, 3, 2], "ecdsa-with-SHA256"),
+    (&[1, 2, 840, 10045, 4, 3, 3], "ecdsa-with-SHA384"),
+    (&[1, 2, 840, 10045, 4, 3, 4], "ecdsa-with-SHA512"),
+    (&[1, 2, 840, 10046, 2, 1], "dhpublicnumber"),
+    (&[1, 2, 840, 113_549, 1, 1, 1], "rsaEncryption"),
+    (&[1, 2, 840, 113_549, 1, 1, 2], "md2WithRSAEncryption"),
+    (&[1, 2, 840, 113_549, 1, 1, 4], "md5WithRSAEncryption"),
+    (&[1, 2, 840, 113_549, 1, 1, 5], "sha1WithRSAEncryption"),
+    (&[1, 2, 840, 113_549, 1, 1, 7], "rsaesOaep"),
+    (&[1, 2, 840, 113_549, 1, 1, 8], "mgf1"),
+    (&[1, 2, 840, 113_549, 1, 1, 10], "rsassa-pss"),
+    (&[1, 2, 840, 113_549, 1, 1, 11], "sha256WithRSAEncryption"),
+    (&[1, 2, 840, 113_549, 1, 1, 12], "sha384WithRSAEncryption"),
+    (&[1, 2, 840, 113_549, 1, 1, 13], "sha512WithRSAEncryption"),
+    (&[1, 2, 840, 113_549, 1, 1, 14], "sha224WithRSAEncryption"),
+    (&[1, 2, 840, 113_549, 1, 5, 12], "pbkdf2"),
+    (&[1, 2, 840, 113_549, 1, 5, 13], "pbes2"),
+    (&[1, 2, 840, 113_549, 1, 5, 14], "pbmac1"),
+    (&[1, 2, 840, 113_549, 2, 5], "md5"),
+    (&[1, 2, 840, 113_549, 2, 7], "hmacWithSHA1"),
+    (&[1, 2, 840, 113_549, 2, 9], "hmacWithSHA256"),
+    (&[1, 2, 840, 113_549, 2, 10], "hmacWithSHA384"),
+    (&[1, 2, 840, 113_549, 2, 11], "hmacWithSHA512"),
+    (&[1, 2, 840, 113_549, 3, 7], "des-ede3-cbc"),
+    (&[1, 3, 14, 3, 2, 26], "sha1"),
+    (&[1, 3, 101, 110], "X25519"),
+    (&[1, 3, 101, 111], "X448"),
+    (&[1, 3, 101, 112], "Ed25519"),
+    (&[1, 3, 101, 113], "Ed448"),
+    (&[1, 3, 132, 0, 34], "secp384r1"),
+    (&[1, 3, 132, 0, 35], "secp521r1"),
+    (&[2, 16, 840, 1, 101, 3, 4, 1, 2], "aes128-CBC"),
+    (&[2, 16, 840, 1, 101, 3, 4, 1, 5], "aes128-wrap"),
+    (&[2, 16, 840, 1, 101, 3, 4, 1, 22], "aes192-CBC"),
+    (&[2, 16, 840, 1, 101, 3, 4, 1, 25], "aes192-wrap"),
+    (&[2, 16, 840, 1, 101, 3, 4, 1, 42], "aes256-CBC"),
+    (&[2, 16, 840, 1, 101, 3, 4, 1, 45], "aes256-wrap"),
+    (&[2, 16, 840, 1, 101, 3, 4, 2, 1], "sha256"),
+    (&[2, 16, 840, 1, 101, 3, 4, 2, 2], "sha384"),
+    (&[2, 16, 840, 1, 101, 3, 4, 2, 3], "sha512"),
+    (&[2, 16, 840, 1, 101, 3, 4, 2, 4], "sha224"),
+    // CMS and PKCS #9 (RFC 5652, RFC 2985)
+    (&[1, 2, 840, 113_549, 1, 7, 1], "data"),
+    (&[1, 2, 840, 113_549, 1, 7, 2], "signedData"),
+    (&[1, 2, 840, 113_549, 1, 7, 3], "envelopedData"),
+    (&[1, 2, 840, 113_549, 1, 7, 5], "digestedData"),
+    (&[1, 2, 840, 113_549, 1, 7, 6], "encryptedData"),
+    (&[1, 2, 840, 113_549, 1, 9, 1], "emailAddress"),
+    (&[1, 2, 840, 113_549, 1, 9, 3], "contentType"),
+    (&[1, 2, 840, 113_549, 1, 9, 4], "messageDigest"),
+    (&[1, 2, 840, 113_549, 1, 9, 5], "signingTime"),
+    (&[1, 2, 840, 113_549, 1, 9, 6], "counterSignature"),
+    (&[1, 2, 840, 113_549, 1, 9, 7], "challengePassword"),
+    (&[1, 2, 840, 113_549, 1, 9, 14], "extensionRequest"),
+    (&[1, 2, 840, 113_549, 1, 9, 15], "smimeCapabilities"),
+    (&[1, 2, 840, 113_549, 1, 9, 20], "friendlyName"),
+    (&[1, 2, 840, 113_549, 1, 9, 21], "localKeyID"),
+    (&[1, 2, 840, 113_549, 1, 9, 22, 1], "x509Certificate"),
+    (&[1, 2, 840, 113_549, 1, 9, 22, 2], "sdsiCertificate"),
+    (&[1, 2, 840, 113_549, 1, 9, 23, 1], "x509CRL"),
+    (&[1, 2, 840, 113_549, 1, 9, 16, 1, 2], "authData"),
+    (&[1, 2, 840, 113_549, 1, 9, 16, 1, 4], "tSTInfo"),
+    (&[1, 2, 840, 113_549, 1, 9, 16, 1, 6], "contentInfo"),
+    (&[1, 2, 840, 113_549, 1, 9, 16, 1, 9], "compressedData"),
+    (&[1, 2, 840, 113_549, 1, 9, 16, 1, 23], "authEnvelopedData"),
+    (&[1, 2, 840, 113_549, 1, 9, 16, 2, 12], "signingCertificate"),
+    (
+        &[1, 2, 840, 113_549, 1, 9, 16, 2, 47],
+        "signingCertificateV2",
+    ),
+    (
+        &[1, 2, 840, 113_549, 1, 9, 16, 2, 52],
+        "cmsAlgorithmProtection",
+    ),
+    (&[1, 2, 840, 113_549, 1, 9, 16, 3, 6], "cms3DESwrap"),
+    (&[1, 2, 840, 113_549, 1, 9, 16, 3, 8], "zlibCompress"),
+    // PKCS #12 (RFC 7292)
+    (&[1, 2, 840, 113_549, 1, 12, 1, 1], "pbeWithSHAAnd128BitRC4"),
+    (&[1, 2, 840, 113_549, 1, 12, 1, 2], "pbeWithSHAAnd40BitRC4"),
+    (
+        &[1, 2, 840, 113_549, 1, 12, 1, 3],
+        "pbeWithSHAAnd3-KeyTripleDES-CBC",
+    ),
+    (
+        &[1, 2, 840, 113_549, 1, 12, 1, 4],
+        "pbeWithSHAAnd2-KeyTripleDES-CBC",
+    ),
+    (
+        &[1, 2, 840, 113_549, 1, 12, 1, 5],
+        "pbeWithSHAAnd128BitRC2-CBC",
+    ),
+    (
+        &[1, 2, 840, 113_549, 1, 12, 1, 6],
+        "pbewithSHAAnd40BitRC2-CBC",
+    ),
+    (&[1, 2, 840, 113_549, 1, 12, 10, 1, 1], "keyBag"),
+    (
+        &[1, 2, 840, 113_549, 1, 12, 10, 1, 2],
+        "pkcs8ShroudedKeyBag",
+    ),
+    (&[1, 2, 840, 113_549, 1, 12, 10, 1, 3], "certBag"),
+    (&[1, 2, 840, 113_549, 1, 12, 10, 1, 4], "crlBag"),
+    (&[1, 2, 840, 113_549, 1, 12, 10, 1, 5], "secretBag"),
+    (&[1, 2, 840, 113_549, 1, 12, 10, 1, 6], "safeContentsBag"),
+    // CMP and CRMF (RFC 4210, RFC 4211)
+    (&[1, 2, 840, 113_533, 7, 66, 13], "passwordBasedMac"),
+    (&[1, 2, 840, 113_533, 7, 66, 30], "dhBasedMac"),
+    (&[1, 2, 840, 113_549, 1, 9, 16, 1, 21], "encKeyWithID"),
+    (&[1, 3, 6, 1, 5, 5, 7, 4, 1], "caProtEncCert"),
+    (&[1, 3, 6, 1, 5, 5, 7, 4, 2], "signKeyPairTypes"),
+    (&[1, 3, 6, 1, 5, 5, 7, 4, 3], "encKeyPairTypes"),
+    (&[1, 3, 6, 1, 5, 5, 7, 4, 4], "preferredSymmAlg"),
+    (&[1, 3, 6, 1, 5, 5, 7, 4, 5], "caKeyUpdateInfo"),
+    (&[1, 3, 6, 1, 5, 5, 7, 4, 6], "currentCRL"),
+    (&[1, 3, 6, 1, 5, 5, 7, 4, 7], "unsupportedOIDs"),
+    (&[1, 3, 6, 1, 5, 5, 7, 4, 10], "keyPairParamReq"),
+    (&[1, 3, 6, 1, 5, 5, 7, 4, 11], "keyPairParamRep"),
+    (&[1, 3, 6, 1, 5, 5, 7, 4, 12], "revPassphrase"),
+    (&[1, 3, 6, 1, 5, 5, 7, 4, 13], "implicitConfirm"),
+    (&[1, 3, 6, 1, 5, 5, 7, 4, 14], "confirmWaitTime"),
+    (&[1, 3, 6, 1, 5, 5, 7, 4, 15], "origPKIMessage"),
+    (&[1, 3, 6, 1, 5, 5, 7, 4, 16], "suppLangTags"),
+    (&[1, 3, 6, 1, 5, 5, 7, 5, 1, 1], "regToken"),
+    (&[1, 3, 6, 1, 5, 5, 7, 5, 1, 2], "authenticator"),
+    (&[1, 3, 6, 1, 5, 5, 7, 5, 1, 3], "pkiPublicationInfo"),
+    (&[1, 3, 6, 1, 5, 5, 7, 5, 1, 4], "pkiArchiveOptions"),
+    (&[1, 3, 6, 1, 5, 5, 7, 5, 1, 5], "oldCertID"),
+    (&[1, 3, 6, 1, 5, 5, 7, 5, 1, 6], "protocolEncrKey"),
+    (&[1, 3, 6, 1, 5, 5, 7, 5, 2, 1], "utf8Pairs"),
+    (&[1, 3, 6, 1, 5, 5, 7, 5, 2, 2], "certReq"),
+    // Kerberos (RFC 4120, RFC 4121, RFC 4556)
+    (&[1, 2, 840, 113_554, 1, 2, 2], "krb5"),
+    (&[1, 3, 6, 1, 5, 2], "kerberosV5"),
+    (&[1, 3, 6, 1, 5, 2, 2], "pkinit-san"),
+    (&[1, 3, 6, 1, 5, 2, 3], "pkinit"),
+    (&[1, 3, 6, 1, 5, 2, 3, 1], "pkinit-authData"),
+    (&[1, 3, 6, 1, 5, 2, 3, 2], "pkinit-DHKeyData"),
+    (&[1, 3, 6, 1, 5, 2, 3, 3], "pkinit-rkeyData"),
+    (&[1, 3, 6, 1, 5, 2, 3, 4], "pkinit-KPClientAuth"),
+    (&[1, 3, 6, 1, 5, 2, 3, 5], "pkinit-KPKdc"),
+    // SNMP and MIB-II (RFC 1155, RFC 1213, RFC 3418)
+    (&[1, 3, 6, 1], "internet"),
+    (&[1, 3, 6, 1, 2], "mgmt"),
+    (&[1, 3, 6, 1, 2, 1], "mib-2"),
+    (&[1, 3, 6, 1, 2, 1, 1], "system"),
+    (&[1, 3, 6, 1, 2, 1, 1, 1], "sysDescr"),
+    (&[1, 3, 6, 1, 2, 1, 1, 2], "sysObjectID"),
+    (&[1, 3, 6, 1, 2, 1, 1, 3], "sysUpTime"),
+    (&[1, 3, 6, 1, 2, 1, 1, 4], "sysContact"),
+    (&[1, 3, 6, 1, 2, 1, 1, 5], "sysName"),
+    (&[1, 3, 6, 1, 2, 1, 1, 6], "sysLocation"),
+    (&[1, 3, 6, 1, 2, 1, 1, 7], "sysServices"),
+    (&[1, 3, 6, 1, 2, 1, 2], "interfaces"),
+    (&[1, 3, 6, 1, 2, 1, 2, 1], "ifNumber"),
+    (&[1, 3, 6, 1, 2, 1, 2, 2], "ifTable"),
+    (&[1, 3, 6, 1, 2, 1, 3], "at"),
+    (&[1, 3, 6, 1, 2, 1, 4], "ip"),
+    (&[1, 3, 6, 1, 2, 1, 5], "icmp"),
+    (&[1, 3, 6, 1, 2, 1, 6], "tcp"),
+    (&[1, 3, 6, 1, 2, 1, 7], "udp"),
+    (&[1, 3, 6, 1, 2, 1, 8], "egp"),
+    (&[1, 3, 6, 1, 2, 1, 10], "transmission"),
+    (&[1, 3, 6, 1, 2, 1, 11], "snmp"),
+    (&[1, 3, 6, 1, 4, 1], "enterprises"),
+    (&[1, 3, 6, 1, 6, 3, 1, 1, 4, 1], "snmpTrapOID"),
+    (&[1, 3, 6, 1, 6, 3, 1, 1, 5, 1], "coldStart"),
+    (&[1, 3, 6, 1, 6, 3, 1, 1, 5, 2], "warmStart"),
+    (&[1, 3, 6, 1, 6, 3, 1, 1, 5, 3], "linkDown"),
+    (&[1, 3, 6, 1, 6, 3, 1, 1, 5, 4], "linkUp"),
+    (&[1, 3, 6, 1, 6, 3, 1, 1, 5, 5], "authenticationFailure"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::REGISTRY;
+
+    #[test]
+    fn unique() {
+        for (i, (arcs, name)) in REGISTRY.iter().enumerate() {
+            assert!(crate::types::Oid::new(arcs).is_some(), "{name}");
+            assert!(
+                REGISTRY[i + 1..]
+                    .iter()
+                    .all(|(other_arcs, other_name)| other_arcs != arcs && other_name != name),
+                "{name} is registered twice"
+            );
+        }
+    }
+}
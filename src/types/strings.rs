@@ -1,5 +1,6 @@
 mod bit;
 mod bmp;
+mod borrowed;
 mod constrained;
 mod general;
 mod graphic;
@@ -18,6 +19,10 @@ pub use {
     alloc::string::String as Utf8String,
     bit::{BitStr, BitString, FixedBitString},
     bmp::BmpString,
+    borrowed::{
+        BorrowedIa5String, BorrowedNumericString, BorrowedOctetString, BorrowedPrintableString,
+        BorrowedUtf8String, BorrowedVisibleString,
+    },
     general::GeneralString,
     graphic::GraphicString,
     ia5::Ia5String,
@@ -28,6 +33,7 @@ pub use {
     visible::VisibleString,
 };
 
+pub(crate) use borrowed::{restricted_str_from_bytes, utf8_str_from_bytes};
 pub(crate) use constrained::{
     should_be_indexed, DynConstrainedCharacterString, StaticPermittedAlphabet,
 };
@@ -54,13 +60,6 @@ macro_rules! impl_restricted_core_traits {
             }
         }
 
-    impl TryFrom<&'_ str> for $target {
-        type Error = PermittedAlphabetError;
-        fn try_from(value: &str) -> Result<Self, Self::Error> {
-            Ok(Self(Self::try_from_slice(value)?))
-        }
-    }
-
     impl TryFrom<alloc::vec::Vec<u8>> for $target {
         type Error = PermittedAlphabetError;
         fn try_from(value: alloc::vec::Vec<u8>) -> Result<Self, Self::Error> {
@@ -68,13 +67,6 @@ macro_rules! impl_restricted_core_traits {
         }
     }
 
-    impl TryFrom<alloc::string::String> for $target {
-        type Error = PermittedAlphabetError;
-        fn try_from(value: alloc::string::String) -> Result<Self, Self::Error> {
-            Ok(Self(Self::try_from_slice(&value)?))
-        }
-    }
-
     impl TryFrom<OctetString> for $target {
         type Error = PermittedAlphabetError;
 
@@ -118,3 +110,34 @@ impl_restricted_core_traits!(
     (TeletexString, u32),
     (VisibleString, u8)
 );
+
+// `TeletexString` converts from `str` through its T.61 character mapping
+// instead, as its characters are T.61 code octets rather than UTF-8 bytes.
+macro_rules! impl_restricted_str_traits {
+    ($($target:ty),* $(,)?) => {
+    $(
+    impl TryFrom<&'_ str> for $target {
+        type Error = PermittedAlphabetError;
+        fn try_from(value: &str) -> Result<Self, Self::Error> {
+            Ok(Self(Self::try_from_slice(value)?))
+        }
+    }
+
+    impl TryFrom<alloc::string::String> for $target {
+        type Error = PermittedAlphabetError;
+        fn try_from(value: alloc::string::String) -> Result<Self, Self::Error> {
+            Ok(Self(Self::try_from_slice(&value)?))
+        }
+    }
+    )*
+};
+}
+impl_restricted_str_traits!(
+    BmpString,
+    GeneralString,
+    GraphicString,
+    Ia5String,
+    NumericString,
+    PrintableString,
+    VisibleString
+);
@@ -0,0 +1,362 @@
+//! String types that borrow from the input they were decoded from, see
+//! [`DecodeBorrowed`](crate::de::DecodeBorrowed).
+
+use super::{
+    AsnType, Constraints, Encode, Encoder, Ia5String, Identifier, NumericString, OctetString,
+    PermittedAlphabetError, PrintableString, StaticPermittedAlphabet, Tag, Utf8String,
+    VisibleString,
+};
+use crate::de::{BorrowDecoder, DecodeBorrowed};
+
+use alloc::{
+    borrow::{Cow, ToOwned},
+    string::String,
+};
+
+/// An `OCTET STRING` that borrows from the decoder's input whenever the
+/// value was encoded as a single primitive.
+#[derive(Debug, Default, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct BorrowedOctetString<'a>(Cow<'a, [u8]>);
+
+impl<'a> BorrowedOctetString<'a> {
+    /// Creates a new string from `bytes`.
+    pub fn new(bytes: impl Into<Cow<'a, [u8]>>) -> Self {
+        Self(bytes.into())
+    }
+
+    /// Returns the contents of the string.
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Returns whether the contents are borrowed rather than owned.
+    #[must_use]
+    pub fn is_borrowed(&self) -> bool {
+        matches!(self.0, Cow::Borrowed(_))
+    }
+
+    /// Returns the underlying [`Cow`].
+    #[must_use]
+    pub fn into_inner(self) -> Cow<'a, [u8]> {
+        self.0
+    }
+
+    /// Copies the contents into an owned [`OctetString`].
+    #[must_use]
+    pub fn into_owned(self) -> OctetString {
+        OctetString::from(self.0.into_owned())
+    }
+}
+
+impl<'a> From<&'a [u8]> for BorrowedOctetString<'a> {
+    fn from(bytes: &'a [u8]) -> Self {
+        Self(Cow::Borrowed(bytes))
+    }
+}
+
+impl From<alloc::vec::Vec<u8>> for BorrowedOctetString<'_> {
+    fn from(bytes: alloc::vec::Vec<u8>) -> Self {
+        Self(Cow::Owned(bytes))
+    }
+}
+
+impl core::ops::Deref for BorrowedOctetString<'_> {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsRef<[u8]> for BorrowedOctetString<'_> {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsnType for BorrowedOctetString<'_> {
+    const TAG: Tag = Tag::OCTET_STRING;
+    const IDENTIFIER: Identifier = Identifier::OCTET_STRING;
+}
+
+impl<'de> DecodeBorrowed<'de> for BorrowedOctetString<'de> {
+    fn decode_borrowed_with_tag_and_constraints<D: BorrowDecoder<'de>>(
+        decoder: &mut D,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<Self, D::Error> {
+        decoder
+            .decode_octet_string_borrowed(tag, constraints)
+            .map(Self)
+    }
+}
+
+impl Encode for BorrowedOctetString<'_> {
+    fn encode_with_tag_and_constraints<'b, E: Encoder<'b>>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        constraints: Constraints,
+        identifier: Identifier,
+    ) -> Result<(), E::Error> {
+        encoder
+            .encode_octet_string(tag, constraints, &self.0, identifier)
+            .map(drop)
+    }
+}
+
+/// A `UTF8String` that borrows from the decoder's input whenever the value
+/// was encoded as a single primitive.
+#[derive(Debug, Default, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct BorrowedUtf8String<'a>(Cow<'a, str>);
+
+impl<'a> BorrowedUtf8String<'a> {
+    /// Creates a new string from `string`.
+    pub fn new(string: impl Into<Cow<'a, str>>) -> Self {
+        Self(string.into())
+    }
+
+    /// Returns the contents of the string.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns whether the contents are borrowed rather than owned.
+    #[must_use]
+    pub fn is_borrowed(&self) -> bool {
+        matches!(self.0, Cow::Borrowed(_))
+    }
+
+    /// Returns the underlying [`Cow`].
+    #[must_use]
+    pub fn into_inner(self) -> Cow<'a, str> {
+        self.0
+    }
+
+    /// Copies the contents into an owned [`Utf8String`].
+    #[must_use]
+    pub fn into_owned(self) -> Utf8String {
+        self.0.into_owned()
+    }
+}
+
+impl<'a> From<&'a str> for BorrowedUtf8String<'a> {
+    fn from(string: &'a str) -> Self {
+        Self(Cow::Borrowed(string))
+    }
+}
+
+impl From<String> for BorrowedUtf8String<'_> {
+    fn from(string: String) -> Self {
+        Self(Cow::Owned(string))
+    }
+}
+
+impl core::ops::Deref for BorrowedUtf8String<'_> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl core::fmt::Display for BorrowedUtf8String<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl AsnType for BorrowedUtf8String<'_> {
+    const TAG: Tag = Tag::UTF8_STRING;
+    const IDENTIFIER: Identifier = Identifier::UTF8_STRING;
+}
+
+impl<'de> DecodeBorrowed<'de> for BorrowedUtf8String<'de> {
+    fn decode_borrowed_with_tag_and_constraints<D: BorrowDecoder<'de>>(
+        decoder: &mut D,
+        tag: Tag,
+        constraints: Constraints,
+    ) -> Result<Self, D::Error> {
+        decoder
+            .decode_utf8_string_borrowed(tag, constraints)
+            .map(Self)
+    }
+}
+
+impl Encode for BorrowedUtf8String<'_> {
+    fn encode_with_tag_and_constraints<'b, E: Encoder<'b>>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        constraints: Constraints,
+        identifier: Identifier,
+    ) -> Result<(), E::Error> {
+        encoder
+            .encode_utf8_string(tag, constraints, &self.0, identifier)
+            .map(drop)
+    }
+}
+
+/// Checks that every octet of `bytes` is in the character set of `S`, and
+/// converts them to a string slice when they are.
+pub(crate) fn restricted_str_from_bytes<S: StaticPermittedAlphabet>(
+    bytes: Cow<'_, [u8]>,
+) -> Result<Cow<'_, str>, PermittedAlphabetError> {
+    if let Some(&byte) = bytes.iter().find(|&&byte| !S::contains_char(byte.into())) {
+        return Err(PermittedAlphabetError::InvalidRestrictedString {
+            source: S::invalid_restricted_string(byte.into()),
+        });
+    }
+
+    let invalid = |error: core::str::Utf8Error| PermittedAlphabetError::Other {
+        message: alloc::format!("{} is not valid UTF-8: {error}", S::CHARACTER_SET_NAME),
+    };
+
+    match bytes {
+        Cow::Borrowed(bytes) => core::str::from_utf8(bytes)
+            .map(Cow::Borrowed)
+            .map_err(invalid),
+        Cow::Owned(bytes) => String::from_utf8(bytes)
+            .map(Cow::Owned)
+            .map_err(|error| invalid(error.utf8_error())),
+    }
+}
+
+/// Converts `bytes` to a UTF-8 string, borrowing them if they are borrowed.
+pub(crate) fn utf8_str_from_bytes(
+    bytes: Cow<'_, [u8]>,
+) -> Result<Cow<'_, str>, core::str::Utf8Error> {
+    match bytes {
+        Cow::Borrowed(bytes) => core::str::from_utf8(bytes).map(Cow::Borrowed),
+        Cow::Owned(bytes) => String::from_utf8(bytes)
+            .map(Cow::Owned)
+            .map_err(|error| error.utf8_error()),
+    }
+}
+
+macro_rules! borrowed_restricted_strings {
+    ($($(#[$meta:meta])* $name:ident($owned:ident, $decode_fn:ident)),* $(,)?) => {
+        $(
+            $(#[$meta])*
+            #[derive(Debug, Default, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+            pub struct $name<'a>(Cow<'a, str>);
+
+            impl<'a> $name<'a> {
+                #[doc = concat!("Creates a new string from `string`, checking that it only contains characters permitted in a [`", stringify!($owned), "`].")]
+                pub fn new(string: impl Into<Cow<'a, str>>) -> Result<Self, PermittedAlphabetError> {
+                    let string = string.into();
+                    if let Some(ch) = string.chars().find(|&ch| !<$owned>::contains_char(ch.into())) {
+                        return Err(PermittedAlphabetError::InvalidRestrictedString {
+                            source: <$owned>::invalid_restricted_string(ch.into()),
+                        });
+                    }
+                    Ok(Self(string))
+                }
+
+                /// Returns the contents of the string.
+                #[must_use]
+                pub fn as_str(&self) -> &str {
+                    &self.0
+                }
+
+                /// Returns whether the contents are borrowed rather than owned.
+                #[must_use]
+                pub fn is_borrowed(&self) -> bool {
+                    matches!(self.0, Cow::Borrowed(_))
+                }
+
+                /// Returns the underlying [`Cow`].
+                #[must_use]
+                pub fn into_inner(self) -> Cow<'a, str> {
+                    self.0
+                }
+
+                #[doc = concat!("Copies the contents into an owned [`", stringify!($owned), "`].")]
+                #[must_use]
+                pub fn into_owned(self) -> $owned {
+                    $owned(self.0.into_owned().into_bytes())
+                }
+
+                fn to_owned_string(&self) -> $owned {
+                    $owned(self.0.as_bytes().to_owned())
+                }
+            }
+
+            impl<'a> TryFrom<&'a str> for $name<'a> {
+                type Error = PermittedAlphabetError;
+
+                fn try_from(string: &'a str) -> Result<Self, Self::Error> {
+                    Self::new(string)
+                }
+            }
+
+            impl TryFrom<String> for $name<'_> {
+                type Error = PermittedAlphabetError;
+
+                fn try_from(string: String) -> Result<Self, Self::Error> {
+                    Self::new(string)
+                }
+            }
+
+            impl core::ops::Deref for $name<'_> {
+                type Target = str;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.0
+                }
+            }
+
+            impl core::fmt::Display for $name<'_> {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    f.write_str(&self.0)
+                }
+            }
+
+            impl AsnType for $name<'_> {
+                const TAG: Tag = <$owned as AsnType>::TAG;
+                const CONSTRAINTS: Constraints = <$owned as AsnType>::CONSTRAINTS;
+                const IDENTIFIER: Identifier = <$owned as AsnType>::IDENTIFIER;
+            }
+
+            impl<'de> DecodeBorrowed<'de> for $name<'de> {
+                fn decode_borrowed_with_tag_and_constraints<D: BorrowDecoder<'de>>(
+                    decoder: &mut D,
+                    tag: Tag,
+                    constraints: Constraints,
+                ) -> Result<Self, D::Error> {
+                    decoder.$decode_fn(tag, constraints).map(Self)
+                }
+            }
+
+            impl Encode for $name<'_> {
+                fn encode_with_tag_and_constraints<'b, E: Encoder<'b>>(
+                    &self,
+                    encoder: &mut E,
+                    tag: Tag,
+                    constraints: Constraints,
+                    identifier: Identifier,
+                ) -> Result<(), E::Error> {
+                    self.to_owned_string()
+                        .encode_with_tag_and_constraints(encoder, tag, constraints, identifier)
+                }
+            }
+        )*
+    };
+}
+
+borrowed_restricted_strings! {
+    /// An `IA5String` that borrows from the decoder's input whenever the
+    /// value was encoded as a single primitive.
+    BorrowedIa5String(Ia5String, decode_ia5_string_borrowed),
+    /// A `PrintableString` that borrows from the decoder's input whenever the
+    /// value was encoded as a single primitive.
+    BorrowedPrintableString(PrintableString, decode_printable_string_borrowed),
+    /// A `VisibleString` that borrows from the decoder's input whenever the
+    /// value was encoded as a single primitive.
+    BorrowedVisibleString(VisibleString, decode_visible_string_borrowed),
+    /// A `NumericString` that borrows from the decoder's input whenever the
+    /// value was encoded as a single primitive.
+    BorrowedNumericString(NumericString, decode_numeric_string_borrowed),
+}
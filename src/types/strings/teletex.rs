@@ -3,39 +3,448 @@ use super::{
     PermittedAlphabetError, StaticPermittedAlphabet, Tag,
 };
 
-use alloc::vec::Vec;
+use alloc::{string::String, vec::Vec};
 use once_cell::race::OnceBox;
 
 /// A string, which contains the characters defined in T.61 standard.
+///
+/// The string is stored as T.61 code octets (the primary set ISO-IR 102 and
+/// the supplementary set ISO-IR 103 in an 8-bit environment), so binary
+/// codecs can reproduce the received encoding exactly. Textual codecs use
+/// [`TeletexString::to_unicode`] and [`TeletexString::from_unicode`] to map
+/// the octets to and from Unicode.
 #[derive(Debug, Default, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct TeletexString(pub(super) Vec<u32>);
 static CHARACTER_MAP: OnceBox<alloc::collections::BTreeMap<u32, u32>> = OnceBox::new();
 static INDEX_MAP: OnceBox<alloc::collections::BTreeMap<u32, u32>> = OnceBox::new();
 
 impl TeletexString {
-    /// Converts the string into a set of big endian bytes.
+    /// Converts the string into its T.61 code octets.
     #[must_use]
     pub fn to_bytes(&self) -> Vec<u8> {
-        self.0.iter().flat_map(|ch| ch.to_be_bytes()).collect()
+        self.0.iter().map(|&ch| ch as u8).collect()
     }
 
-    /// Attempts to convert the provided bytes into [Self].
+    /// Attempts to convert the provided T.61 code octets into [Self].
     ///
     /// # Errors
     /// If any of the provided bytes does not match the allowed character set.
     pub fn from_bytes(bytes: &[u8]) -> Result<Self, PermittedAlphabetError> {
         Ok(Self(Self::try_from_slice(bytes)?))
     }
+
+    /// Converts the T.61 code octets into a Unicode string.
+    ///
+    /// Non-spacing diacritical marks are combined with the following base
+    /// character, producing a precomposed character where Unicode has one.
+    ///
+    /// # Errors
+    /// If the string contains an octet without a Unicode mapping, such as an
+    /// unassigned position or an ISO/IEC 2022 escape sequence, or a
+    /// diacritical mark that isn't followed by a base character.
+    pub fn to_unicode(&self) -> Result<String, PermittedAlphabetError> {
+        let mut string = String::with_capacity(self.0.len());
+        let mut chars = self.0.iter().copied();
+
+        while let Some(ch) = chars.next() {
+            let Some(mark) = diacritic_to_char(ch) else {
+                string.push(t61_to_char(ch).ok_or_else(|| Self::invalid_character(ch))?);
+                continue;
+            };
+
+            let base = chars.next().ok_or_else(|| Self::invalid_character(ch))?;
+            let base_char = t61_to_char(base).ok_or_else(|| Self::invalid_character(base))?;
+            // The deprecated umlaut mark composes the same way as the diaeresis.
+            let diacritic = if ch == 0xC9 { 0xC8 } else { ch };
+
+            match COMPOSED
+                .iter()
+                .find(|(d, b, _)| u32::from(*d) == diacritic && u32::from(*b) == base)
+            {
+                Some((_, _, composed)) => string.push(*composed),
+                None => {
+                    string.push(base_char);
+                    string.push(mark);
+                }
+            }
+        }
+
+        Ok(string)
+    }
+
+    /// Attempts to convert a Unicode string into T.61 code octets.
+    ///
+    /// Precomposed characters and combining marks are converted to a
+    /// non-spacing diacritical mark followed by the base character.
+    ///
+    /// # Errors
+    /// If the string contains a character that has no T.61 representation.
+    pub fn from_unicode(value: &str) -> Result<Self, PermittedAlphabetError> {
+        let mut string = Self::default();
+
+        for ch in value.chars() {
+            if let Some(byte) = char_to_t61(ch) {
+                string.0.push(u32::from(byte));
+            } else if let Some((diacritic, base, _)) = COMPOSED.iter().find(|(_, _, c)| *c == ch) {
+                string.0.push(u32::from(*diacritic));
+                string.0.push(u32::from(*base));
+            } else if let Some((diacritic, _)) = DIACRITICS.iter().find(|(_, c)| *c == ch) {
+                // A combining mark follows its base character in Unicode but
+                // precedes it in T.61, and each base takes at most one mark.
+                let len = string.0.len();
+                let has_base = len > 0
+                    && diacritic_to_char(string.0[len - 1]).is_none()
+                    && (len == 1 || diacritic_to_char(string.0[len - 2]).is_none());
+                if !has_base {
+                    return Err(Self::invalid_character(u32::from(ch)));
+                }
+                string.0.insert(len - 1, u32::from(*diacritic));
+            } else {
+                return Err(Self::invalid_character(u32::from(ch)));
+            }
+        }
+
+        Ok(string)
+    }
+
+    fn invalid_character(ch: u32) -> PermittedAlphabetError {
+        PermittedAlphabetError::InvalidRestrictedString {
+            source: Self::invalid_restricted_string(ch),
+        }
+    }
 }
+
+/// Maps a single T.61 code octet to Unicode, excluding the non-spacing
+/// diacritical marks which only have a meaning together with a base character.
+fn t61_to_char(ch: u32) -> Option<char> {
+    match ch {
+        // ESC introduces ISO/IEC 2022 sequences designating other character
+        // sets, and these positions are not assigned in the primary set.
+        0x1B | 0x23 | 0x24 | 0x5C | 0x5E | 0x60 | 0x7B | 0x7D | 0x7E => None,
+        // C0 controls, primary graphic set, DELETE, C1 controls and NBSP.
+        0x00..=0xA0 => char::from_u32(ch),
+        _ => SUPPLEMENTARY
+            .iter()
+            .find(|(byte, _)| u32::from(*byte) == ch)
+            .map(|(_, c)| *c),
+    }
+}
+
+fn char_to_t61(ch: char) -> Option<u8> {
+    match ch {
+        '\u{1B}' | '\\' | '^' | '`' | '{' | '}' | '~' => None,
+        '#' => Some(0xA6),
+        '$' => Some(0xA4),
+        '\u{00}'..='\u{A0}' => Some(ch as u8),
+        _ => SUPPLEMENTARY
+            .iter()
+            .find(|(_, c)| *c == ch)
+            .map(|(byte, _)| *byte),
+    }
+}
+
+fn diacritic_to_char(ch: u32) -> Option<char> {
+    DIACRITICS
+        .iter()
+        .find(|(byte, _)| u32::from(*byte) == ch)
+        .map(|(_, c)| *c)
+}
+
+/// Supplementary set (ISO-IR 103) characters without a diacritical mark.
+const SUPPLEMENTARY: &[(u8, char)] = &[
+    (0xA1, '¡'),
+    (0xA2, '¢'),
+    (0xA3, '£'),
+    (0xA4, '$'),
+    (0xA5, '¥'),
+    (0xA6, '#'),
+    (0xA7, '§'),
+    (0xA8, '¤'),
+    (0xAB, '«'),
+    (0xB0, '°'),
+    (0xB1, '±'),
+    (0xB2, '²'),
+    (0xB3, '³'),
+    (0xB4, '×'),
+    (0xB5, 'µ'),
+    (0xB6, '¶'),
+    (0xB7, '·'),
+    (0xB8, '÷'),
+    (0xBB, '»'),
+    (0xBC, '¼'),
+    (0xBD, '½'),
+    (0xBE, '¾'),
+    (0xBF, '¿'),
+    (0xE0, 'Ω'),
+    (0xE1, 'Æ'),
+    (0xE2, 'Đ'),
+    (0xE3, 'ª'),
+    (0xE4, 'Ħ'),
+    (0xE6, 'Ĳ'),
+    (0xE7, 'Ŀ'),
+    (0xE8, 'Ł'),
+    (0xE9, 'Ø'),
+    (0xEA, 'Œ'),
+    (0xEB, 'º'),
+    (0xEC, 'Þ'),
+    (0xED, 'Ŧ'),
+    (0xEE, 'Ŋ'),
+    (0xEF, 'ŉ'),
+    (0xF0, 'ĸ'),
+    (0xF1, 'æ'),
+    (0xF2, 'đ'),
+    (0xF3, 'ð'),
+    (0xF4, 'ħ'),
+    (0xF5, 'ı'),
+    (0xF6, 'ĳ'),
+    (0xF7, 'ŀ'),
+    (0xF8, 'ł'),
+    (0xF9, 'ø'),
+    (0xFA, 'œ'),
+    (0xFB, 'ß'),
+    (0xFC, 'þ'),
+    (0xFD, 'ŧ'),
+    (0xFE, 'ŋ'),
+    (0xFF, '\u{AD}'),
+];
+
+/// Non-spacing diacritical marks (ISO-IR 103) and their Unicode combining
+/// characters.
+const DIACRITICS: &[(u8, char)] = &[
+    (0xC1, '\u{0300}'),
+    (0xC2, '\u{0301}'),
+    (0xC3, '\u{0302}'),
+    (0xC4, '\u{0303}'),
+    (0xC5, '\u{0304}'),
+    (0xC6, '\u{0306}'),
+    (0xC7, '\u{0307}'),
+    (0xC8, '\u{0308}'),
+    (0xC9, '\u{0308}'),
+    (0xCA, '\u{030A}'),
+    (0xCB, '\u{0327}'),
+    (0xCC, '\u{0332}'),
+    (0xCD, '\u{030B}'),
+    (0xCE, '\u{0328}'),
+    (0xCF, '\u{030C}'),
+];
+
+/// Diacritical mark and base character pairs with a precomposed Unicode
+/// character.
+const COMPOSED: &[(u8, u8, char)] = &[
+    (0xC1, b'A', '\u{00C0}'),
+    (0xC1, b'E', '\u{00C8}'),
+    (0xC1, b'I', '\u{00CC}'),
+    (0xC1, b'N', '\u{01F8}'),
+    (0xC1, b'O', '\u{00D2}'),
+    (0xC1, b'U', '\u{00D9}'),
+    (0xC1, b'a', '\u{00E0}'),
+    (0xC1, b'e', '\u{00E8}'),
+    (0xC1, b'i', '\u{00EC}'),
+    (0xC1, b'n', '\u{01F9}'),
+    (0xC1, b'o', '\u{00F2}'),
+    (0xC1, b'u', '\u{00F9}'),
+    (0xC2, b'A', '\u{00C1}'),
+    (0xC2, b'C', '\u{0106}'),
+    (0xC2, b'E', '\u{00C9}'),
+    (0xC2, b'G', '\u{01F4}'),
+    (0xC2, b'I', '\u{00CD}'),
+    (0xC2, b'L', '\u{0139}'),
+    (0xC2, b'N', '\u{0143}'),
+    (0xC2, b'O', '\u{00D3}'),
+    (0xC2, b'R', '\u{0154}'),
+    (0xC2, b'S', '\u{015A}'),
+    (0xC2, b'U', '\u{00DA}'),
+    (0xC2, b'Y', '\u{00DD}'),
+    (0xC2, b'Z', '\u{0179}'),
+    (0xC2, b'a', '\u{00E1}'),
+    (0xC2, b'c', '\u{0107}'),
+    (0xC2, b'e', '\u{00E9}'),
+    (0xC2, b'g', '\u{01F5}'),
+    (0xC2, b'i', '\u{00ED}'),
+    (0xC2, b'l', '\u{013A}'),
+    (0xC2, b'n', '\u{0144}'),
+    (0xC2, b'o', '\u{00F3}'),
+    (0xC2, b'r', '\u{0155}'),
+    (0xC2, b's', '\u{015B}'),
+    (0xC2, b'u', '\u{00FA}'),
+    (0xC2, b'y', '\u{00FD}'),
+    (0xC2, b'z', '\u{017A}'),
+    (0xC3, b'A', '\u{00C2}'),
+    (0xC3, b'C', '\u{0108}'),
+    (0xC3, b'E', '\u{00CA}'),
+    (0xC3, b'G', '\u{011C}'),
+    (0xC3, b'H', '\u{0124}'),
+    (0xC3, b'I', '\u{00CE}'),
+    (0xC3, b'J', '\u{0134}'),
+    (0xC3, b'O', '\u{00D4}'),
+    (0xC3, b'S', '\u{015C}'),
+    (0xC3, b'U', '\u{00DB}'),
+    (0xC3, b'W', '\u{0174}'),
+    (0xC3, b'Y', '\u{0176}'),
+    (0xC3, b'a', '\u{00E2}'),
+    (0xC3, b'c', '\u{0109}'),
+    (0xC3, b'e', '\u{00EA}'),
+    (0xC3, b'g', '\u{011D}'),
+    (0xC3, b'h', '\u{0125}'),
+    (0xC3, b'i', '\u{00EE}'),
+    (0xC3, b'j', '\u{0135}'),
+    (0xC3, b'o', '\u{00F4}'),
+    (0xC3, b's', '\u{015D}'),
+    (0xC3, b'u', '\u{00FB}'),
+    (0xC3, b'w', '\u{0175}'),
+    (0xC3, b'y', '\u{0177}'),
+    (0xC4, b'A', '\u{00C3}'),
+    (0xC4, b'I', '\u{0128}'),
+    (0xC4, b'N', '\u{00D1}'),
+    (0xC4, b'O', '\u{00D5}'),
+    (0xC4, b'U', '\u{0168}'),
+    (0xC4, b'a', '\u{00E3}'),
+    (0xC4, b'i', '\u{0129}'),
+    (0xC4, b'n', '\u{00F1}'),
+    (0xC4, b'o', '\u{00F5}'),
+    (0xC4, b'u', '\u{0169}'),
+    (0xC5, b'A', '\u{0100}'),
+    (0xC5, b'E', '\u{0112}'),
+    (0xC5, b'I', '\u{012A}'),
+    (0xC5, b'O', '\u{014C}'),
+    (0xC5, b'U', '\u{016A}'),
+    (0xC5, b'Y', '\u{0232}'),
+    (0xC5, b'a', '\u{0101}'),
+    (0xC5, b'e', '\u{0113}'),
+    (0xC5, b'i', '\u{012B}'),
+    (0xC5, b'o', '\u{014D}'),
+    (0xC5, b'u', '\u{016B}'),
+    (0xC5, b'y', '\u{0233}'),
+    (0xC6, b'A', '\u{0102}'),
+    (0xC6, b'E', '\u{0114}'),
+    (0xC6, b'G', '\u{011E}'),
+    (0xC6, b'I', '\u{012C}'),
+    (0xC6, b'O', '\u{014E}'),
+    (0xC6, b'U', '\u{016C}'),
+    (0xC6, b'a', '\u{0103}'),
+    (0xC6, b'e', '\u{0115}'),
+    (0xC6, b'g', '\u{011F}'),
+    (0xC6, b'i', '\u{012D}'),
+    (0xC6, b'o', '\u{014F}'),
+    (0xC6, b'u', '\u{016D}'),
+    (0xC7, b'A', '\u{0226}'),
+    (0xC7, b'C', '\u{010A}'),
+    (0xC7, b'E', '\u{0116}'),
+    (0xC7, b'G', '\u{0120}'),
+    (0xC7, b'I', '\u{0130}'),
+    (0xC7, b'O', '\u{022E}'),
+    (0xC7, b'Z', '\u{017B}'),
+    (0xC7, b'a', '\u{0227}'),
+    (0xC7, b'c', '\u{010B}'),
+    (0xC7, b'e', '\u{0117}'),
+    (0xC7, b'g', '\u{0121}'),
+    (0xC7, b'o', '\u{022F}'),
+    (0xC7, b'z', '\u{017C}'),
+    (0xC8, b'A', '\u{00C4}'),
+    (0xC8, b'E', '\u{00CB}'),
+    (0xC8, b'I', '\u{00CF}'),
+    (0xC8, b'O', '\u{00D6}'),
+    (0xC8, b'U', '\u{00DC}'),
+    (0xC8, b'Y', '\u{0178}'),
+    (0xC8, b'a', '\u{00E4}'),
+    (0xC8, b'e', '\u{00EB}'),
+    (0xC8, b'i', '\u{00EF}'),
+    (0xC8, b'o', '\u{00F6}'),
+    (0xC8, b'u', '\u{00FC}'),
+    (0xC8, b'y', '\u{00FF}'),
+    (0xCA, b'A', '\u{00C5}'),
+    (0xCA, b'U', '\u{016E}'),
+    (0xCA, b'a', '\u{00E5}'),
+    (0xCA, b'u', '\u{016F}'),
+    (0xCB, b'C', '\u{00C7}'),
+    (0xCB, b'E', '\u{0228}'),
+    (0xCB, b'G', '\u{0122}'),
+    (0xCB, b'K', '\u{0136}'),
+    (0xCB, b'L', '\u{013B}'),
+    (0xCB, b'N', '\u{0145}'),
+    (0xCB, b'R', '\u{0156}'),
+    (0xCB, b'S', '\u{015E}'),
+    (0xCB, b'T', '\u{0162}'),
+    (0xCB, b'c', '\u{00E7}'),
+    (0xCB, b'e', '\u{0229}'),
+    (0xCB, b'g', '\u{0123}'),
+    (0xCB, b'k', '\u{0137}'),
+    (0xCB, b'l', '\u{013C}'),
+    (0xCB, b'n', '\u{0146}'),
+    (0xCB, b'r', '\u{0157}'),
+    (0xCB, b's', '\u{015F}'),
+    (0xCB, b't', '\u{0163}'),
+    (0xCD, b'O', '\u{0150}'),
+    (0xCD, b'U', '\u{0170}'),
+    (0xCD, b'o', '\u{0151}'),
+    (0xCD, b'u', '\u{0171}'),
+    (0xCE, b'A', '\u{0104}'),
+    (0xCE, b'E', '\u{0118}'),
+    (0xCE, b'I', '\u{012E}'),
+    (0xCE, b'O', '\u{01EA}'),
+    (0xCE, b'U', '\u{0172}'),
+    (0xCE, b'a', '\u{0105}'),
+    (0xCE, b'e', '\u{0119}'),
+    (0xCE, b'i', '\u{012F}'),
+    (0xCE, b'o', '\u{01EB}'),
+    (0xCE, b'u', '\u{0173}'),
+    (0xCF, b'A', '\u{01CD}'),
+    (0xCF, b'C', '\u{010C}'),
+    (0xCF, b'D', '\u{010E}'),
+    (0xCF, b'E', '\u{011A}'),
+    (0xCF, b'G', '\u{01E6}'),
+    (0xCF, b'H', '\u{021E}'),
+    (0xCF, b'I', '\u{01CF}'),
+    (0xCF, b'K', '\u{01E8}'),
+    (0xCF, b'L', '\u{013D}'),
+    (0xCF, b'N', '\u{0147}'),
+    (0xCF, b'O', '\u{01D1}'),
+    (0xCF, b'R', '\u{0158}'),
+    (0xCF, b'S', '\u{0160}'),
+    (0xCF, b'T', '\u{0164}'),
+    (0xCF, b'U', '\u{01D3}'),
+    (0xCF, b'Z', '\u{017D}'),
+    (0xCF, b'a', '\u{01CE}'),
+    (0xCF, b'c', '\u{010D}'),
+    (0xCF, b'd', '\u{010F}'),
+    (0xCF, b'e', '\u{011B}'),
+    (0xCF, b'g', '\u{01E7}'),
+    (0xCF, b'h', '\u{021F}'),
+    (0xCF, b'i', '\u{01D0}'),
+    (0xCF, b'j', '\u{01F0}'),
+    (0xCF, b'k', '\u{01E9}'),
+    (0xCF, b'l', '\u{013E}'),
+    (0xCF, b'n', '\u{0148}'),
+    (0xCF, b'o', '\u{01D2}'),
+    (0xCF, b'r', '\u{0159}'),
+    (0xCF, b's', '\u{0161}'),
+    (0xCF, b't', '\u{0165}'),
+    (0xCF, b'u', '\u{01D4}'),
+    (0xCF, b'z', '\u{017E}'),
+];
+
 impl StaticPermittedAlphabet for TeletexString {
     type T = u32;
-    // TODO add correct character set, see https://github.com/mouse07410/asn1c/blob/84d3a59c1bb89c59be6ca0625bb14ebea9084ba5/skeletons/TeletexString.c
-    const CHARACTER_SET: &'static [u32] = &[0];
+    const CHARACTER_SET: &'static [u32] = &{
+        let mut array = [0u32; 0x100];
+        let mut i = 0;
+        while i < 0x100 {
+            array[i as usize] = i;
+            i += 1;
+        }
+        array
+    };
     const CHARACTER_SET_NAME: constrained::CharacterSetName =
         constrained::CharacterSetName::Teletex;
-    // TODO remove once correct character set is added
-    fn contains_char(_: u32) -> bool {
-        true
+
+    // Any octet may appear, as T.61 permits ISO/IEC 2022 escape sequences to
+    // designate further registered character sets.
+    fn contains_char(ch: u32) -> bool {
+        ch <= 0xFF
+    }
+
+    fn try_from_slice(input: impl AsRef<[u8]>) -> Result<Vec<Self::T>, PermittedAlphabetError> {
+        Self::try_from_slice_with_width(input, 1)
     }
 
     fn push_char(&mut self, ch: u32) {
@@ -54,6 +463,20 @@ impl StaticPermittedAlphabet for TeletexString {
     }
 }
 
+impl TryFrom<&'_ str> for TeletexString {
+    type Error = PermittedAlphabetError;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::from_unicode(value)
+    }
+}
+
+impl TryFrom<String> for TeletexString {
+    type Error = PermittedAlphabetError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::from_unicode(&value)
+    }
+}
+
 impl AsnType for TeletexString {
     const TAG: Tag = Tag::TELETEX_STRING;
     const IDENTIFIER: Identifier = Identifier::TELETEX_STRING;
@@ -139,6 +139,7 @@ consts! {
     EMBEDDED_PDV = 11,
     UTF8_STRING = 12,
     RELATIVE_OID = 13,
+    TIME = 14,
     SEQUENCE = 16,
     SET = 17,
     NUMERIC_STRING = 18,
@@ -154,7 +155,12 @@ consts! {
     UNIVERSAL_STRING = 28,
     CHARACTER_STRING = 29,
     BMP_STRING = 30,
-    DATE = 31
+    DATE = 31,
+    TIME_OF_DAY = 32,
+    DATE_TIME = 33,
+    DURATION = 34,
+    OID_IRI = 35,
+    RELATIVE_OID_IRI = 36
 }
 
 impl Tag {
@@ -237,6 +243,8 @@ impl TagTree {
     pub const fn smallest_tag(&self) -> Tag {
         match self {
             Self::Leaf(tag) => *tag,
+            // Open types have no tag of their own.
+            Self::Choice(&[]) => Tag::EOC,
             Self::Choice(tree) => {
                 let mut i = 0;
                 let mut tag: Tag = Tag::new_private(u32::MAX);
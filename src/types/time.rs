@@ -0,0 +1,529 @@
+use alloc::string::String;
+use chrono::Timelike;
+use core::{fmt, ops::RangeInclusive, str::FromStr};
+
+use crate::error::{strings::InvalidTimeString, EncodeError};
+use crate::prelude::Constraints;
+use crate::types::{DateTime, TimeOfDay};
+use crate::{types::Tag, AsnType, Codec, Decode, Decoder, Encode, Encoder};
+
+use super::Identifier;
+
+/// The `TIME` type, holding an ISO 8601 time value with any of the property
+/// settings of X.680, such as `"2024-05-01T12:00Z"` or `"R/P1D"`.
+///
+/// The value is kept as the string it was created or decoded from, which is
+/// checked to be a date, time of day or date and time, an interval between
+/// those and durations, or a recurring interval, in the extended format of
+/// X.680 §38.4. Only the useful time types [`Date`](super::Date),
+/// [`TimeOfDay`], [`DateTime`] and [`IsoDuration`] are represented
+/// structurally.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct IsoTime(String);
+
+impl IsoTime {
+    /// Returns the ISO 8601 string of the value.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for IsoTime {
+    type Err = InvalidTimeString;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let valid = match string.strip_prefix('R') {
+            Some(recurrence) => recurrence.split_once('/').is_some_and(|(count, interval)| {
+                count.bytes().all(|byte| byte.is_ascii_digit()) && is_interval(interval)
+            }),
+            None => is_interval(string),
+        };
+
+        if valid {
+            Ok(Self(string.into()))
+        } else {
+            Err(InvalidTimeString {
+                kind: "TIME",
+                value: string.into(),
+            })
+        }
+    }
+}
+
+/// Whether `string` is a time point or a duration, or an interval between
+/// two of them which aren't both durations.
+fn is_interval(string: &str) -> bool {
+    let is_duration = |string: &str| string.parse::<IsoDuration>().is_ok();
+    match string.split_once('/') {
+        Some((start, end)) => match (start.starts_with('P'), end.starts_with('P')) {
+            (false, false) => is_point(start) && is_point(end),
+            (false, true) => is_point(start) && is_duration(end),
+            (true, false) => is_duration(start) && is_point(end),
+            (true, true) => false,
+        },
+        None if string.starts_with('P') => is_duration(string),
+        None => is_point(string),
+    }
+}
+
+/// Whether `string` is a date, a time of day, or both separated by `T`.
+fn is_point(string: &str) -> bool {
+    match string.split_once('T') {
+        Some((date, time)) => is_date(date) && is_time(time),
+        None => is_date(string) || is_time(string),
+    }
+}
+
+/// Whether `string` is a century, a year, a month, a day of the year, a week
+/// or a day of a week, or a day of a month.
+fn is_date(string: &str) -> bool {
+    // Years with more than four digits, or before year zero, have a sign.
+    let (signed, unsigned) = match string.strip_prefix(['+', '-']) {
+        Some(unsigned) => (true, unsigned),
+        None => (false, string),
+    };
+    let digits = unsigned
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(unsigned.len());
+    let (year, rest) = unsigned.split_at(digits);
+    if year.len() == 2 && !signed {
+        return rest.is_empty();
+    } else if year.len() < 4 || (year.len() > 4 && !signed) {
+        return false;
+    }
+
+    let Some(rest) = rest.strip_prefix('-') else {
+        return rest.is_empty();
+    };
+    if let Some(week) = rest.strip_prefix('W') {
+        return match week.split_once('-') {
+            Some((week, day)) => number(week, 2, 1..=53) && number(day, 1, 1..=7),
+            None => number(week, 2, 1..=53),
+        };
+    }
+    match rest.split_once('-') {
+        Some((month, day)) => number(month, 2, 1..=12) && number(day, 2, 1..=31),
+        None => number(rest, 2, 1..=12) || number(rest, 3, 1..=366),
+    }
+}
+
+/// Whether `string` is a time of day of hours, minutes or seconds, with an
+/// optional fraction of the last of them and an optional time zone.
+fn is_time(string: &str) -> bool {
+    let (time, zone) = string.split_at(string.find(['Z', '+', '-']).unwrap_or(string.len()));
+    let valid_zone = match zone.strip_prefix(['+', '-']) {
+        Some(difference) => match difference.split_once(':') {
+            Some((hours, minutes)) => number(hours, 2, 0..=23) && number(minutes, 2, 0..=59),
+            None => number(difference, 2, 0..=23),
+        },
+        None => zone.is_empty() || zone == "Z",
+    };
+    let (time, fraction) = match time.split_once(['.', ',']) {
+        Some((time, fraction)) => (time, Some(fraction)),
+        None => (time, None),
+    };
+    let valid_fraction = fraction.is_none_or(|fraction| {
+        !fraction.is_empty() && fraction.bytes().all(|byte| byte.is_ascii_digit())
+    });
+
+    let mut components = time.split(':');
+    valid_zone
+        && valid_fraction
+        && components
+            .next()
+            .is_some_and(|hours| number(hours, 2, 0..=24))
+        && components
+            .next()
+            .is_none_or(|minutes| number(minutes, 2, 0..=59))
+        && components
+            .next()
+            .is_none_or(|seconds| number(seconds, 2, 0..=60))
+        && components.next().is_none()
+}
+
+/// Whether `string` is a number of exactly `digits` digits within `range`.
+fn number(string: &str, digits: usize, range: RangeInclusive<u32>) -> bool {
+    string.len() == digits
+        && string.bytes().all(|byte| byte.is_ascii_digit())
+        && string.parse().is_ok_and(|number| range.contains(&number))
+}
+
+/// Checks that `time` is a whole number of seconds, as the values of
+/// `TIME-OF-DAY` and `DATE-TIME` are. chrono represents leap seconds as a
+/// fraction of at least one second, so those are whole too.
+pub(crate) fn whole_seconds(time: &TimeOfDay, codec: Codec) -> Result<(), EncodeError> {
+    if time.nanosecond().is_multiple_of(1_000_000_000) {
+        Ok(())
+    } else {
+        Err(EncodeError::fractional_seconds(codec))
+    }
+}
+
+impl fmt::Display for IsoTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// The `DURATION` type, an ISO 8601 duration such as `"P1Y2M10DT2H30M"` or
+/// `"P3W"`.
+///
+/// Absent components are `None`. The fractional part, if any, belongs to the
+/// last present component.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct IsoDuration {
+    /// The number of years.
+    pub years: Option<u32>,
+    /// The number of months.
+    pub months: Option<u32>,
+    /// The number of weeks, which can't be combined with other components.
+    pub weeks: Option<u32>,
+    /// The number of days.
+    pub days: Option<u32>,
+    /// The number of hours.
+    pub hours: Option<u32>,
+    /// The number of minutes.
+    pub minutes: Option<u32>,
+    /// The number of seconds.
+    pub seconds: Option<u32>,
+    /// The fractional part of the last present component.
+    pub fraction: Option<DurationFraction>,
+}
+
+/// The fractional part of an [`IsoDuration`], `value` being written with exactly
+/// `digits` digits, so `.05` is `{ digits: 2, value: 5 }`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DurationFraction {
+    /// The number of digits, between one and nine.
+    pub digits: u8,
+    /// The value of the digits.
+    pub value: u32,
+}
+
+impl FromStr for IsoDuration {
+    type Err = InvalidTimeString;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let error = || InvalidTimeString {
+            kind: "DURATION",
+            value: string.into(),
+        };
+        let mut rest = string.strip_prefix('P').ok_or_else(error)?;
+        let mut duration = Self::default();
+        let mut in_time = false;
+        // The designators which may still follow, in order.
+        let mut designators: &[u8] = b"YMWD";
+
+        while !rest.is_empty() {
+            if duration.fraction.is_some() {
+                return Err(error());
+            }
+
+            if let Some(time) = rest.strip_prefix('T') {
+                if in_time || time.is_empty() || duration.weeks.is_some() {
+                    return Err(error());
+                }
+                in_time = true;
+                designators = b"HMS";
+                rest = time;
+            }
+
+            let end = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .filter(|end| *end > 0)
+                .ok_or_else(error)?;
+            let number = rest[..end].parse::<u32>().map_err(|_| error())?;
+            rest = &rest[end..];
+
+            if let Some(fraction) = rest.strip_prefix(['.', ',']) {
+                let end = fraction
+                    .find(|c: char| !c.is_ascii_digit())
+                    .filter(|end| (1..=9).contains(end))
+                    .ok_or_else(error)?;
+                duration.fraction = Some(DurationFraction {
+                    digits: end as u8,
+                    value: fraction[..end].parse().map_err(|_| error())?,
+                });
+                rest = &fraction[end..];
+            }
+
+            let designator = *rest.as_bytes().first().ok_or_else(error)?;
+            let position = designators
+                .iter()
+                .position(|d| *d == designator)
+                .ok_or_else(error)?;
+            designators = &designators[position + 1..];
+            rest = &rest[1..];
+
+            let component = match (in_time, designator) {
+                (false, b'Y') => &mut duration.years,
+                (false, b'M') => &mut duration.months,
+                (false, b'W') => {
+                    designators = &[];
+                    &mut duration.weeks
+                }
+                (false, _) => &mut duration.days,
+                (true, b'H') => &mut duration.hours,
+                (true, b'M') => &mut duration.minutes,
+                (true, _) => &mut duration.seconds,
+            };
+            *component = Some(number);
+        }
+
+        if duration == Self::default() || (duration.weeks.is_some() && in_time) {
+            return Err(error());
+        }
+
+        Ok(duration)
+    }
+}
+
+impl fmt::Display for IsoDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The date components, followed by the time components from `TIME`.
+        const TIME: usize = 4;
+        let components = [
+            (self.years, 'Y'),
+            (self.months, 'M'),
+            (self.weeks, 'W'),
+            (self.days, 'D'),
+            (self.hours, 'H'),
+            (self.minutes, 'M'),
+            (self.seconds, 'S'),
+        ];
+
+        f.write_str("P")?;
+        let Some(last) = components
+            .iter()
+            .rposition(|(component, _)| component.is_some())
+        else {
+            return f.write_str("0D");
+        };
+
+        for (index, (component, designator)) in components.iter().enumerate() {
+            if index == TIME && last >= TIME {
+                f.write_str("T")?;
+            }
+            let Some(number) = component else {
+                continue;
+            };
+            write!(f, "{number}")?;
+            if let (true, Some(fraction)) = (index == last, self.fraction) {
+                write!(
+                    f,
+                    ".{:0width$}",
+                    fraction.value,
+                    width = usize::from(fraction.digits)
+                )?;
+            }
+            write!(f, "{designator}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl AsnType for IsoTime {
+    const TAG: Tag = Tag::TIME;
+    const IDENTIFIER: Identifier = Identifier::TIME;
+}
+
+impl Decode for IsoTime {
+    fn decode_with_tag_and_constraints<D: Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+        _constraints: Constraints,
+    ) -> Result<Self, D::Error> {
+        decoder.decode_time(tag)
+    }
+}
+
+impl Encode for IsoTime {
+    fn encode_with_tag_and_constraints<'b, E: Encoder<'b>>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        _constraints: Constraints,
+        identifier: Identifier,
+    ) -> Result<(), E::Error> {
+        encoder.encode_time(tag, self, identifier).map(drop)
+    }
+}
+
+impl AsnType for TimeOfDay {
+    const TAG: Tag = Tag::TIME_OF_DAY;
+    const IDENTIFIER: Identifier = Identifier::TIME_OF_DAY;
+}
+
+impl Decode for TimeOfDay {
+    fn decode_with_tag_and_constraints<D: Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+        _constraints: Constraints,
+    ) -> Result<Self, D::Error> {
+        decoder.decode_time_of_day(tag)
+    }
+}
+
+impl Encode for TimeOfDay {
+    fn encode_with_tag_and_constraints<'b, E: Encoder<'b>>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        _constraints: Constraints,
+        identifier: Identifier,
+    ) -> Result<(), E::Error> {
+        encoder.encode_time_of_day(tag, self, identifier).map(drop)
+    }
+}
+
+impl AsnType for DateTime {
+    const TAG: Tag = Tag::DATE_TIME;
+    const IDENTIFIER: Identifier = Identifier::DATE_TIME;
+}
+
+impl Decode for DateTime {
+    fn decode_with_tag_and_constraints<D: Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+        _constraints: Constraints,
+    ) -> Result<Self, D::Error> {
+        decoder.decode_date_time(tag)
+    }
+}
+
+impl Encode for DateTime {
+    fn encode_with_tag_and_constraints<'b, E: Encoder<'b>>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        _constraints: Constraints,
+        identifier: Identifier,
+    ) -> Result<(), E::Error> {
+        encoder.encode_date_time(tag, self, identifier).map(drop)
+    }
+}
+
+impl AsnType for IsoDuration {
+    const TAG: Tag = Tag::DURATION;
+    const IDENTIFIER: Identifier = Identifier::DURATION;
+}
+
+impl Decode for IsoDuration {
+    fn decode_with_tag_and_constraints<D: Decoder>(
+        decoder: &mut D,
+        tag: Tag,
+        _constraints: Constraints,
+    ) -> Result<Self, D::Error> {
+        decoder.decode_duration(tag)
+    }
+}
+
+impl Encode for IsoDuration {
+    fn encode_with_tag_and_constraints<'b, E: Encoder<'b>>(
+        &self,
+        encoder: &mut E,
+        tag: Tag,
+        _constraints: Constraints,
+        identifier: Identifier,
+    ) -> Result<(), E::Error> {
+        encoder.encode_duration(tag, self, identifier).map(drop)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn duration_strings() {
+        for string in [
+            "P1Y2M10DT2H30M",
+            "P3W",
+            "PT0.5S",
+            "P1DT12H",
+            "PT36H",
+            "P2Y0.05M",
+        ] {
+            let duration = string.parse::<IsoDuration>().unwrap();
+            assert_eq!(duration.to_string(), string);
+        }
+
+        assert_eq!(
+            "PT1,25S".parse::<IsoDuration>().unwrap(),
+            IsoDuration {
+                seconds: Some(1),
+                fraction: Some(DurationFraction {
+                    digits: 2,
+                    value: 25
+                }),
+                ..<_>::default()
+            }
+        );
+
+        for string in [
+            "", "P", "PT", "1Y", "P1S", "P1D2Y", "P1W2D", "P1.5YT1H", "P1YT",
+        ] {
+            assert!(string.parse::<IsoDuration>().is_err(), "{string}");
+        }
+    }
+
+    #[test]
+    fn time_strings() {
+        assert_eq!(
+            "2024-05-01T12:00Z".parse::<IsoTime>().unwrap().as_str(),
+            "2024-05-01T12:00Z"
+        );
+        assert_eq!(IsoTime::from_str("R/P1D").unwrap().to_string(), "R/P1D");
+
+        for string in [
+            "20",
+            "2024",
+            "2024-05",
+            "2024-05-01",
+            "2024-122",
+            "2024-W18",
+            "2024-W18-3",
+            "+12024-01-01",
+            "-0044-03-15",
+            "12",
+            "12:30",
+            "12:30:59.25",
+            "23:59:60Z",
+            "12:30,5+01:00",
+            "12:30-05",
+            "2024-05-01T12:30:00.5Z",
+            "2024-05-01/2024-05-02",
+            "2024-05-01T12:00/PT1H",
+            "P1D/2024-05-01",
+            "R5/2024-05-01T12:00/P1W",
+        ] {
+            assert!(string.parse::<IsoTime>().is_ok(), "{string}");
+        }
+
+        for string in [
+            "",
+            "12:00 Z",
+            "hello",
+            "2024-13",
+            "2024-05-32",
+            "2024-367",
+            "2024-W54",
+            "12024-01-01",
+            "25:00",
+            "12:60",
+            "12:30.",
+            "12:30+1",
+            "2024-05-01T",
+            "T12:00",
+            "P1D/P2D",
+            "2024-05-01/",
+            "R/",
+            "Rx/P1D",
+            "R/R/P1D",
+        ] {
+            assert!(string.parse::<IsoTime>().is_err(), "{string}");
+        }
+    }
+}
@@ -0,0 +1,563 @@
+//! Validation of values against their constraints, independent of any codec.
+//!
+//! [`Validate`] walks a value and reports every component which violates its
+//! value, size, permitted alphabet, or inner subtype constraints, along with
+//! the path to that component. It's implemented for the types in
+//! [`types`](crate::types), and can be derived for your own types with
+//! `#[derive(Validate)]`.
+//!
+//! ```
+//! use rasn::prelude::*;
+//!
+//! #[derive(AsnType, Validate)]
+//! #[rasn(automatic_tags)]
+//! struct Reading {
+//!     #[rasn(value("0..=100"))]
+//!     level: u8,
+//!     #[rasn(size("1..=4"))]
+//!     samples: SequenceOf<Integer>,
+//! }
+//!
+//! let error = Reading { level: 101, samples: vec![] }.validate().unwrap_err();
+//! assert_eq!(error.violations.len(), 2);
+//! assert_eq!(error.violations[0].path, "level");
+//! ```
+//!
+//! Only the root of extensible constraints is checked, so values of
+//! extensible constraints are never violations.
+
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use super::{
+    strings::StaticPermittedAlphabet, AsnType, BitStr, BitString, BmpString, ConstrainedInteger,
+    Constraints, Explicit, FixedBitString, FixedOctetString, GeneralString, GraphicString,
+    Ia5String, Implicit, InnerSubtypeConstraint, Integer, IntegerType, NumericString, OctetString,
+    PrintableString, SetOf, TeletexString, Utf8String, VisibleString,
+};
+use crate::error::{ConstraintViolation, ConstraintViolationKind, ValidationError};
+
+/// A type whose values can be checked against its constraints, that can be
+/// derived with `#[derive(Validate)]`.
+pub trait Validate: AsnType {
+    /// Reports every violation of `constraints` by this value, and of the
+    /// constraints of its components, to `validator`.
+    fn validate_with_constraints(&self, constraints: Constraints, validator: &mut Validator);
+
+    /// Checks this value against the constraints of its type.
+    /// # Errors
+    /// Returns every violation found, if there are any.
+    fn validate(&self) -> Result<(), ValidationError> {
+        let mut validator = Validator::new();
+        self.validate_with_constraints(Self::CONSTRAINTS, &mut validator);
+        validator.finish()
+    }
+}
+
+/// A component of the path to a value being validated.
+#[derive(Debug, Clone, Copy)]
+enum Segment {
+    Field(&'static str),
+    Element(usize),
+}
+
+/// Collects the constraint violations found while walking a value, along with
+/// the path to the component currently being validated.
+#[derive(Debug, Default)]
+pub struct Validator {
+    path: Vec<Segment>,
+    violations: Vec<ConstraintViolation>,
+}
+
+impl Validator {
+    /// Creates a validator without any violations.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Validates the field `name` of the current value.
+    pub fn field<T: Validate + ?Sized>(
+        &mut self,
+        name: &'static str,
+        value: &T,
+        constraints: Constraints,
+    ) {
+        self.path.push(Segment::Field(name));
+        value.validate_with_constraints(constraints, self);
+        self.path.pop();
+    }
+
+    /// Validates the element at `index` of the current value.
+    pub fn element<T: Validate + ?Sized>(&mut self, index: usize, value: &T) {
+        self.path.push(Segment::Element(index));
+        value.validate_with_constraints(T::CONSTRAINTS, self);
+        self.path.pop();
+    }
+
+    /// Checks `value` against the value constraint of `constraints`.
+    pub fn value<I: IntegerType>(&mut self, value: &I, constraints: &Constraints) {
+        self.check(check_value(value, constraints));
+    }
+
+    /// Checks `size` against the size constraint of `constraints`.
+    pub fn size(&mut self, size: usize, constraints: &Constraints) {
+        self.check(check_size(size, constraints));
+    }
+
+    /// Checks `characters` against the permitted alphabet of `constraints`.
+    pub fn alphabet(
+        &mut self,
+        characters: impl IntoIterator<Item = u32>,
+        constraints: &Constraints,
+    ) {
+        self.check(check_alphabet(characters, constraints));
+    }
+
+    /// Checks the inner subtype constraint of `value`.
+    pub fn inner_subtype<T: InnerSubtypeConstraint + Clone>(&mut self, value: &T) {
+        if let Err(source) = value.clone().validate_components() {
+            self.report(ConstraintViolationKind::InnerSubtype { source });
+        }
+    }
+
+    /// Reports a violation by the current value.
+    pub fn report(&mut self, kind: ConstraintViolationKind) {
+        let mut path = String::new();
+        for segment in &self.path {
+            match segment {
+                Segment::Field(name) => {
+                    if !path.is_empty() {
+                        path.push('.');
+                    }
+                    path.push_str(name);
+                }
+                Segment::Element(index) => {
+                    path.push('[');
+                    path.push_str(&index.to_string());
+                    path.push(']');
+                }
+            }
+        }
+
+        self.violations.push(ConstraintViolation { path, kind });
+    }
+
+    /// Returns the violations found.
+    /// # Errors
+    /// Returns every violation found, if there are any.
+    pub fn finish(self) -> Result<(), ValidationError> {
+        if self.violations.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidationError {
+                violations: self.violations,
+            })
+        }
+    }
+
+    fn check(&mut self, result: Result<(), ConstraintViolationKind>) {
+        if let Err(kind) = result {
+            self.report(kind);
+        }
+    }
+}
+
+/// Checks `value` against the root of the value constraint of `constraints`.
+pub(crate) fn check_value<I: IntegerType>(
+    value: &I,
+    constraints: &Constraints,
+) -> Result<(), ConstraintViolationKind> {
+    match constraints.value() {
+        Some(value_constraint)
+            if value_constraint.extensible.is_none()
+                && !value_constraint.constraint.permits(value) =>
+        {
+            Err(ConstraintViolationKind::Value {
+                value: value.to_bigint().unwrap_or_default(),
+                expected: Box::new(value_constraint.constraint),
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Checks `size` against the root of the size constraint of `constraints`.
+pub(crate) fn check_size(
+    size: usize,
+    constraints: &Constraints,
+) -> Result<(), ConstraintViolationKind> {
+    match constraints.size() {
+        Some(size_constraint)
+            if size_constraint.extensible.is_none()
+                && !size_constraint.constraint.permits(size) =>
+        {
+            Err(ConstraintViolationKind::Size {
+                size,
+                expected: size_constraint.constraint,
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Checks `characters` against the root of the permitted alphabet of
+/// `constraints`.
+pub(crate) fn check_alphabet(
+    characters: impl IntoIterator<Item = u32>,
+    constraints: &Constraints,
+) -> Result<(), ConstraintViolationKind> {
+    match constraints.permitted_alphabet() {
+        Some(alphabet) if alphabet.extensible.is_none() => characters
+            .into_iter()
+            .find(|character| !alphabet.constraint.permits(*character))
+            .map_or(Ok(()), |character| {
+                Err(ConstraintViolationKind::PermittedAlphabet { character })
+            }),
+        _ => Ok(()),
+    }
+}
+
+/// Checks the size, in characters, and permitted alphabet of a character
+/// string.
+pub(crate) fn check_characters<I: Iterator<Item = u32>>(
+    characters: impl Fn() -> I,
+    constraints: &Constraints,
+) -> Result<(), ConstraintViolationKind> {
+    check_size(characters().count(), constraints)?;
+    check_alphabet(characters(), constraints)
+}
+
+macro_rules! impl_unconstrained {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl Validate for $ty {
+                fn validate_with_constraints(&self, _: Constraints, _: &mut Validator) {}
+            }
+        )+
+    }
+}
+
+impl_unconstrained! {
+    bool,
+    (),
+    super::ObjectIdentifier,
+    super::Oid,
+    super::EncodedObjectIdentifier,
+    super::EncodedOid,
+    super::RelativeObjectIdentifier,
+    super::RelativeOid,
+    super::OidIri,
+    super::RelativeOidIri,
+    super::External,
+    super::UtcTime,
+    super::GeneralizedTime,
+    super::Date,
+    super::IsoTime,
+    super::TimeOfDay,
+    super::DateTime,
+    super::IsoDuration,
+    super::Any,
+    super::Open,
+}
+
+#[cfg(feature = "f32")]
+impl_unconstrained!(f32);
+#[cfg(feature = "f64")]
+impl_unconstrained!(f64);
+
+impl<T> Validate for core::marker::PhantomData<T> {
+    fn validate_with_constraints(&self, _: Constraints, _: &mut Validator) {}
+}
+
+macro_rules! impl_integers {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl Validate for $ty {
+                fn validate_with_constraints(&self, constraints: Constraints, validator: &mut Validator) {
+                    validator.value(self, &constraints);
+                }
+            }
+        )+
+    }
+}
+
+impl_integers! {
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    Integer,
+}
+
+impl<const START: i128, const END: i128> Validate for ConstrainedInteger<START, END> {
+    fn validate_with_constraints(&self, constraints: Constraints, validator: &mut Validator) {
+        validator.value(&**self, &constraints);
+    }
+}
+
+impl Validate for OctetString {
+    fn validate_with_constraints(&self, constraints: Constraints, validator: &mut Validator) {
+        validator.size(self.len(), &constraints);
+    }
+}
+
+impl<const N: usize> Validate for FixedOctetString<N> {
+    fn validate_with_constraints(&self, constraints: Constraints, validator: &mut Validator) {
+        validator.size(N, &constraints);
+    }
+}
+
+impl Validate for BitString {
+    fn validate_with_constraints(&self, constraints: Constraints, validator: &mut Validator) {
+        validator.size(self.len(), &constraints);
+    }
+}
+
+impl Validate for BitStr {
+    fn validate_with_constraints(&self, constraints: Constraints, validator: &mut Validator) {
+        validator.size(self.len(), &constraints);
+    }
+}
+
+impl<const N: usize> Validate for FixedBitString<N> {
+    fn validate_with_constraints(&self, constraints: Constraints, validator: &mut Validator) {
+        validator.size(self.len(), &constraints);
+    }
+}
+
+impl Validate for Utf8String {
+    fn validate_with_constraints(&self, constraints: Constraints, validator: &mut Validator) {
+        validator.check(check_characters(
+            || self.chars().map(u32::from),
+            &constraints,
+        ));
+    }
+}
+
+impl Validate for str {
+    fn validate_with_constraints(&self, constraints: Constraints, validator: &mut Validator) {
+        validator.check(check_characters(
+            || self.chars().map(u32::from),
+            &constraints,
+        ));
+    }
+}
+
+macro_rules! impl_restricted_strings {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl Validate for $ty {
+                fn validate_with_constraints(&self, constraints: Constraints, validator: &mut Validator) {
+                    validator.check(check_characters(|| self.chars(), &constraints));
+                }
+            }
+        )+
+    }
+}
+
+impl_restricted_strings!(
+    BmpString,
+    GeneralString,
+    GraphicString,
+    Ia5String,
+    NumericString,
+    PrintableString,
+    TeletexString,
+    VisibleString,
+);
+
+impl<T: Validate> Validate for Option<T> {
+    fn validate_with_constraints(&self, constraints: Constraints, validator: &mut Validator) {
+        if let Some(value) = self {
+            value.validate_with_constraints(T::CONSTRAINTS.intersect(constraints), validator);
+        }
+    }
+}
+
+impl<T: Validate> Validate for Box<T> {
+    fn validate_with_constraints(&self, constraints: Constraints, validator: &mut Validator) {
+        (**self).validate_with_constraints(T::CONSTRAINTS.intersect(constraints), validator);
+    }
+}
+
+impl<T: Validate> Validate for &'_ T {
+    fn validate_with_constraints(&self, constraints: Constraints, validator: &mut Validator) {
+        (**self).validate_with_constraints(constraints, validator);
+    }
+}
+
+impl<T: Validate> Validate for Vec<T> {
+    fn validate_with_constraints(&self, constraints: Constraints, validator: &mut Validator) {
+        validator.size(self.len(), &constraints);
+        for (i, element) in self.iter().enumerate() {
+            validator.element(i, element);
+        }
+    }
+}
+
+impl<T: Validate, const N: usize> Validate for [T; N] {
+    fn validate_with_constraints(&self, _: Constraints, validator: &mut Validator) {
+        for (i, element) in self.iter().enumerate() {
+            validator.element(i, element);
+        }
+    }
+}
+
+impl<T: Validate + Eq + core::hash::Hash> Validate for SetOf<T> {
+    fn validate_with_constraints(&self, constraints: Constraints, validator: &mut Validator) {
+        validator.size(self.len(), &constraints);
+        for (i, element) in self.to_vec().into_iter().enumerate() {
+            validator.element(i, element);
+        }
+    }
+}
+
+impl<T: AsnType, V: Validate> Validate for Implicit<T, V> {
+    fn validate_with_constraints(&self, constraints: Constraints, validator: &mut Validator) {
+        self.value.validate_with_constraints(constraints, validator);
+    }
+}
+
+impl<T: AsnType, V: Validate> Validate for Explicit<T, V> {
+    fn validate_with_constraints(&self, constraints: Constraints, validator: &mut Validator) {
+        self.value.validate_with_constraints(constraints, validator);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{prelude::*, types::constraints::Bounded};
+
+    #[derive(AsnType, Validate)]
+    #[rasn(crate_root = "crate", automatic_tags)]
+    struct Record {
+        #[rasn(value("-5..=5"))]
+        offset: i32,
+        #[rasn(size("2..=3"), from("a..=c"))]
+        label: Ia5String,
+        #[rasn(size(2))]
+        flags: Option<BitString>,
+        entries: SequenceOf<Entry>,
+    }
+
+    #[derive(AsnType, Validate)]
+    #[rasn(crate_root = "crate", choice, automatic_tags)]
+    enum Entry {
+        #[rasn(value("0..=9"))]
+        Count(u8),
+        #[rasn(size("1.."))]
+        Name(Utf8String),
+    }
+
+    #[derive(AsnType, Validate)]
+    #[rasn(crate_root = "crate", delegate, size("1..=2"))]
+    struct Key(OctetString);
+
+    #[derive(AsnType, Validate)]
+    #[rasn(crate_root = "crate", automatic_tags)]
+    #[non_exhaustive]
+    struct Extensible {
+        #[rasn(value("0..=3", extensible))]
+        level: Integer,
+    }
+
+    #[test]
+    fn valid() {
+        let record = Record {
+            offset: -5,
+            label: Ia5String::try_from("abc").unwrap(),
+            flags: None,
+            entries: alloc::vec![Entry::Count(9), Entry::Name("a".into())],
+        };
+
+        assert!(record.validate().is_ok());
+        assert!(Key(OctetString::from_static(&[1, 2])).validate().is_ok());
+        assert!(Extensible { level: 4.into() }.validate().is_ok());
+    }
+
+    #[test]
+    fn violations() {
+        let record = Record {
+            offset: 6,
+            label: Ia5String::try_from("abcd").unwrap(),
+            flags: Some(BitString::repeat(false, 3)),
+            entries: alloc::vec![
+                Entry::Count(9),
+                Entry::Count(10),
+                Entry::Name(String::new())
+            ],
+        };
+        let violations = record.validate().unwrap_err().violations;
+        let paths = violations
+            .iter()
+            .map(|v| v.path.as_str())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            paths,
+            [
+                "offset",
+                "label",
+                "flags",
+                "entries[1].Count",
+                "entries[2].Name"
+            ]
+        );
+        assert!(matches!(
+            &violations[0].kind,
+            ConstraintViolationKind::Value { expected, .. }
+                if ***expected == Bounded::const_new(-5, 5)
+        ));
+        assert!(matches!(
+            violations[1].kind,
+            ConstraintViolationKind::Size { size: 4, .. }
+        ));
+    }
+
+    #[test]
+    fn permitted_alphabet() {
+        let record = Record {
+            offset: 0,
+            label: Ia5String::try_from("ad").unwrap(),
+            flags: None,
+            entries: Vec::new(),
+        };
+        let violations = record.validate().unwrap_err().violations;
+
+        assert_eq!(violations.len(), 1);
+        assert!(matches!(
+            violations[0].kind,
+            ConstraintViolationKind::PermittedAlphabet { character: 0x64 }
+        ));
+        assert_eq!(
+            violations[0].to_string(),
+            "label: Character with decimal value 100 is not in the permitted alphabet"
+        );
+    }
+
+    #[test]
+    fn delegate() {
+        let error = Key(OctetString::from_static(&[1, 2, 3]))
+            .validate()
+            .unwrap_err();
+
+        assert_eq!(error.violations[0].path, "");
+        assert_eq!(
+            error.to_string(),
+            "1 constraint violation(s): Size 3 is not in 1..2"
+        );
+    }
+}
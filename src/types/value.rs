@@ -0,0 +1,697 @@
+//! A generic tree of ASN.1 values, decoded and encoded with a
+//! [`TypeDescriptor`] instead of a Rust type.
+
+use alloc::{boxed::Box, string::ToString, vec::Vec};
+
+use super::{
+    descriptor::{EnumeratedVariant, FieldDescriptor, TypeDescriptor, TypeKind, VariantDescriptor},
+    external::Identification,
+    strings::StaticPermittedAlphabet,
+    Any, BitString, BmpString, Date, DateTime, External, GeneralString, GeneralizedTime,
+    GraphicString, Ia5String, Identifier, Integer, IsoDuration, IsoTime, NumericString,
+    ObjectIdentifier, OctetString, OidIri, PrintableString, RelativeObjectIdentifier,
+    RelativeOidIri, TeletexString, TimeOfDay, UtcTime, Utf8String, VisibleString,
+};
+use crate::{de::Error as _, enc::Error as _, error::EncodeError, Codec, Decode, Encode};
+
+/// A dynamically typed ASN.1 value.
+///
+/// Values are decoded and encoded with [`Decoder::decode_value`] and
+/// [`Encoder::encode_value`] by pairing them with a [`TypeDescriptor`], which
+/// allows transcoding between codecs without the Rust type being available.
+///
+/// Explicit tags are transparent, and `OPTIONAL` or `DEFAULT` components
+/// which are absent from the encoding are omitted from `SEQUENCE` and `SET`
+/// values. Unknown extension additions are skipped. `DEFAULT` components
+/// which are present in a value are always encoded, as descriptors don't
+/// carry default values.
+///
+/// The [`Display`](core::fmt::Display) implementation formats the value in
+/// ASN.1 value notation.
+///
+/// [`Decoder::decode_value`]: crate::Decoder::decode_value
+/// [`Encoder::encode_value`]: crate::Encoder::encode_value
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum Value<'a> {
+    /// `BOOLEAN`
+    Boolean(bool),
+    /// `INTEGER`
+    Integer(Integer),
+    /// `REAL`
+    #[cfg(feature = "f64")]
+    Real(f64),
+    /// `NULL`
+    Null,
+    /// `ENUMERATED`, identified by the name of the variant.
+    Enumerated(&'a str),
+    /// `OBJECT IDENTIFIER`
+    ObjectIdentifier(ObjectIdentifier),
+    /// `RELATIVE-OID`
+    RelativeOid(RelativeObjectIdentifier),
+    /// `OID-IRI`
+    OidIri(OidIri),
+    /// `RELATIVE-OID-IRI`
+    RelativeOidIri(RelativeOidIri),
+    /// `EXTERNAL`
+    External(External),
+    /// `BIT STRING`
+    BitString(BitString),
+    /// `OCTET STRING`
+    OctetString(OctetString),
+    /// `UTF8String`
+    Utf8String(Utf8String),
+    /// `IA5String`
+    Ia5String(Ia5String),
+    /// `PrintableString`
+    PrintableString(PrintableString),
+    /// `VisibleString`
+    VisibleString(VisibleString),
+    /// `NumericString`
+    NumericString(NumericString),
+    /// `BMPString`
+    BmpString(BmpString),
+    /// `GeneralString`
+    GeneralString(GeneralString),
+    /// `GraphicString`
+    GraphicString(GraphicString),
+    /// `TeletexString`
+    TeletexString(TeletexString),
+    /// `UTCTime`
+    UtcTime(UtcTime),
+    /// `GeneralizedTime`
+    GeneralizedTime(GeneralizedTime),
+    /// `DATE`
+    Date(Date),
+    /// `TIME`
+    Time(IsoTime),
+    /// `TIME-OF-DAY`
+    TimeOfDay(TimeOfDay),
+    /// `DATE-TIME`
+    DateTime(DateTime),
+    /// `DURATION`
+    Duration(IsoDuration),
+    /// An open type.
+    Any(Any),
+    /// `SEQUENCE`, with the present components in order of definition.
+    Sequence(Vec<NamedValue<'a>>),
+    /// `SET`, with the present components in order of definition.
+    Set(Vec<NamedValue<'a>>),
+    /// `CHOICE`
+    Choice {
+        /// The name of the chosen alternative.
+        variant: &'a str,
+        /// The value of the chosen alternative.
+        value: Box<Value<'a>>,
+    },
+    /// `SEQUENCE OF`
+    SequenceOf(Vec<Value<'a>>),
+    /// `SET OF`
+    SetOf(Vec<Value<'a>>),
+}
+
+/// A named component of a `SEQUENCE` or `SET` value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NamedValue<'a> {
+    /// The name of the component.
+    pub name: &'a str,
+    /// The value of the component.
+    pub value: Value<'a>,
+}
+
+impl<'a> NamedValue<'a> {
+    /// Creates a new named value.
+    #[must_use]
+    pub fn new(name: &'a str, value: Value<'a>) -> Self {
+        Self { name, value }
+    }
+}
+
+impl<'a> Value<'a> {
+    /// Returns the component named `name` if `self` is a `SEQUENCE` or `SET`
+    /// value, or the value of the alternative if `self` is a `CHOICE` value
+    /// with the alternative `name`.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&Value<'a>> {
+        match self {
+            Self::Sequence(components) | Self::Set(components) => components
+                .iter()
+                .find(|component| component.name == name)
+                .map(|component| &component.value),
+            Self::Choice { variant, value } if *variant == name => Some(&**value),
+            _ => None,
+        }
+    }
+
+    /// Decodes a value of a type without inner structure, such as `INTEGER`
+    /// or `UTF8String`, using its regular [`Decode`] implementation.
+    /// Constructed kinds depend on the codec, and return an error here.
+    pub(crate) fn decode_primitive<D: crate::Decoder>(
+        decoder: &mut D,
+        descriptor: &TypeDescriptor<'a>,
+    ) -> Result<Self, D::Error> {
+        let tag = descriptor.tag;
+        let constraints = descriptor.constraints.get();
+
+        macro_rules! decode {
+            ($variant:ident, $ty:ty) => {
+                <$ty>::decode_with_tag_and_constraints(decoder, tag, constraints)
+                    .map(Self::$variant)
+            };
+        }
+
+        match descriptor.kind {
+            TypeKind::Boolean => decode!(Boolean, bool),
+            TypeKind::Integer => decode!(Integer, Integer),
+            #[cfg(feature = "f64")]
+            TypeKind::Real => decode!(Real, f64),
+            TypeKind::Null => decoder.decode_null(tag).map(|()| Self::Null),
+            TypeKind::ObjectIdentifier => decode!(ObjectIdentifier, ObjectIdentifier),
+            TypeKind::RelativeOid => decode!(RelativeOid, RelativeObjectIdentifier),
+            TypeKind::OidIri => decode!(OidIri, OidIri),
+            TypeKind::RelativeOidIri => decode!(RelativeOidIri, RelativeOidIri),
+            TypeKind::External => decode!(External, External),
+            TypeKind::BitString => decode!(BitString, BitString),
+            TypeKind::OctetString => decode!(OctetString, OctetString),
+            TypeKind::Utf8String => decode!(Utf8String, Utf8String),
+            TypeKind::Ia5String => decode!(Ia5String, Ia5String),
+            TypeKind::PrintableString => decode!(PrintableString, PrintableString),
+            TypeKind::VisibleString => decode!(VisibleString, VisibleString),
+            TypeKind::NumericString => decode!(NumericString, NumericString),
+            TypeKind::BmpString => decode!(BmpString, BmpString),
+            TypeKind::GeneralString => decode!(GeneralString, GeneralString),
+            TypeKind::GraphicString => decode!(GraphicString, GraphicString),
+            TypeKind::TeletexString => decode!(TeletexString, TeletexString),
+            TypeKind::UtcTime => decode!(UtcTime, UtcTime),
+            TypeKind::GeneralizedTime => decode!(GeneralizedTime, GeneralizedTime),
+            TypeKind::Date => decode!(Date, Date),
+            TypeKind::Time => decode!(Time, IsoTime),
+            TypeKind::TimeOfDay => decode!(TimeOfDay, TimeOfDay),
+            TypeKind::DateTime => decode!(DateTime, DateTime),
+            TypeKind::Duration => decode!(Duration, IsoDuration),
+            TypeKind::Any => decoder.decode_any().map(Self::Any),
+            _ => Err(D::Error::custom(
+                alloc::format!(
+                    "{:?} can't be decoded without codec specific support",
+                    descriptor.kind
+                ),
+                decoder.codec(),
+            )),
+        }
+    }
+
+    /// Encodes a value of a type without inner structure, such as `INTEGER`
+    /// or `UTF8String`, using the regular [`Encode`] implementation of the
+    /// value. Constructed kinds depend on the codec, and return an error here.
+    pub(crate) fn encode_primitive<'encoder, E: crate::Encoder<'encoder>>(
+        &self,
+        encoder: &mut E,
+        descriptor: &TypeDescriptor,
+    ) -> Result<(), E::Error> {
+        macro_rules! encode {
+            ($value:expr) => {
+                $value.encode_with_tag_and_constraints(
+                    encoder,
+                    descriptor.tag,
+                    descriptor.constraints.get(),
+                    Identifier::EMPTY,
+                )
+            };
+        }
+
+        match (&descriptor.kind, self) {
+            (TypeKind::Boolean, Self::Boolean(value)) => encode!(value),
+            (TypeKind::Integer, Self::Integer(value)) => encode!(value),
+            #[cfg(feature = "f64")]
+            (TypeKind::Real, Self::Real(value)) => encode!(value),
+            (TypeKind::Null, Self::Null) => encode!(()),
+            (TypeKind::ObjectIdentifier, Self::ObjectIdentifier(value)) => encode!(value),
+            (TypeKind::RelativeOid, Self::RelativeOid(value)) => encode!(value),
+            (TypeKind::OidIri, Self::OidIri(value)) => encode!(value),
+            (TypeKind::RelativeOidIri, Self::RelativeOidIri(value)) => encode!(value),
+            (TypeKind::External, Self::External(value)) => encode!(value),
+            (TypeKind::BitString, Self::BitString(value)) => encode!(value),
+            (TypeKind::OctetString, Self::OctetString(value)) => encode!(value),
+            (TypeKind::Utf8String, Self::Utf8String(value)) => encode!(value),
+            (TypeKind::Ia5String, Self::Ia5String(value)) => encode!(value),
+            (TypeKind::PrintableString, Self::PrintableString(value)) => encode!(value),
+            (TypeKind::VisibleString, Self::VisibleString(value)) => encode!(value),
+            (TypeKind::NumericString, Self::NumericString(value)) => encode!(value),
+            (TypeKind::BmpString, Self::BmpString(value)) => encode!(value),
+            (TypeKind::GeneralString, Self::GeneralString(value)) => encode!(value),
+            (TypeKind::GraphicString, Self::GraphicString(value)) => encode!(value),
+            (TypeKind::TeletexString, Self::TeletexString(value)) => encode!(value),
+            (TypeKind::UtcTime, Self::UtcTime(value)) => encode!(value),
+            (TypeKind::GeneralizedTime, Self::GeneralizedTime(value)) => encode!(value),
+            (TypeKind::Date, Self::Date(value)) => encode!(value),
+            (TypeKind::Time, Self::Time(value)) => encode!(value),
+            (TypeKind::TimeOfDay, Self::TimeOfDay(value)) => encode!(value),
+            (TypeKind::DateTime, Self::DateTime(value)) => encode!(value),
+            (TypeKind::Duration, Self::Duration(value)) => encode!(value),
+            (TypeKind::Any, Self::Any(value)) => encode!(value),
+            _ => Err(self.mismatch(descriptor, encoder.codec()).into()),
+        }
+    }
+
+    /// Matches the components of `self` to the fields of the `SEQUENCE` or
+    /// `SET` type described by `descriptor`.
+    pub(crate) fn components<'d>(
+        &self,
+        descriptor: &TypeDescriptor<'d>,
+        codec: Codec,
+    ) -> Result<Components<'d, '_, 'a>, EncodeError> {
+        match (&descriptor.kind, self) {
+            (
+                TypeKind::Sequence {
+                    fields,
+                    extended_fields,
+                },
+                Self::Sequence(components),
+            )
+            | (
+                TypeKind::Set {
+                    fields,
+                    extended_fields,
+                },
+                Self::Set(components),
+            ) => Components::new(fields, *extended_fields, components, codec),
+            _ => Err(self.mismatch(descriptor, codec)),
+        }
+    }
+
+    /// Returns the elements if `self` is a value of the `SEQUENCE OF` or
+    /// `SET OF` type described by `descriptor`.
+    pub(crate) fn elements(
+        &self,
+        descriptor: &TypeDescriptor,
+        codec: Codec,
+    ) -> Result<&[Value<'a>], EncodeError> {
+        match (&descriptor.kind, self) {
+            (TypeKind::SequenceOf { .. }, Self::SequenceOf(elements))
+            | (TypeKind::SetOf { .. }, Self::SetOf(elements)) => Ok(elements),
+            _ => Err(self.mismatch(descriptor, codec)),
+        }
+    }
+
+    /// Returns the index of the chosen alternative in the root or extension
+    /// list of the `CHOICE` type described by `descriptor`, whether it's an
+    /// extension addition, its descriptor and its value.
+    pub(crate) fn chosen<'d>(
+        &self,
+        descriptor: &TypeDescriptor<'d>,
+        codec: Codec,
+    ) -> Result<(usize, bool, &'d VariantDescriptor<'d>, &Value<'a>), EncodeError> {
+        let (
+            TypeKind::Choice {
+                variants,
+                extended_variants,
+            },
+            Self::Choice { variant, value },
+        ) = (&descriptor.kind, self)
+        else {
+            return Err(self.mismatch(descriptor, codec));
+        };
+
+        find_named(variants, *extended_variants, |alternative| {
+            alternative.name == *variant
+        })
+        .map(|(index, is_extension, variant)| (index, is_extension, variant, &**value))
+        .ok_or_else(|| EncodeError::variant_not_in_choice(codec))
+    }
+
+    /// Returns the index of the value in the root or extension list of the
+    /// `ENUMERATED` type described by `descriptor`, whether it's an extension
+    /// addition, and its descriptor.
+    pub(crate) fn enumerated<'d>(
+        &self,
+        descriptor: &TypeDescriptor<'d>,
+        codec: Codec,
+    ) -> Result<(usize, bool, &'d EnumeratedVariant<'d>), EncodeError> {
+        let (
+            TypeKind::Enumerated {
+                variants,
+                extended_variants,
+            },
+            Self::Enumerated(name),
+        ) = (&descriptor.kind, self)
+        else {
+            return Err(self.mismatch(descriptor, codec));
+        };
+
+        find_named(variants, *extended_variants, |variant| {
+            variant.name == *name
+        })
+        .ok_or_else(|| self.mismatch(descriptor, codec))
+    }
+
+    fn mismatch(&self, descriptor: &TypeDescriptor, codec: Codec) -> EncodeError {
+        EncodeError::custom(
+            alloc::format!(
+                "`{self}` is not a valid value of `{}`",
+                descriptor.identifier.unwrap_or("the described type")
+            ),
+            codec,
+        )
+    }
+
+    fn fmt_indented(&self, f: &mut core::fmt::Formatter<'_>, depth: usize) -> core::fmt::Result {
+        match self {
+            Self::Boolean(value) => f.write_str(if *value { "TRUE" } else { "FALSE" }),
+            Self::Integer(value) => write!(f, "{value}"),
+            #[cfg(feature = "f64")]
+            Self::Real(value) => write!(f, "{value}"),
+            Self::Null => f.write_str("NULL"),
+            Self::Enumerated(name) => f.write_str(name),
+            Self::ObjectIdentifier(oid) => fmt_arcs(f, oid),
+            Self::RelativeOid(oid) => fmt_arcs(f, oid),
+            Self::BitString(bits) => {
+                f.write_str("'")?;
+                for bit in bits.iter() {
+                    f.write_str(if *bit { "1" } else { "0" })?;
+                }
+                f.write_str("'B")
+            }
+            Self::OctetString(octets) => fmt_hex(f, octets),
+            Self::Any(any) => fmt_hex(f, any.as_bytes()),
+            Self::Utf8String(string) => fmt_chars(f, string.chars()),
+            Self::Ia5String(string) => fmt_chars(f, string.to_string().chars()),
+            Self::VisibleString(string) => fmt_chars(f, string.to_string().chars()),
+            Self::PrintableString(string) => fmt_alphabet(f, string),
+            Self::NumericString(string) => fmt_alphabet(f, string),
+            Self::BmpString(string) => fmt_alphabet(f, string),
+            Self::GeneralString(string) => fmt_alphabet(f, string),
+            Self::GraphicString(string) => fmt_alphabet(f, string),
+            Self::TeletexString(string) => fmt_alphabet(f, string),
+            Self::UtcTime(time) => write!(f, "\"{}\"", time.format("%y%m%d%H%M%SZ")),
+            Self::GeneralizedTime(time) => write!(f, "\"{}\"", time.format("%Y%m%d%H%M%S%.f%z")),
+            Self::Date(date) => write!(f, "\"{}\"", date.format("%Y-%m-%d")),
+            Self::Time(time) => write!(f, "\"{time}\""),
+            Self::TimeOfDay(time) => write!(f, "\"{}\"", time.format("%H:%M:%S")),
+            Self::DateTime(time) => write!(f, "\"{}\"", time.format("%Y-%m-%dT%H:%M:%S")),
+            Self::Duration(duration) => write!(f, "\"{duration}\""),
+            Self::OidIri(iri) => write!(f, "\"{iri}\""),
+            Self::RelativeOidIri(iri) => write!(f, "\"{iri}\""),
+            Self::External(external) => {
+                Self::Sequence(external_components(external)).fmt_indented(f, depth)
+            }
+            Self::Sequence(components) | Self::Set(components) => {
+                fmt_list(f, depth, components, |f, component| {
+                    write!(f, "{} ", component.name)?;
+                    component.value.fmt_indented(f, depth + 1)
+                })
+            }
+            Self::SequenceOf(values) | Self::SetOf(values) => {
+                fmt_list(f, depth, values, |f, value| {
+                    value.fmt_indented(f, depth + 1)
+                })
+            }
+            Self::Choice { variant, value } => {
+                write!(f, "{variant} : ")?;
+                value.fmt_indented(f, depth)
+            }
+        }
+    }
+}
+
+/// The components of a `SEQUENCE` or `SET` value, matched to the root and
+/// extension fields of its type in order of definition.
+pub(crate) struct Components<'d, 'value, 'a> {
+    pub(crate) fields: &'d [FieldDescriptor<'d>],
+    pub(crate) extended_fields: Option<&'d [FieldDescriptor<'d>]>,
+    pub(crate) root: Vec<Option<&'value Value<'a>>>,
+    pub(crate) extensions: Vec<Option<&'value Value<'a>>>,
+}
+
+impl<'d, 'value, 'a> Components<'d, 'value, 'a> {
+    /// Matches `components` to `fields` and `extended_fields` by name,
+    /// returning an error if a required root component is missing or if a
+    /// component isn't a field of the type.
+    fn new(
+        fields: &'d [FieldDescriptor<'d>],
+        extended_fields: Option<&'d [FieldDescriptor<'d>]>,
+        components: &'value [NamedValue<'a>],
+        codec: Codec,
+    ) -> Result<Self, EncodeError> {
+        let find = |field: &FieldDescriptor| {
+            components
+                .iter()
+                .find(|component| component.name == field.name)
+                .map(|component| &component.value)
+        };
+
+        let root = fields
+            .iter()
+            .map(|field| match find(field) {
+                None if !field.presence.is_optional_or_default() => Err(EncodeError::custom(
+                    alloc::format!("missing required component `{}`", field.name),
+                    codec,
+                )),
+                value => Ok(value),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let extensions = extended_fields
+            .into_iter()
+            .flatten()
+            .map(find)
+            .collect::<Vec<_>>();
+
+        let all_fields = || fields.iter().chain(extended_fields.into_iter().flatten());
+        if let Some(unknown) = components
+            .iter()
+            .find(|component| !all_fields().any(|field| field.name == component.name))
+        {
+            return Err(EncodeError::custom(
+                alloc::format!("unknown component `{}`", unknown.name),
+                codec,
+            ));
+        }
+
+        Ok(Self {
+            fields,
+            extended_fields,
+            root,
+            extensions,
+        })
+    }
+
+    /// Returns the present root components with their fields.
+    pub(crate) fn present(
+        &self,
+    ) -> impl Iterator<Item = (&'d FieldDescriptor<'d>, &'value Value<'a>)> + '_ {
+        self.fields
+            .iter()
+            .zip(&self.root)
+            .filter_map(|(field, value)| value.map(|value| (field, value)))
+    }
+
+    /// Returns the present extension additions with their fields.
+    pub(crate) fn present_extensions(
+        &self,
+    ) -> impl Iterator<Item = (&'d FieldDescriptor<'d>, &'value Value<'a>)> + '_ {
+        self.extended_fields
+            .into_iter()
+            .flatten()
+            .zip(&self.extensions)
+            .filter_map(|(field, value)| value.map(|value| (field, value)))
+    }
+
+    /// Returns whether any extension addition is present.
+    pub(crate) fn has_extensions(&self) -> bool {
+        self.extensions.iter().any(Option::is_some)
+    }
+}
+
+/// Finds the first item matching `predicate` in the root or extension list
+/// of a `CHOICE` or `ENUMERATED` type, returning its index in that list and
+/// whether it is an extension addition.
+fn find_named<'d, T>(
+    root: &'d [T],
+    extensions: Option<&'d [T]>,
+    predicate: impl Fn(&T) -> bool,
+) -> Option<(usize, bool, &'d T)> {
+    let position = |list: &'d [T], is_extension| {
+        list.iter()
+            .position(&predicate)
+            .map(|index| (index, is_extension, &list[index]))
+    };
+
+    position(root, false).or_else(|| extensions.and_then(|list| position(list, true)))
+}
+
+impl core::fmt::Display for Value<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.fmt_indented(f, 0)
+    }
+}
+
+fn fmt_list<T>(
+    f: &mut core::fmt::Formatter<'_>,
+    depth: usize,
+    items: &[T],
+    mut fmt_item: impl FnMut(&mut core::fmt::Formatter<'_>, &T) -> core::fmt::Result,
+) -> core::fmt::Result {
+    if items.is_empty() {
+        return f.write_str("{}");
+    }
+
+    f.write_str("{\n")?;
+    for (i, item) in items.iter().enumerate() {
+        write!(f, "{:width$}", "", width = (depth + 1) * 2)?;
+        fmt_item(f, item)?;
+        f.write_str(if i + 1 == items.len() { "\n" } else { ",\n" })?;
+    }
+    write!(f, "{:width$}}}", "", width = depth * 2)
+}
+
+/// Returns the components of the associated type of `external`.
+fn external_components(external: &External) -> Vec<NamedValue<'static>> {
+    let oid = |oid: &ObjectIdentifier| Value::ObjectIdentifier(oid.clone());
+    let (variant, value) = match &external.identification {
+        Identification::Syntaxes(syntaxes) => (
+            "syntaxes",
+            Value::Sequence(alloc::vec![
+                NamedValue::new("abstract", oid(&syntaxes.abstract_syntax)),
+                NamedValue::new("transfer", oid(&syntaxes.transfer_syntax)),
+            ]),
+        ),
+        Identification::Syntax(syntax) => ("syntax", oid(syntax)),
+        Identification::PresentationContextId(id) => {
+            ("presentation-context-id", Value::Integer(id.clone()))
+        }
+        Identification::ContextNegotiation(negotiation) => (
+            "context-negotiation",
+            Value::Sequence(alloc::vec![
+                NamedValue::new(
+                    "presentation-context-id",
+                    Value::Integer(negotiation.presentation_context_id.clone()),
+                ),
+                NamedValue::new("transfer-syntax", oid(&negotiation.transfer_syntax)),
+            ]),
+        ),
+        Identification::TransferSyntax(syntax) => ("transfer-syntax", oid(syntax)),
+        Identification::Fixed => ("fixed", Value::Null),
+    };
+
+    let mut components = alloc::vec![NamedValue::new(
+        "identification",
+        Value::Choice {
+            variant,
+            value: Box::new(value),
+        },
+    )];
+    if let Some(descriptor) = &external.data_value_descriptor {
+        components.push(NamedValue::new(
+            "data-value-descriptor",
+            Value::GraphicString(descriptor.value.clone()),
+        ));
+    }
+    components.push(NamedValue::new(
+        "data-value",
+        Value::OctetString(external.data_value.clone()),
+    ));
+    components
+}
+
+fn fmt_arcs(f: &mut core::fmt::Formatter<'_>, arcs: &[u32]) -> core::fmt::Result {
+    f.write_str("{")?;
+    for arc in arcs {
+        write!(f, " {arc}")?;
+    }
+    f.write_str(" }")
+}
+
+fn fmt_hex(f: &mut core::fmt::Formatter<'_>, bytes: &[u8]) -> core::fmt::Result {
+    f.write_str("'")?;
+    for byte in bytes {
+        write!(f, "{byte:02X}")?;
+    }
+    f.write_str("'H")
+}
+
+fn fmt_alphabet<S: StaticPermittedAlphabet>(
+    f: &mut core::fmt::Formatter<'_>,
+    string: &S,
+) -> core::fmt::Result {
+    fmt_chars(
+        f,
+        string
+            .chars()
+            .map(|ch| char::from_u32(ch).unwrap_or(char::REPLACEMENT_CHARACTER)),
+    )
+}
+
+fn fmt_chars(
+    f: &mut core::fmt::Formatter<'_>,
+    chars: impl Iterator<Item = char>,
+) -> core::fmt::Result {
+    use core::fmt::Write;
+
+    f.write_char('"')?;
+    for ch in chars {
+        // Quotation marks are escaped by doubling them in value notation.
+        if ch == '"' {
+            f.write_char('"')?;
+        }
+        f.write_char(ch)?;
+    }
+    f.write_char('"')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        let value = Value::Sequence(alloc::vec![
+            NamedValue::new("id", Value::Integer(5.into())),
+            NamedValue::new("name", Value::Utf8String("say \"hi\"".into())),
+            NamedValue::new(
+                "kind",
+                Value::Choice {
+                    variant: "flag",
+                    value: Box::new(Value::Boolean(true)),
+                },
+            ),
+            NamedValue::new(
+                "data",
+                Value::SequenceOf(alloc::vec![
+                    Value::OctetString(OctetString::from_static(&[0x0A, 0xFF])),
+                    Value::BitString(BitString::from_slice(&[0b1010_0000])),
+                ]),
+            ),
+            NamedValue::new("empty", Value::Set(Vec::new())),
+        ]);
+
+        assert_eq!(
+            value.to_string(),
+            "{\n  \
+               id 5,\n  \
+               name \"say \"\"hi\"\"\",\n  \
+               kind flag : TRUE,\n  \
+               data {\n    \
+                 '0AFF'H,\n    \
+                 '10100000'B\n  \
+               },\n  \
+               empty {}\n\
+             }"
+        );
+    }
+
+    #[test]
+    fn get() {
+        let value = Value::Choice {
+            variant: "number",
+            value: Box::new(Value::Sequence(alloc::vec![NamedValue::new(
+                "value",
+                Value::Null
+            )])),
+        };
+
+        assert_eq!(
+            value.get("number").and_then(|value| value.get("value")),
+            Some(&Value::Null)
+        );
+        assert_eq!(value.get("other"), None);
+    }
+}
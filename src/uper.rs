@@ -23,6 +23,15 @@ pub fn decode_with_remainder<T: crate::Decode>(
     crate::per::decode_with_remainder(de::DecoderOptions::unaligned(), input)
 }
 
+/// Attempts to decode a value of the type described by `descriptor` from
+/// `input` using UPER-BASIC, without needing the Rust type of the value.
+pub fn decode_value<'d>(
+    descriptor: &crate::types::TypeDescriptor<'d>,
+    input: &[u8],
+) -> Result<crate::types::value::Value<'d>, crate::error::DecodeError> {
+    crate::per::decode_value(de::DecoderOptions::unaligned(), descriptor, input)
+}
+
 /// Attempts to encode `value` to UPER-CANONICAL.
 pub fn encode<T: crate::Encode>(
     value: &T,
@@ -35,6 +44,66 @@ pub fn encode<T: crate::Encode>(
     }
 }
 
+/// Attempts to encode `value` as the type described by `descriptor` to
+/// UPER-CANONICAL, without needing the Rust type of the value.
+pub fn encode_value(
+    descriptor: &crate::types::TypeDescriptor,
+    value: &crate::types::value::Value,
+) -> Result<alloc::vec::Vec<u8>, crate::error::EncodeError> {
+    let result = crate::per::encode_value(enc::EncoderOptions::unaligned(), descriptor, value)?;
+    if result.is_empty() {
+        Ok(alloc::vec![0x00])
+    } else {
+        Ok(result)
+    }
+}
+
+/// Attempts to encode `value` to UPER-CANONICAL into the start of `buffer`, returning
+/// the number of bytes written.
+///
+/// # Errors
+/// Returns `EncodeError` if `value` cannot be encoded, or with
+/// [`EncodeErrorKind::BufferTooSmall`](crate::error::EncodeErrorKind::BufferTooSmall)
+/// as soon as the encoding doesn't fit into `buffer`. The contents of
+/// `buffer` are unspecified on error.
+pub fn encode_into<T: crate::Encode>(
+    value: &T,
+    buffer: &mut [u8],
+) -> Result<usize, crate::error::EncodeError> {
+    encode_to_sink(value, crate::enc::SliceSink::new(buffer))
+}
+
+/// Attempts to encode `value` to UPER-CANONICAL, writing it to `writer` as it is
+/// encoded and returning the number of bytes written.
+///
+/// # Errors
+/// Returns `EncodeError` if `value` cannot be encoded, or with
+/// [`EncodeErrorKind::Io`](crate::error::EncodeErrorKind::Io) if writing
+/// fails, in which case part of the encoding may have been written.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn encode_to_writer<T: crate::Encode, W: std::io::Write>(
+    value: &T,
+    writer: W,
+) -> Result<usize, crate::error::EncodeError> {
+    encode_to_sink(value, crate::enc::IoSink::new(writer))
+}
+
+/// Encodes `value` to UPER-CANONICAL directly into `sink`, writing a single
+/// zero octet for an empty encoding like [`encode`].
+fn encode_to_sink<T: crate::Encode, S: crate::enc::Sink>(
+    value: &T,
+    mut sink: S,
+) -> Result<usize, crate::error::EncodeError> {
+    match enc::Encoder::encode_to_sink(enc::EncoderOptions::unaligned(), value, &mut sink)? {
+        0 => {
+            crate::enc::Sink::write(&mut sink, &[0], crate::Codec::Uper)?;
+            Ok(1)
+        }
+        written => Ok(written),
+    }
+}
+
 /// Attempts to decode `T` from `input` using UPER-BASIC.
 pub fn decode_with_constraints<T: crate::Decode>(
     constraints: Constraints,
@@ -70,6 +139,33 @@ mod tests {
         round_trip!(uper, bool, false, &[0]);
     }
 
+    #[test]
+    #[cfg(feature = "f64")]
+    fn real() {
+        #[derive(Debug, AsnType, Decode, Encode, PartialEq)]
+        #[rasn(crate_root = "crate")]
+        struct Measurement {
+            valid: bool,
+            value: f64,
+        }
+
+        round_trip!(uper, f64, 0.0, &[0x00]);
+        round_trip!(uper, f64, -0.0, &[0x01, 0x43]);
+        round_trip!(uper, f64, f64::INFINITY, &[0x01, 0x40]);
+        round_trip!(uper, f64, 1.0, &[0x03, 0x80, 0x00, 0x01]);
+        round_trip!(
+            uper,
+            Measurement,
+            Measurement {
+                valid: true,
+                value: 1.0
+            },
+            &[0x81, 0xC0, 0x00, 0x00, 0x80]
+        );
+        // Only the CER forms are allowed in PER.
+        decode_error!(uper, f64, &[0x03, 0x90, 0x01, 0x01]);
+    }
+
     #[test]
     fn integer() {
         round_trip!(uper, Integer, 32768.into(), &[0x03, 0x00, 0x80, 0x00]);
@@ -104,6 +200,45 @@ mod tests {
         round_trip!(uper, E, E::new(1000), &[0x00]);
     }
 
+    #[test]
+    fn time_types() {
+        let date = chrono::NaiveDate::from_ymd_opt(2012, 12, 21).unwrap();
+        let time = TimeOfDay::from_hms_opt(12, 30, 45).unwrap();
+        round_trip!(uper, TimeOfDay, time, &[0x63, 0xd6, 0x80]);
+        round_trip!(
+            uper,
+            DateTime,
+            date.and_time(time),
+            &[0x1e, 0xe8, 0xc7, 0xad]
+        );
+        round_trip!(
+            uper,
+            DateTime,
+            chrono::NaiveDate::from_ymd_opt(1500, 1, 1)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap(),
+            &[0xc0, 0x81, 0x77, 0, 0, 0, 0]
+        );
+        round_trip!(
+            uper,
+            IsoDuration,
+            "P1Y2M10DT2H30M".parse().unwrap(),
+            &[0xdc, 1, 1, 1, 2, 1, 10, 1, 2, 1, 30]
+        );
+    }
+
+    #[test]
+    fn relative_oid_types() {
+        round_trip!(
+            uper,
+            RelativeObjectIdentifier,
+            RelativeObjectIdentifier::new(vec![8571, 3, 2]).unwrap(),
+            &[0x04, 0xc2, 0x7b, 0x03, 0x02]
+        );
+        round_trip!(uper, OidIri, "/ISO".parse().unwrap(), b"\x04/ISO");
+    }
+
     #[test]
     fn sequence_of() {
         round_trip!(uper, Vec<u8>, vec![1; 5], &[0b0000_0101, 1, 1, 1, 1, 1]);
@@ -887,6 +1022,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn delegate_extension_addition() {
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(crate_root = "crate", delegate, size("2..=4"))]
+        struct Code(PrintableString);
+
+        #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
+        #[rasn(crate_root = "crate", automatic_tags)]
+        #[non_exhaustive]
+        struct TestSequence {
+            flag: bool,
+            #[rasn(extension_addition)]
+            code: Option<Code>,
+        }
+
+        round_trip!(
+            uper,
+            TestSequence,
+            TestSequence {
+                flag: false,
+                code: Some(Code(PrintableString::try_from("ue-P").unwrap())),
+            },
+            &[0x80, 0x41, 0x2E, 0xB9, 0x56, 0xD0, 0x00]
+        );
+    }
+
     #[test]
     fn recursive_types() {
         #[derive(AsnType, Debug, Clone, Decode, Encode, PartialEq)]
@@ -1281,6 +1442,36 @@ mod tests {
         );
     }
     #[test]
+    fn explicit_tagged_default() {
+        #[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+        #[rasn(crate_root = "crate")]
+        struct Flags {
+            #[rasn(tag(explicit(0)), default)]
+            version: bool,
+            #[rasn(tag(explicit(1)))]
+            flag: bool,
+        }
+
+        round_trip!(
+            uper,
+            Flags,
+            Flags {
+                version: true,
+                flag: true
+            },
+            &[0b1110_0000]
+        );
+        round_trip!(
+            uper,
+            Flags,
+            Flags {
+                version: false,
+                flag: true
+            },
+            &[0b0100_0000]
+        );
+    }
+    #[test]
     // https://github.com/librasn/rasn/issues/478
     fn test_explicit_tagged_null_choice() {
         use crate as rasn;
@@ -146,6 +146,14 @@ mod tests {
         enum_val: EnumType,
     }
 
+    #[derive(AsnType, Debug, Encode, Decode, PartialEq)]
+    #[rasn(automatic_tags, set)]
+    #[rasn(crate_root = "crate")]
+    struct OptionalSet {
+        flag: Option<bool>,
+        int: Option<Integer>,
+    }
+
     fn bool_default() -> bool {
         bool::default()
     }
@@ -292,6 +300,13 @@ mod tests {
         "OBJECT_IDENTIFIER",
         "1.654.2.1"
     );
+    round_trip!(
+        encoded_object_identifier,
+        EncodedObjectIdentifier,
+        crate::encoded_oid!("2.25.329800735698586629295641978511506172918").to_owned(),
+        "OBJECT_IDENTIFIER",
+        "2.25.329800735698586629295641978511506172918"
+    );
     round_trip!(
         sequence,
         InnerTestA,
@@ -564,4 +579,76 @@ mod tests {
         assert!(decoded.contains(&first));
         assert!(decoded.contains(&second));
     }
+
+    #[test]
+    fn set_with_absent_fields() {
+        let value = OptionalSet {
+            flag: None,
+            int: Some(Integer::from(7)),
+        };
+        let encoded = encode(&value).unwrap();
+        assert_eq!(encoded, b"<OptionalSet><int>7</int></OptionalSet>");
+        assert_eq!(value, decode::<OptionalSet>(&encoded).unwrap());
+
+        let value = OptionalSet {
+            flag: None,
+            int: None,
+        };
+        let encoded = encode(&value).unwrap();
+        assert_eq!(encoded, b"<OptionalSet />");
+        assert_eq!(value, decode::<OptionalSet>(&encoded).unwrap());
+    }
+
+    #[test]
+    fn pretty_printed_empty_constructed_types() {
+        assert_eq!(
+            InnerTestA { hidden: None },
+            decode::<InnerTestA>(b"<InnerTestA>\n</InnerTestA>").unwrap()
+        );
+        assert_eq!(
+            OptionalSet {
+                flag: None,
+                int: None
+            },
+            decode::<OptionalSet>(b"<OptionalSet>\n  </OptionalSet>").unwrap()
+        );
+        assert_eq!(
+            SequenceWithSequenceOf {
+                ids: vec![],
+                flag: true,
+                int: Integer::from(1),
+                enum_val: EnumType::Second,
+            },
+            decode::<SequenceWithSequenceOf>(
+                b"<SequenceWithSequenceOf>\n  <ids>\n  </ids>\n  <flag><true /></flag>\n  \
+                  <int>1</int>\n  <enum_val><zwei /></enum_val>\n</SequenceWithSequenceOf>"
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn empty_and_whitespace_strings() {
+        for (value, encoded) in [
+            ("", "<UTF8String></UTF8String>"),
+            ("  ", "<UTF8String>  </UTF8String>"),
+        ] {
+            let value = Utf8String::from(value);
+            assert_eq!(encode(&value).unwrap(), encoded.as_bytes());
+            assert_eq!(value, decode::<Utf8String>(encoded.as_bytes()).unwrap());
+        }
+        assert_eq!("", decode::<Utf8String>(b"<UTF8String />").unwrap());
+        assert_eq!(
+            VisibleString::try_from(" ").unwrap(),
+            decode::<VisibleString>(b"<VisibleString> </VisibleString>").unwrap()
+        );
+        assert_eq!(
+            OctetString::default(),
+            decode::<OctetString>(b"<OCTET_STRING />").unwrap()
+        );
+        assert_eq!(
+            BitString::new(),
+            decode::<BitString>(b"<BIT_STRING></BIT_STRING>").unwrap()
+        );
+    }
 }
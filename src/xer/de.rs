@@ -9,7 +9,12 @@ use xml_no_std::{
     reader::XmlEvent, ParserConfig,
 };
 
-use crate::{error::*, types::*, xer::BOOLEAN_TRUE_TAG, Decode};
+use crate::{
+    error::*,
+    types::{strings::StaticPermittedAlphabet, validate, *},
+    xer::BOOLEAN_TRUE_TAG,
+    Decode,
+};
 
 use self::fields::Field;
 
@@ -66,7 +71,7 @@ macro_rules! tag {
 macro_rules! decode_string {
     ($this:ident, $tryfrom:path, $tag:path, $needed:literal) => {{
         tag!(StartElement, $this)?;
-        let value = match $this.next_element() {
+        let value = match $this.characters_or_empty() {
             Some(XmlEvent::Characters(value)) => $tryfrom(value).map_err(|e| {
                 DecodeError::string_conversion_failed(
                     $tag,
@@ -86,7 +91,7 @@ macro_rules! decode_string {
 }
 
 macro_rules! decode_time {
-    ($this:ident, $decode_fn:path) => {{
+    ($this:ident, $decode_fn:expr) => {{
         tag!(StartElement, $this)?;
         let value = match $this.next_element() {
             Some(XmlEvent::Characters(value)) => $decode_fn(value),
@@ -103,19 +108,19 @@ macro_rules! decode_time {
 
 macro_rules! value_or_empty {
     ($this:ident, $parser:ident, $expected:expr) => {{
-        let value = match $this.peek() {
-            Some(XmlEvent::Characters(s)) => $parser(s),
-            Some(XmlEvent::EndElement { .. }) => return Ok(<_>::default()),
-            Some(elem) => {
-                return Err(DecodeError::from(XerDecodeErrorKind::XmlTypeMismatch {
-                    needed: $expected,
-                    found: alloc::format!("{elem:?}"),
-                }))
+        match $this.peek() {
+            Some(XmlEvent::Characters(s)) => {
+                let value = $parser(s);
+                let _ = $this.next_element();
+                value
             }
-            _ => return Err(DecodeError::from(XerDecodeErrorKind::EndOfXmlInput {})),
-        };
-        let _ = $this.next_element();
-        value
+            Some(XmlEvent::EndElement { .. }) => Ok(<_>::default()),
+            Some(elem) => Err(DecodeError::from(XerDecodeErrorKind::XmlTypeMismatch {
+                needed: $expected,
+                found: alloc::format!("{elem:?}"),
+            })),
+            _ => Err(DecodeError::from(XerDecodeErrorKind::EndOfXmlInput {})),
+        }
     }};
 }
 
@@ -151,6 +156,7 @@ impl<I: IntoIterator<Item = XmlEvent>> From<I> for XerElement {
 pub struct Decoder {
     stack: alloc::vec::Vec<XerElement>,
     in_list: bool,
+    enforce_constraints: bool,
 }
 
 impl Decoder {
@@ -170,6 +176,26 @@ impl Decoder {
         elements.try_into()
     }
 
+    /// Sets whether the value, size and permitted alphabet constraints of
+    /// values are checked when decoding, failing on the first violation.
+    #[must_use]
+    pub fn enforce_constraints(mut self, enforce: bool) -> Self {
+        self.enforce_constraints = enforce;
+        self
+    }
+
+    /// Checks a constraint of the decoded value, if constraints are enforced.
+    fn enforce(
+        &self,
+        check: impl FnOnce() -> Result<(), ConstraintViolationKind>,
+    ) -> Result<(), DecodeError> {
+        if self.enforce_constraints {
+            check().map_err(|kind| DecodeError::constraint_violation(kind, crate::Codec::Xer))
+        } else {
+            Ok(())
+        }
+    }
+
     fn next_element(&mut self) -> Option<XmlEvent> {
         if let Some(mut elem) = self.stack.pop() {
             let event = elem.next();
@@ -191,6 +217,17 @@ impl Decoder {
         self.stack.last().and_then(XerElement::peek)
     }
 
+    /// Returns the characters of an element, which are empty if the element
+    /// is immediately closed.
+    fn characters_or_empty(&mut self) -> Option<XmlEvent> {
+        match self.peek() {
+            Some(XmlEvent::EndElement { .. }) => {
+                Some(XmlEvent::Characters(alloc::string::String::new()))
+            }
+            _ => self.next_element(),
+        }
+    }
+
     fn sort_by_field_tag_order(
         &mut self,
         field_indices: &[(usize, Field)],
@@ -246,13 +283,26 @@ impl Decoder {
         Decoder {
             stack: elems.into_iter().map(|i| XerElement::from(i)).collect(),
             in_list: false,
+            enforce_constraints: false,
         }
     }
 }
 
 impl TryFrom<alloc::collections::VecDeque<XmlEvent>> for Decoder {
     type Error = DecodeError;
-    fn try_from(value: alloc::collections::VecDeque<XmlEvent>) -> Result<Self, Self::Error> {
+    fn try_from(mut value: alloc::collections::VecDeque<XmlEvent>) -> Result<Self, Self::Error> {
+        // Whitespace which is the only content of an element is a value, such
+        // as a string of spaces, rather than formatting.
+        for i in 1..value.len().saturating_sub(1) {
+            if let (
+                XmlEvent::StartElement { .. },
+                XmlEvent::Whitespace(whitespace),
+                XmlEvent::EndElement { .. },
+            ) = (&value[i - 1], &value[i], &value[i + 1])
+            {
+                value[i] = XmlEvent::Characters(whitespace.clone());
+            }
+        }
         let (mut stack, mut events, mut tag) =
             (alloc::vec![], alloc::collections::VecDeque::new(), None);
         let mut level_of_nested_items = 0;
@@ -317,6 +367,7 @@ impl TryFrom<alloc::collections::VecDeque<XmlEvent>> for Decoder {
         Ok(Self {
             stack,
             in_list: false,
+            enforce_constraints: false,
         })
     }
 }
@@ -406,20 +457,20 @@ impl crate::Decoder for Decoder {
                 }
             }
         }
-        Ok(Any {
-            contents: xml_writer.into_inner().into_bytes(),
-        })
+        Ok(Any::new(xml_writer.into_inner().into_bytes()))
     }
 
     fn decode_bit_string(
         &mut self,
         __tag: Tag,
-        __constraints: Constraints,
+        constraints: Constraints,
     ) -> Result<crate::types::BitString, Self::Error> {
         tag!(StartElement, self)?;
-        let value = value_or_empty!(self, parse_bitstring_value, "`1` or `0`");
+        let value = value_or_empty!(self, parse_bitstring_value, "`1` or `0`")?;
         tag!(EndElement, self)?;
-        value
+        self.enforce(|| validate::check_size(value.len(), &constraints))?;
+
+        Ok(value)
     }
 
     fn decode_bool(&mut self, __tag: Tag) -> Result<bool, Self::Error> {
@@ -509,7 +560,7 @@ impl crate::Decoder for Decoder {
     fn decode_integer<I: crate::types::IntegerType>(
         &mut self,
         _t: Tag,
-        _c: Constraints,
+        constraints: Constraints,
     ) -> Result<I, Self::Error> {
         tag!(StartElement, self)?;
         let value = match self.next_element() {
@@ -529,9 +580,11 @@ impl crate::Decoder for Decoder {
                 found: alloc::format!("{elem:?}"),
             })),
             None => Err(error!(EndOfXmlInput)),
-        };
+        }?;
         tag!(EndElement, self)?;
-        value
+        self.enforce(|| validate::check_value(&value, &constraints))?;
+
+        Ok(value)
     }
 
     fn decode_null(&mut self, _tag: Tag) -> Result<(), Self::Error> {
@@ -557,6 +610,66 @@ impl crate::Decoder for Decoder {
         value
     }
 
+    fn decode_encoded_oid(
+        &mut self,
+        _tag: Tag,
+    ) -> Result<crate::types::EncodedObjectIdentifier, Self::Error> {
+        tag!(StartElement, self)?;
+        let value = match self.next_element() {
+            Some(XmlEvent::Characters(value)) => value.parse().map_err(|_| {
+                DecodeError::from(XerDecodeErrorKind::InvalidInput {
+                    details: "Invalid Object Identifier value.",
+                })
+            }),
+            Some(elem) => Err(DecodeError::from(XerDecodeErrorKind::XmlTypeMismatch {
+                needed: "'.'-separated numeric object identifier arcs",
+                found: alloc::format!("{elem:?}"),
+            })),
+            None => Err(error!(EndOfXmlInput)),
+        };
+        tag!(EndElement, self)?;
+        value
+    }
+
+    fn decode_relative_oid(
+        &mut self,
+        _tag: Tag,
+    ) -> Result<crate::types::RelativeObjectIdentifier, Self::Error> {
+        tag!(StartElement, self)?;
+        let value = match self.next_element() {
+            Some(XmlEvent::Characters(value)) => parse_relative_oid(&value),
+            Some(elem) => Err(DecodeError::from(XerDecodeErrorKind::XmlTypeMismatch {
+                needed: "'.'-separated numeric relative object identifier arcs",
+                found: alloc::format!("{elem:?}"),
+            })),
+            None => Err(error!(EndOfXmlInput)),
+        };
+        tag!(EndElement, self)?;
+        value
+    }
+
+    fn decode_oid_iri(&mut self, tag: Tag) -> Result<OidIri, Self::Error> {
+        let string = self.decode_utf8_string(tag, Constraints::default())?;
+        string.parse::<OidIri>().map_err(|error| {
+            DecodeError::string_conversion_failed(Tag::OID_IRI, error.to_string(), self.codec())
+        })
+    }
+
+    fn decode_relative_oid_iri(&mut self, tag: Tag) -> Result<RelativeOidIri, Self::Error> {
+        let string = self.decode_utf8_string(tag, Constraints::default())?;
+        string.parse::<RelativeOidIri>().map_err(|error| {
+            DecodeError::string_conversion_failed(
+                Tag::RELATIVE_OID_IRI,
+                error.to_string(),
+                self.codec(),
+            )
+        })
+    }
+
+    fn decode_external(&mut self, tag: Tag) -> Result<External, Self::Error> {
+        External::decode_associated(self, tag)
+    }
+
     fn decode_sequence<const RC: usize, const EC: usize, D, DF, F>(
         &mut self,
         _: Tag,
@@ -580,7 +693,8 @@ impl crate::Decoder for Decoder {
             .pop()
             .ok_or_else(|| error!(EndOfXmlInput))?
             .events;
-        let mut sequence_decoder = Decoder::try_from(events)?;
+        let mut sequence_decoder =
+            Decoder::try_from(events)?.enforce_constraints(self.enforce_constraints);
         sequence_decoder.sort_by_field_name_order(field_names)?;
         (decode_fn)(&mut sequence_decoder)
     }
@@ -588,157 +702,183 @@ impl crate::Decoder for Decoder {
     fn decode_sequence_of<D: Decode>(
         &mut self,
         _tag: Tag,
-        _constraints: Constraints,
+        constraints: Constraints,
     ) -> Result<alloc::vec::Vec<D>, Self::Error> {
-        decode_sequence_or_set_items(self)
+        let items = decode_sequence_or_set_items(self)?;
+        self.enforce(|| validate::check_size(items.len(), &constraints))?;
+
+        Ok(items)
     }
 
     fn decode_set_of<D: crate::Decode + Eq + core::hash::Hash>(
         &mut self,
         _t: Tag,
-        _c: Constraints,
+        constraints: Constraints,
     ) -> Result<SetOf<D>, Self::Error> {
         let items = decode_sequence_or_set_items::<D>(self)?;
+        self.enforce(|| validate::check_size(items.len(), &constraints))?;
         Ok(SetOf::from_vec(items))
     }
 
     fn decode_octet_string<'b, T: From<alloc::vec::Vec<u8>> + From<&'b [u8]>>(
         &'b mut self,
         _: Tag,
-        _c: Constraints,
+        constraints: Constraints,
     ) -> Result<T, Self::Error> {
         tag!(StartElement, self)?;
-        let value = match self.peek() {
-            Some(XmlEvent::Characters(s)) => parse_octetstring_value(s),
-            Some(XmlEvent::EndElement { .. }) => return Ok(<T as From<&'b [u8]>>::from(&[])),
-            Some(elem) => {
-                return Err(DecodeError::from(XerDecodeErrorKind::XmlTypeMismatch {
-                    needed: "hexadecimal characters",
-                    found: alloc::format!("{elem:?}"),
-                }))
-            }
-            _ => return Err(DecodeError::from(XerDecodeErrorKind::EndOfXmlInput {})),
-        };
-        let _ = self.next_element();
+        let value = value_or_empty!(self, parse_octetstring_value, "hexadecimal characters")?;
         tag!(EndElement, self)?;
-        value.map(T::from)
+        self.enforce(|| validate::check_size(value.len(), &constraints))?;
+
+        Ok(T::from(value))
     }
 
     fn decode_utf8_string(
         &mut self,
         _tag: Tag,
-        _constraints: Constraints,
+        constraints: Constraints,
     ) -> Result<crate::types::Utf8String, Self::Error> {
         tag!(StartElement, self)?;
-        let value = match self.next_element() {
+        let value = match self.characters_or_empty() {
             Some(XmlEvent::Characters(value)) => Ok(value),
             Some(elem) => Err(DecodeError::from(XerDecodeErrorKind::XmlTypeMismatch {
                 needed: "UTF8 string value",
                 found: alloc::format!("{elem:?}"),
             })),
             None => Err(error!(EndOfXmlInput)),
-        };
+        }?;
         tag!(EndElement, self)?;
-        value
+        self.enforce(|| validate::check_characters(|| value.chars().map(u32::from), &constraints))?;
+
+        Ok(value)
     }
 
     fn decode_visible_string(
         &mut self,
         _tag: Tag,
-        _constraints: Constraints,
+        constraints: Constraints,
     ) -> Result<crate::types::VisibleString, Self::Error> {
-        decode_string!(
+        let string = decode_string!(
             self,
             crate::types::VisibleString::try_from,
             Tag::VISIBLE_STRING,
             "VisibleString value"
-        )
+        )?;
+        self.enforce(|| validate::check_characters(|| string.chars(), &constraints))?;
+
+        Ok(string)
     }
 
     fn decode_general_string(
         &mut self,
         _tag: Tag,
-        _constraints: Constraints,
+        constraints: Constraints,
     ) -> Result<crate::types::GeneralString, Self::Error> {
-        decode_string!(
+        let string = decode_string!(
             self,
             crate::types::GeneralString::try_from,
             Tag::GENERAL_STRING,
             "GeneralString value"
-        )
+        )?;
+        self.enforce(|| validate::check_characters(|| string.chars(), &constraints))?;
+
+        Ok(string)
     }
 
     fn decode_ia5_string(
         &mut self,
         _tag: Tag,
-        _constraints: Constraints,
+        constraints: Constraints,
     ) -> Result<crate::types::Ia5String, Self::Error> {
-        decode_string!(
+        let string = decode_string!(
             self,
             crate::types::Ia5String::try_from,
             Tag::IA5_STRING,
             "IA5String value"
-        )
+        )?;
+        self.enforce(|| validate::check_characters(|| string.chars(), &constraints))?;
+
+        Ok(string)
     }
 
     fn decode_graphic_string(
         &mut self,
         _tag: Tag,
-        _constraints: Constraints,
+        constraints: Constraints,
     ) -> Result<crate::types::GraphicString, Self::Error> {
-        decode_string!(
+        let string = decode_string!(
             self,
             crate::types::GraphicString::try_from,
             Tag::GRAPHIC_STRING,
             "GraphicString value"
-        )
+        )?;
+        self.enforce(|| validate::check_characters(|| string.chars(), &constraints))?;
+
+        Ok(string)
     }
 
     fn decode_printable_string(
         &mut self,
         _tag: Tag,
-        _constraints: Constraints,
+        constraints: Constraints,
     ) -> Result<crate::types::PrintableString, Self::Error> {
-        decode_string!(
+        let string = decode_string!(
             self,
             crate::types::PrintableString::try_from,
             Tag::PRINTABLE_STRING,
             "PrintableString value"
-        )
+        )?;
+        self.enforce(|| validate::check_characters(|| string.chars(), &constraints))?;
+
+        Ok(string)
     }
 
     fn decode_numeric_string(
         &mut self,
         _tag: Tag,
-        _constraints: Constraints,
+        constraints: Constraints,
     ) -> Result<crate::types::NumericString, Self::Error> {
-        decode_string!(
+        let string = decode_string!(
             self,
             crate::types::NumericString::try_from,
             Tag::NUMERIC_STRING,
             "NumericString value"
-        )
+        )?;
+        self.enforce(|| validate::check_characters(|| string.chars(), &constraints))?;
+
+        Ok(string)
     }
 
     fn decode_teletex_string(
         &mut self,
         _tag: Tag,
-        _constraints: Constraints,
+        constraints: Constraints,
     ) -> Result<crate::types::TeletexString, Self::Error> {
-        todo!()
+        let string = decode_string!(
+            self,
+            crate::types::TeletexString::try_from,
+            Tag::TELETEX_STRING,
+            "TeletexString value"
+        )?;
+        self.enforce(|| validate::check_characters(|| string.chars(), &constraints))?;
+
+        Ok(string)
     }
 
     fn decode_bmp_string(
         &mut self,
         _tag: Tag,
-        _constraints: Constraints,
+        constraints: Constraints,
     ) -> Result<crate::types::BmpString, Self::Error> {
-        decode_string!(
+        let string = decode_string!(
             self,
             crate::types::BmpString::try_from,
             Tag::BMP_STRING,
             "BMP String value"
-        )
+        )?;
+        self.enforce(|| validate::check_characters(|| string.chars(), &constraints))?;
+
+        Ok(string)
     }
 
     fn decode_explicit_prefix<D: Decode>(&mut self, _tag: Tag) -> Result<D, Self::Error> {
@@ -784,9 +924,18 @@ impl crate::Decoder for Decoder {
         let mut fields = alloc::vec![];
         field_indices
             .sort_by(|(_, a), (_, b)| a.tag_tree.smallest_tag().cmp(&b.tag_tree.smallest_tag()));
-        let mut sequence_decoder = Decoder::try_from(events)?;
+        let mut sequence_decoder =
+            Decoder::try_from(events)?.enforce_constraints(self.enforce_constraints);
         sequence_decoder.sort_by_field_tag_order(&field_indices)?;
         for (index, field) in field_indices {
+            // Absent fields are left out, the same as in the other codecs.
+            if matches!(
+                sequence_decoder.peek(),
+                Some(XmlEvent::Characters(c)) if c == OPTIONAL_ITEM_NOT_PRESENT
+            ) {
+                let _ = sequence_decoder.next_element();
+                continue;
+            }
             fields.push((decode_fn)(&mut sequence_decoder, index, field.tag)?);
         }
 
@@ -831,7 +980,8 @@ impl crate::Decoder for Decoder {
                     .pop()
                     .ok_or_else(|| error!(EndOfXmlInput))?
                     .events;
-                let mut variant_decoder = Decoder::try_from(events)?;
+                let mut variant_decoder =
+                    Decoder::try_from(events)?.enforce_constraints(self.enforce_constraints);
                 D::from_tag(&mut variant_decoder, tag)
             }
             elem => Err(DecodeError::from(XerDecodeErrorKind::XmlTypeMismatch {
@@ -949,6 +1099,30 @@ impl crate::Decoder for Decoder {
         .map(|dt| dt.date_naive())
     }
 
+    fn decode_time(&mut self, _tag: Tag) -> Result<IsoTime, Self::Error> {
+        decode_time!(self, |value: alloc::string::String| {
+            crate::ber::de::Decoder::parse_time_string(&value)
+        })
+    }
+
+    fn decode_time_of_day(&mut self, _tag: Tag) -> Result<TimeOfDay, Self::Error> {
+        decode_time!(self, |value: alloc::string::String| {
+            crate::ber::de::Decoder::parse_time_of_day_string(&value)
+        })
+    }
+
+    fn decode_date_time(&mut self, _tag: Tag) -> Result<DateTime, Self::Error> {
+        decode_time!(self, |value: alloc::string::String| {
+            crate::ber::de::Decoder::parse_date_time_string(&value)
+        })
+    }
+
+    fn decode_duration(&mut self, _tag: Tag) -> Result<IsoDuration, Self::Error> {
+        decode_time!(self, |value: alloc::string::String| {
+            crate::ber::de::Decoder::parse_duration_string(&value)
+        })
+    }
+
     fn decode_extension_addition_with_explicit_tag_and_constraints<D>(
         &mut self,
         _tag: Tag,
@@ -996,6 +1170,20 @@ fn parse_octetstring_value(val: &str) -> Result<alloc::vec::Vec<u8>, DecodeError
         .map_err(|e| XerDecodeErrorKind::InvalidXerOctetstring { parse_int_err: e }.into())
 }
 
+fn parse_relative_oid(val: &str) -> Result<RelativeObjectIdentifier, DecodeError> {
+    val.split('.')
+        .map(str::parse)
+        .collect::<Result<alloc::vec::Vec<u32>, _>>()
+        .ok()
+        .and_then(RelativeObjectIdentifier::new)
+        .ok_or_else(|| {
+            XerDecodeErrorKind::InvalidInput {
+                details: "Invalid Relative Object Identifier value.",
+            }
+            .into()
+        })
+}
+
 fn parse_object_identifier(val: &str) -> Result<ObjectIdentifier, DecodeError> {
     let arcs = val
         .split('.')
@@ -1065,7 +1253,8 @@ fn decode_sequence_or_set_items<D: Decode>(
             .events
             .try_into()?
     }
-    .into_list_decoder();
+    .into_list_decoder()
+    .enforce_constraints(decoder.enforce_constraints);
 
     let mut items = alloc::vec::Vec::new();
     let mut level_of_nesting = 0;
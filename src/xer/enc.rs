@@ -7,10 +7,11 @@ use crate::{
         vec::Vec,
     },
     types::{
-        fields::Fields, Any, BitStr, BmpString, Constraints, Date, Enumerated, GeneralString,
-        GeneralizedTime, GraphicString, Ia5String, Identifier, IntegerType, NumericString,
-        OctetString, Oid, PrintableString, RealType, SetOf, Tag, TeletexString, UtcTime,
-        VisibleString,
+        fields::Fields, strings::StaticPermittedAlphabet, validate, Any, BitStr, BmpString,
+        Constraints, Date, DateTime, EncodedOid, Enumerated, External, GeneralString,
+        GeneralizedTime, GraphicString, Ia5String, Identifier, IntegerType, IsoDuration, IsoTime,
+        NumericString, OctetString, Oid, OidIri, PrintableString, RealType, RelativeOid,
+        RelativeOidIri, SetOf, Tag, TeletexString, TimeOfDay, UtcTime, VisibleString,
     },
     AsnType,
 };
@@ -21,7 +22,7 @@ use xml_no_std::{
     ParserConfig,
 };
 
-use crate::error::{EncodeError, XerEncodeErrorKind};
+use crate::error::{ConstraintViolationKind, EncodeError, XerEncodeErrorKind};
 
 use super::{BOOLEAN_FALSE_TAG, BOOLEAN_TRUE_TAG, MINUS_INFINITY_TAG, NAN_TAG, PLUS_INFINITY_TAG};
 
@@ -56,6 +57,7 @@ pub struct Encoder {
     start_index_of_last_tag: usize,
     entering_choice_value: bool,
     entering_list_item_type: bool,
+    enforce_constraints: bool,
 }
 
 impl Default for Encoder {
@@ -77,6 +79,27 @@ impl Encoder {
             start_index_of_last_tag: 0,
             entering_choice_value: false,
             entering_list_item_type: false,
+            enforce_constraints: false,
+        }
+    }
+
+    /// Sets whether the value, size and permitted alphabet constraints of
+    /// values are checked when encoding, failing on the first violation.
+    #[must_use]
+    pub fn enforce_constraints(mut self, enforce: bool) -> Self {
+        self.enforce_constraints = enforce;
+        self
+    }
+
+    /// Checks a constraint of the encoded value, if constraints are enforced.
+    fn enforce(
+        &self,
+        check: impl FnOnce() -> Result<(), ConstraintViolationKind>,
+    ) -> Result<(), EncodeError> {
+        if self.enforce_constraints {
+            check().map_err(|kind| EncodeError::constraint_violation(kind, crate::Codec::Xer))
+        } else {
+            Ok(())
         }
     }
 
@@ -183,10 +206,11 @@ impl crate::Encoder<'_> for Encoder {
     fn encode_bit_string(
         &mut self,
         _tag: Tag,
-        _constraints: Constraints,
+        constraints: Constraints,
         value: &BitStr,
         identifier: Identifier,
     ) -> Result<Self::Ok, Self::Error> {
+        self.enforce(|| validate::check_size(value.len(), &constraints))?;
         wrap_in_tags!(
             self,
             Cow::Borrowed(identifier.or(BitStr::IDENTIFIER).unwrap()),
@@ -232,13 +256,79 @@ impl crate::Encoder<'_> for Encoder {
         )
     }
 
+    fn encode_encoded_oid(
+        &mut self,
+        _tag: Tag,
+        value: &EncodedOid,
+        identifier: Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        wrap_in_tags!(
+            self,
+            Cow::Borrowed(identifier.or(EncodedOid::IDENTIFIER).unwrap()),
+            write_encoded_oid,
+            value
+        )
+    }
+
+    fn encode_relative_oid(
+        &mut self,
+        _tag: Tag,
+        value: &[u32],
+        identifier: Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        wrap_in_tags!(
+            self,
+            Cow::Borrowed(identifier.or(RelativeOid::IDENTIFIER).unwrap()),
+            write_object_identifier,
+            value
+        )
+    }
+
+    fn encode_oid_iri(
+        &mut self,
+        _tag: Tag,
+        value: &OidIri,
+        identifier: Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        wrap_in_tags!(
+            self,
+            Cow::Borrowed(identifier.or(OidIri::IDENTIFIER).unwrap()),
+            write_string_type,
+            value.as_str()
+        )
+    }
+
+    fn encode_relative_oid_iri(
+        &mut self,
+        _tag: Tag,
+        value: &RelativeOidIri,
+        identifier: Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        wrap_in_tags!(
+            self,
+            Cow::Borrowed(identifier.or(RelativeOidIri::IDENTIFIER).unwrap()),
+            write_string_type,
+            value.as_str()
+        )
+    }
+
+    fn encode_external(
+        &mut self,
+        tag: Tag,
+        value: &External,
+        identifier: Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        value.encode_associated(self, tag, identifier)
+    }
+
     fn encode_integer<I: IntegerType>(
         &mut self,
         _tag: Tag,
-        _constraints: Constraints,
+        constraints: Constraints,
         value: &I,
         identifier: Identifier,
     ) -> Result<Self::Ok, Self::Error> {
+        self.enforce(|| validate::check_value(value, &constraints))?;
         if let Some(as_bigint) = value.to_bigint() {
             wrap_in_tags!(
                 self,
@@ -262,10 +352,11 @@ impl crate::Encoder<'_> for Encoder {
     fn encode_octet_string(
         &mut self,
         _tag: Tag,
-        _constraints: Constraints,
+        constraints: Constraints,
         value: &[u8],
         identifier: Identifier,
     ) -> Result<Self::Ok, Self::Error> {
+        self.enforce(|| validate::check_size(value.len(), &constraints))?;
         wrap_in_tags!(
             self,
             Cow::Borrowed(identifier.or(OctetString::IDENTIFIER).unwrap()),
@@ -277,10 +368,11 @@ impl crate::Encoder<'_> for Encoder {
     fn encode_general_string(
         &mut self,
         _tag: Tag,
-        _constraints: Constraints,
+        constraints: Constraints,
         value: &GeneralString,
         identifier: Identifier,
     ) -> Result<Self::Ok, Self::Error> {
+        self.enforce(|| validate::check_characters(|| value.chars(), &constraints))?;
         wrap_in_tags!(
             self,
             Cow::Borrowed(identifier.or(GeneralString::IDENTIFIER).unwrap()),
@@ -296,10 +388,11 @@ impl crate::Encoder<'_> for Encoder {
     fn encode_utf8_string(
         &mut self,
         _tag: Tag,
-        _constraints: Constraints,
+        constraints: Constraints,
         value: &str,
         identifier: Identifier,
     ) -> Result<Self::Ok, Self::Error> {
+        self.enforce(|| validate::check_characters(|| value.chars().map(u32::from), &constraints))?;
         wrap_in_tags!(
             self,
             Cow::Borrowed(identifier.or(str::IDENTIFIER).unwrap()),
@@ -311,10 +404,11 @@ impl crate::Encoder<'_> for Encoder {
     fn encode_visible_string(
         &mut self,
         _tag: Tag,
-        _constraints: Constraints,
+        constraints: Constraints,
         value: &VisibleString,
         identifier: Identifier,
     ) -> Result<Self::Ok, Self::Error> {
+        self.enforce(|| validate::check_characters(|| value.chars(), &constraints))?;
         wrap_in_tags!(
             self,
             Cow::Borrowed(identifier.or(VisibleString::IDENTIFIER).unwrap()),
@@ -326,10 +420,11 @@ impl crate::Encoder<'_> for Encoder {
     fn encode_ia5_string(
         &mut self,
         _tag: Tag,
-        _constraints: Constraints,
+        constraints: Constraints,
         value: &Ia5String,
         identifier: Identifier,
     ) -> Result<Self::Ok, Self::Error> {
+        self.enforce(|| validate::check_characters(|| value.chars(), &constraints))?;
         wrap_in_tags!(
             self,
             Cow::Borrowed(identifier.or(Ia5String::IDENTIFIER).unwrap()),
@@ -341,10 +436,11 @@ impl crate::Encoder<'_> for Encoder {
     fn encode_printable_string(
         &mut self,
         _tag: Tag,
-        _constraints: Constraints,
+        constraints: Constraints,
         value: &PrintableString,
         identifier: Identifier,
     ) -> Result<Self::Ok, Self::Error> {
+        self.enforce(|| validate::check_characters(|| value.chars(), &constraints))?;
         wrap_in_tags!(
             self,
             Cow::Borrowed(identifier.or(PrintableString::IDENTIFIER).unwrap()),
@@ -360,10 +456,11 @@ impl crate::Encoder<'_> for Encoder {
     fn encode_numeric_string(
         &mut self,
         _tag: Tag,
-        _constraints: Constraints,
+        constraints: Constraints,
         value: &NumericString,
         identifier: Identifier,
     ) -> Result<Self::Ok, Self::Error> {
+        self.enforce(|| validate::check_characters(|| value.chars(), &constraints))?;
         wrap_in_tags!(
             self,
             Cow::Borrowed(identifier.or(NumericString::IDENTIFIER).unwrap()),
@@ -379,20 +476,32 @@ impl crate::Encoder<'_> for Encoder {
     fn encode_teletex_string(
         &mut self,
         _tag: Tag,
-        _constraints: Constraints,
-        _value: &TeletexString,
-        _identifier: Identifier,
+        constraints: Constraints,
+        value: &TeletexString,
+        identifier: Identifier,
     ) -> Result<Self::Ok, Self::Error> {
-        todo!()
+        self.enforce(|| validate::check_characters(|| value.chars(), &constraints))?;
+        wrap_in_tags!(
+            self,
+            Cow::Borrowed(identifier.or(TeletexString::IDENTIFIER).unwrap()),
+            write_string_type,
+            &value
+                .to_unicode()
+                .map_err(|e| EncodeError::alphabet_constraint_not_satisfied(
+                    e,
+                    crate::Codec::Xer
+                ))?
+        )
     }
 
     fn encode_bmp_string(
         &mut self,
         _tag: Tag,
-        _constraints: Constraints,
+        constraints: Constraints,
         value: &BmpString,
         identifier: Identifier,
     ) -> Result<Self::Ok, Self::Error> {
+        self.enforce(|| validate::check_characters(|| value.chars(), &constraints))?;
         wrap_in_tags!(
             self,
             Cow::Borrowed(identifier.or(BmpString::IDENTIFIER).unwrap()),
@@ -408,10 +517,11 @@ impl crate::Encoder<'_> for Encoder {
     fn encode_graphic_string(
         &mut self,
         _tag: Tag,
-        _constraints: Constraints,
+        constraints: Constraints,
         value: &GraphicString,
         identifier: Identifier,
     ) -> Result<Self::Ok, Self::Error> {
+        self.enforce(|| validate::check_characters(|| value.chars(), &constraints))?;
         wrap_in_tags!(
             self,
             Cow::Borrowed(identifier.or(GraphicString::IDENTIFIER).unwrap()),
@@ -477,7 +587,7 @@ impl crate::Encoder<'_> for Encoder {
         if self.entering_list_item_type {
             // List items that are `CHOICE` delegate types are encoded without their outer tags
             // We use a new encoder to write the inner choice value of the delegate: <ChoiceType><option /></ChoiceType>
-            let mut inner_encoder = Self::new();
+            let mut inner_encoder = Self::new().enforce_constraints(self.enforce_constraints);
             // Then we write an empty string to prompt the XML writer to close any uncloses start tags.
             self.write_empty()?;
             value.encode(&mut inner_encoder)?;
@@ -493,7 +603,7 @@ impl crate::Encoder<'_> for Encoder {
             // Then we write an empty string to prompt the XML writer to close the start tag: <Delegate>
             self.write_empty()?;
             // We use a new encoder to write the inner choice value: <ChoiceType><option /></ChoiceType>
-            let mut inner_encoder = Self::new();
+            let mut inner_encoder = Self::new().enforce_constraints(self.enforce_constraints);
             value.encode(&mut inner_encoder)?;
             // We then remove the outer tag pair: <option />
             inner_encoder.erase_outer_tags();
@@ -544,9 +654,10 @@ impl crate::Encoder<'_> for Encoder {
         &mut self,
         _tag: Tag,
         value: &[E],
-        _constraints: Constraints,
+        constraints: Constraints,
         identifier: Identifier,
     ) -> Result<Self::Ok, Self::Error> {
+        self.enforce(|| validate::check_size(value.len(), &constraints))?;
         let xml_tag = match self.entering_list_item_type {
             true => Cow::Borrowed(identifier.0.ok_or(XerEncodeErrorKind::MissingIdentifier)?),
             false => self.field_tag_stack.pop().unwrap_or(Cow::Borrowed(
@@ -601,9 +712,10 @@ impl crate::Encoder<'_> for Encoder {
         &mut self,
         _tag: Tag,
         value: &SetOf<E>,
-        _constraints: Constraints,
+        constraints: Constraints,
         identifier: Identifier,
     ) -> Result<Self::Ok, Self::Error> {
+        self.enforce(|| validate::check_size(value.len(), &constraints))?;
         let xml_tag = match self.entering_list_item_type {
             true => Cow::Borrowed(identifier.0.ok_or(XerEncodeErrorKind::MissingIdentifier)?),
             false => self.field_tag_stack.pop().unwrap_or(Cow::Borrowed(
@@ -734,6 +846,62 @@ impl crate::Encoder<'_> for Encoder {
             value
         )
     }
+
+    fn encode_time(
+        &mut self,
+        _tag: Tag,
+        value: &IsoTime,
+        identifier: Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        wrap_in_tags!(
+            self,
+            Cow::Borrowed(identifier.or(IsoTime::IDENTIFIER).unwrap()),
+            write_time,
+            value
+        )
+    }
+
+    fn encode_time_of_day(
+        &mut self,
+        _tag: Tag,
+        value: &TimeOfDay,
+        identifier: Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        wrap_in_tags!(
+            self,
+            Cow::Borrowed(identifier.or(TimeOfDay::IDENTIFIER).unwrap()),
+            write_time_of_day,
+            value
+        )
+    }
+
+    fn encode_date_time(
+        &mut self,
+        _tag: Tag,
+        value: &DateTime,
+        identifier: Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        wrap_in_tags!(
+            self,
+            Cow::Borrowed(identifier.or(DateTime::IDENTIFIER).unwrap()),
+            write_date_time,
+            value
+        )
+    }
+
+    fn encode_duration(
+        &mut self,
+        _tag: Tag,
+        value: &IsoDuration,
+        identifier: Identifier,
+    ) -> Result<Self::Ok, Self::Error> {
+        wrap_in_tags!(
+            self,
+            Cow::Borrowed(identifier.or(IsoDuration::IDENTIFIER).unwrap()),
+            write_duration,
+            value
+        )
+    }
 }
 
 impl Encoder {
@@ -751,6 +919,26 @@ impl Encoder {
         self.write(XmlEvent::Characters(&value.format("%Y%m%d").to_string()))
     }
 
+    fn write_time(&mut self, value: &IsoTime) -> Result<(), EncodeError> {
+        self.write(XmlEvent::Characters(value.as_str()))
+    }
+
+    fn write_time_of_day(&mut self, value: &TimeOfDay) -> Result<(), EncodeError> {
+        crate::types::time::whole_seconds(value, crate::Codec::Xer)?;
+        self.write(XmlEvent::Characters(&value.format("%H%M%S").to_string()))
+    }
+
+    fn write_date_time(&mut self, value: &DateTime) -> Result<(), EncodeError> {
+        crate::types::time::whole_seconds(&value.time(), crate::Codec::Xer)?;
+        self.write(XmlEvent::Characters(
+            &value.format("%Y%m%dT%H%M%S").to_string(),
+        ))
+    }
+
+    fn write_duration(&mut self, value: &IsoDuration) -> Result<(), EncodeError> {
+        self.write(XmlEvent::Characters(&value.to_string()))
+    }
+
     fn write_bitstring(&mut self, value: &BitStr) -> Result<(), EncodeError> {
         if value.is_empty() {
             Ok(())
@@ -799,6 +987,10 @@ impl Encoder {
         ))
     }
 
+    fn write_encoded_oid(&mut self, value: &EncodedOid) -> Result<(), EncodeError> {
+        self.write(XmlEvent::Characters(&value.to_string()))
+    }
+
     #[allow(clippy::unnecessary_wraps)]
     fn write_null(&mut self) -> Result<(), EncodeError> {
         Ok(())
@@ -849,15 +1041,17 @@ impl Encoder {
     }
 
     fn write_any(&mut self, value: &Any) -> Result<(), EncodeError> {
+        if value.contents.trim_ascii_start().starts_with(b"<?xml") {
+            return Err(XerEncodeErrorKind::XmlEncodingError {
+                upstream: "Any-type values must not contain XML prolog!".to_string(),
+            }
+            .into());
+        }
         let mut reader = ParserConfig::default().create_reader(value.contents.iter());
         while let Ok(evt) = reader.next() {
             match evt {
-                xml_no_std::reader::XmlEvent::StartDocument { .. } => {
-                    return Err(XerEncodeErrorKind::XmlEncodingError {
-                        upstream: "Any-type values must not contain XML prolog!".to_string(),
-                    }
-                    .into())
-                }
+                // Emitted by the parser even if the value has no prolog.
+                xml_no_std::reader::XmlEvent::StartDocument { .. } => {}
                 xml_no_std::reader::XmlEvent::EndDocument => break,
                 xml_no_std::reader::XmlEvent::ProcessingInstruction { name, data } => {
                     self.write(XmlEvent::ProcessingInstruction {
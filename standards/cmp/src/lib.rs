@@ -0,0 +1,600 @@
+#![doc = include_str!("../README.md")]
+#![no_std]
+
+extern crate alloc;
+
+use alloc::boxed::Box;
+use rasn::prelude::*;
+
+#[doc(inline)]
+pub use rasn_crmf::{
+    CertId, CertReqMessages, CertTemplate, EncryptedValue, PbmParameter, PkiPublicationInfo,
+    PASSWORD_BASED_MAC,
+};
+#[doc(inline)]
+pub use rasn_pkcs10::CertificationRequest;
+#[doc(inline)]
+pub use rasn_pkix::{
+    AlgorithmIdentifier, Certificate, CertificateList, Extensions, GeneralName, KeyIdentifier,
+};
+
+/// A sequence of [`PkiMessage`]s.
+pub type PkiMessages = SequenceOf<PkiMessage>;
+/// Human readable text, with an optional language tag in each string.
+pub type PkiFreeText = SequenceOf<Utf8String>;
+/// The protection of a [`PkiMessage`], which is the signature or MAC of the
+/// DER encoded [`ProtectedPart`].
+pub type PkiProtection = BitString;
+/// Messages nested in a [`PkiBody::Nested`] body, such as when forwarded by a
+/// registration authority.
+pub type NestedMessageContent = PkiMessages;
+/// The reasons a request failed, with a bit set at each of the positions
+/// defined in [`failure_info`].
+pub type PkiFailureInfo = BitString;
+/// A certificate delivered out-of-band.
+pub type OobCert = CmpCertificate;
+/// The challenges proving possession of decryption keys.
+pub type PopoDecKeyChallContent = SequenceOf<Challenge>;
+/// The decrypted integers of each of the [`Challenge`]s.
+pub type PopoDecKeyRespContent = SequenceOf<Integer>;
+/// The certificates to be revoked.
+pub type RevReqContent = SequenceOf<RevDetails>;
+/// An announced certificate.
+pub type CertAnnContent = CmpCertificate;
+/// The announced CRLs.
+pub type CrlAnnContent = SequenceOf<CertificateList>;
+/// The confirmation of a message, which has no contents.
+pub type PkiConfirmContent = ();
+/// The information requested in a general message.
+pub type GenMsgContent = SequenceOf<InfoTypeAndValue>;
+/// The information provided in a general response.
+pub type GenRepContent = SequenceOf<InfoTypeAndValue>;
+/// The confirmation of each of the certificates issued in a response.
+pub type CertConfirmContent = SequenceOf<CertStatus>;
+/// The requests being polled for.
+pub type PollReqContent = SequenceOf<PollReq>;
+/// The responses to a [`PollReqContent`].
+pub type PollRepContent = SequenceOf<PollRep>;
+
+/// The password-based MAC algorithm using a Diffie-Hellman shared secret,
+/// with [`DhbmParameter`] parameters.
+pub const DH_BASED_MAC: &Oid = Oid::ISO_MEMBER_BODY_US_ENTRUST_DH_BASED_MAC;
+
+/// The information types of [`InfoTypeAndValue`].
+pub mod info_type {
+    use rasn::types::Oid;
+
+    /// The certificate the authority wants to be used to encrypt messages.
+    pub const CA_PROT_ENC_CERT: &Oid =
+        Oid::ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_IT_CA_PROT_ENC_CERT;
+    /// The signing algorithms the authority supports.
+    pub const SIGN_KEY_PAIR_TYPES: &Oid =
+        Oid::ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_IT_SIGN_KEY_PAIR_TYPES;
+    /// The encryption and key agreement algorithms the authority supports.
+    pub const ENC_KEY_PAIR_TYPES: &Oid =
+        Oid::ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_IT_ENC_KEY_PAIR_TYPES;
+    /// The symmetric algorithm the authority prefers.
+    pub const PREFERRED_SYMM_ALG: &Oid =
+        Oid::ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_IT_PREFERRED_SYMM_ALG;
+    /// The update of the authority's key.
+    pub const CA_KEY_UPDATE_INFO: &Oid =
+        Oid::ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_IT_CA_KEY_UPDATE_INFO;
+    /// The latest CRL of the authority.
+    pub const CURRENT_CRL: &Oid =
+        Oid::ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_IT_CURRENT_CRL;
+    /// The object identifiers the receiver doesn't support.
+    pub const UNSUPPORTED_OIDS: &Oid =
+        Oid::ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_IT_UNSUPPORTED_OIDS;
+    /// A request for the parameters of a key pair algorithm.
+    pub const KEY_PAIR_PARAM_REQ: &Oid =
+        Oid::ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_IT_KEY_PAIR_PARAM_REQ;
+    /// The parameters of a key pair algorithm.
+    pub const KEY_PAIR_PARAM_REP: &Oid =
+        Oid::ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_IT_KEY_PAIR_PARAM_REP;
+    /// The passphrase authorising a later revocation request.
+    pub const REV_PASSPHRASE: &Oid =
+        Oid::ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_IT_REV_PASSPHRASE;
+    /// The issued certificates are confirmed without a [`PkiBody::CertConf`](crate::PkiBody::CertConf).
+    pub const IMPLICIT_CONFIRM: &Oid =
+        Oid::ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_IT_IMPLICIT_CONFIRM;
+    /// The time the authority waits for a certificate confirmation.
+    pub const CONFIRM_WAIT_TIME: &Oid =
+        Oid::ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_IT_CONFIRM_WAIT_TIME;
+    /// The original message, when a registration authority replaces its
+    /// protection.
+    pub const ORIG_PKI_MESSAGE: &Oid =
+        Oid::ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_IT_ORIG_PKI_MESSAGE;
+    /// The languages supported by the sender for [`PkiFreeText`](crate::PkiFreeText).
+    pub const SUPP_LANG_TAGS: &Oid =
+        Oid::ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_IT_SUPP_LANG_TAGS;
+}
+
+/// The bit positions of the reasons in a [`PkiFailureInfo`].
+pub mod failure_info {
+    /// Unrecognized or unsupported algorithm identifier.
+    pub const BAD_ALG: usize = 0;
+    /// Integrity check failed, e.g. the signature didn't verify.
+    pub const BAD_MESSAGE_CHECK: usize = 1;
+    /// The transaction isn't permitted or supported.
+    pub const BAD_REQUEST: usize = 2;
+    /// The message time wasn't sufficiently close to the system time.
+    pub const BAD_TIME: usize = 3;
+    /// No certificate could be found matching the provided criteria.
+    pub const BAD_CERT_ID: usize = 4;
+    /// The data submitted has the wrong format.
+    pub const BAD_DATA_FORMAT: usize = 5;
+    /// The authority indicated in the request is different from this one.
+    pub const WRONG_AUTHORITY: usize = 6;
+    /// The requester's data is incorrect.
+    pub const INCORRECT_DATA: usize = 7;
+    /// A timestamp was absent but required by policy.
+    pub const MISSING_TIME_STAMP: usize = 8;
+    /// The proof of possession failed.
+    pub const BAD_POP: usize = 9;
+    /// The certificate has already been revoked.
+    pub const CERT_REVOKED: usize = 10;
+    /// The certificate has already been confirmed.
+    pub const CERT_CONFIRMED: usize = 11;
+    /// Invalid integrity, a password-based MAC was expected instead of a
+    /// signature or vice versa.
+    pub const WRONG_INTEGRITY: usize = 12;
+    /// Invalid recipient nonce, either missing or wrong value.
+    pub const BAD_RECIPIENT_NONCE: usize = 13;
+    /// The time source of the authority isn't available.
+    pub const TIME_NOT_AVAILABLE: usize = 14;
+    /// The requested policy isn't supported.
+    pub const UNACCEPTED_POLICY: usize = 15;
+    /// The requested extension isn't supported.
+    pub const UNACCEPTED_EXTENSION: usize = 16;
+    /// The additional information requested couldn't be understood or isn't
+    /// available.
+    pub const ADD_INFO_NOT_AVAILABLE: usize = 17;
+    /// Invalid sender nonce, either missing or wrong size.
+    pub const BAD_SENDER_NONCE: usize = 18;
+    /// Invalid certificate template or missing mandatory information.
+    pub const BAD_CERT_TEMPLATE: usize = 19;
+    /// The signer of the message is unknown or not trusted.
+    pub const SIGNER_NOT_TRUSTED: usize = 20;
+    /// The transaction identifier is already in use.
+    pub const TRANSACTION_ID_IN_USE: usize = 21;
+    /// The version of the message isn't supported.
+    pub const UNSUPPORTED_VERSION: usize = 22;
+    /// The sender isn't authorized to make the request.
+    pub const NOT_AUTHORIZED: usize = 23;
+    /// The request can't be handled due to system unavailability.
+    pub const SYSTEM_UNAVAIL: usize = 24;
+    /// The request can't be handled due to system failure.
+    pub const SYSTEM_FAILURE: usize = 25;
+    /// The certificate can't be issued because a duplicate already exists.
+    pub const DUPLICATE_CERT_REQ: usize = 26;
+}
+
+/// A CMP message.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+pub struct PkiMessage {
+    /// The header of the message.
+    pub header: PkiHeader,
+    /// The message-specific contents.
+    pub body: PkiBody,
+    /// The protection of the header and body.
+    #[rasn(tag(explicit(0)))]
+    pub protection: Option<PkiProtection>,
+    /// Additional certificates, such as those needed to verify the
+    /// protection.
+    #[rasn(tag(explicit(1)), size("1.."))]
+    pub extra_certs: Option<SequenceOf<CmpCertificate>>,
+}
+
+impl PkiMessage {
+    /// Returns the part of the message covered by its protection.
+    pub fn protected_part(&self) -> ProtectedPart {
+        ProtectedPart {
+            header: self.header.clone(),
+            body: self.body.clone(),
+        }
+    }
+}
+
+/// The information common to all [`PkiMessage`]s.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+pub struct PkiHeader {
+    /// The protocol version, see [`PkiHeader::CMP2000`].
+    pub pvno: u8,
+    /// The name of the sender.
+    pub sender: GeneralName,
+    /// The name of the intended recipient.
+    pub recipient: GeneralName,
+    /// The time the message was produced.
+    #[rasn(tag(explicit(0)))]
+    pub message_time: Option<GeneralizedTime>,
+    /// The algorithm used to compute the protection of the message.
+    #[rasn(tag(explicit(1)))]
+    pub protection_alg: Option<AlgorithmIdentifier>,
+    /// The key used by the sender to protect the message.
+    #[rasn(tag(explicit(2)), identifier = "senderKID")]
+    pub sender_kid: Option<KeyIdentifier>,
+    /// The key the recipient should use to verify the message.
+    #[rasn(tag(explicit(3)), identifier = "recipKID")]
+    pub recip_kid: Option<KeyIdentifier>,
+    /// The identifier of the transaction the message belongs to.
+    #[rasn(tag(explicit(4)), identifier = "transactionID")]
+    pub transaction_id: Option<OctetString>,
+    /// A fresh value protecting against replay.
+    #[rasn(tag(explicit(5)))]
+    pub sender_nonce: Option<OctetString>,
+    /// The `sender_nonce` of the message being responded to.
+    #[rasn(tag(explicit(6)))]
+    pub recip_nonce: Option<OctetString>,
+    /// Human readable text for the recipient.
+    #[rasn(tag(explicit(7)))]
+    pub free_text: Option<PkiFreeText>,
+    /// Additional information, such as [`info_type::IMPLICIT_CONFIRM`].
+    #[rasn(tag(explicit(8)), size("1.."))]
+    pub general_info: Option<SequenceOf<InfoTypeAndValue>>,
+}
+
+impl PkiHeader {
+    /// The RFC 2510 version of the protocol.
+    pub const CMP1999: u8 = 1;
+    /// The RFC 4210 version of the protocol.
+    pub const CMP2000: u8 = 2;
+    /// The RFC 9480 version of the protocol, required when using its
+    /// additions such as [`CertStatus::hash_alg`].
+    pub const CMP2021: u8 = 3;
+}
+
+/// The message-specific contents of a [`PkiMessage`].
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+#[rasn(choice)]
+pub enum PkiBody {
+    /// Initialization request.
+    #[rasn(tag(explicit(0)))]
+    Ir(CertReqMessages),
+    /// Initialization response.
+    #[rasn(tag(explicit(1)))]
+    Ip(CertRepMessage),
+    /// Certification request.
+    #[rasn(tag(explicit(2)))]
+    Cr(CertReqMessages),
+    /// Certification response.
+    #[rasn(tag(explicit(3)))]
+    Cp(CertRepMessage),
+    /// PKCS #10 certification request.
+    #[rasn(tag(explicit(4)))]
+    P10cr(CertificationRequest),
+    /// Proof of possession challenge.
+    #[rasn(tag(explicit(5)))]
+    Popdecc(PopoDecKeyChallContent),
+    /// Proof of possession response.
+    #[rasn(tag(explicit(6)))]
+    Popdecr(PopoDecKeyRespContent),
+    /// Key update request.
+    #[rasn(tag(explicit(7)))]
+    Kur(CertReqMessages),
+    /// Key update response.
+    #[rasn(tag(explicit(8)))]
+    Kup(CertRepMessage),
+    /// Key recovery request.
+    #[rasn(tag(explicit(9)))]
+    Krr(CertReqMessages),
+    /// Key recovery response.
+    #[rasn(tag(explicit(10)))]
+    Krp(KeyRecRepContent),
+    /// Revocation request.
+    #[rasn(tag(explicit(11)))]
+    Rr(RevReqContent),
+    /// Revocation response.
+    #[rasn(tag(explicit(12)))]
+    Rp(RevRepContent),
+    /// Cross-certification request.
+    #[rasn(tag(explicit(13)))]
+    Ccr(CertReqMessages),
+    /// Cross-certification response.
+    #[rasn(tag(explicit(14)))]
+    Ccp(CertRepMessage),
+    /// CA key update announcement.
+    #[rasn(tag(explicit(15)))]
+    Ckuann(Box<CaKeyUpdAnnContent>),
+    /// Certificate announcement.
+    #[rasn(tag(explicit(16)))]
+    Cann(CertAnnContent),
+    /// Revocation announcement.
+    #[rasn(tag(explicit(17)))]
+    Rann(RevAnnContent),
+    /// CRL announcement.
+    #[rasn(tag(explicit(18)))]
+    Crlann(CrlAnnContent),
+    /// Confirmation.
+    #[rasn(tag(explicit(19)))]
+    Pkiconf(PkiConfirmContent),
+    /// Nested messages.
+    #[rasn(tag(explicit(20)))]
+    Nested(NestedMessageContent),
+    /// General message.
+    #[rasn(tag(explicit(21)))]
+    Genm(GenMsgContent),
+    /// General response.
+    #[rasn(tag(explicit(22)))]
+    Genp(GenRepContent),
+    /// Error message.
+    #[rasn(tag(explicit(23)))]
+    Error(ErrorMsgContent),
+    /// Certificate confirmation.
+    #[rasn(tag(explicit(24)))]
+    CertConf(CertConfirmContent),
+    /// Polling request.
+    #[rasn(tag(explicit(25)))]
+    PollReq(PollReqContent),
+    /// Polling response.
+    #[rasn(tag(explicit(26)))]
+    PollRep(PollRepContent),
+}
+
+/// The part of a [`PkiMessage`] covered by its protection.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+pub struct ProtectedPart {
+    pub header: PkiHeader,
+    pub body: PkiBody,
+}
+
+/// A certificate in a CMP message.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+#[rasn(choice)]
+pub enum CmpCertificate {
+    /// An X.509 v3 certificate.
+    #[rasn(identifier = "x509v3PKCert")]
+    X509v3PkCert(Box<Certificate>),
+}
+
+/// The parameters of the [`DH_BASED_MAC`] algorithm.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+pub struct DhbmParameter {
+    /// The one-way function hashing the shared secret to derive the key.
+    pub owf: AlgorithmIdentifier,
+    /// The MAC algorithm computed with the key.
+    pub mac: AlgorithmIdentifier,
+}
+
+/// The status of a request.
+#[derive(AsnType, Clone, Copy, Debug, Decode, Encode, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[rasn(delegate)]
+pub struct PkiStatus(u64);
+
+impl PkiStatus {
+    /// The request was granted as requested.
+    pub const ACCEPTED: Self = Self(0);
+    /// The request was granted with modifications.
+    pub const GRANTED_WITH_MODS: Self = Self(1);
+    /// The request was rejected, see the [`PkiFailureInfo`].
+    pub const REJECTION: Self = Self(2);
+    /// The request hasn't been processed yet, and should be polled for.
+    pub const WAITING: Self = Self(3);
+    /// A revocation is imminent.
+    pub const REVOCATION_WARNING: Self = Self(4);
+    /// A revocation has occurred.
+    pub const REVOCATION_NOTIFICATION: Self = Self(5);
+    /// The requested key update has already been done.
+    pub const KEY_UPDATE_WARNING: Self = Self(6);
+
+    /// Returns the raw value of the status.
+    pub fn raw_value(self) -> u64 {
+        self.0
+    }
+}
+
+/// The status of a request, along with the reasons of a failure.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+pub struct PkiStatusInfo {
+    /// The status of the request.
+    pub status: PkiStatus,
+    /// A human readable description of the status.
+    pub status_string: Option<PkiFreeText>,
+    /// The reasons the request failed.
+    pub fail_info: Option<PkiFailureInfo>,
+}
+
+impl PkiStatusInfo {
+    /// Returns whether `fail_info` has the reason at `bit`, one of the
+    /// positions in [`failure_info`].
+    pub fn has_failure(&self, bit: usize) -> bool {
+        self.fail_info
+            .as_ref()
+            .and_then(|info| info.get(bit).map(|bit| *bit))
+            .unwrap_or(false)
+    }
+}
+
+/// The hash of a certificate delivered out-of-band.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+pub struct OobCertHash {
+    /// The hash algorithm, if different from the signature algorithm of the
+    /// certificate.
+    #[rasn(tag(explicit(0)))]
+    pub hash_alg: Option<AlgorithmIdentifier>,
+    /// The identifier of the certificate.
+    #[rasn(tag(explicit(1)))]
+    pub cert_id: Option<CertId>,
+    /// The hash of the DER encoded certificate.
+    pub hash_val: BitString,
+}
+
+/// A challenge proving the possession of a decryption key.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+pub struct Challenge {
+    /// The one-way function computing the witness.
+    pub owf: Option<AlgorithmIdentifier>,
+    /// The result of the one-way function over a random integer.
+    pub witness: OctetString,
+    /// The encrypted random integer and the sender's name.
+    pub challenge: OctetString,
+}
+
+/// The response to certification requests.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+pub struct CertRepMessage {
+    /// Certificates of the certification authorities.
+    #[rasn(tag(explicit(1)), size("1.."))]
+    pub ca_pubs: Option<SequenceOf<CmpCertificate>>,
+    /// The responses to each of the requests.
+    pub response: SequenceOf<CertResponse>,
+}
+
+/// The response to a certification request.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+pub struct CertResponse {
+    /// The identifier of the request responded to.
+    pub cert_req_id: Integer,
+    /// The status of the request.
+    pub status: PkiStatusInfo,
+    /// The issued certificate, and possibly its private key.
+    pub certified_key_pair: Option<CertifiedKeyPair>,
+    /// Additional response information.
+    pub rsp_info: Option<OctetString>,
+}
+
+/// An issued certificate, along with its private key if it was generated by
+/// the authority.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+pub struct CertifiedKeyPair {
+    /// The certificate, in plain or encrypted form.
+    pub cert_or_enc_cert: CertOrEncCert,
+    /// The encrypted private key.
+    #[rasn(tag(explicit(0)))]
+    pub private_key: Option<EncryptedValue>,
+    /// Where the certificate was published.
+    #[rasn(tag(explicit(1)))]
+    pub publication_info: Option<PkiPublicationInfo>,
+}
+
+/// A certificate, in plain or encrypted form.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+#[rasn(choice)]
+pub enum CertOrEncCert {
+    /// The certificate.
+    #[rasn(tag(explicit(0)))]
+    Certificate(CmpCertificate),
+    /// The encrypted certificate.
+    #[rasn(tag(explicit(1)))]
+    EncryptedCert(Box<EncryptedValue>),
+}
+
+/// The response to a key recovery request.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+pub struct KeyRecRepContent {
+    /// The status of the request.
+    pub status: PkiStatusInfo,
+    /// The current signing certificate.
+    #[rasn(tag(explicit(0)))]
+    pub new_sig_cert: Option<CmpCertificate>,
+    /// Certificates of the certification authorities.
+    #[rasn(tag(explicit(1)), size("1.."))]
+    pub ca_certs: Option<SequenceOf<CmpCertificate>>,
+    /// The recovered key pairs.
+    #[rasn(tag(explicit(2)), size("1.."))]
+    pub key_pair_hist: Option<SequenceOf<CertifiedKeyPair>>,
+}
+
+/// A certificate to be revoked.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+pub struct RevDetails {
+    /// The fields identifying the certificate.
+    pub cert_details: CertTemplate,
+    /// The requested CRL entry extensions, such as the reason code.
+    pub crl_entry_details: Option<Extensions>,
+}
+
+/// The response to a revocation request.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+pub struct RevRepContent {
+    /// The status of each of the revocations.
+    #[rasn(size("1.."))]
+    pub status: SequenceOf<PkiStatusInfo>,
+    /// The identifiers of the revoked certificates.
+    #[rasn(tag(explicit(0)), size("1.."))]
+    pub rev_certs: Option<SequenceOf<CertId>>,
+    /// The CRLs of the revoked certificates.
+    #[rasn(tag(explicit(1)), size("1.."))]
+    pub crls: Option<SequenceOf<CertificateList>>,
+}
+
+/// The announcement of an update of the authority's key.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+pub struct CaKeyUpdAnnContent {
+    /// The old public key signed with the new private key.
+    pub old_with_new: CmpCertificate,
+    /// The new public key signed with the old private key.
+    pub new_with_old: CmpCertificate,
+    /// The new public key signed with the new private key.
+    pub new_with_new: CmpCertificate,
+}
+
+/// The announcement of a revocation.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+pub struct RevAnnContent {
+    /// The status of the revocation.
+    pub status: PkiStatus,
+    /// The identifier of the revoked certificate.
+    pub cert_id: CertId,
+    /// When the certificate will be revoked.
+    pub will_be_revoked_at: GeneralizedTime,
+    /// Since when the certificate has been compromised.
+    pub bad_since_date: GeneralizedTime,
+    /// Additional CRL details.
+    pub crl_details: Option<Extensions>,
+}
+
+/// Information of a type identified by its `info_type`, such as those in
+/// [`info_type`].
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+pub struct InfoTypeAndValue {
+    /// The type of the information.
+    pub info_type: ObjectIdentifier,
+    /// The information, if any.
+    pub info_value: Option<Any>,
+}
+
+/// The contents of an error message.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+pub struct ErrorMsgContent {
+    /// The status describing the error.
+    #[rasn(identifier = "pKIStatusInfo")]
+    pub pki_status_info: PkiStatusInfo,
+    /// An implementation-specific error code.
+    pub error_code: Option<Integer>,
+    /// Implementation-specific error details.
+    pub error_details: Option<PkiFreeText>,
+}
+
+/// The confirmation of an issued certificate.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+pub struct CertStatus {
+    /// The hash of the certificate, with the hash algorithm of its
+    /// signature.
+    pub cert_hash: OctetString,
+    /// The identifier of the request the certificate was issued for.
+    pub cert_req_id: Integer,
+    /// Whether the certificate is accepted or rejected.
+    pub status_info: Option<PkiStatusInfo>,
+    /// The hash algorithm of `cert_hash`, when the signature algorithm of
+    /// the certificate doesn't have one.
+    #[rasn(tag(explicit(0)))]
+    pub hash_alg: Option<AlgorithmIdentifier>,
+}
+
+/// A polling request for a certification request.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+pub struct PollReq {
+    /// The identifier of the certification request.
+    pub cert_req_id: Integer,
+}
+
+/// The response to a [`PollReq`].
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+pub struct PollRep {
+    /// The identifier of the certification request.
+    pub cert_req_id: Integer,
+    /// The number of seconds to wait before polling again.
+    pub check_after: Integer,
+    /// The reason for the delay.
+    pub reason: Option<PkiFreeText>,
+}
@@ -0,0 +1,258 @@
+//! Messages exchanged between the OpenSSL CMP client and its mock server.
+
+use pretty_assertions::assert_eq;
+use rasn::prelude::*;
+use rasn_cmp::*;
+use rasn_crmf::{CertReqMsg, ProofOfPossession, OLD_CERT_ID};
+use rasn_pkix::{CrlReason, Name};
+
+const ECDSA_WITH_SHA256: &Oid = Oid::const_new(&[1, 2, 840, 10045, 4, 3, 2]);
+
+#[track_caller]
+fn decode(der: &[u8]) -> PkiMessage {
+    let message: PkiMessage = rasn::der::decode(der).unwrap();
+    assert_eq!(der, rasn::der::encode(&message).unwrap());
+    assert_eq!(message.header.pvno, PkiHeader::CMP2000);
+    assert!(message.protection.is_some());
+    message
+}
+
+fn common_name(name: &GeneralName) -> Option<String> {
+    let GeneralName::DirectoryName(Name::RdnSequence(rdns)) = name else {
+        panic!("expected a directory name");
+    };
+    let rdn = rdns.first()?;
+    let attribute = rdn.to_vec()[0].clone();
+    assert_eq!(
+        attribute.r#type,
+        Oid::JOINT_ISO_ITU_T_DS_ATTRIBUTE_TYPE_COMMON_NAME
+    );
+    Some(rasn::der::decode::<Utf8String>(attribute.value.as_bytes()).unwrap())
+}
+
+fn single_request(messages: &CertReqMessages) -> &CertReqMsg {
+    let [request] = &messages[..] else {
+        panic!("expected a single request");
+    };
+    request
+}
+
+fn issued_certificate(response: &CertRepMessage) -> &Certificate {
+    let [response] = &response.response[..] else {
+        panic!("expected a single response");
+    };
+    assert_eq!(response.cert_req_id, 0.into());
+    assert_eq!(response.status.status, PkiStatus::ACCEPTED);
+    let CertOrEncCert::Certificate(CmpCertificate::X509v3PkCert(certificate)) = &response
+        .certified_key_pair
+        .as_ref()
+        .unwrap()
+        .cert_or_enc_cert
+    else {
+        panic!("expected a plain certificate");
+    };
+    certificate
+}
+
+#[test]
+fn password_based_initialization() {
+    let ir = decode(include_bytes!("data/ir.der"));
+    let ip = decode(include_bytes!("data/ip.der"));
+    let cert_conf = decode(include_bytes!("data/certconf.der"));
+    let pki_conf = decode(include_bytes!("data/pkiconf.der"));
+
+    let header = &ir.header;
+    assert_eq!(
+        common_name(&header.sender).as_deref(),
+        Some("device.example.com")
+    );
+    assert_eq!(
+        common_name(&header.recipient).as_deref(),
+        Some("Example CMP CA")
+    );
+    assert_eq!(header.sender_kid.as_deref(), Some(&b"3078"[..]));
+    let protection_alg = header.protection_alg.as_ref().unwrap();
+    assert_eq!(protection_alg.algorithm, PASSWORD_BASED_MAC);
+    let parameters: PbmParameter =
+        rasn::der::decode(protection_alg.parameters.as_ref().unwrap().as_bytes()).unwrap();
+    assert_eq!(parameters.iteration_count, 500.into());
+    assert_eq!(
+        parameters.owf.algorithm,
+        Oid::JOINT_ISO_ITU_T_COUNTRY_US_ORGANIZATION_GOV_CSOR_NIST_ALGORITHMS_HASH_SHA256
+    );
+
+    let PkiBody::Ir(requests) = &ir.body else {
+        panic!("expected an initialization request");
+    };
+    let request = single_request(requests);
+    assert_eq!(request.cert_req.cert_req_id, 0.into());
+    let template = &request.cert_req.cert_template;
+    let public_key = template.public_key.as_ref().unwrap();
+    assert_eq!(
+        public_key.algorithm.algorithm,
+        Oid::ISO_MEMBER_BODY_US_ANSI_X962_KEY_TYPE_EC_PUBLIC_KEY
+    );
+    let Some(ProofOfPossession::Signature(popo)) = &request.popo else {
+        panic!("expected a signature proof of possession");
+    };
+    assert_eq!(popo.poposk_input, None);
+
+    // The response belongs to the same transaction, and echoes the nonce.
+    assert_eq!(ip.header.transaction_id, header.transaction_id);
+    assert_eq!(ip.header.recip_nonce, header.sender_nonce);
+    let PkiBody::Ip(response) = &ip.body else {
+        panic!("expected an initialization response");
+    };
+    assert_eq!(response.ca_pubs.as_ref().unwrap().len(), 1);
+    let certificate = issued_certificate(response);
+    assert_eq!(certificate.tbs_certificate.serial_number, 0x1234.into());
+    assert_eq!(
+        certificate.tbs_certificate.subject_public_key_info,
+        *public_key
+    );
+
+    let PkiBody::CertConf(confirmations) = &cert_conf.body else {
+        panic!("expected a certificate confirmation");
+    };
+    let [confirmation] = &confirmations[..] else {
+        panic!("expected a single confirmation");
+    };
+    assert_eq!(confirmation.cert_req_id, 0.into());
+    assert_eq!(confirmation.cert_hash.len(), 32);
+    assert_eq!(
+        confirmation.status_info.as_ref().unwrap().status,
+        PkiStatus::ACCEPTED
+    );
+
+    assert_eq!(pki_conf.body, PkiBody::Pkiconf(()));
+    assert_eq!(pki_conf.header.transaction_id, header.transaction_id);
+}
+
+#[test]
+fn signed_key_update() {
+    let kur = decode(include_bytes!("data/kur.der"));
+    let kup = decode(include_bytes!("data/kup.der"));
+
+    assert_eq!(
+        kur.header.protection_alg.as_ref().unwrap().algorithm,
+        ECDSA_WITH_SHA256
+    );
+    let general_info = kur.header.general_info.as_ref().unwrap();
+    assert_eq!(general_info[0].info_type, info_type::IMPLICIT_CONFIRM);
+    let [CmpCertificate::X509v3PkCert(signer)] = &kur.extra_certs.as_ref().unwrap()[..] else {
+        panic!("expected the signer's certificate");
+    };
+
+    let PkiBody::Kur(requests) = &kur.body else {
+        panic!("expected a key update request");
+    };
+    let request = single_request(requests);
+    let controls = request.cert_req.controls.as_ref().unwrap();
+    assert_eq!(controls[0].r#type, OLD_CERT_ID);
+    let old_cert_id: CertId = rasn::der::decode(controls[0].value.as_bytes()).unwrap();
+    assert_eq!(
+        old_cert_id.serial_number,
+        signer.tbs_certificate.serial_number
+    );
+
+    let PkiBody::Kup(response) = &kup.body else {
+        panic!("expected a key update response");
+    };
+    let certificate = issued_certificate(response);
+    assert_eq!(certificate.tbs_certificate.serial_number, 0x1235.into());
+    assert_ne!(
+        certificate.tbs_certificate.subject_public_key_info,
+        signer.tbs_certificate.subject_public_key_info
+    );
+}
+
+#[test]
+fn revocation() {
+    let rr = decode(include_bytes!("data/rr.der"));
+    let rp = decode(include_bytes!("data/rp.der"));
+
+    let PkiBody::Rr(revocations) = &rr.body else {
+        panic!("expected a revocation request");
+    };
+    let [revocation] = &revocations[..] else {
+        panic!("expected a single revocation");
+    };
+    assert_eq!(revocation.cert_details.serial_number, Some(0x1234.into()));
+    let extensions = revocation.crl_entry_details.as_ref().unwrap();
+    assert_eq!(
+        extensions[0].extn_id,
+        Oid::JOINT_ISO_ITU_T_DS_CERTIFICATE_EXTENSION_CRL_REASONS
+    );
+    assert_eq!(
+        rasn::der::decode::<CrlReason>(&extensions[0].extn_value).unwrap(),
+        CrlReason::KeyCompromise
+    );
+
+    let PkiBody::Rp(response) = &rp.body else {
+        panic!("expected a revocation response");
+    };
+    assert_eq!(response.status[0].status, PkiStatus::ACCEPTED);
+    let [revoked] = &response.rev_certs.as_ref().unwrap()[..] else {
+        panic!("expected a single revoked certificate");
+    };
+    assert_eq!(revoked.serial_number, 0x1234.into());
+}
+
+#[test]
+fn general_message() {
+    let genm = decode(include_bytes!("data/genm.der"));
+    let genp = decode(include_bytes!("data/genp.der"));
+
+    let PkiBody::Genm(request) = &genm.body else {
+        panic!("expected a general message");
+    };
+    assert_eq!(
+        request[..],
+        [InfoTypeAndValue {
+            info_type: info_type::SIGN_KEY_PAIR_TYPES.into(),
+            info_value: None,
+        }]
+    );
+    let PkiBody::Genp(response) = &genp.body else {
+        panic!("expected a general response");
+    };
+    assert_eq!(response[0].info_type, info_type::SIGN_KEY_PAIR_TYPES);
+}
+
+#[test]
+fn error() {
+    let error = decode(include_bytes!("data/error.der"));
+
+    let PkiBody::Error(content) = &error.body else {
+        panic!("expected an error message");
+    };
+    let status = &content.pki_status_info;
+    assert_eq!(status.status, PkiStatus::REJECTION);
+    assert!(status.has_failure(failure_info::BAD_REQUEST));
+    assert!(!status.has_failure(failure_info::BAD_POP));
+    assert_eq!(
+        status.status_string.as_deref(),
+        Some(&["missing trust store".to_owned()][..])
+    );
+    assert_eq!(content.error_code, Some(0x1D00_0090.into()));
+    assert_eq!(
+        content.error_details.as_deref(),
+        Some(&["CMP routines".to_owned(), "missing trust store".to_owned()][..])
+    );
+}
+
+#[test]
+fn protected_part() {
+    fn contents(der: &[u8]) -> &[u8] {
+        match der[1] {
+            length @ 0x80.. => &der[2 + usize::from(length & 0x7F)..],
+            _ => &der[2..],
+        }
+    }
+
+    let message = decode(include_bytes!("data/genm.der"));
+    let protected = rasn::der::encode(&message.protected_part()).unwrap();
+    let encoded = rasn::der::encode(&message).unwrap();
+    // The protected part is the header and body at the start of the message.
+    assert!(contents(&encoded).starts_with(contents(&protected)));
+}
@@ -102,6 +102,11 @@ pub struct ContentInfo {
     pub content: Any,
 }
 
+impl rasn::pem::PemLabel for ContentInfo {
+    const LABEL: &'static str = "CMS";
+    const LEGACY_LABELS: &'static [&'static str] = &["PKCS7"];
+}
+
 /// SignedData represents a signed-data content type
 #[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
 pub struct SignedData {
@@ -0,0 +1,372 @@
+#![doc = include_str!("../README.md")]
+#![no_std]
+
+extern crate alloc;
+
+use alloc::boxed::Box;
+use rasn::prelude::*;
+
+#[doc(inline)]
+pub use rasn_cms::EnvelopedData;
+#[doc(inline)]
+pub use rasn_pkcs8::PrivateKeyInfo;
+#[doc(inline)]
+pub use rasn_pkix::{
+    AlgorithmIdentifier, AttributeTypeAndValue, Extensions, GeneralName, Name,
+    SubjectPublicKeyInfo, Time, UniqueIdentifier, Version,
+};
+
+/// One or more certificate request messages.
+pub type CertReqMessages = SequenceOf<CertReqMsg>;
+/// Controls affecting the processing of a [`CertRequest`], such as
+/// [`REG_TOKEN`] and [`PKI_ARCHIVE_OPTIONS`].
+pub type Controls = SequenceOf<AttributeTypeAndValue>;
+/// A one-time secret used to authenticate the subject of a request.
+pub type RegToken = Utf8String;
+/// A long-term secret used to authenticate the subject of a request.
+pub type Authenticator = Utf8String;
+/// The parameters used to generate a key pair on behalf of the subject.
+pub type KeyGenParameters = OctetString;
+/// The certificate being replaced by a request.
+pub type OldCertId = CertId;
+/// The key the subject wants protocol responses to be encrypted with.
+pub type ProtocolEncrKey = SubjectPublicKeyInfo;
+/// Name/value pairs of registration information, in the form
+/// `name?value%name?value%...`.
+pub type Utf8Pairs = Utf8String;
+/// A [`CertRequest`] included as registration information.
+pub type CertReq = CertRequest;
+
+/// The password-based MAC algorithm, with [`PbmParameter`] parameters.
+pub const PASSWORD_BASED_MAC: &Oid = Oid::ISO_MEMBER_BODY_US_ENTRUST_PASSWORD_BASED_MAC;
+
+/// Registration Token control, a [`RegToken`].
+pub const REG_TOKEN: &Oid =
+    Oid::ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_PKIP_REG_CTRL_REG_TOKEN;
+/// Authenticator control, an [`Authenticator`].
+pub const AUTHENTICATOR: &Oid =
+    Oid::ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_PKIP_REG_CTRL_AUTHENTICATOR;
+/// Publication Information control, a [`PkiPublicationInfo`].
+pub const PKI_PUBLICATION_INFO: &Oid =
+    Oid::ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_PKIP_REG_CTRL_PKI_PUBLICATION_INFO;
+/// Archive Options control, a [`PkiArchiveOptions`].
+pub const PKI_ARCHIVE_OPTIONS: &Oid =
+    Oid::ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_PKIP_REG_CTRL_PKI_ARCHIVE_OPTIONS;
+/// Old Certificate ID control, an [`OldCertId`].
+pub const OLD_CERT_ID: &Oid =
+    Oid::ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_PKIP_REG_CTRL_OLD_CERT_ID;
+/// Protocol Encryption Key control, a [`ProtocolEncrKey`].
+pub const PROTOCOL_ENCR_KEY: &Oid =
+    Oid::ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_PKIP_REG_CTRL_PROTOCOL_ENCR_KEY;
+/// UTF8 Pairs registration information, [`Utf8Pairs`].
+pub const UTF8_PAIRS: &Oid =
+    Oid::ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_PKIP_REG_INFO_UTF8_PAIRS;
+/// Certificate Request registration information, a [`CertReq`].
+pub const CERT_REQ: &Oid =
+    Oid::ISO_IDENTIFIED_ORGANISATION_DOD_INTERNET_SECURITY_MECHANISMS_PKIX_PKIP_REG_INFO_CERT_REQ;
+/// The CMS content type of an [`EncKeyWithId`].
+pub const ENC_KEY_WITH_ID: &Oid = Oid::ISO_MEMBER_BODY_US_RSADSI_PKCS9_SMIME_CT_ENC_KEY_WITH_ID;
+
+/// A certificate request, along with the proof of possession of its private
+/// key.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+pub struct CertReqMsg {
+    /// The certificate request.
+    pub cert_req: CertRequest,
+    /// The proof that the requester possesses the private key.
+    pub popo: Option<ProofOfPossession>,
+    /// Supplementary information about the request, such as [`UTF8_PAIRS`].
+    #[rasn(size("1.."))]
+    pub reg_info: Option<SequenceOf<AttributeTypeAndValue>>,
+}
+
+/// The contents of a requested certificate.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+pub struct CertRequest {
+    /// The identifier matching the request with its response.
+    pub cert_req_id: Integer,
+    /// The fields of the requested certificate.
+    pub cert_template: CertTemplate,
+    /// Controls affecting the processing of the request.
+    #[rasn(size("1.."))]
+    pub controls: Option<Controls>,
+}
+
+/// The fields of a certificate, any of which may be omitted to be filled in
+/// by the certification authority.
+#[derive(AsnType, Clone, Debug, Default, Decode, Encode, PartialEq, Eq, Hash)]
+pub struct CertTemplate {
+    /// The version of the certificate, which is usually omitted or `v3`.
+    #[rasn(tag(0))]
+    pub version: Option<Version>,
+    /// The serial number, which is assigned by the authority.
+    #[rasn(tag(1))]
+    pub serial_number: Option<Integer>,
+    /// The algorithm the authority signs the certificate with.
+    #[rasn(tag(2))]
+    pub signing_alg: Option<AlgorithmIdentifier>,
+    /// The issuer of the certificate.
+    #[rasn(tag(explicit(3)))]
+    pub issuer: Option<Name>,
+    /// The validity period of the certificate.
+    #[rasn(tag(4))]
+    pub validity: Option<OptionalValidity>,
+    /// The subject of the certificate.
+    #[rasn(tag(explicit(5)))]
+    pub subject: Option<Name>,
+    /// The public key being certified.
+    #[rasn(tag(6))]
+    pub public_key: Option<SubjectPublicKeyInfo>,
+    /// The unique identifier of the issuer.
+    #[rasn(tag(7), identifier = "issuerUID")]
+    pub issuer_uid: Option<UniqueIdentifier>,
+    /// The unique identifier of the subject.
+    #[rasn(tag(8), identifier = "subjectUID")]
+    pub subject_uid: Option<UniqueIdentifier>,
+    /// The extensions of the certificate.
+    #[rasn(tag(9))]
+    pub extensions: Option<Extensions>,
+}
+
+/// The validity period of a [`CertTemplate`], at least one of which must be
+/// present.
+#[derive(AsnType, Clone, Copy, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+pub struct OptionalValidity {
+    /// The start of the validity period.
+    #[rasn(tag(explicit(0)))]
+    pub not_before: Option<Time>,
+    /// The end of the validity period.
+    #[rasn(tag(explicit(1)))]
+    pub not_after: Option<Time>,
+}
+
+/// The proof that the requester possesses the private key corresponding to
+/// the public key of a request.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+#[rasn(choice)]
+pub enum ProofOfPossession {
+    /// The registration authority has already verified possession.
+    #[rasn(tag(0))]
+    RaVerified(()),
+    /// A signature made with the private key.
+    #[rasn(tag(1))]
+    Signature(PopoSigningKey),
+    /// Possession of a key used for key encipherment.
+    #[rasn(tag(explicit(2)))]
+    KeyEncipherment(PopoPrivKey),
+    /// Possession of a key used for key agreement.
+    #[rasn(tag(explicit(3)))]
+    KeyAgreement(PopoPrivKey),
+}
+
+/// A signature proving possession of a signing key.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+pub struct PopoSigningKey {
+    /// The signed input, which is omitted when the [`CertTemplate`] has a
+    /// subject and public key, in which case the [`CertRequest`] is signed.
+    #[rasn(tag(0))]
+    pub poposk_input: Option<PopoSigningKeyInput>,
+    /// The algorithm used to sign the input.
+    pub algorithm_identifier: AlgorithmIdentifier,
+    /// The signature of the DER encoded input.
+    pub signature: BitString,
+}
+
+/// The input signed in a [`PopoSigningKey`] when the [`CertTemplate`]
+/// doesn't have a subject and public key.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+pub struct PopoSigningKeyInput {
+    /// The authenticated identity of the requester.
+    pub auth_info: PopoSigningKeyAuthInfo,
+    /// The public key being certified.
+    pub public_key: SubjectPublicKeyInfo,
+}
+
+/// The authenticated identity of a [`PopoSigningKeyInput`].
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+#[rasn(choice)]
+pub enum PopoSigningKeyAuthInfo {
+    /// The name of the requester, authenticated by other means.
+    #[rasn(tag(explicit(0)))]
+    Sender(GeneralName),
+    /// A MAC of the public key, computed with a shared secret.
+    #[rasn(identifier = "publicKeyMAC")]
+    PublicKeyMac(PkMacValue),
+}
+
+/// A MAC computed over a public key.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+pub struct PkMacValue {
+    /// The MAC algorithm, such as [`PASSWORD_BASED_MAC`].
+    pub alg_id: AlgorithmIdentifier,
+    /// The MAC of the DER encoded public key.
+    pub value: BitString,
+}
+
+/// The parameters of the [`PASSWORD_BASED_MAC`] algorithm.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+pub struct PbmParameter {
+    /// The salt appended to the shared secret.
+    pub salt: OctetString,
+    /// The one-way function hashing the salted secret to derive the key.
+    pub owf: AlgorithmIdentifier,
+    /// The number of times the one-way function is applied.
+    pub iteration_count: Integer,
+    /// The MAC algorithm computed with the key.
+    pub mac: AlgorithmIdentifier,
+}
+
+/// The proof of possession of a key that can't sign.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+#[rasn(choice)]
+pub enum PopoPrivKey {
+    /// The encrypted private key (deprecated).
+    #[rasn(tag(0))]
+    ThisMessage(BitString),
+    /// Possession is proven in a subsequent message.
+    #[rasn(tag(1))]
+    SubsequentMessage(SubsequentMessage),
+    /// A MAC computed with a Diffie-Hellman shared secret (deprecated).
+    #[rasn(tag(2), identifier = "dhMAC")]
+    DhMac(BitString),
+    /// A MAC computed with a key agreement shared secret.
+    #[rasn(tag(3), identifier = "agreeMAC")]
+    AgreeMac(PkMacValue),
+    /// The private key, encrypted to the certification authority.
+    #[rasn(tag(4))]
+    EncryptedKey(EnvelopedData),
+}
+
+/// How possession of a key is proven in a subsequent message.
+#[derive(AsnType, Clone, Copy, Debug, Decode, Encode, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[rasn(delegate)]
+pub struct SubsequentMessage(u64);
+
+impl SubsequentMessage {
+    /// The certificate is returned encrypted with the public key.
+    pub const ENCR_CERT: Self = Self(0);
+    /// The requester answers a challenge encrypted with the public key.
+    pub const CHALLENGE_RESP: Self = Self(1);
+
+    /// Returns the raw value of the message type.
+    pub fn raw_value(self) -> u64 {
+        self.0
+    }
+}
+
+/// An encrypted value, such as a private key or certificate (deprecated in
+/// favour of [`EnvelopedData`]).
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+pub struct EncryptedValue {
+    /// The algorithm the value is intended for.
+    #[rasn(tag(0))]
+    pub intended_alg: Option<AlgorithmIdentifier>,
+    /// The symmetric algorithm used to encrypt the value.
+    #[rasn(tag(1))]
+    pub symm_alg: Option<AlgorithmIdentifier>,
+    /// The encrypted symmetric key.
+    #[rasn(tag(2))]
+    pub enc_symm_key: Option<BitString>,
+    /// The algorithm used to encrypt the symmetric key.
+    #[rasn(tag(3))]
+    pub key_alg: Option<AlgorithmIdentifier>,
+    /// A hint of the value's contents.
+    #[rasn(tag(4))]
+    pub value_hint: Option<OctetString>,
+    /// The encrypted value.
+    pub enc_value: BitString,
+}
+
+/// An encrypted private key.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+#[rasn(choice)]
+pub enum EncryptedKey {
+    /// The key encrypted as an [`EncryptedValue`] (deprecated).
+    EncryptedValue(EncryptedValue),
+    /// The key encrypted as an [`EncKeyWithId`] in enveloped data.
+    #[rasn(tag(0))]
+    EnvelopedData(EnvelopedData),
+}
+
+/// The value of the [`PKI_ARCHIVE_OPTIONS`] control, requesting the
+/// archival of the private key.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+#[rasn(choice)]
+pub enum PkiArchiveOptions {
+    /// The private key to archive.
+    #[rasn(tag(explicit(0)))]
+    EncryptedPrivKey(Box<EncryptedKey>),
+    /// The parameters to regenerate the private key.
+    #[rasn(tag(1))]
+    KeyGenParameters(KeyGenParameters),
+    /// Whether to archive a private key generated by the authority.
+    #[rasn(tag(2))]
+    ArchiveRemGenPrivKey(bool),
+}
+
+/// The value of the [`PKI_PUBLICATION_INFO`] control, requesting the
+/// publication of the certificate.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+pub struct PkiPublicationInfo {
+    /// Whether to publish the certificate, see [`PkiPublicationInfo::DONT_PUBLISH`].
+    pub action: u8,
+    /// Where to publish the certificate.
+    #[rasn(size("1.."))]
+    pub pub_infos: Option<SequenceOf<SinglePubInfo>>,
+}
+
+impl PkiPublicationInfo {
+    /// The certificate isn't published.
+    pub const DONT_PUBLISH: u8 = 0;
+    /// The certificate is published.
+    pub const PLEASE_PUBLISH: u8 = 1;
+}
+
+/// Where to publish a certificate.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+pub struct SinglePubInfo {
+    /// The publication method, see [`SinglePubInfo::DONT_CARE`].
+    pub pub_method: u8,
+    /// Where the certificate is published.
+    pub pub_location: Option<GeneralName>,
+}
+
+impl SinglePubInfo {
+    /// The certification authority chooses the method.
+    pub const DONT_CARE: u8 = 0;
+    /// The certificate is published in an X.500 directory.
+    pub const X500: u8 = 1;
+    /// The certificate is published on the web.
+    pub const WEB: u8 = 2;
+    /// The certificate is published in an LDAP directory.
+    pub const LDAP: u8 = 3;
+}
+
+/// The identifier of a certificate by its issuer and serial number.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+pub struct CertId {
+    /// The issuer of the certificate.
+    pub issuer: GeneralName,
+    /// The serial number of the certificate.
+    pub serial_number: Integer,
+}
+
+/// A private key, along with the identity it belongs to, encrypted in an
+/// [`EncryptedKey::EnvelopedData`].
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+pub struct EncKeyWithId {
+    /// The private key.
+    pub private_key: PrivateKeyInfo,
+    /// The identity the key belongs to.
+    pub identifier: Option<EncKeyIdentifier>,
+}
+
+/// The identity of an [`EncKeyWithId`].
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+#[rasn(choice)]
+pub enum EncKeyIdentifier {
+    /// A free-form name.
+    String(Utf8String),
+    /// A general name, such as a directory name or email address.
+    GeneralName(GeneralName),
+}
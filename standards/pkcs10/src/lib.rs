@@ -0,0 +1,113 @@
+#![doc = include_str!("../README.md")]
+#![no_std]
+
+use rasn::{
+    error::{DecodeError, EncodeError},
+    prelude::*,
+    Codec,
+};
+
+#[doc(inline)]
+pub use rasn_pkix::{AlgorithmIdentifier, Attribute, Extensions, Name, SubjectPublicKeyInfo};
+
+/// The version of a certification request, which is always 0 (`v1`).
+pub type Version = Integer;
+/// The attributes providing additional information about the subject of a
+/// certification request.
+pub type Attributes = SetOf<Attribute>;
+/// The extensions requested to be included in the certificate.
+pub type ExtensionRequest = Extensions;
+
+/// Challenge Password (PKCS #9) is a password by which the entity may
+/// request certificate revocation.
+pub const CHALLENGE_PASSWORD: &Oid = Oid::ISO_MEMBER_BODY_US_RSADSI_PKCS9_CHALLENGE_PASSWORD;
+
+/// Extension Request (PKCS #9) contains the [`ExtensionRequest`] of the
+/// extensions to be included in the certificate.
+pub const EXTENSION_REQUEST: &Oid = Oid::ISO_MEMBER_BODY_US_RSADSI_PKCS9_EXTENSION_REQUEST;
+
+/// A certification request, signed by the subject requesting a certificate.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+pub struct CertificationRequest {
+    /// The information being signed.
+    pub certification_request_info: CertificationRequestInfo,
+    /// The algorithm used to sign the certification request information.
+    pub signature_algorithm: AlgorithmIdentifier,
+    /// The signature of the DER encoded certification request information.
+    pub signature: BitString,
+}
+
+impl rasn::pem::PemLabel for CertificationRequest {
+    const LABEL: &'static str = "CERTIFICATE REQUEST";
+    const LEGACY_LABELS: &'static [&'static str] = &["NEW CERTIFICATE REQUEST"];
+}
+
+/// The information about the subject of a [`CertificationRequest`].
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+pub struct CertificationRequestInfo {
+    /// The version of the request, for compatibility with future revisions.
+    pub version: Version,
+    /// The distinguished name of the certificate subject.
+    pub subject: Name,
+    /// The public key being certified.
+    #[rasn(identifier = "subjectPKInfo")]
+    pub subject_pk_info: SubjectPublicKeyInfo,
+    /// Additional information about the subject, such as the
+    /// [`EXTENSION_REQUEST`] and [`CHALLENGE_PASSWORD`] attributes.
+    #[rasn(tag(0))]
+    pub attributes: Attributes,
+}
+
+impl CertificationRequestInfo {
+    /// Returns the extensions requested with the [`EXTENSION_REQUEST`]
+    /// attribute, or `None` if the request doesn't have one.
+    ///
+    /// # Errors
+    /// Returns an error if the attribute doesn't have a single value, or its
+    /// value isn't DER encoded [`ExtensionRequest`].
+    pub fn extension_request(&self) -> Result<Option<ExtensionRequest>, DecodeError> {
+        let Some(attribute) = self
+            .attributes
+            .to_vec()
+            .into_iter()
+            .find(|attribute| attribute.r#type == EXTENSION_REQUEST)
+        else {
+            return Ok(None);
+        };
+
+        match attribute.values.to_vec()[..] {
+            [value] => rasn::der::decode(value.as_bytes()).map(Some),
+            ref values => Err(DecodeError::size_constraint_not_satisfied(
+                Some(values.len()),
+                "1".into(),
+                Codec::Der,
+            )),
+        }
+    }
+
+    /// Sets the [`EXTENSION_REQUEST`] attribute to request `extensions`,
+    /// replacing any existing extension request.
+    ///
+    /// # Errors
+    /// Returns an error if `extensions` fail to be DER encoded.
+    pub fn set_extension_request(
+        &mut self,
+        extensions: &ExtensionRequest,
+    ) -> Result<(), EncodeError> {
+        let value = Any::new(rasn::der::encode(extensions)?);
+        let existing = self
+            .attributes
+            .to_vec()
+            .into_iter()
+            .find(|attribute| attribute.r#type == EXTENSION_REQUEST)
+            .cloned();
+        if let Some(existing) = existing {
+            self.attributes.remove(&existing);
+        }
+        self.attributes.insert(Attribute {
+            r#type: EXTENSION_REQUEST.into(),
+            values: SetOf::from([value]),
+        });
+        Ok(())
+    }
+}
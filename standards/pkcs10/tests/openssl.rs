@@ -0,0 +1,114 @@
+use pretty_assertions::assert_eq;
+use rasn::prelude::*;
+use rasn_pkcs10::*;
+use rasn_pkix::{Extension, GeneralName, SubjectAltName};
+
+fn parse(pem: &[u8]) -> (Vec<u8>, CertificationRequest) {
+    let pem = pem::parse(pem).unwrap();
+    assert_eq!(pem.tag(), "CERTIFICATE REQUEST");
+    let request = rasn::der::decode(pem.contents()).unwrap();
+    (pem.contents().to_vec(), request)
+}
+
+#[test]
+fn ec_extension_request() {
+    let (der, request) = parse(include_bytes!("data/ec-extension-request.csr"));
+    assert_eq!(der, rasn::der::encode(&request).unwrap());
+
+    let info = &request.certification_request_info;
+    assert_eq!(info.version, 0.into());
+    let Name::RdnSequence(rdns) = &info.subject;
+    assert_eq!(rdns.len(), 3);
+    assert_eq!(
+        info.subject_pk_info.algorithm.algorithm,
+        Oid::ISO_MEMBER_BODY_US_ANSI_X962_KEY_TYPE_EC_PUBLIC_KEY
+    );
+    assert_eq!(info.attributes.len(), 2);
+
+    let challenge_password = info
+        .attributes
+        .to_vec()
+        .into_iter()
+        .find(|attribute| attribute.r#type == CHALLENGE_PASSWORD)
+        .unwrap();
+    let [password] = challenge_password.values.to_vec()[..] else {
+        panic!("challengePassword must have a single value");
+    };
+    assert_eq!(
+        rasn::der::decode::<Utf8String>(password.as_bytes()).unwrap(),
+        "correct horse"
+    );
+
+    let extensions = info.extension_request().unwrap().unwrap();
+    assert_eq!(extensions.len(), 3);
+    assert_eq!(
+        extensions[0].extn_id,
+        Oid::JOINT_ISO_ITU_T_DS_CERTIFICATE_EXTENSION_SUBJECT_ALT_NAME
+    );
+    assert_eq!(
+        rasn::der::decode::<SubjectAltName>(&extensions[0].extn_value).unwrap(),
+        [
+            GeneralName::DnsName(Ia5String::try_from("device.example.com").unwrap()),
+            GeneralName::DnsName(Ia5String::try_from("www.example.com").unwrap()),
+        ]
+    );
+    assert_eq!(
+        extensions[1].extn_id,
+        Oid::JOINT_ISO_ITU_T_DS_CERTIFICATE_EXTENSION_KEY_USAGE
+    );
+    assert!(extensions[1].critical);
+    assert!(!extensions[2].critical);
+}
+
+#[test]
+fn rsa_without_attributes() {
+    let (der, request) = parse(include_bytes!("data/rsa.csr"));
+    assert_eq!(der, rasn::der::encode(&request).unwrap());
+
+    let info = &request.certification_request_info;
+    assert_eq!(
+        info.subject_pk_info.algorithm.algorithm,
+        Oid::ISO_MEMBER_BODY_US_RSADSI_PKCS1_RSA
+    );
+    assert!(info.attributes.is_empty());
+    assert_eq!(info.extension_request().unwrap(), None);
+}
+
+#[test]
+fn set_extension_request() {
+    let (_, request) = parse(include_bytes!("data/rsa.csr"));
+    let mut info = request.certification_request_info;
+
+    let key_usage = Extension {
+        extn_id: Oid::JOINT_ISO_ITU_T_DS_CERTIFICATE_EXTENSION_KEY_USAGE.into(),
+        critical: true,
+        extn_value: OctetString::from_static(&[0x03, 0x02, 0x07, 0x80]),
+    };
+    let extensions = Extensions::from(vec![key_usage.clone()]);
+    info.set_extension_request(&extensions).unwrap();
+    assert_eq!(info.extension_request().unwrap(), Some(extensions));
+
+    let mut subject_alt_name = key_usage;
+    subject_alt_name.extn_id =
+        Oid::JOINT_ISO_ITU_T_DS_CERTIFICATE_EXTENSION_SUBJECT_ALT_NAME.into();
+    subject_alt_name.critical = false;
+    subject_alt_name.extn_value = rasn::der::encode(&vec![GeneralName::DnsName(
+        Ia5String::try_from("rsa.example.com").unwrap(),
+    )])
+    .unwrap()
+    .into();
+    let extensions = Extensions::from(vec![subject_alt_name]);
+    info.set_extension_request(&extensions).unwrap();
+    assert_eq!(info.attributes.len(), 1);
+    assert_eq!(info.extension_request().unwrap(), Some(extensions));
+
+    let encoded = rasn::der::encode(&info).unwrap();
+    assert_eq!(info, rasn::der::decode(&encoded).unwrap());
+}
+
+#[test]
+fn pem() {
+    let pem = include_str!("data/rsa.csr");
+    let request: CertificationRequest = rasn::der::decode_pem(pem.as_bytes()).unwrap();
+    assert_eq!(pem, rasn::der::encode_pem(&request).unwrap());
+}
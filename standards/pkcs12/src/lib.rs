@@ -0,0 +1,274 @@
+#![doc = include_str!("../README.md")]
+#![no_std]
+
+use rasn::{
+    error::{DecodeError, EncodeError},
+    prelude::*,
+};
+
+#[doc(inline)]
+pub use rasn_cms::{ContentInfo, EncryptedData};
+#[doc(inline)]
+pub use rasn_pkcs8::{EncryptedPrivateKeyInfo, PrivateKeyInfo};
+#[doc(inline)]
+pub use rasn_pkix::{AlgorithmIdentifier, Attribute};
+
+/// The version of a [`Pfx`], which is always 3 (`v3`).
+pub type Version = Integer;
+/// A sequence of [`ContentInfo`]s, each holding [`SafeContents`] either as
+/// plain data, encrypted data or enveloped data.
+pub type AuthenticatedSafe = SequenceOf<ContentInfo>;
+/// A sequence of [`SafeBag`]s.
+pub type SafeContents = SequenceOf<SafeBag>;
+/// Additional information about the contents of a [`SafeBag`], such as the
+/// [`FRIENDLY_NAME`] and [`LOCAL_KEY_ID`] attributes.
+pub type PKCS12Attributes = SetOf<Attribute>;
+/// A [`SafeBag`] containing a private key.
+pub type KeyBag = PrivateKeyInfo;
+/// A [`SafeBag`] containing an encrypted private key.
+pub type Pkcs8ShroudedKeyBag = EncryptedPrivateKeyInfo;
+
+/// The `v3` version of a [`Pfx`].
+pub const V3: u8 = 3;
+
+/// A [`KeyBag`] bag type.
+pub const KEY_BAG: &Oid = Oid::ISO_MEMBER_BODY_US_RSADSI_PKCS12_BAG_TYPES_KEY_BAG;
+/// A [`Pkcs8ShroudedKeyBag`] bag type.
+pub const PKCS8_SHROUDED_KEY_BAG: &Oid =
+    Oid::ISO_MEMBER_BODY_US_RSADSI_PKCS12_BAG_TYPES_PKCS8_SHROUDED_KEY_BAG;
+/// A [`CertBag`] bag type.
+pub const CERT_BAG: &Oid = Oid::ISO_MEMBER_BODY_US_RSADSI_PKCS12_BAG_TYPES_CERT_BAG;
+/// A [`CrlBag`] bag type.
+pub const CRL_BAG: &Oid = Oid::ISO_MEMBER_BODY_US_RSADSI_PKCS12_BAG_TYPES_CRL_BAG;
+/// A [`SecretBag`] bag type.
+pub const SECRET_BAG: &Oid = Oid::ISO_MEMBER_BODY_US_RSADSI_PKCS12_BAG_TYPES_SECRET_BAG;
+/// A nested [`SafeContents`] bag type.
+pub const SAFE_CONTENTS_BAG: &Oid =
+    Oid::ISO_MEMBER_BODY_US_RSADSI_PKCS12_BAG_TYPES_SAFE_CONTENTS_BAG;
+
+/// A DER encoded X.509 certificate in a [`CertBag`].
+pub const X509_CERTIFICATE: &Oid = Oid::ISO_MEMBER_BODY_US_RSADSI_PKCS9_CERT_TYPES_X509_CERTIFICATE;
+/// A base64 encoded SDSI certificate in a [`CertBag`].
+pub const SDSI_CERTIFICATE: &Oid = Oid::ISO_MEMBER_BODY_US_RSADSI_PKCS9_CERT_TYPES_SDSI_CERTIFICATE;
+/// A DER encoded X.509 CRL in a [`CrlBag`].
+pub const X509_CRL: &Oid = Oid::ISO_MEMBER_BODY_US_RSADSI_PKCS9_CRL_TYPES_X509_CRL;
+
+/// Friendly Name (PKCS #9) is a `BMPString` naming the contents of a bag.
+pub const FRIENDLY_NAME: &Oid = Oid::ISO_MEMBER_BODY_US_RSADSI_PKCS9_FRIENDLY_NAME;
+/// Local Key ID (PKCS #9) is an `OCTET STRING` linking a private key to its
+/// certificate.
+pub const LOCAL_KEY_ID: &Oid = Oid::ISO_MEMBER_BODY_US_RSADSI_PKCS9_LOCAL_KEY_ID;
+
+/// Password-based encryption with SHA-1 and 128-bit RC4.
+pub const PBE_WITH_SHA_AND_128_BIT_RC4: &Oid =
+    Oid::ISO_MEMBER_BODY_US_RSADSI_PKCS12_PBE_SHA_AND_128_BIT_RC4;
+/// Password-based encryption with SHA-1 and 40-bit RC4.
+pub const PBE_WITH_SHA_AND_40_BIT_RC4: &Oid =
+    Oid::ISO_MEMBER_BODY_US_RSADSI_PKCS12_PBE_SHA_AND_40_BIT_RC4;
+/// Password-based encryption with SHA-1 and three-key triple DES in CBC mode.
+pub const PBE_WITH_SHA_AND_3_KEY_TRIPLE_DES_CBC: &Oid =
+    Oid::ISO_MEMBER_BODY_US_RSADSI_PKCS12_PBE_SHA_AND_3_KEY_TRIPLE_DES_CBC;
+/// Password-based encryption with SHA-1 and two-key triple DES in CBC mode.
+pub const PBE_WITH_SHA_AND_2_KEY_TRIPLE_DES_CBC: &Oid =
+    Oid::ISO_MEMBER_BODY_US_RSADSI_PKCS12_PBE_SHA_AND_2_KEY_TRIPLE_DES_CBC;
+/// Password-based encryption with SHA-1 and 128-bit RC2 in CBC mode.
+pub const PBE_WITH_SHA_AND_128_BIT_RC2_CBC: &Oid =
+    Oid::ISO_MEMBER_BODY_US_RSADSI_PKCS12_PBE_SHA_AND_128_BIT_RC2_CBC;
+/// Password-based encryption with SHA-1 and 40-bit RC2 in CBC mode.
+pub const PBE_WITH_SHA_AND_40_BIT_RC2_CBC: &Oid =
+    Oid::ISO_MEMBER_BODY_US_RSADSI_PKCS12_PBE_SHA_AND_40_BIT_RC2_CBC;
+
+/// A PKCS #12 Protocol Data Unit, holding the keys, certificates and other
+/// secrets of an entity.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+pub struct Pfx {
+    /// The version of the PDU, see [`V3`].
+    pub version: Version,
+    /// The [`AuthenticatedSafe`], as `data` content when it's protected by
+    /// `mac_data`, or as `signedData` content otherwise.
+    pub auth_safe: ContentInfo,
+    /// The MAC protecting the integrity of `auth_safe`, when in password
+    /// integrity mode.
+    pub mac_data: Option<MacData>,
+}
+
+impl Pfx {
+    /// Returns the [`AuthenticatedSafe`] of the PDU, or `None` if it isn't
+    /// `data` content (e.g. it's signed in public-key integrity mode).
+    ///
+    /// # Errors
+    /// Returns an error if the content isn't an `OCTET STRING` containing an
+    /// encoded [`AuthenticatedSafe`].
+    pub fn authenticated_safe(&self) -> Result<Option<AuthenticatedSafe>, DecodeError> {
+        decode_data(&self.auth_safe)
+    }
+}
+
+/// The MAC of the [`AuthenticatedSafe`] of a [`Pfx`].
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+pub struct MacData {
+    /// The digest algorithm and MAC value.
+    pub mac: DigestInfo,
+    /// The salt used to derive the MAC key from the password.
+    pub mac_salt: OctetString,
+    /// The iteration count used to derive the MAC key from the password.
+    #[rasn(default = "default_iterations")]
+    pub iterations: Integer,
+}
+
+fn default_iterations() -> Integer {
+    1.into()
+}
+
+/// A digest, along with the algorithm used to compute it.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+pub struct DigestInfo {
+    /// The algorithm used to compute the digest.
+    pub digest_algorithm: AlgorithmIdentifier,
+    /// The digest value.
+    pub digest: OctetString,
+}
+
+/// A bag of a key, certificate, CRL or other secret.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+pub struct SafeBag {
+    /// The type of the bag, identifying `bag_value`.
+    pub bag_id: ObjectIdentifier,
+    /// The contents of the bag.
+    #[rasn(tag(explicit(0)), table(bag_id))]
+    pub bag_value: BagValue,
+    /// Additional information about the contents of the bag.
+    pub bag_attributes: Option<PKCS12Attributes>,
+}
+
+impl SafeBag {
+    /// Returns the values of the attribute of type `r#type`, or `None` if the
+    /// bag doesn't have it.
+    pub fn attribute(&self, r#type: &Oid) -> Option<&SetOf<Any>> {
+        self.bag_attributes
+            .as_ref()?
+            .to_vec()
+            .into_iter()
+            .find(|attribute| attribute.r#type == r#type)
+            .map(|attribute| &attribute.values)
+    }
+}
+
+/// The contents of a [`SafeBag`], identified by its `bag_id`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, InformationObjectSet)]
+#[rasn(id_type = ObjectIdentifier)]
+pub enum BagValue {
+    /// A [`KEY_BAG`].
+    #[rasn(id = KEY_BAG)]
+    Key(KeyBag),
+    /// A [`PKCS8_SHROUDED_KEY_BAG`].
+    #[rasn(id = PKCS8_SHROUDED_KEY_BAG)]
+    ShroudedKey(Pkcs8ShroudedKeyBag),
+    /// A [`CERT_BAG`].
+    #[rasn(id = CERT_BAG)]
+    Cert(CertBag),
+    /// A [`CRL_BAG`].
+    #[rasn(id = CRL_BAG)]
+    Crl(CrlBag),
+    /// A [`SECRET_BAG`].
+    #[rasn(id = SECRET_BAG)]
+    Secret(SecretBag),
+    /// A [`SAFE_CONTENTS_BAG`].
+    #[rasn(id = SAFE_CONTENTS_BAG)]
+    SafeContents(SafeContents),
+    /// A bag of an unknown type.
+    Unknown(Any),
+}
+
+/// A bag containing a certificate.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+pub struct CertBag {
+    /// The type of the certificate, identifying `cert_value`.
+    pub cert_id: ObjectIdentifier,
+    /// The certificate.
+    #[rasn(tag(explicit(0)), table(cert_id))]
+    pub cert_value: CertValue,
+}
+
+/// The certificate in a [`CertBag`], identified by its `cert_id`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, InformationObjectSet)]
+#[rasn(id_type = ObjectIdentifier)]
+pub enum CertValue {
+    /// An [`X509_CERTIFICATE`], containing a DER encoded certificate.
+    #[rasn(id = X509_CERTIFICATE)]
+    X509(OctetString),
+    /// A [`SDSI_CERTIFICATE`], containing a base64 encoded certificate.
+    #[rasn(id = SDSI_CERTIFICATE)]
+    Sdsi(Ia5String),
+    /// A certificate of an unknown type.
+    Unknown(Any),
+}
+
+/// A bag containing a certificate revocation list.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+pub struct CrlBag {
+    /// The type of the CRL, identifying `crl_value`.
+    pub crl_id: ObjectIdentifier,
+    /// The CRL.
+    #[rasn(tag(explicit(0)), table(crl_id))]
+    pub crl_value: CrlValue,
+}
+
+/// The CRL in a [`CrlBag`], identified by its `crl_id`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, InformationObjectSet)]
+#[rasn(id_type = ObjectIdentifier)]
+pub enum CrlValue {
+    /// An [`X509_CRL`], containing a DER encoded CRL.
+    #[rasn(id = X509_CRL)]
+    X509(OctetString),
+    /// A CRL of an unknown type.
+    Unknown(Any),
+}
+
+/// A bag containing a secret of any type.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+pub struct SecretBag {
+    /// The type of the secret.
+    pub secret_type_id: ObjectIdentifier,
+    /// The secret.
+    #[rasn(tag(explicit(0)))]
+    pub secret_value: Any,
+}
+
+/// The parameters of the PKCS #12 password-based encryption algorithms, such
+/// as [`PBE_WITH_SHA_AND_3_KEY_TRIPLE_DES_CBC`].
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+pub struct Pkcs12PbeParams {
+    /// The salt used to derive the key from the password.
+    pub salt: OctetString,
+    /// The iteration count used to derive the key from the password.
+    pub iterations: Integer,
+}
+
+/// Decodes the contents of a `data` [`ContentInfo`], such as the
+/// [`SafeContents`] in an [`AuthenticatedSafe`], or returns `None` if it
+/// holds another content type.
+///
+/// # Errors
+/// Returns an error if the content isn't an `OCTET STRING` containing a BER
+/// encoded `T`.
+pub fn decode_data<T: Decode>(content_info: &ContentInfo) -> Result<Option<T>, DecodeError> {
+    if content_info.content_type != rasn_cms::CONTENT_DATA {
+        return Ok(None);
+    }
+
+    let data: OctetString = rasn::ber::decode(content_info.content.as_bytes())?;
+    rasn::ber::decode(&data).map(Some)
+}
+
+/// Encodes `value` as the contents of a `data` [`ContentInfo`].
+///
+/// # Errors
+/// Returns an error if `value` fails to be DER encoded.
+pub fn encode_data<T: Encode>(value: &T) -> Result<ContentInfo, EncodeError> {
+    let data = OctetString::from(rasn::der::encode(value)?);
+    Ok(ContentInfo {
+        content_type: rasn_cms::CONTENT_DATA.into(),
+        content: Any::new(rasn::der::encode(&data)?),
+    })
+}
@@ -0,0 +1,150 @@
+use pretty_assertions::assert_eq;
+use rasn::prelude::*;
+use rasn_cms::algorithms::{Pbes2Parameters, AES256_CBC, PBES2};
+use rasn_pkcs12::*;
+use rasn_pkix::Certificate;
+
+fn safe_contents(pfx: &Pfx) -> Vec<ContentInfo> {
+    let safe = pfx.authenticated_safe().unwrap().unwrap();
+    assert_eq!(safe.len(), 2);
+    safe
+}
+
+fn friendly_name(bag: &SafeBag) -> BmpString {
+    let [name] = &bag.attribute(FRIENDLY_NAME).unwrap().to_vec()[..] else {
+        panic!("friendlyName must have a single value");
+    };
+    rasn::der::decode(name.as_bytes()).unwrap()
+}
+
+fn device_key() -> Vec<u8> {
+    "device key"
+        .encode_utf16()
+        .flat_map(u16::to_be_bytes)
+        .collect()
+}
+
+fn local_key_id(bag: &SafeBag) -> OctetString {
+    let [id] = &bag.attribute(LOCAL_KEY_ID).unwrap().to_vec()[..] else {
+        panic!("localKeyId must have a single value");
+    };
+    rasn::der::decode(id.as_bytes()).unwrap()
+}
+
+#[test]
+fn unencrypted() {
+    let der = include_bytes!("data/unencrypted.p12");
+    let pfx: Pfx = rasn::der::decode(der).unwrap();
+    assert_eq!(&der[..], rasn::der::encode(&pfx).unwrap());
+
+    assert_eq!(pfx.version, V3.into());
+    let mac_data = pfx.mac_data.as_ref().unwrap();
+    assert_eq!(
+        mac_data.mac.digest_algorithm.algorithm,
+        Oid::JOINT_ISO_ITU_T_COUNTRY_US_ORGANIZATION_GOV_CSOR_NIST_ALGORITHMS_HASH_SHA256
+    );
+    assert_eq!(mac_data.mac_salt.len(), 8);
+    assert_eq!(mac_data.iterations, 2048.into());
+
+    let safe = safe_contents(&pfx);
+    let certs: SafeContents = decode_data(&safe[0]).unwrap().unwrap();
+    let [cert_bag] = &certs[..] else {
+        panic!("expected a single certificate bag");
+    };
+    assert_eq!(cert_bag.bag_id, CERT_BAG);
+    let BagValue::Cert(CertBag {
+        cert_id,
+        cert_value: CertValue::X509(cert),
+    }) = &cert_bag.bag_value
+    else {
+        panic!("expected an X.509 certificate bag");
+    };
+    assert_eq!(cert_id, X509_CERTIFICATE);
+    let cert: Certificate = rasn::der::decode(cert).unwrap();
+    // The certificate is self-signed.
+    assert_eq!(cert.tbs_certificate.subject, cert.tbs_certificate.issuer);
+    assert_eq!(friendly_name(cert_bag).to_bytes(), device_key());
+
+    let keys: SafeContents = decode_data(&safe[1]).unwrap().unwrap();
+    let [key_bag] = &keys[..] else {
+        panic!("expected a single key bag");
+    };
+    let BagValue::Key(key) = &key_bag.bag_value else {
+        panic!("expected a key bag");
+    };
+    assert_eq!(
+        key.private_key_algorithm.algorithm,
+        Oid::ISO_MEMBER_BODY_US_ANSI_X962_KEY_TYPE_EC_PUBLIC_KEY
+    );
+    assert_eq!(friendly_name(key_bag).to_bytes(), device_key());
+    assert_eq!(local_key_id(key_bag), local_key_id(cert_bag));
+
+    // Re-encoding the decoded contents produces the same PDU.
+    let rebuilt = Pfx {
+        auth_safe: encode_data(&vec![
+            encode_data(&certs).unwrap(),
+            encode_data(&keys).unwrap(),
+        ])
+        .unwrap(),
+        ..pfx.clone()
+    };
+    assert_eq!(rebuilt, pfx);
+}
+
+#[test]
+fn pbes2_encrypted() {
+    let der = include_bytes!("data/pbes2.p12");
+    let pfx: Pfx = rasn::der::decode(der).unwrap();
+    assert_eq!(&der[..], rasn::der::encode(&pfx).unwrap());
+
+    let safe = safe_contents(&pfx);
+    assert_eq!(safe[0].content_type, rasn_cms::CONTENT_ENCRYPTED_DATA);
+    assert_eq!(decode_data::<SafeContents>(&safe[0]).unwrap(), None);
+    let encrypted: EncryptedData = rasn::der::decode(safe[0].content.as_bytes()).unwrap();
+    let content = &encrypted.encrypted_content_info;
+    assert_eq!(content.content_type, rasn_cms::CONTENT_DATA);
+    assert_eq!(content.content_encryption_algorithm.algorithm, PBES2);
+
+    let keys: SafeContents = decode_data(&safe[1]).unwrap().unwrap();
+    let [key_bag] = &keys[..] else {
+        panic!("expected a single key bag");
+    };
+    assert_eq!(key_bag.bag_id, PKCS8_SHROUDED_KEY_BAG);
+    let BagValue::ShroudedKey(key) = &key_bag.bag_value else {
+        panic!("expected a shrouded key bag");
+    };
+    assert_eq!(key.encryption_algorithm.algorithm, PBES2);
+    let parameters: Pbes2Parameters = rasn::der::decode(
+        key.encryption_algorithm
+            .parameters
+            .as_ref()
+            .unwrap()
+            .as_bytes(),
+    )
+    .unwrap();
+    assert_eq!(parameters.encryption_scheme.algorithm, AES256_CBC);
+    assert_eq!(friendly_name(key_bag).to_bytes(), device_key());
+}
+
+#[test]
+fn secret_bag() {
+    let bag = SafeBag {
+        bag_id: SECRET_BAG.into(),
+        bag_value: BagValue::Secret(SecretBag {
+            secret_type_id: Oid::ISO_MEMBER_BODY_US_RSADSI_PKCS7_DATA.into(),
+            secret_value: Any::new(
+                rasn::der::encode(&OctetString::from_static(b"s3cr3t")).unwrap(),
+            ),
+        }),
+        bag_attributes: None,
+    };
+    let encoded = rasn::der::encode(&bag).unwrap();
+    assert_eq!(bag, rasn::der::decode(&encoded).unwrap());
+    assert_eq!(bag.attribute(FRIENDLY_NAME), None);
+
+    let mismatched = SafeBag {
+        bag_id: KEY_BAG.into(),
+        ..bag
+    };
+    assert!(rasn::der::encode(&mismatched).is_err());
+}
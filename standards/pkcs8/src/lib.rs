@@ -0,0 +1,91 @@
+#![doc = include_str!("../README.md")]
+#![no_std]
+
+use rasn::prelude::*;
+
+#[doc(inline)]
+pub use rasn_pkix::{AlgorithmIdentifier, Attribute};
+
+/// The version of a [`OneAsymmetricKey`], which is 0 (`v1`) when it doesn't
+/// contain a public key, and 1 (`v2`) otherwise.
+pub type Version = Integer;
+/// The PKCS #8 name of [`OneAsymmetricKey`], which is the same structure.
+pub type PrivateKeyInfo = OneAsymmetricKey;
+/// The algorithm the private key is used with, and its parameters.
+pub type PrivateKeyAlgorithmIdentifier = AlgorithmIdentifier;
+/// The encoded private key, with an algorithm-specific format.
+pub type PrivateKey = OctetString;
+/// The encoded public key, with an algorithm-specific format.
+pub type PublicKey = BitString;
+/// Additional information about the key, such as its PKCS #9 friendly name.
+pub type Attributes = SetOf<Attribute>;
+/// The algorithm used to encrypt a private key, and its parameters.
+pub type EncryptionAlgorithmIdentifier = AlgorithmIdentifier;
+/// The encrypted encoding of a [`PrivateKeyInfo`].
+pub type EncryptedData = OctetString;
+/// A collection of one or more asymmetric keys.
+pub type AsymmetricKeyPackage = SequenceOf<OneAsymmetricKey>;
+
+/// The `v1` version of a [`OneAsymmetricKey`], without a public key.
+pub const V1: u8 = 0;
+/// The `v2` version of a [`OneAsymmetricKey`], with a public key.
+pub const V2: u8 = 1;
+
+/// An asymmetric private key, and optionally its public key.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct OneAsymmetricKey {
+    /// The version of the structure, see [`V1`] and [`V2`].
+    pub version: Version,
+    /// The algorithm the key is used with.
+    pub private_key_algorithm: PrivateKeyAlgorithmIdentifier,
+    /// The encoded private key.
+    pub private_key: PrivateKey,
+    /// Additional information about the key.
+    #[rasn(tag(0))]
+    pub attributes: Option<Attributes>,
+    /// The encoded public key, only present in `v2` keys.
+    #[rasn(tag(1), extension_addition)]
+    pub public_key: Option<PublicKey>,
+}
+
+impl OneAsymmetricKey {
+    /// Creates a `v1` key from its algorithm and encoded private key.
+    pub fn new(
+        private_key_algorithm: PrivateKeyAlgorithmIdentifier,
+        private_key: PrivateKey,
+    ) -> Self {
+        Self {
+            version: V1.into(),
+            private_key_algorithm,
+            private_key,
+            attributes: None,
+            public_key: None,
+        }
+    }
+
+    /// Sets the public key, upgrading the key to `v2`.
+    pub fn with_public_key(mut self, public_key: PublicKey) -> Self {
+        self.version = V2.into();
+        self.public_key = Some(public_key);
+        self
+    }
+}
+
+impl rasn::pem::PemLabel for OneAsymmetricKey {
+    const LABEL: &'static str = "PRIVATE KEY";
+}
+
+/// A [`PrivateKeyInfo`] encrypted with a password based or symmetric
+/// encryption scheme, such as PBES2.
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+pub struct EncryptedPrivateKeyInfo {
+    /// The algorithm used to encrypt the key.
+    pub encryption_algorithm: EncryptionAlgorithmIdentifier,
+    /// The encrypted DER encoding of a [`PrivateKeyInfo`].
+    pub encrypted_data: EncryptedData,
+}
+
+impl rasn::pem::PemLabel for EncryptedPrivateKeyInfo {
+    const LABEL: &'static str = "ENCRYPTED PRIVATE KEY";
+}
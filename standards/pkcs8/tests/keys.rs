@@ -0,0 +1,106 @@
+use pretty_assertions::assert_eq;
+use rasn::prelude::*;
+use rasn_cms::algorithms::{Pbes2Parameters, Pbkdf2Parameters, Pbkdf2Salt};
+use rasn_pkcs8::*;
+
+const ED25519: &Oid = Oid::const_new(&[1, 3, 101, 112]);
+
+fn parse(pem: &[u8], tag: &str) -> Vec<u8> {
+    let pem = pem::parse(pem).unwrap();
+    assert_eq!(pem.tag(), tag);
+    pem.into_contents()
+}
+
+#[test]
+fn ed25519_private_key_info() {
+    let der = parse(include_bytes!("data/ed25519.pem"), "PRIVATE KEY");
+    let key: PrivateKeyInfo = rasn::der::decode(&der).unwrap();
+    assert_eq!(der, rasn::der::encode(&key).unwrap());
+
+    assert_eq!(key.version, V1.into());
+    assert_eq!(key.private_key_algorithm.algorithm, ED25519);
+    assert_eq!(key.private_key_algorithm.parameters, None);
+    assert_eq!(key.attributes, None);
+    assert_eq!(key.public_key, None);
+    let private_key: OctetString = rasn::der::decode(&key.private_key).unwrap();
+    assert_eq!(private_key.len(), 32);
+}
+
+#[test]
+fn pem_round_trip() {
+    let pem = include_str!("data/ed25519.pem");
+    let key: PrivateKeyInfo = rasn::der::decode_pem(pem.as_bytes()).unwrap();
+    assert_eq!(pem, rasn::der::encode_pem(&key).unwrap());
+
+    let pem = include_str!("data/ed25519-encrypted.pem");
+    let key: EncryptedPrivateKeyInfo = rasn::der::decode_pem(pem.as_bytes()).unwrap();
+    assert_eq!(pem, rasn::der::encode_pem(&key).unwrap());
+    assert!(rasn::der::decode_pem::<PrivateKeyInfo>(pem.as_bytes()).is_err());
+}
+
+#[test]
+fn rfc8410_one_asymmetric_key() {
+    let der = parse(include_bytes!("data/rfc8410.pem"), "PRIVATE KEY");
+    let key: OneAsymmetricKey = rasn::der::decode(&der).unwrap();
+    assert_eq!(der, rasn::der::encode(&key).unwrap());
+
+    assert_eq!(key.version, V2.into());
+    assert_eq!(key.private_key_algorithm.algorithm, ED25519);
+    let attributes = key.attributes.as_ref().unwrap().to_vec();
+    let [attribute] = &attributes[..] else {
+        panic!("expected a single attribute");
+    };
+    let [value] = &attribute.values.to_vec()[..] else {
+        panic!("expected a single attribute value");
+    };
+    assert_eq!(
+        rasn::der::decode::<Utf8String>(value.as_bytes()).unwrap(),
+        "Curdle Chairs"
+    );
+    assert_eq!(key.public_key.as_ref().unwrap().len(), 32 * 8);
+
+    let mut rebuilt =
+        OneAsymmetricKey::new(key.private_key_algorithm.clone(), key.private_key.clone())
+            .with_public_key(key.public_key.clone().unwrap());
+    rebuilt.attributes = key.attributes.clone();
+    assert_eq!(rebuilt, key);
+}
+
+#[test]
+fn pbes2_encrypted_private_key_info() {
+    let der = parse(
+        include_bytes!("data/ed25519-encrypted.pem"),
+        "ENCRYPTED PRIVATE KEY",
+    );
+    let key: EncryptedPrivateKeyInfo = rasn::der::decode(&der).unwrap();
+    assert_eq!(der, rasn::der::encode(&key).unwrap());
+
+    let algorithm = &key.encryption_algorithm;
+    assert_eq!(algorithm.algorithm, rasn_cms::algorithms::PBES2);
+    let parameters: Pbes2Parameters =
+        rasn::der::decode(algorithm.parameters.as_ref().unwrap().as_bytes()).unwrap();
+    assert_eq!(
+        parameters.key_derivation_func.algorithm,
+        rasn_cms::algorithms::PBKDF2
+    );
+    assert_eq!(
+        parameters.encryption_scheme.algorithm,
+        rasn_cms::algorithms::AES256_CBC
+    );
+
+    let kdf: Pbkdf2Parameters = rasn::der::decode(
+        parameters
+            .key_derivation_func
+            .parameters
+            .as_ref()
+            .unwrap()
+            .as_bytes(),
+    )
+    .unwrap();
+    let Pbkdf2Salt::Specified(salt) = kdf.salt else {
+        panic!("expected a specified salt");
+    };
+    assert_eq!(salt.len(), 16);
+    assert_eq!(kdf.iteration_count, 2048.into());
+    assert_eq!(key.encrypted_data.len(), 64);
+}
@@ -94,6 +94,10 @@ pub struct Certificate {
     pub signature_value: BitString,
 }
 
+impl rasn::pem::PemLabel for Certificate {
+    const LABEL: &'static str = "CERTIFICATE";
+}
+
 /// Information associated with the subject of the certificate and the CA that
 /// issued it.
 #[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
@@ -339,6 +343,10 @@ pub struct SubjectPublicKeyInfo {
     pub subject_public_key: BitString,
 }
 
+impl rasn::pem::PemLabel for SubjectPublicKeyInfo {
+    const LABEL: &'static str = "PUBLIC KEY";
+}
+
 /// Identifying the public key corresponding to the private key used to sign a
 /// certificate.
 ///
@@ -373,6 +381,10 @@ pub struct CertificateList {
     pub signature: BitString,
 }
 
+impl rasn::pem::PemLabel for CertificateList {
+    const LABEL: &'static str = "X509 CRL";
+}
+
 /// The list of revoked certificates along with associated metadata.
 #[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
 pub struct TbsCertList {
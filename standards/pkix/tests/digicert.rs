@@ -3,6 +3,8 @@ use pretty_assertions::assert_eq;
 use rasn::prelude::*;
 use rasn_pkix::*;
 
+const DIGICERT_PEM: &str = include_str!("data/DigiCertAssuredIDTLSCA.crt.pem");
+
 #[test]
 fn it_works() {
     let contents = pem::parse(include_bytes!("data/DigiCertAssuredIDTLSCA.crt.pem")).unwrap();
@@ -12,6 +14,32 @@ fn it_works() {
     assert_eq!(contents.contents(), rasn::der::encode(&cert).unwrap());
 }
 
+#[test]
+fn pem() {
+    let cert: Certificate = rasn::der::decode_pem(DIGICERT_PEM.as_bytes()).unwrap();
+    assert_eq!(DIGICERT_PEM, rasn::der::encode_pem(&cert).unwrap());
+
+    let letsencrypt: Certificate =
+        rasn::der::decode(include_bytes!("data/letsencrypt-x3.crt")).unwrap();
+    let bundle =
+        rasn::der::encode_pem(&cert).unwrap() + &rasn::der::encode_pem(&letsencrypt).unwrap();
+    assert_eq!(
+        rasn::der::decode_pem_bundle::<Certificate>(bundle.as_bytes()).unwrap(),
+        [cert.clone(), letsencrypt]
+    );
+
+    let error = rasn::der::decode_pem::<CertificateList>(DIGICERT_PEM.as_bytes()).unwrap_err();
+    assert!(matches!(
+        *error.kind,
+        rasn::error::DecodeErrorKind::Pem {
+            source: rasn::pem::PemError::UnexpectedLabel {
+                expected: "X509 CRL",
+                ..
+            }
+        }
+    ));
+}
+
 #[test]
 fn extensions() {
     let basic_usage = rasn::der::encode_scope(|encoder| {
@@ -427,3 +455,15 @@ fn lets_encrypt_x3() {
 
     assert_eq!(original_data, rasn::der::encode(&cert).unwrap(),);
 }
+
+#[test]
+fn jer() {
+    let mut cert: Certificate =
+        rasn::der::decode(include_bytes!("data/letsencrypt-x3.crt")).unwrap();
+    // JER only supports integers which fit in an `i64`.
+    cert.tbs_certificate.serial_number = 1.into();
+    let json = rasn::jer::encode(&cert).unwrap();
+
+    // `Any` values decoded from DER are encoded as octet strings.
+    assert!(json.contains(r#""parameters":"0500""#), "{json}");
+}
@@ -0,0 +1,86 @@
+#![cfg(feature = "proptest")]
+
+use proptest::prelude::*;
+use rasn::{arbitrary::strategy, prelude::*};
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+#[rasn(automatic_tags, arbitrary)]
+#[non_exhaustive]
+struct Message {
+    flag: bool,
+    #[rasn(value("-10..=10"))]
+    small: i8,
+    port: u16,
+    number: Integer,
+    range: ConstrainedInteger<-5, 5>,
+    #[rasn(value("0..=10", extensible))]
+    extensible: Integer,
+    #[rasn(size("1..=4"))]
+    octets: OctetString,
+    fixed: FixedOctetString<3>,
+    #[rasn(size("0..=12", extensible))]
+    bits: BitString,
+    #[rasn(size("1..=8"))]
+    name: VisibleString,
+    printable: PrintableString,
+    visible: Option<VisibleString>,
+    numeric: NumericString,
+    oid: ObjectIdentifier,
+    created: UtcTime,
+    modified: GeneralizedTime,
+    #[rasn(size("0..=3"))]
+    entries: SequenceOf<Entry>,
+    tags: SetOf<Integer>,
+    status: Status,
+    payload: Payload,
+    #[rasn(extension_addition)]
+    code: Option<Code>,
+}
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+#[rasn(automatic_tags, arbitrary)]
+struct Entry {
+    key: Code,
+    value: Option<u32>,
+}
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Eq, Hash)]
+#[rasn(delegate, size("2..=4"), arbitrary)]
+struct Code(PrintableString);
+
+#[derive(AsnType, Clone, Copy, Debug, Decode, Encode, PartialEq)]
+#[rasn(enumerated, arbitrary)]
+#[non_exhaustive]
+enum Status {
+    Active = 0,
+    Inactive = 1,
+    #[rasn(extension_addition)]
+    Unknown = 2,
+}
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+#[rasn(choice, automatic_tags, arbitrary)]
+#[non_exhaustive]
+enum Payload {
+    Empty(()),
+    Reading(#[rasn(value("0..=1000"))] u32),
+    Nested(Box<Entry>),
+    #[rasn(extension_addition)]
+    Note(VisibleString),
+}
+
+macro_rules! round_trip {
+    ($($codec:ident),+ $(,)?) => {
+        proptest! {
+            $(
+                #[test]
+                fn $codec(value in strategy::<Message>()) {
+                    let encoded = rasn::$codec::encode(&value).unwrap();
+                    prop_assert_eq!(value, rasn::$codec::decode::<Message>(&encoded).unwrap());
+                }
+            )+
+        }
+    };
+}
+
+round_trip!(ber, cer, der, aper, uper, oer, coer, jer, xer);
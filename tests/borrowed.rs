@@ -0,0 +1,96 @@
+use rasn::prelude::*;
+
+#[derive(AsnType, Debug, DecodeBorrowed, Encode, PartialEq)]
+struct Record<'a> {
+    serial: Integer,
+    issuer: BorrowedPrintableString<'a>,
+    #[rasn(tag(explicit(0)))]
+    subject: BorrowedUtf8String<'a>,
+    #[rasn(tag(1))]
+    email: Option<BorrowedIa5String<'a>>,
+    key: BorrowedOctetString<'a>,
+}
+
+#[derive(AsnType, Debug, DecodeBorrowed, Encode, PartialEq)]
+#[rasn(delegate)]
+struct Name<'a>(BorrowedUtf8String<'a>);
+
+fn record() -> Record<'static> {
+    Record {
+        serial: 7.into(),
+        issuer: BorrowedPrintableString::new("Example CA").unwrap(),
+        subject: "Jörg".into(),
+        email: Some(BorrowedIa5String::new("jorg@example.com").unwrap()),
+        key: BorrowedOctetString::new(&[0xDE, 0xAD, 0xBE, 0xEF][..]),
+    }
+}
+
+fn assert_borrowed(record: &Record<'_>) {
+    assert!(record.issuer.is_borrowed());
+    assert!(record.subject.is_borrowed());
+    assert!(record.email.as_ref().unwrap().is_borrowed());
+    assert!(record.key.is_borrowed());
+}
+
+#[test]
+fn ber() {
+    let encoded = rasn::ber::encode(&record()).unwrap();
+    let decoded: Record = rasn::ber::decode_borrowed(&encoded).unwrap();
+    assert_eq!(record(), decoded);
+    assert_borrowed(&decoded);
+
+    let encoded = rasn::der::encode(&record()).unwrap();
+    let decoded: Record = rasn::der::decode_borrowed(&encoded).unwrap();
+    assert_eq!(record(), decoded);
+    assert_borrowed(&decoded);
+}
+
+#[test]
+fn oer() {
+    let encoded = rasn::oer::encode(&record()).unwrap();
+    let decoded: Record = rasn::oer::decode_borrowed(&encoded).unwrap();
+    assert_eq!(record(), decoded);
+    assert_borrowed(&decoded);
+
+    let mut without_email = record();
+    without_email.email = None;
+    let encoded = rasn::coer::encode(&without_email).unwrap();
+    let decoded: Record = rasn::coer::decode_borrowed(&encoded).unwrap();
+    assert_eq!(without_email, decoded);
+}
+
+#[test]
+fn constructed_strings_are_owned() {
+    let encoded = [
+        0x24, 0x80, 0x04, 0x02, 0xAA, 0xBB, 0x04, 0x01, 0xCC, 0x00, 0x00,
+    ];
+    let decoded: BorrowedOctetString = rasn::ber::decode_borrowed(&encoded).unwrap();
+    assert_eq!(&[0xAA, 0xBB, 0xCC], decoded.as_bytes());
+    assert!(!decoded.is_borrowed());
+
+    assert!(rasn::der::decode_borrowed::<BorrowedOctetString>(&encoded).is_err());
+}
+
+#[test]
+fn delegate() {
+    let encoded = rasn::ber::encode(&Name("Jörg".into())).unwrap();
+    let decoded: Name = rasn::ber::decode_borrowed(&encoded).unwrap();
+    assert_eq!("Jörg", decoded.0.as_str());
+    assert!(decoded.0.is_borrowed());
+}
+
+#[test]
+fn permitted_alphabet() {
+    assert!(BorrowedPrintableString::new("user@example.com").is_err());
+    assert!(rasn::ber::decode_borrowed::<BorrowedPrintableString>(&[0x13, 0x01, b'@']).is_err());
+    assert!(rasn::ber::decode_borrowed::<BorrowedUtf8String>(&[0x0C, 0x01, 0xFF]).is_err());
+}
+
+#[test]
+fn owned_types() {
+    let encoded = rasn::ber::encode(&Integer::from(42)).unwrap();
+    assert_eq!(
+        Integer::from(42),
+        rasn::ber::decode_borrowed::<Integer>(&encoded).unwrap()
+    );
+}
@@ -320,3 +320,106 @@ fn test_full_encode_decode_paths() {
     let enc1 = <u32 as CustomCodec>::encode(&inst.key).unwrap();
     let _dec1 = <u32 as CustomCodec>::decode(&enc1).unwrap();
 }
+
+#[test]
+fn non_contiguous_constraints() {
+    use rasn::types::constraints::{Bounded, Value};
+
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq, Validate)]
+    #[rasn(automatic_tags)]
+    struct Packet {
+        #[rasn(value("1..=5 | 10..=20"))]
+        channel: u8,
+        #[rasn(size("8", "16", "32"))]
+        key: OctetString,
+        #[rasn(value("(0..=100 EXCEPT 50) ^ ..=60"))]
+        level: i32,
+        #[rasn(from("a..=z EXCEPT q"))]
+        label: Ia5String,
+    }
+
+    const VALUE: Value = Value::new(Bounded::const_new(1, 20))
+        .with_set(&[Bounded::const_new(1, 5), Bounded::const_new(10, 20)]);
+    assert_eq!(VALUE.to_string(), "1..5 | 10..20");
+    assert!(VALUE.permits(&4) && !VALUE.permits(&7) && VALUE.permits(&20));
+
+    let packet = Packet {
+        channel: 12,
+        key: vec![0; 16].into(),
+        level: 49,
+        label: Ia5String::try_from("abc").unwrap(),
+    };
+    assert!(packet.validate().is_ok());
+    assert_eq!(
+        packet,
+        rasn::uper::decode(&rasn::uper::encode(&packet).unwrap()).unwrap()
+    );
+
+    // The effective constraints used by PER still permit values outside of
+    // the exact sets.
+    let packet = Packet {
+        channel: 7,
+        key: vec![0; 12].into(),
+        level: 50,
+        label: Ia5String::try_from("qa").unwrap(),
+    };
+    assert!(rasn::uper::encode(&packet).is_ok());
+    let error = packet.validate().unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "4 constraint violation(s): channel: Value 7 is not in 1..5 | 10..20; \
+         key: Size 12 is not in 8 | 16 | 32; level: Value 50 is not in 0..49 | 51..60; \
+         label: Character with decimal value 113 is not in the permitted alphabet"
+    );
+}
+
+#[test]
+fn intersected_non_contiguous_constraints() {
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq, Validate)]
+    #[rasn(delegate, value("1..=5 | 10..=20"))]
+    struct Channel(u8);
+
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq, Validate)]
+    #[rasn(automatic_tags)]
+    struct Tuner {
+        #[rasn(value("0 | 4..=12"))]
+        channel: Channel,
+    }
+
+    for (channel, valid) in [(4, true), (11, true), (1, false), (7, false), (13, false)] {
+        let tuner = Tuner {
+            channel: Channel(channel),
+        };
+        assert_eq!(tuner.validate().is_ok(), valid, "{channel}");
+    }
+    assert_eq!(
+        Tuner {
+            channel: Channel(7)
+        }
+        .validate()
+        .unwrap_err()
+        .to_string(),
+        "1 constraint violation(s): channel: Value 7 is not in 4..5 | 10..12"
+    );
+}
+
+#[test]
+fn constrained_set_fields() {
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+    #[rasn(set, automatic_tags)]
+    struct Settings {
+        #[rasn(value("0..=9"))]
+        level: u8,
+        #[rasn(from("a..=z"), size("1..=4"))]
+        name: Ia5String,
+    }
+
+    let settings = Settings {
+        level: 9,
+        name: Ia5String::try_from("abc").unwrap(),
+    };
+    assert_eq!(
+        settings,
+        rasn::uper::decode(&rasn::uper::encode(&settings).unwrap()).unwrap()
+    );
+}
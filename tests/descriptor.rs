@@ -0,0 +1,254 @@
+use rasn::prelude::*;
+use rasn::types::value::{NamedValue, Value};
+
+#[derive(AsnType, Debug, Decode, Encode, Describe, PartialEq)]
+#[rasn(automatic_tags)]
+#[non_exhaustive]
+struct Message {
+    #[rasn(value("0..=1000"))]
+    id: u16,
+    kind: Kind,
+    payload: Payload,
+    note: Option<Utf8String>,
+    tags: Vec<Ia5String>,
+    #[rasn(extension_addition)]
+    priority: Option<u8>,
+}
+
+#[derive(AsnType, Clone, Copy, Debug, Decode, Encode, Describe, PartialEq)]
+#[rasn(enumerated)]
+#[non_exhaustive]
+enum Kind {
+    Request,
+    Response,
+    #[rasn(extension_addition)]
+    Notification,
+}
+
+#[derive(AsnType, Debug, Decode, Encode, Describe, PartialEq)]
+#[rasn(choice, automatic_tags)]
+#[non_exhaustive]
+enum Payload {
+    Text(Utf8String),
+    Number(Integer),
+    #[rasn(extension_addition)]
+    Blob(OctetString),
+}
+
+#[derive(AsnType, Debug, Decode, Encode, Describe, PartialEq)]
+#[rasn(set)]
+struct Pair {
+    #[rasn(tag(context, 1))]
+    second: bool,
+    #[rasn(tag(context, 0))]
+    first: Integer,
+}
+
+fn message() -> Message {
+    Message {
+        id: 42,
+        kind: Kind::Notification,
+        payload: Payload::Blob(OctetString::from_static(&[0xCA, 0xFE])),
+        note: None,
+        tags: vec![Ia5String::try_from("alpha").unwrap()],
+        priority: Some(3),
+    }
+}
+
+fn message_value() -> Value<'static> {
+    Value::Sequence(vec![
+        NamedValue::new("id", Value::Integer(42.into())),
+        NamedValue::new("kind", Value::Enumerated("Notification")),
+        NamedValue::new(
+            "payload",
+            Value::Choice {
+                variant: "Blob",
+                value: Box::new(Value::OctetString(OctetString::from_static(&[0xCA, 0xFE]))),
+            },
+        ),
+        NamedValue::new(
+            "tags",
+            Value::SequenceOf(vec![Value::Ia5String(
+                Ia5String::try_from("alpha").unwrap(),
+            )]),
+        ),
+        NamedValue::new("priority", Value::Integer(3.into())),
+    ])
+}
+
+macro_rules! test_codecs {
+    ($($codec:ident),+ $(,)?) => {
+        $(
+            #[test]
+            fn $codec() {
+                let encoded = rasn::$codec::encode(&message()).unwrap();
+                let value = rasn::$codec::decode_value(&Message::DESCRIPTOR, &encoded).unwrap();
+                assert_eq!(message_value(), value);
+                assert_eq!(
+                    encoded,
+                    rasn::$codec::encode_value(&Message::DESCRIPTOR, &value).unwrap()
+                );
+
+                let root = Message {
+                    kind: Kind::Response,
+                    payload: Payload::Number((-7).into()),
+                    note: Some("hi".into()),
+                    priority: None,
+                    ..message()
+                };
+                let encoded = rasn::$codec::encode(&root).unwrap();
+                let value = rasn::$codec::decode_value(&Message::DESCRIPTOR, &encoded).unwrap();
+                assert_eq!(value.get("kind"), Some(&Value::Enumerated("Response")));
+                assert_eq!(
+                    value.get("payload").and_then(|payload| payload.get("Number")),
+                    Some(&Value::Integer((-7).into()))
+                );
+                assert_eq!(value.get("note"), Some(&Value::Utf8String("hi".into())));
+                assert_eq!(value.get("priority"), None);
+
+                let pair = Pair { second: true, first: 5.into() };
+                let encoded = rasn::$codec::encode(&pair).unwrap();
+                let value = Value::Set(vec![
+                    NamedValue::new("second", Value::Boolean(true)),
+                    NamedValue::new("first", Value::Integer(5.into())),
+                ]);
+                assert_eq!(
+                    value,
+                    rasn::$codec::decode_value(&Pair::DESCRIPTOR, &encoded).unwrap()
+                );
+                assert_eq!(
+                    encoded,
+                    rasn::$codec::encode_value(&Pair::DESCRIPTOR, &value).unwrap()
+                );
+            }
+        )+
+    }
+}
+
+test_codecs!(ber, cer, der, uper, aper, oer, coer, jer);
+
+#[test]
+fn transcoding() {
+    let ber = rasn::ber::encode(&message()).unwrap();
+    let value = rasn::ber::decode_value(&Message::DESCRIPTOR, &ber).unwrap();
+    let uper = rasn::uper::encode_value(&Message::DESCRIPTOR, &value).unwrap();
+    assert_eq!(rasn::uper::encode(&message()).unwrap(), uper);
+
+    let value = rasn::uper::decode_value(&Message::DESCRIPTOR, &uper).unwrap();
+    let jer = rasn::jer::encode_value(&Message::DESCRIPTOR, &value).unwrap();
+    assert_eq!(message(), rasn::jer::decode::<Message>(&jer).unwrap());
+}
+
+#[test]
+fn invalid_values_are_rejected() {
+    let missing = Value::Sequence(vec![NamedValue::new("id", Value::Integer(1.into()))]);
+    assert!(rasn::uper::encode_value(&Message::DESCRIPTOR, &missing).is_err());
+
+    let mut mismatched = message_value();
+    let Value::Sequence(components) = &mut mismatched else {
+        unreachable!()
+    };
+    components[0].value = Value::Boolean(true);
+    assert!(rasn::ber::encode_value(&Message::DESCRIPTOR, &mismatched).is_err());
+    assert!(rasn::oer::encode_value(&Message::DESCRIPTOR, &mismatched).is_err());
+}
+
+#[test]
+fn display() {
+    assert_eq!(
+        message_value().to_string(),
+        "{\n  \
+           id 42,\n  \
+           kind Notification,\n  \
+           payload Blob : 'CAFE'H,\n  \
+           tags {\n    \
+             \"alpha\"\n  \
+           },\n  \
+           priority 3\n\
+         }"
+    );
+}
+
+#[test]
+fn unknown_extensions_are_skipped() {
+    #[derive(AsnType, Debug, Decode, Encode, Describe, PartialEq)]
+    #[rasn(automatic_tags)]
+    #[non_exhaustive]
+    struct Old {
+        id: u8,
+    }
+
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+    #[rasn(automatic_tags)]
+    #[non_exhaustive]
+    struct New {
+        id: u8,
+        #[rasn(extension_addition)]
+        name: Option<Utf8String>,
+    }
+
+    let new = New {
+        id: 1,
+        name: Some("new".into()),
+    };
+    let expected = Value::Sequence(vec![NamedValue::new("id", Value::Integer(1.into()))]);
+    assert_eq!(
+        expected,
+        rasn::uper::decode_value(&Old::DESCRIPTOR, &rasn::uper::encode(&new).unwrap()).unwrap()
+    );
+    assert_eq!(
+        expected,
+        rasn::oer::decode_value(&Old::DESCRIPTOR, &rasn::oer::encode(&new).unwrap()).unwrap()
+    );
+}
+
+#[test]
+fn aper_set_components_are_padded_where_placed() {
+    #[derive(AsnType, Debug, Decode, Encode, Describe, PartialEq)]
+    #[rasn(set)]
+    struct Record {
+        #[rasn(tag(context, 2))]
+        flag: bool,
+        #[rasn(tag(context, 1))]
+        pairs: SequenceOf<Pair>,
+        #[rasn(tag(context, 0))]
+        data: OctetString,
+    }
+
+    #[derive(AsnType, Debug, Decode, Encode, Describe, PartialEq)]
+    #[rasn(automatic_tags)]
+    struct Wrapper {
+        flag: bool,
+        record: Record,
+    }
+
+    // The components are encoded in the order they appear, but placed in
+    // canonical tag order, so the integer is aligned from the start.
+    let pair = Pair {
+        second: true,
+        first: 5.into(),
+    };
+    assert_eq!(&[0x01, 0x05, 0x80], &*rasn::aper::encode(&pair).unwrap());
+
+    let wrapper = Wrapper {
+        flag: true,
+        record: Record {
+            flag: false,
+            pairs: vec![
+                pair,
+                Pair {
+                    second: false,
+                    first: 300.into(),
+                },
+            ],
+            data: OctetString::from_static(&[0xAB, 0xCD]),
+        },
+    };
+    let encoded = rasn::aper::encode(&wrapper).unwrap();
+    assert_eq!(wrapper, rasn::aper::decode::<Wrapper>(&encoded).unwrap());
+    let value = rasn::aper::decode_value(&Wrapper::DESCRIPTOR, &encoded).unwrap();
+    assert_eq!(
+        encoded,
+        rasn::aper::encode_value(&Wrapper::DESCRIPTOR, &value).unwrap()
+    );
+}
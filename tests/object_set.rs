@@ -0,0 +1,175 @@
+use rasn::{
+    error::{DecodeErrorKind, EncodeErrorKind},
+    prelude::*,
+    Codec,
+};
+
+const CODECS: [Codec; 9] = [
+    Codec::Aper,
+    Codec::Ber,
+    Codec::Cer,
+    Codec::Der,
+    Codec::Uper,
+    Codec::Jer,
+    Codec::Oer,
+    Codec::Coer,
+    Codec::Xer,
+];
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+#[rasn(automatic_tags)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+/// `MESSAGE ::= CLASS { &id INTEGER UNIQUE, &Type } WITH SYNTAX { ID &id TYPE &Type }`
+#[derive(Clone, Debug, PartialEq, InformationObjectSet)]
+#[rasn(id_type = Integer)]
+enum Messages {
+    #[rasn(id = 1)]
+    Text(Utf8String),
+    #[rasn(id = 2)]
+    Point(Point),
+    Unknown(Any),
+}
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+struct Message {
+    kind: Integer,
+    #[rasn(table(kind))]
+    body: Messages,
+}
+
+const ID_AES128_CBC: &Oid = Oid::const_new(&[2, 16, 840, 1, 101, 3, 4, 1, 2]);
+const ID_HMAC: &Oid = Oid::const_new(&[1, 2, 840, 113549, 2, 9]);
+
+#[derive(Clone, Debug, PartialEq, InformationObjectSet)]
+#[rasn(id_type = ObjectIdentifier)]
+enum Parameters {
+    #[rasn(id = ID_AES128_CBC)]
+    Aes128Cbc(OctetString),
+    #[rasn(id = ID_HMAC)]
+    Hmac(u32),
+}
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq)]
+struct AlgorithmIdentifier {
+    algorithm: ObjectIdentifier,
+    #[rasn(table(algorithm))]
+    parameters: Option<Parameters>,
+}
+
+#[track_caller]
+fn round_trip<T: Decode + Encode + PartialEq + core::fmt::Debug>(value: &T) {
+    for codec in CODECS {
+        let encoded = codec.encode_to_binary(value).unwrap();
+        let decoded: T = codec
+            .decode_from_binary(&encoded)
+            .unwrap_or_else(|error| panic!("{codec}: {error}"));
+        assert_eq!(value, &decoded, "{codec}");
+    }
+}
+
+#[test]
+fn dispatch_on_integer_id() {
+    round_trip(&Message {
+        kind: 1.into(),
+        body: Messages::Text("hello".into()),
+    });
+    round_trip(&Message {
+        kind: 2.into(),
+        body: Messages::Point(Point { x: 3, y: -4 }),
+    });
+}
+
+#[test]
+fn dispatch_on_oid_id() {
+    round_trip(&AlgorithmIdentifier {
+        algorithm: ID_AES128_CBC.into(),
+        parameters: Some(Parameters::Aes128Cbc(OctetString::from_static(&[7; 16]))),
+    });
+    round_trip(&AlgorithmIdentifier {
+        algorithm: ID_HMAC.into(),
+        parameters: Some(Parameters::Hmac(256)),
+    });
+    round_trip(&AlgorithmIdentifier {
+        algorithm: ID_AES128_CBC.into(),
+        parameters: None,
+    });
+}
+
+#[test]
+fn open_type_encoding() {
+    let message = Message {
+        kind: 1.into(),
+        body: Messages::Text("hi".into()),
+    };
+    assert_eq!(
+        rasn::der::encode(&message).unwrap(),
+        [0x30, 0x07, 0x02, 0x01, 0x01, 0x0C, 0x02, b'h', b'i']
+    );
+    // The value is wrapped in a length-prefixed octet string.
+    assert_eq!(
+        rasn::uper::encode(&message).unwrap(),
+        [0x01, 0x01, 0x03, 0x02, b'h', b'i']
+    );
+    assert_eq!(
+        rasn::jer::encode(&message).unwrap(),
+        r#"{"body":"hi","kind":1}"#
+    );
+}
+
+#[test]
+fn unknown_objects() {
+    let unknown = Message {
+        kind: 3.into(),
+        body: Messages::Unknown(Any::new(rasn::ber::encode(&true).unwrap())),
+    };
+    let encoded = rasn::ber::encode(&unknown).unwrap();
+    assert_eq!(unknown, rasn::ber::decode::<Message>(&encoded).unwrap());
+    // Values of unknown objects encoded with other codecs are JER octet
+    // strings.
+    assert_eq!(
+        rasn::jer::encode(&unknown).unwrap(),
+        r#"{"body":"0101FF","kind":3}"#
+    );
+
+    let algorithm = AlgorithmIdentifier {
+        algorithm: ID_AES128_CBC.into(),
+        parameters: Some(Parameters::Aes128Cbc(OctetString::from_static(&[7; 16]))),
+    };
+    let mut encoded = rasn::der::encode(&algorithm).unwrap();
+    // Change the last arc of the OID to an unknown algorithm.
+    encoded[12] = 127;
+    let error = rasn::der::decode::<AlgorithmIdentifier>(&encoded).unwrap_err();
+    let DecodeErrorKind::FieldError { nested, .. } = *error.kind else {
+        panic!("unexpected error: {error}");
+    };
+    assert!(
+        matches!(
+            &*nested.kind,
+            DecodeErrorKind::UnknownInformationObject { identifier }
+                if identifier.contains("2, 16, 840, 1, 101, 3, 4, 1, 127")
+        ),
+        "{nested}"
+    );
+}
+
+#[test]
+fn mismatched_identifier() {
+    let message = Message {
+        kind: 1.into(),
+        body: Messages::Point(Point { x: 0, y: 0 }),
+    };
+    for codec in CODECS {
+        let error = codec.encode_to_binary(&message).unwrap_err();
+        assert!(
+            matches!(
+                &*error.kind,
+                EncodeErrorKind::InformationObjectMismatch { .. }
+            ),
+            "{codec}: {error}"
+        );
+    }
+}
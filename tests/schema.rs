@@ -0,0 +1,429 @@
+#![cfg(feature = "schema")]
+
+use rasn::prelude::*;
+use rasn::schema::Schema;
+use rasn::types::value::Value;
+
+const MESSAGES: &str = r#"
+Messages { iso(1) identified-organization(3) example(999) messages(1) }
+DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+    EXPORTS ALL;
+
+    maxId INTEGER ::= 1000
+
+    Message ::= SEQUENCE {
+        id      INTEGER (0..maxId),
+        kind    Kind,
+        payload Payload,
+        note    UTF8String OPTIONAL,
+        tags    SEQUENCE OF IA5String,
+        ...,
+        priority INTEGER OPTIONAL
+    }
+
+    Kind ::= ENUMERATED { request, response, ..., notification }
+
+    Payload ::= CHOICE {
+        text   UTF8String,
+        number INTEGER,
+        ...,
+        blob   OCTET STRING
+    }
+
+    Pair ::= SET {
+        second [1] BOOLEAN,
+        first  [0] INTEGER
+    }
+END
+"#;
+
+#[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+#[rasn(automatic_tags)]
+#[non_exhaustive]
+struct Message {
+    #[rasn(value("0..=1000"))]
+    id: u16,
+    kind: Kind,
+    payload: Payload,
+    note: Option<Utf8String>,
+    tags: Vec<Ia5String>,
+    #[rasn(extension_addition)]
+    priority: Option<Integer>,
+}
+
+#[derive(AsnType, Clone, Copy, Debug, Decode, Encode, PartialEq)]
+#[rasn(enumerated)]
+#[non_exhaustive]
+enum Kind {
+    Request,
+    Response,
+    #[rasn(extension_addition)]
+    Notification = 2,
+}
+
+#[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+#[rasn(choice, automatic_tags)]
+#[non_exhaustive]
+enum Payload {
+    Text(Utf8String),
+    Number(Integer),
+    #[rasn(extension_addition)]
+    Blob(OctetString),
+}
+
+#[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+#[rasn(set)]
+struct Pair {
+    #[rasn(tag(context, 1))]
+    second: bool,
+    #[rasn(tag(context, 0))]
+    first: Integer,
+}
+
+macro_rules! test_codecs {
+    ($($codec:ident),+ $(,)?) => {
+        $(
+            #[test]
+            fn $codec() {
+                let schema = Schema::parse(MESSAGES).unwrap();
+                let message = schema.get("Message").unwrap();
+                let pair = schema.get_in("Messages", "Pair").unwrap();
+
+                for typed in [
+                    Message {
+                        id: 42,
+                        kind: Kind::Notification,
+                        payload: Payload::Blob(OctetString::from_static(&[0xCA, 0xFE])),
+                        note: None,
+                        tags: vec![Ia5String::try_from("alpha").unwrap()],
+                        priority: Some(3.into()),
+                    },
+                    Message {
+                        id: 1000,
+                        kind: Kind::Response,
+                        payload: Payload::Text("text".into()),
+                        note: Some("note".into()),
+                        tags: Vec::new(),
+                        priority: None,
+                    },
+                ] {
+                    let bytes = rasn::$codec::encode(&typed).unwrap();
+                    let value = rasn::$codec::decode_value(message, &bytes).unwrap();
+                    assert_eq!(value.get("id"), Some(&Value::Integer(typed.id.into())));
+                    assert_eq!(rasn::$codec::encode_value(message, &value).unwrap(), bytes);
+                }
+
+                let bytes = rasn::$codec::encode(&Pair { second: true, first: 7.into() }).unwrap();
+                let value = rasn::$codec::decode_value(pair, &bytes).unwrap();
+                assert_eq!(value.get("second"), Some(&Value::Boolean(true)));
+                assert_eq!(rasn::$codec::encode_value(pair, &value).unwrap(), bytes);
+            }
+        )+
+    }
+}
+
+test_codecs!(ber, cer, der, uper, oer, coer);
+
+const LISTS: &str = r#"
+Lists DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+    List ::= SEQUENCE {
+        value INTEGER,
+        next  List OPTIONAL
+    }
+
+    Tree ::= CHOICE {
+        leaf INTEGER,
+        node SEQUENCE OF Tree
+    }
+END
+"#;
+
+#[test]
+fn recursive_types() {
+    let schema = Schema::parse(LISTS).unwrap();
+    let list = schema.get("List").unwrap();
+    let tree = schema.get("Tree").unwrap();
+
+    let value = rasn::jer::decode_value(list, r#"{"value": 1, "next": {"value": 2}}"#).unwrap();
+    let bytes = rasn::der::encode_value(list, &value).unwrap();
+    assert_eq!(
+        bytes,
+        [0x30, 0x08, 0x80, 0x01, 0x01, 0xA1, 0x03, 0x80, 0x01, 0x02]
+    );
+    assert_eq!(rasn::der::decode_value(list, &bytes).unwrap(), value);
+
+    for (descriptor, json) in [
+        (
+            list,
+            r#"{"value": 1, "next": {"value": 2, "next": {"value": 3}}}"#,
+        ),
+        (
+            tree,
+            r#"{"node": [{"leaf": 1}, {"node": [{"node": []}, {"leaf": 2}]}]}"#,
+        ),
+    ] {
+        let value = rasn::jer::decode_value(descriptor, json).unwrap();
+        let bytes = rasn::ber::encode_value(descriptor, &value).unwrap();
+        assert_eq!(rasn::ber::decode_value(descriptor, &bytes).unwrap(), value);
+        let bytes = rasn::aper::encode_value(descriptor, &value).unwrap();
+        assert_eq!(rasn::aper::decode_value(descriptor, &bytes).unwrap(), value);
+        let bytes = rasn::uper::encode_value(descriptor, &value).unwrap();
+        assert_eq!(rasn::uper::decode_value(descriptor, &bytes).unwrap(), value);
+        let bytes = rasn::oer::encode_value(descriptor, &value).unwrap();
+        assert_eq!(rasn::oer::decode_value(descriptor, &bytes).unwrap(), value);
+        let json = rasn::jer::encode_value(descriptor, &value).unwrap();
+        assert_eq!(rasn::jer::decode_value(descriptor, &json).unwrap(), value);
+    }
+}
+
+const CERTIFICATE: &str = r#"
+PKIX1Explicit88 { iso(1) identified-organization(3) dod(6) internet(1)
+  security(5) mechanisms(5) pkix(7) id-mod(0) id-pkix1-explicit(18) }
+DEFINITIONS EXPLICIT TAGS ::= BEGIN
+
+IMPORTS AlgorithmIdentifier FROM Algorithms;
+
+id-pkix OBJECT IDENTIFIER ::= { iso(1) identified-organization(3) dod(6)
+    internet(1) security(5) mechanisms(5) pkix(7) }
+
+Certificate ::= SEQUENCE {
+    tbsCertificate       TBSCertificate,
+    signatureAlgorithm   AlgorithmIdentifier,
+    signature            BIT STRING }
+
+TBSCertificate ::= SEQUENCE {
+    version         [0] Version DEFAULT v1,
+    serialNumber         CertificateSerialNumber,
+    signature            AlgorithmIdentifier,
+    issuer               Name,
+    validity             Validity,
+    subject              Name,
+    subjectPublicKeyInfo SubjectPublicKeyInfo,
+    issuerUniqueID  [1]  IMPLICIT UniqueIdentifier OPTIONAL,
+    subjectUniqueID [2]  IMPLICIT UniqueIdentifier OPTIONAL,
+    extensions      [3]  Extensions OPTIONAL }
+
+Version ::= INTEGER { v1(0), v2(1), v3(2) }
+
+CertificateSerialNumber ::= INTEGER
+
+Validity ::= SEQUENCE {
+    notBefore      Time,
+    notAfter       Time }
+
+Time ::= CHOICE {
+    utcTime        UTCTime,
+    generalTime    GeneralizedTime }
+
+UniqueIdentifier ::= BIT STRING
+
+SubjectPublicKeyInfo ::= SEQUENCE {
+    algorithm            AlgorithmIdentifier,
+    subjectPublicKey     BIT STRING }
+
+Extensions ::= SEQUENCE SIZE (1..MAX) OF Extension
+
+Extension ::= SEQUENCE {
+    extnID      OBJECT IDENTIFIER,
+    critical    BOOLEAN DEFAULT FALSE,
+    extnValue   OCTET STRING }
+
+Name ::= CHOICE { -- only one possibility for now --
+    rdnSequence  RDNSequence }
+
+RDNSequence ::= SEQUENCE OF RelativeDistinguishedName
+
+RelativeDistinguishedName ::= SET SIZE (1..MAX) OF AttributeTypeAndValue
+
+AttributeTypeAndValue ::= SEQUENCE {
+    type     AttributeType,
+    value    AttributeValue }
+
+AttributeType ::= OBJECT IDENTIFIER
+
+AttributeValue ::= ANY -- DEFINED BY AttributeType
+
+-- Parameterized types and classes are skipped.
+ATTRIBUTE ::= CLASS { &id OBJECT IDENTIFIER UNIQUE, &Type }
+    WITH SYNTAX { TYPE &Type IDENTIFIED BY &id }
+
+SingleAttribute { ATTRIBUTE:AttrSet } ::= SEQUENCE {
+    type      ATTRIBUTE.&id({AttrSet}),
+    value     ATTRIBUTE.&Type({AttrSet}{@type}) }
+
+END
+
+Algorithms DEFINITIONS IMPLICIT TAGS ::= BEGIN
+
+AlgorithmIdentifier ::= SEQUENCE {
+    algorithm   OBJECT IDENTIFIER,
+    parameters  ANY DEFINED BY algorithm OPTIONAL }
+
+END
+"#;
+
+#[test]
+fn certificate() {
+    let schema = Schema::parse(CERTIFICATE).unwrap();
+    assert_eq!(
+        schema
+            .modules()
+            .map(|module| module.name())
+            .collect::<Vec<_>>(),
+        ["PKIX1Explicit88", "Algorithms"]
+    );
+    assert!(matches!(
+        schema.get("SingleAttribute"),
+        Err(rasn::error::SchemaError::Unsupported { .. })
+    ));
+
+    let certificate = schema.get("Certificate").unwrap();
+    let bytes = include_bytes!("../standards/pkix/tests/data/letsencrypt-x3.crt");
+    let value = rasn::der::decode_value(certificate, bytes).unwrap();
+
+    let tbs = value.get("tbsCertificate").unwrap();
+    assert_eq!(tbs.get("version"), Some(&Value::Integer(2.into())));
+    assert!(matches!(
+        tbs.get("validity")
+            .and_then(|validity| validity.get("notBefore")),
+        Some(Value::Choice {
+            variant: "utcTime",
+            ..
+        })
+    ));
+    assert_eq!(rasn::der::encode_value(certificate, &value).unwrap(), bytes);
+}
+
+#[test]
+fn time_types() {
+    const SCHEDULE: &str = r#"
+    Schedule DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+        Slot ::= SEQUENCE {
+            start    TIME-OF-DAY,
+            length   DURATION,
+            created  DATE-TIME OPTIONAL
+        }
+    END
+    "#;
+
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+    #[rasn(automatic_tags)]
+    struct Slot {
+        start: TimeOfDay,
+        length: IsoDuration,
+        created: Option<DateTime>,
+    }
+
+    let schema = Schema::parse(SCHEDULE).unwrap();
+    let slot = schema.get("Slot").unwrap();
+    let typed = Slot {
+        start: TimeOfDay::from_hms_opt(9, 30, 0).unwrap(),
+        length: "PT1H30M".parse().unwrap(),
+        created: None,
+    };
+
+    let bytes = rasn::der::encode(&typed).unwrap();
+    let value = rasn::der::decode_value(slot, &bytes).unwrap();
+    assert_eq!(value.get("length"), Some(&Value::Duration(typed.length)));
+    assert_eq!(rasn::der::encode_value(slot, &value).unwrap(), bytes);
+
+    let bytes = rasn::uper::encode(&typed).unwrap();
+    let value = rasn::uper::decode_value(slot, &bytes).unwrap();
+    assert_eq!(value.get("start"), Some(&Value::TimeOfDay(typed.start)));
+    assert_eq!(rasn::uper::encode_value(slot, &value).unwrap(), bytes);
+}
+
+#[test]
+fn relative_object_identifiers() {
+    const REGISTRY: &str = r#"
+    Registry DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+        Entry ::= SEQUENCE {
+            arcs   RELATIVE-OID,
+            iri    OID-IRI,
+            alias  RELATIVE-OID-IRI OPTIONAL
+        }
+    END
+    "#;
+
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+    #[rasn(automatic_tags)]
+    struct Entry {
+        arcs: RelativeObjectIdentifier,
+        iri: OidIri,
+        alias: Option<RelativeOidIri>,
+    }
+
+    let schema = Schema::parse(REGISTRY).unwrap();
+    let entry = schema.get("Entry").unwrap();
+    let typed = Entry {
+        arcs: RelativeObjectIdentifier::new(vec![840, 113_549]).unwrap(),
+        iri: "/ISO/Member-Body/840/113549".parse().unwrap(),
+        alias: Some("RSADSI".parse().unwrap()),
+    };
+
+    let bytes = rasn::der::encode(&typed).unwrap();
+    let value = rasn::der::decode_value(entry, &bytes).unwrap();
+    assert_eq!(
+        value.get("arcs"),
+        Some(&Value::RelativeOid(typed.arcs.clone()))
+    );
+    assert_eq!(rasn::der::encode_value(entry, &value).unwrap(), bytes);
+
+    let bytes = rasn::uper::encode(&typed).unwrap();
+    let value = rasn::uper::decode_value(entry, &bytes).unwrap();
+    assert_eq!(value.get("iri"), Some(&Value::OidIri(typed.iri.clone())));
+    assert_eq!(rasn::uper::encode_value(entry, &value).unwrap(), bytes);
+}
+
+#[test]
+fn external_types() {
+    const PRESENTATION: &str = r#"
+    Presentation DEFINITIONS AUTOMATIC TAGS ::= BEGIN
+        Message ::= SEQUENCE {
+            user-data  EXTERNAL,
+            pdv        EMBEDDED PDV OPTIONAL,
+            text       CHARACTER STRING OPTIONAL
+        }
+    END
+    "#;
+
+    #[derive(AsnType, Debug, Decode, Encode, PartialEq)]
+    #[rasn(automatic_tags)]
+    struct Message {
+        #[rasn(identifier = "user-data")]
+        user_data: External,
+        pdv: Option<EmbeddedPdv>,
+        text: Option<CharacterString>,
+    }
+
+    let schema = Schema::parse(PRESENTATION).unwrap();
+    let message = schema.get("Message").unwrap();
+    let typed = Message {
+        user_data: External {
+            identification: Identification::PresentationContextId(1.into()),
+            data_value_descriptor: None,
+            data_value: vec![0x60, 0x00].into(),
+        },
+        pdv: None,
+        text: Some(CharacterString {
+            identification: Identification::Fixed,
+            string_value: b"hi".to_vec().into(),
+        }),
+    };
+
+    let bytes = rasn::der::encode(&typed).unwrap();
+    let value = rasn::der::decode_value(message, &bytes).unwrap();
+    assert_eq!(
+        value.get("user-data"),
+        Some(&Value::External(typed.user_data.clone()))
+    );
+    assert_eq!(
+        value.get("text").and_then(|text| text.get("string-value")),
+        Some(&Value::OctetString(b"hi".to_vec().into()))
+    );
+    assert_eq!(rasn::der::encode_value(message, &value).unwrap(), bytes);
+
+    let bytes = rasn::aper::encode(&typed).unwrap();
+    let value = rasn::aper::decode_value(message, &bytes).unwrap();
+    assert_eq!(rasn::aper::encode_value(message, &value).unwrap(), bytes);
+}
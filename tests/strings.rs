@@ -456,3 +456,74 @@ fn test_per_encode_utf8_string() {
         assert_eq!(case, uper::decode::<Utf8String>(&buf_expected).unwrap());
     }
 }
+
+#[derive(AsnType, Decode, Encode, Debug, Clone, PartialEq)]
+#[rasn(automatic_tags)]
+pub struct TeletexNames {
+    pub common_name: TeletexString,
+    pub surname: Option<TeletexString>,
+}
+
+#[test]
+fn test_teletex_string_round_trip() {
+    let names = TeletexNames {
+        common_name: TeletexString::try_from("Željko Ćosić").unwrap(),
+        surname: Some(TeletexString::try_from("Grüße #1 ½").unwrap()),
+    };
+    for codec in [
+        rasn::Codec::Aper,
+        rasn::Codec::Ber,
+        rasn::Codec::Cer,
+        rasn::Codec::Der,
+        rasn::Codec::Uper,
+        rasn::Codec::Jer,
+        rasn::Codec::Oer,
+        rasn::Codec::Coer,
+        rasn::Codec::Xer,
+    ] {
+        let encoded = codec.encode_to_binary(&names).unwrap();
+        let decoded = codec.decode_from_binary::<TeletexNames>(&encoded).unwrap();
+        assert_eq!(names, decoded, "{codec}");
+    }
+}
+
+#[test]
+fn test_teletex_string_character_mapping() {
+    let string = TeletexString::try_from("Grüße #1").unwrap();
+    assert_eq!(
+        string.to_bytes(),
+        [0x47, 0x72, 0xC8, 0x75, 0xFB, 0x65, 0x20, 0xA6, 0x31]
+    );
+    assert_eq!("Grüße #1", string.to_unicode().unwrap());
+    // Combining marks are converted to a diacritic and base character pair.
+    let combined = TeletexString::try_from("e\u{0301}").unwrap();
+    assert_eq!(combined.to_bytes(), [0xC2, 0x65]);
+    assert_eq!("é", combined.to_unicode().unwrap());
+    assert!(TeletexString::try_from("€").is_err());
+    assert!(TeletexString::try_from("\u{0301}").is_err());
+
+    // Escape sequences are kept as is by the binary codecs, but have no
+    // Unicode representation for the textual ones.
+    let escaped = TeletexString::from_bytes(&[0x1B, 0x28, 0x42, 0x41]).unwrap();
+    assert!(escaped.to_unicode().is_err());
+    assert!(jer::encode(&escaped).is_err());
+    assert_eq!(
+        escaped,
+        ber::decode::<TeletexString>(&ber::encode(&escaped).unwrap()).unwrap()
+    );
+    assert!(TeletexString::from_bytes(&[0xC8])
+        .unwrap()
+        .to_unicode()
+        .is_err());
+}
+
+#[test]
+fn test_teletex_string_encodings() {
+    let string = TeletexString::try_from("ü").unwrap();
+    assert_eq!(ber::encode(&string).unwrap(), [0x14, 0x02, 0xC8, 0x75]);
+    assert_eq!(uper::encode(&string).unwrap(), [0x02, 0xC8, 0x75]);
+    assert_eq!(aper::encode(&string).unwrap(), [0x02, 0xC8, 0x75]);
+    assert_eq!(oer::encode(&string).unwrap(), [0x02, 0xC8, 0x75]);
+    assert_eq!(jer::encode(&string).unwrap(), "\"ü\"");
+    assert_eq!(string, jer::decode::<TeletexString>("\"ü\"").unwrap());
+}
@@ -0,0 +1,96 @@
+use rasn::{
+    ber::{de, enc},
+    error::{DecodeError, EncodeError},
+    prelude::*,
+};
+
+#[derive(AsnType, Clone, Debug, Decode, Encode, PartialEq, Validate)]
+#[rasn(automatic_tags)]
+struct Reading {
+    #[rasn(value("0..=100"))]
+    level: u8,
+    #[rasn(size("1..=4"), from("A..=Z"))]
+    station: Ia5String,
+    #[rasn(size("1..=2"))]
+    samples: SequenceOf<Integer>,
+}
+
+fn valid() -> Reading {
+    Reading {
+        level: 42,
+        station: Ia5String::try_from("AB").unwrap(),
+        samples: vec![1.into(), 2.into()],
+    }
+}
+
+fn invalid() -> Reading {
+    Reading {
+        level: 101,
+        station: Ia5String::try_from("ab").unwrap(),
+        samples: vec![1.into(), 2.into(), 3.into()],
+    }
+}
+
+fn der_encode(value: &Reading, enforce: bool) -> Result<Vec<u8>, EncodeError> {
+    let mut encoder = enc::Encoder::new(enc::EncoderOptions::der().enforce_constraints(enforce));
+    value.encode(&mut encoder)?;
+    Ok(encoder.output())
+}
+
+fn der_decode(input: &[u8], enforce: bool) -> Result<Reading, DecodeError> {
+    Reading::decode(&mut de::Decoder::new(
+        input,
+        de::DecoderOptions::der().enforce_constraints(enforce),
+    ))
+}
+
+#[test]
+fn validate() {
+    assert!(valid().validate().is_ok());
+
+    let error = invalid().validate().unwrap_err();
+    let paths: Vec<_> = error.violations.iter().map(|v| v.path.as_str()).collect();
+    assert_eq!(paths, ["level", "station", "samples"]);
+}
+
+#[test]
+fn ber_enforcement() {
+    let encoded = der_encode(&valid(), true).unwrap();
+    assert_eq!(der_decode(&encoded, true).unwrap(), valid());
+
+    // Constraints are only checked when asked to.
+    let encoded = der_encode(&invalid(), false).unwrap();
+    assert_eq!(der_decode(&encoded, false).unwrap(), invalid());
+    assert!(der_encode(&invalid(), true).is_err());
+    assert!(der_decode(&encoded, true).is_err());
+}
+
+#[test]
+fn jer_enforcement() {
+    let encode = |value: &Reading| {
+        let mut encoder = rasn::jer::enc::Encoder::new().enforce_constraints(true);
+        value.encode(&mut encoder).map(|_| encoder.to_string())
+    };
+    let decode = |input: &str| {
+        Reading::decode(&mut rasn::jer::de::Decoder::new(input)?.enforce_constraints(true))
+    };
+
+    assert_eq!(decode(&encode(&valid()).unwrap()).unwrap(), valid());
+    assert!(encode(&invalid()).is_err());
+    assert!(decode(&rasn::jer::encode(&invalid()).unwrap()).is_err());
+}
+
+#[test]
+fn xer_enforcement() {
+    let encode = |value: &Reading| {
+        let mut encoder = rasn::xer::enc::Encoder::new().enforce_constraints(true);
+        value.encode(&mut encoder).map(|_| encoder.finish())
+    };
+    let decode = |input: &[u8]| {
+        Reading::decode(&mut rasn::xer::de::Decoder::new(input)?.enforce_constraints(true))
+    };
+
+    assert_eq!(decode(&encode(&valid()).unwrap()).unwrap(), valid());
+    assert!(encode(&invalid()).is_err());
+    assert!(decode(&rasn::xer::encode(&invalid()).unwrap()).is_err());
+}